@@ -89,7 +89,7 @@ impl Problem {
 }
 
 /// The fitness function for `Selection`
-impl<'a> FitnessFunction<Selection, i64> for &'a Problem {
+impl FitnessFunction<Selection, i64> for &Problem {
     fn fitness_of(&self, selection: &Selection) -> i64 {
         let (total_weight, total_value) = selection
             .iter()
@@ -243,7 +243,7 @@ fn main() {
                     knapsack.weight
                 );
             },
-            Ok(SimResult::Final(step, processing_time, duration, stop_reason)) => {
+            Ok(SimResult::Final(step, processing_time, duration, stop_reason, _)) => {
                 let best_solution = step.result.best_solution;
                 println!("{}", stop_reason);
                 println!(
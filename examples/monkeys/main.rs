@@ -3,7 +3,8 @@
 //! [infinite monkey theorem](https://en.wikipedia.org/wiki/Infinite_monkey_theorem).
 
 use genevo::{
-    operator::prelude::*, population::ValueEncodedGenomeBuilder, prelude::*, types::fmt::Display,
+    operator::prelude::*, population::CharValueEncodedGenomeBuilder, prelude::*,
+    types::fmt::Display,
 };
 
 // const TARGET_TEXT: &str = "See how a genius creates a legend";
@@ -38,22 +39,12 @@ impl Default for Parameter {
     }
 }
 
-/// The phenotype
-type Text = String;
+/// The characters the monkeys are typing
+const ALPHABET: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+                        [\\]^_`abcdefghijklmnopqrstuvwxyz{|}";
 
-/// The genotype
-type TextGenome = Vec<u8>;
-
-/// How do the genes of the genotype show up in the phenotype
-trait AsPhenotype {
-    fn as_text(&self) -> Text;
-}
-
-impl AsPhenotype for TextGenome {
-    fn as_text(&self) -> Text {
-        String::from_utf8(self.to_vec()).unwrap()
-    }
-}
+/// The genotype, which is also the phenotype
+type TextGenome = String;
 
 /// The fitness function for `TextGenome`s.
 #[derive(Clone, Debug)]
@@ -62,14 +53,13 @@ struct FitnessCalc;
 impl FitnessFunction<TextGenome, usize> for FitnessCalc {
     fn fitness_of(&self, genome: &TextGenome) -> usize {
         let mut score = 0;
-        for (c, t) in genome.iter().zip(TARGET_TEXT.chars()) {
-            let c = *c as char;
+        for (c, t) in genome.chars().zip(TARGET_TEXT.chars()) {
             if c == t {
                 score += 1;
             }
         }
         let fraction = score as f32 / TARGET_TEXT.len() as f32;
-        (fraction * fraction * 100_00. + 0.5).floor() as usize
+        (fraction * fraction * 10_000. + 0.5).floor() as usize
     }
 
     fn average(&self, fitness_values: &[usize]) -> usize {
@@ -77,7 +67,7 @@ impl FitnessFunction<TextGenome, usize> for FitnessCalc {
     }
 
     fn highest_possible_fitness(&self) -> usize {
-        100_00
+        10_000
     }
 
     fn lowest_possible_fitness(&self) -> usize {
@@ -89,7 +79,10 @@ fn main() {
     let params = Parameter::default();
 
     let initial_population: Population<TextGenome> = build_population()
-        .with_genome_builder(ValueEncodedGenomeBuilder::new(TARGET_TEXT.len(), 32, 126))
+        .with_genome_builder(CharValueEncodedGenomeBuilder::new(
+            TARGET_TEXT.len(),
+            ALPHABET,
+        ))
         .of_size(params.population_size)
         .uniform_at_random();
 
@@ -101,7 +94,7 @@ fn main() {
                 params.num_individuals_per_parents,
             ))
             .with_crossover(MultiPointCrossBreeder::new(params.num_crossover_points))
-            .with_mutation(RandomValueMutator::new(params.mutation_rate, 32, 126))
+            .with_mutation(RandomCharMutator::new(params.mutation_rate, ALPHABET))
             .with_reinsertion(ElitistReinserter::new(
                 FitnessCalc,
                 true,
@@ -133,23 +126,26 @@ fn main() {
                     step.duration.fmt(),
                     step.processing_time.fmt()
                 );
-                println!("      {}", best_solution.solution.genome.as_text());
+                println!("      {}", best_solution.solution.genome);
                 //                println!("| population: [{}]", result.population.iter().map(|g| g.as_text())
                 //                    .collect::<Vec<String>>().join("], ["));
             },
-            Ok(SimResult::Final(step, processing_time, duration, stop_reason)) => {
+            Ok(SimResult::Final(step, processing_time, duration, stop_reason, _)) => {
+                let time_to_best = step.time_to_best();
                 let best_solution = step.result.best_solution;
                 println!("{}", stop_reason);
                 println!(
                     "Final result after {}: generation: {}, \
-                     best solution with fitness {} found in generation {}, processing_time: {}",
+                     best solution with fitness {} found in generation {} after {}, \
+                     processing_time: {}",
                     duration.fmt(),
                     step.iteration,
                     best_solution.solution.fitness,
                     best_solution.generation,
+                    time_to_best.fmt(),
                     processing_time.fmt()
                 );
-                println!("      {}", best_solution.solution.genome.as_text());
+                println!("      {}", best_solution.solution.genome);
                 break;
             },
             Err(error) => {
@@ -180,7 +180,7 @@ fn main() {
                     println!("      {:?}", row);
                 }
             }
-            Ok(SimResult::Final(step, processing_time, duration, stop_reason)) => {
+            Ok(SimResult::Final(step, processing_time, duration, stop_reason, _)) => {
                 let best_solution = step.result.best_solution;
                 println!("{}", stop_reason);
                 println!(
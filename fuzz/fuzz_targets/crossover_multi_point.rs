@@ -0,0 +1,16 @@
+#![no_main]
+
+use genevo::{
+    fuzz::{fuzz_crossover, FuzzLimits},
+    recombination::discrete::MultiPointCrossBreeder,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // the multi point crossover requires at least 2 genes per cut point
+    let limits = FuzzLimits {
+        genome_length: 6..=64,
+        ..FuzzLimits::default()
+    };
+    fuzz_crossover::<Vec<i32>, _>(&MultiPointCrossBreeder::new(3), &limits, data);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use genevo::{
+    fuzz::{fuzz_crossover, FuzzLimits},
+    recombination::order::{OrderOneCrossover, PartiallyMappedCrossover},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let limits = FuzzLimits {
+        num_parents: 2..=2,
+        ..FuzzLimits::default()
+    };
+    fuzz_crossover::<Vec<usize>, _>(&OrderOneCrossover::new(), &limits, data);
+    fuzz_crossover::<Vec<usize>, _>(&PartiallyMappedCrossover::new(), &limits, data);
+});
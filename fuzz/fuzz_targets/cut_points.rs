@@ -0,0 +1,8 @@
+#![no_main]
+
+use genevo::fuzz::fuzz_cut_points;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    fuzz_cut_points(data);
+});
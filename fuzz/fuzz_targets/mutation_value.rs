@@ -0,0 +1,13 @@
+#![no_main]
+
+use genevo::{
+    fuzz::{fuzz_mutation, FuzzLimits},
+    mutation::value::{GaussianMutator, RandomValueMutator},
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let limits = FuzzLimits::default();
+    fuzz_mutation::<Vec<i32>, _>(&RandomValueMutator::new(0.3, -100, 100), &limits, data);
+    fuzz_mutation::<Vec<f64>, _>(&GaussianMutator::new(0.3, 1., -1e6, 1e6), &limits, data);
+});
@@ -0,0 +1,146 @@
+//! This crate provides the derive macros of the
+//! [genevo](https://crates.io/crates/genevo) crate. Use the macros through
+//! the `derive` feature of `genevo` instead of depending on this crate
+//! directly.
+//!
+//! The `Fitness` derive macro implements the `genevo::genetic::Fitness`
+//! trait, the `genevo::genetic::AsScalar` trait and the arithmetic
+//! operators `Add`, `Sub` and `Sum` for newtypes over a fitness value, e.g.
+//! an integer:
+//!
+//! ```ignore
+//! use genevo::prelude::*;
+//!
+//! #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Fitness)]
+//! struct Score(u32);
+//! ```
+//!
+//! All implementations delegate to the wrapped value, which must itself
+//! implement `Fitness` and `AsScalar`. The newtype must derive or implement
+//! the supertraits of `Fitness`, i.e. `Clone`, `Debug`, `Eq` and `Ord`, by
+//! itself.
+
+#![doc(html_root_url = "https://docs.rs/genevo-derive/0.7.1")]
+#![deny(unsafe_code)]
+#![warn(
+    missing_copy_implementations,
+    missing_debug_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Index, Member};
+
+/// Derives `Fitness`, `AsScalar`, `Add`, `Sub` and `Sum` for a newtype that
+/// wraps a single fitness value.
+#[proc_macro_derive(Fitness)]
+pub fn derive_fitness(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_fitness(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_fitness(mut input: DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let (member, field_type) = newtype_field(&input)?;
+    input
+        .generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! {
+            #field_type: ::genevo::genetic::Fitness
+                + ::genevo::genetic::AsScalar
+                + ::std::ops::Add<Output = #field_type>
+                + ::std::ops::Sub<Output = #field_type>
+        });
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let wrap = |value: TokenStream2| match member {
+        Member::Named(ref field) => quote!(#name { #field: #value }),
+        Member::Unnamed(_) => quote!(#name(#value)),
+    };
+    let zero = wrap(quote!(<#field_type as ::genevo::genetic::Fitness>::zero()));
+    let abs_diff = wrap(quote!(::genevo::genetic::Fitness::abs_diff(
+        &self.#member,
+        &other.#member
+    )));
+    let add = wrap(quote!(self.#member + other.#member));
+    let sub = wrap(quote!(self.#member - other.#member));
+
+    Ok(quote! {
+        impl #impl_generics ::genevo::genetic::Fitness for #name #type_generics #where_clause {
+            fn zero() -> Self {
+                #zero
+            }
+
+            fn abs_diff(&self, other: &Self) -> Self {
+                #abs_diff
+            }
+        }
+
+        impl #impl_generics ::genevo::genetic::AsScalar for #name #type_generics #where_clause {
+            #[inline]
+            fn as_scalar(&self) -> f64 {
+                ::genevo::genetic::AsScalar::as_scalar(&self.#member)
+            }
+        }
+
+        impl #impl_generics ::std::ops::Add for #name #type_generics #where_clause {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                #add
+            }
+        }
+
+        impl #impl_generics ::std::ops::Sub for #name #type_generics #where_clause {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                #sub
+            }
+        }
+
+        impl #impl_generics ::std::iter::Sum for #name #type_generics #where_clause {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: ::std::iter::Iterator<Item = Self>,
+            {
+                iter.fold(::genevo::genetic::Fitness::zero(), |sum, value| sum + value)
+            }
+        }
+    })
+}
+
+/// Returns the member and the type of the only field of the given struct.
+fn newtype_field(input: &DeriveInput) -> Result<(Member, syn::Type), syn::Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Fitness can only be derived for structs with exactly one field",
+            ))
+        },
+    };
+    let mut iter = fields.iter();
+    match (iter.next(), iter.next()) {
+        (Some(field), None) => {
+            let member = match fields {
+                Fields::Named(_) => Member::Named(field.ident.clone().expect("named field")),
+                _ => Member::Unnamed(Index::from(0)),
+            };
+            Ok((member, field.ty.clone()))
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Fitness can only be derived for structs with exactly one field",
+        )),
+    }
+}
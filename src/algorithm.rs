@@ -3,30 +3,118 @@
 //! operators as defined in the `operator` module.
 
 use crate::{
-    genetic::{Fitness, Genotype},
-    random::Prng,
+    genetic::{AsScalar, Fitness, GenomeDistance, Genotype},
+    random::{random_index, Prng, Rng, SliceRandom},
+    statistic::{average_pairwise_distance, FitnessStats},
 };
 use chrono::{DateTime, Local};
-use std::{error::Error, fmt::Debug, rc::Rc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Debug},
+    sync::Arc,
+};
 
 /// An `Algorithm` defines the steps to be processed in a
 /// `simulation::Simulation`. The `Simulation` uses an implementation of an
 /// `Algorithm` to perform one iteration of the evaluation stage.
 pub trait Algorithm {
-    type Output: Clone + Debug + PartialEq;
+    type Output: Clone + Debug;
     type Error: Error + Clone + Debug + PartialEq;
 
     fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error>;
 
     fn reset(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns true if the best solution of the given `output` is better than
+    /// the best solution of the `best` output found so far. The simulation
+    /// uses it to track when the best solution was last improved.
+    ///
+    /// The default implementation considers every output an improvement, as
+    /// the outputs of an arbitrary algorithm can not be compared.
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        let _ = (output, best);
+        true
+    }
+}
+
+/// A `StagedAlgorithm` can process an iteration in several stages, e.g. the
+/// evaluation, selection, breeding and reinsertion stages of a genetic
+/// algorithm. The `simulation::simulator::Simulator` uses it to advance a
+/// simulation in small time slices, see `Simulator::tick`.
+pub trait StagedAlgorithm: Algorithm {
+    /// Processes the next stage of the given iteration.
+    ///
+    /// Returns the output of the iteration if the stage completed the
+    /// iteration and `None` if further stages of the iteration remain to be
+    /// processed. The first stage of an iteration is processed after the
+    /// previous iteration has been completed.
+    fn next_stage(
+        &mut self,
+        iteration: u64,
+        rng: &mut Prng,
+    ) -> Result<Option<Self::Output>, Self::Error>;
+}
+
+/// A `Checkpointable` algorithm can save the progress it has made so far in
+/// a `Snapshot` and restore it later. The `simulation::simulator::Simulator`
+/// uses it to save `simulation::checkpoint::Checkpoint`s.
+///
+/// The snapshot holds the state that the algorithm changes from iteration
+/// to iteration, e.g. the current population, but not the configuration of
+/// the algorithm.
+///
+/// It is implemented by the `ga::GeneticAlgorithm`, the
+/// `ga::island::IslandModel` and the `map_elites::MapElites` algorithm.
+pub trait Checkpointable: Algorithm {
+    type Snapshot: Clone + Debug;
+
+    /// Returns a snapshot of the progress of this algorithm.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Restores the progress of this algorithm from the given snapshot.
+    fn restore(&mut self, snapshot: Self::Snapshot);
 }
 
+/// An `OptimizationResult` is the output of an `Algorithm` that searches for
+/// the best solution of an optimization problem, such as the genetic
+/// algorithm, simulated annealing or an evolution strategy.
+///
+/// Everything that only needs the best solution works for any algorithm
+/// whose output implements this trait, e.g. the
+/// `termination::limit::FitnessLimit`, the best solution reported by a
+/// `simulation::State` and the comparison of algorithms in the `compare`
+/// module. New algorithms get these features by implementing this trait for
+/// their output.
 pub trait OptimizationResult<G, F>
 where
     G: Genotype,
     F: Fitness,
 {
+    /// Returns the best solution of this result. Depending on the algorithm
+    /// it is the best solution of the current iteration, like for the
+    /// genetic algorithm, or the best solution found so far.
     fn best_solution(&self) -> &BestSolution<G, F>;
+
+    /// Returns the fitness of the best solution of this result.
+    fn best_fitness<'a>(&'a self) -> &'a F
+    where
+        G: 'a,
+    {
+        &self.best_solution().solution.fitness
+    }
+
+    /// Returns whether the best solution of this result is better than the
+    /// best solution of the `other` result. Algorithms can use it to
+    /// implement `Algorithm::is_improvement`.
+    fn improves_on(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.best_fitness() > other.best_fitness()
+    }
 }
 
 /// The `Evaluated` type marks an individual as evaluated. Mostly this means
@@ -37,6 +125,7 @@ where
 /// simulation with more sophisticated fitness calculations this can improve
 /// performance.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Evaluated<G, F>
 where
     G: Genotype,
@@ -53,6 +142,7 @@ where
 /// If the solution is finished this is the overall best solution found by the
 /// simulation.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BestSolution<G, F>
 where
     G: Genotype,
@@ -66,6 +156,136 @@ where
     pub solution: Evaluated<G, F>,
 }
 
+/// The `HallOfFame` keeps the best distinct solutions found during a whole
+/// run, up to a fixed capacity.
+///
+/// Unlike the `BestSolution` of a generation, a solution stays in the hall
+/// of fame until better solutions push it out, even if it has been lost from
+/// the population. The members are ordered from the highest to the lowest
+/// fitness. Members of equal fitness keep the order in which they entered.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HallOfFame<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    capacity: usize,
+    members: Vec<Evaluated<G, F>>,
+}
+
+impl<G, F> HallOfFame<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Constructs a new empty `HallOfFame` that keeps up to `capacity`
+    /// solutions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "the capacity must be at least 1");
+        HallOfFame {
+            capacity,
+            members: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of solutions this hall of fame keeps.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the members ordered from the highest to the lowest fitness.
+    pub fn members(&self) -> &[Evaluated<G, F>] {
+        &self.members
+    }
+
+    /// Returns the member with the highest fitness.
+    pub fn best(&self) -> Option<&Evaluated<G, F>> {
+        self.members.first()
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns whether there are no members yet.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Removes all members.
+    pub fn clear(&mut self) {
+        self.members.clear();
+    }
+
+    /// Returns the members ordered from the highest to the lowest fitness.
+    pub fn into_members(self) -> Vec<Evaluated<G, F>> {
+        self.members
+    }
+
+    /// Returns whether a solution of the given fitness would enter this hall
+    /// of fame, provided that it is not a member already.
+    fn admits(&self, fitness: &F) -> bool {
+        self.members.len() < self.capacity
+            || self
+                .members
+                .last()
+                .is_some_and(|worst| *fitness > worst.fitness)
+    }
+}
+
+impl<G, F> HallOfFame<G, F>
+where
+    G: Genotype + PartialEq,
+    F: Fitness,
+{
+    /// Adds the given solution if this hall of fame is not full yet or the
+    /// solution is better than the worst member, which is pushed out then.
+    /// A solution whose genome is a member already is not added again.
+    ///
+    /// Returns whether the solution has been added.
+    pub fn offer(&mut self, candidate: Evaluated<G, F>) -> bool {
+        if !self.admits(&candidate.fitness)
+            || self
+                .members
+                .iter()
+                .any(|member| member.genome == candidate.genome)
+        {
+            return false;
+        }
+        let position = self
+            .members
+            .iter()
+            .position(|member| member.fitness < candidate.fitness)
+            .unwrap_or(self.members.len());
+        self.members.insert(position, candidate);
+        self.members.truncate(self.capacity);
+        true
+    }
+
+    /// Offers all individuals of the given evaluated population.
+    pub fn update(&mut self, evaluated: &EvaluatedPopulation<G, F>) {
+        for (genome, fitness) in evaluated
+            .individuals()
+            .iter()
+            .zip(evaluated.fitness_values())
+        {
+            // checked first to clone only the genomes that may enter
+            if self.admits(fitness) {
+                self.offer(Evaluated {
+                    genome: genome.clone(),
+                    fitness: fitness.clone(),
+                });
+            }
+        }
+    }
+}
+
 /// The `EvaluatedPopulation` holds the results of the evaluation stage of
 /// the genetic algorithm. It is used to pass these values to the
 /// `operator::SelectionOp` to enable this operator to do its job.
@@ -80,12 +300,13 @@ where
 /// data structures. To be able to change the fields internally later when
 /// new optimization are found the fields are kept private.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EvaluatedPopulation<G, F>
 where
     G: Genotype,
     F: Fitness,
 {
-    individuals: Rc<Vec<G>>,
+    individuals: Arc<Vec<G>>,
     fitness_values: Vec<F>,
     highest_fitness: F,
     lowest_fitness: F,
@@ -99,7 +320,7 @@ where
 {
     /// Construct a new instance of the `EvaluatedPopulation` struct.
     pub fn new(
-        individuals: Rc<Vec<G>>,
+        individuals: Arc<Vec<G>>,
         fitness_values: Vec<F>,
         highest_fitness: F,
         lowest_fitness: F,
@@ -115,7 +336,7 @@ where
     }
 
     /// Returns the individuals of the population that has been evaluated.
-    pub fn individuals(&self) -> Rc<Vec<G>> {
+    pub fn individuals(&self) -> Arc<Vec<G>> {
         self.individuals.clone()
     }
 
@@ -149,18 +370,115 @@ where
         &self.average_fitness
     }
 
+    /// Returns the minimum, maximum, mean and standard deviation of the
+    /// fitness values of the population calculated in a single pass.
+    pub fn fitness_stats(&self) -> FitnessStats
+    where
+        F: AsScalar + Sync,
+    {
+        FitnessStats::of(&self.fitness_values)
+    }
+
+    /// Returns the genotypic diversity of the population, i.e. the average
+    /// distance between all pairs of individuals. The calculation takes
+    /// quadratic time in the size of the population.
+    pub fn genotypic_diversity(&self) -> f64
+    where
+        G: GenomeDistance,
+    {
+        average_pairwise_distance(&self.individuals, G::distance)
+    }
+
+    /// Returns the phenotypic diversity of the population, i.e. the standard
+    /// deviation of the fitness values.
+    pub fn phenotypic_diversity(&self) -> f64
+    where
+        F: AsScalar + Sync,
+    {
+        self.fitness_stats().std_dev()
+    }
+
     /// Returns the individual at the given index.
     pub fn individual(&self, index: usize) -> Option<&G> {
         self.individuals.get(index)
     }
 
-    /// Returns the `genetic::Fitness` value of the given individual.
+    /// Returns the index of the individual with the highest fitness value.
+    /// If several individuals have the highest fitness value, one of them is
+    /// chosen by the given `TieBreaking`.
+    pub fn index_of_best<R>(&self, tie_breaking: &TieBreaking<G>, rng: &mut R) -> Option<usize>
+    where
+        R: Rng + Sized,
+    {
+        let ties: Vec<usize> = self
+            .fitness_values
+            .iter()
+            .enumerate()
+            .filter(|(_, fitness)| **fitness == self.highest_fitness)
+            .map(|(index, _)| index)
+            .collect();
+        let genomes: Vec<&G> = ties.iter().map(|index| &self.individuals[*index]).collect();
+        tie_breaking
+            .order(&genomes, rng)
+            .first()
+            .map(|position| ties[*position])
+    }
+
+    /// Returns the indices of the `k` individuals with the highest fitness
+    /// values, ordered from the best to the worst. Individuals of equal
+    /// fitness keep the order of the population, so the result is the same
+    /// as the first `k` indices of a stable sort of the whole population.
     ///
-    /// Note: This function might be more expensive due to the data structure
-    /// chosen for this struct. So use it sparingly.
-    pub fn fitness_of_individual(&self, individual: &G) -> Option<&F> {
-        self.index_of_individual(individual)
-            .map(|index| &self.fitness_values[index])
+    /// The individuals are partitioned by quickselect before only the `k`
+    /// best are sorted, which takes `O(n + k log k)` time for a population
+    /// of size `n`. If `k` is greater than the size of the population the
+    /// indices of all individuals are returned.
+    pub fn top_k_indices(&self, k: usize) -> Vec<usize> {
+        let fitness_values = &self.fitness_values;
+        let k = k.min(fitness_values.len());
+        let mut indices: Vec<usize> = (0..fitness_values.len()).collect();
+        // descending by fitness and ascending by index is a total order,
+        // which makes the unstable selection deterministic
+        let best_first = |x: &usize, y: &usize| {
+            fitness_values[*y]
+                .cmp(&fitness_values[*x])
+                .then_with(|| x.cmp(y))
+        };
+        if k > 0 && k < indices.len() {
+            indices.select_nth_unstable_by(k - 1, best_first);
+        }
+        indices.truncate(k);
+        indices.sort_unstable_by(best_first);
+        indices
+    }
+
+    /// Returns the indices of `k` distinct individuals chosen uniformly at
+    /// random, in random order.
+    ///
+    /// The indices are drawn by Floyd's algorithm, which takes `O(k)` time
+    /// and memory independent of the size of the population. If `k` is
+    /// greater than the size of the population the indices of all
+    /// individuals are returned.
+    pub fn sample_indices<R>(&self, rng: &mut R, k: usize) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let length = self.fitness_values.len();
+        let k = k.min(length);
+        let mut chosen = HashSet::with_capacity(k);
+        let mut sample = Vec::with_capacity(k);
+        for upper in length - k..length {
+            let index = random_index(rng, upper + 1);
+            let index = if chosen.insert(index) {
+                index
+            } else {
+                chosen.insert(upper);
+                upper
+            };
+            sample.push(index);
+        }
+        sample.shuffle(rng);
+        sample
     }
 
     /// Returns the `genetic::Genotype` of the individual with a given
@@ -179,19 +497,115 @@ where
     /// Note: This function might be more expensive due to the data structure
     /// chosen for this struct. So use it sparingly.
     pub fn evaluated_individual_with_fitness(&self, fitness: &F) -> Option<Evaluated<G, F>> {
-        self.index_of_fitness(&fitness).map(|index| Evaluated {
+        self.index_of_fitness(fitness).map(|index| Evaluated {
             genome: self.individuals[index].clone(),
             fitness: self.fitness_values[index].clone(),
         })
     }
 
-    /// Determines the index in the `individuals` slice of an individual.
-    fn index_of_individual(&self, individual: &G) -> Option<usize> {
-        self.individuals.iter().position(|v| *v == *individual)
-    }
-
     /// Determines the index in the `fitness_values` slice of a fitness value.
     fn index_of_fitness(&self, fitness: &F) -> Option<usize> {
         self.fitness_values.iter().position(|v| *v == *fitness)
     }
 }
+
+/// The `TieBreaking` defines which individual is preferred among individuals
+/// of equal fitness, e.g. when the best solution of a generation is
+/// determined or when the `reinsertion::elitist::ElitistReinserter` picks
+/// the best individuals.
+#[derive(Default)]
+pub enum TieBreaking<G> {
+    /// Prefers the individual that comes first. This is the default.
+    #[default]
+    First,
+    /// Chooses among the individuals uniformly at random.
+    Random,
+    /// Prefers individuals from the offspring over individuals of the old
+    /// population. Where the origin of the individuals is not known, e.g.
+    /// when the best solution of a generation is determined, the individual
+    /// that comes first is preferred, as all reinserters of this crate place
+    /// the individuals from the offspring first into the new population.
+    PreferOffspring,
+    /// Prefers the individual with the greatest sum of distances to the
+    /// other individuals of equal fitness, where the distance between two
+    /// genomes is calculated by the given function.
+    MostDiverse(fn(&G, &G) -> f64),
+}
+
+impl<G> TieBreaking<G> {
+    /// Returns the positions of the given genomes of equal fitness in the
+    /// order of preference, i.e. the position of the preferred genome first.
+    pub fn order<R>(&self, genomes: &[&G], rng: &mut R) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let mut positions: Vec<usize> = (0..genomes.len()).collect();
+        match self {
+            TieBreaking::First | TieBreaking::PreferOffspring => {},
+            TieBreaking::Random => positions.shuffle(rng),
+            TieBreaking::MostDiverse(distance) => {
+                let diversity: Vec<f64> = genomes
+                    .iter()
+                    .map(|genome| genomes.iter().map(|other| distance(genome, other)).sum())
+                    .collect();
+                // the sort is stable, so the first one wins among equally diverse
+                positions.sort_by(|a, b| {
+                    diversity[*b]
+                        .partial_cmp(&diversity[*a])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            },
+        }
+        positions
+    }
+}
+
+impl<G> Clone for TieBreaking<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for TieBreaking<G> {}
+
+impl<G> Debug for TieBreaking<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TieBreaking::First => f.write_str("First"),
+            TieBreaking::Random => f.write_str("Random"),
+            TieBreaking::PreferOffspring => f.write_str("PreferOffspring"),
+            TieBreaking::MostDiverse(_) => f.write_str("MostDiverse"),
+        }
+    }
+}
+
+/// Two `TieBreaking`s are equal if they are the same variant. The distance
+/// functions of `MostDiverse` are not compared.
+impl<G> PartialEq for TieBreaking<G> {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// Helpers of the `EvaluatedPopulation` that need to compare individuals.
+/// They are only available for `genetic::Genotype`s that implement
+/// `PartialEq`.
+impl<G, F> EvaluatedPopulation<G, F>
+where
+    G: Genotype + PartialEq,
+    F: Fitness,
+{
+    /// Returns the `genetic::Fitness` value of the given individual.
+    ///
+    /// Note: This function might be more expensive due to the data structure
+    /// chosen for this struct. So use it sparingly.
+    pub fn fitness_of_individual(&self, individual: &G) -> Option<&F> {
+        self.index_of_individual(individual)
+            .map(|index| &self.fitness_values[index])
+    }
+
+    /// Determines the index in the `individuals` slice of an individual.
+    fn index_of_individual(&self, individual: &G) -> Option<usize> {
+        self.individuals.iter().position(|v| *v == *individual)
+    }
+}
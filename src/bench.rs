@@ -0,0 +1,246 @@
+//! The `bench` module provides functions to measure the throughput of a
+//! `ga::GeneticAlgorithm` on the current hardware.
+//!
+//! The throughput is measured in generations per second and in fitness
+//! evaluations per second for different numbers of threads. The result is a
+//! `ScalingReport` that shows how well a configuration scales with the number
+//! of threads, e.g. to size the hardware for a genetic algorithm workload.
+//!
+//! This module is only available with the crate feature `bench` and not on
+//! wasm targets.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{bench::measure_scaling, ga::presets, prelude::*};
+//!
+//! #[derive(Clone, Debug)]
+//! struct CountOnes;
+//!
+//! impl FitnessFunction<Vec<bool>, usize> for CountOnes {
+//!     fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+//!         genome.iter().filter(|gene| **gene).count()
+//!     }
+//!
+//!     fn average(&self, values: &[usize]) -> usize {
+//!         values.iter().sum::<usize>() / values.len()
+//!     }
+//!
+//!     fn highest_possible_fitness(&self) -> usize {
+//!         64
+//!     }
+//!
+//!     fn lowest_possible_fitness(&self) -> usize {
+//!         0
+//!     }
+//! }
+//!
+//! let build_algorithm = || presets::binary(64).with_evaluation(CountOnes).build();
+//!
+//! let report = measure_scaling(build_algorithm, 10, &[1, 2]).unwrap();
+//!
+//! for throughput in report.measurements() {
+//!     println!(
+//!         "{} threads: {:.1} generations/s",
+//!         throughput.num_threads, throughput.generations_per_second
+//!     );
+//! }
+//! ```
+
+use crate::{
+    algorithm::Algorithm,
+    ga::{GeneticAlgorithm, GeneticAlgorithmError},
+    genetic::{Fitness, FitnessFunction, Genotype},
+    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
+    random::{get_rng, Seed},
+};
+use chrono::{Duration, Local};
+use std::fmt::{self, Display};
+
+/// The seed used to run the measurements, so that each measurement processes
+/// the same generations.
+const BENCH_SEED: Seed = Seed::from_bytes([42; 32]);
+
+/// The `Throughput` of a genetic algorithm measured with a certain number of
+/// threads.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Throughput {
+    /// The number of threads used for the measurement.
+    pub num_threads: usize,
+    /// The number of generations processed.
+    pub generations: u64,
+    /// The number of fitness evaluations of the populations processed.
+    pub evaluations: u64,
+    /// The wall-clock time it took to process the generations.
+    pub duration: Duration,
+    /// The number of generations processed per second.
+    pub generations_per_second: f64,
+    /// The number of fitness evaluations processed per second.
+    pub evaluations_per_second: f64,
+}
+
+/// The `ScalingReport` holds the `Throughput` of a genetic algorithm for
+/// each number of threads it has been measured with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScalingReport {
+    measurements: Vec<Throughput>,
+}
+
+impl ScalingReport {
+    /// Returns the measurements in the order of the given thread counts.
+    pub fn measurements(&self) -> &[Throughput] {
+        &self.measurements
+    }
+
+    /// Returns the measurement for the given number of threads.
+    pub fn throughput(&self, num_threads: usize) -> Option<&Throughput> {
+        self.measurements
+            .iter()
+            .find(|throughput| throughput.num_threads == num_threads)
+    }
+
+    /// Returns the speedup of the measurement with the given number of
+    /// threads relative to the first measurement, i.e. the ratio of their
+    /// generations per second.
+    pub fn speedup(&self, num_threads: usize) -> Option<f64> {
+        let baseline = self.measurements.first()?;
+        let throughput = self.throughput(num_threads)?;
+        if baseline.generations_per_second > 0. {
+            Some(throughput.generations_per_second / baseline.generations_per_second)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for ScalingReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>8} {:>14} {:>16} {:>8}",
+            "threads", "generations/s", "evaluations/s", "speedup"
+        )?;
+        for throughput in &self.measurements {
+            writeln!(
+                f,
+                "{:>8} {:>14.2} {:>16.2} {:>8.2}",
+                throughput.num_threads,
+                throughput.generations_per_second,
+                throughput.evaluations_per_second,
+                self.speedup(throughput.num_threads).unwrap_or(0.)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that can occur while measuring the throughput.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BenchError {
+    /// The thread pool for the given number of threads can not be built.
+    ThreadPool(String),
+    /// The genetic algorithm failed to process a generation.
+    Algorithm(GeneticAlgorithmError),
+}
+
+impl Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BenchError::ThreadPool(details) => write!(f, "{}", details),
+            BenchError::Algorithm(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+impl From<GeneticAlgorithmError> for BenchError {
+    fn from(error: GeneticAlgorithmError) -> Self {
+        BenchError::Algorithm(error)
+    }
+}
+
+/// Measures the throughput of a genetic algorithm for each of the given
+/// numbers of threads.
+///
+/// For each number of threads the algorithm is built by the given function
+/// on a thread pool of that size and processes the given number of
+/// generations. Every measurement processes the generations with the same
+/// seed, so if the function always builds the same algorithm, e.g. with a
+/// seeded initial population, the measurements only differ in the number of
+/// threads.
+pub fn measure_scaling<B, G, F, E, S, C, M, R>(
+    build_algorithm: B,
+    generations: u64,
+    thread_counts: &[usize],
+) -> Result<ScalingReport, BenchError>
+where
+    B: Fn() -> GeneticAlgorithm<G, F, E, S, C, M, R> + Sync,
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    let mut measurements = Vec::with_capacity(thread_counts.len());
+    for &num_threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|error| {
+                BenchError::ThreadPool(format!(
+                    "Thread pool of {} threads can not be built: {}",
+                    num_threads, error
+                ))
+            })?;
+        let throughput =
+            pool.install(|| measure(&mut build_algorithm(), generations, num_threads))?;
+        measurements.push(throughput);
+    }
+    Ok(ScalingReport { measurements })
+}
+
+fn measure<G, F, E, S, C, M, R>(
+    algorithm: &mut GeneticAlgorithm<G, F, E, S, C, M, R>,
+    generations: u64,
+    num_threads: usize,
+) -> Result<Throughput, GeneticAlgorithmError>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    let mut rng = get_rng(BENCH_SEED);
+    let mut evaluations = 0;
+    let started_at = Local::now();
+    for iteration in 1..=generations {
+        let state = algorithm.next(iteration, &mut rng)?;
+        evaluations += state.fitness_evaluations as u64;
+    }
+    let duration = Local::now().signed_duration_since(started_at);
+    let seconds = duration
+        .num_microseconds()
+        .map_or(f64::MAX, |micros| micros as f64 / 1_000_000.);
+    let per_second = |count: u64| {
+        if seconds > 0. {
+            count as f64 / seconds
+        } else {
+            0.
+        }
+    };
+    Ok(Throughput {
+        num_threads,
+        generations,
+        evaluations,
+        duration,
+        generations_per_second: per_second(generations),
+        evaluations_per_second: per_second(evaluations),
+    })
+}
@@ -0,0 +1,209 @@
+//! The `cache` module provides caches that help to avoid repeating expensive
+//! computations on the same `genetic::Genotype` several times.
+//!
+//! The provided caches are:
+//! * `LruCache` - a generic key value cache with a limited number of entries
+//!   that evicts the least recently used entry when it is full.
+//! * `DecodeCache` - a cache for decoded `genetic::Genotype`s, e.g. for
+//!   `genetic::Phenotype`s, that can be shared between the fitness evaluation
+//!   and the reporting of results.
+
+use crate::genetic::Genotype;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The `LruCache` stores key value pairs up to a maximum number of entries.
+/// When the cache is full and a new entry is inserted the entry that has not
+/// been accessed for the longest time is evicted (least recently used).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LruCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// The maximum number of entries in this cache.
+    capacity: usize,
+    /// The entries together with the tick of their last access.
+    entries: HashMap<K, (V, u64)>,
+    /// The keys ordered by the tick of their last access.
+    recently_used: BTreeMap<u64, K>,
+    /// A counter that is increased on every access.
+    tick: u64,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    /// Constructs a new `LruCache` that can hold up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            recently_used: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the maximum number of entries of this cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries currently stored in this cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether this cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value stored for the given key and marks the entry as
+    /// recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let tick = self.tick;
+        match self.entries.get_mut(key) {
+            Some((value, last_used)) => {
+                self.recently_used.remove(last_used);
+                self.recently_used.insert(tick, key.clone());
+                *last_used = tick;
+                Some(value)
+            },
+            None => None,
+        }
+    }
+
+    /// Inserts the given value for the given key. If the cache is full the
+    /// least recently used entry is evicted.
+    ///
+    /// Returns the previous value for the given key, if there was one.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.capacity == 0 {
+            return None;
+        }
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((previous, last_used)) = self.entries.remove(&key) {
+            self.recently_used.remove(&last_used);
+            self.recently_used.insert(tick, key.clone());
+            self.entries.insert(key, (value, tick));
+            return Some(previous);
+        }
+        if self.entries.len() >= self.capacity {
+            let least_recently_used = self.recently_used.keys().next().cloned();
+            if let Some(oldest) = least_recently_used {
+                if let Some(evicted) = self.recently_used.remove(&oldest) {
+                    self.entries.remove(&evicted);
+                }
+            }
+        }
+        self.recently_used.insert(tick, key.clone());
+        self.entries.insert(key, (value, tick));
+        None
+    }
+
+    /// Removes all entries from this cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recently_used.clear();
+    }
+}
+
+/// The `DecodeCache` caches the result of decoding a `genetic::Genotype`,
+/// e.g. building a `genetic::Phenotype` such as a schedule object from its
+/// genes. So expensive decodings only run once per unique genome.
+///
+/// The entries are keyed by the hash of the genome. The genome is stored
+/// alongside the decoded value, so hash collisions never return the decoded
+/// value of another genome.
+///
+/// Clones of a `DecodeCache` share the same entries. Thus one instance can be
+/// handed to the `genetic::FitnessFunction` and another one to the code that
+/// reports the results. To restrict the cache to the genomes of one generation
+/// call `clear` at the start of each generation.
+#[derive(Clone, Debug)]
+pub struct DecodeCache<G, P>
+where
+    G: Genotype + Hash + PartialEq,
+    P: Clone,
+{
+    entries: Arc<Mutex<LruCache<u64, (G, P)>>>,
+}
+
+impl<G, P> DecodeCache<G, P>
+where
+    G: Genotype + Hash + PartialEq,
+    P: Clone,
+{
+    /// Constructs a new `DecodeCache` that holds up to `capacity` decoded
+    /// genomes.
+    pub fn new(capacity: usize) -> Self {
+        DecodeCache {
+            entries: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+
+    /// Returns the decoded value of the given genome. The `decoder` is only
+    /// called if the genome is not found in the cache.
+    pub fn decode<D>(&self, genome: &G, decoder: D) -> P
+    where
+        D: FnOnce(&G) -> P,
+    {
+        let key = genome_hash(genome);
+        if let Some((cached_genome, decoded)) = self.lock().get(&key) {
+            if cached_genome == genome {
+                return decoded.clone();
+            }
+        }
+        // decode without holding the lock, so other threads are not blocked
+        let decoded = decoder(genome);
+        self.lock().insert(key, (genome.clone(), decoded.clone()));
+        decoded
+    }
+
+    /// Returns the number of decoded genomes currently stored in the cache.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the maximum number of decoded genomes stored in the cache.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity()
+    }
+
+    /// Removes all decoded genomes from the cache.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LruCache<u64, (G, P)>> {
+        // a poisoned cache is still consistent, as entries are only inserted
+        // as a whole
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Calculates the hash of a `genetic::Genotype`.
+pub fn genome_hash<G>(genome: &G) -> u64
+where
+    G: Hash,
+{
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    genome.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,79 @@
+use super::*;
+use galvanic_assert::matchers::*;
+
+mod lru_cache {
+
+    use super::*;
+
+    #[test]
+    fn evicts_the_least_recently_used_entry_when_full() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "one");
+        cache.insert(2, "two");
+        // access 1 so that 2 becomes the least recently used entry
+        cache.get(&1);
+        cache.insert(3, "three");
+
+        expect_that!(&cache.len(), eq(2));
+        expect_that!(&cache.get(&1), eq(Some(&"one")));
+        expect_that!(&cache.get(&2), eq(None));
+        expect_that!(&cache.get(&3), eq(Some(&"three")));
+    }
+
+    #[test]
+    fn insert_of_existing_key_replaces_the_value() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1, "one");
+
+        let previous = cache.insert(1, "uno");
+
+        expect_that!(&previous, eq(Some("one")));
+        expect_that!(&cache.len(), eq(1));
+        expect_that!(&cache.get(&1), eq(Some(&"uno")));
+    }
+
+    #[test]
+    fn cache_with_capacity_0_stores_nothing() {
+        let mut cache = LruCache::new(0);
+        cache.insert(1, "one");
+
+        expect_that!(&cache.is_empty(), eq(true));
+    }
+}
+
+mod decode_cache {
+
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn decodes_each_genome_only_once() {
+        let cache: DecodeCache<Vec<u8>, String> = DecodeCache::new(10);
+        let calls = Cell::new(0);
+        let decoder = |genome: &Vec<u8>| {
+            calls.set(calls.get() + 1);
+            String::from_utf8(genome.clone()).unwrap()
+        };
+
+        let first = cache.decode(&b"genevo".to_vec(), decoder);
+        let second = cache.decode(&b"genevo".to_vec(), decoder);
+        let third = cache.decode(&b"other".to_vec(), decoder);
+
+        expect_that!(&first, eq("genevo".to_string()));
+        expect_that!(&second, eq("genevo".to_string()));
+        expect_that!(&third, eq("other".to_string()));
+        expect_that!(&calls.get(), eq(2));
+    }
+
+    #[test]
+    fn clones_share_the_decoded_values() {
+        let cache: DecodeCache<Vec<u8>, usize> = DecodeCache::new(10);
+        let shared = cache.clone();
+
+        cache.decode(&vec![1, 2, 3], |genome| genome.len());
+
+        expect_that!(&shared.len(), eq(1));
+        shared.clear();
+        expect_that!(&cache.is_empty(), eq(true));
+    }
+}
@@ -0,0 +1,524 @@
+//! The `compare` module provides a harness to compare the convergence of
+//! different configurations of an algorithm, e.g. different stacks of
+//! operators, on the same problem.
+//!
+//! Each candidate configuration is run once for each of the given seeds.
+//! A run stops as soon as a solution with the target fitness has been found
+//! or the maximum number of generations has been processed. The result is a
+//! `ComparisonReport` with a `CandidateSummary` for each candidate ranked by
+//! the success rate and the median number of generations needed to reach the
+//! target.
+//!
+//! Different operator types result in different algorithm types. Therefore
+//! each candidate is given as a function that builds the algorithm. A grid
+//! of operator configurations of the same types can be added at once by
+//! `Comparison::with_grid`.
+//!
+//! Whether one candidate finds better solutions than another one or was just
+//! lucky with the seeds can be decided by `ComparisonReport::significance`,
+//! which applies the Mann-Whitney U test to the best fitness values of the
+//! runs of both candidates.
+
+use crate::{
+    algorithm::{Algorithm, OptimizationResult},
+    genetic::{AsScalar, Fitness, Genotype},
+    random::{get_rng, Seed},
+};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+};
+
+/// Creates a new `Comparison` of candidates that try to reach the given
+/// target fitness in at most `max_generations` generations.
+pub fn compare<G, F>(target_fitness: F, max_generations: u64) -> Comparison<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    Comparison::new(target_fitness, max_generations)
+}
+
+type RunFn<F> = Box<dyn Fn(Seed) -> Result<RunOutcome<F>, String>>;
+
+struct Candidate<F>
+where
+    F: Fitness,
+{
+    name: String,
+    run: RunFn<F>,
+}
+
+/// A `Comparison` holds the candidates to be compared and the settings of
+/// the runs.
+pub struct Comparison<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    _g: PhantomData<G>,
+    target_fitness: F,
+    max_generations: u64,
+    seeds: Vec<Seed>,
+    candidates: Vec<Candidate<F>>,
+}
+
+impl<G, F> Debug for Comparison<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Comparison")
+            .field("target_fitness", &self.target_fitness)
+            .field("max_generations", &self.max_generations)
+            .field("seeds", &self.seeds)
+            .field(
+                "candidates",
+                &self.candidates.iter().map(|c| &c.name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<G, F> Comparison<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Constructs a new `Comparison` without candidates. By default each
+    /// candidate is run with a single seed.
+    pub fn new(target_fitness: F, max_generations: u64) -> Self {
+        Comparison {
+            _g: PhantomData,
+            target_fitness,
+            max_generations,
+            seeds: vec![Seed::default()],
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Sets the seeds each candidate is run with.
+    pub fn with_seeds<S>(mut self, seeds: Vec<S>) -> Self
+    where
+        S: Into<Seed>,
+    {
+        self.seeds = seeds.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a candidate with the given name. The `build` function is called
+    /// to create a fresh instance of the algorithm for each run.
+    pub fn with_candidate<A, B>(mut self, name: &str, build: B) -> Self
+    where
+        A: Algorithm,
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+        B: Fn() -> A + 'static,
+        F: 'static,
+    {
+        let target_fitness = self.target_fitness.clone();
+        let max_generations = self.max_generations;
+        self.candidates.push(Candidate {
+            name: name.to_string(),
+            run: Box::new(move |seed| {
+                run_candidate(build(), seed, &target_fitness, max_generations)
+            }),
+        });
+        self
+    }
+
+    /// Adds a candidate for each combination of the given selectors,
+    /// crossovers and mutators. The name of each candidate is made up of the
+    /// names of the operators. The `build` function creates the algorithm
+    /// from one combination of operators.
+    pub fn with_grid<S, C, M, A, B>(
+        mut self,
+        selectors: Vec<(&str, S)>,
+        crossovers: Vec<(&str, C)>,
+        mutators: Vec<(&str, M)>,
+        build: B,
+    ) -> Self
+    where
+        S: Clone + 'static,
+        C: Clone + 'static,
+        M: Clone + 'static,
+        A: Algorithm,
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+        B: Fn(S, C, M) -> A + Clone + 'static,
+        F: 'static,
+    {
+        for (selector_name, selector) in &selectors {
+            for (crossover_name, crossover) in &crossovers {
+                for (mutator_name, mutator) in &mutators {
+                    let name = format!("{} / {} / {}", selector_name, crossover_name, mutator_name);
+                    let (selector, crossover, mutator) =
+                        (selector.clone(), crossover.clone(), mutator.clone());
+                    let build = build.clone();
+                    self = self.with_candidate(&name, move || {
+                        build(selector.clone(), crossover.clone(), mutator.clone())
+                    });
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the names of the candidates in the order they have been added.
+    pub fn candidate_names(&self) -> Vec<&str> {
+        self.candidates.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Runs all candidates with all seeds and returns the ranked summary.
+    pub fn run(&self) -> Result<ComparisonReport<F>, ComparisonError> {
+        let mut summaries = Vec::with_capacity(self.candidates.len());
+        for candidate in &self.candidates {
+            let mut runs = Vec::with_capacity(self.seeds.len());
+            for seed in &self.seeds {
+                let outcome = (candidate.run)(*seed).map_err(|error| {
+                    ComparisonError::RunFailed(format!(
+                        "candidate {} failed with seed {:?}: {}",
+                        candidate.name, seed, error
+                    ))
+                })?;
+                runs.push(outcome);
+            }
+            summaries.push(CandidateSummary::new(candidate.name.clone(), runs));
+        }
+        summaries.sort_by(CandidateSummary::rank);
+        Ok(ComparisonReport { summaries })
+    }
+}
+
+/// Runs one candidate with one seed.
+fn run_candidate<A, G, F>(
+    mut algorithm: A,
+    seed: Seed,
+    target_fitness: &F,
+    max_generations: u64,
+) -> Result<RunOutcome<F>, String>
+where
+    A: Algorithm,
+    <A as Algorithm>::Output: OptimizationResult<G, F>,
+    G: Genotype,
+    F: Fitness,
+{
+    let mut rng = get_rng(seed);
+    let mut best_fitness: Option<F> = None;
+    for generation in 1..=max_generations {
+        let output = algorithm
+            .next(generation, &mut rng)
+            .map_err(|error| error.to_string())?;
+        let fitness = &output.best_solution().solution.fitness;
+        if best_fitness.as_ref().is_none_or(|best| fitness > best) {
+            best_fitness = Some(fitness.clone());
+        }
+        if fitness >= target_fitness {
+            return Ok(RunOutcome {
+                seed,
+                generations: generation,
+                reached_target: true,
+                best_fitness: fitness.clone(),
+            });
+        }
+    }
+    Ok(RunOutcome {
+        seed,
+        generations: max_generations,
+        reached_target: false,
+        best_fitness: best_fitness.ok_or_else(|| "no generation has been processed".to_string())?,
+    })
+}
+
+/// The outcome of running one candidate with one seed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunOutcome<F>
+where
+    F: Fitness,
+{
+    /// The seed the run has been started with.
+    pub seed: Seed,
+    /// The number of generations processed until the run stopped.
+    pub generations: u64,
+    /// Whether a solution with the target fitness has been found.
+    pub reached_target: bool,
+    /// The best fitness value found in this run.
+    pub best_fitness: F,
+}
+
+/// The summary of all runs of one candidate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CandidateSummary<F>
+where
+    F: Fitness,
+{
+    /// The name of the candidate.
+    pub name: String,
+    /// The fraction of runs that reached the target fitness.
+    pub success_rate: f64,
+    /// The median number of generations needed to reach the target fitness
+    /// by the successful runs. `None` if no run has been successful.
+    pub median_generations_to_target: Option<u64>,
+    /// The best fitness value found by any of the runs.
+    pub best_fitness: F,
+    /// The outcome of each run.
+    pub runs: Vec<RunOutcome<F>>,
+}
+
+impl<F> CandidateSummary<F>
+where
+    F: Fitness,
+{
+    fn new(name: String, runs: Vec<RunOutcome<F>>) -> Self {
+        let mut generations: Vec<u64> = runs
+            .iter()
+            .filter(|run| run.reached_target)
+            .map(|run| run.generations)
+            .collect();
+        generations.sort_unstable();
+        let success_rate = generations.len() as f64 / runs.len() as f64;
+        let median_generations_to_target = median(&generations);
+        let best_fitness = runs
+            .iter()
+            .map(|run| &run.best_fitness)
+            .max()
+            .cloned()
+            .unwrap_or_else(F::zero);
+        CandidateSummary {
+            name,
+            success_rate,
+            median_generations_to_target,
+            best_fitness,
+            runs,
+        }
+    }
+
+    /// Orders better candidates first: a higher success rate wins, then
+    /// fewer median generations to the target, then the better fitness.
+    fn rank(&self, other: &Self) -> Ordering {
+        other
+            .success_rate
+            .partial_cmp(&self.success_rate)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                match (
+                    self.median_generations_to_target,
+                    other.median_generations_to_target,
+                ) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            })
+            .then_with(|| other.best_fitness.cmp(&self.best_fitness))
+    }
+}
+
+/// Returns the median of the given sorted values.
+fn median(sorted: &[u64]) -> Option<u64> {
+    let len = sorted.len();
+    match len {
+        0 => None,
+        _ if len % 2 == 1 => Some(sorted[len / 2]),
+        _ => Some((sorted[len / 2 - 1] + sorted[len / 2]) / 2),
+    }
+}
+
+/// The ranked summary of a `Comparison`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparisonReport<F>
+where
+    F: Fitness,
+{
+    summaries: Vec<CandidateSummary<F>>,
+}
+
+impl<F> ComparisonReport<F>
+where
+    F: Fitness,
+{
+    /// Returns the summaries of all candidates, best ranked first.
+    pub fn summaries(&self) -> &[CandidateSummary<F>] {
+        &self.summaries
+    }
+
+    /// Returns the summary of the best ranked candidate.
+    pub fn winner(&self) -> Option<&CandidateSummary<F>> {
+        self.summaries.first()
+    }
+
+    /// Returns the summary of the candidate with the given name.
+    pub fn summary(&self, name: &str) -> Option<&CandidateSummary<F>> {
+        self.summaries.iter().find(|summary| summary.name == name)
+    }
+
+    /// Tests whether the best fitness values found by the runs of the two
+    /// given candidates differ significantly, using the Mann-Whitney U test.
+    ///
+    /// Returns `None` if one of the candidates is not in this report.
+    pub fn significance(&self, candidate1: &str, candidate2: &str) -> Option<Significance>
+    where
+        F: AsScalar,
+    {
+        let best_fitness_values = |summary: &CandidateSummary<F>| -> Vec<f64> {
+            summary
+                .runs
+                .iter()
+                .map(|run| run.best_fitness.as_scalar())
+                .collect()
+        };
+        let sample1 = best_fitness_values(self.summary(candidate1)?);
+        let sample2 = best_fitness_values(self.summary(candidate2)?);
+        Some(mann_whitney_u(&sample1, &sample2))
+    }
+}
+
+/// The result of a Mann-Whitney U test of two samples.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Significance {
+    /// The U statistic of the first sample, i.e. the number of pairs in
+    /// which the value of the first sample is greater than the value of the
+    /// second sample, where ties count one half.
+    pub u_statistic: f64,
+    /// The two-sided p-value of the test, i.e. the probability to observe a
+    /// difference at least as large if both samples come from the same
+    /// distribution. It is calculated with the normal approximation, which
+    /// is reasonably accurate for samples of about 8 or more values each.
+    pub p_value: f64,
+    /// The effect size as rank-biserial correlation in the range of -1 to 1.
+    /// A positive value means that the first sample tends to have greater
+    /// values, a negative value that the second sample does.
+    pub effect_size: f64,
+}
+
+impl Significance {
+    /// Returns whether the difference is significant at the given level,
+    /// e.g. 0.05.
+    pub fn is_significant(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+/// Applies the two-sided Mann-Whitney U test to the given samples.
+///
+/// If one of the samples is empty the test is not meaningful and the
+/// resulting p-value is 1.
+pub fn mann_whitney_u(sample1: &[f64], sample2: &[f64]) -> Significance {
+    let n1 = sample1.len() as f64;
+    let n2 = sample2.len() as f64;
+    if sample1.is_empty() || sample2.is_empty() {
+        return Significance {
+            u_statistic: 0.,
+            p_value: 1.,
+            effect_size: 0.,
+        };
+    }
+    let mut values: Vec<(f64, bool)> = sample1
+        .iter()
+        .map(|value| (*value, true))
+        .chain(sample2.iter().map(|value| (*value, false)))
+        .collect();
+    values.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    // assign average ranks to ties and accumulate the tie correction
+    let mut rank_sum1 = 0.;
+    let mut tie_correction = 0.;
+    let mut start = 0;
+    while start < values.len() {
+        let mut end = start + 1;
+        while end < values.len() && values[end].0 == values[start].0 {
+            end += 1;
+        }
+        let average_rank = (start + end + 1) as f64 / 2.;
+        rank_sum1 += values[start..end]
+            .iter()
+            .filter(|(_, first)| *first)
+            .count() as f64
+            * average_rank;
+        let ties = (end - start) as f64;
+        tie_correction += ties * ties * ties - ties;
+        start = end;
+    }
+    let u_statistic = rank_sum1 - n1 * (n1 + 1.) / 2.;
+    let n = n1 + n2;
+    let mean = n1 * n2 / 2.;
+    let variance = n1 * n2 / 12. * ((n + 1.) - tie_correction / (n * (n - 1.)));
+    let p_value = if variance > 0. {
+        // with continuity correction
+        let z = ((u_statistic - mean).abs() - 0.5).max(0.) / variance.sqrt();
+        (2. * (1. - standard_normal_cdf(z))).min(1.)
+    } else {
+        1.
+    };
+    Significance {
+        u_statistic,
+        p_value,
+        effect_size: 2. * u_statistic / (n1 * n2) - 1.,
+    }
+}
+
+/// Approximates the cumulative distribution function of the standard normal
+/// distribution (Abramowitz and Stegun, formula 7.1.26).
+fn standard_normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1. / (1. + 0.327_591_1 * x);
+    let polynomial = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1. - polynomial * (-x * x).exp();
+    if z >= 0. {
+        (1. + erf) / 2.
+    } else {
+        (1. - erf) / 2.
+    }
+}
+
+impl<F> Display for ComparisonReport<F>
+where
+    F: Fitness,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:<40} {:>8} {:>16}  best fitness",
+            "rank", "candidate", "success", "median gens"
+        )?;
+        for (rank, summary) in self.summaries.iter().enumerate() {
+            let median = summary
+                .median_generations_to_target
+                .map_or_else(|| "-".to_string(), |m| m.to_string());
+            writeln!(
+                f,
+                "{:>4}  {:<40} {:>7.1}% {:>16}  {:?}",
+                rank + 1,
+                summary.name,
+                summary.success_rate * 100.,
+                median,
+                summary.best_fitness
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// An error that can occur while running a `Comparison`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonError {
+    /// The algorithm of a candidate returned an error.
+    RunFailed(String),
+}
+
+impl Display for ComparisonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ComparisonError::RunFailed(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for ComparisonError {}
+
+#[cfg(test)]
+mod tests;
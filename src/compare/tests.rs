@@ -0,0 +1,200 @@
+use super::*;
+use crate::{
+    ga::genetic_algorithm,
+    genetic::FitnessFunction,
+    mutation::value::RandomValueMutator,
+    population::{build_population, Population, ValueEncodedGenomeBuilder},
+    recombination::discrete::MultiPointCrossBreeder,
+    reinsertion::elitist::ElitistReinserter,
+    selection::truncation::MaximizeSelector,
+};
+use galvanic_assert::matchers::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        400
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+fn initial_population() -> Population<Vec<i32>> {
+    build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(4, 0, 101))
+        .of_size(20)
+        .using_seed([3; 32])
+}
+
+fn outcome(generations: u64, reached_target: bool, best_fitness: i32) -> RunOutcome<i32> {
+    RunOutcome {
+        seed: Seed::default(),
+        generations,
+        reached_target,
+        best_fitness,
+    }
+}
+
+#[test]
+fn summary_median_only_counts_successful_runs() {
+    let summary = CandidateSummary::new(
+        "candidate".to_string(),
+        vec![
+            outcome(10, true, 400),
+            outcome(50, false, 390),
+            outcome(20, true, 400),
+            outcome(30, true, 400),
+        ],
+    );
+
+    expect_that!(&summary.success_rate, eq(0.75));
+    expect_that!(&summary.median_generations_to_target, eq(Some(20)));
+    expect_that!(&summary.best_fitness, eq(400));
+}
+
+#[test]
+fn summaries_are_ranked_by_success_rate_then_median_generations() {
+    let mut summaries = [
+        CandidateSummary::new("slow".to_string(), vec![outcome(40, true, 400)]),
+        CandidateSummary::new("failed".to_string(), vec![outcome(50, false, 399)]),
+        CandidateSummary::new("fast".to_string(), vec![outcome(10, true, 400)]),
+    ];
+
+    summaries.sort_by(CandidateSummary::rank);
+
+    let names: Vec<&str> = summaries.iter().map(|s| s.name.as_str()).collect();
+    expect_that!(&names, eq(vec!["fast", "slow", "failed"]));
+}
+
+#[test]
+fn compare_runs_every_candidate_of_the_grid_with_every_seed() {
+    let comparison = compare(360, 30)
+        .with_seeds(vec![[1; 32], [2; 32]])
+        .with_grid(
+            vec![("max 0.5", MaximizeSelector::new(0.5, 2))],
+            vec![
+                ("1-point", MultiPointCrossBreeder::new(1)),
+                ("2-point", MultiPointCrossBreeder::new(2)),
+            ],
+            vec![
+                ("low", RandomValueMutator::new(0.05, 0, 101)),
+                ("high", RandomValueMutator::new(0.3, 0, 101)),
+            ],
+            |selector, crossover, mutator| {
+                genetic_algorithm()
+                    .with_evaluation(SumOfValues)
+                    .with_selection(selector)
+                    .with_crossover(crossover)
+                    .with_mutation(mutator)
+                    .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.7))
+                    .with_initial_population(initial_population())
+                    .build()
+            },
+        );
+
+    expect_that!(&comparison.candidate_names().len(), eq(4));
+    expect_that!(
+        &comparison.candidate_names()[0],
+        eq("max 0.5 / 1-point / low")
+    );
+
+    let report = comparison.run().unwrap();
+
+    expect_that!(&report.summaries().len(), eq(4));
+    for summary in report.summaries() {
+        expect_that!(&summary.runs.len(), eq(2));
+    }
+    let winner = report.winner().unwrap();
+    for summary in report.summaries() {
+        expect_that!(
+            &winner.success_rate,
+            greater_than_or_equal(summary.success_rate)
+        );
+    }
+}
+
+#[test]
+fn mann_whitney_u_of_clearly_separated_samples_is_significant() {
+    let sample1 = [11., 12., 13., 14., 15., 16., 17., 18., 19., 20.];
+    let sample2 = [1., 2., 3., 4., 5., 6., 7., 8., 9., 10.];
+
+    let significance = mann_whitney_u(&sample1, &sample2);
+
+    expect_that!(&significance.u_statistic, eq(100.));
+    expect_that!(&significance.effect_size, eq(1.));
+    expect_that!(&significance.p_value, less_than(0.001));
+    expect_that!(&significance.is_significant(0.05), eq(true));
+}
+
+#[test]
+fn mann_whitney_u_of_interleaved_samples_is_not_significant() {
+    let sample1 = [1., 3., 5., 7., 9., 11., 13., 15.];
+    let sample2 = [2., 4., 6., 8., 10., 12., 14., 16.];
+
+    let significance = mann_whitney_u(&sample1, &sample2);
+
+    expect_that!(&significance.u_statistic, eq(28.));
+    expect_that!(&significance.effect_size, close_to(-0.125, 1e-9));
+    expect_that!(&significance.p_value, greater_than(0.5));
+    expect_that!(&significance.is_significant(0.05), eq(false));
+}
+
+#[test]
+fn mann_whitney_u_matches_the_reference_p_value_with_ties() {
+    // reference values of the normal approximation with tie and continuity
+    // correction
+    let sample1 = [3., 4., 4., 5., 6., 6., 7., 9.];
+    let sample2 = [1., 2., 2., 3., 4., 5., 5., 6.];
+
+    let significance = mann_whitney_u(&sample1, &sample2);
+
+    expect_that!(&significance.u_statistic, eq(49.5));
+    expect_that!(&significance.p_value, close_to(0.0712, 1e-3));
+}
+
+#[test]
+fn mann_whitney_u_of_identical_values_has_p_value_of_one() {
+    let significance = mann_whitney_u(&[5., 5., 5.], &[5., 5.]);
+
+    expect_that!(&significance.p_value, eq(1.));
+    expect_that!(&significance.effect_size, eq(0.));
+}
+
+#[test]
+fn significance_compares_the_best_fitness_of_the_runs_of_two_candidates() {
+    let build = |mutation_rate| {
+        move || {
+            genetic_algorithm()
+                .with_evaluation(SumOfValues)
+                .with_selection(MaximizeSelector::new(0.5, 2))
+                .with_crossover(MultiPointCrossBreeder::new(1))
+                .with_mutation(RandomValueMutator::new(mutation_rate, 0, 101))
+                .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.7))
+                .with_initial_population(initial_population())
+                .build()
+        }
+    };
+    let report = compare(400, 5)
+        .with_seeds((1..=10).map(|seed| [seed; 32]).collect())
+        .with_candidate("mutating", build(0.3))
+        .with_candidate("frozen", build(0.))
+        .run()
+        .unwrap();
+
+    let significance = report.significance("mutating", "frozen").unwrap();
+
+    expect_that!(&significance.effect_size, greater_than(0.));
+    expect_that!(&report.significance("mutating", "unknown"), eq(None));
+}
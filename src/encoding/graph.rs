@@ -0,0 +1,142 @@
+//! The `graph` module provides the `Graph` type, a graph encoded
+//! `genetic::Genotype` for network design problems.
+//!
+//! A `Graph` is an undirected simple graph, i.e. there are no loops and at
+//! most one edge between two nodes. The graph is stored as adjacency list,
+//! where the neighbors of each node are kept in ascending order.
+//!
+//! Random graphs can be built with the
+//! `population::GraphEncodedGenomeBuilder`. Operators for graphs are the
+//! `mutation::graph::EdgeSwapMutator` and the
+//! `recombination::graph::SubgraphCrossBreeder`.
+
+use super::GraphEncoded;
+use crate::genetic::Genotype;
+
+/// An edge of a `Graph` given by the indices of the two nodes it connects.
+pub type Edge = (usize, usize);
+
+/// The `Graph` is an undirected simple graph stored as adjacency list.
+///
+/// The nodes of a `Graph` are identified by their index, which ranges from
+/// `0` to `num_nodes - 1`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Constructs a new `Graph` with the given number of nodes and no edges.
+    pub fn new(num_nodes: usize) -> Self {
+        Graph {
+            adjacency: vec![Vec::new(); num_nodes],
+        }
+    }
+
+    /// Constructs a new `Graph` with the given number of nodes and the given
+    /// edges. Loops and duplicate edges are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an edge refers to a node that is not in the graph.
+    pub fn with_edges<I>(num_nodes: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = Edge>,
+    {
+        let mut graph = Graph::new(num_nodes);
+        for (node1, node2) in edges {
+            graph.add_edge(node1, node2);
+        }
+        graph
+    }
+
+    /// Returns the number of nodes of this graph.
+    pub fn num_nodes(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Returns the number of edges of this graph.
+    pub fn num_edges(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum::<usize>() / 2
+    }
+
+    /// Returns the neighbors of the given node in ascending order.
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    /// Returns the degree of the given node, i.e. the number of its
+    /// neighbors.
+    pub fn degree(&self, node: usize) -> usize {
+        self.adjacency[node].len()
+    }
+
+    /// Returns true if there is an edge between the given nodes.
+    pub fn has_edge(&self, node1: usize, node2: usize) -> bool {
+        self.adjacency[node1].binary_search(&node2).is_ok()
+    }
+
+    /// Adds an edge between the given nodes. Returns false if the edge is a
+    /// loop or is already in the graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the nodes is not in the graph.
+    pub fn add_edge(&mut self, node1: usize, node2: usize) -> bool {
+        assert!(
+            node1 < self.num_nodes() && node2 < self.num_nodes(),
+            "edge ({}, {}) refers to a node that is not in a graph of {} nodes",
+            node1,
+            node2,
+            self.num_nodes()
+        );
+        if node1 == node2 {
+            return false;
+        }
+        match self.adjacency[node1].binary_search(&node2) {
+            Ok(_) => false,
+            Err(position) => {
+                self.adjacency[node1].insert(position, node2);
+                let position = self.adjacency[node2].binary_search(&node1).unwrap_err();
+                self.adjacency[node2].insert(position, node1);
+                true
+            },
+        }
+    }
+
+    /// Removes the edge between the given nodes. Returns false if there is
+    /// no such edge.
+    pub fn remove_edge(&mut self, node1: usize, node2: usize) -> bool {
+        match self.adjacency[node1].binary_search(&node2) {
+            Ok(position) => {
+                self.adjacency[node1].remove(position);
+                let position = self.adjacency[node2].binary_search(&node1).unwrap();
+                self.adjacency[node2].remove(position);
+                true
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Returns an iterator over all edges of this graph. Each edge is
+    /// returned once with the smaller node index first.
+    pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(node, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |neighbor| **neighbor > node)
+                    .map(move |neighbor| (node, *neighbor))
+            })
+    }
+}
+
+/// Implementation of a genotype using `Graph`.
+impl Genotype for Graph {
+    type Dna = Edge;
+}
+
+/// Implementation of a graph encoded `genetic::Genotype` using `Graph`.
+impl GraphEncoded for Graph {}
@@ -0,0 +1,192 @@
+//! The `matrix` module provides the `Matrix` type, a 2D value encoded
+//! `genetic::Genotype` for schedules such as timetables or rosters.
+//!
+//! A `Matrix` holds `rows` x `cols` categorical values, e.g. the rows are
+//! the rooms or the employees and the columns are the time slots, where each
+//! value is the assigned course or shift. The `Matrix` provides helpers to
+//! count typical constraint violations, which can be used to calculate the
+//! fitness of a schedule.
+//!
+//! Random matrices can be built with the
+//! `population::MatrixEncodedGenomeBuilder`. Operators for matrices are the
+//! `mutation::matrix::MatrixSwapMutator` and the
+//! `recombination::matrix::MatrixCrossBreeder`.
+
+use super::ValueEncoded;
+use crate::genetic::Genotype;
+use std::{collections::HashSet, fmt::Debug, hash::Hash};
+
+/// The `Axis` of a `Matrix` along which an operator works.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The operator works on whole rows or within a row.
+    Rows,
+    /// The operator works on whole columns or within a column.
+    Columns,
+}
+
+/// The `Matrix` is a 2D genome of `rows` x `cols` values stored in row-major
+/// order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<V> {
+    rows: usize,
+    cols: usize,
+    values: Vec<V>,
+}
+
+impl<V> Matrix<V> {
+    /// Constructs a new `Matrix` of the given size with all values set to
+    /// the given value.
+    pub fn new(rows: usize, cols: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        Matrix {
+            rows,
+            cols,
+            values: vec![value; rows * cols],
+        }
+    }
+
+    /// Constructs a new `Matrix` of the given size from the given values in
+    /// row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values is not `rows * cols`.
+    pub fn from_values(rows: usize, cols: usize, values: Vec<V>) -> Self {
+        assert_eq!(
+            values.len(),
+            rows * cols,
+            "a matrix of {} rows and {} columns needs {} values",
+            rows,
+            cols,
+            rows * cols
+        );
+        Matrix { rows, cols, values }
+    }
+
+    /// Returns the number of rows of this matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of this matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of rows or columns along the given axis.
+    pub fn len_of(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.rows,
+            Axis::Columns => self.cols,
+        }
+    }
+
+    /// Returns the values of this matrix in row-major order.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns the value at the given row and column.
+    pub fn get(&self, row: usize, col: usize) -> &V {
+        &self.values[self.index(row, col)]
+    }
+
+    /// Sets the value at the given row and column.
+    pub fn set(&mut self, row: usize, col: usize, value: V) {
+        let index = self.index(row, col);
+        self.values[index] = value;
+    }
+
+    /// Swaps the values at the given positions, each given as row and
+    /// column.
+    pub fn swap(&mut self, position1: (usize, usize), position2: (usize, usize)) {
+        let index1 = self.index(position1.0, position1.1);
+        let index2 = self.index(position2.0, position2.1);
+        self.values.swap(index1, index2);
+    }
+
+    /// Returns the values of the given row.
+    pub fn row(&self, row: usize) -> &[V] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns an iterator over the values of the given column.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &V> + '_ {
+        assert!(col < self.cols, "column {} is out of bounds", col);
+        self.values.iter().skip(col).step_by(self.cols)
+    }
+
+    /// Returns the number of values that occur more than once within a row,
+    /// summed up over all rows. Each repeated occurrence counts as one
+    /// violation, e.g. a course that is held more than once in the same room.
+    pub fn duplicates_in_rows(&self) -> usize
+    where
+        V: Eq + Hash,
+    {
+        (0..self.rows)
+            .map(|row| count_duplicates(self.row(row).iter()))
+            .sum()
+    }
+
+    /// Returns the number of values that occur more than once within a
+    /// column, summed up over all columns. Each repeated occurrence counts
+    /// as one violation, e.g. a course that is held in two rooms in the same
+    /// time slot.
+    pub fn duplicates_in_columns(&self) -> usize
+    where
+        V: Eq + Hash,
+    {
+        (0..self.cols)
+            .map(|col| count_duplicates(self.column(col)))
+            .sum()
+    }
+
+    /// Returns the number of values that violate the given constraint. The
+    /// constraint is called with the row, the column and the value and
+    /// returns true if the value violates the constraint.
+    pub fn count_violations<P>(&self, mut violates: P) -> usize
+    where
+        P: FnMut(usize, usize, &V) -> bool,
+    {
+        self.values
+            .iter()
+            .enumerate()
+            .filter(|(index, value)| violates(index / self.cols, index % self.cols, value))
+            .count()
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        assert!(
+            row < self.rows && col < self.cols,
+            "position ({}, {}) is out of bounds of a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        row * self.cols + col
+    }
+}
+
+fn count_duplicates<'a, V, I>(values: I) -> usize
+where
+    V: 'a + Eq + Hash,
+    I: Iterator<Item = &'a V>,
+{
+    let mut seen = HashSet::new();
+    values.filter(|value| !seen.insert(*value)).count()
+}
+
+/// Implementation of a genotype using `Matrix`.
+impl<V> Genotype for Matrix<V>
+where
+    V: Clone + Debug + Send + Sync,
+{
+    type Dna = V;
+}
+
+/// Implementation of a value encoded `genetic::Genotype` using `Matrix`.
+impl<V> ValueEncoded for Matrix<V> {}
@@ -0,0 +1,197 @@
+//! The `encoding` module provides basic scheme of encoding
+//! `genetic::Genotype`s.
+//!
+//! Most important encoding schemes are:
+//! * binary encoding
+//! * value encoding
+//! * permutation encoding
+//! * tree encoding
+//! * graph encoding
+//!
+//! To express which encoding scheme is used for a specific `genetic::Genotype`
+//! a set of marker traits are defined:
+//! * `BinaryEncoded`
+//! * `ValueEncoded`
+//! * `PermutationEncoded`
+//! * `TreeEncoded`
+//! * `GraphEncoded`
+//!
+//! These marker traits are important for providing default implementations
+//! for the `operator::CrossoverOp` and the `operator::MutationOp`. In order
+//! to use any of the default operator implementation the `genetic::Genotype`
+//! used for a genetic algorithm application must be marked with the
+//! appropriate encoding trait. If an application is defining its own crossover
+//! and mutation operators then using these marker traits is optional.
+//!
+//! The `graph` module provides the `graph::Graph` type for graph encoded
+//! `genetic::Genotype`s (with crate feature `graph`). The `matrix` module
+//! provides the `matrix::Matrix` type for 2D value encoded
+//! `genetic::Genotype`s such as schedules (with crate feature `matrix`).
+
+#[cfg(feature = "graph")]
+pub mod graph;
+
+#[cfg(feature = "matrix")]
+pub mod matrix;
+
+use crate::genetic::{GenomeDistance, Genotype};
+use std::fmt::Debug;
+
+/// Marker trait for declaring a `genetic::Genotype` as binary encoded.
+pub trait BinaryEncoded {}
+
+/// Marker trait for declaring a `genetic::Genotype` as value encoded.
+pub trait ValueEncoded {}
+
+/// Marker trait for declaring a permutation encoded `genetic::Genotype`.
+pub trait PermutationEncoded {}
+
+/// Marker trait for declaring a tree encoded `genetic::Genotype`.
+pub trait TreeEncoded: Genotype {}
+
+/// Marker trait for declaring a graph encoded `genetic::Genotype`.
+pub trait GraphEncoded {}
+
+/// Implementation of a genotype using `Vec`.
+impl<V> Genotype for Vec<V>
+where
+    V: Clone + Debug + Send + Sync,
+{
+    type Dna = V;
+}
+
+/// Implementation of binary encoded `genetic::Genotype`
+/// using `Vec<bool>`.
+impl BinaryEncoded for Vec<bool> {}
+
+/// The Hamming distance between two binary encoded genomes, i.e. the
+/// number of positions with different bits. Bits beyond the end of the
+/// shorter genome count as different.
+impl GenomeDistance for Vec<bool> {
+    fn distance(&self, other: &Self) -> f64 {
+        let different = self.iter().zip(other).filter(|(a, b)| a != b).count();
+        (different + self.len().abs_diff(other.len())) as f64
+    }
+}
+
+macro_rules! impl_euclidean_genome_distance {
+    ($($t:ty),*) => {
+        $(
+            /// The Euclidean distance between two value encoded genomes.
+            /// Values beyond the end of the shorter genome are compared to 0.
+            impl GenomeDistance for Vec<$t> {
+                #[allow(trivial_numeric_casts)]
+                fn distance(&self, other: &Self) -> f64 {
+                    let length = self.len().max(other.len());
+                    (0..length)
+                        .map(|index| {
+                            let a = self.get(index).map_or(0., |value| *value as f64);
+                            let b = other.get(index).map_or(0., |value| *value as f64);
+                            (a - b) * (a - b)
+                        })
+                        .sum::<f64>()
+                        .sqrt()
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_genome_distance!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/// Implementation of a value encoded `genetic::Genotype`.
+/// using `Vec`.
+impl<V> ValueEncoded for Vec<V> {}
+
+/// Implementation of a permutation encoded `genetic::Genotype`
+/// using `Vec`.
+impl<V> PermutationEncoded for Vec<V> {}
+
+/// Implementation of a text encoded genotype using `String`.
+///
+/// The genes of a `String` genome are its characters, not its bytes, so that
+/// operators never split a multi-byte character.
+impl Genotype for String {
+    type Dna = char;
+}
+
+/// Implementation of a value encoded `genetic::Genotype`
+/// using `String`.
+impl ValueEncoded for String {}
+
+/// Implementation of a genotype using fixed-size arrays `[V; N]`.
+///
+/// Arrays keep the genes inline, so that small genomes of a length known at
+/// compile time are handled without heap allocations.
+impl<V, const N: usize> Genotype for [V; N]
+where
+    V: Clone + Debug + Send + Sync,
+{
+    type Dna = V;
+}
+
+/// Implementation of binary encoded `genetic::Genotype`
+/// using `[bool; N]`.
+impl<const N: usize> BinaryEncoded for [bool; N] {}
+
+/// Implementation of a value encoded `genetic::Genotype`
+/// using `[V; N]`.
+impl<V, const N: usize> ValueEncoded for [V; N] {}
+
+/// Implementation of a permutation encoded `genetic::Genotype`
+/// using `[V; N]`.
+impl<V, const N: usize> PermutationEncoded for [V; N] {}
+
+#[cfg(feature = "fixedbitset")]
+mod fixedbitset_genotype {
+    use super::{BinaryEncoded, Genotype};
+    use crate::genetic::GenomeDistance;
+
+    use fixedbitset::FixedBitSet;
+
+    /// The Hamming distance between two binary encoded genomes, i.e. the
+    /// number of positions with different bits.
+    impl GenomeDistance for FixedBitSet {
+        fn distance(&self, other: &Self) -> f64 {
+            self.symmetric_difference(other).count() as f64
+        }
+    }
+
+    /// Implementation of genotype using `fixedbistset::FixedBitSet`.
+    impl Genotype for FixedBitSet {
+        type Dna = bool;
+    }
+
+    /// Implementation of binary encoded `genetic::Genotype`
+    /// using `fixedbistset::FixedBitSet`.
+    impl BinaryEncoded for FixedBitSet {}
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_genotype {
+    use super::{BinaryEncoded, Genotype, PermutationEncoded, ValueEncoded};
+    use smallvec::{Array, SmallVec};
+    use std::fmt::Debug;
+
+    /// Implementation of binary encoded `genetic::Genotype`
+    /// using `smallvec::SmallVec`.
+    impl<A, V> Genotype for SmallVec<A>
+    where
+        A: Array<Item = V> + Sync,
+        V: Clone + Debug + Send + Sync,
+    {
+        type Dna = V;
+    }
+
+    /// Implementation of binary encoded `genetic::Genotype`
+    /// using `smallvec::SmallVec<Item = bool>`.
+    impl<A> BinaryEncoded for SmallVec<A> where A: Array<Item = bool> {}
+
+    /// Implementation of a value encoded `genetic::Genotype`.
+    /// using `smallvec::SmallVec`.
+    impl<A> ValueEncoded for SmallVec<A> where A: Array {}
+
+    /// Implementation of a permutation encoded `genetic::Genotype`
+    /// using `smallvec::SmallVec`.
+    impl<A> PermutationEncoded for SmallVec<A> where A: Array {}
+}
@@ -0,0 +1,417 @@
+//! The `es` module provides an implementation of an evolution strategy for
+//! real-parameter optimization, i.e. for genomes of type `Vec<f64>`.
+//!
+//! Unlike the `ga::GeneticAlgorithm` an evolution strategy relies on
+//! mutation only. Each individual carries its own step sizes, one for each
+//! gene, which are mutated along with the genes. Step sizes that produce fit
+//! offspring survive with them, so the strategy adapts the step sizes to the
+//! fitness landscape by itself.
+//!
+//! The steps of the `EvolutionStrategy` are:
+//!
+//! 1. **Initialize**: Evaluate the initial population and select the μ best
+//!    individuals as parents. All step sizes start with the initial step
+//!    size.
+//! 2. **Mutation**: Breed λ offspring. Each child is a copy of a parent
+//!    chosen uniformly at random. First its step sizes are mutated by a
+//!    log-normal factor, then each gene is changed by a normally distributed
+//!    value with the mutated step size as standard deviation.
+//! 3. **Selection**: Select the μ best individuals as parents of the next
+//!    generation, either from the parents and the offspring together (plus
+//!    selection, "(μ+λ)-ES") or from the offspring only (comma selection,
+//!    "(μ,λ)-ES").
+//! 4. **Loop**: Go to step 2
+//!
+//! Plus selection never loses the best individual found so far. Comma
+//! selection forgets every parent after one generation, which helps to
+//! escape local optima and to adapt the step sizes, but requires λ ≥ μ.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, OptimizationResult},
+    genetic::{Fitness, FitnessFunction},
+    population::Population,
+    random::{random_index, random_standard_normal, Prng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+/// The selection scheme of an `EvolutionStrategy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EsSelection {
+    /// The parents of the next generation are selected from the parents and
+    /// the offspring of the current generation, "(μ+λ)-ES".
+    Plus,
+    /// The parents of the next generation are selected from the offspring of
+    /// the current generation only, "(μ,λ)-ES".
+    Comma,
+}
+
+impl Display for EsSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EsSelection::Plus => write!(f, "plus"),
+            EsSelection::Comma => write!(f, "comma"),
+        }
+    }
+}
+
+/// An individual of an `EvolutionStrategy` together with its step sizes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EsIndividual<F>
+where
+    F: Fitness,
+{
+    /// The genes of this individual.
+    pub genome: Vec<f64>,
+    /// The step size of each gene, i.e. the standard deviation of the
+    /// normally distributed change of the gene when it is mutated.
+    pub step_sizes: Vec<f64>,
+    /// The fitness of the genome.
+    pub fitness: F,
+}
+
+/// The `State` struct holds the results of one generation of the evolution
+/// strategy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct State<F>
+where
+    F: Fitness,
+{
+    /// The parents selected for the next generation, sorted from the fittest
+    /// to the least fit individual.
+    pub parents: Vec<EsIndividual<F>>,
+    /// The average step size of all genes of all parents.
+    pub mean_step_size: f64,
+    /// The best solution found so far.
+    pub best_solution: BestSolution<Vec<f64>, F>,
+    /// Processing time for this generation.
+    pub processing_time: ProcessingTime,
+}
+
+impl<F> OptimizationResult<Vec<f64>, F> for State<F>
+where
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<Vec<f64>, F> {
+        &self.best_solution
+    }
+}
+
+/// An error that can occur during execution of the `EvolutionStrategy`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EvolutionStrategyError {
+    /// The algorithm is run with an empty initial population.
+    EmptyPopulation(String),
+    /// The parameters μ and λ or the step sizes are not valid.
+    InvalidParameters(String),
+}
+
+impl Display for EvolutionStrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvolutionStrategyError::EmptyPopulation(details) => write!(f, "{}", details),
+            EvolutionStrategyError::InvalidParameters(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for EvolutionStrategyError {}
+
+/// The `EvolutionStrategy` evolves μ parents by breeding λ offspring per
+/// generation with self-adaptive step sizes for `Vec<f64>` genomes.
+///
+/// The step sizes are mutated by the uncorrelated mutation with one step
+/// size per gene: with `n` genes, each step size is multiplied by
+/// `exp(τ' * N(0, 1) + τ * N_i(0, 1))` where `τ' = 1 / sqrt(2n)` and
+/// `τ = 1 / sqrt(2 * sqrt(n))`, and it is kept at or above a minimum step
+/// size so that the search does not freeze.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvolutionStrategy<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Vec<f64>, F>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    mu: usize,
+    lambda: usize,
+    selection: EsSelection,
+    initial_step_size: f64,
+    min_step_size: f64,
+    initial_population: Population<Vec<f64>>,
+    parents: Vec<EsIndividual<F>>,
+    best: Option<BestSolution<Vec<f64>, F>>,
+    processing_time: ProcessingTime,
+}
+
+impl<F, E> EvolutionStrategy<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Vec<f64>, F>,
+{
+    /// Constructs a new `EvolutionStrategy` that selects `mu` parents and
+    /// breeds `lambda` offspring in each generation. By default it uses plus
+    /// selection, an initial step size of 1 and a minimum step size of
+    /// 1e-10.
+    pub fn new(
+        evaluator: E,
+        mu: usize,
+        lambda: usize,
+        initial_population: Population<Vec<f64>>,
+    ) -> Self {
+        EvolutionStrategy {
+            _f: PhantomData,
+            evaluator,
+            mu,
+            lambda,
+            selection: EsSelection::Plus,
+            initial_step_size: 1.,
+            min_step_size: 1e-10,
+            initial_population,
+            parents: Vec::new(),
+            best: None,
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    /// Sets the selection scheme, plus or comma selection.
+    pub fn with_selection(mut self, selection: EsSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the step size of all genes of the initial population.
+    pub fn with_initial_step_size(mut self, initial_step_size: f64) -> Self {
+        self.initial_step_size = initial_step_size;
+        self
+    }
+
+    /// Sets the minimum step size, below which no step size is lowered by
+    /// the self-adaptation.
+    pub fn with_min_step_size(mut self, min_step_size: f64) -> Self {
+        self.min_step_size = min_step_size;
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn mu(&self) -> usize {
+        self.mu
+    }
+
+    pub fn lambda(&self) -> usize {
+        self.lambda
+    }
+
+    pub fn selection(&self) -> EsSelection {
+        self.selection
+    }
+
+    pub fn initial_step_size(&self) -> f64 {
+        self.initial_step_size
+    }
+
+    pub fn min_step_size(&self) -> f64 {
+        self.min_step_size
+    }
+
+    /// Returns the current parents, sorted from the fittest to the least fit
+    /// individual. It is empty if the algorithm has not been started yet.
+    pub fn parents(&self) -> &[EsIndividual<F>] {
+        &self.parents
+    }
+
+    fn validate(&self) -> Result<(), EvolutionStrategyError> {
+        if self.initial_population.size() == 0 {
+            return Err(EvolutionStrategyError::EmptyPopulation(
+                "The evolution strategy can not be started with an empty initial population."
+                    .to_string(),
+            ));
+        }
+        if self.mu == 0 || self.lambda == 0 {
+            return Err(EvolutionStrategyError::InvalidParameters(format!(
+                "mu and lambda must be greater than 0, but mu is {} and lambda is {}.",
+                self.mu, self.lambda
+            )));
+        }
+        if self.selection == EsSelection::Comma && self.lambda < self.mu {
+            return Err(EvolutionStrategyError::InvalidParameters(format!(
+                "comma selection requires lambda to be at least mu, but mu is {} and lambda \
+                 is {}.",
+                self.mu, self.lambda
+            )));
+        }
+        let valid_initial_step_size =
+            self.initial_step_size > 0. && self.initial_step_size.is_finite();
+        let valid_min_step_size = self.min_step_size >= 0. && self.min_step_size.is_finite();
+        if !valid_initial_step_size || !valid_min_step_size {
+            return Err(EvolutionStrategyError::InvalidParameters(format!(
+                "the initial step size must be positive and the minimum step size must not be \
+                 negative, but the initial step size is {} and the minimum step size is {}.",
+                self.initial_step_size, self.min_step_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Evaluates the given genomes and step sizes.
+    fn evaluate(&mut self, candidates: Vec<(Vec<f64>, Vec<f64>)>) -> Vec<EsIndividual<F>> {
+        let genomes: Vec<Vec<f64>> = candidates
+            .iter()
+            .map(|(genome, _)| genome.clone())
+            .collect();
+        let fitness_values: Vec<F> = genomes
+            .iter()
+            .map(|genome| self.evaluator.fitness_of(genome))
+            .collect();
+        self.evaluator
+            .population_evaluated(&genomes, &fitness_values);
+        candidates
+            .into_iter()
+            .zip(fitness_values)
+            .map(|((genome, step_sizes), fitness)| EsIndividual {
+                genome,
+                step_sizes,
+                fitness,
+            })
+            .collect()
+    }
+
+    /// Breeds `lambda` children by mutating randomly chosen parents.
+    fn breed_offspring(&self, rng: &mut Prng) -> Vec<(Vec<f64>, Vec<f64>)> {
+        (0..self.lambda)
+            .map(|_| {
+                let parent = &self.parents[random_index(rng, self.parents.len())];
+                self_adaptive_mutation(&parent.genome, &parent.step_sizes, self.min_step_size, rng)
+            })
+            .collect()
+    }
+
+    /// Keeps the `mu` fittest of the given individuals as parents.
+    fn select_parents(&mut self, mut candidates: Vec<EsIndividual<F>>) {
+        candidates.sort_by(|a, b| b.fitness.cmp(&a.fitness));
+        candidates.truncate(self.mu);
+        self.parents = candidates;
+    }
+
+    fn update_best(&mut self, iteration: u64) {
+        let fittest = &self.parents[0];
+        let improved = self
+            .best
+            .as_ref()
+            .is_none_or(|best| fittest.fitness > best.solution.fitness);
+        if improved {
+            self.best = Some(BestSolution {
+                found_at: Local::now(),
+                generation: iteration,
+                solution: Evaluated {
+                    genome: fittest.genome.clone(),
+                    fitness: fittest.fitness.clone(),
+                },
+            });
+        }
+    }
+}
+
+/// Mutates the step sizes by a log-normal factor and then the genes by
+/// normally distributed values with the mutated step sizes as standard
+/// deviation.
+fn self_adaptive_mutation(
+    genome: &[f64],
+    step_sizes: &[f64],
+    min_step_size: f64,
+    rng: &mut Prng,
+) -> (Vec<f64>, Vec<f64>) {
+    let n = genome.len().max(1) as f64;
+    let global_learning_rate = 1. / (2. * n).sqrt();
+    let local_learning_rate = 1. / (2. * n.sqrt()).sqrt();
+    let global_factor = global_learning_rate * random_standard_normal(rng);
+    let step_sizes: Vec<f64> = step_sizes
+        .iter()
+        .map(|step_size| {
+            let local_factor = local_learning_rate * random_standard_normal(rng);
+            (step_size * (global_factor + local_factor).exp()).max(min_step_size)
+        })
+        .collect();
+    let genome = genome
+        .iter()
+        .zip(&step_sizes)
+        .map(|(gene, step_size)| gene + step_size * random_standard_normal(rng))
+        .collect();
+    (genome, step_sizes)
+}
+
+impl<F, E> TrackProcessingTime for EvolutionStrategy<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Vec<f64>, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<F, E> Algorithm for EvolutionStrategy<F, E>
+where
+    F: Fitness,
+    E: FitnessFunction<Vec<f64>, F>,
+{
+    type Output = State<F>;
+    type Error = EvolutionStrategyError;
+
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        output.improves_on(best)
+    }
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        self.validate()?;
+        let generation = timed(|| {
+            let candidates = if self.parents.is_empty() {
+                let initial_step_size = self.initial_step_size;
+                let initial = self
+                    .initial_population
+                    .individuals()
+                    .iter()
+                    .map(|genome| (genome.clone(), vec![initial_step_size; genome.len()]))
+                    .collect();
+                self.evaluate(initial)
+            } else {
+                let offspring = self.breed_offspring(rng);
+                let mut offspring = self.evaluate(offspring);
+                if self.selection == EsSelection::Plus {
+                    offspring.append(&mut self.parents);
+                }
+                offspring
+            };
+            self.select_parents(candidates);
+            self.update_best(iteration);
+        })
+        .run();
+        self.processing_time = generation.time;
+
+        let num_step_sizes: usize = self.parents.iter().map(|p| p.step_sizes.len()).sum();
+        let sum_step_sizes: f64 = self.parents.iter().flat_map(|p| &p.step_sizes).sum();
+        Ok(State {
+            parents: self.parents.clone(),
+            mean_step_size: sum_step_sizes / num_step_sizes.max(1) as f64,
+            best_solution: self.best.clone().expect("best solution is evaluated"),
+            processing_time: self.processing_time,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.parents.clear();
+        self.best = None;
+        Ok(true)
+    }
+}
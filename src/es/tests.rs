@@ -0,0 +1,185 @@
+use super::*;
+use crate::{
+    random::get_rng,
+    simulation::{simulator::simulate, SimResult, Simulation, SimulationBuilder},
+    termination::{
+        limit::{FitnessLimit, GenerationLimit},
+        or,
+    },
+};
+use galvanic_assert::matchers::*;
+
+/// The negated sphere function scaled to integers, with its maximum of 0 at
+/// the origin.
+#[derive(Clone, Debug, PartialEq)]
+struct Sphere;
+
+impl FitnessFunction<Vec<f64>, i64> for Sphere {
+    fn fitness_of(&self, genome: &Vec<f64>) -> i64 {
+        -(genome.iter().map(|x| x * x).sum::<f64>() * 1_000_000.) as i64
+    }
+
+    fn average(&self, fitness_values: &[i64]) -> i64 {
+        fitness_values.iter().sum::<i64>() / fitness_values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        i64::MIN
+    }
+}
+
+fn initial_population() -> Population<Vec<f64>> {
+    Population::with_individuals(vec![vec![5.; 3], vec![-4.; 3], vec![3., -2., 1.]])
+}
+
+mod self_adaptive_mutation {
+
+    use super::*;
+
+    #[test]
+    fn step_sizes_are_kept_at_the_minimum_step_size() {
+        let mut rng = get_rng([1; 32]);
+
+        for _ in 0..100 {
+            let (_, step_sizes) = self_adaptive_mutation(&[0.; 4], &[1e-12; 4], 0.5, &mut rng);
+
+            for step_size in step_sizes {
+                expect_that!(&step_size, greater_than_or_equal(0.5));
+            }
+        }
+    }
+
+    #[test]
+    fn genes_are_changed_by_the_mutated_step_sizes() {
+        let mut rng = get_rng([1; 32]);
+
+        let (genome, step_sizes) = self_adaptive_mutation(&[0.; 4], &[1.; 4], 0., &mut rng);
+
+        expect_that!(&genome.len(), eq(4));
+        expect_that!(&step_sizes.len(), eq(4));
+        expect_that!(&genome, not(eq(vec![0.; 4])));
+    }
+}
+
+mod evolution_strategy {
+
+    use super::*;
+
+    #[test]
+    fn first_generation_selects_the_mu_best_of_the_initial_population() {
+        let mut es =
+            EvolutionStrategy::new(Sphere, 2, 6, initial_population()).with_initial_step_size(0.5);
+        let mut rng = get_rng([3; 32]);
+
+        let state = es.next(1, &mut rng).unwrap();
+
+        expect_that!(&state.parents.len(), eq(2));
+        expect_that!(&state.parents[0].genome, eq(vec![3., -2., 1.]));
+        expect_that!(&state.parents[1].genome, eq(vec![-4.; 3]));
+        expect_that!(&state.mean_step_size, eq(0.5));
+    }
+
+    #[test]
+    fn plus_selection_never_loses_the_best_parent() {
+        let mut es = EvolutionStrategy::new(Sphere, 3, 6, initial_population());
+        let mut rng = get_rng([3; 32]);
+        let mut best_parent_fitness = i64::MIN;
+
+        for iteration in 1..=30 {
+            let state = es.next(iteration, &mut rng).unwrap();
+
+            expect_that!(
+                &state.parents[0].fitness,
+                greater_than_or_equal(best_parent_fitness)
+            );
+            best_parent_fitness = state.parents[0].fitness;
+        }
+    }
+
+    #[test]
+    fn comma_selection_selects_from_the_offspring_only() {
+        let mut es = EvolutionStrategy::new(Sphere, 2, 4, initial_population())
+            .with_selection(EsSelection::Comma);
+        let mut rng = get_rng([3; 32]);
+        let initial = es.next(1, &mut rng).unwrap();
+
+        let state = es.next(2, &mut rng).unwrap();
+
+        for parent in &state.parents {
+            for initial_parent in &initial.parents {
+                expect_that!(&parent.genome, not(eq(initial_parent.genome.clone())));
+            }
+        }
+        expect_that!(
+            &state.best_solution.solution.fitness,
+            greater_than_or_equal(initial.best_solution.solution.fitness)
+        );
+    }
+
+    #[test]
+    fn comma_selection_requires_lambda_to_be_at_least_mu() {
+        let mut es = EvolutionStrategy::new(Sphere, 4, 2, initial_population())
+            .with_selection(EsSelection::Comma);
+        let mut rng = get_rng([3; 32]);
+
+        let result = es.next(1, &mut rng);
+
+        expect_that!(
+            &result,
+            eq(Err(EvolutionStrategyError::InvalidParameters(
+                "comma selection requires lambda to be at least mu, but mu is 4 and lambda is 2."
+                    .to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn empty_initial_population_is_an_error() {
+        let mut es = EvolutionStrategy::new(Sphere, 1, 1, Population::with_individuals(vec![]));
+        let mut rng = get_rng([3; 32]);
+
+        let result = es.next(1, &mut rng);
+
+        expect_that!(&result.is_err(), eq(true));
+    }
+
+    #[test]
+    fn reset_starts_again_from_the_initial_population() {
+        let mut es = EvolutionStrategy::new(Sphere, 2, 6, initial_population());
+        let mut rng = get_rng([3; 32]);
+        for iteration in 1..=5 {
+            es.next(iteration, &mut rng).unwrap();
+        }
+
+        es.reset().unwrap();
+
+        expect_that!(&es.parents().is_empty(), eq(true));
+    }
+
+    #[test]
+    fn simulator_runs_the_evolution_strategy_until_the_fitness_limit_is_reached() {
+        let es = EvolutionStrategy::new(Sphere, 5, 35, initial_population())
+            .with_selection(EsSelection::Comma);
+        let mut simulator = simulate(es)
+            .until(or(FitnessLimit::new(-100), GenerationLimit::new(2_000)))
+            .build_with_seed([7; 32]);
+
+        let result = simulator.run().unwrap();
+
+        match result {
+            SimResult::Final(state, _, _, stop_reason, _) => {
+                expect_that!(&stop_reason.contains("fitness"), eq(true));
+                expect_that!(
+                    &state.result.best_solution.solution.fitness,
+                    greater_than_or_equal(-100)
+                );
+                expect_that!(&state.result.mean_step_size, less_than(1.));
+            },
+            SimResult::Intermediate(_) => panic!("expected final result"),
+        }
+    }
+}
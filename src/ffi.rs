@@ -0,0 +1,403 @@
+//! The `ffi` module provides C compatible functions to drive a genetic
+//! algorithm from a C or C++ host. It is only available with the crate
+//! feature `ffi`.
+//!
+//! The algorithm is one of the `ga::presets`, configured by a `GenevoConfig`.
+//! Genomes are exchanged with the host as arrays of `double` values:
+//! * binary encoded genomes as `0.0` and `1.0`
+//! * real valued genomes as their values
+//! * permutations as the indices `0.0` to `genome_length - 1`
+//!
+//! The fitness of a genome is calculated by a callback of the host. The
+//! callback is called from several threads at the same time, so it must be
+//! thread safe, as must be the `user_data` passed to it.
+//!
+//! The matching C declarations are:
+//!
+//! ```c
+//! typedef struct GenevoGa GenevoGa;
+//!
+//! typedef struct {
+//!     uint32_t encoding;
+//!     uint64_t genome_length;
+//!     uint64_t population_size;
+//!     double min_value;
+//!     double max_value;
+//!     int64_t lowest_fitness;
+//!     int64_t highest_fitness;
+//!     uint64_t seed;
+//! } GenevoConfig;
+//!
+//! typedef struct {
+//!     uint64_t generation;
+//!     uint64_t population_size;
+//!     double highest_fitness;
+//!     double average_fitness;
+//!     double lowest_fitness;
+//!     double best_fitness;
+//!     int64_t processing_time_micros;
+//!     uint64_t evaluation_timeouts;
+//!     uint64_t selection_sample_size;
+//! } GenerationSummary;
+//!
+//! typedef int64_t (*GenevoFitnessCallback)(const double *genes, size_t length, void *user_data);
+//!
+//! GenevoGa *genevo_ga_create(const GenevoConfig *config, GenevoFitnessCallback callback, void *user_data);
+//! int32_t genevo_ga_step(GenevoGa *ga);
+//! int32_t genevo_ga_summary(const GenevoGa *ga, GenerationSummary *summary);
+//! int32_t genevo_ga_best_fitness(const GenevoGa *ga, int64_t *fitness);
+//! size_t genevo_ga_best_genome(const GenevoGa *ga, double *buffer, size_t capacity);
+//! void genevo_ga_destroy(GenevoGa *ga);
+//! ```
+
+#![allow(unsafe_code)]
+
+use crate::{
+    algorithm::{Algorithm, BestSolution},
+    fitness::callback::CallbackFitnessFunction,
+    ga::{
+        presets::{self, BinaryAlgorithm, PermutationAlgorithm, RealValuedAlgorithm},
+        GenerationSummary, State,
+    },
+    genetic::Genotype,
+    random::{get_rng, random_seed, Prng, Seed},
+};
+use std::{
+    cmp::Ordering,
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr, slice,
+};
+
+/// Value of `GenevoConfig::encoding` for binary encoded genomes.
+pub const GENEVO_ENCODING_BINARY: u32 = 0;
+/// Value of `GenevoConfig::encoding` for real valued genomes.
+pub const GENEVO_ENCODING_REAL_VALUED: u32 = 1;
+/// Value of `GenevoConfig::encoding` for permutation encoded genomes.
+pub const GENEVO_ENCODING_PERMUTATION: u32 = 2;
+
+/// The configuration of a genetic algorithm created by `genevo_ga_create`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GenevoConfig {
+    /// One of the `GENEVO_ENCODING_*` constants.
+    pub encoding: u32,
+    /// The number of genes of each genome.
+    pub genome_length: u64,
+    /// The number of individuals of the population.
+    pub population_size: u64,
+    /// The lower bound of the values of real valued genomes.
+    pub min_value: f64,
+    /// The upper bound of the values of real valued genomes.
+    pub max_value: f64,
+    /// The lowest fitness value the callback returns.
+    pub lowest_fitness: i64,
+    /// The highest fitness value the callback returns.
+    pub highest_fitness: i64,
+    /// The seed for all random decisions. `0` means a random seed.
+    pub seed: u64,
+}
+
+/// The fitness callback of the host. It gets the genes of one genome and the
+/// `user_data` given to `genevo_ga_create`.
+pub type GenevoFitnessCallback =
+    extern "C" fn(genes: *const f64, length: usize, user_data: *mut c_void) -> i64;
+
+/// A genetic algorithm driven by a C host.
+#[derive(Debug)]
+pub struct GenevoGa {
+    engine: Engine,
+    rng: Prng,
+    iteration: u64,
+    summary: Option<GenerationSummary>,
+    best_genome: Vec<f64>,
+    best_fitness: Option<i64>,
+}
+
+type Evaluator<G> = CallbackFitnessFunction<G>;
+
+#[derive(Debug)]
+enum Engine {
+    Binary(BinaryAlgorithm<i64, Evaluator<Vec<bool>>>),
+    RealValued(RealValuedAlgorithm<i64, Evaluator<Vec<f64>>>),
+    Permutation(PermutationAlgorithm<i64, Evaluator<Vec<usize>>>),
+}
+
+/// The callback of the host together with its user data.
+#[derive(Clone, Copy)]
+struct HostCallback {
+    callback: GenevoFitnessCallback,
+    user_data: *mut c_void,
+}
+
+// The host guarantees that the callback and the user data may be used from
+// several threads at the same time, see the module documentation.
+unsafe impl Send for HostCallback {}
+unsafe impl Sync for HostCallback {}
+
+impl HostCallback {
+    fn call(&self, genes: &[f64]) -> i64 {
+        (self.callback)(genes.as_ptr(), genes.len(), self.user_data)
+    }
+
+    fn evaluator<G>(self, config: &GenevoConfig) -> Evaluator<G>
+    where
+        G: Genotype + HostGenes,
+    {
+        CallbackFitnessFunction::new(
+            move |genome: &G| self.call(&genome.host_genes()),
+            config.lowest_fitness,
+            config.highest_fitness,
+        )
+    }
+}
+
+/// A genome that is exchanged with the host as array of `double` values.
+trait HostGenes {
+    fn host_genes(&self) -> Vec<f64>;
+}
+
+impl HostGenes for Vec<bool> {
+    fn host_genes(&self) -> Vec<f64> {
+        self.iter().map(|gene| f64::from(u8::from(*gene))).collect()
+    }
+}
+
+impl HostGenes for Vec<f64> {
+    fn host_genes(&self) -> Vec<f64> {
+        self.clone()
+    }
+}
+
+impl HostGenes for Vec<usize> {
+    fn host_genes(&self) -> Vec<f64> {
+        self.iter().map(|gene| *gene as f64).collect()
+    }
+}
+
+fn seed_of(config: &GenevoConfig) -> Seed {
+    if config.seed == 0 {
+        return random_seed();
+    }
+    let mut bytes = [0; Seed::LENGTH];
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&config.seed.to_le_bytes());
+    }
+    Seed::from(bytes)
+}
+
+impl GenevoGa {
+    fn new(config: &GenevoConfig, host: HostCallback) -> Option<Self> {
+        let genome_length = usize::try_from(config.genome_length).ok()?;
+        let population_size = usize::try_from(config.population_size).ok()?;
+        let seed = seed_of(config);
+        let engine = match config.encoding {
+            GENEVO_ENCODING_BINARY => Engine::Binary(
+                presets::binary(genome_length)
+                    .with_population_size(population_size)
+                    .with_seed(seed)
+                    .with_evaluation(host.evaluator(config))
+                    .build(),
+            ),
+            GENEVO_ENCODING_REAL_VALUED => {
+                if config.min_value.partial_cmp(&config.max_value) != Some(Ordering::Less) {
+                    return None;
+                }
+                Engine::RealValued(
+                    presets::real_valued(genome_length, config.min_value..config.max_value)
+                        .with_population_size(population_size)
+                        .with_seed(seed)
+                        .with_evaluation(host.evaluator(config))
+                        .build(),
+                )
+            },
+            GENEVO_ENCODING_PERMUTATION => Engine::Permutation(
+                presets::permutation(genome_length)
+                    .with_population_size(population_size)
+                    .with_seed(seed)
+                    .with_evaluation(host.evaluator(config))
+                    .build(),
+            ),
+            _ => return None,
+        };
+        Some(GenevoGa {
+            engine,
+            rng: get_rng(seed),
+            iteration: 0,
+            summary: None,
+            best_genome: Vec::new(),
+            best_fitness: None,
+        })
+    }
+
+    fn step(&mut self) -> bool {
+        self.iteration += 1;
+        let iteration = self.iteration;
+        let rng = &mut self.rng;
+        let result = match &mut self.engine {
+            Engine::Binary(algorithm) => algorithm
+                .next(iteration, rng)
+                .map(|state| summarize(&state)),
+            Engine::RealValued(algorithm) => algorithm
+                .next(iteration, rng)
+                .map(|state| summarize(&state)),
+            Engine::Permutation(algorithm) => algorithm
+                .next(iteration, rng)
+                .map(|state| summarize(&state)),
+        };
+        match result {
+            Ok((summary, genes, fitness)) => {
+                self.summary = Some(summary);
+                if self.best_fitness.is_none_or(|best| fitness > best) {
+                    self.best_fitness = Some(fitness);
+                    self.best_genome = genes;
+                }
+                true
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+fn summarize<G>(state: &State<G, i64>) -> (GenerationSummary, Vec<f64>, i64)
+where
+    G: Genotype + HostGenes,
+{
+    let BestSolution { solution, .. } = &state.best_solution;
+    (
+        state.summary(),
+        solution.genome.host_genes(),
+        solution.fitness,
+    )
+}
+
+/// Creates a genetic algorithm from the given configuration, which evaluates
+/// genomes by the given callback. Returns a null pointer if the
+/// configuration is invalid.
+///
+/// # Safety
+///
+/// `config` must point to a valid `GenevoConfig`. The `callback` and the
+/// `user_data` must be safe to be called and used from several threads at
+/// the same time for as long as the algorithm exists. The returned algorithm
+/// must be released by `genevo_ga_destroy`.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_create(
+    config: *const GenevoConfig,
+    callback: GenevoFitnessCallback,
+    user_data: *mut c_void,
+) -> *mut GenevoGa {
+    let config = match config.as_ref() {
+        Some(config) => *config,
+        None => return ptr::null_mut(),
+    };
+    let host = HostCallback {
+        callback,
+        user_data,
+    };
+    match catch_unwind(|| GenevoGa::new(&config, host)) {
+        Ok(Some(ga)) => Box::into_raw(Box::new(ga)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Processes one generation of the genetic algorithm. Returns `0` on success
+/// and `-1` on failure.
+///
+/// # Safety
+///
+/// `ga` must be a pointer returned by `genevo_ga_create` that has not been
+/// destroyed yet.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_step(ga: *mut GenevoGa) -> i32 {
+    let ga = match ga.as_mut() {
+        Some(ga) => ga,
+        None => return -1,
+    };
+    match catch_unwind(AssertUnwindSafe(|| ga.step())) {
+        Ok(true) => 0,
+        _ => -1,
+    }
+}
+
+/// Writes the summary of the last generation to `summary`. Returns `0` on
+/// success and `-1` if no generation has been processed yet.
+///
+/// # Safety
+///
+/// `ga` must be a pointer returned by `genevo_ga_create` that has not been
+/// destroyed yet and `summary` must point to writable memory for a
+/// `GenerationSummary`.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_summary(
+    ga: *const GenevoGa,
+    summary: *mut GenerationSummary,
+) -> i32 {
+    match (ga.as_ref().and_then(|ga| ga.summary), summary.is_null()) {
+        (Some(last), false) => {
+            *summary = last;
+            0
+        },
+        _ => -1,
+    }
+}
+
+/// Writes the fitness of the best genome found so far to `fitness`. Returns
+/// `0` on success and `-1` if no generation has been processed yet.
+///
+/// # Safety
+///
+/// `ga` must be a pointer returned by `genevo_ga_create` that has not been
+/// destroyed yet and `fitness` must point to a writable `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_best_fitness(ga: *const GenevoGa, fitness: *mut i64) -> i32 {
+    match (
+        ga.as_ref().and_then(|ga| ga.best_fitness),
+        fitness.is_null(),
+    ) {
+        (Some(best), false) => {
+            *fitness = best;
+            0
+        },
+        _ => -1,
+    }
+}
+
+/// Copies the genes of the best genome found so far into `buffer`, at most
+/// `capacity` values. Returns the number of genes of the genome, which is `0`
+/// if no generation has been processed yet.
+///
+/// # Safety
+///
+/// `ga` must be a pointer returned by `genevo_ga_create` that has not been
+/// destroyed yet and `buffer` must point to writable memory for `capacity`
+/// `double` values.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_best_genome(
+    ga: *const GenevoGa,
+    buffer: *mut f64,
+    capacity: usize,
+) -> usize {
+    let ga = match ga.as_ref() {
+        Some(ga) => ga,
+        None => return 0,
+    };
+    if !buffer.is_null() {
+        let len = ga.best_genome.len().min(capacity);
+        slice::from_raw_parts_mut(buffer, len).copy_from_slice(&ga.best_genome[..len]);
+    }
+    ga.best_genome.len()
+}
+
+/// Releases a genetic algorithm created by `genevo_ga_create`.
+///
+/// # Safety
+///
+/// `ga` must be a pointer returned by `genevo_ga_create` that has not been
+/// destroyed yet, or a null pointer.
+#[no_mangle]
+pub unsafe extern "C" fn genevo_ga_destroy(ga: *mut GenevoGa) {
+    if !ga.is_null() {
+        drop(Box::from_raw(ga));
+    }
+}
@@ -0,0 +1,220 @@
+//! The `cached` module provides the `CachedFitnessFunction`, a
+//! `genetic::FitnessFunction` that memoizes the fitness values calculated by
+//! another fitness function.
+//!
+//! Selection and reinsertion carry over many individuals unchanged from one
+//! generation to the next, and crossover and mutation often breed genomes
+//! that have been seen before. For expensive fitness functions it pays off to
+//! look up the fitness of such genomes instead of calculating it again. The
+//! genetic algorithm does not evaluate genomes whose fitness is found in the
+//! cache, not even on a thread with an evaluation timeout.
+//!
+//! Only fitness functions whose result depends on nothing but the genome
+//! should be cached. The fitness of e.g. a `novelty::NoveltyFitness` changes
+//! with its archive, so cached values would be outdated.
+
+use crate::{
+    cache::LruCache,
+    genetic::{Fitness, FitnessFunction, Genotype},
+};
+use std::{
+    fmt::{self, Debug},
+    hash::Hash,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The `CachedFitnessFunction` wraps a `genetic::FitnessFunction` and
+/// stores the fitness values it calculates in an `LruCache` of limited
+/// capacity. When the cache is full the fitness of the genome that has not
+/// been looked up for the longest time is evicted.
+///
+/// Clones of a `CachedFitnessFunction` share the same cache, so the threads
+/// that evaluate a population in parallel benefit from each other's results.
+#[derive(Clone)]
+pub struct CachedFitnessFunction<G, F, E>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    evaluator: E,
+    cache: Arc<Mutex<FitnessCache<G, F>>>,
+}
+
+/// The cached fitness values together with the number of hits and misses.
+#[derive(Debug)]
+struct FitnessCache<G, F>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+{
+    entries: LruCache<G, F>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<G, F, E> CachedFitnessFunction<G, F, E>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    /// Constructs a new `CachedFitnessFunction` that memoizes the fitness
+    /// values of up to `capacity` genomes calculated by the given evaluator.
+    pub fn new(evaluator: E, capacity: usize) -> Self {
+        CachedFitnessFunction {
+            evaluator,
+            cache: Arc::new(Mutex::new(FitnessCache {
+                entries: LruCache::new(capacity),
+                hits: 0,
+                misses: 0,
+            })),
+        }
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the maximum number of fitness values stored in the cache.
+    pub fn capacity(&self) -> usize {
+        self.lock().entries.capacity()
+    }
+
+    /// Returns the number of fitness values currently stored in the cache.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Returns the number of fitness values that have been found in the
+    /// cache.
+    pub fn hits(&self) -> u64 {
+        self.lock().hits
+    }
+
+    /// Returns the number of fitness values that have been calculated by the
+    /// wrapped fitness function.
+    pub fn misses(&self) -> u64 {
+        self.lock().misses
+    }
+
+    /// Removes all fitness values from the cache and resets the number of
+    /// hits and misses.
+    pub fn clear(&self) {
+        let mut cache = self.lock();
+        cache.entries.clear();
+        cache.hits = 0;
+        cache.misses = 0;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, FitnessCache<G, F>> {
+        // a poisoned cache is still consistent, as entries are only inserted
+        // as a whole
+        self.cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<G, F, E> Debug for CachedFitnessFunction<G, F, E>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+    E: FitnessFunction<G, F> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cache = self.lock();
+        f.debug_struct("CachedFitnessFunction")
+            .field("evaluator", &self.evaluator)
+            .field("capacity", &cache.entries.capacity())
+            .field("len", &cache.entries.len())
+            .field("hits", &cache.hits)
+            .field("misses", &cache.misses)
+            .finish()
+    }
+}
+
+impl<G, F, E> FitnessFunction<G, F> for CachedFitnessFunction<G, F, E>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        if let Some(fitness) = self.cached_fitness_of(genome) {
+            return fitness;
+        }
+        // evaluate without holding the lock, so other threads are not blocked
+        let fitness = self.evaluator.fitness_of(genome);
+        let mut cache = self.lock();
+        cache.misses += 1;
+        cache.entries.insert(genome.clone(), fitness.clone());
+        fitness
+    }
+
+    fn cached_fitness_of(&self, genome: &G) -> Option<F> {
+        let mut cache = self.lock();
+        let fitness = cache.entries.get(genome).cloned();
+        if fitness.is_some() {
+            cache.hits += 1;
+        }
+        fitness
+    }
+
+    fn fitness_of_population(&self, population: &[G]) -> Vec<F> {
+        let cached: Vec<Option<F>> = population
+            .iter()
+            .map(|genome| self.cached_fitness_of(genome))
+            .collect();
+        let uncached: Vec<G> = population
+            .iter()
+            .zip(&cached)
+            .filter(|(_, fitness)| fitness.is_none())
+            .map(|(genome, _)| genome.clone())
+            .collect();
+        let calculated = self.evaluator.fitness_of_population(&uncached);
+        {
+            let mut cache = self.lock();
+            cache.misses += uncached.len() as u64;
+            for (genome, fitness) in uncached.into_iter().zip(&calculated) {
+                cache.entries.insert(genome, fitness.clone());
+            }
+        }
+        let mut calculated = calculated.into_iter();
+        cached
+            .into_iter()
+            .map(|fitness| {
+                fitness
+                    .or_else(|| calculated.next())
+                    .expect("fitness value")
+            })
+            .collect()
+    }
+
+    fn evaluates_population_at_once(&self) -> bool {
+        self.evaluator.evaluates_population_at_once()
+    }
+
+    fn average(&self, fitness_values: &[F]) -> F {
+        self.evaluator.average(fitness_values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.evaluator.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.evaluator.lowest_possible_fitness()
+    }
+
+    fn population_evaluated(&mut self, population: &[G], fitness_values: &[F]) {
+        self.evaluator
+            .population_evaluated(population, fitness_values);
+    }
+}
@@ -0,0 +1,96 @@
+//! The `callback` module provides the `CallbackFitnessFunction`, a
+//! `genetic::FitnessFunction` that calculates the fitness by calling a
+//! boxed callback.
+//!
+//! Unlike a fitness function that is generic over the type of a closure, the
+//! type of a `CallbackFitnessFunction` only depends on the genotype. This
+//! makes it possible to name the type of a genetic algorithm without generic
+//! parameters for the evaluator, as it is required by bindings to other
+//! languages, where the callback forwards to e.g. a Python function.
+
+use crate::genetic::{FitnessFunction, Genotype};
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+/// The `CallbackFitnessFunction` calculates the fitness of a genome as `i64`
+/// by calling a callback.
+#[derive(Clone)]
+pub struct CallbackFitnessFunction<G>
+where
+    G: Genotype,
+{
+    callback: Arc<dyn Fn(&G) -> i64 + Send + Sync>,
+    lowest_fitness: i64,
+    highest_fitness: i64,
+}
+
+impl<G> CallbackFitnessFunction<G>
+where
+    G: Genotype,
+{
+    /// Constructs a new `CallbackFitnessFunction` that calls the given
+    /// callback and whose fitness values range from `lowest_fitness` to
+    /// `highest_fitness`.
+    pub fn new<C>(callback: C, lowest_fitness: i64, highest_fitness: i64) -> Self
+    where
+        C: Fn(&G) -> i64 + Send + Sync + 'static,
+    {
+        CallbackFitnessFunction {
+            callback: Arc::new(callback),
+            lowest_fitness,
+            highest_fitness,
+        }
+    }
+}
+
+impl<G> Debug for CallbackFitnessFunction<G>
+where
+    G: Genotype,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CallbackFitnessFunction")
+            .field("lowest_fitness", &self.lowest_fitness)
+            .field("highest_fitness", &self.highest_fitness)
+            .finish()
+    }
+}
+
+/// Two `CallbackFitnessFunction`s are equal if they share the same callback
+/// and have the same fitness range.
+impl<G> PartialEq for CallbackFitnessFunction<G>
+where
+    G: Genotype,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.callback, &other.callback)
+            && self.lowest_fitness == other.lowest_fitness
+            && self.highest_fitness == other.highest_fitness
+    }
+}
+
+impl<G> FitnessFunction<G, i64> for CallbackFitnessFunction<G>
+where
+    G: Genotype,
+{
+    fn fitness_of(&self, genome: &G) -> i64 {
+        (self.callback)(genome).clamp(self.lowest_fitness, self.highest_fitness)
+    }
+
+    fn average(&self, fitness_values: &[i64]) -> i64 {
+        if fitness_values.is_empty() {
+            return 0;
+        }
+        let sum: i128 = fitness_values.iter().map(|value| *value as i128).sum();
+        (sum / fitness_values.len() as i128) as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        self.highest_fitness
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        self.lowest_fitness
+    }
+}
@@ -0,0 +1,419 @@
+//! The `command` module provides the `CommandFitnessFunction`, a
+//! `genetic::FitnessFunction` that delegates the evaluation of genomes to an
+//! external executable, e.g. an existing command line simulator.
+//!
+//! The genomes are converted into text by a user supplied encoder and passed
+//! to the executable either as lines on its standard input or as the last
+//! argument of its command line. The executable prints the fitness of each
+//! genome as a decimal number on a line of its own to standard output.
+//!
+//! This module is only available with the crate feature `command`.
+
+use crate::genetic::{FitnessFunction, Genotype};
+use std::{
+    fmt::{self, Debug, Display},
+    io::Write,
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread,
+};
+
+/// The default factor the fitness values parsed from the output of the
+/// executable are multiplied with before they are rounded to integers.
+pub const DEFAULT_COMMAND_FITNESS_SCALE: f64 = 1.;
+
+/// Defines how the encoded genomes are passed to the executable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CommandInput {
+    /// The genomes of a batch are written to the standard input of the
+    /// executable, one genome per line. The executable must print the
+    /// fitness values in the same order.
+    Stdin,
+    /// The executable is started once per genome with the encoded genome as
+    /// last argument. Genomes are not batched in this mode.
+    Argument,
+}
+
+/// An error that can occur while evaluating genomes by an external
+/// executable.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommandError {
+    /// The executable could not be started or its input and output could
+    /// not be transferred.
+    Io(String),
+    /// The executable exited with a non zero exit status.
+    Failed(String),
+    /// The output of the executable could not be parsed into fitness values.
+    InvalidOutput(String),
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandError::Io(details) => write!(f, "io error: {}", details),
+            CommandError::Failed(details) => write!(f, "command failed: {}", details),
+            CommandError::InvalidOutput(details) => write!(f, "invalid output: {}", details),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// The `CommandFitnessFunction` evaluates genomes by running an external
+/// executable.
+///
+/// Fitness values are parsed as floating point numbers, multiplied by the
+/// `scale` and rounded to `i64`. Genomes whose evaluation fails get the
+/// lowest possible fitness. The number of failed evaluations is counted and
+/// can be queried by `num_failures`.
+///
+/// When the genetic algorithm evaluates a population in parallel, several
+/// processes are started at the same time. The number of processes running
+/// concurrently is bounded by `max_processes` across all clones of the
+/// `CommandFitnessFunction`.
+#[derive(Clone)]
+pub struct CommandFitnessFunction<G>
+where
+    G: Genotype,
+{
+    program: String,
+    args: Vec<String>,
+    input: CommandInput,
+    encoder: Arc<dyn Fn(&G) -> String + Send + Sync>,
+    batch_size: usize,
+    scale: f64,
+    lowest_fitness: i64,
+    highest_fitness: i64,
+    pool: Arc<ProcessPool>,
+    num_failures: Arc<AtomicUsize>,
+}
+
+impl<G> CommandFitnessFunction<G>
+where
+    G: Genotype,
+{
+    /// Constructs a new `CommandFitnessFunction` that runs the given program
+    /// and encodes genomes by the given `encoder`.
+    ///
+    /// By default the genomes are written to standard input, batches hold
+    /// one genome, as many processes as available CPUs may run concurrently
+    /// and the fitness values range from `0` to `i64::MAX`.
+    pub fn new<P, N>(program: P, encoder: N) -> Self
+    where
+        P: Into<String>,
+        N: Fn(&G) -> String + Send + Sync + 'static,
+    {
+        let max_processes = thread::available_parallelism()
+            .map(|parallelism| parallelism.get())
+            .unwrap_or(1);
+        CommandFitnessFunction {
+            program: program.into(),
+            args: Vec::new(),
+            input: CommandInput::Stdin,
+            encoder: Arc::new(encoder),
+            batch_size: 1,
+            scale: DEFAULT_COMMAND_FITNESS_SCALE,
+            lowest_fitness: 0,
+            highest_fitness: i64::MAX,
+            pool: Arc::new(ProcessPool::new(max_processes)),
+            num_failures: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Sets the arguments the program is started with.
+    pub fn with_args<I, A>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<String>,
+    {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how the encoded genomes are passed to the program.
+    pub fn with_input(mut self, input: CommandInput) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the number of genomes that are written to the standard input of
+    /// one process by `fitness_of_all`.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Sets the maximum number of processes that run concurrently.
+    pub fn with_max_processes(mut self, max_processes: usize) -> Self {
+        self.pool = Arc::new(ProcessPool::new(max_processes.max(1)));
+        self
+    }
+
+    /// Sets the factor the parsed fitness values are multiplied with before
+    /// they are rounded to integers, e.g. `1000.` to keep three decimal
+    /// places.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the lowest and highest possible fitness values after scaling.
+    pub fn with_fitness_range(mut self, lowest: i64, highest: i64) -> Self {
+        self.lowest_fitness = lowest;
+        self.highest_fitness = highest;
+        self
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    pub fn input(&self) -> CommandInput {
+        self.input
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    pub fn max_processes(&self) -> usize {
+        self.pool.max_processes
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the number of evaluations that failed so far.
+    pub fn num_failures(&self) -> usize {
+        self.num_failures.load(Ordering::Relaxed)
+    }
+
+    /// Evaluates the given genomes by running the program and returns their
+    /// fitness values or the error that made the evaluation fail.
+    ///
+    /// In `CommandInput::Stdin` mode all genomes are written to one process.
+    /// In `CommandInput::Argument` mode one process per genome is started.
+    pub fn evaluate(&self, genomes: &[G]) -> Result<Vec<i64>, CommandError> {
+        match self.input {
+            CommandInput::Stdin => {
+                let input: String = genomes
+                    .iter()
+                    .map(|genome| (self.encoder)(genome) + "\n")
+                    .collect();
+                self.run(None, Some(input), genomes.len())
+            },
+            CommandInput::Argument => genomes
+                .iter()
+                .map(|genome| {
+                    self.run(Some((self.encoder)(genome)), None, 1)
+                        .map(|fitness| fitness[0])
+                })
+                .collect(),
+        }
+    }
+
+    /// Evaluates all given genomes in batches of `batch_size` genomes, with
+    /// up to `max_processes` batches at a time. Genomes whose evaluation
+    /// fails get the lowest possible fitness.
+    pub fn fitness_of_all(&self, genomes: &[G]) -> Vec<i64> {
+        let batch_size = match self.input {
+            CommandInput::Stdin => self.batch_size,
+            CommandInput::Argument => 1,
+        };
+        thread::scope(|scope| {
+            let batches: Vec<_> = genomes
+                .chunks(batch_size)
+                .map(|batch| scope.spawn(move || self.fitness_of_batch(batch)))
+                .collect();
+            batches
+                .into_iter()
+                .flat_map(|batch| batch.join().expect("evaluation of batch panicked"))
+                .collect()
+        })
+    }
+
+    fn fitness_of_batch(&self, genomes: &[G]) -> Vec<i64> {
+        self.evaluate(genomes).unwrap_or_else(|_| {
+            self.num_failures
+                .fetch_add(genomes.len(), Ordering::Relaxed);
+            vec![self.lowest_fitness; genomes.len()]
+        })
+    }
+
+    fn run(
+        &self,
+        argument: Option<String>,
+        input: Option<String>,
+        num_genomes: usize,
+    ) -> Result<Vec<i64>, CommandError> {
+        let _permit = self.pool.acquire();
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .args(argument)
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        let mut child = command
+            .spawn()
+            .map_err(|error| CommandError::Io(format!("{}: {}", self.program, error)))?;
+        // write on a separate thread, so that a program that prints its
+        // results before it has read all input can not block
+        let writer = match (input, child.stdin.take()) {
+            (Some(input), Some(mut stdin)) => {
+                Some(thread::spawn(move || stdin.write_all(input.as_bytes())))
+            },
+            _ => None,
+        };
+        let output = child
+            .wait_with_output()
+            .map_err(|error| CommandError::Io(error.to_string()))?;
+        if let Some(writer) = writer {
+            writer
+                .join()
+                .map_err(|_| CommandError::Io("writing to stdin panicked".to_string()))?
+                .map_err(|error| CommandError::Io(error.to_string()))?;
+        }
+        if !output.status.success() {
+            return Err(CommandError::Failed(format!(
+                "{} exited with {}",
+                self.program, output.status
+            )));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let fitness = stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| self.parse_fitness(line))
+            .collect::<Result<Vec<_>, _>>()?;
+        if fitness.len() != num_genomes {
+            return Err(CommandError::InvalidOutput(format!(
+                "expected {} fitness values but got {}",
+                num_genomes,
+                fitness.len()
+            )));
+        }
+        Ok(fitness)
+    }
+
+    fn parse_fitness(&self, line: &str) -> Result<i64, CommandError> {
+        let value: f64 = line
+            .parse()
+            .map_err(|_| CommandError::InvalidOutput(format!("not a number: {}", line)))?;
+        let scaled = (value * self.scale).round();
+        if scaled.is_nan() {
+            return Err(CommandError::InvalidOutput(format!(
+                "not a number: {}",
+                line
+            )));
+        }
+        // float to int casts saturate
+        Ok((scaled as i64).clamp(self.lowest_fitness, self.highest_fitness))
+    }
+}
+
+impl<G> Debug for CommandFitnessFunction<G>
+where
+    G: Genotype,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CommandFitnessFunction")
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .field("input", &self.input)
+            .field("batch_size", &self.batch_size)
+            .field("max_processes", &self.pool.max_processes)
+            .field("scale", &self.scale)
+            .field("lowest_fitness", &self.lowest_fitness)
+            .field("highest_fitness", &self.highest_fitness)
+            .finish()
+    }
+}
+
+impl<G> FitnessFunction<G, i64> for CommandFitnessFunction<G>
+where
+    G: Genotype,
+{
+    fn fitness_of(&self, genome: &G) -> i64 {
+        self.fitness_of_batch(std::slice::from_ref(genome))[0]
+    }
+
+    fn fitness_of_population(&self, population: &[G]) -> Vec<i64> {
+        self.fitness_of_all(population)
+    }
+
+    fn evaluates_population_at_once(&self) -> bool {
+        true
+    }
+
+    fn average(&self, fitness_values: &[i64]) -> i64 {
+        if fitness_values.is_empty() {
+            return 0;
+        }
+        let sum: i128 = fitness_values.iter().map(|value| *value as i128).sum();
+        (sum / fitness_values.len() as i128) as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        self.highest_fitness
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        self.lowest_fitness
+    }
+}
+
+/// Bounds the number of processes that run at the same time.
+#[derive(Debug)]
+struct ProcessPool {
+    max_processes: usize,
+    running: Mutex<usize>,
+    finished: Condvar,
+}
+
+impl ProcessPool {
+    fn new(max_processes: usize) -> Self {
+        ProcessPool {
+            max_processes,
+            running: Mutex::new(0),
+            finished: Condvar::new(),
+        }
+    }
+
+    fn acquire(&self) -> ProcessPermit<'_> {
+        let mut running = self.running.lock().unwrap_or_else(|p| p.into_inner());
+        while *running >= self.max_processes {
+            running = self
+                .finished
+                .wait(running)
+                .unwrap_or_else(|p| p.into_inner());
+        }
+        *running += 1;
+        ProcessPermit { pool: self }
+    }
+}
+
+struct ProcessPermit<'a> {
+    pool: &'a ProcessPool,
+}
+
+impl Drop for ProcessPermit<'_> {
+    fn drop(&mut self) {
+        let mut running = self.pool.running.lock().unwrap_or_else(|p| p.into_inner());
+        *running -= 1;
+        self.pool.finished.notify_one();
+    }
+}
@@ -0,0 +1,312 @@
+//! The `constraint` module provides support for constrained optimization.
+//!
+//! The constraints of a problem, e.g. the weight limit of a knapsack, are
+//! defined by a `ConstraintFunction` separately from the objective, which is
+//! calculated by the `genetic::FitnessFunction`. A genome that satisfies all
+//! constraints is feasible. There are two ways to combine the constraints
+//! with the objective:
+//! * `PenalizedFitness` subtracts a penalty proportional to the violation of
+//!   the constraints from the fitness. The penalty factor must be chosen so
+//!   that infeasible genomes do not outperform feasible ones.
+//! * `FeasibilityFirst` compares genomes by Deb's rules, which need no
+//!   penalty factor: a feasible genome is better than any infeasible one,
+//!   feasible genomes are compared by their fitness and infeasible genomes by
+//!   their violation of the constraints. The rules are implemented by the
+//!   order of the `Constrained` fitness values, so they are applied by all
+//!   selection and reinsertion operators that compare fitness values, e.g.
+//!   the `selection::tournament::TournamentSelector`.
+
+use crate::{
+    fitness::function::NumericFitness,
+    genetic::{Fitness, FitnessFunction, Genotype},
+};
+use std::cmp::Ordering;
+
+/// A `ConstraintFunction` measures how much a `genetic::Genotype` violates
+/// the constraints of a problem.
+pub trait ConstraintFunction<G>: Clone
+where
+    G: Genotype,
+{
+    /// Returns the violation of each constraint by the given genome. A
+    /// constraint is satisfied if its violation is 0 or less. The greater
+    /// the violation, the further the genome is from satisfying it.
+    fn violations_of(&self, genome: &G) -> Vec<f64>;
+
+    /// Returns the sum of the violations of the constraints that are not
+    /// satisfied by the given genome, or 0 if the genome is feasible. A
+    /// violation of NaN counts as infinite.
+    fn total_violation_of(&self, genome: &G) -> f64 {
+        self.violations_of(genome)
+            .into_iter()
+            .map(|violation| {
+                if violation.is_nan() {
+                    f64::INFINITY
+                } else {
+                    violation.max(0.)
+                }
+            })
+            .sum()
+    }
+
+    /// Returns whether the given genome satisfies all constraints.
+    fn is_feasible(&self, genome: &G) -> bool {
+        self.total_violation_of(genome) == 0.
+    }
+}
+
+/// The `PenalizedFitness` is a `genetic::FitnessFunction` that subtracts the
+/// total violation of the constraints multiplied by the penalty factor from
+/// the fitness calculated by the wrapped fitness function.
+///
+/// Feasible genomes keep their fitness. The penalized fitness of infeasible
+/// genomes may be lower than the lowest possible fitness of the wrapped
+/// fitness function, so the lowest possible fitness is the smallest value of
+/// the fitness type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PenalizedFitness<E, C> {
+    evaluator: E,
+    constraints: C,
+    penalty_factor: f64,
+}
+
+impl<E, C> PenalizedFitness<E, C> {
+    /// Constructs a new `PenalizedFitness` that penalizes the fitness
+    /// calculated by the given evaluator with the violation of the given
+    /// constraints multiplied by the penalty factor.
+    pub fn new(evaluator: E, constraints: C, penalty_factor: f64) -> Self {
+        PenalizedFitness {
+            evaluator,
+            constraints,
+            penalty_factor,
+        }
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the constraints.
+    pub fn constraints(&self) -> &C {
+        &self.constraints
+    }
+
+    /// Returns the factor the violation of the constraints is multiplied by.
+    pub fn penalty_factor(&self) -> f64 {
+        self.penalty_factor
+    }
+
+    /// Sets the factor the violation of the constraints is multiplied by.
+    pub fn set_penalty_factor(&mut self, value: f64) {
+        self.penalty_factor = value;
+    }
+}
+
+impl<G, F, E, C> FitnessFunction<G, F> for PenalizedFitness<E, C>
+where
+    G: Genotype,
+    F: NumericFitness,
+    E: FitnessFunction<G, F>,
+    C: ConstraintFunction<G>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        let fitness = self.evaluator.fitness_of(genome);
+        let violation = self.constraints.total_violation_of(genome);
+        if violation == 0. {
+            fitness
+        } else {
+            F::from_f64(fitness.as_f64() - self.penalty_factor * violation)
+        }
+    }
+
+    fn average(&self, fitness_values: &[F]) -> F {
+        self.evaluator.average(fitness_values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.evaluator.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        F::min_value()
+    }
+}
+
+/// A `Constrained` fitness value is the fitness of a feasible genome or the
+/// total violation of the constraints by an infeasible genome.
+///
+/// `Constrained` values are ordered by Deb's rules: any `Feasible` value is
+/// greater than any `Infeasible` value, `Feasible` values are ordered by
+/// their fitness and `Infeasible` values are ordered by their violation,
+/// where the smaller violation is the greater value.
+#[derive(Clone, Debug)]
+pub enum Constrained<F> {
+    /// The fitness of a feasible genome.
+    Feasible(F),
+    /// The total violation of the constraints by an infeasible genome.
+    Infeasible(f64),
+}
+
+impl<F> Constrained<F> {
+    /// Returns whether this is the value of a feasible genome.
+    pub fn is_feasible(&self) -> bool {
+        matches!(self, Constrained::Feasible(_))
+    }
+
+    /// Returns the fitness of a feasible genome, or `None` if the genome is
+    /// infeasible.
+    pub fn fitness(&self) -> Option<&F> {
+        match self {
+            Constrained::Feasible(fitness) => Some(fitness),
+            Constrained::Infeasible(_) => None,
+        }
+    }
+
+    /// Returns the total violation of the constraints, which is 0 for a
+    /// feasible genome.
+    pub fn violation(&self) -> f64 {
+        match self {
+            Constrained::Feasible(_) => 0.,
+            Constrained::Infeasible(violation) => *violation,
+        }
+    }
+}
+
+impl<F> PartialEq for Constrained<F>
+where
+    F: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F> Eq for Constrained<F> where F: Ord {}
+
+impl<F> PartialOrd for Constrained<F>
+where
+    F: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F> Ord for Constrained<F>
+where
+    F: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Constrained::Feasible(fitness), Constrained::Feasible(other_fitness)) => {
+                fitness.cmp(other_fitness)
+            },
+            (Constrained::Feasible(_), Constrained::Infeasible(_)) => Ordering::Greater,
+            (Constrained::Infeasible(_), Constrained::Feasible(_)) => Ordering::Less,
+            (Constrained::Infeasible(violation), Constrained::Infeasible(other_violation)) => {
+                other_violation.total_cmp(violation)
+            },
+        }
+    }
+}
+
+impl<F> Fitness for Constrained<F>
+where
+    F: Fitness,
+{
+    fn zero() -> Self {
+        Constrained::Feasible(F::zero())
+    }
+
+    /// Returns the absolute difference of the fitness values if both values
+    /// are feasible, otherwise the absolute difference of the violations.
+    fn abs_diff(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Constrained::Feasible(fitness), Constrained::Feasible(other_fitness)) => {
+                Constrained::Feasible(fitness.abs_diff(other_fitness))
+            },
+            _ => Constrained::Infeasible((self.violation() - other.violation()).abs()),
+        }
+    }
+}
+
+/// The `FeasibilityFirst` is a `genetic::FitnessFunction` that compares
+/// genomes by Deb's rules. It calculates `Constrained` fitness values: the
+/// fitness calculated by the wrapped fitness function for feasible genomes
+/// and the total violation of the constraints for infeasible genomes.
+///
+/// The fitness of infeasible genomes is not calculated at all, which saves
+/// the costs of the wrapped fitness function for them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeasibilityFirst<E, C> {
+    evaluator: E,
+    constraints: C,
+}
+
+impl<E, C> FeasibilityFirst<E, C> {
+    /// Constructs a new `FeasibilityFirst` that compares genomes by the
+    /// given constraints first and by the fitness calculated by the given
+    /// evaluator second.
+    pub fn new(evaluator: E, constraints: C) -> Self {
+        FeasibilityFirst {
+            evaluator,
+            constraints,
+        }
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the constraints.
+    pub fn constraints(&self) -> &C {
+        &self.constraints
+    }
+}
+
+impl<G, F, E, C> FitnessFunction<G, Constrained<F>> for FeasibilityFirst<E, C>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    C: ConstraintFunction<G>,
+{
+    fn fitness_of(&self, genome: &G) -> Constrained<F> {
+        let violation = self.constraints.total_violation_of(genome);
+        if violation == 0. {
+            Constrained::Feasible(self.evaluator.fitness_of(genome))
+        } else {
+            Constrained::Infeasible(violation)
+        }
+    }
+
+    /// Returns the average fitness calculated by the wrapped fitness
+    /// function if all values are feasible, otherwise the average violation
+    /// of all values.
+    fn average(&self, fitness_values: &[Constrained<F>]) -> Constrained<F> {
+        let feasible: Option<Vec<F>> = fitness_values
+            .iter()
+            .map(|value| value.fitness().cloned())
+            .collect();
+        match feasible {
+            Some(feasible) => Constrained::Feasible(self.evaluator.average(&feasible)),
+            None => Constrained::Infeasible(
+                fitness_values
+                    .iter()
+                    .map(Constrained::violation)
+                    .sum::<f64>()
+                    / fitness_values.len() as f64,
+            ),
+        }
+    }
+
+    fn highest_possible_fitness(&self) -> Constrained<F> {
+        Constrained::Feasible(self.evaluator.highest_possible_fitness())
+    }
+
+    fn lowest_possible_fitness(&self) -> Constrained<F> {
+        Constrained::Infeasible(f64::INFINITY)
+    }
+}
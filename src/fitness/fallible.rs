@@ -0,0 +1,61 @@
+//! The `fallible` module provides the `FallibleFitness`, which makes a
+//! `genetic::TryFitnessFunction` usable in an algorithm.
+//!
+//! The errors of the wrapped fitness function are passed on to the
+//! algorithm, which handles them according to its
+//! `ga::EvaluationErrorPolicy`, e.g. by assigning the lowest possible fitness
+//! or by discarding the genome.
+
+use crate::genetic::{EvalError, Fitness, FitnessFunction, Genotype, TryFitnessFunction};
+
+/// The `FallibleFitness` is a `genetic::FitnessFunction` that wraps a
+/// `genetic::TryFitnessFunction`.
+///
+/// Its `fitness_of` function returns the lowest possible fitness for genomes
+/// whose evaluation fails, while `try_fitness_of` passes the `EvalError` on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FallibleFitness<T> {
+    evaluator: T,
+}
+
+impl<T> FallibleFitness<T> {
+    /// Constructs a new `FallibleFitness` that wraps the given fitness
+    /// function.
+    pub fn new(evaluator: T) -> Self {
+        FallibleFitness { evaluator }
+    }
+
+    /// Returns the wrapped fitness function.
+    pub fn evaluator(&self) -> &T {
+        &self.evaluator
+    }
+}
+
+impl<G, F, T> FitnessFunction<G, F> for FallibleFitness<T>
+where
+    G: Genotype,
+    F: Fitness,
+    T: TryFitnessFunction<G, F>,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        self.evaluator
+            .try_fitness_of(genome)
+            .unwrap_or_else(|_| self.evaluator.lowest_possible_fitness())
+    }
+
+    fn try_fitness_of(&self, genome: &G) -> Result<F, EvalError> {
+        self.evaluator.try_fitness_of(genome)
+    }
+
+    fn average(&self, fitness_values: &[F]) -> F {
+        self.evaluator.average(fitness_values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.evaluator.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.evaluator.lowest_possible_fitness()
+    }
+}
@@ -0,0 +1,199 @@
+//! The `function` module provides the `FnFitnessFunction`, a
+//! `genetic::FitnessFunction` that is constructed from a closure.
+//!
+//! Implementing the `genetic::FitnessFunction` trait requires the average and
+//! the bounds of the fitness values besides the fitness calculation itself.
+//! For quick experiments and tests the `FnFitnessFunction` only needs the
+//! closure: the average is the arithmetic mean and the bounds default to the
+//! smallest and largest value of the fitness type, as provided by the
+//! `NumericFitness` trait.
+//!
+//! ```
+//! use genevo::{fitness::function::FnFitnessFunction, prelude::*};
+//!
+//! let evaluator = FnFitnessFunction::new(|genome: &Vec<i32>| genome.iter().sum::<i32>())
+//!     .with_bounds(0, 100);
+//!
+//! assert_eq!(evaluator.fitness_of(&vec![20, 30]), 50);
+//! assert_eq!(evaluator.fitness_of(&vec![70, 80]), 100);
+//! assert_eq!(evaluator.average(&[10, 20, 60]), 30);
+//! assert_eq!(evaluator.highest_possible_fitness(), 100);
+//! ```
+
+use crate::{
+    genetic::{Fitness, FitnessFunction, Genotype},
+    types::{FitnessF32, FitnessF64},
+};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// A `NumericFitness` is a `genetic::Fitness` value of a primitive numeric
+/// type, which provides the defaults of the `FnFitnessFunction`.
+pub trait NumericFitness: Fitness {
+    /// Returns the arithmetic mean of the given values, or 0 if there are
+    /// no values.
+    fn mean(values: &[Self]) -> Self;
+
+    /// Returns the smallest value of this type.
+    fn min_value() -> Self;
+
+    /// Returns the largest value of this type.
+    fn max_value() -> Self;
+
+    /// Converts the given float into a value of this type. Fractions are
+    /// truncated and values out of range saturate at the bounds of the type.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts this value into a float.
+    fn as_f64(&self) -> f64;
+}
+
+macro_rules! implement_numeric_fitness_for_integer {
+    ( $($t:ty),* ) => {
+        $(
+            impl NumericFitness for $t {
+                fn mean(values: &[$t]) -> $t {
+                    if values.is_empty() {
+                        return 0;
+                    }
+                    let sum: i128 = values.iter().map(|value| *value as i128).sum();
+                    (sum / values.len() as i128) as $t
+                }
+
+                fn min_value() -> $t {
+                    <$t>::MIN
+                }
+
+                fn max_value() -> $t {
+                    <$t>::MAX
+                }
+
+                fn from_f64(value: f64) -> $t {
+                    value as $t
+                }
+
+                fn as_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    }
+}
+
+implement_numeric_fitness_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! implement_numeric_fitness_for_float {
+    ( $($name:ident: $t:ty),* ) => {
+        $(
+            // the casts are trivial for f64
+            #[allow(trivial_numeric_casts)]
+            impl NumericFitness for $name {
+                fn mean(values: &[$name]) -> $name {
+                    if values.is_empty() {
+                        return $name::new(0.);
+                    }
+                    let sum: f64 = values.iter().map(|value| f64::from(value.value())).sum();
+                    $name::new((sum / values.len() as f64) as $t)
+                }
+
+                fn min_value() -> $name {
+                    $name::new(<$t>::MIN)
+                }
+
+                fn max_value() -> $name {
+                    $name::new(<$t>::MAX)
+                }
+
+                fn from_f64(value: f64) -> $name {
+                    $name::new(value as $t)
+                }
+
+                fn as_f64(&self) -> f64 {
+                    f64::from(self.value())
+                }
+            }
+        )*
+    }
+}
+
+implement_numeric_fitness_for_float!(FitnessF32: f32, FitnessF64: f64);
+
+/// The `FnFitnessFunction` calculates the fitness of a genome by calling a
+/// closure. The fitness values are clamped to the bounds set by
+/// `with_bounds`, which default to the smallest and the largest value of the
+/// fitness type.
+#[derive(Clone)]
+pub struct FnFitnessFunction<G, F, C>
+where
+    G: Genotype,
+    F: NumericFitness,
+    C: Fn(&G) -> F + Clone,
+{
+    _g: PhantomData<G>,
+    function: C,
+    lowest_fitness: F,
+    highest_fitness: F,
+}
+
+impl<G, F, C> FnFitnessFunction<G, F, C>
+where
+    G: Genotype,
+    F: NumericFitness,
+    C: Fn(&G) -> F + Clone,
+{
+    /// Constructs a new `FnFitnessFunction` that calls the given closure.
+    pub fn new(function: C) -> Self {
+        FnFitnessFunction {
+            _g: PhantomData,
+            function,
+            lowest_fitness: F::min_value(),
+            highest_fitness: F::max_value(),
+        }
+    }
+
+    /// Sets the lowest and the highest possible fitness value.
+    pub fn with_bounds(mut self, lowest_fitness: F, highest_fitness: F) -> Self {
+        self.lowest_fitness = lowest_fitness;
+        self.highest_fitness = highest_fitness;
+        self
+    }
+}
+
+impl<G, F, C> Debug for FnFitnessFunction<G, F, C>
+where
+    G: Genotype,
+    F: NumericFitness,
+    C: Fn(&G) -> F + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FnFitnessFunction")
+            .field("lowest_fitness", &self.lowest_fitness)
+            .field("highest_fitness", &self.highest_fitness)
+            .finish()
+    }
+}
+
+impl<G, F, C> FitnessFunction<G, F> for FnFitnessFunction<G, F, C>
+where
+    G: Genotype,
+    F: NumericFitness,
+    C: Fn(&G) -> F + Clone,
+{
+    fn fitness_of(&self, genome: &G) -> F {
+        (self.function)(genome).clamp(self.lowest_fitness.clone(), self.highest_fitness.clone())
+    }
+
+    fn average(&self, fitness_values: &[F]) -> F {
+        F::mean(fitness_values)
+    }
+
+    fn highest_possible_fitness(&self) -> F {
+        self.highest_fitness.clone()
+    }
+
+    fn lowest_possible_fitness(&self) -> F {
+        self.lowest_fitness.clone()
+    }
+}
@@ -0,0 +1,31 @@
+//! The `fitness` module provides implementations of the
+//! `genetic::FitnessFunction` that can be used on their own or that wrap
+//! problem specific fitness functions.
+//!
+//! The provided implementations of the `genetic::FitnessFunction` are:
+//! * `novelty::NoveltyFitness`
+//! * `callback::CallbackFitnessFunction`
+//! * `cached::CachedFitnessFunction`
+//! * `function::FnFitnessFunction`
+//! * `constraint::PenalizedFitness`
+//! * `constraint::FeasibilityFirst`
+//! * `fallible::FallibleFitness`
+//! * `command::CommandFitnessFunction` (with crate feature `command`)
+
+pub mod novelty;
+
+pub mod callback;
+
+pub mod cached;
+
+pub mod function;
+
+pub mod constraint;
+
+pub mod fallible;
+
+#[cfg(feature = "command")]
+pub mod command;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,313 @@
+//! The `novelty` module provides support for novelty search.
+//!
+//! Instead of rewarding the individuals that perform best on the objective
+//! of the problem, novelty search rewards the individuals whose behavior
+//! differs the most from the behavior seen so far. This helps on deceptive
+//! problems where following the objective fitness leads the population into
+//! local optima.
+//!
+//! The behavior of an individual is described by a vector of numbers which is
+//! calculated by a user supplied `BehaviorDescriptor`. The `NoveltyFitness`
+//! scores each individual by the average distance of its behavior to the
+//! behaviors of its nearest neighbors in the `NoveltyArchive`. The archive is
+//! a hall of fame of the most novel individuals found so far. It is updated
+//! by the algorithm after each generation has been evaluated.
+
+use crate::genetic::{FitnessFunction, Genotype};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// The default factor by which novelty scores are multiplied before they are
+/// converted into integer `genetic::Fitness` values.
+pub const DEFAULT_NOVELTY_SCALE: f64 = 1_000.;
+
+/// A `BehaviorDescriptor` characterizes the behavior of an individual, e.g.
+/// the final position of a robot in a maze, by a vector of numbers.
+pub trait BehaviorDescriptor<G>: Clone + Debug
+where
+    G: Genotype,
+{
+    /// Calculates the behavior of the given `genetic::Genotype`.
+    ///
+    /// All behaviors calculated by one `BehaviorDescriptor` must have the
+    /// same number of dimensions.
+    fn behavior_of(&self, genome: &G) -> Vec<f64>;
+}
+
+/// An individual that has been added to the `NoveltyArchive` together with
+/// its behavior.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArchivedIndividual<G>
+where
+    G: Genotype,
+{
+    /// The genome of the individual.
+    pub genome: G,
+    /// The behavior of the individual as calculated by the
+    /// `BehaviorDescriptor`.
+    pub behavior: Vec<f64>,
+}
+
+/// The `NoveltyArchive` holds the individuals whose behavior was novel at the
+/// time they were evaluated. When the archive is full the individual that
+/// has been added first is removed.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NoveltyArchive<G>
+where
+    G: Genotype,
+{
+    capacity: usize,
+    individuals: VecDeque<ArchivedIndividual<G>>,
+}
+
+impl<G> NoveltyArchive<G>
+where
+    G: Genotype,
+{
+    /// Constructs a new empty `NoveltyArchive` that holds up to `capacity`
+    /// individuals.
+    pub fn new(capacity: usize) -> Self {
+        NoveltyArchive {
+            capacity,
+            individuals: VecDeque::new(),
+        }
+    }
+
+    /// Returns the maximum number of individuals in this archive.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of individuals in this archive.
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    /// Returns whether this archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+
+    /// Returns an iterator over the individuals in this archive in the order
+    /// they have been added.
+    pub fn iter(&self) -> impl Iterator<Item = &ArchivedIndividual<G>> {
+        self.individuals.iter()
+    }
+
+    /// Adds an individual to this archive. Individuals with a behavior that
+    /// is already contained in the archive are not added.
+    ///
+    /// Returns whether the individual has been added.
+    pub fn add(&mut self, genome: G, behavior: Vec<f64>) -> bool {
+        if self.capacity == 0 || self.individuals.iter().any(|a| a.behavior == behavior) {
+            return false;
+        }
+        if self.individuals.len() >= self.capacity {
+            self.individuals.pop_front();
+        }
+        self.individuals
+            .push_back(ArchivedIndividual { genome, behavior });
+        true
+    }
+
+    /// Removes all individuals from this archive.
+    pub fn clear(&mut self) {
+        self.individuals.clear();
+    }
+
+    /// Calculates the novelty of the given behavior, which is the average
+    /// distance to the `num_neighbors` nearest behaviors in this archive.
+    ///
+    /// Returns infinity if the archive is empty, as any behavior is novel
+    /// then.
+    pub fn novelty_of(&self, behavior: &[f64], num_neighbors: usize) -> f64 {
+        if self.individuals.is_empty() {
+            return f64::INFINITY;
+        }
+        let mut distances: Vec<f64> = self
+            .individuals
+            .iter()
+            .map(|archived| euclidean_distance(&archived.behavior, behavior))
+            .collect();
+        distances.sort_by(|a, b| a.partial_cmp(b).expect("behaviors must not contain NaN"));
+        let k = num_neighbors.max(1).min(distances.len());
+        distances[..k].iter().sum::<f64>() / k as f64
+    }
+}
+
+/// The `NoveltyFitness` is a `genetic::FitnessFunction` that scores
+/// individuals by the novelty of their behavior. The novelty is the average
+/// distance of the behavior of an individual to the behaviors of its
+/// `num_neighbors` nearest neighbors in the `NoveltyArchive`.
+///
+/// The novelty is multiplied by the `scale` factor and rounded to get an
+/// integer `genetic::Fitness` value. Individuals of a generation whose
+/// novelty is greater than or equal to the `add_threshold` are added to the
+/// archive after the generation has been evaluated.
+///
+/// Clones of a `NoveltyFitness` share the same archive. Thus operators that
+/// hold their own copy of the fitness function, like the
+/// `reinsertion::elitist::ElitistReinserter`, score individuals against the
+/// same archive as the algorithm does.
+#[derive(Clone, Debug)]
+pub struct NoveltyFitness<G, D>
+where
+    G: Genotype,
+    D: BehaviorDescriptor<G>,
+{
+    descriptor: D,
+    num_neighbors: usize,
+    add_threshold: f64,
+    scale: f64,
+    archive: Arc<RwLock<NoveltyArchive<G>>>,
+}
+
+impl<G, D> NoveltyFitness<G, D>
+where
+    G: Genotype,
+    D: BehaviorDescriptor<G>,
+{
+    /// Constructs a new instance of the `NoveltyFitness` with an empty
+    /// archive that holds up to `archive_capacity` individuals.
+    pub fn new(
+        descriptor: D,
+        num_neighbors: usize,
+        add_threshold: f64,
+        archive_capacity: usize,
+    ) -> Self {
+        NoveltyFitness {
+            descriptor,
+            num_neighbors,
+            add_threshold,
+            scale: DEFAULT_NOVELTY_SCALE,
+            archive: Arc::new(RwLock::new(NoveltyArchive::new(archive_capacity))),
+        }
+    }
+
+    /// Replaces the archive with the given one, e.g. an archive that has been
+    /// loaded from a checkpoint. Clones of this fitness function share the
+    /// given archive as well.
+    pub fn with_archive(self, archive: NoveltyArchive<G>) -> Self {
+        *self.write_archive() = archive;
+        self
+    }
+
+    /// Sets the factor by which the novelty is multiplied before it is
+    /// converted into a `genetic::Fitness` value.
+    pub fn with_scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Returns the `BehaviorDescriptor` used by this fitness function.
+    pub fn descriptor(&self) -> &D {
+        &self.descriptor
+    }
+
+    /// Returns the number of nearest neighbors used to calculate the novelty.
+    pub fn num_neighbors(&self) -> usize {
+        self.num_neighbors
+    }
+
+    /// Returns the minimum novelty an individual must have to be added to
+    /// the archive.
+    pub fn add_threshold(&self) -> f64 {
+        self.add_threshold
+    }
+
+    /// Returns the factor by which the novelty is multiplied before it is
+    /// converted into a `genetic::Fitness` value.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns a snapshot of the current archive.
+    pub fn archive(&self) -> NoveltyArchive<G> {
+        self.read_archive().clone()
+    }
+
+    /// Returns the number of individuals in the archive.
+    pub fn archive_len(&self) -> usize {
+        self.read_archive().len()
+    }
+
+    /// Removes all individuals from the archive.
+    pub fn clear_archive(&self) {
+        self.write_archive().clear();
+    }
+
+    /// Calculates the novelty of the given genome against the current
+    /// archive without converting it into a `genetic::Fitness` value.
+    pub fn novelty_of(&self, genome: &G) -> f64 {
+        let behavior = self.descriptor.behavior_of(genome);
+        self.read_archive()
+            .novelty_of(&behavior, self.num_neighbors)
+    }
+
+    fn read_archive(&self) -> RwLockReadGuard<'_, NoveltyArchive<G>> {
+        // the archive is only modified as a whole, so it is consistent even
+        // if the lock has been poisoned
+        self.archive
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write_archive(&self) -> RwLockWriteGuard<'_, NoveltyArchive<G>> {
+        self.archive
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<G, D> FitnessFunction<G, u64> for NoveltyFitness<G, D>
+where
+    G: Genotype,
+    D: BehaviorDescriptor<G>,
+{
+    fn fitness_of(&self, genome: &G) -> u64 {
+        // casting an infinite novelty saturates at `u64::MAX`
+        (self.novelty_of(genome) * self.scale).round() as u64
+    }
+
+    fn average(&self, fitness_values: &[u64]) -> u64 {
+        if fitness_values.is_empty() {
+            return 0;
+        }
+        let sum: u128 = fitness_values.iter().map(|value| *value as u128).sum();
+        (sum / fitness_values.len() as u128) as u64
+    }
+
+    fn highest_possible_fitness(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn lowest_possible_fitness(&self) -> u64 {
+        0
+    }
+
+    fn population_evaluated(&mut self, population: &[G], fitness_values: &[u64]) {
+        let threshold = self.add_threshold * self.scale;
+        let mut archive = self.write_archive();
+        for (genome, fitness) in population.iter().zip(fitness_values) {
+            if *fitness as f64 >= threshold {
+                archive.add(genome.clone(), self.descriptor.behavior_of(genome));
+            }
+        }
+    }
+}
+
+/// Calculates the euclidean distance between two behaviors.
+pub fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
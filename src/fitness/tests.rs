@@ -0,0 +1,552 @@
+use super::novelty::*;
+use crate::genetic::FitnessFunction;
+use galvanic_assert::matchers::*;
+
+#[derive(Clone, Debug)]
+struct FirstTwoGenes;
+
+impl BehaviorDescriptor<Vec<i32>> for FirstTwoGenes {
+    fn behavior_of(&self, genome: &Vec<i32>) -> Vec<f64> {
+        vec![genome[0] as f64, genome[1] as f64]
+    }
+}
+
+mod novelty_fitness {
+
+    use super::*;
+
+    #[test]
+    fn any_genome_is_maximally_novel_for_an_empty_archive() {
+        let fitness = NoveltyFitness::new(FirstTwoGenes, 2, 1., 10);
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2]), eq(u64::MAX));
+    }
+
+    #[test]
+    fn novel_individuals_are_added_to_the_archive_after_evaluation() {
+        let mut fitness = NoveltyFitness::new(FirstTwoGenes, 2, 2., 10);
+        let population = vec![vec![0, 0], vec![3, 4], vec![1, 1]];
+        let fitness_values = vec![3_000, 2_500, 1_000];
+
+        fitness.population_evaluated(&population, &fitness_values);
+
+        let archived: Vec<Vec<i32>> = fitness.archive().iter().map(|a| a.genome.clone()).collect();
+        expect_that!(&archived, eq(vec![vec![0, 0], vec![3, 4]]));
+    }
+
+    #[test]
+    fn fitness_is_average_distance_to_nearest_neighbors_in_archive() {
+        let mut fitness = NoveltyFitness::new(FirstTwoGenes, 2, 0., 10);
+        let population = vec![vec![0, 0], vec![3, 4], vec![30, 40]];
+        fitness.population_evaluated(&population, &[0, 0, 0]);
+
+        // distances to [0, 0] and [3, 4] are 0 and 5
+        expect_that!(&fitness.fitness_of(&vec![0, 0]), eq(2_500));
+        expect_that!(&fitness.novelty_of(&vec![0, 0]), eq(2.5));
+    }
+
+    #[test]
+    fn full_archive_drops_the_oldest_individual() {
+        let mut fitness = NoveltyFitness::new(FirstTwoGenes, 1, 0., 2);
+        let population = vec![vec![0, 0], vec![1, 1], vec![2, 2]];
+
+        fitness.population_evaluated(&population, &[0, 0, 0]);
+
+        let archived: Vec<Vec<i32>> = fitness.archive().iter().map(|a| a.genome.clone()).collect();
+        expect_that!(&archived, eq(vec![vec![1, 1], vec![2, 2]]));
+    }
+
+    #[test]
+    fn clones_share_the_archive() {
+        let mut fitness = NoveltyFitness::new(FirstTwoGenes, 1, 0., 10);
+        let shared = fitness.clone();
+
+        fitness.population_evaluated(&[vec![1, 2]], &[0]);
+
+        expect_that!(&shared.archive_len(), eq(1));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod novelty_archive_checkpoint {
+
+    use super::*;
+
+    #[test]
+    fn restored_archive_is_shared_with_clones() {
+        let mut original = NoveltyFitness::new(FirstTwoGenes, 1, 0., 10);
+        original.population_evaluated(&[vec![1, 2], vec![3, 4]], &[0, 0]);
+        let json = serde_json::to_string(&original.archive()).unwrap();
+
+        let archive: NoveltyArchive<Vec<i32>> = serde_json::from_str(&json).unwrap();
+        let restored = NoveltyFitness::new(FirstTwoGenes, 1, 0., 10).with_archive(archive);
+        let shared = restored.clone();
+
+        expect_that!(&shared.archive(), eq(original.archive()));
+        expect_that!(&restored.novelty_of(&vec![1, 2]), eq(0.));
+    }
+}
+
+#[cfg(all(feature = "command", unix))]
+mod command_fitness_function {
+
+    use super::*;
+    use crate::fitness::command::*;
+
+    fn sum_of_values() -> CommandFitnessFunction<Vec<i32>> {
+        CommandFitnessFunction::new("awk", |genome: &Vec<i32>| {
+            genome
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .with_args(["{ s = 0; for (i = 1; i <= NF; i++) s += $i; print s / 2 }"])
+    }
+
+    #[test]
+    fn parses_the_fitness_from_the_output_of_the_program() {
+        let fitness = sum_of_values().with_scale(10.);
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2, 4]), eq(35));
+    }
+
+    #[test]
+    fn evaluates_batches_of_genomes_in_input_order() {
+        let fitness = sum_of_values().with_batch_size(2).with_max_processes(2);
+        let genomes = vec![vec![2, 2], vec![10], vec![0, 0, 6], vec![8, 8, 8], vec![4]];
+
+        expect_that!(&fitness.fitness_of_all(&genomes), eq(vec![2, 5, 3, 12, 2]));
+        expect_that!(&fitness.num_failures(), eq(0));
+    }
+
+    #[test]
+    fn passes_genomes_as_argument() {
+        let fitness =
+            CommandFitnessFunction::new("echo", |genome: &Vec<i32>| genome.len().to_string())
+                .with_input(CommandInput::Argument);
+
+        expect_that!(
+            &fitness.fitness_of_all(&[vec![1], vec![1, 2, 3]]),
+            eq(vec![1, 3])
+        );
+    }
+
+    #[test]
+    fn failed_evaluations_get_the_lowest_possible_fitness() {
+        let fitness = CommandFitnessFunction::new("false", |_: &Vec<i32>| String::new())
+            .with_fitness_range(-1, 100);
+
+        expect_that!(&fitness.fitness_of(&vec![1]), eq(-1));
+        expect_that!(&fitness.num_failures(), eq(1));
+        expect_that!(&fitness.evaluate(&[vec![1]]).is_err(), eq(true));
+    }
+
+    #[test]
+    fn output_that_is_not_a_number_is_an_error() {
+        let fitness = CommandFitnessFunction::new("echo", |_: &Vec<i32>| String::new())
+            .with_args(["fast"])
+            .with_input(CommandInput::Argument);
+
+        expect_that!(
+            &fitness.evaluate(&[vec![1]]),
+            eq(Err(CommandError::InvalidOutput(
+                "not a number: fast".to_string()
+            )))
+        );
+    }
+}
+
+mod callback_fitness_function {
+
+    use super::*;
+    use crate::fitness::callback::CallbackFitnessFunction;
+
+    #[test]
+    fn fitness_is_calculated_by_the_callback_and_kept_within_range() {
+        let fitness = CallbackFitnessFunction::new(
+            |genome: &Vec<i32>| genome.iter().sum::<i32>() as i64,
+            0,
+            10,
+        );
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2, 3]), eq(6));
+        expect_that!(&fitness.fitness_of(&vec![7, 8]), eq(10));
+        expect_that!(&fitness.fitness_of(&vec![-5]), eq(0));
+    }
+
+    #[test]
+    fn average_of_large_fitness_values_does_not_overflow() {
+        let fitness = CallbackFitnessFunction::new(|_: &Vec<i32>| 0, 0, i64::MAX);
+
+        expect_that!(
+            &fitness.average(&[i64::MAX, i64::MAX - 2]),
+            eq(i64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn clones_share_the_callback() {
+        let fitness = CallbackFitnessFunction::new(|_: &Vec<i32>| 1, 0, 1);
+        let other = CallbackFitnessFunction::new(|_: &Vec<i32>| 1, 0, 1);
+
+        expect_that!(&fitness.clone(), eq(fitness.clone()));
+        expect_that!(&(fitness == other), eq(false));
+    }
+}
+
+mod cached_fitness_function {
+
+    use super::*;
+    use crate::{
+        algorithm::Algorithm,
+        fitness::{cached::CachedFitnessFunction, callback::CallbackFitnessFunction},
+        ga::genetic_algorithm,
+        mutation::value::RandomValueMutator,
+        population::build_population,
+        population::ValueEncodedGenomeBuilder,
+        random::get_rng,
+        recombination::discrete::UniformCrossBreeder,
+        reinsertion::elitist::ElitistReinserter,
+        selection::truncation::MaximizeSelector,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    fn counting_sum_of_values(evaluations: Arc<AtomicUsize>) -> CallbackFitnessFunction<Vec<i32>> {
+        CallbackFitnessFunction::new(
+            move |genome: &Vec<i32>| {
+                evaluations.fetch_add(1, Ordering::SeqCst);
+                genome.iter().sum::<i32>() as i64
+            },
+            0,
+            1_000,
+        )
+    }
+
+    #[test]
+    fn fitness_of_a_genome_is_calculated_only_once() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let fitness = CachedFitnessFunction::new(counting_sum_of_values(evaluations.clone()), 10);
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2, 3]), eq(6));
+        expect_that!(&fitness.clone().fitness_of(&vec![1, 2, 3]), eq(6));
+        expect_that!(&fitness.fitness_of(&vec![4]), eq(4));
+
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(2));
+        expect_that!(&fitness.hits(), eq(1));
+        expect_that!(&fitness.misses(), eq(2));
+        expect_that!(&fitness.len(), eq(2));
+    }
+
+    #[test]
+    fn least_recently_used_fitness_is_evicted_when_full() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let fitness = CachedFitnessFunction::new(counting_sum_of_values(evaluations.clone()), 2);
+
+        fitness.fitness_of(&vec![1]);
+        fitness.fitness_of(&vec![2]);
+        fitness.fitness_of(&vec![1]);
+        fitness.fitness_of(&vec![3]);
+
+        expect_that!(&fitness.cached_fitness_of(&vec![1]), eq(Some(1)));
+        expect_that!(&fitness.cached_fitness_of(&vec![2]), eq(None));
+        expect_that!(&fitness.cached_fitness_of(&vec![3]), eq(Some(3)));
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(3));
+    }
+
+    #[test]
+    fn fitness_of_population_only_calculates_uncached_genomes() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let fitness = CachedFitnessFunction::new(counting_sum_of_values(evaluations.clone()), 10);
+        fitness.fitness_of(&vec![2]);
+
+        let fitness_values = fitness.fitness_of_population(&[vec![1], vec![2], vec![3]]);
+
+        expect_that!(&fitness_values, eq(vec![1, 2, 3]));
+        expect_that!(&evaluations.load(Ordering::SeqCst), eq(3));
+        expect_that!(&fitness.hits(), eq(1));
+        expect_that!(&fitness.len(), eq(3));
+    }
+
+    #[test]
+    fn clear_removes_all_fitness_values_and_resets_the_counters() {
+        let fitness = CachedFitnessFunction::new(counting_sum_of_values(Arc::default()), 2);
+        fitness.fitness_of(&vec![1]);
+        fitness.fitness_of(&vec![1]);
+
+        fitness.clear();
+
+        expect_that!(&fitness.is_empty(), eq(true));
+        expect_that!(&fitness.hits(), eq(0));
+        expect_that!(&fitness.misses(), eq(0));
+    }
+
+    #[test]
+    fn genetic_algorithm_does_not_evaluate_cached_genomes_again() {
+        let evaluations = Arc::new(AtomicUsize::new(0));
+        let fitness =
+            CachedFitnessFunction::new(counting_sum_of_values(evaluations.clone()), 1_000);
+        let population = build_population()
+            .with_genome_builder(ValueEncodedGenomeBuilder::new(4, 0, 10))
+            .of_size(20)
+            .using_seed([3; 32]);
+        let mut algorithm = genetic_algorithm()
+            .with_evaluation(fitness.clone())
+            .with_selection(MaximizeSelector::new(0.5, 2))
+            .with_crossover(UniformCrossBreeder::new())
+            .with_mutation(RandomValueMutator::new(0.1, 0, 10))
+            .with_reinsertion(ElitistReinserter::new(fitness.clone(), false, 0.5))
+            .with_initial_population(population)
+            .build();
+        let mut rng = get_rng([5; 32]);
+
+        for generation in 1..=5 {
+            algorithm.next(generation, &mut rng).unwrap();
+        }
+
+        expect_that!(&(fitness.hits() > 0), eq(true));
+        expect_that!(
+            &(evaluations.load(Ordering::SeqCst) as u64),
+            eq(fitness.misses())
+        );
+    }
+}
+
+mod fn_fitness_function {
+
+    use super::*;
+    use crate::fitness::function::{FnFitnessFunction, NumericFitness};
+
+    #[test]
+    fn fitness_is_calculated_by_the_closure() {
+        let offset = 3;
+        let fitness =
+            FnFitnessFunction::new(move |genome: &Vec<i32>| genome.iter().sum::<i32>() + offset);
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2, 3]), eq(9));
+    }
+
+    #[test]
+    fn bounds_default_to_the_range_of_the_fitness_type() {
+        let fitness = FnFitnessFunction::new(|genome: &Vec<u8>| genome[0]);
+
+        expect_that!(&fitness.lowest_possible_fitness(), eq(0));
+        expect_that!(&fitness.highest_possible_fitness(), eq(255));
+    }
+
+    #[test]
+    fn fitness_is_kept_within_the_bounds() {
+        let fitness = FnFitnessFunction::new(|genome: &Vec<i64>| genome.iter().sum::<i64>())
+            .with_bounds(0, 10);
+
+        expect_that!(&fitness.fitness_of(&vec![7, 8]), eq(10));
+        expect_that!(&fitness.fitness_of(&vec![-5]), eq(0));
+        expect_that!(&fitness.lowest_possible_fitness(), eq(0));
+        expect_that!(&fitness.highest_possible_fitness(), eq(10));
+    }
+
+    #[test]
+    fn average_is_the_arithmetic_mean() {
+        let fitness = FnFitnessFunction::new(|_: &Vec<i32>| 0);
+
+        expect_that!(&fitness.average(&[1, 2, 6]), eq(3));
+        expect_that!(&fitness.average(&[]), eq(0));
+    }
+
+    #[test]
+    fn mean_of_large_values_does_not_overflow() {
+        expect_that!(&u64::mean(&[u64::MAX, u64::MAX - 2]), eq(u64::MAX - 1));
+        expect_that!(&i8::mean(&[i8::MIN, i8::MIN]), eq(i8::MIN));
+    }
+}
+
+mod constraint {
+
+    use super::*;
+    use crate::fitness::{
+        constraint::{Constrained, ConstraintFunction, FeasibilityFirst, PenalizedFitness},
+        function::FnFitnessFunction,
+    };
+
+    /// The sum of the values must not exceed 10 and the first value must not
+    /// be negative.
+    #[derive(Clone, Debug, PartialEq)]
+    struct SumLimit;
+
+    impl ConstraintFunction<Vec<i32>> for SumLimit {
+        fn violations_of(&self, genome: &Vec<i32>) -> Vec<f64> {
+            vec![
+                f64::from(genome.iter().sum::<i32>() - 10),
+                f64::from(-genome[0]),
+            ]
+        }
+    }
+
+    type Sum = FnFitnessFunction<Vec<i32>, i32, fn(&Vec<i32>) -> i32>;
+
+    fn sum() -> Sum {
+        FnFitnessFunction::new(|genome: &Vec<i32>| genome.iter().sum())
+    }
+
+    #[test]
+    fn total_violation_sums_up_the_violated_constraints_only() {
+        expect_that!(&SumLimit.total_violation_of(&vec![2, 3]), eq(0.));
+        expect_that!(&SumLimit.total_violation_of(&vec![8, 5]), eq(3.));
+        expect_that!(&SumLimit.total_violation_of(&vec![-2, 15]), eq(5.));
+        expect_that!(&SumLimit.is_feasible(&vec![4, 6]), eq(true));
+        expect_that!(&SumLimit.is_feasible(&vec![-1, 0]), eq(false));
+    }
+
+    #[test]
+    fn penalized_fitness_subtracts_the_weighted_violation() {
+        let fitness = PenalizedFitness::new(sum(), SumLimit, 4.);
+
+        expect_that!(&fitness.fitness_of(&vec![4, 6]), eq(10));
+        expect_that!(&fitness.fitness_of(&vec![8, 5]), eq(1));
+        expect_that!(&fitness.lowest_possible_fitness(), eq(i32::MIN));
+    }
+
+    #[test]
+    fn feasible_values_are_greater_than_infeasible_values() {
+        let feasible: Constrained<i32> = Constrained::Feasible(-100);
+        let slightly_infeasible = Constrained::Infeasible(0.5);
+        let very_infeasible = Constrained::Infeasible(7.);
+
+        expect_that!(&feasible, greater_than(slightly_infeasible.clone()));
+        expect_that!(&slightly_infeasible, greater_than(very_infeasible));
+        expect_that!(&Constrained::Feasible(3), greater_than(feasible));
+    }
+
+    #[test]
+    fn feasibility_first_compares_infeasible_genomes_by_their_violation() {
+        let fitness = FeasibilityFirst::new(sum(), SumLimit);
+
+        expect_that!(
+            &fitness.fitness_of(&vec![4, 6]),
+            eq(Constrained::Feasible(10))
+        );
+        expect_that!(
+            &fitness.fitness_of(&vec![8, 5]),
+            eq(Constrained::Infeasible(3.))
+        );
+        expect_that!(
+            &fitness.fitness_of(&vec![1, 1]),
+            greater_than(fitness.fitness_of(&vec![20, 0]))
+        );
+        expect_that!(
+            &fitness.lowest_possible_fitness(),
+            less_than(fitness.fitness_of(&vec![20, 0]))
+        );
+    }
+
+    #[test]
+    fn average_of_feasibility_first_is_the_average_violation_if_any_is_infeasible() {
+        let fitness = FeasibilityFirst::new(sum(), SumLimit);
+
+        expect_that!(
+            &fitness.average(&[Constrained::Feasible(2), Constrained::Feasible(6)]),
+            eq(Constrained::Feasible(4))
+        );
+        expect_that!(
+            &fitness.average(&[Constrained::Feasible(2), Constrained::Infeasible(6.)]),
+            eq(Constrained::Infeasible(3.))
+        );
+    }
+}
+
+mod float_fitness_function {
+
+    use super::*;
+    use crate::{fitness::function::FnFitnessFunction, types::FitnessF64};
+
+    #[test]
+    fn closure_may_calculate_float_fitness_values() {
+        let fitness = FnFitnessFunction::new(|genome: &Vec<f64>| {
+            FitnessF64::new(-genome.iter().map(|x| x * x).sum::<f64>())
+        })
+        .with_bounds(FitnessF64::new(-100.), FitnessF64::new(0.));
+
+        expect_that!(&fitness.fitness_of(&vec![1., 2.]), eq(FitnessF64::new(-5.)));
+        expect_that!(
+            &fitness.fitness_of(&vec![f64::NAN]),
+            eq(FitnessF64::new(-100.))
+        );
+        expect_that!(
+            &fitness.average(&[FitnessF64::new(-1.), FitnessF64::new(-4.)]),
+            eq(FitnessF64::new(-2.5))
+        );
+    }
+}
+
+mod fallible_fitness {
+
+    use super::*;
+    use crate::{
+        fitness::fallible::FallibleFitness,
+        genetic::{EvalError, TryFitnessFunction},
+    };
+
+    /// Sums up the values of genomes that contain no negative value.
+    #[derive(Clone, Debug, PartialEq)]
+    struct SumOfPositives;
+
+    impl TryFitnessFunction<Vec<i32>, i32> for SumOfPositives {
+        fn try_fitness_of(&self, genome: &Vec<i32>) -> Result<i32, EvalError> {
+            if genome.iter().any(|value| *value < 0) {
+                Err(EvalError::Invalid("negative value".to_string()))
+            } else {
+                Ok(genome.iter().sum())
+            }
+        }
+
+        fn average(&self, fitness_values: &[i32]) -> i32 {
+            fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+        }
+
+        fn highest_possible_fitness(&self) -> i32 {
+            100
+        }
+
+        fn lowest_possible_fitness(&self) -> i32 {
+            -1
+        }
+    }
+
+    #[test]
+    fn evaluates_valid_genomes_with_the_wrapped_fitness_function() {
+        let fitness = FallibleFitness::new(SumOfPositives);
+
+        expect_that!(&fitness.fitness_of(&vec![1, 2, 3]), eq(6));
+        expect_that!(&fitness.try_fitness_of(&vec![1, 2, 3]), eq(Ok(6)));
+    }
+
+    #[test]
+    fn try_fitness_of_passes_the_error_on() {
+        let fitness = FallibleFitness::new(SumOfPositives);
+
+        expect_that!(
+            &fitness.try_fitness_of(&vec![1, -2, 3]),
+            eq(Err(EvalError::Invalid("negative value".to_string())))
+        );
+    }
+
+    #[test]
+    fn fitness_of_invalid_genomes_is_the_lowest_possible_fitness() {
+        let fitness = FallibleFitness::new(SumOfPositives);
+
+        expect_that!(&fitness.fitness_of(&vec![1, -2, 3]), eq(-1));
+    }
+
+    #[test]
+    fn eval_error_describes_the_reason() {
+        let error = EvalError::Panicked("index out of bounds".to_string());
+
+        expect_that!(
+            &error.to_string(),
+            eq("evaluation panicked: index out of bounds".to_string())
+        );
+    }
+}
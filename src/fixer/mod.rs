@@ -0,0 +1,13 @@
+//! The `fixer` module provides implementations of the `operator::FixerOp`
+//! that repair invalid offspring.
+//!
+//! The provided implementations of the `operator::FixerOp` are:
+//! * `noop::NoOpFixer`
+//! * `permutation::PermutationFixer`
+
+pub mod noop;
+
+pub mod permutation;
+
+#[cfg(test)]
+mod tests;
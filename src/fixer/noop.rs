@@ -0,0 +1,37 @@
+//! The `noop` module provides the `NoOpFixer`.
+
+use crate::{
+    genetic::Genotype,
+    operator::{FixerOp, GeneticOperator},
+    random::Rng,
+};
+
+/// The `NoOpFixer` leaves all genomes unchanged. It is meant for problems
+/// without constraints, where a fixer is expected but nothing needs to be
+/// repaired.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NoOpFixer;
+
+impl NoOpFixer {
+    /// Constructs a new `NoOpFixer`.
+    pub fn new() -> Self {
+        NoOpFixer
+    }
+}
+
+impl GeneticOperator for NoOpFixer {
+    fn name() -> String {
+        "No-Op-Fixer".to_string()
+    }
+}
+
+impl<G> FixerOp<G> for NoOpFixer
+where
+    G: Genotype,
+{
+    fn fix<R>(&self, _genome: &mut G, _rng: &mut R)
+    where
+        R: Rng + Sized,
+    {
+    }
+}
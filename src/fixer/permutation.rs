@@ -0,0 +1,57 @@
+//! The `permutation` module provides the `PermutationFixer`, which repairs
+//! permutation encoded genomes.
+
+use crate::{
+    operator::{FixerOp, GeneticOperator},
+    random::{Rng, SliceRandom},
+};
+
+/// The `PermutationFixer` repairs genomes that are meant to be permutations
+/// of the values `0..n`, where `n` is the length of the genome.
+///
+/// The first occurrence of each valid value is kept. Repeated values and
+/// values out of range are replaced by the missing values in random order.
+/// Valid permutations are left unchanged.
+///
+/// This fixer allows to use crossover and mutation operators that do not
+/// preserve permutations, e.g. the `recombination::discrete` operators, on
+/// permutation encoded genomes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PermutationFixer;
+
+impl PermutationFixer {
+    /// Constructs a new `PermutationFixer`.
+    pub fn new() -> Self {
+        PermutationFixer
+    }
+}
+
+impl GeneticOperator for PermutationFixer {
+    fn name() -> String {
+        "Permutation-Fixer".to_string()
+    }
+}
+
+impl FixerOp<Vec<usize>> for PermutationFixer {
+    fn fix<R>(&self, genome: &mut Vec<usize>, rng: &mut R)
+    where
+        R: Rng + Sized,
+    {
+        let mut seen = vec![false; genome.len()];
+        let mut invalid_loci = Vec::new();
+        for (locus, value) in genome.iter().enumerate() {
+            match seen.get_mut(*value) {
+                Some(seen) if !*seen => *seen = true,
+                _ => invalid_loci.push(locus),
+            }
+        }
+        if invalid_loci.is_empty() {
+            return;
+        }
+        let mut missing: Vec<usize> = (0..seen.len()).filter(|value| !seen[*value]).collect();
+        missing.shuffle(rng);
+        for (locus, value) in invalid_loci.into_iter().zip(missing) {
+            genome[locus] = value;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::{noop::*, permutation::*};
+use crate::{operator::FixerOp, random::get_rng};
+use galvanic_assert::matchers::*;
+
+#[test]
+fn noop_fixer_leaves_the_genome_unchanged() {
+    let mut genome = vec![3, 3, 7];
+
+    NoOpFixer::new().fix(&mut genome, &mut get_rng([1; 32]));
+
+    expect_that!(&genome, eq(vec![3, 3, 7]));
+}
+
+#[test]
+fn permutation_fixer_leaves_valid_permutations_unchanged() {
+    let mut genome = vec![2, 0, 4, 1, 3];
+
+    PermutationFixer::new().fix(&mut genome, &mut get_rng([1; 32]));
+
+    expect_that!(&genome, eq(vec![2, 0, 4, 1, 3]));
+}
+
+#[test]
+fn permutation_fixer_replaces_repeated_and_out_of_range_values() {
+    let mut genome = vec![2, 0, 2, 9, 1, 0];
+
+    PermutationFixer::new().fix(&mut genome, &mut get_rng([1; 32]));
+
+    // the first occurrences of the valid values are kept
+    expect_that!(&genome[0], eq(2));
+    expect_that!(&genome[1], eq(0));
+    expect_that!(&genome[4], eq(1));
+    let mut values = genome.clone();
+    values.sort_unstable();
+    expect_that!(&values, eq(vec![0, 1, 2, 3, 4, 5]));
+}
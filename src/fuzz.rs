@@ -0,0 +1,297 @@
+//! The `fuzz` module provides harness functions that feed arbitrary input
+//! into crossover and mutation operators, to find panics such as indices out
+//! of range. They are meant to be called from fuzz targets, e.g. of
+//! `cargo fuzz`, which pass the raw bytes generated by the fuzzer.
+//!
+//! The bytes are decoded by a `FuzzInput` into the seed of the random number
+//! generator, the number of parents and the genomes. When the bytes run out,
+//! the remaining values are decoded as zeros, so that every input is valid.
+//! The decoded values stay within `FuzzLimits`, so that the operators are
+//! only called with input that the genetic algorithm could produce as well.
+//!
+//! Authors of operators can fuzz their own operators the same way. A fuzz
+//! target for the `MultiPointCrossBreeder` looks like this:
+//!
+//! ```rust,ignore
+//! #![no_main]
+//!
+//! use genevo::{
+//!     fuzz::{fuzz_crossover, FuzzLimits},
+//!     recombination::discrete::MultiPointCrossBreeder,
+//! };
+//! use libfuzzer_sys::fuzz_target;
+//!
+//! fuzz_target!(|data: &[u8]| {
+//!     fuzz_crossover::<Vec<i32>, _>(&MultiPointCrossBreeder::new(3), &FuzzLimits::default(), data);
+//! });
+//! ```
+//!
+//! Genome types implement the `FuzzGenome` trait to be decoded from the
+//! input. The fuzz targets of this crate are found in the `fuzz` directory
+//! of the repository.
+//!
+//! This module is only available with the crate feature `fuzz`.
+
+use crate::{
+    genetic::{Genotype, Parents, TracedChild},
+    operator::{CrossoverOp, MutationOp},
+    population::GenomeLength,
+    random::{get_rng, random_cut_points, random_cut_points_from_range, random_n_cut_points, Seed},
+};
+use std::ops::RangeInclusive;
+
+/// The `FuzzInput` decodes values from the bytes generated by a fuzzer.
+/// Once all bytes are consumed, every further byte is read as 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzInput<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> FuzzInput<'a> {
+    /// Constructs a new `FuzzInput` that decodes the given bytes.
+    pub fn new(data: &'a [u8]) -> Self {
+        FuzzInput { data, position: 0 }
+    }
+
+    /// Returns whether all bytes have been consumed.
+    pub fn is_exhausted(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    /// Returns the next byte.
+    pub fn byte(&mut self) -> u8 {
+        let byte = self.data.get(self.position).copied().unwrap_or(0);
+        self.position += 1;
+        byte
+    }
+
+    /// Returns the next 8 bytes as `u64`.
+    pub fn u64(&mut self) -> u64 {
+        let mut bytes = [0; 8];
+        for byte in &mut bytes {
+            *byte = self.byte();
+        }
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns the next byte as `bool`.
+    pub fn bool(&mut self) -> bool {
+        self.byte() & 1 == 1
+    }
+
+    /// Returns a value within the given range.
+    pub fn usize_in(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (min, max) = (*range.start(), *range.end());
+        if max <= min {
+            return min;
+        }
+        match ((max - min) as u64).checked_add(1) {
+            Some(span) => min + (self.u64() % span) as usize,
+            None => min + self.u64() as usize,
+        }
+    }
+
+    /// Returns a finite value within the given range.
+    pub fn f64_in(&mut self, range: RangeInclusive<f64>) -> f64 {
+        let fraction = self.u64() as f64 / u64::MAX as f64;
+        range.start() + fraction * (range.end() - range.start())
+    }
+
+    /// Returns the next 32 bytes as `Seed`.
+    pub fn seed(&mut self) -> Seed {
+        let mut bytes = [0; Seed::LENGTH];
+        for byte in &mut bytes {
+            *byte = self.byte();
+        }
+        Seed::from_bytes(bytes)
+    }
+
+    /// Returns a permutation of the numbers `0..length`.
+    pub fn permutation(&mut self, length: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..length).collect();
+        for i in (1..length).rev() {
+            let j = self.usize_in(0..=i);
+            permutation.swap(i, j);
+        }
+        permutation
+    }
+}
+
+/// A `FuzzGenome` is a `genetic::Genotype` that can be decoded from a
+/// `FuzzInput`.
+pub trait FuzzGenome: Genotype + GenomeLength {
+    /// Decodes a genome of the given length from the input.
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self;
+}
+
+impl FuzzGenome for Vec<bool> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        (0..length).map(|_| input.bool()).collect()
+    }
+}
+
+impl FuzzGenome for Vec<u8> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        (0..length).map(|_| input.byte()).collect()
+    }
+}
+
+impl FuzzGenome for Vec<i32> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        (0..length).map(|_| input.u64() as i32).collect()
+    }
+}
+
+impl FuzzGenome for Vec<i64> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        (0..length).map(|_| input.u64() as i64).collect()
+    }
+}
+
+impl FuzzGenome for Vec<f64> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        (0..length).map(|_| input.f64_in(-1e6..=1e6)).collect()
+    }
+}
+
+/// Genomes of type `Vec<usize>` are decoded as permutations, so that they
+/// are valid input for the operators of permutation encoded genomes.
+impl FuzzGenome for Vec<usize> {
+    fn from_fuzz_input(input: &mut FuzzInput, length: usize) -> Self {
+        input.permutation(length)
+    }
+}
+
+/// The `FuzzLimits` bound the values decoded from the fuzz input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FuzzLimits {
+    /// The range of the length of the genomes. All genomes of one run have
+    /// the same length.
+    pub genome_length: RangeInclusive<usize>,
+    /// The range of the number of parents that are crossed over.
+    pub num_parents: RangeInclusive<usize>,
+}
+
+impl Default for FuzzLimits {
+    fn default() -> Self {
+        FuzzLimits {
+            genome_length: 4..=64,
+            num_parents: 2..=4,
+        }
+    }
+}
+
+/// Crosses over parents decoded from the given bytes by the given breeder,
+/// by the infallible functions `crossover` and `crossover_traced` as well as
+/// by the fallible functions `try_crossover` and `try_crossover_traced`.
+/// The segments of traced children must cover the whole genome of the child
+/// and refer to existing parents.
+///
+/// Panics if the breeder panics or the segments are not consistent.
+pub fn fuzz_crossover<G, C>(breeder: &C, limits: &FuzzLimits, data: &[u8])
+where
+    G: FuzzGenome,
+    C: CrossoverOp<G>,
+{
+    let mut input = FuzzInput::new(data);
+    let mut rng = get_rng(input.seed());
+    let num_parents = input.usize_in(limits.num_parents.clone());
+    let length = input.usize_in(limits.genome_length.clone());
+    let parents: Parents<G> = (0..num_parents)
+        .map(|_| G::from_fuzz_input(&mut input, length))
+        .collect();
+
+    breeder.crossover(parents.clone(), &mut rng);
+    let _ = breeder.try_crossover(parents.clone(), &mut rng);
+    for child in breeder.crossover_traced(parents.clone(), &mut rng) {
+        check_segments(&child, num_parents);
+    }
+    if let Ok(children) = breeder.try_crossover_traced(parents, &mut rng) {
+        for child in children {
+            check_segments(&child, num_parents);
+        }
+    }
+}
+
+/// Mutates a genome decoded from the given bytes by the given mutator, by
+/// the infallible functions `mutate` and `mutate_traced` as well as by the
+/// fallible functions `try_mutate` and `try_mutate_traced`.
+///
+/// Panics if the mutator panics.
+pub fn fuzz_mutation<G, M>(mutator: &M, limits: &FuzzLimits, data: &[u8])
+where
+    G: FuzzGenome,
+    M: MutationOp<G>,
+{
+    let mut input = FuzzInput::new(data);
+    let mut rng = get_rng(input.seed());
+    let length = input.usize_in(limits.genome_length.clone());
+    let genome = G::from_fuzz_input(&mut input, length);
+
+    mutator.mutate(genome.clone(), &mut rng);
+    let _ = mutator.try_mutate(genome.clone(), &mut rng);
+    mutator.mutate_traced(TracedChild::untraced(genome.clone()), &mut rng);
+    let _ = mutator.try_mutate_traced(TracedChild::untraced(genome), &mut rng);
+}
+
+/// Generates cut points with lengths and ranges decoded from the given bytes
+/// by the functions of the `random` module and checks that they are within
+/// the range and in ascending order.
+///
+/// Panics if a function panics or returns invalid cut points.
+pub fn fuzz_cut_points(data: &[u8]) {
+    let mut input = FuzzInput::new(data);
+    let mut rng = get_rng(input.seed());
+
+    let length = input.usize_in(4..=1_000);
+    let (cut1, cut2) = random_cut_points(&mut rng, length);
+    assert!(cut1 < cut2 && cut2 < length, "invalid cut points");
+
+    let min = input.usize_in(0..=1_000);
+    let max = input.usize_in(min + 4..=min + 1_000);
+    let (cut1, cut2) = random_cut_points_from_range(&mut rng, min, max);
+    assert!(
+        min <= cut1 && cut1 < cut2 && cut2 < max,
+        "invalid cut points"
+    );
+
+    let n = input.usize_in(1..=16);
+    let length = input.usize_in(2 * n..=2 * n + 1_000);
+    let cut_points = random_n_cut_points(&mut rng, n, length);
+    assert_eq!(cut_points.len(), n, "wrong number of cut points");
+    assert!(
+        cut_points.windows(2).all(|pair| pair[0] < pair[1]),
+        "cut points are not in ascending order"
+    );
+    assert!(
+        cut_points.iter().all(|cut_point| *cut_point < length),
+        "cut point out of range"
+    );
+}
+
+/// Checks that the segments of the child are contiguous, cover the whole
+/// genome and refer to one of the parents, if the child is traced.
+fn check_segments<G>(child: &TracedChild<G>, num_parents: usize)
+where
+    G: FuzzGenome,
+{
+    if child.segments.is_empty() {
+        return;
+    }
+    let mut end = 0;
+    for segment in &child.segments {
+        assert_eq!(segment.start, end, "segments are not contiguous");
+        assert!(
+            segment.start <= segment.end,
+            "segment ends before it starts"
+        );
+        assert!(segment.parent < num_parents, "segment of unknown parent");
+        end = segment.end;
+    }
+    assert_eq!(
+        end,
+        child.genome.genome_length(),
+        "segments do not cover the genome"
+    );
+}
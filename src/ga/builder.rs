@@ -1,11 +1,24 @@
-use super::GeneticAlgorithm;
+#[cfg(not(target_arch = "wasm32"))]
+use super::GeneticAlgorithmError;
+use super::{
+    trace::{TraceGenome, Tracing},
+    DiversityTracking, EvaluationErrorPolicy, EvaluationTimeout, GeneticAlgorithm,
+    HallOfFameTracking, Pipeline, SharedFixer, SharedLocalSearch, SharedThreadPool,
+    SuccessTracking,
+};
 use crate::{
-    genetic::{Fitness, FitnessFunction, Genotype},
-    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
-    population::Population,
-    statistic::ProcessingTime,
+    algorithm::{HallOfFame, TieBreaking},
+    genetic::{AsScalar, Fitness, FitnessFunction, GenomeDistance, Genotype},
+    operator::{CrossoverOp, FixerOp, LocalSearchOp, MutationOp, ReinsertionOp, SelectionOp},
+    population::{
+        allocator::{PopulationAllocator, SharedAllocator},
+        Population,
+    },
+    recombination::probabilistic::ProbabilisticCrossover,
+    statistic::{PopulationDiversity, ProcessingTime},
 };
-use std::{marker::PhantomData, rc::Rc};
+use chrono::Duration;
+use std::{hash::Hash, io::Write, marker::PhantomData, ops::RangeInclusive, sync::Arc};
 
 const DEFAULT_MIN_POPULATION_SIZE: usize = 6;
 
@@ -27,6 +40,20 @@ where
     mutator: M,
     reinserter: R,
     min_population_size: usize,
+    evaluation_timeout: Option<EvaluationTimeout<G, F, E>>,
+    evaluation_error_policy: EvaluationErrorPolicy,
+    allocator: Option<SharedAllocator<G>>,
+    tie_breaking: TieBreaking<G>,
+    success_tracking: Option<SuccessTracking<G, F>>,
+    elitism: usize,
+    selection_subsample: Option<f64>,
+    pinned_offspring: Option<usize>,
+    fixer: Option<SharedFixer<G>>,
+    local_search: Option<SharedLocalSearch<G, E>>,
+    diversity_tracking: Option<DiversityTracking<G, F>>,
+    hall_of_fame: Option<HallOfFameTracking<G, F>>,
+    tracing: Option<Tracing<G>>,
+    thread_pool: Option<SharedThreadPool>,
     initial_population: Population<G>,
 }
 
@@ -49,9 +76,27 @@ where
             mutator: self.mutator,
             reinserter: self.reinserter,
             min_population_size: self.min_population_size,
-            population: Rc::new(self.initial_population.individuals().to_vec()),
+            evaluation_timeout: self.evaluation_timeout,
+            evaluation_error_policy: self.evaluation_error_policy,
+            allocator: self.allocator,
+            tie_breaking: self.tie_breaking,
+            population: Arc::new(self.initial_population.individuals().to_vec()),
             initial_population: self.initial_population,
             processing_time: ProcessingTime::zero(),
+            best_fitness: None,
+            stagnant_generations: 0,
+            success_tracking: self.success_tracking,
+            elitism: self.elitism,
+            selection_subsample: self.selection_subsample,
+            pinned_offspring: self.pinned_offspring,
+            fixer: self.fixer,
+            local_search: self.local_search,
+            diversity_tracking: self.diversity_tracking,
+            hall_of_fame: self.hall_of_fame,
+            tracing: self.tracing,
+            thread_pool: self.thread_pool,
+            offspring_success: None,
+            pipeline: Pipeline::Evaluate,
         }
     }
 
@@ -59,6 +104,251 @@ where
         self.min_population_size = min_population_size;
         self
     }
+
+    /// Limits the time the evaluation of the fitness of one genome may take.
+    /// Genomes whose evaluation does not finish within the given `timeout`
+    /// get the given `fallback` fitness value.
+    ///
+    /// With a timeout the genomes are evaluated on worker threads, at most
+    /// as many as the thread pool of the algorithm has threads. If all
+    /// workers are busy, an evaluation waits for the next free worker. The
+    /// timeout of an evaluation starts when a worker picks it up. A thread
+    /// can not be cancelled, so an evaluation that timed out keeps its
+    /// thread busy until it finishes on its own; its result is dropped. The
+    /// worker is replaced by a new thread, so that evaluations that never
+    /// finish do not block the evaluation of the other genomes.
+    ///
+    /// Evaluations that fail are handled according to the
+    /// `EvaluationErrorPolicy` like evaluations without a timeout.
+    pub fn with_evaluation_timeout(mut self, timeout: Duration, fallback: F) -> Self
+    where
+        G: 'static,
+        F: Send + 'static,
+        E: Send + 'static,
+    {
+        self.evaluation_timeout = Some(EvaluationTimeout::new(timeout, fallback));
+        self
+    }
+
+    /// Sets how genomes are handled whose fitness can not be evaluated,
+    /// because the `FitnessFunction` returns an error or panics. By default
+    /// the algorithm stops with an error.
+    ///
+    /// The `EvaluationErrorPolicy::RepairAndRetry` policy repairs the
+    /// genomes with the fixer set by `with_fixer`.
+    pub fn with_evaluation_error_policy(mut self, policy: EvaluationErrorPolicy) -> Self {
+        self.evaluation_error_policy = policy;
+        self
+    }
+
+    /// Sets the `PopulationAllocator` that provides the buffers for the
+    /// populations and the offspring. The buffers of the offspring are taken
+    /// back once the offspring has been reinserted, the buffers of the
+    /// populations once they have been replaced and are not shared anymore.
+    pub fn with_allocator<A>(mut self, allocator: A) -> Self
+    where
+        A: PopulationAllocator<G> + 'static,
+    {
+        self.allocator = Some(SharedAllocator::new(allocator));
+        self
+    }
+
+    /// Sets how the best solution of a generation is chosen among the
+    /// individuals with the highest fitness. Defaults to
+    /// `TieBreaking::First`.
+    pub fn with_tie_breaking(mut self, tie_breaking: TieBreaking<G>) -> Self {
+        self.tie_breaking = tie_breaking;
+        self
+    }
+
+    /// Enables or disables the tracking of the `OffspringSuccess`, i.e. the
+    /// fraction of children that are fitter than the best of their parents.
+    /// Disabled by default.
+    ///
+    /// The children are compared with their parents when they are evaluated
+    /// with the next generation, so no genome is evaluated more than once.
+    /// The success is reported in the `State` of that generation and passed
+    /// to the `operator::GenerationAware` operators. Children that do not
+    /// make it into the next generation count as not successful. The parents
+    /// and the children are looked up in the evaluated populations through
+    /// an index that is built once per generation, which requires genomes
+    /// that implement `Hash` and `Eq`.
+    pub fn with_offspring_success_tracking(mut self, enabled: bool) -> Self
+    where
+        G: Hash + Eq,
+    {
+        self.success_tracking = if enabled {
+            Some(SuccessTracking::new())
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Sets the number of the fittest individuals of each generation that
+    /// are copied unchanged into the next generation, independent of the
+    /// configured `operator::ReinsertionOp`. Defaults to 0.
+    ///
+    /// The elites replace randomly chosen individuals of the population
+    /// created by the reinserter, so that the size of the population does
+    /// not change. A reinserter that keeps the best individuals by itself,
+    /// like the `reinsertion::elitist::ElitistReinserter`, may keep an elite
+    /// a second time.
+    pub fn with_elitism(mut self, elitism: usize) -> Self {
+        self.elitism = elitism;
+        self
+    }
+
+    /// Lets the `operator::SelectionOp` select the parents from a random
+    /// subsample of the given fraction of the evaluated population instead
+    /// of from the whole population. By default the whole population is used.
+    ///
+    /// For huge populations this trades accuracy of the selection for
+    /// throughput, as the costs of most selection operators grow with the
+    /// number of individuals they select from. The subsample is drawn
+    /// uniformly at random, so its fitness distribution is an unbiased
+    /// estimate of the one of the population. But the fittest individuals
+    /// take part in a generation only with the probability `fraction`, which
+    /// weakens the selection pressure, and the variance of the selection
+    /// grows the smaller the subsample is. The number of individuals in the
+    /// subsample is reported in the `State` of each generation.
+    ///
+    /// The number of parents selected from the subsample still suffices to
+    /// breed the number of offspring the reinserter requires.
+    pub fn with_selection_subsample(mut self, fraction: f64) -> Self {
+        self.selection_subsample = Some(fraction);
+        self
+    }
+
+    /// Pins the number of offspring that is bred in each generation.
+    ///
+    /// By default the number of offspring is the number the reinserter
+    /// requires for the size of the population, see
+    /// `operator::ReinsertionOp::required_offspring`. The selection produces
+    /// as many groups of parents as are needed to breed the pinned number of
+    /// offspring, regardless of its selection ratio.
+    pub fn with_pinned_offspring(mut self, num_offspring: usize) -> Self {
+        self.pinned_offspring = Some(num_offspring);
+        self
+    }
+
+    /// Sets the `operator::FixerOp` that repairs the offspring of each
+    /// generation. No repair by default.
+    ///
+    /// The fixer is applied to each child after the mutation and before the
+    /// reinsertion, so that invalid children never make it into the next
+    /// generation.
+    pub fn with_fixer<X>(mut self, fixer: X) -> Self
+    where
+        X: FixerOp<G> + Send + Sync + 'static,
+    {
+        self.fixer = Some(SharedFixer::new(fixer));
+        self
+    }
+
+    /// Sets the `operator::LocalSearchOp` that refines the given ratio of
+    /// the offspring of each generation, which makes the genetic algorithm
+    /// a memetic algorithm. No local search by default.
+    ///
+    /// The children to refine are chosen at random after the mutation and
+    /// the repair. The local search evaluates the fitness of the genomes it
+    /// explores in addition to the regular evaluation of the population, so
+    /// the budget of the local search should be chosen with the costs of the
+    /// fitness function in mind.
+    pub fn with_local_search<L>(mut self, local_search: L, ratio: f64) -> Self
+    where
+        L: LocalSearchOp<G, F> + Send + Sync + 'static,
+    {
+        self.local_search = Some(SharedLocalSearch::new(local_search, ratio));
+        self
+    }
+
+    /// Enables the tracking of the `PopulationDiversity` of each generation.
+    /// Disabled by default.
+    ///
+    /// The diversity is reported in the `State` of each generation and passed
+    /// to the `operator::GenerationAware` operators. The genotypic diversity
+    /// compares all pairs of individuals, which takes quadratic time in the
+    /// size of the population.
+    pub fn with_diversity_tracking(mut self) -> Self
+    where
+        G: GenomeDistance,
+        F: AsScalar + Sync,
+    {
+        self.diversity_tracking = Some(DiversityTracking::new(PopulationDiversity::of::<G, F>));
+        self
+    }
+
+    /// Keeps a `HallOfFame` with up to `capacity` of the best distinct
+    /// solutions found over the whole run. Disabled by default.
+    ///
+    /// The hall of fame is updated with each evaluated generation and
+    /// reported in the `State` of each generation, so the final result of a
+    /// simulation holds the best solutions of the entire run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is 0.
+    pub fn with_hall_of_fame(mut self, capacity: usize) -> Self
+    where
+        G: PartialEq,
+    {
+        self.hall_of_fame = Some(HallOfFameTracking::new(capacity, HallOfFame::update));
+        self
+    }
+
+    /// Enables the trace mode for the given range of generations. Disabled
+    /// by default.
+    ///
+    /// For each traced generation the selected parents, the cut points and
+    /// mutated loci of each child and the reinsertion decisions are written
+    /// to the given writer, see the `ga::trace` module for the format. A
+    /// failure to write the trace stops the algorithm with a
+    /// `GeneticAlgorithmError::TraceFailed`.
+    pub fn with_trace<W>(mut self, writer: W, generations: RangeInclusive<u64>) -> Self
+    where
+        G: TraceGenome,
+        W: Write + Send + 'static,
+    {
+        self.tracing = Some(Tracing::new(writer, generations));
+        self
+    }
+
+    /// Sets the rayon thread pool the populations are evaluated and bred in.
+    /// By default the global rayon thread pool is used.
+    ///
+    /// A separate thread pool keeps the algorithm from competing with other
+    /// work in the global thread pool of the application. The thread pool
+    /// may be shared with other algorithms by passing it as `Arc`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_thread_pool<P>(mut self, pool: P) -> Self
+    where
+        P: Into<Arc<rayon::ThreadPool>>,
+    {
+        self.thread_pool = Some(SharedThreadPool::new(pool.into()));
+        self
+    }
+
+    /// Limits the number of threads the populations are evaluated and bred
+    /// in to the given number by building a thread pool of that size, e.g.
+    /// for fitness functions that may only run a limited number of times in
+    /// parallel.
+    ///
+    /// Returns a `GeneticAlgorithmError::ThreadPoolFailed` if the thread pool
+    /// can not be built.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_num_threads(self, num_threads: usize) -> Result<Self, GeneticAlgorithmError> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|error| {
+                GeneticAlgorithmError::ThreadPoolFailed(format!(
+                    "Thread pool of {} threads can not be built: {}",
+                    num_threads, error
+                ))
+            })?;
+        Ok(self.with_thread_pool(pool))
+    }
 }
 
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -192,6 +482,22 @@ where
     S: SelectionOp<G, F>,
     C: CrossoverOp<G>,
 {
+    /// Applies the crossover operator only with the given probability to
+    /// each group of parents. Otherwise the parents are copied unchanged into
+    /// the offspring before they are mutated.
+    pub fn with_crossover_probability(
+        self,
+        crossover_probability: f64,
+    ) -> GeneticAlgorithmWithEvalSeleAndBreeBuilder<G, F, E, S, ProbabilisticCrossover<C>> {
+        GeneticAlgorithmWithEvalSeleAndBreeBuilder {
+            _g: self._g,
+            _f: self._f,
+            evaluator: self.evaluator,
+            selector: self.selector,
+            breeder: ProbabilisticCrossover::new(self.breeder, crossover_probability),
+        }
+    }
+
     pub fn with_mutation<M>(
         self,
         mutation_op: M,
@@ -301,6 +607,20 @@ where
             mutator: self.mutator,
             reinserter: self.reinserter,
             min_population_size: DEFAULT_MIN_POPULATION_SIZE,
+            evaluation_timeout: None,
+            evaluation_error_policy: EvaluationErrorPolicy::default(),
+            allocator: None,
+            tie_breaking: TieBreaking::default(),
+            success_tracking: None,
+            elitism: 0,
+            selection_subsample: None,
+            pinned_offspring: None,
+            fixer: None,
+            local_search: None,
+            diversity_tracking: None,
+            hall_of_fame: None,
+            tracing: None,
+            thread_pool: None,
             initial_population,
         }
     }
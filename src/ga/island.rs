@@ -0,0 +1,998 @@
+//! The `island` module provides the `IslandModel`, a genetic algorithm that
+//! evolves several populations, the islands, side by side and lets the best
+//! individuals migrate from island to island from time to time.
+//!
+//! Each island is evolved by its own `Island` implementation, usually a
+//! `ga::GeneticAlgorithm`. The islands only have to agree on the type of the
+//! genotype and the fitness, so that each island can use a different
+//! operator stack and different parameters, e.g. an explorative island with
+//! a high mutation rate and an exploitative island with a strong selection
+//! pressure. Such heterogeneous islands typically outperform homogeneous
+//! ones on hard problems.
+//!
+//! The islands are connected in a ring. Every `migration_interval`
+//! generations `num_migrants` individuals of each island migrate to the next
+//! island in the ring. The `EmigrantSelector` chooses the individuals that
+//! leave an island and the `ImmigrantReplacer` chooses the individuals that
+//! are replaced by the immigrants. By default the best individuals of an
+//! island replace the worst individuals of the next island.
+//!
+//! Provided emigrant selectors are:
+//! * `BestEmigrants` - the fittest individuals emigrate.
+//! * `RandomEmigrants` - randomly chosen individuals emigrate.
+//! * `TournamentEmigrants` - the winners of tournaments emigrate.
+//!
+//! Provided immigrant replacers are:
+//! * `ReplaceWorst` - the immigrants replace the least fit individuals.
+//! * `ReplaceRandom` - the immigrants replace randomly chosen individuals.
+//! * `ReplaceMostSimilar` - each immigrant replaces the individual that is
+//!   most similar to it.
+//!
+//! The `IslandModelState` of each generation reports the `MigrationStats`
+//! of each island. The most recent `MigrationEvent`s are kept in a history
+//! of limited capacity.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{ga::island::island_model, population::ValueEncodedGenomeBuilder, prelude::*};
+//!
+//! #[derive(Clone, Debug)]
+//! struct SumOfValues;
+//!
+//! impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+//!     fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+//!         genome.iter().sum()
+//!     }
+//!
+//!     fn average(&self, values: &[i32]) -> i32 {
+//!         values.iter().sum::<i32>() / values.len() as i32
+//!     }
+//!
+//!     fn highest_possible_fitness(&self) -> i32 {
+//!         800
+//!     }
+//!
+//!     fn lowest_possible_fitness(&self) -> i32 {
+//!         0
+//!     }
+//! }
+//!
+//! let population = || {
+//!     build_population()
+//!         .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+//!         .of_size(20)
+//!         .uniform_at_random()
+//! };
+//!
+//! let explorative = genetic_algorithm()
+//!     .with_evaluation(SumOfValues)
+//!     .with_selection(RouletteWheelSelector::new(0.8, 2))
+//!     .with_crossover(UniformCrossBreeder::new())
+//!     .with_mutation(RandomValueMutator::new(0.5, 0, 100))
+//!     .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.8))
+//!     .with_initial_population(population())
+//!     .build();
+//!
+//! let exploitative = genetic_algorithm()
+//!     .with_evaluation(SumOfValues)
+//!     .with_selection(MaximizeSelector::new(0.8, 2))
+//!     .with_crossover(MultiPointCrossBreeder::new(2))
+//!     .with_mutation(BreederValueMutator::new(0.1, 5, 2, 0, 100))
+//!     .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.8))
+//!     .with_initial_population(population())
+//!     .build();
+//!
+//! let model = island_model()
+//!     .with_island(explorative)
+//!     .with_island(exploitative)
+//!     .with_migration_interval(5)
+//!     .with_num_migrants(2)
+//!     .build();
+//!
+//! let mut simulator = simulate(model).until(GenerationLimit::new(20)).build();
+//! let result = simulator.run().unwrap();
+//! ```
+
+use super::{GeneticAlgorithm, GeneticAlgorithmError, Snapshot, State};
+use crate::{
+    algorithm::{Algorithm, BestSolution, Checkpointable, EvaluatedPopulation, OptimizationResult},
+    genetic::{Fitness, FitnessFunction, Genotype},
+    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
+    random::{random_index, Prng, Rng, SliceRandom},
+    statistic::{ProcessingTime, TrackProcessingTime},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+};
+
+/// An `Island` evolves one population of an `IslandModel`.
+///
+/// It is implemented by the `ga::GeneticAlgorithm`, which allows to
+/// combine genetic algorithms with different operators in one
+/// `IslandModel`.
+pub trait Island<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Evolves the population of this island by one generation.
+    fn evolve(
+        &mut self,
+        generation: u64,
+        rng: &mut Prng,
+    ) -> Result<State<G, F>, GeneticAlgorithmError>;
+
+    /// Returns the population that is evolved in the next generation.
+    fn population(&self) -> &[G];
+
+    /// Replaces the population that is evolved in the next generation.
+    fn set_population(&mut self, population: Vec<G>);
+
+    /// Returns the fitness of the given genome as evaluated on this island.
+    fn fitness_of(&self, genome: &G) -> F;
+
+    /// Returns the processing time of the last generation.
+    fn processing_time(&self) -> ProcessingTime;
+
+    /// Resets this island to its initial population.
+    fn reset(&mut self) -> Result<bool, GeneticAlgorithmError>;
+
+    /// Returns a snapshot of the progress of this island, which is saved in
+    /// the checkpoints of the `IslandModel`.
+    fn snapshot(&self) -> Snapshot<G, F>;
+
+    /// Restores the progress of this island from the given snapshot.
+    fn restore(&mut self, snapshot: Snapshot<G, F>);
+}
+
+impl<G, F, E, S, C, M, R> Island<G, F> for GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    fn evolve(
+        &mut self,
+        generation: u64,
+        rng: &mut Prng,
+    ) -> Result<State<G, F>, GeneticAlgorithmError> {
+        self.next(generation, rng)
+    }
+
+    fn population(&self) -> &[G] {
+        &self.population
+    }
+
+    fn set_population(&mut self, population: Vec<G>) {
+        self.population = population.into();
+    }
+
+    fn fitness_of(&self, genome: &G) -> F {
+        self.evaluator.fitness_of(genome)
+    }
+
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+
+    fn reset(&mut self) -> Result<bool, GeneticAlgorithmError> {
+        Algorithm::reset(self)
+    }
+
+    fn snapshot(&self) -> Snapshot<G, F> {
+        Checkpointable::snapshot(self)
+    }
+
+    fn restore(&mut self, snapshot: Snapshot<G, F>) {
+        Checkpointable::restore(self, snapshot)
+    }
+}
+
+/// An `EmigrantSelector` chooses the individuals that emigrate from an
+/// island.
+pub trait EmigrantSelector<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Selects the given number of emigrants from the evaluated population
+    /// of an island.
+    fn select_emigrants<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_emigrants: usize,
+        rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized;
+}
+
+/// An `ImmigrantReplacer` chooses the individuals of an island that are
+/// replaced by immigrants.
+pub trait ImmigrantReplacer<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Returns the positions of the individuals in the given population that
+    /// are replaced by the given immigrants, one distinct position per
+    /// immigrant in the order of the immigrants. The `fitness_of` function
+    /// evaluates an individual on the island.
+    fn select_replaced<R>(
+        &self,
+        population: &[G],
+        immigrants: &[G],
+        fitness_of: &dyn Fn(&G) -> F,
+        rng: &mut R,
+    ) -> Vec<usize>
+    where
+        R: Rng + Sized;
+}
+
+/// The fittest individuals emigrate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BestEmigrants;
+
+impl<G, F> EmigrantSelector<G, F> for BestEmigrants
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_emigrants<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_emigrants: usize,
+        _rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let fitness_values = evaluated.fitness_values();
+        let mut indices: Vec<usize> = (0..fitness_values.len()).collect();
+        indices.sort_by(|&a, &b| fitness_values[b].cmp(&fitness_values[a]));
+        let individuals = evaluated.individuals();
+        indices
+            .into_iter()
+            .take(num_emigrants)
+            .map(|index| individuals[index].clone())
+            .collect()
+    }
+}
+
+/// Randomly chosen individuals emigrate. Each individual emigrates at most
+/// once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RandomEmigrants;
+
+impl<G, F> EmigrantSelector<G, F> for RandomEmigrants
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_emigrants<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_emigrants: usize,
+        rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let individuals = evaluated.individuals();
+        let mut indices: Vec<usize> = (0..individuals.len()).collect();
+        let num_emigrants = num_emigrants.min(indices.len());
+        let (chosen, _) = indices.partial_shuffle(rng, num_emigrants);
+        chosen
+            .iter()
+            .map(|&index| individuals[index].clone())
+            .collect()
+    }
+}
+
+/// The winners of tournaments emigrate. For each emigrant a tournament of
+/// `tournament_size` randomly chosen individuals is held and the fittest
+/// participant wins. An individual can win more than one tournament.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TournamentEmigrants {
+    tournament_size: usize,
+}
+
+impl TournamentEmigrants {
+    /// Constructs a new instance of `TournamentEmigrants`.
+    pub fn new(tournament_size: usize) -> Self {
+        TournamentEmigrants { tournament_size }
+    }
+
+    /// Returns the number of participants per tournament.
+    pub fn tournament_size(&self) -> usize {
+        self.tournament_size
+    }
+}
+
+impl<G, F> EmigrantSelector<G, F> for TournamentEmigrants
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_emigrants<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_emigrants: usize,
+        rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let individuals = evaluated.individuals();
+        let fitness_values = evaluated.fitness_values();
+        if individuals.is_empty() {
+            return Vec::new();
+        }
+        (0..num_emigrants)
+            .map(|_| {
+                let winner = (0..self.tournament_size.max(1))
+                    .map(|_| random_index(rng, individuals.len()))
+                    .max_by(|&a, &b| fitness_values[a].cmp(&fitness_values[b]))
+                    .unwrap_or(0);
+                individuals[winner].clone()
+            })
+            .collect()
+    }
+}
+
+/// The immigrants replace the least fit individuals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReplaceWorst;
+
+impl<G, F> ImmigrantReplacer<G, F> for ReplaceWorst
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_replaced<R>(
+        &self,
+        population: &[G],
+        immigrants: &[G],
+        fitness_of: &dyn Fn(&G) -> F,
+        _rng: &mut R,
+    ) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let fitness_values: Vec<F> = population.iter().map(fitness_of).collect();
+        let mut indices: Vec<usize> = (0..population.len()).collect();
+        indices.sort_by(|&a, &b| fitness_values[a].cmp(&fitness_values[b]));
+        indices.truncate(immigrants.len());
+        indices
+    }
+}
+
+/// The immigrants replace randomly chosen individuals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReplaceRandom;
+
+impl<G, F> ImmigrantReplacer<G, F> for ReplaceRandom
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_replaced<R>(
+        &self,
+        population: &[G],
+        immigrants: &[G],
+        _fitness_of: &dyn Fn(&G) -> F,
+        rng: &mut R,
+    ) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let mut indices: Vec<usize> = (0..population.len()).collect();
+        let num_replaced = immigrants.len().min(indices.len());
+        let (chosen, _) = indices.partial_shuffle(rng, num_replaced);
+        chosen.to_vec()
+    }
+}
+
+/// Each immigrant replaces the individual that is most similar to it, i.e.
+/// the individual with the smallest distance to the immigrant as calculated
+/// by the given function. This preserves the diversity of the island.
+#[derive(Debug)]
+pub struct ReplaceMostSimilar<G> {
+    distance: fn(&G, &G) -> f64,
+}
+
+impl<G> Clone for ReplaceMostSimilar<G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for ReplaceMostSimilar<G> {}
+
+impl<G> ReplaceMostSimilar<G> {
+    /// Constructs a new instance of `ReplaceMostSimilar` with the given
+    /// distance function.
+    pub fn new(distance: fn(&G, &G) -> f64) -> Self {
+        ReplaceMostSimilar { distance }
+    }
+}
+
+impl<G, F> ImmigrantReplacer<G, F> for ReplaceMostSimilar<G>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_replaced<R>(
+        &self,
+        population: &[G],
+        immigrants: &[G],
+        _fitness_of: &dyn Fn(&G) -> F,
+        _rng: &mut R,
+    ) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let mut replaced = Vec::with_capacity(immigrants.len());
+        for immigrant in immigrants {
+            let most_similar = (0..population.len())
+                .filter(|index| !replaced.contains(index))
+                .map(|index| (index, (self.distance)(immigrant, &population[index])))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b));
+            match most_similar {
+                Some((index, _)) => replaced.push(index),
+                None => break,
+            }
+        }
+        replaced
+    }
+}
+
+/// The `MigrationEvent` records the migration of individuals from one
+/// island to the next.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MigrationEvent<F>
+where
+    F: Fitness,
+{
+    /// The generation after which the individuals migrated.
+    pub generation: u64,
+    /// The index of the island that the individuals left.
+    pub from_island: usize,
+    /// The index of the island that the individuals entered.
+    pub to_island: usize,
+    /// The number of individuals that migrated.
+    pub num_migrants: usize,
+    /// The highest fitness of the immigrants on the island they entered.
+    pub best_immigrant_fitness: Option<F>,
+    /// The number of immigrants that are fitter than the individual they
+    /// replaced.
+    pub num_improving: usize,
+}
+
+/// The `MigrationStats` accumulate the migrations of one island since the
+/// start of the simulation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MigrationStats {
+    /// The number of individuals that left the island.
+    pub num_emigrants: u64,
+    /// The number of individuals that entered the island.
+    pub num_immigrants: u64,
+    /// The number of immigrants that are fitter than the individual they
+    /// replaced.
+    pub num_improving_immigrants: u64,
+}
+
+impl MigrationStats {
+    /// Returns the fraction of immigrants that are fitter than the
+    /// individual they replaced, or 0 if no individuals entered the island.
+    pub fn improvement_ratio(&self) -> f64 {
+        if self.num_immigrants == 0 {
+            0.
+        } else {
+            self.num_improving_immigrants as f64 / self.num_immigrants as f64
+        }
+    }
+}
+
+/// The `State` of one generation of the `IslandModel`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IslandModelState<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The states of the islands in the order in which the islands have
+    /// been added to the model.
+    pub island_states: Vec<State<G, F>>,
+    /// The best solution of all islands in this generation.
+    pub best_solution: BestSolution<G, F>,
+    /// The index of the island that has found the best solution.
+    pub best_island: usize,
+    /// The accumulated processing time of all islands in this generation.
+    pub processing_time: ProcessingTime,
+    /// Whether individuals have migrated after this generation.
+    pub migrated: bool,
+    /// The accumulated migration statistics of each island in the order in
+    /// which the islands have been added to the model.
+    pub migration_stats: Vec<MigrationStats>,
+}
+
+impl<G, F> OptimizationResult<G, F> for IslandModelState<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<G, F> {
+        &self.best_solution
+    }
+}
+
+/// The `IslandModelSnapshot` holds the progress of an `IslandModel`, i.e.
+/// the snapshot of each island and the accumulated migrations.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: Serialize, F: Serialize",
+        deserialize = "G: Deserialize<'de>, F: Deserialize<'de>"
+    ))
+)]
+pub struct IslandModelSnapshot<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The snapshots of the islands in the order in which the islands have
+    /// been added to the model.
+    pub islands: Vec<Snapshot<G, F>>,
+    /// The accumulated migration statistics of each island.
+    pub migration_stats: Vec<MigrationStats>,
+    /// The most recent migrations, the oldest first.
+    pub migration_history: VecDeque<MigrationEvent<F>>,
+}
+
+/// The `IslandModel` evolves the populations of several `Island`s side by
+/// side and lets individuals migrate between the islands as chosen by the
+/// `EmigrantSelector` `ES` and the `ImmigrantReplacer` `IR`.
+pub struct IslandModel<G, F, ES = BestEmigrants, IR = ReplaceWorst>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    islands: Vec<Box<dyn Island<G, F> + Send>>,
+    migration_interval: u64,
+    num_migrants: usize,
+    emigrant_selector: ES,
+    immigrant_replacer: IR,
+    migration_stats: Vec<MigrationStats>,
+    migration_history: VecDeque<MigrationEvent<F>>,
+    migration_history_capacity: usize,
+    processing_time: ProcessingTime,
+}
+
+impl<G, F, ES, IR> Debug for IslandModel<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F> + Debug,
+    IR: ImmigrantReplacer<G, F> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IslandModel")
+            .field("num_islands", &self.islands.len())
+            .field("migration_interval", &self.migration_interval)
+            .field("num_migrants", &self.num_migrants)
+            .field("emigrant_selector", &self.emigrant_selector)
+            .field("immigrant_replacer", &self.immigrant_replacer)
+            .field("migration_stats", &self.migration_stats)
+            .field("migration_history", &self.migration_history)
+            .field(
+                "migration_history_capacity",
+                &self.migration_history_capacity,
+            )
+            .field("processing_time", &self.processing_time)
+            .finish()
+    }
+}
+
+impl<G, F, ES, IR> IslandModel<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    /// Returns the number of islands.
+    pub fn num_islands(&self) -> usize {
+        self.islands.len()
+    }
+
+    /// Returns the island at the given index.
+    pub fn island(&self, index: usize) -> Option<&(dyn Island<G, F> + Send)> {
+        self.islands.get(index).map(AsRef::as_ref)
+    }
+
+    /// Returns the number of generations between two migrations.
+    pub fn migration_interval(&self) -> u64 {
+        self.migration_interval
+    }
+
+    /// Sets the number of generations between two migrations.
+    pub fn set_migration_interval(&mut self, value: u64) {
+        self.migration_interval = value;
+    }
+
+    /// Returns the number of individuals that migrate from each island.
+    pub fn num_migrants(&self) -> usize {
+        self.num_migrants
+    }
+
+    /// Sets the number of individuals that migrate from each island.
+    pub fn set_num_migrants(&mut self, value: usize) {
+        self.num_migrants = value;
+    }
+
+    /// Returns the `EmigrantSelector` of this model.
+    pub fn emigrant_selector(&self) -> &ES {
+        &self.emigrant_selector
+    }
+
+    /// Returns the `ImmigrantReplacer` of this model.
+    pub fn immigrant_replacer(&self) -> &IR {
+        &self.immigrant_replacer
+    }
+
+    /// Returns the accumulated migration statistics of each island.
+    pub fn migration_stats(&self) -> &[MigrationStats] {
+        &self.migration_stats
+    }
+
+    /// Returns the most recent migrations, the oldest first. The number of
+    /// migrations kept is limited by the capacity of the history.
+    pub fn migration_history(&self) -> &VecDeque<MigrationEvent<F>> {
+        &self.migration_history
+    }
+
+    /// Returns the maximum number of migrations kept in the history.
+    pub fn migration_history_capacity(&self) -> usize {
+        self.migration_history_capacity
+    }
+
+    /// Returns whether individuals migrate after the given generation.
+    fn is_migration_due(&self, generation: u64) -> bool {
+        self.islands.len() > 1
+            && self.num_migrants > 0
+            && self.migration_interval > 0
+            && generation.is_multiple_of(self.migration_interval)
+    }
+
+    /// Lets individuals of each island migrate to the next island in the
+    /// ring.
+    fn migrate(&mut self, generation: u64, island_states: &[State<G, F>], rng: &mut Prng) {
+        let emigrants: Vec<Vec<G>> = island_states
+            .iter()
+            .map(|state| {
+                self.emigrant_selector.select_emigrants(
+                    &state.evaluated_population,
+                    self.num_migrants,
+                    rng,
+                )
+            })
+            .collect();
+        let num_islands = self.islands.len();
+        for (from_island, immigrants) in emigrants.into_iter().enumerate() {
+            let to_island = (from_island + 1) % num_islands;
+            let island = &mut self.islands[to_island];
+            let mut population = island.population().to_vec();
+            let fitness_of = |genome: &G| island.fitness_of(genome);
+            let replaced =
+                self.immigrant_replacer
+                    .select_replaced(&population, &immigrants, &fitness_of, rng);
+            let mut best_immigrant_fitness = None;
+            let mut num_improving = 0;
+            for (position, immigrant) in replaced.iter().zip(&immigrants) {
+                let immigrant_fitness = fitness_of(immigrant);
+                if immigrant_fitness > fitness_of(&population[*position]) {
+                    num_improving += 1;
+                }
+                if best_immigrant_fitness
+                    .as_ref()
+                    .is_none_or(|best| immigrant_fitness > *best)
+                {
+                    best_immigrant_fitness = Some(immigrant_fitness);
+                }
+                population[*position] = immigrant.clone();
+            }
+            island.set_population(population);
+            let num_migrants = replaced.len();
+            self.migration_stats[from_island].num_emigrants += num_migrants as u64;
+            let stats = &mut self.migration_stats[to_island];
+            stats.num_immigrants += num_migrants as u64;
+            stats.num_improving_immigrants += num_improving as u64;
+            self.record(MigrationEvent {
+                generation,
+                from_island,
+                to_island,
+                num_migrants,
+                best_immigrant_fitness,
+                num_improving,
+            });
+        }
+    }
+
+    /// Adds the given event to the history. The oldest event is dropped if
+    /// the capacity of the history is exceeded.
+    fn record(&mut self, event: MigrationEvent<F>) {
+        if self.migration_history_capacity == 0 {
+            return;
+        }
+        if self.migration_history.len() == self.migration_history_capacity {
+            self.migration_history.pop_front();
+        }
+        self.migration_history.push_back(event);
+    }
+}
+
+impl<G, F, ES, IR> TrackProcessingTime for IslandModel<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, F, ES, IR> Algorithm for IslandModel<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    type Output = IslandModelState<G, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        output.improves_on(best)
+    }
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.islands.is_empty() {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "Island model in generation {} has no islands.",
+                iteration
+            )));
+        }
+        let mut island_states = Vec::with_capacity(self.islands.len());
+        let mut processing_time = ProcessingTime::zero();
+        for island in &mut self.islands {
+            island_states.push(island.evolve(iteration, rng)?);
+            processing_time += island.processing_time();
+        }
+        let best_island = (0..island_states.len())
+            .max_by(|&a, &b| {
+                let fitness = |index: usize| &island_states[index].best_solution.solution.fitness;
+                // prefer the island that has been added first on equal fitness
+                fitness(a).cmp(fitness(b)).then(b.cmp(&a))
+            })
+            .unwrap_or(0);
+        let migrated = self.is_migration_due(iteration);
+        if migrated {
+            self.migrate(iteration, &island_states, rng);
+        }
+        self.processing_time = processing_time;
+        Ok(IslandModelState {
+            best_solution: island_states[best_island].best_solution.clone(),
+            island_states,
+            best_island,
+            processing_time,
+            migrated,
+            migration_stats: self.migration_stats.clone(),
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.migration_stats = vec![MigrationStats::default(); self.islands.len()];
+        self.migration_history.clear();
+        for island in &mut self.islands {
+            island.reset()?;
+        }
+        Ok(true)
+    }
+}
+
+/// The snapshot of an `IslandModel` can only be restored into a model with
+/// the same islands. Islands without a snapshot keep their progress.
+impl<G, F, ES, IR> Checkpointable for IslandModel<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    type Snapshot = IslandModelSnapshot<G, F>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        IslandModelSnapshot {
+            islands: self
+                .islands
+                .iter()
+                .map(|island| island.snapshot())
+                .collect(),
+            migration_stats: self.migration_stats.clone(),
+            migration_history: self.migration_history.clone(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        for (island, island_snapshot) in self.islands.iter_mut().zip(snapshot.islands) {
+            island.restore(island_snapshot);
+        }
+        self.migration_stats = snapshot.migration_stats;
+        self.migration_stats
+            .resize(self.islands.len(), MigrationStats::default());
+        self.migration_history = snapshot.migration_history;
+    }
+}
+
+/// The `IslandModelBuilder` builds an `IslandModel` from the islands added
+/// one by one.
+pub struct IslandModelBuilder<G, F, ES = BestEmigrants, IR = ReplaceWorst>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    islands: Vec<Box<dyn Island<G, F> + Send>>,
+    migration_interval: u64,
+    num_migrants: usize,
+    emigrant_selector: ES,
+    immigrant_replacer: IR,
+    migration_history_capacity: usize,
+}
+
+impl<G, F, ES, IR> Debug for IslandModelBuilder<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F> + Debug,
+    IR: ImmigrantReplacer<G, F> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IslandModelBuilder")
+            .field("num_islands", &self.islands.len())
+            .field("migration_interval", &self.migration_interval)
+            .field("num_migrants", &self.num_migrants)
+            .field("emigrant_selector", &self.emigrant_selector)
+            .field("immigrant_replacer", &self.immigrant_replacer)
+            .field(
+                "migration_history_capacity",
+                &self.migration_history_capacity,
+            )
+            .finish()
+    }
+}
+
+impl<G, F, ES, IR> IslandModelBuilder<G, F, ES, IR>
+where
+    G: Genotype,
+    F: Fitness,
+    ES: EmigrantSelector<G, F>,
+    IR: ImmigrantReplacer<G, F>,
+{
+    /// Adds the given island to the model.
+    pub fn with_island<I>(mut self, island: I) -> Self
+    where
+        I: Island<G, F> + Send + 'static,
+    {
+        self.islands.push(Box::new(island));
+        self
+    }
+
+    /// Sets the number of generations between two migrations. Default is 10.
+    /// An interval of 0 disables the migration.
+    pub fn with_migration_interval(mut self, migration_interval: u64) -> Self {
+        self.migration_interval = migration_interval;
+        self
+    }
+
+    /// Sets the number of individuals that migrate from each island.
+    /// Default is 1.
+    pub fn with_num_migrants(mut self, num_migrants: usize) -> Self {
+        self.num_migrants = num_migrants;
+        self
+    }
+
+    /// Sets the `EmigrantSelector` that chooses the individuals that leave
+    /// an island. Default is `BestEmigrants`.
+    pub fn with_emigrant_selector<ES2>(
+        self,
+        emigrant_selector: ES2,
+    ) -> IslandModelBuilder<G, F, ES2, IR>
+    where
+        ES2: EmigrantSelector<G, F>,
+    {
+        IslandModelBuilder {
+            islands: self.islands,
+            migration_interval: self.migration_interval,
+            num_migrants: self.num_migrants,
+            emigrant_selector,
+            immigrant_replacer: self.immigrant_replacer,
+            migration_history_capacity: self.migration_history_capacity,
+        }
+    }
+
+    /// Sets the `ImmigrantReplacer` that chooses the individuals that are
+    /// replaced by immigrants. Default is `ReplaceWorst`.
+    pub fn with_immigrant_replacer<IR2>(
+        self,
+        immigrant_replacer: IR2,
+    ) -> IslandModelBuilder<G, F, ES, IR2>
+    where
+        IR2: ImmigrantReplacer<G, F>,
+    {
+        IslandModelBuilder {
+            islands: self.islands,
+            migration_interval: self.migration_interval,
+            num_migrants: self.num_migrants,
+            emigrant_selector: self.emigrant_selector,
+            immigrant_replacer,
+            migration_history_capacity: self.migration_history_capacity,
+        }
+    }
+
+    /// Sets the maximum number of migrations kept in the history. Default is
+    /// 100.
+    pub fn with_migration_history_capacity(mut self, capacity: usize) -> Self {
+        self.migration_history_capacity = capacity;
+        self
+    }
+
+    pub fn build(self) -> IslandModel<G, F, ES, IR> {
+        IslandModel {
+            migration_stats: vec![MigrationStats::default(); self.islands.len()],
+            islands: self.islands,
+            migration_interval: self.migration_interval,
+            num_migrants: self.num_migrants,
+            emigrant_selector: self.emigrant_selector,
+            immigrant_replacer: self.immigrant_replacer,
+            migration_history: VecDeque::with_capacity(self.migration_history_capacity),
+            migration_history_capacity: self.migration_history_capacity,
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+}
+
+/// Creates a new `IslandModelBuilder` without islands.
+pub fn island_model<G, F>() -> IslandModelBuilder<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    IslandModelBuilder {
+        islands: Vec::new(),
+        migration_interval: 10,
+        num_migrants: 1,
+        emigrant_selector: BestEmigrants,
+        immigrant_replacer: ReplaceWorst,
+        migration_history_capacity: 100,
+    }
+}
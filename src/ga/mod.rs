@@ -7,16 +7,21 @@
 //! 2. **Fitness**: Evaluate the fitness of each genotype in the population
 //! 3. **New Population**: Create a new population by repeating following steps
 //!    until the new population is complete:
-//! 3.1. **Selection**: Select a tuple of parent genotypes from a population
-//!      according to their fitness and the selection strategy of the
-//!      configured `operator::SelectionOp`
-//! 3.2. **Crossover**: With a crossover probability cross over the parents to
-//!      form a new offspring (child) by means of the configured
-//!      `operator::CrossoverOp`.
-//! 3.3. **Mutation**: With a mutation probability mutate new offspring at each
-//!      locus (position in genotype) by means of the configured
-//!      `operator::MutationOp`.
-//! 3.4. **Accepting**: Place new offspring in the new population.
+//!    1. **Selection**: Select a tuple of parent genotypes from a population
+//!       according to their fitness and the selection strategy of the
+//!       configured `operator::SelectionOp`
+//!    2. **Crossover**: With a crossover probability cross over the parents to
+//!       form a new offspring (child) by means of the configured
+//!       `operator::CrossoverOp`.
+//!    3. **Mutation**: With a mutation probability mutate new offspring at each
+//!       locus (position in genotype) by means of the configured
+//!       `operator::MutationOp`.
+//!    4. **Repair**: Optionally repair invalid offspring by means of the
+//!       configured `operator::FixerOp`.
+//!    5. **Local Search**: Optionally refine a fraction of the offspring by
+//!       means of the configured `operator::LocalSearchOp`, which turns the
+//!       genetic algorithm into a memetic algorithm.
+//!    6. **Accepting**: Place new offspring in the new population.
 //! 4. **Replace**: Use new generated population for a further run of the
 //!    algorithm.
 //! 5. **Termination**: If the end condition is satisfied, stop, and return the
@@ -25,28 +30,62 @@
 
 pub mod builder;
 
-use self::builder::EmptyGeneticAlgorithmBuilder;
+pub mod island;
+
+pub mod presets;
+mod timeout;
+
+pub mod trace;
+
+use self::{
+    builder::EmptyGeneticAlgorithmBuilder,
+    timeout::TimeoutWorkers,
+    trace::{ChildTrace, Tracing},
+};
 use crate::{
-    algorithm::{Algorithm, BestSolution, EvaluatedPopulation},
-    genetic::{Fitness, FitnessFunction, Genotype, Offspring, Parents},
-    operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
-    population::Population,
-    random::Prng,
-    statistic::{timed, ProcessingTime, TimedResult, TrackProcessingTime},
+    algorithm::{
+        Algorithm, BestSolution, Checkpointable, Evaluated, EvaluatedPopulation, HallOfFame,
+        OptimizationResult, StagedAlgorithm, TieBreaking,
+    },
+    genetic::{
+        AsScalar, EvalError, Fitness, FitnessFunction, Genotype, Offspring, Parents, Provenance,
+    },
+    operator::{
+        count_of_ratio, CrossoverOp, FixerOp, GenerationFeedback, LocalSearchOp, MutationOp,
+        OperatorError, ParentDemand, ReinsertionOp, SelectionOp,
+    },
+    population::{
+        allocator::{allocate, new_population, release, retire, SharedAllocator},
+        Population,
+    },
+    random::{random_index, Prng, SliceRandom},
+    statistic::{
+        history::GenerationStatistics, timed, PopulationDiversity, ProcessingTime, TimedResult,
+        TrackProcessingTime,
+    },
 };
-use chrono::Local;
-#[cfg(not(target_arch = "wasm32"))]
+use chrono::{Duration, Local};
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
 use rayon;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::{
-    fmt::{self, Display},
+    borrow::Cow,
+    collections::HashMap,
+    fmt::{self, Debug, Display},
+    hash::Hash,
     marker::PhantomData,
-    rc::Rc,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
 };
 
 /// The `State` struct holds the results of one pass of the genetic algorithm
 /// loop, i.e. the processing of the evolution from one generation to the next
 /// generation.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct State<G, F>
 where
     G: Genotype,
@@ -59,60 +98,1553 @@ where
     /// Processing time for this generation. In case of parallel processing it
     /// is the accumulated time spent by each thread.
     pub processing_time: ProcessingTime,
+    /// Number of fitness values of this generation that have been calculated
+    /// by the fitness function, including the evaluations that timed out or
+    /// failed and the evaluations of repaired genomes. Fitness values taken
+    /// from the cache of the fitness function are not counted.
+    pub fitness_evaluations: usize,
+    /// Number of genomes of this generation whose fitness evaluation timed
+    /// out and which got the fallback fitness value.
+    pub evaluation_timeouts: usize,
+    /// Number of genomes of this generation whose fitness evaluation failed
+    /// and which have been handled according to the `EvaluationErrorPolicy`.
+    pub evaluation_failures: usize,
+    /// The success of the offspring bred from the previous generation, whose
+    /// children have been evaluated with this generation, if the algorithm
+    /// has been configured to track it. It is `None` for the first
+    /// generation.
+    pub offspring_success: Option<OffspringSuccess>,
+    /// The number of individuals the parents have been selected from. It is
+    /// smaller than the size of the evaluated population if the algorithm
+    /// has been configured to select from a subsample.
+    pub selection_sample_size: usize,
+    /// The diversity of the evaluated population, if the algorithm has been
+    /// configured to track it.
+    pub diversity: Option<PopulationDiversity>,
+    /// The best distinct solutions found since the start of the run up to
+    /// and including this generation, if the algorithm has been configured
+    /// to keep a hall of fame.
+    pub hall_of_fame: Option<HallOfFame<G, F>>,
 }
 
-/// An error that can occur during execution of a `GeneticAlgorithm`.
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub enum GeneticAlgorithmError {
-    /// The algorithm is run with an empty population.
-    EmptyPopulation(String),
-    /// The algorithm is run with an population size that is smaller than the
-    /// required minimum.
-    PopulationTooSmall(String),
+impl<G, F> OptimizationResult<G, F> for State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<G, F> {
+        &self.best_solution
+    }
+}
+
+impl<G, F> GenerationStatistics for State<G, F>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+{
+    fn highest_fitness_value(&self) -> f64 {
+        self.evaluated_population.highest_fitness().as_scalar()
+    }
+
+    fn average_fitness_value(&self) -> f64 {
+        self.evaluated_population.average_fitness().as_scalar()
+    }
+
+    fn lowest_fitness_value(&self) -> f64 {
+        self.evaluated_population.lowest_fitness().as_scalar()
+    }
+
+    fn population_diversity(&self) -> Option<PopulationDiversity> {
+        self.diversity
+    }
+}
+
+impl<G, F> State<G, F>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+{
+    /// Returns the key figures of this generation as `GenerationSummary`.
+    pub fn summary(&self) -> GenerationSummary {
+        GenerationSummary {
+            generation: self.best_solution.generation,
+            population_size: self.evaluated_population.fitness_values().len() as u64,
+            highest_fitness: self.evaluated_population.highest_fitness().as_scalar(),
+            average_fitness: self.evaluated_population.average_fitness().as_scalar(),
+            lowest_fitness: self.evaluated_population.lowest_fitness().as_scalar(),
+            best_fitness: self.best_solution.solution.fitness.as_scalar(),
+            processing_time_micros: self
+                .processing_time
+                .duration()
+                .num_microseconds()
+                .unwrap_or(i64::MAX),
+            evaluation_timeouts: self.evaluation_timeouts as u64,
+            selection_sample_size: self.selection_sample_size as u64,
+        }
+    }
+}
+
+/// The `GenerationSummary` holds the key figures of one generation of the
+/// genetic algorithm as plain numbers.
+///
+/// Other than the `State` it is not generic and has a fixed C compatible
+/// memory layout, so it can be handed over as is to bindings for other
+/// languages.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GenerationSummary {
+    /// The number of the generation.
+    pub generation: u64,
+    /// The number of individuals of the evaluated population.
+    pub population_size: u64,
+    /// The highest fitness value of the population.
+    pub highest_fitness: f64,
+    /// The average fitness value of the population.
+    pub average_fitness: f64,
+    /// The lowest fitness value of the population.
+    pub lowest_fitness: f64,
+    /// The fitness value of the best solution of this generation.
+    pub best_fitness: f64,
+    /// The processing time of this generation in microseconds.
+    pub processing_time_micros: i64,
+    /// The number of fitness evaluations that timed out.
+    pub evaluation_timeouts: u64,
+    /// The number of individuals the parents have been selected from.
+    pub selection_sample_size: u64,
+}
+
+/// The `OffspringSuccess` counts the children of a generation whose fitness
+/// exceeds the fitness of the best of their parents.
+///
+/// The ratio of successful children is the key signal of the 1/5th success
+/// rule and of the adaptation of operator portfolios: a high ratio means the
+/// operators can explore more boldly, a low ratio means they should refine
+/// the solutions found so far.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffspringSuccess {
+    /// The number of children that have been bred.
+    pub num_children: usize,
+    /// The number of children that are fitter than the best of their
+    /// parents.
+    pub num_successful: usize,
+}
+
+impl OffspringSuccess {
+    /// Returns the fraction of children that are fitter than the best of
+    /// their parents, or 0 if no children have been bred.
+    pub fn ratio(&self) -> f64 {
+        if self.num_children == 0 {
+            0.
+        } else {
+            self.num_successful as f64 / self.num_children as f64
+        }
+    }
+}
+
+/// The function that calculates the `PopulationDiversity` of a generation.
+///
+/// It captures the bounds on the genotype and the fitness that the
+/// calculation requires, so that the `GeneticAlgorithm` does not need them.
+pub(crate) struct DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    diversity_of: fn(&EvaluatedPopulation<G, F>) -> PopulationDiversity,
+}
+
+impl<G, F> DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    pub(crate) fn new(diversity_of: fn(&EvaluatedPopulation<G, F>) -> PopulationDiversity) -> Self {
+        DiversityTracking { diversity_of }
+    }
+}
+
+impl<G, F> Clone for DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G, F> Copy for DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+}
+
+impl<G, F> Debug for DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("DiversityTracking")
+    }
+}
+
+/// All `DiversityTracking`s are equal, the functions are not compared.
+impl<G, F> PartialEq for DiversityTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// The `HallOfFameTracking` holds the `HallOfFame` of a `GeneticAlgorithm`
+/// and the function that updates it with a generation.
+///
+/// The function captures the bound on the genotype that the update
+/// requires, so that the `GeneticAlgorithm` does not need it.
+pub(crate) struct HallOfFameTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    hall_of_fame: HallOfFame<G, F>,
+    update: fn(&mut HallOfFame<G, F>, &EvaluatedPopulation<G, F>),
+}
+
+impl<G, F> HallOfFameTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    pub(crate) fn new(
+        capacity: usize,
+        update: fn(&mut HallOfFame<G, F>, &EvaluatedPopulation<G, F>),
+    ) -> Self {
+        HallOfFameTracking {
+            hall_of_fame: HallOfFame::new(capacity),
+            update,
+        }
+    }
+}
+
+impl<G, F> Clone for HallOfFameTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn clone(&self) -> Self {
+        HallOfFameTracking {
+            hall_of_fame: self.hall_of_fame.clone(),
+            update: self.update,
+        }
+    }
+}
+
+impl<G, F> Debug for HallOfFameTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HallOfFameTracking")
+            .field("hall_of_fame", &self.hall_of_fame)
+            .finish()
+    }
+}
+
+/// Two `HallOfFameTracking`s are equal if their halls of fame are equal, the
+/// functions are not compared.
+impl<G, F> PartialEq for HallOfFameTracking<G, F>
+where
+    G: Genotype + PartialEq,
+    F: Fitness,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hall_of_fame == other.hall_of_fame
+    }
+}
+
+/// The `SuccessTracking` holds the children bred from the last generation
+/// until they have been evaluated with the next generation, and the function
+/// that looks up individuals in an evaluated population.
+///
+/// The fitness of the parents is looked up in the evaluated population they
+/// have been selected from, and the fitness of the children in the evaluated
+/// population of the next generation, so that no genome is evaluated twice.
+/// All individuals of a generation are looked up at once through an index
+/// of the evaluated population. The function captures the bound on the
+/// genotype that the index requires, so that the `GeneticAlgorithm` does not
+/// need it.
+pub(crate) struct SuccessTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The number of children bred from the last generation, or `None` if
+    /// no children have been bred since the last evaluation.
+    num_children: Option<usize>,
+    /// The children together with the fitness of the best of their parents.
+    children: Vec<(G, F)>,
+    indices_of: IndicesOfFn<G, F>,
+}
+
+/// The function that returns the index of each of the given genomes in the
+/// evaluated population, or `None` for genomes that are not part of it.
+type IndicesOfFn<G, F> = fn(&EvaluatedPopulation<G, F>, &[&G]) -> Vec<Option<usize>>;
+
+impl<G, F> SuccessTracking<G, F>
+where
+    G: Genotype + Hash + Eq,
+    F: Fitness,
+{
+    pub(crate) fn new() -> Self {
+        SuccessTracking {
+            num_children: None,
+            children: Vec::new(),
+            indices_of: |evaluated, genomes| {
+                let individuals = evaluated.individuals();
+                // in reverse, so that the first of equal individuals is found
+                let indices: HashMap<&G, usize> = individuals
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(index, individual)| (individual, index))
+                    .collect();
+                genomes
+                    .iter()
+                    .map(|genome| indices.get(genome).copied())
+                    .collect()
+            },
+        }
+    }
+}
+
+impl<G, F> SuccessTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Returns the fitness of the best parent of each group of the given
+    /// parents, if all parents of the group are members of the given
+    /// evaluated population.
+    fn best_parent_fitness(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        parents: &[Parents<G>],
+    ) -> Vec<Option<F>> {
+        let genomes: Vec<&G> = parents.iter().flatten().collect();
+        let fitness_values = evaluated.fitness_values();
+        let mut indices = (self.indices_of)(evaluated, &genomes).into_iter();
+        parents
+            .iter()
+            .map(|group| {
+                let group_indices: Vec<Option<usize>> =
+                    indices.by_ref().take(group.len()).collect();
+                group_indices
+                    .into_iter()
+                    .map(|index| index.map(|index| &fitness_values[index]))
+                    .collect::<Option<Vec<&F>>>()
+                    .and_then(|fitness_values| fitness_values.into_iter().max().cloned())
+            })
+            .collect()
+    }
+
+    /// Keeps the children of the given offspring until the next evaluation.
+    /// The fitness of the best parents is given for each group of parents
+    /// together with the number of children the group has bred, in the
+    /// order of the offspring.
+    fn offspring_bred(
+        &mut self,
+        offspring: &[G],
+        best_parent_fitness: Vec<Option<F>>,
+        num_children: &[usize],
+    ) {
+        self.num_children = Some(offspring.len());
+        self.children = best_parent_fitness
+            .into_iter()
+            .zip(num_children)
+            .flat_map(|(fitness, &num_children)| std::iter::repeat_n(fitness, num_children))
+            .zip(offspring)
+            .filter_map(|(fitness, child)| fitness.map(|fitness| (child.clone(), fitness)))
+            .collect();
+    }
+
+    /// Compares the children bred from the last generation with the best of
+    /// their parents. Children that are not part of the given evaluated
+    /// population, as they have not been reinserted or their evaluation
+    /// failed, are not successful.
+    ///
+    /// Returns `None` if no children have been bred since the last
+    /// evaluation.
+    fn offspring_evaluated(
+        &mut self,
+        evaluated: &EvaluatedPopulation<G, F>,
+    ) -> Option<OffspringSuccess> {
+        let num_children = self.num_children.take()?;
+        let children = std::mem::take(&mut self.children);
+        let genomes: Vec<&G> = children.iter().map(|(child, _)| child).collect();
+        let fitness_values = evaluated.fitness_values();
+        let num_successful = (self.indices_of)(evaluated, &genomes)
+            .into_iter()
+            .zip(&children)
+            .filter(|(index, (_, best_parent_fitness))| {
+                index.is_some_and(|index| fitness_values[index] > *best_parent_fitness)
+            })
+            .count();
+        Some(OffspringSuccess {
+            num_children,
+            num_successful,
+        })
+    }
+
+    /// Drops the children that have not been evaluated yet.
+    fn clear(&mut self) {
+        self.num_children = None;
+        self.children.clear();
+    }
+}
+
+impl<G, F> Clone for SuccessTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn clone(&self) -> Self {
+        SuccessTracking {
+            num_children: self.num_children,
+            children: self.children.clone(),
+            indices_of: self.indices_of,
+        }
+    }
+}
+
+impl<G, F> Debug for SuccessTracking<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SuccessTracking")
+            .field("num_children", &self.num_children)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+/// Two `SuccessTracking`s are equal if they hold the same children, the
+/// functions are not compared.
+impl<G, F> PartialEq for SuccessTracking<G, F>
+where
+    G: Genotype + PartialEq,
+    F: Fitness,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.num_children == other.num_children && self.children == other.children
+    }
+}
+
+/// The function that repairs a child with an `operator::FixerOp`.
+type FixFn<G> = dyn Fn(&mut G, &mut Prng) + Send + Sync;
+
+/// The `operator::FixerOp` that repairs the offspring of each generation.
+///
+/// It hides the type of the fixer, so that the `GeneticAlgorithm` does not
+/// need a type parameter for it. Clones of the algorithm share the same
+/// fixer.
+pub(crate) struct SharedFixer<G>
+where
+    G: Genotype,
+{
+    name: String,
+    fix: Arc<FixFn<G>>,
+}
+
+impl<G> SharedFixer<G>
+where
+    G: Genotype,
+{
+    pub(crate) fn new<X>(fixer: X) -> Self
+    where
+        X: FixerOp<G> + Send + Sync + 'static,
+    {
+        SharedFixer {
+            name: X::name(),
+            fix: Arc::new(move |genome, rng| fixer.fix(genome, rng)),
+        }
+    }
+}
+
+impl<G> Clone for SharedFixer<G>
+where
+    G: Genotype,
+{
+    fn clone(&self) -> Self {
+        SharedFixer {
+            name: self.name.clone(),
+            fix: self.fix.clone(),
+        }
+    }
+}
+
+impl<G> Debug for SharedFixer<G>
+where
+    G: Genotype,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SharedFixer").field(&self.name).finish()
+    }
+}
+
+/// Two `SharedFixer`s are equal if they share the same fixer.
+impl<G> PartialEq for SharedFixer<G>
+where
+    G: Genotype,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.fix, &other.fix)
+    }
+}
+
+/// The function that refines a child with an `operator::LocalSearchOp`.
+type ImproveFn<G, E> = dyn Fn(G, &E, &mut Prng) -> G + Send + Sync;
+
+/// The `operator::LocalSearchOp` that refines a fraction of the offspring
+/// of each generation.
+///
+/// Like the `SharedFixer` it hides the type of the local search, and clones
+/// of the algorithm share the same local search.
+pub(crate) struct SharedLocalSearch<G, E>
+where
+    G: Genotype,
+{
+    name: String,
+    ratio: f64,
+    improve: Arc<ImproveFn<G, E>>,
+}
+
+impl<G, E> SharedLocalSearch<G, E>
+where
+    G: Genotype,
+{
+    pub(crate) fn new<F, L>(local_search: L, ratio: f64) -> Self
+    where
+        F: Fitness,
+        E: FitnessFunction<G, F>,
+        L: LocalSearchOp<G, F> + Send + Sync + 'static,
+    {
+        SharedLocalSearch {
+            name: L::name(),
+            ratio,
+            improve: Arc::new(move |genome, evaluator, rng| {
+                local_search.improve(genome, evaluator, rng)
+            }),
+        }
+    }
+
+    /// Refines the given ratio of randomly chosen children of the offspring.
+    fn refine(&self, children: &mut [G], evaluator: &E, rng: &mut Prng) {
+        let mut indices: Vec<usize> = (0..children.len()).collect();
+        let count = count_of_ratio(indices.len(), self.ratio);
+        let (chosen, _) = indices.partial_shuffle(rng, count);
+        for &index in chosen.iter() {
+            let child = children[index].clone();
+            children[index] = (self.improve)(child, evaluator, rng);
+        }
+    }
+}
+
+impl<G, E> Clone for SharedLocalSearch<G, E>
+where
+    G: Genotype,
+{
+    fn clone(&self) -> Self {
+        SharedLocalSearch {
+            name: self.name.clone(),
+            ratio: self.ratio,
+            improve: self.improve.clone(),
+        }
+    }
+}
+
+impl<G, E> Debug for SharedLocalSearch<G, E>
+where
+    G: Genotype,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedLocalSearch")
+            .field("name", &self.name)
+            .field("ratio", &self.ratio)
+            .finish()
+    }
+}
+
+/// Two `SharedLocalSearch`es are equal if they share the same local search
+/// and refine the same ratio of the offspring.
+impl<G, E> PartialEq for SharedLocalSearch<G, E>
+where
+    G: Genotype,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.improve, &other.improve) && self.ratio == other.ratio
+    }
+}
+
+/// The rayon thread pool the `GeneticAlgorithm` evaluates and breeds its
+/// populations in. Clones of the algorithm share the same thread pool.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub(crate) struct SharedThreadPool(Arc<rayon::ThreadPool>);
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SharedThreadPool {
+    pub(crate) fn new(pool: Arc<rayon::ThreadPool>) -> Self {
+        SharedThreadPool(pool)
+    }
+
+    /// Runs the given function in the given thread pool, or in the global
+    /// rayon thread pool if no thread pool is given.
+    fn install<T, OP>(pool: Option<&Self>, op: OP) -> T
+    where
+        OP: FnOnce() -> T + Send,
+        T: Send,
+    {
+        match pool {
+            Some(pool) => pool.0.install(op),
+            None => op(),
+        }
+    }
+}
+
+/// Two `SharedThreadPool`s are equal if they share the same thread pool.
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialEq for SharedThreadPool {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
-impl Display for GeneticAlgorithmError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            GeneticAlgorithmError::EmptyPopulation(details) => write!(f, "{}", details),
-            GeneticAlgorithmError::PopulationTooSmall(details) => write!(f, "{}", details),
+/// On wasm32 there are no threads, so the `GeneticAlgorithm` always runs on
+/// the current thread.
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum SharedThreadPool {}
+
+#[cfg(target_arch = "wasm32")]
+impl SharedThreadPool {
+    fn install<T, OP>(_pool: Option<&Self>, op: OP) -> T
+    where
+        OP: FnOnce() -> T,
+    {
+        op()
+    }
+}
+
+/// The `Stage` of a generation that a `GeneticAlgorithm` processes next.
+///
+/// The stages of a generation are processed in the order `Evaluate`,
+/// `Select`, `Breed` and `Reinsert`. The `Algorithm::next` function
+/// processes all remaining stages of a generation at once, while the
+/// `StagedAlgorithm::next_stage` function processes one stage at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// Evaluates the fitness of the population and determines the best
+    /// solution of the generation.
+    Evaluate,
+    /// Selects the parents for breeding.
+    Select,
+    /// Breeds and mutates the offspring of the selected parents, repairs it,
+    /// if a fixer is set, and refines it, if a local search is set.
+    Breed,
+    /// Combines the offspring and the population to the population of the
+    /// next generation.
+    Reinsert,
+}
+
+/// The `Pipeline` holds the intermediate results of the generation that is
+/// in progress.
+#[derive(Clone, Debug, PartialEq)]
+enum Pipeline<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    Evaluate,
+    Select(Evaluation<G, F>),
+    Breed(Evaluation<G, F>, Vec<Parents<G>>),
+    Reinsert(Evaluation<G, F>, Offspring<G>),
+}
+
+impl<G, F> Pipeline<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn stage(&self) -> Stage {
+        match self {
+            Pipeline::Evaluate => Stage::Evaluate,
+            Pipeline::Select(_) => Stage::Select,
+            Pipeline::Breed(_, _) => Stage::Breed,
+            Pipeline::Reinsert(_, _) => Stage::Reinsert,
+        }
+    }
+}
+
+/// The result of the evaluation stage of a generation.
+#[derive(Clone, Debug, PartialEq)]
+struct Evaluation<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    evaluated_population: EvaluatedPopulation<G, F>,
+    best_solution: BestSolution<G, F>,
+    fitness_evaluations: usize,
+    evaluation_timeouts: usize,
+    evaluation_failures: usize,
+    /// The number of individuals the parents have been selected from.
+    selection_sample_size: usize,
+    diversity: Option<PopulationDiversity>,
+    hall_of_fame: Option<HallOfFame<G, F>>,
+    /// The processing time of the stages processed so far.
+    processing_time: ProcessingTime,
+}
+
+/// An error that can occur during execution of a `GeneticAlgorithm`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeneticAlgorithmError {
+    /// The algorithm is run with an empty population.
+    EmptyPopulation(String),
+    /// The algorithm is run with an population size that is smaller than the
+    /// required minimum.
+    PopulationTooSmall(String),
+    /// A genetic operator failed in the given stage of a generation.
+    OperatorFailed {
+        /// The stage in which the operator failed.
+        stage: Stage,
+        /// The description of the failure.
+        details: String,
+    },
+    /// The trace of a generation can not be written.
+    TraceFailed(String),
+    /// The thread pool for the algorithm can not be built.
+    ThreadPoolFailed(String),
+    /// The fitness of a genome can not be evaluated and the
+    /// `EvaluationErrorPolicy` is `Abort`.
+    EvaluationFailed(String),
+}
+
+impl Display for GeneticAlgorithmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneticAlgorithmError::EmptyPopulation(details) => write!(f, "{}", details),
+            GeneticAlgorithmError::PopulationTooSmall(details) => write!(f, "{}", details),
+            GeneticAlgorithmError::OperatorFailed { details, .. } => write!(f, "{}", details),
+            GeneticAlgorithmError::TraceFailed(details) => write!(f, "{}", details),
+            GeneticAlgorithmError::ThreadPoolFailed(details) => write!(f, "{}", details),
+            GeneticAlgorithmError::EvaluationFailed(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for GeneticAlgorithmError {}
+
+pub fn genetic_algorithm<G, F>() -> EmptyGeneticAlgorithmBuilder<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    EmptyGeneticAlgorithmBuilder::new()
+}
+
+/// Evaluates the given candidates with the given `FitnessFunction` and
+/// returns them ranked from the highest to the lowest fitness. Candidates
+/// with equal fitness keep their order in the given slice.
+///
+/// The candidates are evaluated in parallel like the populations of the
+/// `GeneticAlgorithm`, but independent of the algorithm. This way externally
+/// generated solutions can be scored and compared, e.g. to validate them.
+/// Candidates whose evaluation fails get the lowest possible fitness.
+pub fn rank_candidates<G, F, E>(evaluator: &E, candidates: &[G]) -> Vec<Evaluated<G, F>>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+{
+    let fitness = par_evaluate_fitness(candidates, evaluator, None)
+        .result
+        .fitness;
+    let mut ranked: Vec<Evaluated<G, F>> = candidates
+        .iter()
+        .cloned()
+        .zip(fitness)
+        .map(|(genome, fitness)| Evaluated { genome, fitness })
+        .collect();
+    ranked.sort_by(|x, y| y.fitness.cmp(&x.fitness));
+    ranked
+}
+
+/// The `EvaluationErrorPolicy` defines how the `GeneticAlgorithm` handles
+/// genomes whose fitness can not be evaluated, because
+/// `genetic::FitnessFunction::try_fitness_of` returns a `genetic::EvalError`
+/// or the fitness function panics. Evaluations that time out are handled by
+/// the `EvaluationTimeout` instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EvaluationErrorPolicy {
+    /// Stops the algorithm with a `GeneticAlgorithmError::EvaluationFailed`.
+    /// This is the default.
+    #[default]
+    Abort,
+    /// Assigns the lowest possible fitness to the genome, so that it is
+    /// unlikely to be selected or to survive.
+    LowestFitness,
+    /// Removes the genome from the population of the generation. The
+    /// population shrinks by the discarded genomes until it gets smaller
+    /// than the required minimum size.
+    Discard,
+    /// Repairs the genome with the `operator::FixerOp` of the algorithm and
+    /// evaluates it again. The repaired genome replaces the original one if
+    /// its evaluation succeeds. Otherwise, or if no fixer is set, the genome
+    /// gets the lowest possible fitness.
+    RepairAndRetry,
+}
+
+/// The `EvaluationTimeout` limits the time the evaluation of the fitness of
+/// one genome may take. See `GeneticAlgorithmBuilder::with_evaluation_timeout`.
+///
+/// Timeouts are not supported on wasm32 targets, where the evaluation always
+/// runs to completion.
+#[derive(Clone, Debug)]
+pub struct EvaluationTimeout<G, F, E> {
+    timeout: Duration,
+    fallback: F,
+    // the worker threads the genomes are evaluated on; clones of the
+    // algorithm share the same workers
+    workers: Arc<TimeoutWorkers>,
+    // evaluates a genome on one of the workers; a function pointer keeps
+    // the `'static` bounds of the workers' jobs out of the `GeneticAlgorithm`
+    evaluate: TimedEvaluationFn<G, F, E>,
+}
+
+/// Evaluates a genome on one of the `TimeoutWorkers` and returns `None` if
+/// the evaluation timed out.
+type TimedEvaluationFn<G, F, E> =
+    fn(&TimeoutWorkers, &E, &G, Duration) -> Option<Result<F, EvalError>>;
+
+impl<G, F, E> EvaluationTimeout<G, F, E>
+where
+    G: Genotype + 'static,
+    F: Fitness + Send + 'static,
+    E: FitnessFunction<G, F> + Send + 'static,
+{
+    pub(crate) fn new(timeout: Duration, fallback: F) -> Self {
+        EvaluationTimeout {
+            timeout,
+            fallback,
+            workers: Arc::new(TimeoutWorkers::new()),
+            evaluate: fitness_within_timeout::<G, F, E>,
+        }
+    }
+}
+
+impl<G, F, E> PartialEq for EvaluationTimeout<G, F, E>
+where
+    F: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.timeout == other.timeout && self.fallback == other.fallback
+    }
+}
+
+impl<G, F, E> EvaluationTimeout<G, F, E> {
+    /// Returns the maximum duration of the evaluation of one genome.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the fitness value of genomes whose evaluation timed out.
+    pub fn fallback(&self) -> &F {
+        &self.fallback
+    }
+}
+
+/// The `Snapshot` holds the progress of a `GeneticAlgorithm`, i.e. the
+/// current population and the tracking of the highest fitness that is fed
+/// back to the operators.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: Serialize, F: Serialize",
+        deserialize = "G: Deserialize<'de>, F: Deserialize<'de>"
+    ))
+)]
+pub struct Snapshot<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The individuals of the population of the next generation.
+    pub population: Vec<G>,
+    /// The highest fitness found so far.
+    pub best_fitness: Option<F>,
+    /// The number of generations in which the highest fitness has not been
+    /// improved.
+    pub stagnant_generations: u64,
+    /// The hall of fame, if the algorithm keeps one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hall_of_fame: Option<HallOfFame<G, F>>,
+}
+
+/// A `GeneticAlgorithm` declares the building blocks that make up the actual
+/// algorithm for a specific optimization problem.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+    R: ReinsertionOp<G, F>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    selector: S,
+    breeder: C,
+    mutator: M,
+    reinserter: R,
+    min_population_size: usize,
+    evaluation_timeout: Option<EvaluationTimeout<G, F, E>>,
+    evaluation_error_policy: EvaluationErrorPolicy,
+    allocator: Option<SharedAllocator<G>>,
+    tie_breaking: TieBreaking<G>,
+    initial_population: Population<G>,
+    population: Arc<Vec<G>>,
+    processing_time: ProcessingTime,
+    best_fitness: Option<F>,
+    stagnant_generations: u64,
+    success_tracking: Option<SuccessTracking<G, F>>,
+    elitism: usize,
+    selection_subsample: Option<f64>,
+    pinned_offspring: Option<usize>,
+    fixer: Option<SharedFixer<G>>,
+    local_search: Option<SharedLocalSearch<G, E>>,
+    diversity_tracking: Option<DiversityTracking<G, F>>,
+    hall_of_fame: Option<HallOfFameTracking<G, F>>,
+    tracing: Option<Tracing<G>>,
+    thread_pool: Option<SharedThreadPool>,
+    offspring_success: Option<OffspringSuccess>,
+    pipeline: Pipeline<G, F>,
+}
+
+impl<G, F, E, S, C, M, R> GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+    R: ReinsertionOp<G, F>,
+{
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn selector(&self) -> &S {
+        &self.selector
+    }
+
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    pub fn reinserter(&self) -> &R {
+        &self.reinserter
+    }
+
+    pub fn min_population_size(&self) -> usize {
+        self.min_population_size
+    }
+
+    pub fn evaluation_timeout(&self) -> Option<&EvaluationTimeout<G, F, E>> {
+        self.evaluation_timeout.as_ref()
+    }
+
+    /// Returns how genomes whose fitness evaluation fails are handled.
+    pub fn evaluation_error_policy(&self) -> EvaluationErrorPolicy {
+        self.evaluation_error_policy
+    }
+
+    /// Returns how the best solution is chosen among individuals of equal
+    /// fitness.
+    pub fn tie_breaking(&self) -> &TieBreaking<G> {
+        &self.tie_breaking
+    }
+
+    /// Returns whether the success of the offspring is tracked.
+    pub fn tracks_offspring_success(&self) -> bool {
+        self.success_tracking.is_some()
+    }
+
+    /// Returns the number of the fittest individuals of each generation that
+    /// are copied unchanged into the next generation.
+    pub fn elitism(&self) -> usize {
+        self.elitism
+    }
+
+    /// Sets the number of the fittest individuals of each generation that
+    /// are copied unchanged into the next generation.
+    pub fn set_elitism(&mut self, elitism: usize) {
+        self.elitism = elitism;
+    }
+
+    /// Returns the fraction of the evaluated population the parents are
+    /// selected from, or `None` if they are selected from the whole
+    /// population.
+    pub fn selection_subsample(&self) -> Option<f64> {
+        self.selection_subsample
+    }
+
+    /// Sets the fraction of the evaluated population the parents are
+    /// selected from. `None` selects from the whole population.
+    pub fn set_selection_subsample(&mut self, fraction: Option<f64>) {
+        self.selection_subsample = fraction;
+    }
+
+    /// Returns whether the diversity of the population is tracked.
+    pub fn tracks_diversity(&self) -> bool {
+        self.diversity_tracking.is_some()
+    }
+
+    /// Returns the best distinct solutions found so far, or `None` if the
+    /// algorithm does not keep a hall of fame.
+    pub fn hall_of_fame(&self) -> Option<&HallOfFame<G, F>> {
+        self.hall_of_fame
+            .as_ref()
+            .map(|tracking| &tracking.hall_of_fame)
+    }
+
+    /// Returns the number of offspring that is bred in each generation for
+    /// the current population. It is the pinned number of offspring if one
+    /// has been set, otherwise the number of offspring the reinserter
+    /// requires.
+    pub fn required_offspring(&self) -> usize {
+        self.pinned_offspring
+            .unwrap_or_else(|| self.reinserter.required_offspring(self.population.len()))
+    }
+
+    /// Returns the name of the `operator::FixerOp` that repairs the
+    /// offspring, or `None` if the offspring is not repaired.
+    pub fn fixer_name(&self) -> Option<&str> {
+        self.fixer.as_ref().map(|fixer| fixer.name.as_str())
+    }
+
+    /// Returns the name of the `operator::LocalSearchOp` that refines the
+    /// offspring, or `None` if the offspring is not refined.
+    pub fn local_search_name(&self) -> Option<&str> {
+        self.local_search
+            .as_ref()
+            .map(|local_search| local_search.name.as_str())
+    }
+
+    /// Returns the fraction of the offspring that is refined by the local
+    /// search, or `None` if the offspring is not refined.
+    pub fn local_search_ratio(&self) -> Option<f64> {
+        self.local_search
+            .as_ref()
+            .map(|local_search| local_search.ratio)
+    }
+
+    /// Returns the number of threads of the thread pool the populations are
+    /// evaluated and bred in, or `None` if the global rayon thread pool is
+    /// used.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn num_threads(&self) -> Option<usize> {
+        self.thread_pool
+            .as_ref()
+            .map(|pool| pool.0.current_num_threads())
+    }
+
+    /// Returns the pinned number of offspring, if any.
+    pub fn pinned_offspring(&self) -> Option<usize> {
+        self.pinned_offspring
+    }
+
+    /// Pins the number of offspring that is bred in each generation, or
+    /// unpins it if `None` is given.
+    pub fn set_pinned_offspring(&mut self, num_offspring: Option<usize>) {
+        self.pinned_offspring = num_offspring;
+    }
+
+    /// Returns the stage of the current generation that is processed next.
+    pub fn stage(&self) -> Stage {
+        self.pipeline.stage()
+    }
+
+    pub fn selector_mut(&mut self) -> &mut S {
+        &mut self.selector
+    }
+
+    pub fn breeder_mut(&mut self) -> &mut C {
+        &mut self.breeder
+    }
+
+    pub fn mutator_mut(&mut self) -> &mut M {
+        &mut self.mutator
+    }
+
+    pub fn reinserter_mut(&mut self) -> &mut R {
+        &mut self.reinserter
+    }
+}
+
+impl<G, F, E, S, C, M, R> TrackProcessingTime for GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+    R: ReinsertionOp<G, F>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, F, E, S, C, M, R> GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    /// Returns the `Tracing` if the given generation is traced.
+    fn tracing_of(&self, iteration: u64) -> Option<&Tracing<G>> {
+        self.tracing
+            .as_ref()
+            .filter(|tracing| tracing.traces(iteration))
+    }
+
+    /// Processes the next stage of the given generation. Returns the state
+    /// of the generation after its last stage has been processed.
+    fn process_stage(
+        &mut self,
+        iteration: u64,
+        rng: &mut Prng,
+    ) -> Result<Option<State<G, F>>, GeneticAlgorithmError> {
+        match std::mem::replace(&mut self.pipeline, Pipeline::Evaluate) {
+            Pipeline::Evaluate => {
+                let evaluation = self.evaluate(iteration, rng)?;
+                self.pipeline = Pipeline::Select(evaluation);
+                Ok(None)
+            },
+            Pipeline::Select(mut evaluation) => {
+                // Stage 3: The making of a new population:
+                let demand = ParentDemand::new(self.required_offspring());
+                let evaluator = &self.evaluator;
+                let selector = &self.selector;
+                let subsample = self.selection_subsample;
+                let selection = timed(|| {
+                    let sample = match subsample {
+                        Some(fraction) => Cow::Owned(subsample_population(
+                            &evaluation.evaluated_population,
+                            fraction,
+                            evaluator,
+                            rng,
+                        )),
+                        None => Cow::Borrowed(&evaluation.evaluated_population),
+                    };
+                    let sample_size = sample.fitness_values().len();
+                    let parents = selector.select_for(&sample, demand, rng);
+                    (parents, sample_size)
+                })
+                .run();
+                let (parents, sample_size) = selection.result;
+                if let Some(tracing) = self.tracing_of(iteration) {
+                    let population = evaluation.evaluated_population.individuals();
+                    tracing.write(
+                        iteration,
+                        tracing.selection(iteration, &population, &parents),
+                    )?;
+                }
+                evaluation.selection_sample_size = sample_size;
+                evaluation.processing_time += selection.time;
+                self.pipeline = Pipeline::Breed(evaluation, parents);
+                Ok(None)
+            },
+            Pipeline::Breed(mut evaluation, parents) => {
+                let best_parent_fitness = self.success_tracking.as_ref().map(|tracking| {
+                    tracking.best_parent_fitness(&evaluation.evaluated_population, &parents)
+                });
+                let tracing = self.tracing_of(iteration);
+                let TimedResult {
+                    result: breeding,
+                    time: breeding_time,
+                } = SharedThreadPool::install(self.thread_pool.as_ref(), || {
+                    par_breed_offspring(
+                        parents,
+                        &self.breeder,
+                        &self.mutator,
+                        tracing,
+                        self.allocator.as_ref(),
+                        rng,
+                    )
+                });
+                let Breeding {
+                    mut offspring,
+                    num_children,
+                    child_traces,
+                } = breeding?;
+                if let Some(tracing) = tracing {
+                    tracing.write(iteration, tracing.breeding(iteration, child_traces))?;
+                }
+                if let Some(fixer) = &self.fixer {
+                    let repair = timed(|| {
+                        for child in offspring.individuals_mut() {
+                            (fixer.fix)(child, rng);
+                        }
+                    })
+                    .run();
+                    evaluation.processing_time += repair.time;
+                }
+                if let Some(local_search) = &self.local_search {
+                    let evaluator = &self.evaluator;
+                    let refinement =
+                        timed(|| local_search.refine(offspring.individuals_mut(), evaluator, rng))
+                            .run();
+                    evaluation.processing_time += refinement.time;
+                }
+                if let (Some(tracking), Some(best_parent_fitness)) =
+                    (&mut self.success_tracking, best_parent_fitness)
+                {
+                    tracking.offspring_bred(&offspring, best_parent_fitness, &num_children);
+                }
+                offspring.set_provenance(Provenance {
+                    crossover: C::name(),
+                    mutation: M::name(),
+                });
+                evaluation.processing_time += breeding_time;
+                self.pipeline = Pipeline::Reinsert(evaluation, offspring);
+                Ok(None)
+            },
+            Pipeline::Reinsert(evaluation, mut offspring) => {
+                let traced_offspring = self.tracing_of(iteration).map(|_| offspring.to_vec());
+                let reinsertion = timed(|| {
+                    let next_generation = self.reinserter.combine(
+                        &mut offspring,
+                        &evaluation.evaluated_population,
+                        rng,
+                    );
+                    preserve_elites(
+                        next_generation,
+                        &evaluation.evaluated_population,
+                        self.elitism,
+                        &self.tie_breaking,
+                        rng,
+                    )
+                })
+                .run();
+                if let (Some(tracing), Some(traced_offspring)) =
+                    (self.tracing_of(iteration), traced_offspring)
+                {
+                    let record = tracing.reinsertion(
+                        iteration,
+                        &traced_offspring,
+                        &evaluation.evaluated_population.individuals(),
+                        &reinsertion.result,
+                    );
+                    tracing.write(iteration, Some(record))?;
+                }
+
+                // Stage 4: On to the next generation:
+                self.processing_time = evaluation.processing_time + reinsertion.time;
+                release(self.allocator.as_ref(), offspring.take_individuals());
+                let population = new_population(self.allocator.as_ref(), reinsertion.result);
+                retire(
+                    self.allocator.as_ref(),
+                    std::mem::replace(&mut self.population, population),
+                );
+                Ok(Some(State {
+                    evaluated_population: evaluation.evaluated_population,
+                    best_solution: evaluation.best_solution,
+                    processing_time: self.processing_time,
+                    fitness_evaluations: evaluation.fitness_evaluations,
+                    evaluation_timeouts: evaluation.evaluation_timeouts,
+                    evaluation_failures: evaluation.evaluation_failures,
+                    offspring_success: self.offspring_success,
+                    selection_sample_size: evaluation.selection_sample_size,
+                    diversity: evaluation.diversity,
+                    hall_of_fame: evaluation.hall_of_fame,
+                }))
+            },
+        }
+    }
+
+    /// Processes the evaluation stage of the given generation.
+    fn evaluate(
+        &mut self,
+        iteration: u64,
+        rng: &mut Prng,
+    ) -> Result<Evaluation<G, F>, GeneticAlgorithmError> {
+        if self.population.is_empty() {
+            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                "Population of generation {} is empty. The required minimum size for \
+                 populations is {}.",
+                iteration, self.min_population_size
+            )));
+        }
+        if self.population.len() < self.min_population_size {
+            return Err(GeneticAlgorithmError::PopulationTooSmall(format!(
+                "Population of generation {} has a size of {} which is smaller than the \
+                 required minimum size of {}",
+                iteration,
+                self.population.len(),
+                self.min_population_size
+            )));
+        }
+
+        // Stage 2: The fitness check:
+        let TimedResult {
+            result: mut values,
+            time: evaluation_time,
+        } = SharedThreadPool::install(self.thread_pool.as_ref(), || {
+            evaluate_fitness(
+                &self.population,
+                &self.evaluator,
+                self.evaluation_timeout.as_ref(),
+            )
+        });
+        let evaluation_failures = values.failures.len();
+        let handling = timed(|| self.handle_evaluation_failures(&mut values, iteration, rng)).run();
+        handling.result?;
+        let average = timed(|| self.evaluator.average(&values.fitness)).run();
+        let evaluated_population = EvaluatedPopulation::new(
+            self.population.clone(),
+            values.fitness,
+            values.highest,
+            values.lowest,
+            average.result,
+        );
+        self.evaluator
+            .population_evaluated(&self.population, evaluated_population.fitness_values());
+        let offspring_success = timed(|| {
+            self.success_tracking
+                .as_mut()
+                .and_then(|tracking| tracking.offspring_evaluated(&evaluated_population))
+        })
+        .run();
+        self.offspring_success = offspring_success.result;
+        let best_solution =
+            determine_best_solution(iteration, &evaluated_population, &self.tie_breaking, rng);
+        let diversity = timed(|| {
+            self.diversity_tracking
+                .map(|tracking| (tracking.diversity_of)(&evaluated_population))
+        })
+        .run();
+        let hall_of_fame = timed(|| {
+            self.hall_of_fame.as_mut().map(|tracking| {
+                (tracking.update)(&mut tracking.hall_of_fame, &evaluated_population);
+                tracking.hall_of_fame.clone()
+            })
+        })
+        .run();
+        self.feed_back_generation(iteration, &evaluated_population, diversity.result);
+        Ok(Evaluation {
+            selection_sample_size: evaluated_population.fitness_values().len(),
+            evaluated_population,
+            best_solution: best_solution.result,
+            fitness_evaluations: values.evaluations,
+            evaluation_timeouts: values.timeouts,
+            evaluation_failures,
+            diversity: diversity.result,
+            hall_of_fame: hall_of_fame.result,
+            processing_time: evaluation_time
+                + handling.time
+                + offspring_success.time
+                + average.time
+                + best_solution.time
+                + diversity.time
+                + hall_of_fame.time,
+        })
+    }
+
+    /// Handles the genomes whose fitness evaluation failed according to the
+    /// `EvaluationErrorPolicy` of this algorithm.
+    fn handle_evaluation_failures(
+        &mut self,
+        values: &mut FitnessValues<F>,
+        iteration: u64,
+        rng: &mut Prng,
+    ) -> Result<(), GeneticAlgorithmError> {
+        if values.failures.is_empty() {
+            return Ok(());
+        }
+        match self.evaluation_error_policy {
+            EvaluationErrorPolicy::Abort => {
+                let (index, error) = &values.failures[0];
+                return Err(GeneticAlgorithmError::EvaluationFailed(format!(
+                    "Evaluation of genome {} of generation {} failed: {}",
+                    index, iteration, error
+                )));
+            },
+            EvaluationErrorPolicy::LowestFitness => return Ok(()),
+            EvaluationErrorPolicy::Discard => {
+                let failed: Vec<usize> = values.failures.iter().map(|(index, _)| *index).collect();
+                let population = Arc::make_mut(&mut self.population);
+                let mut index = 0;
+                population.retain(|_| {
+                    index += 1;
+                    failed.binary_search(&(index - 1)).is_err()
+                });
+                let mut index = 0;
+                values.fitness.retain(|_| {
+                    index += 1;
+                    failed.binary_search(&(index - 1)).is_err()
+                });
+                if population.is_empty() {
+                    return Err(GeneticAlgorithmError::EmptyPopulation(format!(
+                        "All genomes of generation {} have been discarded, because their \
+                         evaluation failed.",
+                        iteration
+                    )));
+                }
+            },
+            EvaluationErrorPolicy::RepairAndRetry => {
+                if let Some(fixer) = &self.fixer {
+                    let population = Arc::make_mut(&mut self.population);
+                    for (index, _) in &values.failures {
+                        let mut genome = population[*index].clone();
+                        (fixer.fix)(&mut genome, rng);
+                        values.evaluations += 1;
+                        if let Ok(score) = catch_fitness_of(&self.evaluator, &genome) {
+                            population[*index] = genome;
+                            values.fitness[*index] = score;
+                        }
+                    }
+                }
+            },
+        }
+        values.update_bounds(&self.evaluator);
+        Ok(())
+    }
+}
+
+impl<G, F, E, S, C, M, R> Algorithm for GeneticAlgorithm<G, F, E, S, C, M, R>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    type Output = State<G, F>;
+    type Error = GeneticAlgorithmError;
+
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        output.improves_on(best)
+    }
+
+    /// Processes all remaining stages of the current generation.
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        loop {
+            if let Some(state) = self.process_stage(iteration, rng)? {
+                return Ok(state);
+            }
+        }
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.population = Arc::new(self.initial_population.individuals().to_vec());
+        self.best_fitness = None;
+        self.stagnant_generations = 0;
+        self.offspring_success = None;
+        if let Some(tracking) = &mut self.success_tracking {
+            tracking.clear();
         }
+        if let Some(tracking) = &mut self.hall_of_fame {
+            tracking.hall_of_fame.clear();
+        }
+        self.pipeline = Pipeline::Evaluate;
+        Ok(true)
     }
 }
 
-impl std::error::Error for GeneticAlgorithmError {}
-
-pub fn genetic_algorithm<G, F>() -> EmptyGeneticAlgorithmBuilder<G, F>
+/// The `GeneticAlgorithm` processes a generation in the stages as defined by
+/// `Stage`.
+impl<G, F, E, S, C, M, R> StagedAlgorithm for GeneticAlgorithm<G, F, E, S, C, M, R>
 where
     G: Genotype,
-    F: Fitness,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
 {
-    EmptyGeneticAlgorithmBuilder::new()
+    fn next_stage(
+        &mut self,
+        iteration: u64,
+        rng: &mut Prng,
+    ) -> Result<Option<Self::Output>, Self::Error> {
+        self.process_stage(iteration, rng)
+    }
 }
 
-/// A `GeneticAlgorithm` declares the building blocks that make up the actual
-/// algorithm for a specific optimization problem.
-#[derive(Clone, Debug, PartialEq)]
-pub struct GeneticAlgorithm<G, F, E, S, C, M, R>
+impl<G, F, E, S, C, M, R> Checkpointable for GeneticAlgorithm<G, F, E, S, C, M, R>
 where
     G: Genotype,
-    F: Fitness,
-    E: FitnessFunction<G, F>,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
     S: SelectionOp<G, F>,
-    C: CrossoverOp<G>,
-    M: MutationOp<G>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
     R: ReinsertionOp<G, F>,
 {
-    _f: PhantomData<F>,
-    evaluator: E,
-    selector: S,
-    breeder: C,
-    mutator: M,
-    reinserter: R,
-    min_population_size: usize,
-    initial_population: Population<G>,
-    population: Rc<Vec<G>>,
-    processing_time: ProcessingTime,
+    type Snapshot = Snapshot<G, F>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        Snapshot {
+            population: self.population.to_vec(),
+            best_fitness: self.best_fitness.clone(),
+            stagnant_generations: self.stagnant_generations,
+            hall_of_fame: self.hall_of_fame().cloned(),
+        }
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        self.population = Arc::new(snapshot.population);
+        self.best_fitness = snapshot.best_fitness;
+        self.stagnant_generations = snapshot.stagnant_generations;
+        if let Some(tracking) = &mut self.hall_of_fame {
+            tracking.hall_of_fame = snapshot
+                .hall_of_fame
+                .unwrap_or_else(|| HallOfFame::new(tracking.hall_of_fame.capacity()));
+        }
+        if let Some(tracking) = &mut self.success_tracking {
+            tracking.clear();
+        }
+        self.pipeline = Pipeline::Evaluate;
+    }
 }
 
 impl<G, F, E, S, C, M, R> GeneticAlgorithm<G, F, E, S, C, M, R>
@@ -125,228 +1657,534 @@ where
     M: MutationOp<G>,
     R: ReinsertionOp<G, F>,
 {
-    pub fn evaluator(&self) -> &E {
-        &self.evaluator
-    }
-
-    pub fn selector(&self) -> &S {
-        &self.selector
-    }
-
-    pub fn breeder(&self) -> &C {
-        &self.breeder
+    /// Tracks the progress of the highest fitness and passes the feedback
+    /// about the evaluated generation to the `GenerationAware` operators.
+    fn feed_back_generation(
+        &mut self,
+        generation: u64,
+        evaluated: &EvaluatedPopulation<G, F>,
+        diversity: Option<PopulationDiversity>,
+    ) {
+        let highest_fitness = evaluated.highest_fitness();
+        let improved = self
+            .best_fitness
+            .as_ref()
+            .is_none_or(|best| highest_fitness > best);
+        if improved {
+            self.best_fitness = Some(highest_fitness.clone());
+            self.stagnant_generations = 0;
+        } else {
+            self.stagnant_generations += 1;
+        }
+        let operators = [
+            self.selector.as_generation_aware(),
+            self.breeder.as_generation_aware(),
+            self.mutator.as_generation_aware(),
+            self.reinserter.as_generation_aware(),
+        ];
+        if operators.iter().all(Option::is_none) {
+            return;
+        }
+        let feedback = GenerationFeedback {
+            generation,
+            population_size: evaluated.individuals().len(),
+            improved,
+            stagnant_generations: self.stagnant_generations,
+            fitness_diversity: fitness_diversity(evaluated.fitness_values()),
+            offspring_success: self.offspring_success.as_ref().map(OffspringSuccess::ratio),
+            diversity,
+        };
+        for operator in operators.into_iter().flatten() {
+            operator.generation_evaluated(&feedback);
+        }
     }
+}
 
-    pub fn mutator(&self) -> &M {
-        &self.mutator
+/// Copies the given number of the fittest individuals of the evaluated
+/// population into the next generation. They replace randomly chosen
+/// individuals of the next generation.
+///
+/// Among individuals of equal fitness the elites are chosen by the given
+/// `TieBreaking`. Each individual of the evaluated population is copied at
+/// most once.
+fn preserve_elites<G, F>(
+    mut next_generation: Vec<G>,
+    evaluated: &EvaluatedPopulation<G, F>,
+    elitism: usize,
+    tie_breaking: &TieBreaking<G>,
+    rng: &mut Prng,
+) -> Vec<G>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    let num_elites = elitism.min(next_generation.len());
+    if num_elites == 0 {
+        return next_generation;
     }
-
-    pub fn reinserter(&self) -> &R {
-        &self.reinserter
+    let elites = elite_indices(evaluated, num_elites, tie_breaking, rng);
+    let mut positions: Vec<usize> = (0..next_generation.len()).collect();
+    let (replaced, _) = positions.partial_shuffle(rng, elites.len());
+    replaced.sort_unstable();
+    let individuals = evaluated.individuals();
+    for (&position, index) in replaced.iter().zip(elites) {
+        next_generation[position] = individuals[index].clone();
     }
+    next_generation
+}
 
-    pub fn min_population_size(&self) -> usize {
-        self.min_population_size
+/// Returns the indices of up to `num_elites` distinct individuals of the
+/// evaluated population with the highest fitness values, ordered from the
+/// best to the worst. Each run of individuals of equal fitness is ordered
+/// by the given `TieBreaking`.
+fn elite_indices<G, F>(
+    evaluated: &EvaluatedPopulation<G, F>,
+    num_elites: usize,
+    tie_breaking: &TieBreaking<G>,
+    rng: &mut Prng,
+) -> Vec<usize>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    let fitness_values = evaluated.fitness_values();
+    let individuals = evaluated.individuals();
+    // the sort is stable, so individuals of equal fitness keep their order
+    let mut ranking: Vec<usize> = (0..fitness_values.len()).collect();
+    ranking.sort_by(|&a, &b| fitness_values[b].cmp(&fitness_values[a]));
+    let mut chosen = vec![false; individuals.len()];
+    let mut elites = Vec::with_capacity(num_elites);
+    let mut start = 0;
+    while start < ranking.len() && elites.len() < num_elites {
+        let fitness = &fitness_values[ranking[start]];
+        let end = start
+            + ranking[start..]
+                .iter()
+                .take_while(|&&index| fitness_values[index] == *fitness)
+                .count();
+        let ties = &ranking[start..end];
+        let genomes: Vec<&G> = ties.iter().map(|&index| &individuals[index]).collect();
+        for position in tie_breaking.order(&genomes, rng) {
+            let index = ties[position];
+            if elites.len() < num_elites && !chosen[index] {
+                chosen[index] = true;
+                elites.push(index);
+            }
+        }
+        start = end;
     }
+    elites
 }
 
-impl<G, F, E, S, C, M, R> TrackProcessingTime for GeneticAlgorithm<G, F, E, S, C, M, R>
+/// Draws a random subsample of the given fraction of the evaluated
+/// population by reservoir sampling. The subsample holds at least one
+/// individual and keeps the order of the individuals in the population.
+fn subsample_population<G, F, E>(
+    evaluated: &EvaluatedPopulation<G, F>,
+    fraction: f64,
+    evaluator: &E,
+    rng: &mut Prng,
+) -> EvaluatedPopulation<G, F>
 where
     G: Genotype,
     F: Fitness,
     E: FitnessFunction<G, F>,
-    S: SelectionOp<G, F>,
-    C: CrossoverOp<G>,
-    M: MutationOp<G>,
-    R: ReinsertionOp<G, F>,
 {
-    fn processing_time(&self) -> ProcessingTime {
-        self.processing_time
+    let population_size = evaluated.fitness_values().len();
+    let sample_size = count_of_ratio(population_size, fraction).clamp(1, population_size);
+    let mut reservoir: Vec<usize> = (0..sample_size).collect();
+    for index in sample_size..population_size {
+        let position = random_index(rng, index + 1);
+        if position < sample_size {
+            reservoir[position] = index;
+        }
     }
+    reservoir.sort_unstable();
+    let individuals = evaluated.individuals();
+    let fitness_values = evaluated.fitness_values();
+    let sample: Vec<G> = reservoir
+        .iter()
+        .map(|&index| individuals[index].clone())
+        .collect();
+    let sample_fitness: Vec<F> = reservoir
+        .iter()
+        .map(|&index| fitness_values[index].clone())
+        .collect();
+    let highest = sample_fitness
+        .iter()
+        .max()
+        .cloned()
+        .expect("non-empty sample");
+    let lowest = sample_fitness
+        .iter()
+        .min()
+        .cloned()
+        .expect("non-empty sample");
+    let average = evaluator.average(&sample_fitness);
+    EvaluatedPopulation::new(Arc::new(sample), sample_fitness, highest, lowest, average)
 }
 
-impl<G, F, E, S, C, M, R> Algorithm for GeneticAlgorithm<G, F, E, S, C, M, R>
+/// Returns the fraction of distinct values of the given fitness values.
+fn fitness_diversity<F>(fitness_values: &[F]) -> f64
 where
-    G: Genotype,
-    F: Fitness + Send + Sync,
-    E: FitnessFunction<G, F> + Sync,
-    S: SelectionOp<G, F>,
-    C: CrossoverOp<G> + Sync,
-    M: MutationOp<G> + Sync,
-    R: ReinsertionOp<G, F>,
+    F: Fitness,
 {
-    type Output = State<G, F>;
-    type Error = GeneticAlgorithmError;
-
-    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
-        if self.population.is_empty() {
-            return Err(GeneticAlgorithmError::EmptyPopulation(format!(
-                "Population of generation {} is empty. The required minimum size for \
-                 populations is {}.",
-                iteration, self.min_population_size
-            )));
-        }
-        if self.population.len() < self.min_population_size {
-            return Err(GeneticAlgorithmError::PopulationTooSmall(format!(
-                "Population of generation {} has a size of {} which is smaller than the \
-                 required minimum size of {}",
-                iteration,
-                self.population.len(),
-                self.min_population_size
-            )));
-        }
+    if fitness_values.is_empty() {
+        return 0.;
+    }
+    let mut distinct: Vec<&F> = fitness_values.iter().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    distinct.len() as f64 / fitness_values.len() as f64
+}
 
-        // Stage 2: The fitness check:
-        let evaluation = evaluate_fitness(self.population.clone(), &self.evaluator);
-        let best_solution = determine_best_solution(iteration, &evaluation.result);
-
-        // Stage 3: The making of a new population:
-        let selection = timed(|| self.selector.select_from(&evaluation.result, rng)).run();
-        let mut breeding = par_breed_offspring(selection.result, &self.breeder, &self.mutator, rng);
-        let reinsertion = timed(|| {
-            self.reinserter
-                .combine(&mut breeding.result, &evaluation.result, rng)
-        })
-        .run();
+/// The `genetic::Fitness` values of a population.
+struct FitnessValues<F> {
+    /// The fitness values in the order of the genotypes of the population.
+    fitness: Vec<F>,
+    highest: F,
+    lowest: F,
+    /// The number of fitness values calculated by the fitness function.
+    evaluations: usize,
+    /// The number of evaluations that timed out.
+    timeouts: usize,
+    /// The indices of the genotypes whose evaluation failed in ascending
+    /// order together with the errors. These genotypes got the lowest
+    /// possible fitness.
+    failures: Vec<(usize, EvalError)>,
+}
 
-        // Stage 4: On to the next generation:
-        self.processing_time = evaluation.time
-            + best_solution.time
-            + selection.time
-            + breeding.time
-            + reinsertion.time;
-        let next_generation = reinsertion.result;
-        self.population = Rc::new(next_generation);
-        Ok(State {
-            evaluated_population: evaluation.result,
-            best_solution: best_solution.result,
-            processing_time: self.processing_time,
-        })
+impl<F> FitnessValues<F>
+where
+    F: Fitness,
+{
+    /// Determines the highest and lowest of the fitness values again after
+    /// they have been changed.
+    fn update_bounds<G, E>(&mut self, evaluator: &E)
+    where
+        G: Genotype,
+        E: FitnessFunction<G, F>,
+    {
+        let (highest, lowest) = bounds_of(&self.fitness, evaluator);
+        self.highest = highest;
+        self.lowest = lowest;
     }
+}
 
-    fn reset(&mut self) -> Result<bool, Self::Error> {
-        self.processing_time = ProcessingTime::zero();
-        self.population = Rc::new(self.initial_population.individuals().to_vec());
-        Ok(true)
-    }
+/// Returns the highest and the lowest of the given fitness values.
+fn bounds_of<G, F, E>(fitness: &[F], evaluator: &E) -> (F, F)
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    let highest = fitness
+        .iter()
+        .fold(evaluator.lowest_possible_fitness(), |highest, score| {
+            if *score > highest {
+                score.clone()
+            } else {
+                highest
+            }
+        });
+    let lowest = fitness
+        .iter()
+        .fold(evaluator.highest_possible_fitness(), |lowest, score| {
+            if *score < lowest {
+                score.clone()
+            } else {
+                lowest
+            }
+        });
+    (highest, lowest)
 }
 
 fn evaluate_fitness<G, F, E>(
-    population: Rc<Vec<G>>,
+    population: &[G],
     evaluator: &E,
-) -> TimedResult<EvaluatedPopulation<G, F>>
+    timeout: Option<&EvaluationTimeout<G, F, E>>,
+) -> TimedResult<FitnessValues<F>>
 where
     G: Genotype + Sync,
     F: Fitness + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
 {
-    let evaluation = par_evaluate_fitness(&population, evaluator);
-    let average = timed(|| evaluator.average(&evaluation.result.0)).run();
-    let evaluated = EvaluatedPopulation::new(
-        population,
-        evaluation.result.0,
-        evaluation.result.1,
-        evaluation.result.2,
-        average.result,
-    );
-    TimedResult {
-        result: evaluated,
-        time: evaluation.time + average.time,
+    if evaluator.evaluates_population_at_once() {
+        timed(|| batch_evaluate_fitness(population, evaluator)).run()
+    } else {
+        par_evaluate_fitness(population, evaluator, timeout)
     }
 }
 
 /// Calculates the `genetic::Fitness` value of each `genetic::Genotype` and
-/// records the highest and lowest values.
-#[cfg(not(target_arch = "wasm32"))]
-fn par_evaluate_fitness<G, F, E>(population: &[G], evaluator: &E) -> TimedResult<(Vec<F>, F, F)>
+/// records the highest and lowest values, the number of evaluations that
+/// timed out and the evaluations that failed.
+///
+/// The fitness values are returned in the order of the genotypes in the given
+/// population, no matter in which order the threads finish their work. The
+/// operators rely on this order, as they access the fitness value of the
+/// individual at index `i` by the same index. Any other way of evaluating the
+/// population must guarantee this order as well.
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
+fn par_evaluate_fitness<G, F, E>(
+    population: &[G],
+    evaluator: &E,
+    timeout: Option<&EvaluationTimeout<G, F, E>>,
+) -> TimedResult<FitnessValues<F>>
 where
     G: Genotype + Sync,
     F: Fitness + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
 {
     if population.len() < 50 {
-        timed(|| {
-            let mut fitness = Vec::with_capacity(population.len());
-            let mut highest = evaluator.lowest_possible_fitness();
-            let mut lowest = evaluator.highest_possible_fitness();
-            for genome in population.iter() {
-                let score = evaluator.fitness_of(genome);
-                if score > highest {
-                    highest = score.clone();
-                }
-                if score < lowest {
-                    lowest = score.clone();
-                }
-                fitness.push(score);
-            }
-            (fitness, highest, lowest)
-        })
-        .run()
+        timed(|| seq_evaluate_fitness(population, evaluator, timeout)).run()
     } else {
         let mid_point = population.len() / 2;
         let (l_slice, r_slice) = population.split_at(mid_point);
-        let (mut left, mut right) = rayon::join(
-            || par_evaluate_fitness(l_slice, evaluator),
-            || par_evaluate_fitness(r_slice, evaluator),
+        let (left, right) = rayon::join(
+            || par_evaluate_fitness(l_slice, evaluator, timeout),
+            || par_evaluate_fitness(r_slice, evaluator, timeout),
+        );
+        let mut values = left.result;
+        let right_values = right.result;
+        values.fitness.extend(right_values.fitness);
+        if right_values.highest > values.highest {
+            values.highest = right_values.highest;
+        }
+        if right_values.lowest < values.lowest {
+            values.lowest = right_values.lowest;
+        }
+        values.evaluations += right_values.evaluations;
+        values.timeouts += right_values.timeouts;
+        values.failures.extend(
+            right_values
+                .failures
+                .into_iter()
+                .map(|(index, error)| (mid_point + index, error)),
         );
-        let mut fitness = Vec::with_capacity(population.len());
-        fitness.append(&mut left.result.0);
-        fitness.append(&mut right.result.0);
-        let highest = if left.result.1 >= right.result.1 {
-            left.result.1
-        } else {
-            right.result.1
-        };
-        let lowest = if left.result.2 <= right.result.2 {
-            left.result.2
-        } else {
-            right.result.2
-        };
         TimedResult {
-            result: (fitness, highest, lowest),
+            result: values,
             time: left.time + right.time,
         }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn par_evaluate_fitness<G, F, E>(population: &[G], evaluator: &E) -> TimedResult<(Vec<F>, F, F)>
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-parallel")))]
+fn par_evaluate_fitness<G, F, E>(
+    population: &[G],
+    evaluator: &E,
+    timeout: Option<&EvaluationTimeout<G, F, E>>,
+) -> TimedResult<FitnessValues<F>>
 where
     G: Genotype + Sync,
     F: Fitness + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
 {
-    timed(|| {
-        let mut fitness = Vec::with_capacity(population.len());
-        let mut highest = evaluator.lowest_possible_fitness();
-        let mut lowest = evaluator.highest_possible_fitness();
-        for genome in population.iter() {
-            let score = evaluator.fitness_of(genome);
-            if score > highest {
-                highest = score.clone();
-            }
-            if score < lowest {
-                lowest = score.clone();
-            }
-            fitness.push(score);
+    timed(|| seq_evaluate_fitness(population, evaluator, timeout)).run()
+}
+
+fn seq_evaluate_fitness<G, F, E>(
+    population: &[G],
+    evaluator: &E,
+    timeout: Option<&EvaluationTimeout<G, F, E>>,
+) -> FitnessValues<F>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    let mut fitness = Vec::with_capacity(population.len());
+    let mut highest = evaluator.lowest_possible_fitness();
+    let mut lowest = evaluator.highest_possible_fitness();
+    let mut evaluations = 0;
+    let mut timeouts = 0;
+    let mut failures = Vec::new();
+    for (index, genome) in population.iter().enumerate() {
+        let cached = evaluator.cached_fitness_of(genome);
+        if cached.is_none() {
+            evaluations += 1;
         }
-        (fitness, highest, lowest)
-    })
-    .run()
+        let score = match (cached, timeout) {
+            (Some(score), _) => score,
+            (None, Some(timeout)) => {
+                match (timeout.evaluate)(&timeout.workers, evaluator, genome, timeout.timeout) {
+                    Some(Ok(score)) => score,
+                    Some(Err(error)) => {
+                        failures.push((index, error));
+                        evaluator.lowest_possible_fitness()
+                    },
+                    None => {
+                        timeouts += 1;
+                        timeout.fallback.clone()
+                    },
+                }
+            },
+            (None, None) => match catch_fitness_of(evaluator, genome) {
+                Ok(score) => score,
+                Err(error) => {
+                    failures.push((index, error));
+                    evaluator.lowest_possible_fitness()
+                },
+            },
+        };
+        if score > highest {
+            highest = score.clone();
+        }
+        if score < lowest {
+            lowest = score.clone();
+        }
+        fitness.push(score);
+    }
+    FitnessValues {
+        fitness,
+        highest,
+        lowest,
+        evaluations,
+        timeouts,
+        failures,
+    }
+}
+
+/// Calculates the `genetic::Fitness` value of the given `genetic::Genotype`
+/// with `genetic::FitnessFunction::try_fitness_of` and turns a panic of the
+/// fitness function into an `EvalError::Panicked`.
+fn catch_fitness_of<G, F, E>(evaluator: &E, genome: &G) -> Result<F, EvalError>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    panic::catch_unwind(AssertUnwindSafe(|| evaluator.try_fitness_of(genome))).unwrap_or_else(
+        |payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown cause".to_string());
+            Err(EvalError::Panicked(message))
+        },
+    )
+}
+
+/// Calculates the `genetic::Fitness` values of all `genetic::Genotype`s
+/// whose fitness is not cached by one call to `fitness_of_population` and
+/// records the highest and lowest values.
+fn batch_evaluate_fitness<G, F, E>(population: &[G], evaluator: &E) -> FitnessValues<F>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    let cached: Vec<Option<F>> = population
+        .iter()
+        .map(|genome| evaluator.cached_fitness_of(genome))
+        .collect();
+    let uncached: Vec<G> = population
+        .iter()
+        .zip(&cached)
+        .filter(|(_, score)| score.is_none())
+        .map(|(genome, _)| genome.clone())
+        .collect();
+    let calculated = evaluator.fitness_of_population(&uncached);
+    assert_eq!(
+        calculated.len(),
+        uncached.len(),
+        "fitness_of_population must return one fitness value per genotype"
+    );
+    let mut calculated = calculated.into_iter();
+    let fitness: Vec<F> = cached
+        .into_iter()
+        .map(|score| score.or_else(|| calculated.next()).expect("fitness value"))
+        .collect();
+    let (highest, lowest) = bounds_of(&fitness, evaluator);
+    FitnessValues {
+        fitness,
+        highest,
+        lowest,
+        evaluations: uncached.len(),
+        timeouts: 0,
+        failures: Vec::new(),
+    }
+}
+
+/// Evaluates the given genome on one of the given workers and returns the
+/// result of `genetic::FitnessFunction::try_fitness_of` or `None` if the
+/// evaluation timed out. The timeout starts when a worker picks up the
+/// evaluation. At most as many workers are running as the current thread
+/// pool has threads. The worker of an evaluation that timed out is replaced
+/// by a new one.
+#[cfg(not(target_arch = "wasm32"))]
+fn fitness_within_timeout<G, F, E>(
+    workers: &TimeoutWorkers,
+    evaluator: &E,
+    genome: &G,
+    timeout: Duration,
+) -> Option<Result<F, EvalError>>
+where
+    G: Genotype + 'static,
+    F: Fitness + Send + 'static,
+    E: FitnessFunction<G, F> + Send + 'static,
+{
+    let max_workers = rayon::current_num_threads();
+    let (started_sender, started) = mpsc::channel();
+    let (sender, receiver) = mpsc::channel();
+    let genome = genome.clone();
+    let evaluator = evaluator.clone();
+    let ticket = workers.execute(
+        max_workers,
+        Box::new(move || {
+            // the receivers are gone if the evaluation timed out
+            let _ = started_sender.send(());
+            let _ = sender.send(catch_fitness_of(&evaluator, &genome));
+        }),
+    );
+    let aborted = || {
+        Err(EvalError::Failed(
+            "the evaluation has been aborted".to_string(),
+        ))
+    };
+    if started.recv().is_err() {
+        return Some(aborted());
+    }
+    match receiver.recv_timeout(timeout.to_std().unwrap_or_default()) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => {
+            workers.abandon(max_workers, &ticket);
+            None
+        },
+        Err(RecvTimeoutError::Disconnected) => Some(aborted()),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn fitness_within_timeout<G, F, E>(
+    _workers: &TimeoutWorkers,
+    evaluator: &E,
+    genome: &G,
+    _timeout: Duration,
+) -> Option<Result<F, EvalError>>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    Some(catch_fitness_of(evaluator, genome))
 }
 
 /// Determines the best solution of the current population
 fn determine_best_solution<G, F>(
     generation: u64,
     score_board: &EvaluatedPopulation<G, F>,
+    tie_breaking: &TieBreaking<G>,
+    rng: &mut Prng,
 ) -> TimedResult<BestSolution<G, F>>
 where
     G: Genotype,
     F: Fitness,
 {
     timed(|| {
-        let evaluated = score_board
-            .evaluated_individual_with_fitness(&score_board.highest_fitness())
+        let index = score_board
+            .index_of_best(tie_breaking, rng)
             .unwrap_or_else(|| {
                 panic!(
                     "No fitness value of {:?} found in this EvaluatedPopulation",
@@ -356,21 +2194,104 @@ where
         BestSolution {
             found_at: Local::now(),
             generation,
-            solution: evaluated,
+            solution: Evaluated {
+                genome: score_board.individuals()[index].clone(),
+                fitness: score_board.fitness_values()[index].clone(),
+            },
         }
     })
     .run()
 }
 
+/// Returns the error for a failed crossover or mutation operator.
+fn operator_failed(kind: &str, name: String, error: OperatorError) -> GeneticAlgorithmError {
+    GeneticAlgorithmError::OperatorFailed {
+        stage: Stage::Breed,
+        details: format!("The {} operator {} failed: {}", kind, name, error),
+    }
+}
+
+/// Lets the parents breed their children, mutates the children and adds them
+/// to the offspring. The children are traced by the breeder only if the
+/// mutator makes use of the segments they are assembled from.
+///
+/// Returns the number of children the parents have bred. If a tracing is
+/// given, the traces of the children are returned as well.
+///
+/// Returns a `GeneticAlgorithmError::OperatorFailed` if the breeder or the
+/// mutator fails.
+fn breed_and_mutate<G, C, M>(
+    breeder: &C,
+    mutator: &M,
+    tracing: Option<&Tracing<G>>,
+    parents: Parents<G>,
+    offspring: &mut Offspring<G>,
+    rng: &mut Prng,
+) -> Result<(usize, Vec<ChildTrace>), GeneticAlgorithmError>
+where
+    G: Genotype,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    let traced_parents = tracing.map(|_| parents.clone());
+    let mut child_traces = Vec::new();
+    let num_individuals = offspring.len();
+    if mutator.uses_segments() {
+        let children = breeder
+            .try_crossover_traced(parents, rng)
+            .map_err(|error| operator_failed("crossover", C::name(), error))?;
+        for child in children {
+            let bred = traced_parents.as_ref().map(|_| child.genome.clone());
+            let mutant = mutator
+                .try_mutate_traced(child, rng)
+                .map_err(|error| operator_failed("mutation", M::name(), error))?;
+            if let (Some(tracing), Some(parents), Some(bred)) = (tracing, &traced_parents, bred) {
+                child_traces.push(tracing.child(parents, &bred, &mutant));
+            }
+            offspring.push(mutant);
+        }
+    } else {
+        let children = breeder
+            .try_crossover(parents, rng)
+            .map_err(|error| operator_failed("crossover", C::name(), error))?;
+        for child in children {
+            let bred = traced_parents.as_ref().map(|_| child.clone());
+            let mutant = mutator
+                .try_mutate(child, rng)
+                .map_err(|error| operator_failed("mutation", M::name(), error))?;
+            if let (Some(tracing), Some(parents), Some(bred)) = (tracing, &traced_parents, bred) {
+                child_traces.push(tracing.child(parents, &bred, &mutant));
+            }
+            offspring.push(mutant);
+        }
+    }
+    Ok((offspring.len() - num_individuals, child_traces))
+}
+
+/// The offspring of a generation as bred by `par_breed_offspring`.
+struct Breeding<G>
+where
+    G: Genotype,
+{
+    offspring: Offspring<G>,
+    /// The number of children bred by each group of parents.
+    num_children: Vec<usize>,
+    /// The traces of the children of each group of parents, if the
+    /// generation is traced.
+    child_traces: Vec<Vec<ChildTrace>>,
+}
+
 /// Lets the parents breed their offspring and mutate its children. And
 /// finally combines the offspring of all parents into one big offspring.
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
 fn par_breed_offspring<G, C, M>(
     parents: Vec<Parents<G>>,
     breeder: &C,
     mutator: &M,
+    tracing: Option<&Tracing<G>>,
+    allocator: Option<&SharedAllocator<G>>,
     rng: &mut Prng,
-) -> TimedResult<Offspring<G>>
+) -> TimedResult<Result<Breeding<G>, GeneticAlgorithmError>>
 where
     G: Genotype + Send,
     C: CrossoverOp<G> + Sync,
@@ -378,15 +2299,23 @@ where
 {
     if parents.len() < 50 {
         timed(|| {
-            let mut offspring: Offspring<G> = Vec::with_capacity(parents.len() * parents[0].len());
+            let mut offspring =
+                Offspring::from(allocate(allocator, parents.iter().map(Vec::len).sum()));
+            let mut num_children = Vec::with_capacity(parents.len());
+            let mut child_traces = Vec::new();
             for parents in parents {
-                let children = breeder.crossover(parents, rng);
-                for child in children {
-                    let mutated = mutator.mutate(child, rng);
-                    offspring.push(mutated);
+                let (group_children, group_traces) =
+                    breed_and_mutate(breeder, mutator, tracing, parents, &mut offspring, rng)?;
+                num_children.push(group_children);
+                if tracing.is_some() {
+                    child_traces.push(group_traces);
                 }
             }
-            offspring
+            Ok(Breeding {
+                offspring,
+                num_children,
+                child_traces,
+            })
         })
         .run()
     } else {
@@ -395,45 +2324,66 @@ where
         rng.jump();
         let mut rng2 = rng.clone();
         let mid_point = parents.len() / 2;
-        let mut offspring = Vec::with_capacity(parents.len() * 2);
+        let mut offspring =
+            Offspring::from(allocate(allocator, parents.iter().map(Vec::len).sum()));
         let mut parents = parents;
         let r_slice = parents.drain(mid_point..).collect();
         let l_slice = parents;
-        let (mut left, mut right) = rayon::join(
-            || par_breed_offspring(l_slice, breeder, mutator, &mut rng1),
-            || par_breed_offspring(r_slice, breeder, mutator, &mut rng2),
+        let (left, right) = rayon::join(
+            || par_breed_offspring(l_slice, breeder, mutator, tracing, allocator, &mut rng1),
+            || par_breed_offspring(r_slice, breeder, mutator, tracing, allocator, &mut rng2),
         );
-        offspring.append(&mut left.result);
-        offspring.append(&mut right.result);
-        TimedResult {
-            result: offspring,
-            time: left.time + right.time,
-        }
+        let time = left.time + right.time;
+        let result = left.result.and_then(|mut left| {
+            let mut right = right.result?;
+            offspring.append(&mut left.offspring);
+            offspring.append(&mut right.offspring);
+            release(allocator, left.offspring.take_individuals());
+            release(allocator, right.offspring.take_individuals());
+            left.child_traces.append(&mut right.child_traces);
+            left.num_children.append(&mut right.num_children);
+            Ok(Breeding {
+                offspring,
+                num_children: left.num_children,
+                child_traces: left.child_traces,
+            })
+        });
+        TimedResult { result, time }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm-parallel")))]
 fn par_breed_offspring<G, C, M>(
     parents: Vec<Parents<G>>,
     breeder: &C,
     mutator: &M,
+    tracing: Option<&Tracing<G>>,
+    allocator: Option<&SharedAllocator<G>>,
     rng: &mut Prng,
-) -> TimedResult<Offspring<G>>
+) -> TimedResult<Result<Breeding<G>, GeneticAlgorithmError>>
 where
     G: Genotype + Send,
     C: CrossoverOp<G> + Sync,
     M: MutationOp<G> + Sync,
 {
     timed(|| {
-        let mut offspring: Offspring<G> = Vec::with_capacity(parents.len() * parents[0].len());
+        let mut offspring =
+            Offspring::from(allocate(allocator, parents.iter().map(Vec::len).sum()));
+        let mut num_children = Vec::with_capacity(parents.len());
+        let mut child_traces = Vec::new();
         for parents in parents {
-            let children = breeder.crossover(parents, rng);
-            for child in children {
-                let mutated = mutator.mutate(child, rng);
-                offspring.push(mutated);
+            let (group_children, group_traces) =
+                breed_and_mutate(breeder, mutator, tracing, parents, &mut offspring, rng)?;
+            num_children.push(group_children);
+            if tracing.is_some() {
+                child_traces.push(group_traces);
             }
         }
-        offspring
+        Ok(Breeding {
+            offspring,
+            num_children,
+            child_traces,
+        })
     })
     .run()
 }
@@ -0,0 +1,497 @@
+//! The `presets` module provides ready-made operator stacks for the common
+//! encodings of `genetic::Genotype`s, so that a working genetic algorithm can
+//! be set up without choosing every operator and parameter first.
+//!
+//! Each preset bundles a `population::GenomeBuilder`, a population size, an
+//! `operator::SelectionOp`, an `operator::CrossoverOp` and an
+//! `operator::MutationOp` with defaults that work reasonably well for the
+//! encoding. The reinsertion is done by an `ElitistReinserter` that is created
+//! for the fitness function given to `Preset::with_evaluation`. Every
+//! component can be swapped before the algorithm is built.
+//!
+//! The type aliases `BinaryAlgorithm`, `RealValuedAlgorithm` and
+//! `PermutationAlgorithm` name the algorithms built from the unchanged
+//! presets. Together with the `fitness::callback::CallbackFitnessFunction`
+//! they give fully concrete types, e.g. to be wrapped by bindings for other
+//! languages.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{ga::presets, prelude::*};
+//!
+//! #[derive(Clone, Debug)]
+//! struct CountOnes;
+//!
+//! impl FitnessFunction<Vec<bool>, usize> for CountOnes {
+//!     fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+//!         genome.iter().filter(|gene| **gene).count()
+//!     }
+//!
+//!     fn average(&self, values: &[usize]) -> usize {
+//!         values.iter().sum::<usize>() / values.len()
+//!     }
+//!
+//!     fn highest_possible_fitness(&self) -> usize {
+//!         32
+//!     }
+//!
+//!     fn lowest_possible_fitness(&self) -> usize {
+//!         0
+//!     }
+//! }
+//!
+//! let algorithm = presets::binary(32)
+//!     .with_population_size(50)
+//!     .with_evaluation(CountOnes)
+//!     .build();
+//!
+//! let mut sim = simulate(algorithm)
+//!     .until(GenerationLimit::new(10))
+//!     .build();
+//!
+//! assert!(sim.run().is_ok());
+//! ```
+
+use crate::{
+    algorithm::BestSolution,
+    ga::{
+        builder::GeneticAlgorithmBuilder, genetic_algorithm, GeneticAlgorithm,
+        GeneticAlgorithmError,
+    },
+    genetic::{Fitness, FitnessFunction, Genotype},
+    mutation::{
+        order::SwapOrderMutator,
+        value::{BreederValueMutator, RandomValueMutator},
+    },
+    operator::{CrossoverOp, MutationOp, SelectionOp},
+    population::{
+        build_population, BinaryEncodedGenomeBuilder, GenomeBuilder,
+        PermutationEncodedGenomeBuilder, ValueEncodedGenomeBuilder,
+    },
+    random::Seed,
+    recombination::{discrete::UniformCrossBreeder, order::PartiallyMappedCrossover},
+    reinsertion::elitist::ElitistReinserter,
+    selection::tournament::TournamentSelector,
+    simulation::{
+        simulator::{simulate, SimError},
+        SimResult, Simulation, SimulationBuilder,
+    },
+    statistic::ProcessingTime,
+    termination::{StopReason, Termination},
+};
+use chrono::Duration;
+use std::{fmt::Debug, marker::PhantomData, ops::Range};
+
+/// The number of individuals of the initial population of a preset.
+pub const DEFAULT_POPULATION_SIZE: usize = 100;
+/// The selection ratio of the `TournamentSelector` of a preset.
+pub const DEFAULT_SELECTION_RATIO: f64 = 0.7;
+/// The size of the tournaments of the `TournamentSelector` of a preset.
+pub const DEFAULT_TOURNAMENT_SIZE: usize = 3;
+/// The replace ratio of the `ElitistReinserter` of a preset.
+pub const DEFAULT_REPLACE_RATIO: f64 = 0.7;
+
+/// A preset of a genetic algorithm for genomes of type `G` that are built by
+/// the `GenomeBuilder` `B`, with the selection operator `S`, the crossover
+/// operator `C` and the mutation operator `M`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preset<G, B, S, C, M>
+where
+    G: Genotype,
+    B: GenomeBuilder<G>,
+{
+    genome_builder: B,
+    population_size: usize,
+    seed: Option<Seed>,
+    selector: S,
+    breeder: C,
+    mutator: M,
+    replace_ratio: f64,
+    _g: PhantomData<G>,
+}
+
+/// The `Preset` for binary encoded genomes returned by `binary`.
+pub type BinaryPreset = Preset<
+    Vec<bool>,
+    BinaryEncodedGenomeBuilder,
+    TournamentSelector,
+    UniformCrossBreeder,
+    RandomValueMutator<Vec<bool>>,
+>;
+
+/// The `Preset` for real valued genomes returned by `real_valued`.
+pub type RealValuedPreset = Preset<
+    Vec<f64>,
+    ValueEncodedGenomeBuilder<f64>,
+    TournamentSelector,
+    UniformCrossBreeder,
+    BreederValueMutator<Vec<f64>>,
+>;
+
+/// The `Preset` for permutation encoded genomes returned by `permutation`.
+pub type PermutationPreset = Preset<
+    Vec<usize>,
+    PermutationEncodedGenomeBuilder,
+    TournamentSelector,
+    PartiallyMappedCrossover,
+    SwapOrderMutator,
+>;
+
+/// The `GeneticAlgorithm` built from a `BinaryPreset` with the fitness type
+/// `F` and the `FitnessFunction` `E`.
+pub type BinaryAlgorithm<F, E> = GeneticAlgorithm<
+    Vec<bool>,
+    F,
+    E,
+    TournamentSelector,
+    UniformCrossBreeder,
+    RandomValueMutator<Vec<bool>>,
+    ElitistReinserter<Vec<bool>, F, E>,
+>;
+
+/// The `GeneticAlgorithm` built from a `RealValuedPreset` with the fitness
+/// type `F` and the `FitnessFunction` `E`.
+pub type RealValuedAlgorithm<F, E> = GeneticAlgorithm<
+    Vec<f64>,
+    F,
+    E,
+    TournamentSelector,
+    UniformCrossBreeder,
+    BreederValueMutator<Vec<f64>>,
+    ElitistReinserter<Vec<f64>, F, E>,
+>;
+
+/// The `GeneticAlgorithm` built from a `PermutationPreset` with the fitness
+/// type `F` and the `FitnessFunction` `E`.
+pub type PermutationAlgorithm<F, E> = GeneticAlgorithm<
+    Vec<usize>,
+    F,
+    E,
+    TournamentSelector,
+    PartiallyMappedCrossover,
+    SwapOrderMutator,
+    ElitistReinserter<Vec<usize>, F, E>,
+>;
+
+/// Returns a `Preset` for binary encoded genomes of the given length.
+///
+/// The genes are mutated by flipping them at random with a mutation rate
+/// of one gene per genome on average.
+pub fn binary(genome_length: usize) -> BinaryPreset {
+    Preset::new(
+        BinaryEncodedGenomeBuilder::new(genome_length),
+        UniformCrossBreeder::new(),
+        RandomValueMutator::new(one_gene_per_genome(genome_length), false, true),
+    )
+}
+
+/// Returns a `Preset` for real valued genomes of the given length. The
+/// values of the genes are kept within the given bounds.
+///
+/// The genes are mutated by the breeder mutation with a mutation range of a
+/// tenth of the bounds and a mutation rate of one gene per genome on average.
+pub fn real_valued(genome_length: usize, bounds: Range<f64>) -> RealValuedPreset {
+    let mutation_range = (bounds.end - bounds.start) / 10.;
+    Preset::new(
+        ValueEncodedGenomeBuilder::new(genome_length, bounds.start, bounds.end),
+        UniformCrossBreeder::new(),
+        BreederValueMutator::new(
+            one_gene_per_genome(genome_length),
+            mutation_range,
+            8,
+            bounds.start,
+            bounds.end,
+        ),
+    )
+}
+
+/// Returns a `Preset` for genomes that are permutations of the values
+/// `0..genome_length`.
+///
+/// The offspring is bred by the partially mapped crossover and mutated by
+/// swapping two genes, so that every genome remains a valid permutation.
+pub fn permutation(genome_length: usize) -> PermutationPreset {
+    Preset::new(
+        PermutationEncodedGenomeBuilder::new(genome_length),
+        PartiallyMappedCrossover::new(),
+        SwapOrderMutator::new(one_gene_per_genome(genome_length)),
+    )
+}
+
+pub(crate) fn one_gene_per_genome(genome_length: usize) -> f64 {
+    1. / genome_length.max(1) as f64
+}
+
+impl<G, B, C, M> Preset<G, B, TournamentSelector, C, M>
+where
+    G: Genotype,
+    B: GenomeBuilder<G>,
+{
+    pub(crate) fn new(genome_builder: B, breeder: C, mutator: M) -> Self {
+        Preset {
+            genome_builder,
+            population_size: DEFAULT_POPULATION_SIZE,
+            seed: None,
+            selector: TournamentSelector::new(
+                DEFAULT_SELECTION_RATIO,
+                2,
+                DEFAULT_TOURNAMENT_SIZE,
+                1.,
+                false,
+            ),
+            breeder,
+            mutator,
+            replace_ratio: DEFAULT_REPLACE_RATIO,
+            _g: PhantomData,
+        }
+    }
+}
+
+impl<G, B, S, C, M> Preset<G, B, S, C, M>
+where
+    G: Genotype,
+    B: GenomeBuilder<G>,
+{
+    /// Returns the `GenomeBuilder` that builds the initial population.
+    pub fn genome_builder(&self) -> &B {
+        &self.genome_builder
+    }
+
+    /// Returns the size of the initial population.
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    /// Returns the selection operator.
+    pub fn selector(&self) -> &S {
+        &self.selector
+    }
+
+    /// Returns the crossover operator.
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    /// Returns the mutation operator.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the seed the initial population is built with, if any.
+    pub fn seed(&self) -> Option<Seed> {
+        self.seed
+    }
+
+    /// Returns the replace ratio of the `ElitistReinserter`.
+    pub fn replace_ratio(&self) -> f64 {
+        self.replace_ratio
+    }
+
+    /// Sets the size of the initial population.
+    pub fn with_population_size(mut self, population_size: usize) -> Self {
+        self.population_size = population_size;
+        self
+    }
+
+    /// Sets the seed the initial population is built with. Without a seed
+    /// the initial population is built from a random seed.
+    pub fn with_seed<Z>(mut self, seed: Z) -> Self
+    where
+        Z: Into<Seed>,
+    {
+        self.seed = Some(seed.into());
+        self
+    }
+
+    /// Sets the replace ratio of the `ElitistReinserter`.
+    pub fn with_replace_ratio(mut self, replace_ratio: f64) -> Self {
+        self.replace_ratio = replace_ratio;
+        self
+    }
+
+    /// Replaces the selection operator of this preset.
+    pub fn with_selection<S2>(self, selector: S2) -> Preset<G, B, S2, C, M> {
+        Preset {
+            genome_builder: self.genome_builder,
+            population_size: self.population_size,
+            seed: self.seed,
+            selector,
+            breeder: self.breeder,
+            mutator: self.mutator,
+            replace_ratio: self.replace_ratio,
+            _g: PhantomData,
+        }
+    }
+
+    /// Replaces the crossover operator of this preset.
+    pub fn with_crossover<C2>(self, breeder: C2) -> Preset<G, B, S, C2, M> {
+        Preset {
+            genome_builder: self.genome_builder,
+            population_size: self.population_size,
+            seed: self.seed,
+            selector: self.selector,
+            breeder,
+            mutator: self.mutator,
+            replace_ratio: self.replace_ratio,
+            _g: PhantomData,
+        }
+    }
+
+    /// Replaces the mutation operator of this preset.
+    pub fn with_mutation<M2>(self, mutator: M2) -> Preset<G, B, S, C, M2> {
+        Preset {
+            genome_builder: self.genome_builder,
+            population_size: self.population_size,
+            seed: self.seed,
+            selector: self.selector,
+            breeder: self.breeder,
+            mutator,
+            replace_ratio: self.replace_ratio,
+            _g: PhantomData,
+        }
+    }
+
+    /// Builds a random initial population and returns the builder of a
+    /// `GeneticAlgorithm` that evaluates the individuals with the given
+    /// `FitnessFunction`.
+    #[allow(clippy::type_complexity)]
+    pub fn with_evaluation<F, E>(
+        self,
+        fitness_function: E,
+    ) -> GeneticAlgorithmBuilder<G, F, E, S, C, M, ElitistReinserter<G, F, E>>
+    where
+        F: Fitness,
+        E: FitnessFunction<G, F>,
+        S: SelectionOp<G, F>,
+        C: CrossoverOp<G>,
+        M: MutationOp<G>,
+    {
+        let population_builder = build_population()
+            .with_genome_builder(self.genome_builder)
+            .of_size(self.population_size);
+        let initial_population = match self.seed {
+            Some(seed) => population_builder.using_seed(seed),
+            None => population_builder.uniform_at_random(),
+        };
+        genetic_algorithm()
+            .with_evaluation(fitness_function.clone())
+            .with_selection(self.selector)
+            .with_crossover(self.breeder)
+            .with_mutation(self.mutator)
+            .with_reinsertion(ElitistReinserter::new(
+                fitness_function,
+                false,
+                self.replace_ratio,
+            ))
+            .with_initial_population(initial_population)
+    }
+}
+
+/// The `Evolution` is the report of a run of `evolve`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Evolution<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The overall best solution found during the run.
+    pub best_solution: BestSolution<G, F>,
+    /// The number of generations that have been processed.
+    pub generations: u64,
+    /// The reason why the run stopped.
+    pub stop_reason: StopReason,
+    /// The wall time of the whole run.
+    pub duration: Duration,
+    /// The accumulated processing time of all threads.
+    pub processing_time: ProcessingTime,
+    /// The time from the start of the run until the best solution was found.
+    pub time_to_best: Duration,
+}
+
+/// The type of the genetic algorithm that `evolve` builds from a preset.
+pub type PresetAlgorithm<G, F, E, S, C, M> =
+    GeneticAlgorithm<G, F, E, S, C, M, ElitistReinserter<G, F, E>>;
+
+/// Evolves solutions for the given `problem` using the given `preset` until
+/// the given `termination` condition is met, and returns the overall best
+/// solution together with a report of the run.
+///
+/// This wires building the initial population, building the genetic
+/// algorithm and running the simulation for the common case. For more
+/// control over the simulation use the preset and `simulate` directly.
+///
+/// ## Example
+///
+/// ```rust
+/// use genevo::{ga::presets, prelude::*};
+///
+/// #[derive(Clone, Debug)]
+/// struct CountOnes;
+///
+/// impl FitnessFunction<Vec<bool>, usize> for CountOnes {
+///     fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+///         genome.iter().filter(|gene| **gene).count()
+///     }
+///
+///     fn average(&self, values: &[usize]) -> usize {
+///         values.iter().sum::<usize>() / values.len()
+///     }
+///
+///     fn highest_possible_fitness(&self) -> usize {
+///         16
+///     }
+///
+///     fn lowest_possible_fitness(&self) -> usize {
+///         0
+///     }
+/// }
+///
+/// let evolution = evolve(CountOnes, presets::binary(16), GenerationLimit::new(20)).unwrap();
+///
+/// assert!(evolution.best_solution.solution.fitness <= 16);
+/// ```
+pub fn evolve<G, F, E, B, S, C, M, T>(
+    problem: E,
+    preset: Preset<G, B, S, C, M>,
+    termination: T,
+) -> Result<Evolution<G, F>, GeneticAlgorithmError>
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync + Debug,
+    B: GenomeBuilder<G>,
+    S: SelectionOp<G, F> + Debug,
+    C: CrossoverOp<G> + Sync + Debug,
+    M: MutationOp<G> + Sync + Debug,
+    T: Termination<PresetAlgorithm<G, F, E, S, C, M>>,
+{
+    let seed = preset.seed;
+    let algorithm = preset.with_evaluation(problem).build();
+    let builder = simulate(algorithm).until(termination);
+    let mut simulator = match seed {
+        Some(seed) => builder.build_with_seed(seed),
+        None => builder.build(),
+    };
+    let result = simulator.run().map_err(|error| match error {
+        SimError::AlgorithmError(error) => error,
+        SimError::SimulationAlreadyRunning(_)
+        | SimError::InvalidSeedLog(_)
+        | SimError::IterationInProgress(_) => {
+            unreachable!("the simulator is not shared and does not replay a seed log")
+        },
+    })?;
+    match result {
+        SimResult::Final(state, processing_time, duration, stop_reason, best_result) => {
+            Ok(Evolution {
+                best_solution: best_result.best_solution,
+                generations: state.iteration,
+                stop_reason,
+                duration,
+                processing_time,
+                time_to_best: state.time_to_best(),
+            })
+        },
+        SimResult::Intermediate(_) => unreachable!("a run ends with the final result"),
+    }
+}
@@ -0,0 +1,195 @@
+//! The `timeout` module provides the worker threads the fitness of genomes
+//! is evaluated on if the `GeneticAlgorithm` limits the duration of the
+//! evaluation of one genome.
+//!
+//! The number of workers is bounded by the number of threads of the thread
+//! pool the population is evaluated in. If all workers are busy, an
+//! evaluation waits for the next free worker. The timeout of an evaluation
+//! starts when a worker picks it up.
+//!
+//! A thread can not be cancelled. An evaluation that timed out keeps its
+//! thread busy until it finishes on its own. The worker is therefore
+//! abandoned and does not count as a worker anymore, so that a new worker is
+//! started in its place. This way evaluations that never finish do not
+//! block the evaluations of the following genomes. The thread of an
+//! abandoned worker ends as soon as its evaluation is finished.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+    fmt::{self, Debug},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+};
+
+/// A job that is run by one of the `TimeoutWorkers`.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type Job = Box<dyn FnOnce() + Send>;
+
+/// A queued job together with its ticket.
+#[cfg(not(target_arch = "wasm32"))]
+type QueuedJob = (Job, Arc<Ticket>);
+
+/// The `Ticket` of a job is shared by the worker that runs the job and the
+/// thread that waits for its result.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub(crate) struct Ticket {
+    state: Mutex<JobState>,
+}
+
+/// The state of a job that has been picked up by a worker.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum JobState {
+    #[default]
+    Running,
+    Finished,
+    Abandoned,
+}
+
+/// The `TimeoutWorkers` run the timed evaluations of a `GeneticAlgorithm`.
+/// Clones of the algorithm share the same workers. The workers are started
+/// on demand and stop when the `TimeoutWorkers` are dropped and their last
+/// job is done.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct TimeoutWorkers {
+    sender: Mutex<Sender<QueuedJob>>,
+    receiver: Arc<Mutex<Receiver<QueuedJob>>>,
+    workload: Arc<Mutex<Workload>>,
+}
+
+/// The number of workers and of the jobs they are busy with.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+struct Workload {
+    num_workers: usize,
+    starting_workers: usize,
+    idle_workers: usize,
+    queued_jobs: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TimeoutWorkers {
+    pub(crate) fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        TimeoutWorkers {
+            sender: Mutex::new(sender),
+            receiver: Arc::new(Mutex::new(receiver)),
+            workload: Arc::new(Mutex::new(Workload::default())),
+        }
+    }
+
+    /// Queues the given job and returns its `Ticket`. A new worker is
+    /// started if there are more queued jobs than idle workers and less
+    /// than `max_workers` workers.
+    pub(crate) fn execute(&self, max_workers: usize, job: Job) -> Arc<Ticket> {
+        let ticket = Arc::new(Ticket::default());
+        {
+            let mut workload = lock(&self.workload);
+            workload.queued_jobs += 1;
+            self.start_workers(&mut workload, max_workers);
+        }
+        // the workers only stop after the sender has been dropped
+        let _ = lock(&self.sender).send((job, ticket.clone()));
+        ticket
+    }
+
+    /// Abandons the worker that runs the job of the given `Ticket`, e.g.
+    /// because the job timed out. The worker stops as soon as the job is
+    /// finished, and a new worker is started in its place if jobs are
+    /// waiting. Nothing happens if the job has been finished already.
+    ///
+    /// Must only be called for a job that has been picked up by a worker.
+    pub(crate) fn abandon(&self, max_workers: usize, ticket: &Ticket) {
+        let mut state = lock(&ticket.state);
+        if *state == JobState::Running {
+            *state = JobState::Abandoned;
+            let mut workload = lock(&self.workload);
+            workload.num_workers -= 1;
+            self.start_workers(&mut workload, max_workers);
+        }
+    }
+
+    /// Starts as many workers as needed for the queued jobs, but no more
+    /// than `max_workers` workers.
+    fn start_workers(&self, workload: &mut Workload, max_workers: usize) {
+        while workload.queued_jobs > workload.idle_workers + workload.starting_workers
+            && workload.num_workers < max_workers.max(1)
+        {
+            workload.num_workers += 1;
+            workload.starting_workers += 1;
+            self.start_worker();
+        }
+    }
+
+    fn start_worker(&self) {
+        let receiver = self.receiver.clone();
+        let workload = self.workload.clone();
+        thread::spawn(move || {
+            {
+                let mut load = lock(&workload);
+                load.starting_workers -= 1;
+                load.idle_workers += 1;
+            }
+            loop {
+                let job = lock(&receiver).recv();
+                let mut load = lock(&workload);
+                load.idle_workers -= 1;
+                match job {
+                    Ok((job, ticket)) => {
+                        load.queued_jobs -= 1;
+                        drop(load);
+                        job();
+                        let mut state = lock(&ticket.state);
+                        if *state == JobState::Abandoned {
+                            // another worker has taken the place of this one
+                            return;
+                        }
+                        *state = JobState::Finished;
+                        drop(state);
+                        lock(&workload).idle_workers += 1;
+                    },
+                    // all senders are gone
+                    Err(_) => {
+                        load.num_workers -= 1;
+                        return;
+                    },
+                }
+            }
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Debug for TimeoutWorkers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TimeoutWorkers")
+            .field("workload", &*lock(&self.workload))
+            .finish()
+    }
+}
+
+/// Locks the given mutex. The guarded values are valid even if another
+/// thread panicked, as the jobs catch the panics of the fitness functions.
+#[cfg(not(target_arch = "wasm32"))]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// On wasm32 there are no threads, so the evaluations always run to
+/// completion on the current thread.
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug)]
+pub(crate) struct TimeoutWorkers;
+
+#[cfg(target_arch = "wasm32")]
+impl TimeoutWorkers {
+    pub(crate) fn new() -> Self {
+        TimeoutWorkers
+    }
+}
@@ -0,0 +1,613 @@
+//! The `trace` module provides the trace mode of the `GeneticAlgorithm`. It
+//! writes a structured dump of each stage of selected generations, which
+//! helps to find out why an operator misbehaves in a certain generation.
+//!
+//! The trace mode is enabled by `GeneticAlgorithmBuilder::with_trace` for a
+//! range of generations. For each traced generation the trace contains
+//! * the indices of the selected parents within the population,
+//! * the cut points of the crossover and the loci changed by the mutation
+//!   of each child,
+//! * the indices of the offspring and of the individuals of the population
+//!   that are reinserted into the next generation.
+//!
+//! The trace is written as text with one `TraceRecord` per line. The
+//! `TraceReader` reads the records back and `pretty_print` formats a trace
+//! for humans.
+//!
+//! The operators do not report what they did, so the trace is derived by
+//! comparing the genomes before and after each stage. Therefore the genomes
+//! must implement `TraceGenome`. An individual is identified by equality, so
+//! among several equal individuals the first one is reported. The cut points
+//! are the loci at which a child continues with the genes of another parent.
+//! Where the genes of several parents are equal, the genes are attributed to
+//! the parent that continues the longest, so the cut points are the fewest
+//! that explain the child.
+
+use crate::{
+    ga::GeneticAlgorithmError,
+    genetic::{Genotype, Locus, Parents},
+    population::GenomeLength,
+};
+use std::{
+    fmt::{self, Debug, Display},
+    io::{self, BufRead, Write},
+    ops::RangeInclusive,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+/// A `TraceGenome` is a `genetic::Genotype` that can be traced, because its
+/// genes can be compared locus by locus.
+pub trait TraceGenome: Genotype + GenomeLength + PartialEq {
+    /// Returns the loci at which the genes of this genome differ from the
+    /// genes of the other genome, in ascending order. If the genomes differ
+    /// in length, the loci beyond the end of the shorter genome differ.
+    fn differing_loci(&self, other: &Self) -> Vec<Locus>;
+}
+
+impl<V> TraceGenome for Vec<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn differing_loci(&self, other: &Self) -> Vec<Locus> {
+        (0..self.len().max(other.len()))
+            .filter(|locus| self.get(*locus) != other.get(*locus))
+            .collect()
+    }
+}
+
+/// An error that can occur when writing or reading a trace.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TraceError {
+    /// The trace can not be written or read.
+    Io(String),
+    /// A line of the trace is not a valid `TraceRecord`.
+    InvalidRecord(String),
+}
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TraceError::Io(details) => write!(f, "{}", details),
+            TraceError::InvalidRecord(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl From<io::Error> for TraceError {
+    fn from(error: io::Error) -> Self {
+        TraceError::Io(error.to_string())
+    }
+}
+
+/// A `TraceRecord` is one line of a trace. It describes one decision of a
+/// stage of a generation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TraceRecord {
+    /// The parents selected for a group of parents, given by their indices
+    /// within the population, or `None` if a parent is not found in the
+    /// population.
+    Selection {
+        /// The generation of the record.
+        generation: u64,
+        /// The index of the group of parents.
+        group: usize,
+        /// The indices of the parents.
+        parents: Vec<Option<usize>>,
+    },
+    /// A child bred from a group of parents.
+    Breeding {
+        /// The generation of the record.
+        generation: u64,
+        /// The index of the group of parents.
+        group: usize,
+        /// The index of the child among the children of the group.
+        child: usize,
+        /// The loci at which the child continues with the genes of another
+        /// parent.
+        cut_points: Vec<Locus>,
+        /// The loci that have been changed by the mutation.
+        mutated_loci: Vec<Locus>,
+    },
+    /// The individuals that make up the next generation.
+    Reinsertion {
+        /// The generation of the record.
+        generation: u64,
+        /// The indices of the reinserted offspring.
+        offspring: Vec<usize>,
+        /// The indices of the individuals of the population that survive.
+        survivors: Vec<usize>,
+    },
+}
+
+impl TraceRecord {
+    /// Returns the generation of this record.
+    pub fn generation(&self) -> u64 {
+        match self {
+            TraceRecord::Selection { generation, .. }
+            | TraceRecord::Breeding { generation, .. }
+            | TraceRecord::Reinsertion { generation, .. } => *generation,
+        }
+    }
+}
+
+/// Writes a record as one line without the line break, e.g.
+/// `500 select 3 parents=12,7`, `500 breed 3.1 cut_points=4,9 mutated_loci=-`
+/// or `500 reinsert offspring=0,1,4 survivors=2,3`. An empty list is written
+/// as `-` and a parent that is not found as `?`.
+impl Display for TraceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TraceRecord::Selection {
+                generation,
+                group,
+                parents,
+            } => {
+                let parents: Vec<String> = parents
+                    .iter()
+                    .map(|parent| match parent {
+                        Some(index) => index.to_string(),
+                        None => "?".to_string(),
+                    })
+                    .collect();
+                write!(
+                    f,
+                    "{} select {} parents={}",
+                    generation,
+                    group,
+                    join(&parents)
+                )
+            },
+            TraceRecord::Breeding {
+                generation,
+                group,
+                child,
+                cut_points,
+                mutated_loci,
+            } => write!(
+                f,
+                "{} breed {}.{} cut_points={} mutated_loci={}",
+                generation,
+                group,
+                child,
+                join(cut_points),
+                join(mutated_loci)
+            ),
+            TraceRecord::Reinsertion {
+                generation,
+                offspring,
+                survivors,
+            } => write!(
+                f,
+                "{} reinsert offspring={} survivors={}",
+                generation,
+                join(offspring),
+                join(survivors)
+            ),
+        }
+    }
+}
+
+fn join<T>(values: &[T]) -> String
+where
+    T: ToString,
+{
+    if values.is_empty() {
+        return "-".to_string();
+    }
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl FromStr for TraceRecord {
+    type Err = TraceError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let invalid = || TraceError::InvalidRecord(format!("invalid trace record: {}", line));
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let generation: u64 = fields
+            .first()
+            .and_then(|field| field.parse().ok())
+            .ok_or_else(invalid)?;
+        match fields[1..] {
+            ["select", group, parents] => Ok(TraceRecord::Selection {
+                generation,
+                group: group.parse().map_err(|_| invalid())?,
+                parents: split(value_of(parents, "parents").ok_or_else(invalid)?)
+                    .map(|parent| match parent {
+                        "?" => Ok(None),
+                        _ => parent.parse().map(Some),
+                    })
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| invalid())?,
+            }),
+            ["breed", child, cut_points, mutated_loci] => {
+                let (group, child) = child.split_once('.').ok_or_else(invalid)?;
+                Ok(TraceRecord::Breeding {
+                    generation,
+                    group: group.parse().map_err(|_| invalid())?,
+                    child: child.parse().map_err(|_| invalid())?,
+                    cut_points: parse_list(value_of(cut_points, "cut_points"))
+                        .ok_or_else(invalid)?,
+                    mutated_loci: parse_list(value_of(mutated_loci, "mutated_loci"))
+                        .ok_or_else(invalid)?,
+                })
+            },
+            ["reinsert", offspring, survivors] => Ok(TraceRecord::Reinsertion {
+                generation,
+                offspring: parse_list(value_of(offspring, "offspring")).ok_or_else(invalid)?,
+                survivors: parse_list(value_of(survivors, "survivors")).ok_or_else(invalid)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+fn value_of<'a>(field: &'a str, key: &str) -> Option<&'a str> {
+    field
+        .split_once('=')
+        .filter(|(name, _)| *name == key)
+        .map(|(_, value)| value)
+}
+
+fn split(list: &str) -> impl Iterator<Item = &str> {
+    list.split(',').filter(|value| *value != "-")
+}
+
+fn parse_list(list: Option<&str>) -> Option<Vec<usize>> {
+    split(list?).map(|value| value.parse().ok()).collect()
+}
+
+/// The `TraceReader` reads the `TraceRecord`s of a trace line by line.
+/// Empty lines are skipped.
+#[derive(Debug)]
+pub struct TraceReader<R> {
+    reader: R,
+    line: String,
+}
+
+impl<R> TraceReader<R>
+where
+    R: BufRead,
+{
+    /// Constructs a new `TraceReader` that reads the trace from the given
+    /// reader.
+    pub fn new(reader: R) -> Self {
+        TraceReader {
+            reader,
+            line: String::new(),
+        }
+    }
+}
+
+impl<R> Iterator for TraceReader<R>
+where
+    R: BufRead,
+{
+    type Item = Result<TraceRecord, TraceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => return Some(self.line.trim().parse()),
+                Err(error) => return Some(Err(error.into())),
+            }
+        }
+    }
+}
+
+/// Reads the trace from the given reader and writes it in a human readable
+/// form to the given writer, grouped by generation and stage.
+pub fn pretty_print<R, W>(reader: R, mut writer: W) -> Result<(), TraceError>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut current: Option<(u64, &str)> = None;
+    for record in TraceReader::new(reader) {
+        let record = record?;
+        let stage = match record {
+            TraceRecord::Selection { .. } => "Selection",
+            TraceRecord::Breeding { .. } => "Breeding",
+            TraceRecord::Reinsertion { .. } => "Reinsertion",
+        };
+        if current.map(|(generation, _)| generation) != Some(record.generation()) {
+            writeln!(writer, "Generation {}", record.generation())?;
+        }
+        if current != Some((record.generation(), stage)) {
+            writeln!(writer, "  {}", stage)?;
+        }
+        current = Some((record.generation(), stage));
+        match record {
+            TraceRecord::Selection { group, parents, .. } => {
+                let parents: Vec<String> = parents
+                    .iter()
+                    .map(|parent| match parent {
+                        Some(index) => index.to_string(),
+                        None => "not in population".to_string(),
+                    })
+                    .collect();
+                writeln!(
+                    writer,
+                    "    group {}: parents {}",
+                    group,
+                    parents.join(", ")
+                )?;
+            },
+            TraceRecord::Breeding {
+                group,
+                child,
+                cut_points,
+                mutated_loci,
+                ..
+            } => writeln!(
+                writer,
+                "    group {} child {}: cut points {}; mutated loci {}",
+                group,
+                child,
+                pretty_list(&cut_points),
+                pretty_list(&mutated_loci)
+            )?,
+            TraceRecord::Reinsertion {
+                offspring,
+                survivors,
+                ..
+            } => {
+                writeln!(writer, "    offspring {}", pretty_list(&offspring))?;
+                writeln!(writer, "    survivors {}", pretty_list(&survivors))?;
+            },
+        }
+    }
+    Ok(())
+}
+
+fn pretty_list(values: &[usize]) -> String {
+    if values.is_empty() {
+        return "none".to_string();
+    }
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The trace of one child as derived by the `Tracing`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ChildTrace {
+    cut_points: Vec<Locus>,
+    mutated_loci: Vec<Locus>,
+}
+
+/// The trace mode of a `GeneticAlgorithm`, which writes the trace of the
+/// given generations to the writer.
+///
+/// It captures the bounds on the genotype that tracing requires, so that
+/// the `GeneticAlgorithm` does not need them.
+pub(crate) struct Tracing<G>
+where
+    G: Genotype,
+{
+    generations: RangeInclusive<u64>,
+    writer: Arc<Mutex<dyn Write + Send>>,
+    index_of: fn(&[G], &G) -> Option<usize>,
+    differing_loci: fn(&G, &G) -> Vec<Locus>,
+    genome_length: fn(&G) -> usize,
+}
+
+impl<G> Tracing<G>
+where
+    G: TraceGenome,
+{
+    pub(crate) fn new<W>(writer: W, generations: RangeInclusive<u64>) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Tracing {
+            generations,
+            writer: Arc::new(Mutex::new(writer)),
+            index_of: |individuals, genome| individuals.iter().position(|other| other == genome),
+            differing_loci: G::differing_loci,
+            genome_length: G::genome_length,
+        }
+    }
+}
+
+impl<G> Tracing<G>
+where
+    G: Genotype,
+{
+    /// Returns whether the given generation is traced.
+    pub(crate) fn traces(&self, generation: u64) -> bool {
+        self.generations.contains(&generation)
+    }
+
+    /// Writes the given records, one per line.
+    pub(crate) fn write(
+        &self,
+        generation: u64,
+        records: impl IntoIterator<Item = TraceRecord>,
+    ) -> Result<(), GeneticAlgorithmError> {
+        let mut writer = self
+            .writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        records
+            .into_iter()
+            .try_for_each(|record| writeln!(writer, "{}", record))
+            .and_then(|_| writer.flush())
+            .map_err(|error| {
+                GeneticAlgorithmError::TraceFailed(format!(
+                    "Can not write the trace of generation {}: {}",
+                    generation, error
+                ))
+            })
+    }
+
+    /// Returns the selection records of the given groups of parents.
+    pub(crate) fn selection(
+        &self,
+        generation: u64,
+        population: &[G],
+        parents: &[Parents<G>],
+    ) -> Vec<TraceRecord> {
+        parents
+            .iter()
+            .enumerate()
+            .map(|(group, parents)| TraceRecord::Selection {
+                generation,
+                group,
+                parents: parents
+                    .iter()
+                    .map(|parent| (self.index_of)(population, parent))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Derives the trace of a child from its parents, the child as bred by
+    /// the crossover and the child after the mutation.
+    pub(crate) fn child(&self, parents: &[G], bred: &G, mutated: &G) -> ChildTrace {
+        let genome_length = (self.genome_length)(bred);
+        // the number of consecutive genes from each locus on that equal the
+        // genes of a parent
+        let runs: Vec<Vec<usize>> = parents
+            .iter()
+            .map(|parent| {
+                let mut matches = vec![true; genome_length];
+                for locus in (self.differing_loci)(bred, parent) {
+                    if locus < genome_length {
+                        matches[locus] = false;
+                    }
+                }
+                let mut runs = vec![0; genome_length + 1];
+                for locus in (0..genome_length).rev() {
+                    if matches[locus] {
+                        runs[locus] = runs[locus + 1] + 1;
+                    }
+                }
+                runs
+            })
+            .collect();
+        let run = |parent: usize, locus: Locus| runs[parent][locus];
+        let longest_run = |locus: Locus| {
+            (0..runs.len())
+                .filter(|parent| run(*parent, locus) > 0)
+                .max_by_key(|parent| (run(*parent, locus), usize::MAX - parent))
+        };
+        let mut cut_points = Vec::new();
+        let mut current = longest_run(0);
+        for locus in 1..genome_length {
+            if current.is_some_and(|parent| run(parent, locus) > 0) {
+                continue;
+            }
+            if let Some(parent) = longest_run(locus) {
+                if current.is_some() {
+                    cut_points.push(locus);
+                }
+                current = Some(parent);
+            }
+        }
+        ChildTrace {
+            cut_points,
+            mutated_loci: (self.differing_loci)(bred, mutated),
+        }
+    }
+
+    /// Returns the breeding records of the given traces of the children of
+    /// each group of parents.
+    pub(crate) fn breeding(
+        &self,
+        generation: u64,
+        children: Vec<Vec<ChildTrace>>,
+    ) -> Vec<TraceRecord> {
+        children
+            .into_iter()
+            .enumerate()
+            .flat_map(|(group, children)| {
+                children
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(child, trace)| TraceRecord::Breeding {
+                        generation,
+                        group,
+                        child,
+                        cut_points: trace.cut_points,
+                        mutated_loci: trace.mutated_loci,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the reinsertion record of the next generation that has been
+    /// made up of the given offspring and population.
+    pub(crate) fn reinsertion(
+        &self,
+        generation: u64,
+        offspring: &[G],
+        population: &[G],
+        next_generation: &[G],
+    ) -> TraceRecord {
+        let mut reinserted_offspring = Vec::new();
+        let mut survivors = Vec::new();
+        for individual in next_generation {
+            if let Some(index) = (self.index_of)(offspring, individual) {
+                reinserted_offspring.push(index);
+            } else if let Some(index) = (self.index_of)(population, individual) {
+                survivors.push(index);
+            }
+        }
+        reinserted_offspring.sort_unstable();
+        survivors.sort_unstable();
+        TraceRecord::Reinsertion {
+            generation,
+            offspring: reinserted_offspring,
+            survivors,
+        }
+    }
+}
+
+impl<G> Clone for Tracing<G>
+where
+    G: Genotype,
+{
+    fn clone(&self) -> Self {
+        Tracing {
+            generations: self.generations.clone(),
+            writer: self.writer.clone(),
+            index_of: self.index_of,
+            differing_loci: self.differing_loci,
+            genome_length: self.genome_length,
+        }
+    }
+}
+
+impl<G> Debug for Tracing<G>
+where
+    G: Genotype,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Tracing")
+            .field("generations", &self.generations)
+            .finish()
+    }
+}
+
+/// `Tracing`s are equal if they trace the same generations to the same
+/// writer, the functions are not compared.
+impl<G> PartialEq for Tracing<G>
+where
+    G: Genotype,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.generations == other.generations && Arc::ptr_eq(&self.writer, &other.writer)
+    }
+}
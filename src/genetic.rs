@@ -3,7 +3,29 @@
 //! or search problem. The types are named after terms as they are found in
 //! genetic biology.
 
-use std::fmt::Debug;
+use std::{
+    fmt::{self, Debug, Display},
+    iter::FromIterator,
+    ops::Deref,
+};
+
+/// Derives `Fitness` and `AsScalar`, as well as the arithmetic operators
+/// `Add`, `Sub` and `Sum`, for newtypes that wrap a single fitness value.
+///
+/// ```
+/// use genevo::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Fitness)]
+/// struct Score(u32);
+///
+/// assert_eq!(Score(3).abs_diff(&Score(7)), Score(4));
+/// assert_eq!(Score(3).as_scalar(), 3.);
+/// assert_eq!(vec![Score(1), Score(2)].into_iter().sum::<Score>(), Score(3));
+/// ```
+///
+/// The macro is provided by the `derive` feature.
+#[cfg(feature = "derive")]
+pub use genevo_derive::Fitness;
 
 /// A `Phenotype` is a candidate solution of the optimization or search problem.
 /// Phenotypes are also called individuals or creatures. A `Phenotype` is the
@@ -36,8 +58,18 @@ where
 /// In order to achieve an efficient execution of the genetic algorithm these
 /// properties should be stored in a compact form such as strings or vectors
 /// of primitive types.
-pub trait Genotype: Clone + Debug + PartialEq + Send + Sync {
-    type Dna: Clone + Debug + PartialEq;
+pub trait Genotype: Clone + Debug + Send + Sync {
+    type Dna: Clone + Debug;
+}
+
+/// A `GenomeDistance` measures how different two `Genotype`s are. It is
+/// used to calculate the genotypic diversity of a population.
+///
+/// The distance of a genome to itself must be 0 and the distance must be
+/// symmetric.
+pub trait GenomeDistance {
+    /// Returns the distance between this genome and the other genome.
+    fn distance(&self, other: &Self) -> f64;
 }
 
 /// The `Locus` is a position within a `Genotype`.
@@ -57,13 +89,298 @@ pub type Locus = usize;
 pub type Parents<G> = Vec<G>;
 pub type ParentsSlice<'a, G> = &'a [G];
 
+/// A `Pair` of parents is the fixed-arity representation of the common case
+/// of breeding from two `Genotype`s. Crossover operators can provide a fast
+/// path for a `Pair` through `operator::CrossoverOp::crossover_pair`.
+///
+/// A `Pair` converts into `Parents` and `Parents` of exactly two individuals
+/// can be converted into a `Pair` using `TryFrom`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pair<G>(pub G, pub G);
+
+impl<G> From<Pair<G>> for Parents<G> {
+    fn from(pair: Pair<G>) -> Self {
+        vec![pair.0, pair.1]
+    }
+}
+
+impl<G> TryFrom<Parents<G>> for Pair<G> {
+    /// The `Parents` are handed back if there are not exactly two of them.
+    type Error = Parents<G>;
+
+    fn try_from(parents: Parents<G>) -> Result<Self, Self::Error> {
+        if parents.len() != 2 {
+            return Err(parents);
+        }
+        let mut parents = parents.into_iter();
+        match (parents.next(), parents.next()) {
+            (Some(first), Some(second)) => Ok(Pair(first, second)),
+            _ => unreachable!("there are exactly two parents"),
+        }
+    }
+}
+
 /// The `Children` type defines a set of `Genotype`s which is the outcome of
 /// the `operator::CrossoverOp` function.
 pub type Children<G> = Vec<G>;
 
+/// A `Segment` is a range of loci of a child that an `operator::CrossoverOp`
+/// has copied from one of the parents. The range includes the `start` locus
+/// and excludes the `end` locus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Segment {
+    /// The first locus of the segment.
+    pub start: Locus,
+    /// The locus after the last locus of the segment.
+    pub end: Locus,
+    /// The index of the parent in the `Parents` the segment is copied from.
+    pub parent: usize,
+}
+
+/// A `TracedChild` is a child together with the `Segment`s it has been
+/// assembled from by an `operator::CrossoverOp`. The segments are ordered by
+/// their loci. If the crossover operator does not trace the origin of the
+/// genes, the list of segments is empty.
+///
+/// The segments tell an `operator::MutationOp` where the crossover happened,
+/// see `operator::MutationOp::mutate_traced`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TracedChild<G> {
+    /// The genome of the child.
+    pub genome: G,
+    /// The segments the genome has been assembled from.
+    pub segments: Vec<Segment>,
+}
+
+impl<G> TracedChild<G> {
+    /// Constructs a new `TracedChild` whose origin is not known.
+    pub fn untraced(genome: G) -> Self {
+        TracedChild {
+            genome,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Returns the crossover boundaries, i.e. the loci at which a segment
+    /// starts that comes from another parent than the segment before.
+    pub fn boundaries(&self) -> impl Iterator<Item = Locus> + '_ {
+        self.segments
+            .windows(2)
+            .filter(|pair| pair[0].parent != pair[1].parent)
+            .map(|pair| pair[1].start)
+    }
+}
+
+/// The `TracedChildren` are the outcome of the
+/// `operator::CrossoverOp::crossover_traced` function.
+pub type TracedChildren<G> = Vec<TracedChild<G>>;
+
 /// The `Offspring` type defines the set of `Children` of type `Genotype`
 /// which represents the all children of all `Parents` of one generation.
-pub type Offspring<G> = Vec<G>;
+///
+/// Besides the children themselves the `Offspring` can carry information
+/// about where the children come from:
+/// * the indices of the parents of each child, if they are known, e.g. for
+///   genealogy
+/// * the `Provenance`, i.e. the names of the operators that bred the children
+///
+/// For compatibility with code that expects a plain vector of children the
+/// `Offspring` dereferences to `Vec<G>`. The children should only be removed
+/// by the methods of `Offspring` itself, which keep the parent indices in
+/// sync with the children.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Offspring<G>
+where
+    G: Genotype,
+{
+    individuals: Vec<G>,
+    parent_indices: Option<Vec<Vec<usize>>>,
+    provenance: Option<Provenance>,
+}
+
+/// The `Provenance` names the operators that bred an `Offspring`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Provenance {
+    /// The name of the `operator::CrossoverOp`.
+    pub crossover: String,
+    /// The name of the `operator::MutationOp`.
+    pub mutation: String,
+}
+
+impl<G> Offspring<G>
+where
+    G: Genotype,
+{
+    /// Constructs a new empty `Offspring`.
+    pub fn new() -> Self {
+        Offspring::from(Vec::new())
+    }
+
+    /// Constructs a new empty `Offspring` with room for `capacity` children
+    /// without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Offspring::from(Vec::with_capacity(capacity))
+    }
+
+    /// Sets the `Provenance` of this `Offspring`.
+    pub fn with_provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Returns the `Provenance` of this `Offspring` if it is known.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.provenance.as_ref()
+    }
+
+    /// Sets the `Provenance` of this `Offspring`.
+    pub fn set_provenance(&mut self, provenance: Provenance) {
+        self.provenance = Some(provenance);
+    }
+
+    /// Adds a child of unknown parents.
+    pub fn push(&mut self, child: G) {
+        if let Some(parent_indices) = self.parent_indices.as_mut() {
+            parent_indices.push(Vec::new());
+        }
+        self.individuals.push(child);
+    }
+
+    /// Adds a child together with the indices of its parents in the
+    /// population.
+    pub fn push_with_parents(&mut self, child: G, parent_indices: Vec<usize>) {
+        let num_individuals = self.individuals.len();
+        self.parent_indices
+            .get_or_insert_with(|| vec![Vec::new(); num_individuals])
+            .push(parent_indices);
+        self.individuals.push(child);
+    }
+
+    /// Returns the indices of the parents of the child at the given index.
+    /// Returns `None` if the parents of the child are not known.
+    pub fn parent_indices(&self, index: usize) -> Option<&[usize]> {
+        self.parent_indices
+            .as_ref()
+            .and_then(|parent_indices| parent_indices.get(index))
+            .filter(|parents| !parents.is_empty())
+            .map(Vec::as_slice)
+    }
+
+    /// Removes the last child and returns it.
+    pub fn pop(&mut self) -> Option<G> {
+        if let Some(parent_indices) = self.parent_indices.as_mut() {
+            parent_indices.pop();
+        }
+        self.individuals.pop()
+    }
+
+    /// Removes the child at the given index and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> G {
+        if let Some(parent_indices) = self.parent_indices.as_mut() {
+            parent_indices.remove(index);
+        }
+        self.individuals.remove(index)
+    }
+
+    /// Keeps the first `len` children and drops the rest.
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(parent_indices) = self.parent_indices.as_mut() {
+            parent_indices.truncate(len);
+        }
+        self.individuals.truncate(len);
+    }
+
+    /// Moves all children of `other` into this `Offspring`, leaving `other`
+    /// empty.
+    pub fn append(&mut self, other: &mut Offspring<G>) {
+        match (self.parent_indices.as_mut(), other.parent_indices.as_mut()) {
+            (Some(own), Some(others)) => own.append(others),
+            (Some(own), None) => own.resize(own.len() + other.individuals.len(), Vec::new()),
+            (None, Some(others)) => {
+                let mut parent_indices = vec![Vec::new(); self.individuals.len()];
+                parent_indices.append(others);
+                self.parent_indices = Some(parent_indices);
+            },
+            (None, None) => {},
+        }
+        other.parent_indices = None;
+        self.individuals.append(&mut other.individuals);
+    }
+
+    /// Returns the children as mutable slice, e.g. to repair them in place.
+    pub fn individuals_mut(&mut self) -> &mut [G] {
+        &mut self.individuals
+    }
+
+    /// Removes all children and returns them.
+    pub fn take_individuals(&mut self) -> Vec<G> {
+        self.parent_indices = None;
+        std::mem::take(&mut self.individuals)
+    }
+
+    /// Converts this `Offspring` into the vector of its children.
+    pub fn into_individuals(self) -> Vec<G> {
+        self.individuals
+    }
+}
+
+impl<G> Default for Offspring<G>
+where
+    G: Genotype,
+{
+    fn default() -> Self {
+        Offspring::new()
+    }
+}
+
+impl<G> From<Vec<G>> for Offspring<G>
+where
+    G: Genotype,
+{
+    fn from(individuals: Vec<G>) -> Self {
+        Offspring {
+            individuals,
+            parent_indices: None,
+            provenance: None,
+        }
+    }
+}
+
+impl<G> FromIterator<G> for Offspring<G>
+where
+    G: Genotype,
+{
+    fn from_iter<I: IntoIterator<Item = G>>(iter: I) -> Self {
+        Offspring::from(iter.into_iter().collect::<Vec<G>>())
+    }
+}
+
+impl<G> IntoIterator for Offspring<G>
+where
+    G: Genotype,
+{
+    type Item = G;
+    type IntoIter = std::vec::IntoIter<G>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.individuals.into_iter()
+    }
+}
+
+impl<G> Deref for Offspring<G>
+where
+    G: Genotype,
+{
+    type Target = Vec<G>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.individuals
+    }
+}
 
 /// A `Fitness` value is used to determine the quality of a `Genotype`.
 /// `Fitness` values should have an ordering, also called ranking.
@@ -110,6 +427,51 @@ where
     /// Calculates the `Fitness` value of the given `Genotype`.
     fn fitness_of(&self, a: &G) -> F;
 
+    /// Calculates the `Fitness` value of the given `Genotype` or returns an
+    /// `EvalError` if it can not be calculated. How the algorithm handles
+    /// the error is defined by its `ga::EvaluationErrorPolicy`.
+    ///
+    /// The default implementation calls `fitness_of` and never fails.
+    /// Fitness functions that can fail implement `TryFitnessFunction` and
+    /// are wrapped into a `fitness::fallible::FallibleFitness`.
+    fn try_fitness_of(&self, a: &G) -> Result<F, EvalError> {
+        Ok(self.fitness_of(a))
+    }
+
+    /// Returns the `Fitness` value of the given `Genotype` if it is already
+    /// known, e.g. from a cache, without calculating it.
+    ///
+    /// The algorithm does not evaluate `Genotype`s whose `Fitness` value is
+    /// known. The default implementation returns `None`.
+    fn cached_fitness_of(&self, _a: &G) -> Option<F> {
+        None
+    }
+
+    /// Calculates the `Fitness` values of all given `Genotype`s at once. The
+    /// `Fitness` value at index `i` of the result is the one of the
+    /// `Genotype` at index `i` of `population`.
+    ///
+    /// This function is only called by the algorithm if
+    /// `evaluates_population_at_once` returns `true`. Fitness functions that
+    /// run much faster on a whole population than on single genotypes, e.g.
+    /// on a GPU, override both functions. The default implementation calls
+    /// `fitness_of` for each `Genotype`.
+    fn fitness_of_population(&self, population: &[G]) -> Vec<F> {
+        population
+            .iter()
+            .map(|genome| self.fitness_of(genome))
+            .collect()
+    }
+
+    /// Returns whether the algorithm evaluates the population by one call to
+    /// `fitness_of_population` instead of evaluating the `Genotype`s one by
+    /// one in parallel. An evaluation timeout does not apply to the
+    /// evaluation of a whole population. The default implementation returns
+    /// `false`.
+    fn evaluates_population_at_once(&self) -> bool {
+        false
+    }
+
     /// Calculates the average `Fitness` value of the given `Fitness` values.
     fn average(&self, a: &[F]) -> F;
 
@@ -119,4 +481,65 @@ where
     /// Returns the worst of all theoretically possible `Fitness` values.
     /// This is usually a value equivalent to zero.
     fn lowest_possible_fitness(&self) -> F;
+
+    /// Is called by the algorithm after the `Fitness` values of all
+    /// `Genotype`s of a generation have been calculated. The `Fitness` value
+    /// at index `i` of `fitness_values` is the one of the `Genotype` at index
+    /// `i` of `population`.
+    ///
+    /// Fitness functions that maintain state across generations, such as the
+    /// archive of a `fitness::novelty::NoveltyFitness`, can update it here.
+    /// The default implementation does nothing.
+    fn population_evaluated(&mut self, _population: &[G], _fitness_values: &[F]) {}
+}
+
+/// An `EvalError` is the reason why the `Fitness` value of a `Genotype` can
+/// not be calculated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EvalError {
+    /// The `Genotype` is invalid, e.g. it violates a hard constraint, so that
+    /// its `Fitness` value is undefined.
+    Invalid(String),
+    /// The evaluation failed, e.g. because an external program or service
+    /// used for the calculation failed.
+    Failed(String),
+    /// The fitness function panicked with the given message.
+    Panicked(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::Invalid(details) => write!(f, "invalid genotype: {}", details),
+            EvalError::Failed(details) => write!(f, "evaluation failed: {}", details),
+            EvalError::Panicked(details) => write!(f, "evaluation panicked: {}", details),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// A `TryFitnessFunction` calculates the `Fitness` value of a `Genotype` like
+/// a `FitnessFunction`, but may fail with an `EvalError` instead of
+/// panicking, e.g. for infeasible genomes or failing external evaluators.
+///
+/// To use it in an algorithm it is wrapped into a
+/// `fitness::fallible::FallibleFitness`.
+pub trait TryFitnessFunction<G, F>: Clone
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Calculates the `Fitness` value of the given `Genotype` or returns an
+    /// `EvalError` if it can not be calculated.
+    fn try_fitness_of(&self, a: &G) -> Result<F, EvalError>;
+
+    /// Calculates the average `Fitness` value of the given `Fitness` values.
+    fn average(&self, a: &[F]) -> F;
+
+    /// Returns the very best of all theoretically possible `Fitness` values.
+    fn highest_possible_fitness(&self) -> F;
+
+    /// Returns the worst of all theoretically possible `Fitness` values.
+    fn lowest_possible_fitness(&self) -> F;
 }
@@ -107,10 +107,28 @@ pub mod algorithm;
 
 pub mod ga;
 
+#[cfg(feature = "map-elites")]
+pub mod map_elites;
+
+pub mod sa;
+
+pub mod es;
+
+pub mod compare;
+
+#[cfg(feature = "multi-objective")]
+pub mod pareto;
+
 pub mod population;
 
+pub mod cache;
+
+pub mod fitness;
+
 pub mod encoding;
 
+pub mod problems;
+
 pub mod operator;
 
 pub mod simulation;
@@ -123,6 +141,10 @@ pub mod mutation;
 
 pub mod reinsertion;
 
+pub mod fixer;
+
+pub mod local_search;
+
 pub mod termination;
 
 pub mod random;
@@ -130,3 +152,18 @@ pub mod random;
 pub mod statistic;
 
 pub mod types;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(all(feature = "bench", not(target_arch = "wasm32")))]
+pub mod bench;
+
+#[cfg(all(feature = "wasm-parallel", target_arch = "wasm32"))]
+pub mod wasm;
+
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
+#[cfg(feature = "stable-api")]
+pub mod stable;
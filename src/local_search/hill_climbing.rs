@@ -0,0 +1,136 @@
+//! The `hill_climbing` module provides the `HillClimber`.
+
+use crate::{
+    genetic::{Fitness, FitnessFunction, Genotype},
+    operator::{
+        usize_parameter, GenerationAware, GeneticOperator, LocalSearchOp, MutationOp,
+        OperatorParameterError, ParamRange, ParamSpace, TunableOperator,
+    },
+    random::Rng,
+};
+
+/// The name of the parameter of the maximum number of iterations.
+const MAX_ITERATIONS: &str = "max_iterations";
+
+/// The `HillClimber` is a stochastic hill climbing `operator::LocalSearchOp`.
+///
+/// In each iteration it mutates the current genome with the wrapped
+/// `operator::MutationOp` and moves to the mutated genome if its fitness is
+/// higher. The search stops after the maximum number of iterations or as
+/// soon as the highest possible fitness is reached. Each iteration evaluates
+/// the fitness of one genome, so the maximum number of iterations is the
+/// budget of fitness evaluations per refined genome.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HillClimber<M> {
+    mutator: M,
+    max_iterations: usize,
+}
+
+impl<M> HillClimber<M> {
+    /// Constructs a new `HillClimber` that explores the neighborhood of a
+    /// genome with the given mutator for at most the given number of
+    /// iterations.
+    pub fn new(mutator: M, max_iterations: usize) -> Self {
+        HillClimber {
+            mutator,
+            max_iterations,
+        }
+    }
+
+    /// Returns the wrapped mutator.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the wrapped mutator as mutable reference.
+    pub fn mutator_mut(&mut self) -> &mut M {
+        &mut self.mutator
+    }
+
+    /// Returns the maximum number of iterations per refined genome.
+    pub fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+
+    /// Sets the maximum number of iterations per refined genome.
+    pub fn set_max_iterations(&mut self, value: usize) {
+        self.max_iterations = value;
+    }
+}
+
+impl<M> GeneticOperator for HillClimber<M>
+where
+    M: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Hill-Climber-{}", M::name())
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        self.mutator.as_generation_aware()
+    }
+}
+
+impl<M> TunableOperator for HillClimber<M>
+where
+    M: TunableOperator,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        let mut names = vec![MAX_ITERATIONS];
+        names.extend(self.mutator.parameter_names());
+        names
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            MAX_ITERATIONS => Some(self.max_iterations as f64),
+            _ => self.mutator.parameter(name),
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        match name {
+            MAX_ITERATIONS => {
+                let value = self.parameter_space().validate(name, value)?;
+                self.max_iterations = usize_parameter(name, value)?;
+                Ok(())
+            },
+            _ => self.mutator.set_parameter(name, value),
+        }
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(ParamRange::integer(MAX_ITERATIONS, 0., f64::INFINITY))
+            .merge(self.mutator.parameter_space())
+    }
+}
+
+impl<G, F, M> LocalSearchOp<G, F> for HillClimber<M>
+where
+    G: Genotype,
+    F: Fitness,
+    M: MutationOp<G>,
+{
+    fn improve<E, R>(&self, genome: G, evaluator: &E, rng: &mut R) -> G
+    where
+        E: FitnessFunction<G, F>,
+        R: Rng + Sized,
+    {
+        let highest_fitness = evaluator.highest_possible_fitness();
+        let mut fitness = evaluator.fitness_of(&genome);
+        let mut current = genome;
+        for _ in 0..self.max_iterations {
+            if fitness >= highest_fitness {
+                break;
+            }
+            let candidate = self.mutator.mutate(current.clone(), rng);
+            let candidate_fitness = evaluator.fitness_of(&candidate);
+            if candidate_fitness > fitness {
+                current = candidate;
+                fitness = candidate_fitness;
+            }
+        }
+        current
+    }
+}
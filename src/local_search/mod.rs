@@ -0,0 +1,11 @@
+//! The `local_search` module provides implementations of the
+//! `operator::LocalSearchOp` that refine the offspring of a memetic
+//! algorithm.
+//!
+//! The provided implementations of the `operator::LocalSearchOp` are:
+//! * `hill_climbing::HillClimber`
+
+pub mod hill_climbing;
+
+#[cfg(test)]
+mod tests;
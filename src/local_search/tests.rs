@@ -0,0 +1,71 @@
+use super::hill_climbing::*;
+use crate::{
+    fitness::function::FnFitnessFunction,
+    genetic::FitnessFunction,
+    mutation::value::RandomValueMutator,
+    operator::{LocalSearchOp, TunableOperator},
+    random::{get_rng, random_seed},
+};
+use galvanic_assert::matchers::*;
+use proptest::prelude::*;
+
+fn sum_of_values() -> impl FitnessFunction<Vec<i32>, i32> {
+    FnFitnessFunction::new(|genome: &Vec<i32>| genome.iter().sum::<i32>()).with_bounds(0, 40)
+}
+
+proptest! {
+
+    #[test]
+    fn hill_climber_never_decreases_the_fitness(
+        genome in proptest::collection::vec(0..10, 4),
+        max_iterations in 0usize..50,
+    ) {
+        let evaluator = sum_of_values();
+        let hill_climber = HillClimber::new(RandomValueMutator::new(0.5, 0, 10), max_iterations);
+
+        let improved = hill_climber.improve(genome.clone(), &evaluator, &mut get_rng(random_seed()));
+
+        prop_assert!(improved.iter().sum::<i32>() >= genome.iter().sum::<i32>());
+    }
+}
+
+#[test]
+fn hill_climber_without_iterations_returns_the_genome_unchanged() {
+    let hill_climber = HillClimber::new(RandomValueMutator::new(1., 0, 10), 0);
+
+    let improved = hill_climber.improve(vec![1, 2, 3, 4], &sum_of_values(), &mut get_rng([3; 32]));
+
+    expect_that!(&improved, eq(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn hill_climber_stops_at_the_highest_possible_fitness() {
+    let hill_climber = HillClimber::new(RandomValueMutator::new(1., 0, 10), 100);
+
+    let improved = hill_climber.improve(vec![10; 4], &sum_of_values(), &mut get_rng([3; 32]));
+
+    expect_that!(&improved, eq(vec![10; 4]));
+}
+
+#[test]
+fn hill_climber_climbs_towards_higher_fitness() {
+    let hill_climber = HillClimber::new(RandomValueMutator::new(0.25, 0, 10), 500);
+
+    let improved = hill_climber.improve(vec![0; 4], &sum_of_values(), &mut get_rng([3; 32]));
+
+    expect_that!(&improved.iter().sum::<i32>(), greater_than(30));
+}
+
+#[test]
+fn hill_climber_exposes_its_iterations_and_the_mutator_parameters() {
+    let mut hill_climber = HillClimber::new(RandomValueMutator::<Vec<i32>>::new(0.25, 0, 10), 20);
+
+    hill_climber.set_parameter("max_iterations", 40.).unwrap();
+
+    expect_that!(&hill_climber.max_iterations(), eq(40));
+    expect_that!(
+        &hill_climber.parameter_names().contains(&"max_iterations"),
+        eq(true)
+    );
+    expect_that!(&hill_climber.parameter_names().len(), greater_than(1));
+}
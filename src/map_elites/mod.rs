@@ -0,0 +1,613 @@
+//! The `map_elites` module provides an implementation of the MAP-Elites
+//! (Multi-dimensional Archive of Phenotypic Elites) algorithm, a
+//! quality-diversity algorithm.
+//!
+//! Instead of evolving one population towards the single best solution,
+//! MAP-Elites searches for the best solution in each niche of a grid of
+//! niches. The niche of an individual is determined by its features, which
+//! are calculated by a `fitness::novelty::BehaviorDescriptor`.
+//!
+//! The stages of the MAP-Elites algorithm are:
+//!
+//! 1. **Initialize**: Evaluate the initial population and place each
+//!    individual into the niche determined by its features, if it is better
+//!    than the current elite of that niche.
+//! 2. **Variation**: Sample parents uniformly at random from the elites in
+//!    the archive, cross them over by means of the configured
+//!    `operator::CrossoverOp` and mutate the children by means of the
+//!    configured `operator::MutationOp`.
+//! 3. **Placement**: Evaluate the children and place them into their niches
+//!    if they are better than the current elite of the niche.
+//! 4. **Loop**: Go to step 2
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Checkpointable, Evaluated, OptimizationResult},
+    fitness::novelty::BehaviorDescriptor,
+    genetic::{Fitness, FitnessFunction, Genotype, Offspring, Parents},
+    operator::{CrossoverOp, MutationOp},
+    population::Population,
+    random::{random_index, Prng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+/// One dimension of a `FeatureGrid`. The range from `min` to `max` is divided
+/// into `num_bins` bins of equal size. Feature values outside of the range
+/// are placed into the first or last bin respectively.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridDimension {
+    min: f64,
+    max: f64,
+    num_bins: usize,
+}
+
+impl GridDimension {
+    /// Constructs a new `GridDimension`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is not greater than `min` or `num_bins` is 0.
+    pub fn new(min: f64, max: f64, num_bins: usize) -> Self {
+        assert!(max > min, "max must be greater than min");
+        assert!(num_bins > 0, "num_bins must be greater than 0");
+        GridDimension { min, max, num_bins }
+    }
+
+    /// Returns the lower bound of this dimension.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the upper bound of this dimension.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the number of bins of this dimension.
+    pub fn num_bins(&self) -> usize {
+        self.num_bins
+    }
+
+    /// Returns the index of the bin the given feature value falls into.
+    pub fn bin_of(&self, value: f64) -> usize {
+        let relative = (value - self.min) / (self.max - self.min);
+        if relative.is_nan() || relative <= 0. {
+            0
+        } else {
+            ((relative * self.num_bins as f64) as usize).min(self.num_bins - 1)
+        }
+    }
+}
+
+/// The `FeatureGrid` maps the features of an individual to a niche. A niche
+/// is identified by the bin index in each `GridDimension`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeatureGrid {
+    dimensions: Vec<GridDimension>,
+}
+
+impl FeatureGrid {
+    /// Constructs a new `FeatureGrid` with the given dimensions.
+    pub fn new(dimensions: Vec<GridDimension>) -> Self {
+        FeatureGrid { dimensions }
+    }
+
+    /// Returns the dimensions of this grid.
+    pub fn dimensions(&self) -> &[GridDimension] {
+        &self.dimensions
+    }
+
+    /// Returns the total number of niches in this grid.
+    pub fn num_niches(&self) -> usize {
+        self.dimensions.iter().map(|d| d.num_bins).product()
+    }
+
+    /// Returns the niche for the given features or `None` if the number of
+    /// features does not match the number of dimensions of this grid.
+    pub fn niche_of(&self, features: &[f64]) -> Option<Niche> {
+        if features.len() != self.dimensions.len() {
+            return None;
+        }
+        Some(
+            self.dimensions
+                .iter()
+                .zip(features)
+                .map(|(dimension, value)| dimension.bin_of(*value))
+                .collect(),
+        )
+    }
+}
+
+/// A `Niche` is identified by the index of the bin in each dimension of the
+/// `FeatureGrid`.
+pub type Niche = Vec<usize>;
+
+/// An `Elite` is the best individual found so far in its niche.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Elite<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The genome of the elite.
+    pub genome: G,
+    /// The fitness value of the elite.
+    pub fitness: F,
+    /// The features of the elite as calculated by the `BehaviorDescriptor`.
+    pub features: Vec<f64>,
+    /// The iteration in which the elite has been found.
+    pub iteration: u64,
+}
+
+/// The `EliteArchive` holds the elite of each niche that has been occupied
+/// so far.
+///
+/// With the `serde` feature enabled the archive can be serialized on its
+/// own, e.g. to checkpoint a long running simulation, and later be loaded
+/// to warm start a `MapElites` algorithm by `MapElites::with_archive` or
+/// to analyze the elites without the rest of the algorithm.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: Serialize, F: Serialize",
+        deserialize = "G: Deserialize<'de>, F: Deserialize<'de>"
+    ))
+)]
+pub struct EliteArchive<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    #[cfg_attr(feature = "serde", serde(with = "niche_entries"))]
+    elites: BTreeMap<Niche, Elite<G, F>>,
+}
+
+impl<G, F> EliteArchive<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Constructs a new empty `EliteArchive`.
+    pub fn new() -> Self {
+        EliteArchive {
+            elites: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of occupied niches.
+    pub fn len(&self) -> usize {
+        self.elites.len()
+    }
+
+    /// Returns whether no niche is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.elites.is_empty()
+    }
+
+    /// Returns the elite of the given niche.
+    pub fn get(&self, niche: &[usize]) -> Option<&Elite<G, F>> {
+        self.elites.get(niche)
+    }
+
+    /// Returns an iterator over the occupied niches and their elites ordered
+    /// by niche.
+    pub fn iter(&self) -> impl Iterator<Item = (&Niche, &Elite<G, F>)> {
+        self.elites.iter()
+    }
+
+    /// Places the given elite into the given niche, if the niche is empty or
+    /// the elite is better than the current elite of the niche.
+    ///
+    /// Returns whether the elite has been placed into the niche.
+    pub fn insert(&mut self, niche: Niche, elite: Elite<G, F>) -> bool {
+        match self.elites.get(&niche) {
+            Some(current) if current.fitness >= elite.fitness => false,
+            _ => {
+                self.elites.insert(niche, elite);
+                true
+            },
+        }
+    }
+
+    /// Returns the elite with the highest fitness of all niches.
+    pub fn best(&self) -> Option<&Elite<G, F>> {
+        self.elites.values().fold(None, |best, elite| match best {
+            Some(best) if best.fitness >= elite.fitness => Some(best),
+            _ => Some(elite),
+        })
+    }
+
+    /// Returns the fraction of the niches of the given grid that are
+    /// occupied.
+    pub fn coverage(&self, grid: &FeatureGrid) -> f64 {
+        self.elites.len() as f64 / grid.num_niches() as f64
+    }
+
+    /// Removes all elites from this archive.
+    pub fn clear(&mut self) {
+        self.elites.clear();
+    }
+}
+
+impl<G, F> Default for EliteArchive<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn default() -> Self {
+        EliteArchive::new()
+    }
+}
+
+/// The `State` struct holds the results of one iteration of the MAP-Elites
+/// algorithm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The archive of elites after this iteration.
+    pub archive: EliteArchive<G, F>,
+    /// The number of individuals placed into the archive in this iteration.
+    pub num_placed: usize,
+    /// The best elite of all niches.
+    pub best_solution: BestSolution<G, F>,
+    /// Processing time for this iteration.
+    pub processing_time: ProcessingTime,
+}
+
+impl<G, F> OptimizationResult<G, F> for State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<G, F> {
+        &self.best_solution
+    }
+}
+
+/// An error that can occur during execution of the `MapElites` algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MapElitesError {
+    /// The algorithm is run with an empty initial population.
+    EmptyPopulation(String),
+    /// The number of features of an individual does not match the number
+    /// of dimensions of the `FeatureGrid`.
+    FeatureDimensionMismatch(String),
+}
+
+impl Display for MapElitesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MapElitesError::EmptyPopulation(details) => write!(f, "{}", details),
+            MapElitesError::FeatureDimensionMismatch(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for MapElitesError {}
+
+/// The `MapElites` algorithm keeps the best individual of each niche of a
+/// `FeatureGrid`. In each iteration it breeds a batch of children from
+/// parents sampled from the archive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapElites<G, F, E, D, C, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    D: BehaviorDescriptor<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    descriptor: D,
+    grid: FeatureGrid,
+    breeder: C,
+    mutator: M,
+    batch_size: usize,
+    num_individuals_per_parents: usize,
+    initial_population: Population<G>,
+    initial_archive: EliteArchive<G, F>,
+    archive: EliteArchive<G, F>,
+    processing_time: ProcessingTime,
+}
+
+impl<G, F, E, D, C, M> MapElites<G, F, E, D, C, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    D: BehaviorDescriptor<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    /// Constructs a new instance of the `MapElites` algorithm. By default it
+    /// breeds as many children per iteration as there are individuals in the
+    /// initial population and uses 2 individuals per parents.
+    pub fn new(
+        evaluator: E,
+        descriptor: D,
+        grid: FeatureGrid,
+        breeder: C,
+        mutator: M,
+        initial_population: Population<G>,
+    ) -> Self {
+        MapElites {
+            _f: PhantomData,
+            evaluator,
+            descriptor,
+            grid,
+            breeder,
+            mutator,
+            batch_size: initial_population.size(),
+            num_individuals_per_parents: 2,
+            initial_population,
+            initial_archive: EliteArchive::new(),
+            archive: EliteArchive::new(),
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    /// Sets the minimum number of children bred in each iteration.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Warm starts the algorithm with the given archive, e.g. one that has
+    /// been loaded from a checkpoint. If the archive is not empty the initial
+    /// population is not evaluated and the first iteration breeds children
+    /// from the elites of the given archive right away.
+    ///
+    /// Resetting the algorithm restores the given archive.
+    pub fn with_archive(mut self, archive: EliteArchive<G, F>) -> Self {
+        self.initial_archive = archive.clone();
+        self.archive = archive;
+        self
+    }
+
+    /// Sets the number of individuals sampled from the archive for each
+    /// crossover.
+    pub fn with_num_individuals_per_parents(mut self, value: usize) -> Self {
+        self.num_individuals_per_parents = value;
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn descriptor(&self) -> &D {
+        &self.descriptor
+    }
+
+    pub fn grid(&self) -> &FeatureGrid {
+        &self.grid
+    }
+
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    pub fn num_individuals_per_parents(&self) -> usize {
+        self.num_individuals_per_parents
+    }
+
+    /// Returns the archive of elites found so far.
+    pub fn archive(&self) -> &EliteArchive<G, F> {
+        &self.archive
+    }
+
+    /// Breeds at least `batch_size` children from parents sampled uniformly
+    /// at random from the archive.
+    fn breed_offspring(&self, rng: &mut Prng) -> Offspring<G> {
+        let elites: Vec<&G> = self
+            .archive
+            .iter()
+            .map(|(_, elite)| &elite.genome)
+            .collect();
+        let mut offspring = Offspring::with_capacity(self.batch_size);
+        while offspring.len() < self.batch_size {
+            let parents: Parents<G> = (0..self.num_individuals_per_parents)
+                .map(|_| elites[random_index(rng, elites.len())].clone())
+                .collect();
+            for child in self.breeder.crossover(parents, rng) {
+                offspring.push(self.mutator.mutate(child, rng));
+            }
+        }
+        offspring
+    }
+
+    /// Evaluates the given individuals and places them into the archive.
+    /// Returns the number of individuals that have been placed.
+    fn place_into_archive(
+        &mut self,
+        iteration: u64,
+        individuals: &[G],
+    ) -> Result<usize, MapElitesError> {
+        let fitness_values: Vec<F> = individuals
+            .iter()
+            .map(|genome| self.evaluator.fitness_of(genome))
+            .collect();
+        self.evaluator
+            .population_evaluated(individuals, &fitness_values);
+        let mut num_placed = 0;
+        for (genome, fitness) in individuals.iter().zip(fitness_values) {
+            let features = self.descriptor.behavior_of(genome);
+            let niche = self.grid.niche_of(&features).ok_or_else(|| {
+                MapElitesError::FeatureDimensionMismatch(format!(
+                    "The behavior descriptor returned {} features but the feature grid has {} \
+                     dimensions.",
+                    features.len(),
+                    self.grid.dimensions.len()
+                ))
+            })?;
+            let elite = Elite {
+                genome: genome.clone(),
+                fitness,
+                features,
+                iteration,
+            };
+            if self.archive.insert(niche, elite) {
+                num_placed += 1;
+            }
+        }
+        Ok(num_placed)
+    }
+}
+
+impl<G, F, E, D, C, M> TrackProcessingTime for MapElites<G, F, E, D, C, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    D: BehaviorDescriptor<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, F, E, D, C, M> Algorithm for MapElites<G, F, E, D, C, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    D: BehaviorDescriptor<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    type Output = State<G, F>;
+    type Error = MapElitesError;
+
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        output.improves_on(best)
+    }
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if self.initial_population.size() == 0 {
+            return Err(MapElitesError::EmptyPopulation(
+                "MAP-Elites can not be started with an empty initial population.".to_string(),
+            ));
+        }
+        let placement = timed(|| {
+            let individuals = if self.archive.is_empty() {
+                self.initial_population.individuals().to_vec()
+            } else {
+                self.breed_offspring(rng).into_individuals()
+            };
+            self.place_into_archive(iteration, &individuals)
+        })
+        .run();
+        let num_placed = placement.result?;
+        self.processing_time = placement.time;
+
+        let best = self
+            .archive
+            .best()
+            .expect("the archive is not empty after the initial population has been placed");
+        Ok(State {
+            archive: self.archive.clone(),
+            num_placed,
+            best_solution: BestSolution {
+                found_at: Local::now(),
+                generation: best.iteration,
+                solution: Evaluated {
+                    genome: best.genome.clone(),
+                    fitness: best.fitness.clone(),
+                },
+            },
+            processing_time: self.processing_time,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.archive = self.initial_archive.clone();
+        Ok(true)
+    }
+}
+
+/// The progress of the `MapElites` algorithm is held by its `EliteArchive`,
+/// which is therefore the snapshot of a checkpoint.
+impl<G, F, E, D, C, M> Checkpointable for MapElites<G, F, E, D, C, M>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+    D: BehaviorDescriptor<G>,
+    C: CrossoverOp<G>,
+    M: MutationOp<G>,
+{
+    type Snapshot = EliteArchive<G, F>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.archive.clone()
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        self.archive = snapshot;
+    }
+}
+
+/// Serializes the elites of an `EliteArchive` as a sequence of niche and
+/// elite pairs, as formats like JSON only support strings as map keys.
+#[cfg(feature = "serde")]
+mod niche_entries {
+    use super::{Elite, Niche};
+    use crate::genetic::{Fitness, Genotype};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::BTreeMap;
+
+    pub fn serialize<G, F, S>(
+        elites: &BTreeMap<Niche, Elite<G, F>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        G: Genotype + Serialize,
+        F: Fitness + Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(elites.iter())
+    }
+
+    pub fn deserialize<'de, G, F, D>(
+        deserializer: D,
+    ) -> Result<BTreeMap<Niche, Elite<G, F>>, D::Error>
+    where
+        G: Genotype + Deserialize<'de>,
+        F: Fitness + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let entries: Vec<(Niche, Elite<G, F>)> = Vec::deserialize(deserializer)?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,279 @@
+use super::*;
+use crate::{
+    mutation::value::RandomValueMutator, random::get_rng,
+    recombination::discrete::UniformCrossBreeder,
+};
+use galvanic_assert::matchers::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        400
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FirstTwoValues;
+
+impl BehaviorDescriptor<Vec<i32>> for FirstTwoValues {
+    fn behavior_of(&self, genome: &Vec<i32>) -> Vec<f64> {
+        vec![genome[0] as f64, genome[1] as f64]
+    }
+}
+
+mod feature_grid {
+
+    use super::*;
+
+    #[test]
+    fn niche_of_clamps_features_to_the_grid() {
+        let grid = FeatureGrid::new(vec![
+            GridDimension::new(0., 10., 5),
+            GridDimension::new(-1., 1., 2),
+        ]);
+
+        expect_that!(&grid.num_niches(), eq(10));
+        expect_that!(&grid.niche_of(&[4.5, 0.5]), eq(Some(vec![2, 1])));
+        expect_that!(&grid.niche_of(&[-3., 7.]), eq(Some(vec![0, 1])));
+        expect_that!(&grid.niche_of(&[10., -1.]), eq(Some(vec![4, 0])));
+        expect_that!(&grid.niche_of(&[1.]), eq(None));
+    }
+}
+
+mod elite_archive {
+
+    use super::*;
+
+    fn elite(fitness: i32) -> Elite<Vec<i32>, i32> {
+        Elite {
+            genome: vec![fitness],
+            fitness,
+            features: vec![],
+            iteration: 0,
+        }
+    }
+
+    #[test]
+    fn keeps_only_the_best_individual_per_niche() {
+        let mut archive = EliteArchive::new();
+
+        expect_that!(&archive.insert(vec![0], elite(5)), eq(true));
+        expect_that!(&archive.insert(vec![0], elite(3)), eq(false));
+        expect_that!(&archive.insert(vec![0], elite(7)), eq(true));
+        expect_that!(&archive.insert(vec![1], elite(2)), eq(true));
+
+        expect_that!(&archive.len(), eq(2));
+        expect_that!(&archive.get(&[0]).map(|e| e.fitness), eq(Some(7)));
+        expect_that!(&archive.best().map(|e| e.fitness), eq(Some(7)));
+    }
+}
+
+mod map_elites {
+
+    use super::*;
+
+    #[test]
+    fn fills_the_archive_with_elites() {
+        let initial_population = Population::with_individuals(vec![
+            vec![5, 5, 5, 5],
+            vec![50, 50, 50, 50],
+            vec![95, 95, 95, 95],
+        ]);
+        let grid = FeatureGrid::new(vec![
+            GridDimension::new(0., 100., 4),
+            GridDimension::new(0., 100., 4),
+        ]);
+        let mut algorithm = MapElites::new(
+            SumOfValues,
+            FirstTwoValues,
+            grid,
+            UniformCrossBreeder::new(),
+            RandomValueMutator::new(0.5, 0, 100),
+            initial_population,
+        )
+        .with_batch_size(10);
+        let mut rng = get_rng([7; 32]);
+
+        let first = algorithm.next(1, &mut rng).unwrap();
+        expect_that!(&first.num_placed, eq(3));
+        expect_that!(&first.best_solution.solution.fitness, eq(380));
+
+        for iteration in 2..=20 {
+            algorithm.next(iteration, &mut rng).unwrap();
+        }
+
+        expect_that!(&algorithm.archive().len(), greater_than(3));
+        for (niche, elite) in algorithm.archive().iter() {
+            expect_that!(
+                &algorithm.grid().niche_of(&elite.features),
+                eq(Some(niche.clone()))
+            );
+        }
+    }
+
+    #[test]
+    fn reports_mismatching_feature_dimensions() {
+        let grid = FeatureGrid::new(vec![GridDimension::new(0., 100., 4)]);
+        let mut algorithm = MapElites::new(
+            SumOfValues,
+            FirstTwoValues,
+            grid,
+            UniformCrossBreeder::new(),
+            RandomValueMutator::new(0.5, 0, 100),
+            Population::with_individuals(vec![vec![1, 2]]),
+        );
+
+        let result = algorithm.next(1, &mut get_rng([7; 32]));
+
+        expect_that!(
+            &result,
+            eq(Err(MapElitesError::FeatureDimensionMismatch(
+                "The behavior descriptor returned 2 features but the feature grid has 1 \
+                 dimensions."
+                    .to_string()
+            )))
+        );
+    }
+}
+
+mod simulation {
+
+    use super::*;
+    use crate::{
+        simulation::{
+            simulator::{simulate, Simulator},
+            SimResult, Simulation, SimulationBuilder,
+        },
+        termination::limit::GenerationLimit,
+    };
+
+    type TestMapElites = MapElites<
+        Vec<i32>,
+        i32,
+        SumOfValues,
+        FirstTwoValues,
+        UniformCrossBreeder,
+        RandomValueMutator<Vec<i32>>,
+    >;
+
+    fn algorithm() -> TestMapElites {
+        MapElites::new(
+            SumOfValues,
+            FirstTwoValues,
+            FeatureGrid::new(vec![
+                GridDimension::new(0., 100., 4),
+                GridDimension::new(0., 100., 4),
+            ]),
+            UniformCrossBreeder::new(),
+            RandomValueMutator::new(0.5, 0, 100),
+            Population::with_individuals(vec![vec![5, 5, 5, 5], vec![95, 95, 95, 95]]),
+        )
+        .with_batch_size(10)
+    }
+
+    fn step_archives(
+        simulator: &mut Simulator<TestMapElites, GenerationLimit>,
+        steps: usize,
+    ) -> Vec<EliteArchive<Vec<i32>, i32>> {
+        (0..steps)
+            .map(|_| match simulator.step().unwrap() {
+                SimResult::Intermediate(state) | SimResult::Final(state, _, _, _, _) => {
+                    state.result.archive
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn simulator_resumes_map_elites_from_a_checkpoint() {
+        let mut simulator = simulate(algorithm())
+            .until(GenerationLimit::new(6))
+            .build_with_seed([5; 32]);
+        step_archives(&mut simulator, 3);
+        let checkpoint = simulator.save_checkpoint().unwrap();
+        let expected = step_archives(&mut simulator, 3);
+
+        let mut resumed =
+            Simulator::from_checkpoint(checkpoint, algorithm(), GenerationLimit::new(6));
+
+        expect_that!(&step_archives(&mut resumed, 3), eq(expected));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod checkpoint {
+
+    use super::*;
+
+    fn algorithm() -> MapElites<
+        Vec<i32>,
+        i32,
+        SumOfValues,
+        FirstTwoValues,
+        UniformCrossBreeder,
+        RandomValueMutator<Vec<i32>>,
+    > {
+        MapElites::new(
+            SumOfValues,
+            FirstTwoValues,
+            FeatureGrid::new(vec![
+                GridDimension::new(0., 100., 4),
+                GridDimension::new(0., 100., 4),
+            ]),
+            UniformCrossBreeder::new(),
+            RandomValueMutator::new(0.5, 0, 100),
+            Population::with_individuals(vec![vec![5, 5, 5, 5], vec![95, 95, 95, 95]]),
+        )
+        .with_batch_size(10)
+    }
+
+    #[test]
+    fn archive_can_be_loaded_without_the_algorithm() {
+        let mut original = algorithm();
+        let mut rng = get_rng([7; 32]);
+        for iteration in 1..=5 {
+            original.next(iteration, &mut rng).unwrap();
+        }
+
+        let json = serde_json::to_string(original.archive()).unwrap();
+        let loaded: EliteArchive<Vec<i32>, i32> = serde_json::from_str(&json).unwrap();
+
+        expect_that!(&loaded, eq(original.archive().clone()));
+    }
+
+    #[test]
+    fn warm_started_algorithm_continues_from_the_archive() {
+        let mut original = algorithm();
+        let mut rng = get_rng([7; 32]);
+        for iteration in 1..=5 {
+            original.next(iteration, &mut rng).unwrap();
+        }
+        let json = serde_json::to_string(original.archive()).unwrap();
+        let archive: EliteArchive<Vec<i32>, i32> = serde_json::from_str(&json).unwrap();
+
+        let mut resumed = algorithm().with_archive(archive.clone());
+        let state = resumed.next(6, &mut rng).unwrap();
+
+        // the initial population is not placed again, so no elite gets lost
+        for (niche, elite) in archive.iter() {
+            let current = state.archive.get(niche).unwrap();
+            expect_that!(&current.fitness, greater_than_or_equal(elite.fitness));
+        }
+        resumed.reset().unwrap();
+        expect_that!(resumed.archive(), eq(archive));
+    }
+}
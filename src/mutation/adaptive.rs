@@ -0,0 +1,261 @@
+//! The `adaptive` module provides the `AdaptiveMutationOp`, an
+//! `operator::MutationOp` that adapts the mutation rate of a wrapped
+//! `operator::MutationOp` while the algorithm is running.
+//!
+//! The `AdaptiveMutationOp` receives an `operator::GenerationFeedback` from
+//! the `ga::GeneticAlgorithm` after each generation. When the population
+//! converges prematurely, i.e. the highest fitness stagnates or the fitness
+//! values of the population hardly differ, the mutation rate is increased to
+//! explore new regions of the search space. When the highest fitness
+//! improves again, the mutation rate is decreased back towards the initial
+//! mutation rate.
+
+use crate::{
+    genetic::{Genotype, TracedChild},
+    operator::{
+        float_parameter, unknown_parameter, GenerationAware, GenerationFeedback, GeneticOperator,
+        MutationOp, OperatorParameterError, ParamRange, ParamSpace, TunableOperator,
+    },
+    random::Rng,
+};
+use std::marker::PhantomData;
+
+/// The name of the parameter of the wrapped mutator that is adapted.
+const MUTATION_RATE: &str = "mutation_rate";
+
+/// The default factor by which the mutation rate is increased or decreased.
+pub const DEFAULT_ADAPTATION_FACTOR: f64 = 1.5;
+
+/// The default number of generations without improvement after which the
+/// mutation rate is increased.
+pub const DEFAULT_PATIENCE: u64 = 5;
+
+/// The default fitness diversity below which the population is considered
+/// as converged.
+pub const DEFAULT_DIVERSITY_THRESHOLD: f64 = 0.1;
+
+/// The `AdaptiveMutationOp` wraps a `operator::MutationOp` with a parameter
+/// named `mutation_rate` and adapts the mutation rate to the progress of the
+/// algorithm.
+///
+/// The mutation rate is multiplied by the adaptation factor, up to the
+/// maximum mutation rate, in each generation in which the population is
+/// considered as converged. The population is considered as converged if
+/// the highest fitness has not improved for `patience` generations or if the
+/// fitness diversity is lower than the diversity threshold. In a generation
+/// that improves the highest fitness the mutation rate is divided by the
+/// adaptation factor, down to the initial mutation rate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    mutator: M,
+    initial_rate: f64,
+    max_rate: f64,
+    adaptation_factor: f64,
+    patience: u64,
+    diversity_threshold: f64,
+    _g: PhantomData<G>,
+}
+
+impl<G, M> AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    /// Constructs a new `AdaptiveMutationOp` that wraps the given mutator and
+    /// increases its mutation rate up to the given maximum mutation rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given mutator has no parameter named `mutation_rate`.
+    pub fn new(mutator: M, max_rate: f64) -> Self {
+        let initial_rate = mutator.parameter(MUTATION_RATE).unwrap_or_else(|| {
+            panic!(
+                "the mutator {} has no parameter named {}",
+                M::name(),
+                MUTATION_RATE
+            )
+        });
+        AdaptiveMutationOp {
+            mutator,
+            initial_rate,
+            max_rate,
+            adaptation_factor: DEFAULT_ADAPTATION_FACTOR,
+            patience: DEFAULT_PATIENCE,
+            diversity_threshold: DEFAULT_DIVERSITY_THRESHOLD,
+            _g: PhantomData,
+        }
+    }
+
+    /// Sets the factor by which the mutation rate is increased or decreased.
+    pub fn with_adaptation_factor(mut self, adaptation_factor: f64) -> Self {
+        self.adaptation_factor = adaptation_factor;
+        self
+    }
+
+    /// Sets the number of generations without improvement after which the
+    /// mutation rate is increased.
+    pub fn with_patience(mut self, patience: u64) -> Self {
+        self.patience = patience;
+        self
+    }
+
+    /// Sets the fitness diversity below which the population is considered
+    /// as converged.
+    pub fn with_diversity_threshold(mut self, diversity_threshold: f64) -> Self {
+        self.diversity_threshold = diversity_threshold;
+        self
+    }
+
+    /// Returns the wrapped mutator.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the current mutation rate of the wrapped mutator.
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutator
+            .parameter(MUTATION_RATE)
+            .unwrap_or(self.initial_rate)
+    }
+
+    /// Returns the mutation rate the wrapped mutator had initially.
+    pub fn initial_rate(&self) -> f64 {
+        self.initial_rate
+    }
+
+    /// Returns the maximum mutation rate.
+    pub fn max_rate(&self) -> f64 {
+        self.max_rate
+    }
+
+    /// Returns the factor by which the mutation rate is increased or
+    /// decreased.
+    pub fn adaptation_factor(&self) -> f64 {
+        self.adaptation_factor
+    }
+
+    /// Returns the number of generations without improvement after which
+    /// the mutation rate is increased.
+    pub fn patience(&self) -> u64 {
+        self.patience
+    }
+
+    /// Returns the fitness diversity below which the population is
+    /// considered as converged.
+    pub fn diversity_threshold(&self) -> f64 {
+        self.diversity_threshold
+    }
+}
+
+impl<G, M> GeneticOperator for AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn name() -> String {
+        format!("Adaptive-{}", M::name())
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        Some(self)
+    }
+}
+
+impl<G, M> GenerationAware for AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn generation_evaluated(&mut self, feedback: &GenerationFeedback) {
+        let rate = self.mutation_rate();
+        let converged = feedback.stagnant_generations >= self.patience
+            || feedback.fitness_diversity < self.diversity_threshold;
+        let adapted_rate = if converged {
+            (rate * self.adaptation_factor).min(self.max_rate)
+        } else if feedback.improved {
+            (rate / self.adaptation_factor).max(self.initial_rate)
+        } else {
+            rate
+        };
+        if adapted_rate != rate {
+            // the adapted rate is finite as long as the settings are
+            let _ = self.mutator.set_parameter(MUTATION_RATE, adapted_rate);
+        }
+    }
+}
+
+impl<G, M> TunableOperator for AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        let mut names = self.mutator.parameter_names();
+        names.extend([
+            "max_mutation_rate",
+            "adaptation_factor",
+            "diversity_threshold",
+        ]);
+        names
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "max_mutation_rate" => Some(self.max_rate),
+            "adaptation_factor" => Some(self.adaptation_factor),
+            "diversity_threshold" => Some(self.diversity_threshold),
+            _ => self.mutator.parameter(name),
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "max_mutation_rate" => self.max_rate = float_parameter(name, value)?,
+            "adaptation_factor" => self.adaptation_factor = float_parameter(name, value)?,
+            "diversity_threshold" => self.diversity_threshold = float_parameter(name, value)?,
+            _ if self.mutator.parameter(name).is_some() => {
+                return self.mutator.set_parameter(name, value)
+            },
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        self.mutator.parameter_space().merge(
+            ParamSpace::new()
+                .with(ParamRange::float("max_mutation_rate", 0., 1.))
+                .with(ParamRange::float("adaptation_factor", 1., f64::INFINITY))
+                .with(ParamRange::float("diversity_threshold", 0., f64::INFINITY)),
+        )
+    }
+}
+
+impl<G, M> MutationOp<G> for AdaptiveMutationOp<G, M>
+where
+    G: Genotype,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutator.mutate(genome, rng)
+    }
+
+    fn uses_segments(&self) -> bool {
+        self.mutator.uses_segments()
+    }
+
+    fn mutate_traced<R>(&self, child: TracedChild<G>, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutator.mutate_traced(child, rng)
+    }
+}
@@ -0,0 +1,152 @@
+//! The `boundary` module provides the `BoundaryMutator`, an
+//! `operator::MutationOp` that focuses the mutation of a wrapped
+//! `operator::MutationOp` on the crossover boundaries of a child.
+//!
+//! The crossover boundaries are known from the `genetic::Segment`s that an
+//! `operator::CrossoverOp` records in `operator::CrossoverOp::crossover_traced`.
+
+use crate::{
+    genetic::{Genotype, TracedChild},
+    mutation::masked::RestoreLoci,
+    operator::{
+        GenerationAware, GeneticOperator, MutationOp, OperatorParameterError, ParamSpace,
+        TunableOperator,
+    },
+    random::Rng,
+};
+use std::marker::PhantomData;
+
+/// The `BoundaryMutator` wraps a `operator::MutationOp` and only keeps the
+/// mutations of genes that are within a `radius` of a crossover boundary.
+/// All other genes are restored after the wrapped operator mutated a child.
+///
+/// A locus is within the radius of a boundary, if its distance to the first
+/// locus of the segment that starts at the boundary is smaller than the
+/// radius. Thus a radius of 1 keeps the mutations of the two genes next to
+/// each boundary.
+///
+/// If the boundaries of a child are not known or there are none, the child
+/// is mutated by the wrapped operator as a whole.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundaryMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    mutator: M,
+    radius: usize,
+    _g: PhantomData<G>,
+}
+
+impl<G, M> BoundaryMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    /// Constructs a new `BoundaryMutator` that wraps the given mutator and
+    /// keeps the mutations within the given radius of crossover boundaries.
+    pub fn new(mutator: M, radius: usize) -> Self {
+        BoundaryMutator {
+            mutator,
+            radius,
+            _g: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped mutator.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the wrapped mutator as mutable reference.
+    pub fn mutator_mut(&mut self) -> &mut M {
+        &mut self.mutator
+    }
+
+    /// Returns the radius around the crossover boundaries.
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Sets the radius around the crossover boundaries.
+    pub fn set_radius(&mut self, value: usize) {
+        self.radius = value;
+    }
+}
+
+impl<G, M> GeneticOperator for BoundaryMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    fn name() -> String {
+        format!("Boundary-{}", M::name())
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        self.mutator.as_generation_aware()
+    }
+}
+
+impl<G, M> TunableOperator for BoundaryMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        self.mutator.parameter_names()
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        self.mutator.parameter(name)
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        self.mutator.set_parameter(name, value)
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        self.mutator.parameter_space()
+    }
+}
+
+impl<G, M> MutationOp<G> for BoundaryMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutator.mutate(genome, rng)
+    }
+
+    fn uses_segments(&self) -> bool {
+        true
+    }
+
+    fn mutate_traced<R>(&self, child: TracedChild<G>, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = child.segments.last().map_or(0, |segment| segment.end);
+        let mut frozen = vec![true; genome_length];
+        let mut has_boundaries = false;
+        for boundary in child.boundaries() {
+            has_boundaries = true;
+            let start = boundary.saturating_sub(self.radius);
+            let end = (boundary + self.radius).min(genome_length);
+            for flag in &mut frozen[start..end] {
+                *flag = false;
+            }
+        }
+        if !has_boundaries {
+            return self.mutator.mutate(child.genome, rng);
+        }
+        let original = child.genome.clone();
+        let mut mutated = self.mutator.mutate(child.genome, rng);
+        mutated.restore_loci(&original, &frozen);
+        mutated
+    }
+}
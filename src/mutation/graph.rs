@@ -0,0 +1,111 @@
+//! The `graph` module provides `operator::MutationOp`s for graph encoded
+//! `genetic::Genotype`s.
+
+use crate::{
+    encoding::graph::Graph,
+    operator::{
+        float_parameter, random_count_of_ratio, unknown_parameter, GeneticOperator, MutationOp,
+        OperatorParameterError, ParamSpace, TunableOperator, MUTATION_RATE,
+    },
+    random::{random_index, Rng},
+};
+
+/// The `EdgeSwapMutator` mutates a `Graph` by swapping the ends of two
+/// randomly chosen edges, i.e. the edges `(a, b)` and `(c, d)` are replaced
+/// by the edges `(a, d)` and `(c, b)`.
+///
+/// An edge swap keeps the degree of every node, so graphs that satisfy
+/// degree bounds still do after the mutation. A swap that would create a loop
+/// or a duplicate edge is skipped.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdgeSwapMutator {
+    mutation_rate: f64,
+}
+
+impl EdgeSwapMutator {
+    pub fn new(mutation_rate: f64) -> Self {
+        EdgeSwapMutator { mutation_rate }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+}
+
+impl GeneticOperator for EdgeSwapMutator {
+    fn name() -> String {
+        "Edge-Swap-Mutation".to_string()
+    }
+}
+
+impl TunableOperator for EdgeSwapMutator {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
+impl MutationOp<Graph> for EdgeSwapMutator {
+    fn mutate<R>(&self, genome: Graph, rng: &mut R) -> Graph
+    where
+        R: Rng + Sized,
+    {
+        let mut edges: Vec<(usize, usize)> = genome.edges().collect();
+        let num_edges = edges.len();
+        if num_edges < 2 {
+            return genome;
+        }
+        let num_mutations = random_count_of_ratio(num_edges, self.mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index1 = random_index(rng, num_edges);
+            let index2 = random_index(rng, num_edges);
+            let (a, b) = edges[index1];
+            let (c, d) = if rng.gen() {
+                edges[index2]
+            } else {
+                (edges[index2].1, edges[index2].0)
+            };
+            if a == c
+                || a == d
+                || b == c
+                || b == d
+                || mutated.has_edge(a, d)
+                || mutated.has_edge(c, b)
+            {
+                continue;
+            }
+            mutated.remove_edge(a, b);
+            mutated.remove_edge(c, d);
+            mutated.add_edge(a, d);
+            mutated.add_edge(c, b);
+            edges[index1] = (a.min(d), a.max(d));
+            edges[index2] = (c.min(b), c.max(b));
+        }
+        mutated
+    }
+}
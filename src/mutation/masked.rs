@@ -0,0 +1,231 @@
+//! The `masked` module provides the `MaskedMutator`, an `operator::MutationOp`
+//! that keeps selected loci of a `genetic::Genotype` frozen while the other
+//! loci are mutated by a wrapped `operator::MutationOp`.
+//!
+//! This way partial solutions, e.g. known-good gene positions or constants,
+//! can be locked while the rest of the genome evolves.
+
+use crate::{
+    genetic::{Genotype, Locus, TracedChild},
+    operator::{
+        GenerationAware, GeneticOperator, MutationOp, OperatorParameterError, ParamSpace,
+        TunableOperator,
+    },
+    random::Rng,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The `MaskedMutator` wraps a `operator::MutationOp` and restores the genes
+/// at the frozen loci after the wrapped operator mutated a genome.
+///
+/// The mask holds one flag per locus. A locus is frozen if its flag is
+/// `true`. Loci beyond the length of the mask are not frozen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaskedMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    mutator: M,
+    frozen: Vec<bool>,
+    _g: PhantomData<G>,
+}
+
+impl<G, M> MaskedMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    /// Constructs a new `MaskedMutator` that wraps the given mutator and
+    /// keeps the loci flagged as `true` in the given mask frozen.
+    pub fn new(mutator: M, frozen: Vec<bool>) -> Self {
+        MaskedMutator {
+            mutator,
+            frozen,
+            _g: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped mutator.
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    /// Returns the wrapped mutator as mutable reference.
+    pub fn mutator_mut(&mut self) -> &mut M {
+        &mut self.mutator
+    }
+
+    /// Returns the mask of frozen loci.
+    pub fn frozen(&self) -> &[bool] {
+        &self.frozen
+    }
+
+    /// Returns whether the given locus is frozen.
+    pub fn is_frozen(&self, locus: Locus) -> bool {
+        self.frozen.get(locus).cloned().unwrap_or(false)
+    }
+
+    /// Freezes the given locus.
+    pub fn freeze(&mut self, locus: Locus) {
+        if locus >= self.frozen.len() {
+            self.frozen.resize(locus + 1, false);
+        }
+        self.frozen[locus] = true;
+    }
+
+    /// Unfreezes the given locus.
+    pub fn unfreeze(&mut self, locus: Locus) {
+        if let Some(flag) = self.frozen.get_mut(locus) {
+            *flag = false;
+        }
+    }
+}
+
+impl<G, M> GeneticOperator for MaskedMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    fn name() -> String {
+        format!("Masked-{}", M::name())
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        self.mutator.as_generation_aware()
+    }
+}
+
+impl<G, M> TunableOperator for MaskedMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G> + TunableOperator,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        self.mutator.parameter_names()
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        self.mutator.parameter(name)
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        self.mutator.set_parameter(name, value)
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        self.mutator.parameter_space()
+    }
+}
+
+impl<G, M> MutationOp<G> for MaskedMutator<G, M>
+where
+    G: Genotype + RestoreLoci,
+    M: MutationOp<G>,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        if !self.frozen.contains(&true) {
+            return self.mutator.mutate(genome, rng);
+        }
+        let original = genome.clone();
+        let mut mutated = self.mutator.mutate(genome, rng);
+        mutated.restore_loci(&original, &self.frozen);
+        mutated
+    }
+
+    fn uses_segments(&self) -> bool {
+        self.mutator.uses_segments()
+    }
+
+    fn mutate_traced<R>(&self, child: TracedChild<G>, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        if !self.frozen.contains(&true) {
+            return self.mutator.mutate_traced(child, rng);
+        }
+        let original = child.genome.clone();
+        let mut mutated = self.mutator.mutate_traced(child, rng);
+        mutated.restore_loci(&original, &self.frozen);
+        mutated
+    }
+}
+
+/// A `genetic::Genotype` that supports restoring the genes at given loci
+/// from another genome of the same kind.
+pub trait RestoreLoci: Genotype {
+    /// Restores the genes at the loci flagged as `true` in `frozen` from the
+    /// `original` genome.
+    fn restore_loci(&mut self, original: &Self, frozen: &[bool]);
+}
+
+/// Restores the frozen loci of a slice of genes.
+///
+/// If the original gene of a frozen locus has been moved to another locus,
+/// e.g. by an `operator::MutationOp` for permutation encoded genomes, the
+/// genes are swapped back. Thus permutations remain valid permutations.
+/// Otherwise the original gene is copied back.
+fn restore_slice<V>(genes: &mut [V], original: &[V], frozen: &[bool])
+where
+    V: Clone + PartialEq,
+{
+    let len = genes.len().min(original.len());
+    for locus in (0..len).filter(|locus| frozen.get(*locus) == Some(&true)) {
+        if genes[locus] == original[locus] {
+            continue;
+        }
+        // frozen loci before this one have already been restored
+        let moved_to = (0..len).find(|other| {
+            (*other > locus || frozen.get(*other) != Some(&true))
+                && genes[*other] == original[locus]
+        });
+        match moved_to {
+            Some(other) => genes.swap(locus, other),
+            None => genes[locus] = original[locus].clone(),
+        }
+    }
+}
+
+impl<V> RestoreLoci for Vec<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync,
+{
+    fn restore_loci(&mut self, original: &Self, frozen: &[bool]) {
+        restore_slice(self, original, frozen);
+    }
+}
+
+#[cfg(feature = "fixedbitset")]
+mod fixedbitset_restore_loci {
+    use super::RestoreLoci;
+    use fixedbitset::FixedBitSet;
+
+    impl RestoreLoci for FixedBitSet {
+        fn restore_loci(&mut self, original: &Self, frozen: &[bool]) {
+            let len = self.len().min(original.len());
+            for (bit, _) in frozen.iter().enumerate().take(len).filter(|(_, f)| **f) {
+                self.set(bit, original[bit]);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec_restore_loci {
+    use super::{restore_slice, RestoreLoci};
+    use smallvec::{Array, SmallVec};
+    use std::fmt::Debug;
+
+    impl<A, V> RestoreLoci for SmallVec<A>
+    where
+        A: Array<Item = V> + Sync,
+        V: Clone + Debug + PartialEq + Send + Sync,
+    {
+        fn restore_loci(&mut self, original: &Self, frozen: &[bool]) {
+            restore_slice(self, original, frozen);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! The `matrix` module provides `operator::MutationOp`s for matrix encoded
+//! `genetic::Genotype`s.
+
+use crate::{
+    encoding::matrix::{Axis, Matrix},
+    operator::{
+        float_parameter, random_count_of_ratio, unknown_parameter, GeneticOperator, MutationOp,
+        OperatorParameterError, ParamSpace, TunableOperator, MUTATION_RATE,
+    },
+    random::{random_index, Rng},
+};
+use std::fmt::Debug;
+
+/// The `MatrixSwapMutator` mutates a `Matrix` by swapping two randomly
+/// chosen values within the same row or within the same column, depending on
+/// the `Axis` the mutator works on.
+///
+/// A swap keeps the values of each row (or column) as a whole, e.g. the
+/// number of lessons of a room stays the same while they are moved to other
+/// time slots.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixSwapMutator {
+    mutation_rate: f64,
+    axis: Axis,
+}
+
+impl MatrixSwapMutator {
+    pub fn new(mutation_rate: f64, axis: Axis) -> Self {
+        MatrixSwapMutator {
+            mutation_rate,
+            axis,
+        }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    /// Returns the `Axis` along which the values are swapped.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+}
+
+impl GeneticOperator for MatrixSwapMutator {
+    fn name() -> String {
+        "Matrix-Swap-Mutation".to_string()
+    }
+}
+
+impl TunableOperator for MatrixSwapMutator {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
+impl<V> MutationOp<Matrix<V>> for MatrixSwapMutator
+where
+    V: Clone + Debug + Send + Sync,
+{
+    fn mutate<R>(&self, genome: Matrix<V>, rng: &mut R) -> Matrix<V>
+    where
+        R: Rng + Sized,
+    {
+        let (rows, cols) = (genome.rows(), genome.cols());
+        if rows == 0 || cols == 0 {
+            return genome;
+        }
+        let num_values = rows * cols;
+        let num_mutations = random_count_of_ratio(num_values, self.mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            match self.axis {
+                Axis::Rows => {
+                    let row = random_index(rng, rows);
+                    let col1 = random_index(rng, cols);
+                    let col2 = random_index(rng, cols);
+                    mutated.swap((row, col1), (row, col2));
+                },
+                Axis::Columns => {
+                    let col = random_index(rng, cols);
+                    let row1 = random_index(rng, rows);
+                    let row2 = random_index(rng, rows);
+                    mutated.swap((row1, col), (row2, col));
+                },
+            }
+        }
+        mutated
+    }
+}
@@ -1,7 +1,31 @@
 //! The `mutation` module provides `operator::MutationOp`s implementation
 //! of various mutation schemes for binary encoded, value encoded, permutation
-//! encoded and tree encoded `genetic::Genotype`s.
+//! encoded, tree encoded, graph encoded and matrix encoded
+//! `genetic::Genotype`s. The operators for graph and matrix encoded
+//! `genetic::Genotype`s are available with the crate features `graph` and
+//! `matrix`.
+//!
+//! The `masked::MaskedMutator` wraps any of these operators to keep selected
+//! loci frozen. The `boundary::BoundaryMutator` wraps any of these operators
+//! to focus the mutation on the crossover boundaries. The
+//! `adaptive::AdaptiveMutationOp` wraps any of these operators to adapt the
+//! mutation rate to the progress of the algorithm.
 
 pub mod value;
 
 pub mod order;
+
+#[cfg(feature = "graph")]
+pub mod graph;
+
+#[cfg(feature = "matrix")]
+pub mod matrix;
+
+pub mod masked;
+
+pub mod boundary;
+
+pub mod adaptive;
+
+#[cfg(test)]
+mod tests;
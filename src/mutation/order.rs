@@ -2,7 +2,10 @@
 //! `genetic::Genotype`s.
 
 use crate::{
-    operator::{GeneticOperator, MutationOp},
+    operator::{
+        float_parameter, random_count_of_ratio, unknown_parameter, GeneticOperator, MutationOp,
+        OperatorParameterError, ParamSpace, TunableOperator, MUTATION_RATE,
+    },
     random::{random_cut_points, Rng},
 };
 use std::fmt::Debug;
@@ -33,17 +36,42 @@ impl GeneticOperator for InsertOrderMutator {
     }
 }
 
+impl TunableOperator for InsertOrderMutator {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
 impl<V> MutationOp<Vec<V>> for InsertOrderMutator
 where
-    V: Clone + Debug + PartialEq + Send + Sync,
+    V: Clone + Debug + Send + Sync,
 {
     fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
     where
         R: Rng + Sized,
     {
         let genome_length = genome.len();
-        let num_mutations =
-            ((genome_length as f64 * self.mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let num_mutations = random_count_of_ratio(genome_length, self.mutation_rate, rng);
         let mut mutated = genome;
         for _ in 0..num_mutations {
             let (locus1, locus2) = random_cut_points(rng, genome_length);
@@ -80,17 +108,42 @@ impl GeneticOperator for SwapOrderMutator {
     }
 }
 
+impl TunableOperator for SwapOrderMutator {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
 impl<V> MutationOp<Vec<V>> for SwapOrderMutator
 where
-    V: Clone + Debug + PartialEq + Send + Sync,
+    V: Clone + Debug + Send + Sync,
 {
     fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
     where
         R: Rng + Sized,
     {
         let genome_length = genome.len();
-        let num_mutations =
-            ((genome_length as f64 * self.mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let num_mutations = random_count_of_ratio(genome_length, self.mutation_rate, rng);
         let mut mutated = genome;
         for _ in 0..num_mutations {
             let (locus1, locus2) = random_cut_points(rng, genome_length);
@@ -99,3 +152,78 @@ where
         mutated
     }
 }
+
+/// The `InversionMutator` reverses the order of the genes between two random
+/// loci. For routing problems like the traveling salesman problem this
+/// replaces only the two edges at the ends of the reversed section, while
+/// the other mutators of this module replace up to four edges.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InversionMutator {
+    mutation_rate: f64,
+}
+
+impl InversionMutator {
+    pub fn new(mutation_rate: f64) -> Self {
+        InversionMutator { mutation_rate }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+}
+
+impl GeneticOperator for InversionMutator {
+    fn name() -> String {
+        "Order-Inversion-Mutation".to_string()
+    }
+}
+
+impl TunableOperator for InversionMutator {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
+impl<V> MutationOp<Vec<V>> for InversionMutator
+where
+    V: Clone + Debug + Send + Sync,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = genome.len();
+        let num_mutations = random_count_of_ratio(genome_length, self.mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let (locus1, locus2) = random_cut_points(rng, genome_length);
+            mutated[locus1..=locus2].reverse();
+        }
+        mutated
+    }
+}
@@ -0,0 +1,683 @@
+use super::value::*;
+use crate::{
+    operator::MutationOp,
+    random::{get_rng, random_seed},
+};
+use galvanic_assert::matchers::*;
+use proptest::prelude::*;
+
+mod out_of_bounds_policy {
+
+    use super::*;
+
+    fn bounded_i32(value: i32, policy: OutOfBoundsPolicy) -> i32 {
+        // mutate the value by 0 to only apply the bounds
+        BreederValueMutation::breeder_mutated_within_bounds(
+            value,
+            &0,
+            1.,
+            1,
+            &0,
+            &10,
+            policy,
+            &mut get_rng(random_seed()),
+        )
+    }
+
+    #[test]
+    fn clamp_sets_the_nearest_bound_on_both_sides() {
+        expect_that!(&bounded_i32(-3, OutOfBoundsPolicy::Clamp), eq(0));
+        expect_that!(&bounded_i32(13, OutOfBoundsPolicy::Clamp), eq(10));
+    }
+
+    #[test]
+    fn reflect_mirrors_at_the_exceeded_bound_on_both_sides() {
+        expect_that!(&bounded_i32(-3, OutOfBoundsPolicy::Reflect), eq(3));
+        expect_that!(&bounded_i32(13, OutOfBoundsPolicy::Reflect), eq(7));
+    }
+
+    #[test]
+    fn wrap_continues_at_the_other_bound_on_both_sides() {
+        expect_that!(&bounded_i32(-1, OutOfBoundsPolicy::Wrap), eq(10));
+        expect_that!(&bounded_i32(11, OutOfBoundsPolicy::Wrap), eq(0));
+        expect_that!(&bounded_i32(13, OutOfBoundsPolicy::Wrap), eq(2));
+    }
+
+    #[test]
+    fn values_within_bounds_are_kept_by_every_policy() {
+        for policy in &[
+            OutOfBoundsPolicy::Clamp,
+            OutOfBoundsPolicy::Reflect,
+            OutOfBoundsPolicy::Wrap,
+            OutOfBoundsPolicy::Rerandomize,
+        ] {
+            expect_that!(&bounded_i32(4, *policy), eq(4));
+        }
+    }
+
+    fn policy() -> impl Strategy<Value = OutOfBoundsPolicy> {
+        prop_oneof![
+            Just(OutOfBoundsPolicy::Clamp),
+            Just(OutOfBoundsPolicy::Reflect),
+            Just(OutOfBoundsPolicy::Wrap),
+            Just(OutOfBoundsPolicy::Rerandomize),
+        ]
+    }
+
+    proptest! {
+
+        #[test]
+        fn bounded_integer_values_respect_the_bounds(
+            policy in policy(),
+            (min, max) in (-1_000i32..1_000).prop_flat_map(|min| (Just(min), min..1_000)),
+            value in -1_000i32..1_000,
+            range in 0i32..1_000_000,
+            sign in prop_oneof![Just(-1i8), Just(1i8)],
+        ) {
+            let bounded = BreederValueMutation::breeder_mutated_within_bounds(
+                value, &range, 1., sign, &min, &max, policy, &mut get_rng(random_seed()),
+            );
+
+            prop_assert!(bounded >= min && bounded <= max, "{} not in {}..={}", bounded, min, max);
+        }
+
+        #[test]
+        fn bounded_unsigned_values_respect_the_bounds(
+            policy in policy(),
+            (min, max) in (0u8..255).prop_flat_map(|min| (Just(min), min..=255)),
+            value in 0u8..=255,
+            range in 0u8..=255,
+            sign in prop_oneof![Just(-1i8), Just(1i8)],
+        ) {
+            let bounded = BreederValueMutation::breeder_mutated_within_bounds(
+                value, &range, 1., sign, &min, &max, policy, &mut get_rng(random_seed()),
+            );
+
+            prop_assert!(bounded >= min && bounded <= max, "{} not in {}..={}", bounded, min, max);
+        }
+
+        #[test]
+        fn bounded_float_values_respect_the_bounds(
+            policy in policy(),
+            (min, max) in (-1_000f64..1_000.).prop_flat_map(|min| (Just(min), min..1_000.)),
+            value in -1_000f64..1_000.,
+            range in 0f64..1_000_000.,
+            adjustment in 0f64..=1.,
+            sign in prop_oneof![Just(-1i8), Just(1i8)],
+        ) {
+            let bounded = BreederValueMutation::breeder_mutated_within_bounds(
+                value, &range, adjustment, sign, &min, &max, policy, &mut get_rng(random_seed()),
+            );
+
+            prop_assert!(bounded >= min && bounded <= max, "{} not in {}..={}", bounded, min, max);
+        }
+
+        #[test]
+        fn breeder_value_mutator_output_respects_the_bounds(
+            policy in policy(),
+            genome in prop::collection::vec(-50i32..=50, 1..20),
+            range in 1i32..200,
+        ) {
+            let mutator = BreederValueMutator::new(1., range, 3, -50, 50)
+                .with_out_of_bounds_policy(policy);
+
+            let mutated = mutator.mutate(genome, &mut get_rng(random_seed()));
+
+            prop_assert!(mutated.iter().all(|value| *value >= -50 && *value <= 50), "{:?}", mutated);
+        }
+    }
+}
+
+mod masked_mutator {
+
+    use super::*;
+    use crate::mutation::{masked::MaskedMutator, order::SwapOrderMutator};
+
+    #[test]
+    fn frozen_loci_keep_their_values() {
+        let mutator = MaskedMutator::new(
+            RandomValueMutator::new(1., 100, 200),
+            vec![true, false, true],
+        );
+
+        for _ in 0..20 {
+            let mutated = mutator.mutate(vec![1, 2, 3, 4, 5], &mut get_rng(random_seed()));
+
+            expect_that!(&mutated[0], eq(1));
+            expect_that!(&mutated[2], eq(3));
+        }
+    }
+
+    #[test]
+    fn permutations_stay_valid_permutations() {
+        let mut mutator = MaskedMutator::new(SwapOrderMutator::new(1.), vec![]);
+        mutator.freeze(0);
+        mutator.freeze(4);
+
+        for _ in 0..20 {
+            let mutated = mutator.mutate(vec![0, 1, 2, 3, 4, 5], &mut get_rng(random_seed()));
+
+            let mut sorted = mutated.clone();
+            sorted.sort_unstable();
+            expect_that!(&sorted, eq(vec![0, 1, 2, 3, 4, 5]));
+            expect_that!(&mutated[0], eq(0));
+            expect_that!(&mutated[4], eq(4));
+        }
+    }
+
+    #[test]
+    fn unfreeze_releases_a_locus() {
+        let mut mutator: MaskedMutator<Vec<u8>, _> =
+            MaskedMutator::new(SwapOrderMutator::new(1.), vec![true, true]);
+
+        mutator.unfreeze(1);
+        mutator.unfreeze(7);
+
+        expect_that!(&mutator.frozen(), eq(&[true, false][..]));
+        expect_that!(&mutator.is_frozen(7), eq(false));
+    }
+}
+
+mod random_char_mutator {
+
+    use super::*;
+
+    proptest! {
+
+        #[test]
+        fn mutated_text_keeps_its_length_and_consists_of_the_alphabet(
+            text in "[a-c]{0,40}",
+            mutation_rate in 0f64..1.,
+        ) {
+            let mutator = RandomCharMutator::new(mutation_rate, "abcxyz");
+            let genome: Vec<char> = text.chars().collect();
+
+            let mutated = mutator.mutate(genome.clone(), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(mutated.len(), genome.len());
+            prop_assert!(mutated.iter().all(|c| "abcxyz".contains(*c)));
+        }
+
+        #[test]
+        fn mutated_string_keeps_its_number_of_characters(
+            text in "[äöü]{0,40}",
+            mutation_rate in 0f64..1.,
+        ) {
+            let mutator = RandomCharMutator::new(mutation_rate, "aé€");
+
+            let mutated = mutator.mutate(text.clone(), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(mutated.chars().count(), text.chars().count());
+            prop_assert!(mutated.chars().all(|c| "äöüaé€".contains(c)));
+        }
+    }
+
+    #[test]
+    fn mutation_rate_of_one_inserts_characters_of_the_alphabet() {
+        let mutator = RandomCharMutator::new(1., "x");
+
+        let mutated = mutator.mutate(vec!['a'; 50], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated.contains(&'x'), eq(true));
+    }
+}
+
+mod bool_and_char_value_mutation {
+
+    use super::*;
+
+    proptest! {
+
+        #[test]
+        fn random_value_mutator_flips_bools(value in any::<bool>()) {
+            let mutator = RandomValueMutator::new(1., false, true);
+
+            let mutated: Vec<bool> = mutator.mutate(vec![value], &mut get_rng(random_seed()));
+
+            prop_assert_eq!(mutated, vec![!value]);
+        }
+
+        #[test]
+        fn random_value_mutator_picks_chars_of_the_inclusive_range(
+            text in "[a-z]{1,40}",
+            mutation_rate in 0f64..1.,
+        ) {
+            let mutator = RandomValueMutator::new(mutation_rate, 'x', 'z');
+            let genome: Vec<char> = text.chars().collect();
+
+            let mutated = mutator.mutate(genome.clone(), &mut get_rng(random_seed()));
+
+            for (mutated_char, original) in mutated.iter().zip(&genome) {
+                prop_assert!(mutated_char == original || ('x'..='z').contains(mutated_char));
+            }
+        }
+
+        #[test]
+        fn breeder_value_mutator_keeps_chars_within_the_bounds(
+            text in "[a-z]{1,40}",
+            policy in prop_oneof![
+                Just(OutOfBoundsPolicy::Clamp),
+                Just(OutOfBoundsPolicy::Reflect),
+                Just(OutOfBoundsPolicy::Wrap),
+                Just(OutOfBoundsPolicy::Rerandomize),
+            ],
+        ) {
+            let mutator = BreederValueMutator::new(1., '\u{5}', 1, 'a', 'z')
+                .with_out_of_bounds_policy(policy);
+            let genome: Vec<char> = text.chars().collect();
+
+            let mutated = mutator.mutate(genome, &mut get_rng(random_seed()));
+
+            prop_assert!(mutated.iter().all(|c| c.is_ascii_lowercase()));
+        }
+    }
+
+    #[test]
+    fn breeder_value_mutation_flips_a_bool_if_the_range_is_true() {
+        expect_that!(
+            &BreederValueMutation::breeder_mutated(false, &true, 1., 1),
+            eq(true)
+        );
+        expect_that!(
+            &BreederValueMutation::breeder_mutated(true, &true, 1., -1),
+            eq(false)
+        );
+        expect_that!(
+            &BreederValueMutation::breeder_mutated(true, &false, 1., 1),
+            eq(true)
+        );
+    }
+
+    #[test]
+    fn breeder_value_mutation_wraps_chars_around_the_bounds() {
+        let mutated = BreederValueMutation::breeder_mutated_within_bounds(
+            'y',
+            &'\u{3}',
+            1.,
+            1,
+            &'a',
+            &'z',
+            OutOfBoundsPolicy::Wrap,
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&mutated, eq('b'));
+    }
+}
+
+mod gaussian_mutator {
+
+    use super::*;
+    use crate::operator::TunableOperator;
+
+    proptest! {
+
+        #[test]
+        fn mutated_values_stay_within_the_bounds(
+            genome in prop::collection::vec(-10f64..10., 1..40),
+            mutation_rate in 0f64..1.,
+            sigma in 0f64..100.,
+        ) {
+            let mutator = GaussianMutator::new(mutation_rate, sigma, -10., 10.);
+
+            let mutated = mutator.mutate(genome.clone(), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(mutated.len(), genome.len());
+            prop_assert!(mutated.iter().all(|value| (-10. ..=10.).contains(value)));
+        }
+
+        #[test]
+        fn mutated_integers_stay_within_the_bounds(
+            genome in prop::collection::vec(0u8..=20, 1..40),
+            sigma in 0f64..50.,
+        ) {
+            let mutator = GaussianMutator::new(1., sigma, 0, 20);
+
+            let mutated = mutator.mutate(genome, &mut get_rng(random_seed()));
+
+            prop_assert!(mutated.iter().all(|value| *value <= 20));
+        }
+    }
+
+    #[test]
+    fn genes_with_a_sigma_of_zero_are_not_changed() {
+        let mutator = GaussianMutator::new(1., 5., -100., 100.).with_sigmas(vec![0., 5., 0.]);
+
+        let mutated = mutator.mutate(vec![1., 2., 3., 4.], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated[0], eq(1.));
+        expect_that!(&mutated[2], eq(3.));
+    }
+
+    #[test]
+    fn mutation_rate_of_zero_does_not_change_the_genome() {
+        let mutator = GaussianMutator::new(0., 5., -100., 100.);
+
+        let mutated = mutator.mutate(vec![1., 2., 3., 4.], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated, eq(vec![1., 2., 3., 4.]));
+    }
+
+    #[test]
+    fn deltas_are_normally_distributed_with_the_given_sigma() {
+        let mutator = GaussianMutator::new(1., 2., -1000., 1000.);
+        let mut rng = get_rng(random_seed());
+
+        let deltas = mutator.mutate(vec![0.; 10_000], &mut rng);
+
+        let mean = deltas.iter().sum::<f64>() / deltas.len() as f64;
+        let variance = deltas.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / deltas.len() as f64;
+        expect_that!(&mean.abs(), lt(0.1));
+        expect_that!(&(variance.sqrt() - 2.).abs(), lt(0.1));
+    }
+
+    #[test]
+    fn sigma_can_be_tuned() {
+        let mut mutator = GaussianMutator::<Vec<f64>>::new(0.5, 1., 0., 1.);
+
+        mutator.set_parameter("sigma", 0.25).unwrap();
+
+        expect_that!(&mutator.sigma(), eq(0.25));
+        expect_that!(&mutator.parameter("sigma"), eq(Some(0.25)));
+    }
+}
+
+#[cfg(feature = "graph")]
+mod edge_swap_mutator {
+
+    use super::*;
+    use crate::{
+        encoding::graph::Graph,
+        mutation::graph::EdgeSwapMutator,
+        population::{GenomeBuilder, GraphEncodedGenomeBuilder},
+    };
+
+    proptest! {
+
+        #[test]
+        fn mutated_graph_keeps_the_degree_of_every_node(
+            num_nodes in 4usize..30,
+            mutation_rate in 0f64..1.,
+        ) {
+            let mut rng = get_rng(random_seed());
+            let graph = GraphEncodedGenomeBuilder::new(num_nodes, 1, 3).build_genome(0, &mut rng);
+
+            let mutated = EdgeSwapMutator::new(mutation_rate).mutate(graph.clone(), &mut rng);
+
+            prop_assert_eq!(mutated.num_edges(), graph.num_edges());
+            for node in 0..num_nodes {
+                prop_assert_eq!(mutated.degree(node), graph.degree(node));
+            }
+        }
+    }
+
+    #[test]
+    fn swaps_the_ends_of_two_edges() {
+        let graph = Graph::with_edges(4, vec![(0, 1), (2, 3)]);
+
+        let mutated = EdgeSwapMutator::new(1.).mutate(graph.clone(), &mut get_rng(random_seed()));
+
+        expect_that!(&mutated.num_edges(), eq(2));
+        expect_that!(&mutated.has_edge(0, 1), eq(mutated.has_edge(2, 3)));
+    }
+}
+
+#[cfg(feature = "matrix")]
+mod matrix_swap_mutator {
+
+    use super::*;
+    use crate::{
+        encoding::matrix::{Axis, Matrix},
+        mutation::matrix::MatrixSwapMutator,
+    };
+
+    fn sorted<'a>(values: impl Iterator<Item = &'a u8>) -> Vec<u8> {
+        let mut values: Vec<u8> = values.cloned().collect();
+        values.sort_unstable();
+        values
+    }
+
+    proptest! {
+
+        #[test]
+        fn row_swaps_keep_the_values_of_each_row(
+            values in prop::collection::vec(0u8..10, 24),
+            mutation_rate in 0f64..1.,
+        ) {
+            let matrix = Matrix::from_values(4, 6, values);
+
+            let mutated = MatrixSwapMutator::new(mutation_rate, Axis::Rows)
+                .mutate(matrix.clone(), &mut get_rng(random_seed()));
+
+            for row in 0..4 {
+                prop_assert_eq!(sorted(mutated.row(row).iter()), sorted(matrix.row(row).iter()));
+            }
+        }
+
+        #[test]
+        fn column_swaps_keep_the_values_of_each_column(
+            values in prop::collection::vec(0u8..10, 24),
+            mutation_rate in 0f64..1.,
+        ) {
+            let matrix = Matrix::from_values(4, 6, values);
+
+            let mutated = MatrixSwapMutator::new(mutation_rate, Axis::Columns)
+                .mutate(matrix.clone(), &mut get_rng(random_seed()));
+
+            for col in 0..6 {
+                prop_assert_eq!(sorted(mutated.column(col)), sorted(matrix.column(col)));
+            }
+        }
+    }
+}
+
+mod boundary_mutator {
+
+    use super::*;
+    use crate::{
+        genetic::{Segment, TracedChild},
+        mutation::boundary::BoundaryMutator,
+    };
+
+    fn traced_child() -> TracedChild<Vec<i32>> {
+        TracedChild {
+            genome: vec![0; 20],
+            segments: vec![
+                Segment {
+                    start: 0,
+                    end: 10,
+                    parent: 0,
+                },
+                Segment {
+                    start: 10,
+                    end: 20,
+                    parent: 1,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn only_genes_within_the_radius_of_a_boundary_are_mutated() {
+        let mutator = BoundaryMutator::new(RandomValueMutator::new(1., 1, 10), 2);
+
+        for _ in 0..20 {
+            let mutated = mutator.mutate_traced(traced_child(), &mut get_rng(random_seed()));
+
+            for (locus, gene) in mutated.iter().enumerate() {
+                if !(8..12).contains(&locus) {
+                    expect_that!(gene, eq(0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn child_without_boundaries_is_mutated_as_a_whole() {
+        let mutator = BoundaryMutator::new(RandomValueMutator::new(1., 1, 10), 2);
+        let mut child = traced_child();
+        child.segments[1].parent = 0;
+
+        let mutated = mutator.mutate_traced(child, &mut get_rng(random_seed()));
+
+        expect_that!(
+            &mutated.iter().filter(|gene| **gene != 0).count(),
+            greater_than(4)
+        );
+    }
+}
+
+mod adaptive_mutation_op {
+
+    use super::*;
+    use crate::{
+        mutation::adaptive::AdaptiveMutationOp,
+        operator::{GenerationAware, GenerationFeedback, GeneticOperator},
+    };
+
+    fn adaptive(
+        mutation_rate: f64,
+        max_rate: f64,
+    ) -> AdaptiveMutationOp<Vec<i32>, RandomValueMutator<Vec<i32>>> {
+        AdaptiveMutationOp::new(RandomValueMutator::new(mutation_rate, 0, 9), max_rate)
+    }
+
+    fn feedback(improved: bool, stagnant_generations: u64, diversity: f64) -> GenerationFeedback {
+        GenerationFeedback {
+            generation: 1,
+            population_size: 10,
+            improved,
+            stagnant_generations,
+            fitness_diversity: diversity,
+            offspring_success: None,
+            diversity: None,
+        }
+    }
+
+    #[test]
+    fn increases_the_rate_up_to_the_maximum_while_the_fitness_stagnates() {
+        let mut mutator = adaptive(0.1, 0.3)
+            .with_adaptation_factor(2.)
+            .with_patience(3);
+
+        mutator.generation_evaluated(&feedback(false, 2, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.1, 1e-9));
+
+        mutator.generation_evaluated(&feedback(false, 3, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.2, 1e-9));
+
+        mutator.generation_evaluated(&feedback(false, 4, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.3, 1e-9));
+        expect_that!(&mutator.mutator().mutation_rate(), close_to(0.3, 1e-9));
+    }
+
+    #[test]
+    fn increases_the_rate_if_the_fitness_diversity_is_low() {
+        let mut mutator = adaptive(0.1, 0.5).with_diversity_threshold(0.2);
+
+        mutator.generation_evaluated(&feedback(true, 0, 0.1));
+
+        expect_that!(&mutator.mutation_rate(), close_to(0.15, 1e-9));
+    }
+
+    #[test]
+    fn decreases_the_rate_down_to_the_initial_rate_on_improvement() {
+        let mut mutator = adaptive(0.1, 0.5)
+            .with_adaptation_factor(2.)
+            .with_patience(1);
+        mutator.generation_evaluated(&feedback(false, 1, 1.));
+        mutator.generation_evaluated(&feedback(false, 2, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.4, 1e-9));
+
+        mutator.generation_evaluated(&feedback(true, 0, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.2, 1e-9));
+
+        mutator.generation_evaluated(&feedback(true, 0, 1.));
+        mutator.generation_evaluated(&feedback(true, 0, 1.));
+        expect_that!(&mutator.mutation_rate(), close_to(0.1, 1e-9));
+    }
+
+    #[test]
+    fn is_generation_aware() {
+        let mut mutator = adaptive(0.1, 0.5);
+        let mut plain: RandomValueMutator<Vec<i32>> = RandomValueMutator::new(0.1, 0, 9);
+
+        expect_that!(&mutator.as_generation_aware().is_some(), eq(true));
+        expect_that!(
+            &GeneticOperator::as_generation_aware(&mut plain).is_none(),
+            eq(true)
+        );
+    }
+}
+
+mod inversion_mutator {
+
+    use super::*;
+    use crate::mutation::order::InversionMutator;
+
+    proptest! {
+
+        #[test]
+        fn mutated_genome_remains_a_permutation(
+            genome_length in 4usize..50,
+            mutation_rate in 0f64..1.,
+        ) {
+            let genome: Vec<usize> = (0..genome_length).collect();
+
+            let mut mutated = InversionMutator::new(mutation_rate)
+                .mutate(genome.clone(), &mut get_rng(random_seed()));
+
+            mutated.sort_unstable();
+            prop_assert_eq!(mutated, genome);
+        }
+    }
+
+    #[test]
+    fn reverses_a_section_of_the_genome() {
+        let genome: Vec<usize> = (0..10).collect();
+
+        let mutated =
+            InversionMutator::new(0.1).mutate(genome.clone(), &mut get_rng(random_seed()));
+
+        let changed: Vec<usize> = (0..10).filter(|locus| mutated[*locus] != *locus).collect();
+        if let (Some(first), Some(last)) = (changed.first(), changed.last()) {
+            let reversed: Vec<usize> = (*first..=*last).rev().collect();
+            expect_that!(&mutated[*first..=*last].to_vec(), eq(reversed));
+        }
+    }
+}
+
+mod array_genome_mutation {
+
+    use super::*;
+
+    proptest! {
+
+        #[test]
+        fn random_value_mutator_keeps_array_values_in_range(mutation_rate in 0f64..1.) {
+            let mutator = RandomValueMutator::new(mutation_rate, -5, 5);
+
+            let mutated: [i32; 6] = mutator.mutate([0; 6], &mut get_rng(random_seed()));
+
+            prop_assert!(mutated.iter().all(|value| (-5..=5).contains(value)));
+        }
+
+        #[test]
+        fn breeder_value_mutator_keeps_array_values_in_range(mutation_rate in 0f64..1.) {
+            let mutator = BreederValueMutator::new(mutation_rate, 3., 2, -1., 1.);
+
+            let mutated: [f64; 6] = mutator.mutate([0.; 6], &mut get_rng(random_seed()));
+
+            prop_assert!(mutated.iter().all(|value| (-1. ..=1.).contains(value)));
+        }
+    }
+
+    #[test]
+    fn mutation_rate_of_one_mutates_array_genomes() {
+        let mutator = RandomValueMutator::new(1., false, true);
+
+        let mutated: [bool; 1] = mutator.mutate([false], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated, eq([true]));
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{
     genetic::Genotype,
-    operator::{GeneticOperator, MutationOp},
-    random::{random_index, Rng},
+    operator::{
+        float_parameter, random_count_of_ratio, unknown_parameter, GeneticOperator, MutationOp,
+        OperatorParameterError, ParamRange, ParamSpace, TunableOperator, MUTATION_RATE,
+    },
+    random::{random_index, random_probability, random_standard_normal, Rng},
 };
 use rand::seq::SliceRandom;
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct RandomValueMutator<G>
 where
     G: Genotype + RandomGenomeMutation,
@@ -16,6 +19,18 @@ where
     max_value: <G as Genotype>::Dna,
 }
 
+impl<G> PartialEq for RandomValueMutator<G>
+where
+    G: Genotype + RandomGenomeMutation,
+    <G as Genotype>::Dna: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.mutation_rate == other.mutation_rate
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+    }
+}
+
 impl<G> RandomValueMutator<G>
 where
     G: Genotype + RandomGenomeMutation,
@@ -31,6 +46,14 @@ where
             max_value,
         }
     }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
 }
 
 impl<G> GeneticOperator for RandomValueMutator<G>
@@ -42,6 +65,35 @@ where
     }
 }
 
+impl<G> TunableOperator for RandomValueMutator<G>
+where
+    G: Genotype + RandomGenomeMutation,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
 impl<G> MutationOp<G> for RandomValueMutator<G>
 where
     G: Genotype + RandomGenomeMutation,
@@ -91,8 +143,7 @@ where
         R: Rng + Sized,
     {
         let genome_length = genome.len();
-        let num_mutations =
-            ((genome_length as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let num_mutations = random_count_of_ratio(genome_length, mutation_rate, rng);
         let mut mutated = genome;
         for _ in 0..num_mutations {
             let index = random_index(rng, genome_length);
@@ -107,9 +158,40 @@ where
     }
 }
 
+impl<V, const N: usize> RandomGenomeMutation for [V; N]
+where
+    V: Clone + Debug + PartialEq + Send + Sync + RandomValueMutation,
+{
+    type Dna = V;
+
+    fn mutate_genome<R>(
+        genome: Self,
+        mutation_rate: f64,
+        min_value: &V,
+        max_value: &V,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let num_mutations = random_count_of_ratio(N, mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, N);
+            mutated[index] = RandomValueMutation::random_mutated(
+                mutated[index].clone(),
+                min_value,
+                max_value,
+                rng,
+            );
+        }
+        mutated
+    }
+}
+
 #[cfg(feature = "fixedbitset")]
 mod fixedbitset_random_genome_mutation {
-    use super::{random_index, RandomGenomeMutation};
+    use super::{random_count_of_ratio, random_index, RandomGenomeMutation};
     use crate::genetic::Genotype;
     use fixedbitset::FixedBitSet;
     use rand::Rng;
@@ -128,8 +210,7 @@ mod fixedbitset_random_genome_mutation {
             R: Rng + Sized,
         {
             let genome_length = genome.len();
-            let num_mutations =
-                ((genome_length as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize;
+            let num_mutations = random_count_of_ratio(genome_length, mutation_rate, rng);
             let mut mutated = genome;
             for _ in 0..num_mutations {
                 let bit = random_index(rng, genome_length);
@@ -143,7 +224,7 @@ mod fixedbitset_random_genome_mutation {
 
 #[cfg(feature = "smallvec")]
 mod smallvec_random_genome_mutation {
-    use super::{random_index, RandomGenomeMutation, RandomValueMutation};
+    use super::{random_count_of_ratio, random_index, RandomGenomeMutation, RandomValueMutation};
     use rand::Rng;
     use smallvec::{Array, SmallVec};
     use std::fmt::Debug;
@@ -166,8 +247,7 @@ mod smallvec_random_genome_mutation {
             R: Rng + Sized,
         {
             let genome_length = genome.len();
-            let num_mutations =
-                ((genome_length as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize;
+            let num_mutations = random_count_of_ratio(genome_length, mutation_rate, rng);
             let mut mutated = genome;
             for _ in 0..num_mutations {
                 let index = random_index(rng, genome_length);
@@ -206,17 +286,165 @@ macro_rules! impl_random_value_mutation {
 
 impl_random_value_mutation!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
 
+/// A `bool` value is mutated by flipping it, unless the bounds only allow
+/// one value.
 impl RandomValueMutation for bool {
     #[inline]
-    fn random_mutated<R>(_value: bool, _min_value: &bool, _max_value: &bool, rng: &mut R) -> bool
+    fn random_mutated<R>(value: bool, min_value: &bool, max_value: &bool, _rng: &mut R) -> bool
+    where
+        R: Rng + Sized,
+    {
+        if min_value == max_value {
+            *min_value
+        } else {
+            !value
+        }
+    }
+}
+
+/// A `char` value is mutated by picking a random character of the range
+/// `min_value..=max_value`, e.g. `'a'..='z'`. Unlike for numbers the maximum
+/// value is included, so that the bounds can name the alphabet.
+impl RandomValueMutation for char {
+    #[inline]
+    fn random_mutated<R>(_value: char, min_value: &char, max_value: &char, rng: &mut R) -> char
     where
         R: Rng + Sized,
     {
-        rng.gen_bool(0.5)
+        rng.gen_range(*min_value..=*max_value)
     }
 }
 
+/// The `RandomCharMutator` mutates text encoded genomes by replacing
+/// randomly chosen characters with characters picked from an alphabet.
+///
+/// As only characters of the alphabet are inserted, text genomes such as
+/// `Vec<char>` stay valid without converting them from and to bytes.
 #[derive(Clone, Debug, PartialEq)]
+pub struct RandomCharMutator<G>
+where
+    G: Genotype + RandomCharMutation,
+{
+    mutation_rate: f64,
+    alphabet: Vec<char>,
+    _g: PhantomData<G>,
+}
+
+impl<G> RandomCharMutator<G>
+where
+    G: Genotype + RandomCharMutation,
+{
+    /// Constructs a new `RandomCharMutator` that inserts characters of the
+    /// given `alphabet`, which must not be empty.
+    pub fn new(mutation_rate: f64, alphabet: &str) -> Self {
+        RandomCharMutator {
+            mutation_rate,
+            alphabet: alphabet.chars().collect(),
+            _g: PhantomData,
+        }
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    /// Returns the characters that are inserted by this mutator.
+    pub fn alphabet(&self) -> &[char] {
+        &self.alphabet
+    }
+}
+
+impl<G> GeneticOperator for RandomCharMutator<G>
+where
+    G: Genotype + RandomCharMutation,
+{
+    fn name() -> String {
+        "Random-Char-Mutator".to_string()
+    }
+}
+
+impl<G> TunableOperator for RandomCharMutator<G>
+where
+    G: Genotype + RandomCharMutation,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
+impl<G> MutationOp<G> for RandomCharMutator<G>
+where
+    G: Genotype + RandomCharMutation,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        RandomCharMutation::mutate_genome(genome, self.mutation_rate, &self.alphabet, rng)
+    }
+}
+
+pub trait RandomCharMutation: Genotype {
+    fn mutate_genome<R>(genome: Self, mutation_rate: f64, alphabet: &[char], rng: &mut R) -> Self
+    where
+        R: Rng + Sized;
+}
+
+impl RandomCharMutation for Vec<char> {
+    fn mutate_genome<R>(genome: Self, mutation_rate: f64, alphabet: &[char], rng: &mut R) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = genome.len();
+        let num_mutations = random_count_of_ratio(genome_length, mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, genome_length);
+            mutated[index] = alphabet[random_index(rng, alphabet.len())];
+        }
+        mutated
+    }
+}
+
+/// The characters of a `String` genome are mutated like a `Vec<char>`
+/// genome, so that multi-byte characters are replaced as a whole.
+impl RandomCharMutation for String {
+    fn mutate_genome<R>(genome: Self, mutation_rate: f64, alphabet: &[char], rng: &mut R) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let chars: Vec<char> = genome.chars().collect();
+        RandomCharMutation::mutate_genome(chars, mutation_rate, alphabet, rng)
+            .into_iter()
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct BreederValueMutator<G>
 where
     G: Genotype + BreederGenomeMutation,
@@ -226,6 +454,22 @@ where
     mutation_precision: u8,
     min_value: <G as Genotype>::Dna,
     max_value: <G as Genotype>::Dna,
+    out_of_bounds_policy: OutOfBoundsPolicy,
+}
+
+impl<G> PartialEq for BreederValueMutator<G>
+where
+    G: Genotype + BreederGenomeMutation,
+    <G as Genotype>::Dna: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.mutation_rate == other.mutation_rate
+            && self.mutation_range == other.mutation_range
+            && self.mutation_precision == other.mutation_precision
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+            && self.out_of_bounds_policy == other.out_of_bounds_policy
+    }
 }
 
 impl<G> BreederValueMutator<G>
@@ -245,8 +489,32 @@ where
             mutation_precision,
             min_value,
             max_value,
+            out_of_bounds_policy: OutOfBoundsPolicy::Clamp,
         }
     }
+
+    /// Sets the policy for mutated values that fall outside of the bounds.
+    /// Default is `OutOfBoundsPolicy::Clamp`.
+    pub fn with_out_of_bounds_policy(mut self, policy: OutOfBoundsPolicy) -> Self {
+        self.out_of_bounds_policy = policy;
+        self
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    pub fn out_of_bounds_policy(&self) -> OutOfBoundsPolicy {
+        self.out_of_bounds_policy
+    }
+
+    pub fn set_out_of_bounds_policy(&mut self, policy: OutOfBoundsPolicy) {
+        self.out_of_bounds_policy = policy;
+    }
 }
 
 impl<G> GeneticOperator for BreederValueMutator<G>
@@ -258,6 +526,35 @@ where
     }
 }
 
+impl<G> TunableOperator for BreederValueMutator<G>
+where
+    G: Genotype + BreederGenomeMutation,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(MUTATION_RATE)
+    }
+}
+
 impl<G> MutationOp<G> for BreederValueMutator<G>
 where
     G: Genotype + BreederGenomeMutation,
@@ -273,6 +570,7 @@ where
             self.mutation_precision,
             &self.min_value,
             &self.max_value,
+            self.out_of_bounds_policy,
             rng,
         )
     }
@@ -281,6 +579,7 @@ where
 pub trait BreederGenomeMutation: Genotype {
     type Dna: Clone;
 
+    #[allow(clippy::too_many_arguments)]
     fn mutate_genome<R>(
         genome: Self,
         mutation_rate: f64,
@@ -288,6 +587,7 @@ pub trait BreederGenomeMutation: Genotype {
         precision: u8,
         min_value: &<Self as Genotype>::Dna,
         max_value: &<Self as Genotype>::Dna,
+        out_of_bounds_policy: OutOfBoundsPolicy,
         rng: &mut R,
     ) -> Self
     where
@@ -314,14 +614,14 @@ where
         precision: u8,
         min_value: &<Self as Genotype>::Dna,
         max_value: &<Self as Genotype>::Dna,
+        out_of_bounds_policy: OutOfBoundsPolicy,
         rng: &mut R,
     ) -> Vec<V>
     where
         R: Rng + Sized,
     {
         let genome_length = genome.len();
-        let num_mutations =
-            ((genome_length as f64 * mutation_rate) + rng.gen::<f64>()).floor() as usize;
+        let num_mutations = random_count_of_ratio(genome_length, mutation_rate, rng);
         let mut mutated = genome;
         for _ in 0..num_mutations {
             let index = random_index(rng, genome_length);
@@ -331,31 +631,169 @@ where
             } else {
                 1.
             };
-            let value_mut = BreederValueMutation::breeder_mutated(
+            mutated[index] = BreederValueMutation::breeder_mutated_within_bounds(
                 mutated[index].clone(),
                 range,
                 adjustment,
                 sign,
+                min_value,
+                max_value,
+                out_of_bounds_policy,
+                rng,
             );
-            if value_mut < *min_value {
-                mutated[index] =
-                    RandomValueMutation::random_mutated(value_mut, min_value, max_value, rng)
-            } else if value_mut > *max_value {
-                mutated[index] = max_value.clone();
+        }
+        mutated
+    }
+}
+
+impl<V, const N: usize> BreederGenomeMutation for [V; N]
+where
+    V: Clone
+        + Debug
+        + PartialEq
+        + PartialOrd
+        + Send
+        + Sync
+        + BreederValueMutation
+        + RandomValueMutation,
+{
+    type Dna = V;
+
+    fn mutate_genome<R>(
+        genome: [V; N],
+        mutation_rate: f64,
+        range: &V,
+        precision: u8,
+        min_value: &V,
+        max_value: &V,
+        out_of_bounds_policy: OutOfBoundsPolicy,
+        rng: &mut R,
+    ) -> [V; N]
+    where
+        R: Rng + Sized,
+    {
+        let num_mutations = random_count_of_ratio(N, mutation_rate, rng);
+        let mut mutated = genome;
+        for _ in 0..num_mutations {
+            let index = random_index(rng, N);
+            let sign = *[-1, 1].choose(rng).unwrap();
+            let adjustment = if *[true, false].choose(rng).unwrap() {
+                1. / (1i64 << precision) as f64
             } else {
-                mutated[index] = value_mut;
-            }
+                1.
+            };
+            mutated[index] = BreederValueMutation::breeder_mutated_within_bounds(
+                mutated[index].clone(),
+                range,
+                adjustment,
+                sign,
+                min_value,
+                max_value,
+                out_of_bounds_policy,
+                rng,
+            );
         }
         mutated
     }
 }
 
+/// The `OutOfBoundsPolicy` defines how a mutated value that falls outside
+/// of the bounds `min_value..=max_value` of a mutation operator is brought
+/// back into bounds. The policy is applied the same way to values below the
+/// minimum and above the maximum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutOfBoundsPolicy {
+    /// The value is set to the nearest bound.
+    Clamp,
+    /// The value is mirrored at the bound it exceeded, e.g. a value 3 above
+    /// the maximum becomes the maximum minus 3.
+    Reflect,
+    /// The value wraps around to the other end of the range, e.g. a value
+    /// 3 above the maximum of an integer range becomes the minimum plus 2.
+    Wrap,
+    /// The value is replaced by a random value within the bounds.
+    Rerandomize,
+}
+
 pub trait BreederValueMutation {
     fn breeder_mutated(value: Self, range: &Self, adjustment: f64, sign: i8) -> Self;
+
+    /// Mutates the value like `breeder_mutated` and brings the result back
+    /// into the bounds `min_value..=max_value` according to the given
+    /// `OutOfBoundsPolicy`.
+    ///
+    /// The default implementation only knows the ordering of the values.
+    /// Therefore `OutOfBoundsPolicy::Reflect` and `OutOfBoundsPolicy::Wrap`
+    /// fall back to clamping. The implementations for the primitive number
+    /// types support all policies.
+    #[allow(clippy::too_many_arguments)]
+    fn breeder_mutated_within_bounds<R>(
+        value: Self,
+        range: &Self,
+        adjustment: f64,
+        sign: i8,
+        min_value: &Self,
+        max_value: &Self,
+        policy: OutOfBoundsPolicy,
+        rng: &mut R,
+    ) -> Self
+    where
+        Self: Clone + PartialOrd + RandomValueMutation + Sized,
+        R: Rng + Sized,
+    {
+        let mutated = Self::breeder_mutated(value, range, adjustment, sign);
+        let is_below = mutated < *min_value;
+        if !is_below && mutated <= *max_value {
+            return mutated;
+        }
+        match policy {
+            OutOfBoundsPolicy::Rerandomize => {
+                RandomValueMutation::random_mutated(mutated, min_value, max_value, rng)
+            },
+            _ if is_below => min_value.clone(),
+            _ => max_value.clone(),
+        }
+    }
+}
+
+/// Brings the given value into the bounds `min..=max`. For discrete types
+/// the `granularity` is 1, so that wrapping around steps from the maximum
+/// to the minimum value. For continuous types the `granularity` is 0.
+fn apply_bounds<R>(
+    value: f64,
+    min: f64,
+    max: f64,
+    granularity: f64,
+    policy: OutOfBoundsPolicy,
+    rng: &mut R,
+) -> f64
+where
+    R: Rng + Sized,
+{
+    if (value >= min && value <= max) || max <= min {
+        return value.max(min).min(max);
+    }
+    match policy {
+        OutOfBoundsPolicy::Clamp => value.max(min).min(max),
+        OutOfBoundsPolicy::Reflect => {
+            let width = max - min;
+            let offset = (value - min).rem_euclid(2. * width);
+            if offset > width {
+                min + 2. * width - offset
+            } else {
+                min + offset
+            }
+        },
+        OutOfBoundsPolicy::Wrap => {
+            let width = max - min + granularity;
+            (min + (value - min).rem_euclid(width)).min(max)
+        },
+        OutOfBoundsPolicy::Rerandomize => rng.gen_range(min..=max),
+    }
 }
 
 macro_rules! impl_breeder_mutation {
-    ($($t:ty),*) => {
+    ($granularity:expr; $($t:ty),*) => {
         $(
             #[allow(trivial_numeric_casts)]
             impl BreederValueMutation for $t {
@@ -363,9 +801,346 @@ macro_rules! impl_breeder_mutation {
                 fn breeder_mutated(value: $t, range: &$t, adjustment: f64, sign: i8) -> $t {
                     (value as f64 + *range as f64 * adjustment * sign as f64) as $t
                 }
+
+                fn breeder_mutated_within_bounds<R>(
+                    value: $t,
+                    range: &$t,
+                    adjustment: f64,
+                    sign: i8,
+                    min_value: &$t,
+                    max_value: &$t,
+                    policy: OutOfBoundsPolicy,
+                    rng: &mut R,
+                ) -> $t
+                where
+                    R: Rng + Sized,
+                {
+                    // calculate in f64 so that values beyond the range of the
+                    // type are not saturated before the bounds are applied
+                    let mut mutated = value as f64 + *range as f64 * adjustment * sign as f64;
+                    if $granularity > 0. {
+                        // truncate towards zero like `breeder_mutated` does
+                        mutated = mutated.trunc();
+                        if policy == OutOfBoundsPolicy::Rerandomize
+                            && (mutated < *min_value as f64 || mutated > *max_value as f64)
+                        {
+                            return rng.gen_range(*min_value..=*max_value);
+                        }
+                    }
+                    let bounded = apply_bounds(
+                        mutated,
+                        *min_value as f64,
+                        *max_value as f64,
+                        $granularity,
+                        policy,
+                        rng,
+                    ) as $t;
+                    // guard against rounding errors of the f64 arithmetic
+                    if bounded < *min_value {
+                        *min_value
+                    } else if bounded > *max_value {
+                        *max_value
+                    } else {
+                        bounded
+                    }
+                }
+            }
+        )*
+    }
+}
+
+impl_breeder_mutation!(1.; u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_breeder_mutation!(0.; f32, f64);
+
+/// A `bool` value is flipped if the range is `true` and kept otherwise.
+impl BreederValueMutation for bool {
+    #[inline]
+    fn breeder_mutated(value: bool, range: &bool, _adjustment: f64, _sign: i8) -> bool {
+        value ^ *range
+    }
+}
+
+/// A `char` value is shifted by the code point of the range, e.g. the range
+/// `'\u{3}'` shifts a character by up to 3 code points. Code points that are
+/// no valid characters are skipped.
+impl BreederValueMutation for char {
+    #[inline]
+    fn breeder_mutated(value: char, range: &char, adjustment: f64, sign: i8) -> char {
+        char_at(
+            f64::from(u32::from(value))
+                + f64::from(u32::from(*range)) * adjustment * f64::from(sign),
+        )
+    }
+
+    fn breeder_mutated_within_bounds<R>(
+        value: char,
+        range: &char,
+        adjustment: f64,
+        sign: i8,
+        min_value: &char,
+        max_value: &char,
+        policy: OutOfBoundsPolicy,
+        rng: &mut R,
+    ) -> char
+    where
+        R: Rng + Sized,
+    {
+        let min = f64::from(u32::from(*min_value));
+        let max = f64::from(u32::from(*max_value));
+        let mutated = (f64::from(u32::from(value))
+            + f64::from(u32::from(*range)) * adjustment * f64::from(sign))
+        .trunc();
+        if policy == OutOfBoundsPolicy::Rerandomize && (mutated < min || mutated > max) {
+            return rng.gen_range(*min_value..=*max_value);
+        }
+        char_at(apply_bounds(mutated, min, max, 1., policy, rng)).clamp(*min_value, *max_value)
+    }
+}
+
+/// Returns the character at the given code point. Code points of the
+/// surrogate range are moved to the next valid character.
+fn char_at(code_point: f64) -> char {
+    let code_point = code_point
+        .trunc()
+        .clamp(0., f64::from(u32::from(char::MAX))) as u32;
+    char::from_u32(code_point).unwrap_or('\u{e000}')
+}
+
+/// The `GaussianMutator` mutates value-encoded genomes by adding a normally
+/// distributed delta to the genes. The delta has mean 0 and the standard
+/// deviation `sigma`. Each gene is mutated with the probability of the
+/// mutation rate. Mutated values are clamped to the bounds
+/// `min_value..=max_value`.
+///
+/// By default all genes are mutated with the same `sigma`. The
+/// `with_sigmas` function sets an individual sigma per gene, e.g. for
+/// genomes where the genes have different scales. Genes beyond the length
+/// of the given sigmas are mutated with the default `sigma`.
+#[derive(Clone, Debug)]
+pub struct GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+{
+    mutation_rate: f64,
+    sigma: f64,
+    sigmas: Option<Vec<f64>>,
+    min_value: <G as Genotype>::Dna,
+    max_value: <G as Genotype>::Dna,
+}
+
+impl<G> PartialEq for GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+    <G as Genotype>::Dna: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.mutation_rate == other.mutation_rate
+            && self.sigma == other.sigma
+            && self.sigmas == other.sigmas
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+    }
+}
+
+impl<G> GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+{
+    pub fn new(
+        mutation_rate: f64,
+        sigma: f64,
+        min_value: <G as Genotype>::Dna,
+        max_value: <G as Genotype>::Dna,
+    ) -> Self {
+        GaussianMutator {
+            mutation_rate,
+            sigma,
+            sigmas: None,
+            min_value,
+            max_value,
+        }
+    }
+
+    /// Sets an individual sigma for each gene. The sigma at index `i` is
+    /// used for the gene at index `i`.
+    pub fn with_sigmas(mut self, sigmas: Vec<f64>) -> Self {
+        self.sigmas = Some(sigmas);
+        self
+    }
+
+    pub fn mutation_rate(&self) -> f64 {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, value: f64) {
+        self.mutation_rate = value;
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    pub fn set_sigma(&mut self, value: f64) {
+        self.sigma = value;
+    }
+
+    pub fn sigmas(&self) -> Option<&[f64]> {
+        self.sigmas.as_deref()
+    }
+
+    pub fn set_sigmas(&mut self, sigmas: Option<Vec<f64>>) {
+        self.sigmas = sigmas;
+    }
+}
+
+impl<G> GeneticOperator for GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+{
+    fn name() -> String {
+        "Gaussian-Mutator".to_string()
+    }
+}
+
+impl<G> TunableOperator for GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["mutation_rate", "sigma"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "mutation_rate" => Some(self.mutation_rate),
+            "sigma" => Some(self.sigma),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "mutation_rate" => self.mutation_rate = float_parameter(name, value)?,
+            "sigma" => self.sigma = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(MUTATION_RATE)
+            .with(ParamRange::float("sigma", 0., f64::INFINITY))
+    }
+}
+
+impl<G> MutationOp<G> for GaussianMutator<G>
+where
+    G: Genotype + GaussianGenomeMutation,
+{
+    fn mutate<R>(&self, genome: G, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        GaussianGenomeMutation::mutate_genome(
+            genome,
+            self.mutation_rate,
+            self.sigma,
+            self.sigmas.as_deref().unwrap_or(&[]),
+            &self.min_value,
+            &self.max_value,
+            rng,
+        )
+    }
+}
+
+pub trait GaussianGenomeMutation: Genotype {
+    type Dna: Clone;
+
+    fn mutate_genome<R>(
+        genome: Self,
+        mutation_rate: f64,
+        sigma: f64,
+        sigmas: &[f64],
+        min_value: &<Self as Genotype>::Dna,
+        max_value: &<Self as Genotype>::Dna,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized;
+}
+
+impl<V> GaussianGenomeMutation for Vec<V>
+where
+    V: Clone + Debug + PartialEq + Send + Sync + GaussianValueMutation,
+{
+    type Dna = V;
+
+    fn mutate_genome<R>(
+        genome: Self,
+        mutation_rate: f64,
+        sigma: f64,
+        sigmas: &[f64],
+        min_value: &V,
+        max_value: &V,
+        rng: &mut R,
+    ) -> Self
+    where
+        R: Rng + Sized,
+    {
+        let mut mutated = genome;
+        for (index, value) in mutated.iter_mut().enumerate() {
+            if random_probability(rng) < mutation_rate {
+                let sigma = sigmas.get(index).copied().unwrap_or(sigma);
+                let delta = random_standard_normal(rng) * sigma;
+                *value = GaussianValueMutation::gaussian_mutated(
+                    value.clone(),
+                    delta,
+                    min_value,
+                    max_value,
+                );
+            }
+        }
+        mutated
+    }
+}
+
+pub trait GaussianValueMutation {
+    /// Adds the `delta` to the value and clamps the result to the bounds
+    /// `min_value..=max_value`.
+    fn gaussian_mutated(value: Self, delta: f64, min_value: &Self, max_value: &Self) -> Self;
+}
+
+macro_rules! impl_gaussian_value_mutation {
+    ($($t:ty),*) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl GaussianValueMutation for $t {
+                #[inline]
+                fn gaussian_mutated(value: $t, delta: f64, min_value: &$t, max_value: &$t) -> $t {
+                    let mutated = (value as f64 + delta).round();
+                    mutated.max(*min_value as f64).min(*max_value as f64) as $t
+                }
+            }
+        )*
+    }
+}
+
+impl_gaussian_value_mutation!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_gaussian_float_mutation {
+    ($($t:ty),*) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl GaussianValueMutation for $t {
+                #[inline]
+                fn gaussian_mutated(value: $t, delta: f64, min_value: &$t, max_value: &$t) -> $t {
+                    ((value as f64 + delta) as $t).max(*min_value).min(*max_value)
+                }
             }
         )*
     }
 }
 
-impl_breeder_mutation!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+impl_gaussian_float_mutation!(f32, f64);
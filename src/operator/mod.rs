@@ -9,11 +9,19 @@
 
 pub mod prelude;
 
+#[cfg(feature = "region")]
+pub mod region;
+
 use crate::{
     algorithm::EvaluatedPopulation,
-    genetic::{Children, Fitness, Genotype, Offspring, Parents},
+    genetic::{
+        Children, Fitness, FitnessFunction, Genotype, Offspring, Pair, Parents, TracedChild,
+        TracedChildren,
+    },
     random::Rng,
+    statistic::PopulationDiversity,
 };
+use std::fmt::{self, Display};
 
 /// Marker trait for genetic operators and functions that are used for
 /// single-objective optimization.
@@ -40,6 +48,406 @@ pub trait GeneticOperator: Clone {
     /// this operator (first part) with some name for the kind of operator
     /// (second part), e.g. "Flip-Bit-Mutation" or "Roulette-Wheel-Selection".
     fn name() -> String;
+
+    /// Returns this operator as `GenerationAware` operator, if it wants to
+    /// receive feedback about each generation from the algorithm.
+    ///
+    /// The default implementation returns `None`. Operators that wrap other
+    /// operators should return the wrapped operator.
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        None
+    }
+}
+
+/// A `GenerationAware` operator receives feedback about each generation from
+/// the `ga::GeneticAlgorithm`, e.g. to adapt its parameters when the
+/// population converges prematurely.
+///
+/// The algorithm calls `generation_evaluated` on each operator whose
+/// `GeneticOperator::as_generation_aware` returns `Some`, after the
+/// population of a generation has been evaluated and before the operators
+/// make up the next population.
+pub trait GenerationAware {
+    /// Receives the feedback about the generation that has just been
+    /// evaluated.
+    fn generation_evaluated(&mut self, feedback: &GenerationFeedback);
+}
+
+/// The `GenerationFeedback` describes the progress of the algorithm as of
+/// the generation that has just been evaluated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GenerationFeedback {
+    /// The number of the generation.
+    pub generation: u64,
+    /// The number of individuals in the population.
+    pub population_size: usize,
+    /// Whether the highest fitness of this generation is higher than the
+    /// highest fitness of all previous generations.
+    pub improved: bool,
+    /// The number of generations since the highest fitness has improved the
+    /// last time.
+    pub stagnant_generations: u64,
+    /// The fraction of distinct fitness values in the population, which
+    /// ranges from `1 / population_size`, when all individuals have the same
+    /// fitness, to 1, when all fitness values are different. A low value is
+    /// a sign of a converged population.
+    pub fitness_diversity: f64,
+    /// The fraction of the children bred in the previous generation that
+    /// are fitter than the best of their parents, if the algorithm tracks
+    /// the success of the offspring.
+    pub offspring_success: Option<f64>,
+    /// The genotypic and phenotypic diversity of the population, if the
+    /// algorithm tracks the diversity.
+    pub diversity: Option<PopulationDiversity>,
+}
+
+/// A `TunableOperator` is a genetic operator whose parameters can be read and
+/// changed by their names. It provides a uniform way to access the `set_*`
+/// methods of the operators, e.g. for controllers that adjust the parameters
+/// of the operators while a simulation is running (online control).
+///
+/// All parameter values are represented as `f64`. Parameters of integer
+/// types are rounded to the nearest integer when they are set.
+pub trait TunableOperator {
+    /// Returns the names of all parameters of this operator that can be
+    /// tuned.
+    fn parameter_names(&self) -> Vec<&'static str>;
+
+    /// Returns the current value of the parameter with the given name or
+    /// `None` if this operator has no parameter of that name.
+    fn parameter(&self, name: &str) -> Option<f64>;
+
+    /// Sets the parameter with the given name to the given value.
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError>;
+
+    /// Returns the `ParamSpace` of this operator, i.e. the type and the
+    /// range of valid values of each parameter. Auto-tuners use it to
+    /// explore valid configurations only.
+    ///
+    /// The default implementation describes each parameter returned by
+    /// `parameter_names` as unbounded float parameter. The operators of
+    /// this crate describe their actual ranges and reject values outside
+    /// these ranges in `set_parameter`.
+    fn parameter_space(&self) -> ParamSpace {
+        self.parameter_names()
+            .into_iter()
+            .fold(ParamSpace::new(), |space, name| {
+                space.with(ParamRange::float(name, f64::NEG_INFINITY, f64::INFINITY))
+            })
+    }
+}
+
+/// The type of the values of a parameter of a `TunableOperator`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParamKind {
+    /// The parameter takes any float value within its range.
+    Float,
+    /// The parameter takes integer values within its range.
+    Integer,
+}
+
+/// The `ParamRange` describes the type and the range of valid values of one
+/// parameter of a `TunableOperator`. The range includes its bounds, which
+/// may be infinite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParamRange {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The type of the values of the parameter.
+    pub kind: ParamKind,
+    /// The smallest valid value.
+    pub min: f64,
+    /// The largest valid value.
+    pub max: f64,
+}
+
+impl ParamRange {
+    /// Constructs the range of a float parameter.
+    pub const fn float(name: &'static str, min: f64, max: f64) -> Self {
+        ParamRange {
+            name,
+            kind: ParamKind::Float,
+            min,
+            max,
+        }
+    }
+
+    /// Constructs the range of an integer parameter.
+    pub const fn integer(name: &'static str, min: f64, max: f64) -> Self {
+        ParamRange {
+            name,
+            kind: ParamKind::Integer,
+            min,
+            max,
+        }
+    }
+
+    /// Returns whether the given value is valid for this parameter. Values
+    /// of integer parameters are rounded to the nearest integer first.
+    pub fn contains(&self, value: f64) -> bool {
+        self.validate(value).is_ok()
+    }
+
+    /// Returns the given value, rounded to the nearest integer for integer
+    /// parameters, or an error if it is not a finite number within this
+    /// range.
+    pub fn validate(&self, value: f64) -> Result<f64, OperatorParameterError> {
+        let value = match self.kind {
+            ParamKind::Float => value,
+            ParamKind::Integer => value.round(),
+        };
+        if !value.is_finite() {
+            return Err(OperatorParameterError::InvalidValue(format!(
+                "value {} for parameter {} is not a finite number",
+                value, self.name
+            )));
+        }
+        if value < self.min || value > self.max {
+            return Err(OperatorParameterError::InvalidValue(format!(
+                "value {} for parameter {} is not within [{}, {}]",
+                value, self.name, self.min, self.max
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// The `ParamSpace` describes the parameters of a `TunableOperator`, as
+/// returned by `TunableOperator::parameter_space`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParamSpace {
+    ranges: Vec<ParamRange>,
+}
+
+impl ParamSpace {
+    /// Constructs an empty `ParamSpace`.
+    pub fn new() -> Self {
+        ParamSpace { ranges: Vec::new() }
+    }
+
+    /// Adds the given parameter to this `ParamSpace`. A parameter of the
+    /// same name is replaced.
+    pub fn with(mut self, range: ParamRange) -> Self {
+        self.ranges.retain(|existing| existing.name != range.name);
+        self.ranges.push(range);
+        self
+    }
+
+    /// Adds all parameters of the given `ParamSpace` to this one, e.g. the
+    /// parameters of a wrapped operator.
+    pub fn merge(self, other: ParamSpace) -> Self {
+        other.ranges.into_iter().fold(self, ParamSpace::with)
+    }
+
+    /// Returns the ranges of all parameters.
+    pub fn ranges(&self) -> &[ParamRange] {
+        &self.ranges
+    }
+
+    /// Returns the range of the parameter with the given name.
+    pub fn range(&self, name: &str) -> Option<&ParamRange> {
+        self.ranges.iter().find(|range| range.name == name)
+    }
+
+    /// Validates the given value of the parameter with the given name, as
+    /// `ParamRange::validate` does. Returns an error if there is no
+    /// parameter of that name.
+    pub fn validate(&self, name: &str, value: f64) -> Result<f64, OperatorParameterError> {
+        self.range(name)
+            .ok_or_else(|| unknown_parameter(name))?
+            .validate(value)
+    }
+}
+
+/// An error that can occur when a parameter of a `TunableOperator` is set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperatorParameterError {
+    /// The operator has no parameter of the given name.
+    UnknownParameter(String),
+    /// The value is not valid for the parameter.
+    InvalidValue(String),
+}
+
+impl Display for OperatorParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OperatorParameterError::UnknownParameter(name) => {
+                write!(f, "unknown operator parameter: {}", name)
+            },
+            OperatorParameterError::InvalidValue(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for OperatorParameterError {}
+
+/// An error that a `CrossoverOp` or a `MutationOp` returns from its fallible
+/// functions, e.g. `CrossoverOp::try_crossover`, if it can not be applied.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperatorError {
+    /// The genotypes violate a constraint of the problem domain.
+    ConstraintViolated(String),
+    /// The operator failed for another reason.
+    Failed(String),
+}
+
+impl Display for OperatorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OperatorError::ConstraintViolated(details) => {
+                write!(f, "constraint violated: {}", details)
+            },
+            OperatorError::Failed(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for OperatorError {}
+
+/// Checks that the given value is a valid value for a parameter of type
+/// `f64`.
+pub(crate) fn float_parameter(name: &str, value: f64) -> Result<f64, OperatorParameterError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(OperatorParameterError::InvalidValue(format!(
+            "value {} for parameter {} is not a finite number",
+            value, name
+        )))
+    }
+}
+
+/// Converts the given value into a valid value for a parameter of type
+/// `usize`.
+pub(crate) fn usize_parameter(name: &str, value: f64) -> Result<usize, OperatorParameterError> {
+    if value.is_finite() && value >= 0. {
+        Ok(value.round() as usize)
+    } else {
+        Err(OperatorParameterError::InvalidValue(format!(
+            "value {} for parameter {} is not a positive integer",
+            value, name
+        )))
+    }
+}
+
+/// Returns the error for a parameter name that is not known by an operator.
+pub(crate) fn unknown_parameter(name: &str) -> OperatorParameterError {
+    OperatorParameterError::UnknownParameter(name.to_string())
+}
+
+/// The range of the ratio of the population selected as parents.
+pub(crate) const SELECTION_RATIO: ParamRange =
+    ParamRange::float("selection_ratio", 0., f64::INFINITY);
+
+/// The range of the number of individuals per group of parents.
+pub(crate) const NUM_INDIVIDUALS_PER_PARENTS: ParamRange =
+    ParamRange::integer("num_individuals_per_parents", 2., f64::INFINITY);
+
+/// The range of the probability that a gene is mutated.
+pub(crate) const MUTATION_RATE: ParamRange = ParamRange::float("mutation_rate", 0., 1.);
+
+/// The range of the ratio of the population replaced by offspring.
+pub(crate) const REPLACE_RATIO: ParamRange = ParamRange::float("replace_ratio", 0., 1.);
+
+/// The number of parts into which ratios are divided when they are applied
+/// to counts.
+const RATIO_PARTS: u64 = 1_000_000;
+
+/// Converts the given ratio into an integer number of parts per
+/// `RATIO_PARTS`. Negative ratios and NaN are converted to 0, the conversion
+/// saturates for huge ratios.
+fn ratio_parts(ratio: f64) -> u64 {
+    if ratio.is_nan() || ratio <= 0. {
+        0
+    } else {
+        (ratio * RATIO_PARTS as f64).round() as u64
+    }
+}
+
+/// Returns the given ratio of the given count rounded half up to the nearest
+/// integer.
+///
+/// The ratio is converted into an integer number of parts per million and
+/// the count is calculated in integer arithmetic, so that the same ratio
+/// results in the same count on all platforms and with all compilers.
+pub fn count_of_ratio(count: usize, ratio: f64) -> usize {
+    let scaled = count as u128 * u128::from(ratio_parts(ratio));
+    let rounded = (scaled + u128::from(RATIO_PARTS / 2)) / u128::from(RATIO_PARTS);
+    usize::try_from(rounded).unwrap_or(usize::MAX)
+}
+
+/// Returns the given ratio of the given count rounded randomly to one of the
+/// two nearest integers. The count is rounded up with a probability equal to
+/// the fractional part, so that on average the result equals the exact
+/// ratio of the count.
+///
+/// Like `count_of_ratio` the count is calculated in integer arithmetic.
+pub fn random_count_of_ratio<R>(count: usize, ratio: f64, rng: &mut R) -> usize
+where
+    R: Rng + Sized,
+{
+    let scaled = count as u128 * u128::from(ratio_parts(ratio));
+    let whole = scaled / u128::from(RATIO_PARTS);
+    let fraction = (scaled % u128::from(RATIO_PARTS)) as u64;
+    let rounded = if rng.gen_range(0..RATIO_PARTS) < fraction {
+        whole + 1
+    } else {
+        whole
+    };
+    usize::try_from(rounded).unwrap_or(usize::MAX)
+}
+
+/// The `ParentDemand` is the number of offspring that a `ReinsertionOp` needs
+/// to make up the population of the next generation.
+///
+/// The `ga::GeneticAlgorithm` asks the `ReinsertionOp` for the number of
+/// offspring it requires and passes the demand for them to
+/// `SelectionOp::select_for`, so that the selection produces
+/// exactly as many groups of parents as are needed to breed the required
+/// offspring. This way selection and reinsertion agree by construction
+/// instead of by the coincidence of their ratios.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParentDemand {
+    num_offspring: usize,
+}
+
+impl ParentDemand {
+    /// Constructs a new `ParentDemand` for the given number of offspring.
+    pub fn new(num_offspring: usize) -> Self {
+        ParentDemand { num_offspring }
+    }
+
+    /// Constructs the `ParentDemand` for the given fraction of a population
+    /// of the given size.
+    ///
+    /// The number of offspring is rounded to the nearest integer, but is at
+    /// least 1 for a positive ratio and not bigger than the population size.
+    pub fn from_ratio(population_size: usize, ratio: f64) -> Self {
+        let num_offspring = if population_size == 0 || ratio.is_nan() || ratio <= 0. {
+            0
+        } else {
+            count_of_ratio(population_size, ratio).clamp(1, population_size)
+        };
+        ParentDemand { num_offspring }
+    }
+
+    /// Returns the number of offspring.
+    pub fn num_offspring(&self) -> usize {
+        self.num_offspring
+    }
+
+    /// Returns the number of groups of parents needed to breed the offspring
+    /// if each group consists of the given number of individuals.
+    ///
+    /// It is assumed that the `CrossoverOp` breeds as many children as it
+    /// gets parents, as all crossover operators of this crate do.
+    pub fn num_parent_groups(&self, num_individuals_per_parents: usize) -> usize {
+        if num_individuals_per_parents == 0 {
+            0
+        } else {
+            self.num_offspring.div_ceil(num_individuals_per_parents)
+        }
+    }
 }
 
 /// A `SelectionOp` defines the function of how to select solutions for being
@@ -58,6 +466,36 @@ where
     ) -> Vec<Parents<G>>
     where
         R: Rng + Sized;
+
+    /// Selects exactly as many groups of parents from the given population
+    /// as are needed to breed the offspring of the given `ParentDemand`.
+    ///
+    /// The default implementation calls `select_from` and drops surplus
+    /// groups of parents or repeats the selected groups until the demand is
+    /// met. Operators should override this method to select the demanded
+    /// number of parents directly.
+    fn select_for<R>(
+        &self,
+        population: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let mut selected = self.select_from(population, rng);
+        let num_parent_groups = match selected.first() {
+            Some(parents) => demand.num_parent_groups(parents.len()),
+            None => return selected,
+        };
+        selected.truncate(num_parent_groups);
+        let mut index = 0;
+        while selected.len() < num_parent_groups {
+            selected.push(selected[index].clone());
+            index += 1;
+        }
+        selected
+    }
 }
 
 /// A `CrossoverOp` defines a function of how to crossover two
@@ -74,6 +512,79 @@ where
     fn crossover<R>(&self, parents: Parents<G>, rng: &mut R) -> Children<G>
     where
         R: Rng + Sized;
+
+    /// Performs the crossover of a `genetic::Pair` of parents. This is called
+    /// instead of `crossover` whenever exactly two parents have been selected.
+    ///
+    /// The default implementation converts the pair into `genetic::Parents`
+    /// and calls `crossover`. Operators that can breed two parents faster
+    /// than any number of parents should override this method.
+    fn crossover_pair<R>(&self, parents: Pair<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        self.crossover(parents.into(), rng)
+    }
+
+    /// Performs the crossover of the `genetic::Parents` and returns the
+    /// children together with the `genetic::Segment`s they are assembled
+    /// from. This is called instead of `crossover` if the mutation operator
+    /// makes use of the segments, see `MutationOp::uses_segments`.
+    ///
+    /// The default implementation calls `crossover` and returns children
+    /// without segments. Operators that copy segments of the parents should
+    /// override this method.
+    fn crossover_traced<R>(&self, parents: Parents<G>, rng: &mut R) -> TracedChildren<G>
+    where
+        R: Rng + Sized,
+    {
+        self.crossover(parents, rng)
+            .into_iter()
+            .map(TracedChild::untraced)
+            .collect()
+    }
+
+    /// Performs the crossover of the `genetic::Parents` like `crossover`,
+    /// but returns an `OperatorError` instead of panicking if the crossover
+    /// is not possible. The `ga::GeneticAlgorithm` calls this function and
+    /// stops with a `GeneticAlgorithmError::OperatorFailed` on error.
+    ///
+    /// Operators that validate constraints of the problem domain override
+    /// this function. Their `crossover` function is still called by
+    /// algorithms that can not handle errors and should panic on failure.
+    ///
+    /// The default implementation calls `crossover_pair` for exactly two
+    /// parents and `crossover` otherwise, and never fails.
+    fn try_crossover<R>(
+        &self,
+        parents: Parents<G>,
+        rng: &mut R,
+    ) -> Result<Children<G>, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        Ok(match Pair::try_from(parents) {
+            Ok(pair) => self.crossover_pair(pair, rng),
+            Err(parents) => self.crossover(parents, rng),
+        })
+    }
+
+    /// Performs the crossover of the `genetic::Parents` like
+    /// `crossover_traced`, but returns an `OperatorError` if the crossover is
+    /// not possible. This is called instead of `try_crossover` if the mutation
+    /// operator makes use of the segments.
+    ///
+    /// The default implementation calls `crossover_traced` and never fails.
+    fn try_crossover_traced<R>(
+        &self,
+        parents: Parents<G>,
+        rng: &mut R,
+    ) -> Result<TracedChildren<G>, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        Ok(self.crossover_traced(parents, rng))
+    }
 }
 
 /// A `MutationOp` defines a function of how a `genetic::Genotype` mutates. It
@@ -93,6 +604,98 @@ where
     fn mutate<R>(&self, genome: G, rng: &mut R) -> G
     where
         R: Rng + Sized;
+
+    /// Returns true if this operator makes use of the `genetic::Segment`s of
+    /// a `genetic::TracedChild`. Only then the children are bred by
+    /// `CrossoverOp::crossover_traced` and mutated by `mutate_traced`.
+    ///
+    /// The default implementation returns false.
+    fn uses_segments(&self) -> bool {
+        false
+    }
+
+    /// Mutates the given `genetic::TracedChild` and returns the mutated
+    /// 'Genotype'. The segments of the child tell where the crossover
+    /// happened, e.g. to focus the mutation on the crossover boundaries.
+    ///
+    /// The default implementation ignores the segments and calls `mutate`.
+    fn mutate_traced<R>(&self, child: TracedChild<G>, rng: &mut R) -> G
+    where
+        R: Rng + Sized,
+    {
+        self.mutate(child.genome, rng)
+    }
+
+    /// Mutates the given `Genotype` like `mutate`, but returns an
+    /// `OperatorError` instead of panicking if the mutation is not possible.
+    /// The `ga::GeneticAlgorithm` calls this function and stops with a
+    /// `GeneticAlgorithmError::OperatorFailed` on error.
+    ///
+    /// Operators that validate constraints of the problem domain override
+    /// this function. Their `mutate` function is still called by algorithms
+    /// that can not handle errors and should panic on failure.
+    ///
+    /// The default implementation calls `mutate` and never fails.
+    fn try_mutate<R>(&self, genome: G, rng: &mut R) -> Result<G, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        Ok(self.mutate(genome, rng))
+    }
+
+    /// Mutates the given `genetic::TracedChild` like `mutate_traced`, but
+    /// returns an `OperatorError` if the mutation is not possible. This is
+    /// called instead of `try_mutate` if this operator makes use of the
+    /// segments.
+    ///
+    /// The default implementation calls `mutate_traced` and never fails.
+    fn try_mutate_traced<R>(&self, child: TracedChild<G>, rng: &mut R) -> Result<G, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        Ok(self.mutate_traced(child, rng))
+    }
+}
+
+/// A `FixerOp` repairs `genetic::Genotype`s that violate the constraints of
+/// the problem, e.g. the weight limit of a knapsack or the validity of a
+/// permutation.
+///
+/// The `ga::GeneticAlgorithm` applies the fixer to each child of the
+/// offspring after the mutation and before the reinsertion, see
+/// `ga::builder::GeneticAlgorithmBuilder::with_fixer`. This way the crossover
+/// and mutation operators do not need to know about the constraints, and
+/// only repaired offspring make it into the next generation.
+pub trait FixerOp<G>: GeneticOperator
+where
+    G: Genotype,
+{
+    /// Repairs the given `genetic::Genotype` in place. A valid genome should
+    /// be left unchanged.
+    fn fix<R>(&self, genome: &mut G, rng: &mut R)
+    where
+        R: Rng + Sized;
+}
+
+/// A `LocalSearchOp` refines a `genetic::Genotype` by searching its
+/// neighborhood for genomes of a higher fitness, e.g. by hill climbing.
+///
+/// A genetic algorithm that refines its offspring by local search is known
+/// as a memetic algorithm. The `ga::GeneticAlgorithm` applies the local
+/// search to a fraction of the offspring after the mutation and the repair,
+/// see `ga::builder::GeneticAlgorithmBuilder::with_local_search`.
+pub trait LocalSearchOp<G, F>: GeneticOperator
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Searches the neighborhood of the given `genetic::Genotype` using the
+    /// given fitness function and returns the best genome found. If no
+    /// better genome is found the given genome is returned.
+    fn improve<E, R>(&self, genome: G, evaluator: &E, rng: &mut R) -> G
+    where
+        E: FitnessFunction<G, F>,
+        R: Rng + Sized;
 }
 
 /// A `ReinsertionOp` defines a function that combines the offspring with the
@@ -135,4 +738,30 @@ where
     ) -> Vec<G>
     where
         R: Rng + Sized;
+
+    /// Returns the number of offspring this operator needs to combine with
+    /// a population of the given size, or `None` if it makes use of any
+    /// number of offspring.
+    ///
+    /// The default implementation returns `None`.
+    fn parent_demand(&self, _population_size: usize) -> Option<ParentDemand> {
+        None
+    }
+
+    /// Returns the number of offspring the `ga::GeneticAlgorithm` breeds for
+    /// this operator when it combines the offspring with a population of the
+    /// given size.
+    ///
+    /// The default implementation returns the number of offspring of the
+    /// `parent_demand`. An operator that makes use of any number of offspring
+    /// gets as many offspring as the population has individuals, so that the
+    /// size of the offspring never depends on the selection ratio of the
+    /// `SelectionOp`.
+    fn required_offspring(&self, population_size: usize) -> usize {
+        self.parent_demand(population_size)
+            .map_or(population_size, |demand| demand.num_offspring())
+    }
 }
+
+#[cfg(test)]
+mod tests;
@@ -1,7 +1,36 @@
-pub use crate::selection::{proportionate::*, ranking::*, tournament::*, truncation::*, *};
+// The modules of the operator families are re-exported by name as well, so
+// that operators can be referred to by their module, e.g. `discrete::*`.
+// Module names that exist in several families, like `order`, are referred
+// to by their family instead, e.g. `mutation::order`.
 
-pub use crate::recombination::{discrete::*, order::*, *};
+pub use crate::{mutation, recombination, reinsertion, selection};
 
-pub use crate::mutation::{order::*, value::*, *};
+pub use crate::selection::{
+    proportionate::*, tournament, tournament::*, truncation, truncation::*,
+};
 
-pub use crate::reinsertion::{elitist::*, random::*, *};
+pub use crate::recombination::{discrete, discrete::*, order::*, probabilistic, probabilistic::*};
+
+pub use crate::mutation::{
+    adaptive, adaptive::*, boundary, boundary::*, masked, masked::*, order::*, value, value::*,
+};
+
+pub use crate::reinsertion::{
+    crowding, crowding::*, elitist, elitist::*, proportionate::*, random, random::*,
+};
+
+pub use crate::fixer::{noop::*, permutation::*};
+
+pub use crate::local_search::hill_climbing::*;
+
+#[cfg(feature = "graph")]
+pub use crate::{mutation::graph::*, recombination::graph::*};
+
+#[cfg(feature = "matrix")]
+pub use crate::{mutation::matrix::*, recombination::matrix::*};
+
+#[cfg(feature = "multi-objective")]
+pub use crate::{reinsertion::nsga2::*, selection::nsga2::*};
+
+#[cfg(feature = "region")]
+pub use crate::operator::region::*;
@@ -0,0 +1,243 @@
+//! The `region` module provides the `RegionRouter`, which applies different
+//! `operator::MutationOp`s and `operator::CrossoverOp`s to different regions
+//! of a flat `Vec` genome.
+//!
+//! This way the behavior of operators can be mixed within one genome, e.g.
+//! genes `0..10` encode switches that are mutated by flipping values while
+//! genes `10..20` encode quantities that are mutated by small steps, without
+//! inventing a custom genotype.
+
+use crate::{
+    genetic::{Children, Genotype, Parents},
+    operator::{CrossoverOp, GeneticOperator, MutationOp},
+    random::Rng,
+};
+use rand::RngCore;
+use std::{fmt, ops::Range, sync::Arc};
+
+/// Object safe form of a `MutationOp`, so that operators of different types
+/// can be routed to by one `RegionRouter`.
+trait RoutedMutationOp<G>: Send + Sync {
+    fn mutate_routed(&self, genome: G, rng: &mut dyn RngCore) -> G;
+
+    fn operator_name(&self) -> String;
+}
+
+impl<G, M> RoutedMutationOp<G> for M
+where
+    G: Genotype,
+    M: MutationOp<G> + Send + Sync,
+{
+    fn mutate_routed(&self, genome: G, mut rng: &mut dyn RngCore) -> G {
+        self.mutate(genome, &mut rng)
+    }
+
+    fn operator_name(&self) -> String {
+        M::name()
+    }
+}
+
+/// Object safe form of a `CrossoverOp`, so that operators of different
+/// types can be routed to by one `RegionRouter`.
+trait RoutedCrossoverOp<G>: Send + Sync {
+    fn crossover_routed(&self, parents: Parents<G>, rng: &mut dyn RngCore) -> Children<G>;
+
+    fn operator_name(&self) -> String;
+}
+
+impl<G, C> RoutedCrossoverOp<G> for C
+where
+    G: Genotype,
+    C: CrossoverOp<G> + Send + Sync,
+{
+    fn crossover_routed(&self, parents: Parents<G>, mut rng: &mut dyn RngCore) -> Children<G> {
+        self.crossover(parents, &mut rng)
+    }
+
+    fn operator_name(&self) -> String {
+        C::name()
+    }
+}
+
+/// A region of a genome together with the operator that is applied to it.
+type Routed<R> = (Range<usize>, Arc<R>);
+
+/// The `RegionRouter` applies different operators to different index ranges
+/// (regions) of a `Vec` genome. It implements `operator::MutationOp` as well
+/// as `operator::CrossoverOp`, so the same router can be used for both
+/// stages of the genetic algorithm.
+///
+/// Each region is handed to its operator as a genome of its own. Genes that
+/// are not covered by any region are not mutated. On crossover the genes
+/// that are not covered by any region are copied from the parent with the
+/// same index as the child. The operators must not change the length of the
+/// regions they are applied to.
+///
+/// Regions may only overlap if the effect of applying several operators to
+/// the same genes one after another is intended.
+#[derive(Clone)]
+pub struct RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    mutation_regions: Vec<Routed<dyn RoutedMutationOp<Vec<V>>>>,
+    crossover_regions: Vec<Routed<dyn RoutedCrossoverOp<Vec<V>>>>,
+}
+
+impl<V> RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    /// Constructs a new `RegionRouter` without any regions.
+    pub fn new() -> Self {
+        RegionRouter {
+            mutation_regions: Vec::new(),
+            crossover_regions: Vec::new(),
+        }
+    }
+
+    /// Adds a region that is mutated by the given `operator::MutationOp`.
+    pub fn with_mutation<M>(mut self, region: Range<usize>, mutator: M) -> Self
+    where
+        M: MutationOp<Vec<V>> + Send + Sync + 'static,
+    {
+        self.mutation_regions.push((region, Arc::new(mutator)));
+        self
+    }
+
+    /// Adds a region that is recombined by the given `operator::CrossoverOp`.
+    pub fn with_crossover<C>(mut self, region: Range<usize>, breeder: C) -> Self
+    where
+        C: CrossoverOp<Vec<V>> + Send + Sync + 'static,
+    {
+        self.crossover_regions.push((region, Arc::new(breeder)));
+        self
+    }
+
+    /// Returns the regions that are mutated in the order they have been
+    /// added.
+    pub fn mutation_regions(&self) -> Vec<Range<usize>> {
+        self.mutation_regions
+            .iter()
+            .map(|(r, _)| r.clone())
+            .collect()
+    }
+
+    /// Returns the regions that are recombined in the order they have been
+    /// added.
+    pub fn crossover_regions(&self) -> Vec<Range<usize>> {
+        self.crossover_regions
+            .iter()
+            .map(|(r, _)| r.clone())
+            .collect()
+    }
+}
+
+impl<V> Default for RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        RegionRouter::new()
+    }
+}
+
+impl<V> fmt::Debug for RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mutations: Vec<_> = self
+            .mutation_regions
+            .iter()
+            .map(|(region, op)| (region.clone(), op.operator_name()))
+            .collect();
+        let crossovers: Vec<_> = self
+            .crossover_regions
+            .iter()
+            .map(|(region, op)| (region.clone(), op.operator_name()))
+            .collect();
+        f.debug_struct("RegionRouter")
+            .field("mutation_regions", &mutations)
+            .field("crossover_regions", &crossovers)
+            .finish()
+    }
+}
+
+impl<V> GeneticOperator for RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn name() -> String {
+        "Region-Router".to_string()
+    }
+}
+
+/// Restricts the region to the length of the genome.
+fn clip(region: &Range<usize>, length: usize) -> Range<usize> {
+    region.start.min(length)..region.end.min(length)
+}
+
+impl<V> MutationOp<Vec<V>> for RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn mutate<R>(&self, genome: Vec<V>, rng: &mut R) -> Vec<V>
+    where
+        R: Rng + Sized,
+    {
+        let mut mutated = genome;
+        for (region, mutator) in &self.mutation_regions {
+            let region = clip(region, mutated.len());
+            if region.is_empty() {
+                continue;
+            }
+            let part = mutator.mutate_routed(mutated[region.clone()].to_vec(), rng);
+            assert_eq!(
+                part.len(),
+                region.len(),
+                "{} changed the length of region {:?}",
+                mutator.operator_name(),
+                region
+            );
+            mutated.splice(region, part);
+        }
+        mutated
+    }
+}
+
+impl<V> CrossoverOp<Vec<V>> for RegionRouter<V>
+where
+    V: Clone + fmt::Debug + Send + Sync + 'static,
+{
+    fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let mut children = parents.clone();
+        for (region, breeder) in &self.crossover_regions {
+            let length = parents.iter().map(Vec::len).min().unwrap_or(0);
+            let region = clip(region, length);
+            if region.is_empty() {
+                continue;
+            }
+            let parts: Parents<Vec<V>> = children
+                .iter()
+                .map(|parent| parent[region.clone()].to_vec())
+                .collect();
+            let part_children = breeder.crossover_routed(parts, rng);
+            children.truncate(part_children.len());
+            for (child, part) in children.iter_mut().zip(part_children) {
+                assert_eq!(
+                    part.len(),
+                    region.len(),
+                    "{} changed the length of region {:?}",
+                    breeder.operator_name(),
+                    region
+                );
+                child.splice(region.clone(), part);
+            }
+        }
+        children
+    }
+}
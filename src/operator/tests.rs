@@ -0,0 +1,307 @@
+#[cfg(feature = "region")]
+mod region_router {
+
+    use crate::{
+        mutation::value::RandomValueMutator,
+        operator::{region::RegionRouter, CrossoverOp, MutationOp},
+        random::{get_rng, random_seed},
+        recombination::discrete::{SinglePointCrossBreeder, UniformCrossBreeder},
+    };
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn mutates_each_region_with_its_own_operator() {
+        let router = RegionRouter::new()
+            .with_mutation(0..3, RandomValueMutator::new(1., 10, 20))
+            .with_mutation(3..6, RandomValueMutator::new(1., 100, 200));
+
+        for _ in 0..20 {
+            let mutated = router.mutate(vec![0; 8], &mut get_rng(random_seed()));
+
+            expect_that!(&mutated.len(), eq(8));
+            for value in &mutated[0..3] {
+                expect_that!(&(*value == 0 || (10..20).contains(value)), eq(true));
+            }
+            for value in &mutated[3..6] {
+                expect_that!(&(*value == 0 || (100..200).contains(value)), eq(true));
+            }
+            expect_that!(&mutated[6..].to_vec(), eq(vec![0, 0]));
+        }
+    }
+
+    #[test]
+    fn genes_outside_of_crossover_regions_are_inherited_from_the_same_parent() {
+        let router = RegionRouter::new()
+            .with_crossover(0..4, UniformCrossBreeder::new())
+            .with_crossover(4..8, SinglePointCrossBreeder::new());
+
+        let children =
+            router.crossover(vec![vec![1; 10], vec![2; 10]], &mut get_rng(random_seed()));
+
+        expect_that!(&children.len(), eq(2));
+        expect_that!(&children[0][8..].to_vec(), eq(vec![1, 1]));
+        expect_that!(&children[1][8..].to_vec(), eq(vec![2, 2]));
+        for child in &children {
+            expect_that!(&child[..8].iter().all(|v| *v == 1 || *v == 2), eq(true));
+        }
+    }
+
+    #[test]
+    fn regions_beyond_the_genome_are_clipped() {
+        let router = RegionRouter::new().with_mutation(2..100, RandomValueMutator::new(1., 5, 6));
+
+        let mutated = router.mutate(vec![0, 0, 0], &mut get_rng(random_seed()));
+
+        expect_that!(&mutated[..2].to_vec(), eq(vec![0, 0]));
+        expect_that!(&mutated.len(), eq(3));
+    }
+}
+
+mod count_of_ratio {
+
+    use crate::{
+        operator::{count_of_ratio, random_count_of_ratio},
+        random::get_rng,
+    };
+    use galvanic_assert::matchers::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn rounds_half_up_to_the_nearest_integer() {
+        expect_that!(&count_of_ratio(10, 0.25), eq(3));
+        expect_that!(&count_of_ratio(10, 0.24), eq(2));
+        expect_that!(&count_of_ratio(7, 0.5), eq(4));
+        expect_that!(&count_of_ratio(100, 0.7), eq(70));
+    }
+
+    #[test]
+    fn is_zero_for_negative_and_nan_ratios() {
+        expect_that!(&count_of_ratio(10, -0.5), eq(0));
+        expect_that!(&count_of_ratio(10, f64::NAN), eq(0));
+        expect_that!(
+            &random_count_of_ratio(10, f64::NAN, &mut get_rng([1; 32])),
+            eq(0)
+        );
+    }
+
+    #[test]
+    fn saturates_for_huge_ratios() {
+        expect_that!(&count_of_ratio(usize::MAX, f64::MAX), eq(usize::MAX));
+    }
+
+    #[test]
+    fn random_count_is_exact_for_whole_numbers() {
+        let mut rng = get_rng([1; 32]);
+        for _ in 0..100 {
+            expect_that!(&random_count_of_ratio(20, 0.5, &mut rng), eq(10));
+        }
+    }
+
+    #[test]
+    fn random_count_equals_the_exact_ratio_on_average() {
+        let mut rng = get_rng([1; 32]);
+        let total: usize = (0..10_000)
+            .map(|_| random_count_of_ratio(10, 0.05, &mut rng))
+            .sum();
+        expect_that!(&total, all_of!(greater_than(4_700), less_than(5_300)));
+    }
+
+    proptest! {
+
+        #[test]
+        fn random_count_is_one_of_the_two_nearest_integers(
+            count in 0usize..10_000,
+            ratio in 0f64..2.,
+            seed in any::<[u8; 32]>(),
+        ) {
+            let exact = count_of_ratio(count, ratio);
+            let random = random_count_of_ratio(count, ratio, &mut get_rng(seed));
+            prop_assert!(random + 1 >= exact && random <= exact + 1);
+        }
+    }
+}
+
+mod param_space {
+
+    use crate::{
+        mutation::{
+            adaptive::AdaptiveMutationOp,
+            order::{InversionMutator, SwapOrderMutator},
+            value::{GaussianMutator, RandomValueMutator},
+        },
+        operator::{OperatorParameterError, ParamKind, ParamRange, ParamSpace, TunableOperator},
+        recombination::discrete::{MultiPointCrossBreeder, UniformCrossBreeder},
+        selection::{
+            proportionate::BoltzmannSelector,
+            tournament::TournamentSelector,
+            truncation::{MaximizeSelector, TruncationSelector},
+        },
+    };
+    use galvanic_assert::matchers::*;
+
+    #[derive(Clone, Debug)]
+    struct CustomOperator {
+        weight: f64,
+    }
+
+    impl TunableOperator for CustomOperator {
+        fn parameter_names(&self) -> Vec<&'static str> {
+            vec!["weight"]
+        }
+
+        fn parameter(&self, name: &str) -> Option<f64> {
+            match name {
+                "weight" => Some(self.weight),
+                _ => None,
+            }
+        }
+
+        fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+            match name {
+                "weight" => self.weight = value,
+                _ => return Err(OperatorParameterError::UnknownParameter(name.to_string())),
+            }
+            Ok(())
+        }
+    }
+
+    fn names(space: &ParamSpace) -> Vec<&'static str> {
+        space.ranges().iter().map(|range| range.name).collect()
+    }
+
+    #[test]
+    fn integer_values_are_rounded_before_they_are_validated() {
+        let range = ParamRange::integer("size", 1., 10.);
+
+        expect_that!(&range.validate(2.6), eq(Ok(3.)));
+        expect_that!(&range.validate(10.4), eq(Ok(10.)));
+        expect_that!(&range.contains(0.4), eq(false));
+    }
+
+    #[test]
+    fn values_outside_of_the_range_and_non_finite_values_are_invalid() {
+        let range = ParamRange::float("rate", 0., 1.);
+
+        expect_that!(
+            &range.validate(1.5),
+            eq(Err(OperatorParameterError::InvalidValue(
+                "value 1.5 for parameter rate is not within [0, 1]".to_string()
+            )))
+        );
+        expect_that!(&range.contains(f64::NAN), eq(false));
+        expect_that!(&range.contains(0.), eq(true));
+    }
+
+    #[test]
+    fn space_replaces_parameters_of_the_same_name() {
+        let space = ParamSpace::new()
+            .with(ParamRange::float("rate", 0., 1.))
+            .with(ParamRange::integer("size", 1., 5.))
+            .merge(ParamSpace::new().with(ParamRange::float("rate", 0., 0.5)));
+
+        expect_that!(&names(&space), eq(vec!["size", "rate"]));
+        expect_that!(&space.range("rate").map(|range| range.max), eq(Some(0.5)));
+        expect_that!(
+            &space.validate("unknown", 1.),
+            eq(Err(OperatorParameterError::UnknownParameter(
+                "unknown".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn default_space_describes_unbounded_float_parameters() {
+        let operator = CustomOperator { weight: 1. };
+
+        let space = operator.parameter_space();
+
+        expect_that!(
+            &space.range("weight").copied(),
+            eq(Some(ParamRange::float(
+                "weight",
+                f64::NEG_INFINITY,
+                f64::INFINITY
+            )))
+        );
+    }
+
+    #[test]
+    fn spaces_of_the_built_in_operators_describe_all_their_parameters() {
+        let operators: Vec<Box<dyn TunableOperator>> = vec![
+            Box::new(MaximizeSelector::new(0.5, 2)),
+            Box::new(TruncationSelector::new(0.3, 0.5, 2)),
+            Box::new(TournamentSelector::new(0.5, 2, 3, 1., false)),
+            Box::new(BoltzmannSelector::new(0.5, 2, 10., 0.9)),
+            Box::new(MultiPointCrossBreeder::new(2)),
+            Box::new(UniformCrossBreeder::new()),
+            Box::new(RandomValueMutator::<Vec<i32>>::new(0.1, 0, 10)),
+            Box::new(GaussianMutator::<Vec<f64>>::new(0.1, 1., -5., 5.)),
+            Box::new(SwapOrderMutator::new(0.1)),
+            Box::new(InversionMutator::new(0.1)),
+            Box::new(AdaptiveMutationOp::new(
+                RandomValueMutator::<Vec<i32>>::new(0.1, 0, 10),
+                0.5,
+            )),
+        ];
+
+        for operator in operators {
+            let mut expected = operator.parameter_names();
+            let mut described = names(&operator.parameter_space());
+            expected.sort_unstable();
+            described.sort_unstable();
+            expect_that!(&described, eq(expected));
+            for name in operator.parameter_names() {
+                let value = operator.parameter(name).unwrap();
+                expect_that!(
+                    &operator.parameter_space().validate(name, value).is_ok(),
+                    eq(true)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn built_in_operators_reject_values_outside_of_their_space() {
+        let mut selector = TournamentSelector::new(0.5, 2, 3, 1., false);
+
+        expect_that!(
+            &selector.set_parameter("probability", 1.5).is_err(),
+            eq(true)
+        );
+        expect_that!(
+            &selector.set_parameter("tournament_size", 0.).is_err(),
+            eq(true)
+        );
+        expect_that!(&selector.set_parameter("tournament_size", 4.2), eq(Ok(())));
+        expect_that!(&selector.parameter("tournament_size"), eq(Some(4.)));
+        expect_that!(
+            &selector
+                .parameter_space()
+                .range("tournament_size")
+                .map(|range| range.kind),
+            eq(Some(ParamKind::Integer))
+        );
+    }
+
+    #[test]
+    fn adaptive_mutation_describes_the_parameters_of_the_wrapped_mutator() {
+        let mut mutator =
+            AdaptiveMutationOp::new(RandomValueMutator::<Vec<i32>>::new(0.1, 0, 10), 0.5);
+
+        expect_that!(
+            &mutator
+                .parameter_space()
+                .range("mutation_rate")
+                .map(|range| range.max),
+            eq(Some(1.))
+        );
+        expect_that!(
+            &mutator.set_parameter("mutation_rate", 2.).is_err(),
+            eq(true)
+        );
+        expect_that!(
+            &mutator.set_parameter("adaptation_factor", 0.5).is_err(),
+            eq(true)
+        );
+    }
+}
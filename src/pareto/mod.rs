@@ -0,0 +1,274 @@
+//! The `pareto` module provides the building blocks for multi-objective
+//! optimization based on Pareto dominance.
+//!
+//! Multi-objective problems have several conflicting objectives, e.g. the
+//! cost and the quality of a product. Instead of scalarizing the objectives
+//! into one fitness value, the objectives are kept in a `Pareto` fitness
+//! value. A solution dominates another solution if it is at least as good in
+//! all objectives and better in at least one objective. The solutions that
+//! are not dominated by any other solution form the Pareto front.
+//!
+//! This module provides:
+//! * `Pareto` - a `genetic::Fitness` value of several objectives
+//! * `non_dominated_sort` - sorts a population into non-dominated fronts
+//! * `crowding_distance` - measures how crowded the neighborhood of the
+//!   solutions of a front is
+//!
+//! The `selection::nsga2::Nsga2Selector` and the
+//! `reinsertion::nsga2::Nsga2Reinserter` use them to implement the NSGA-II
+//! algorithm.
+
+use crate::genetic::{AsScalar, Fitness};
+use std::cmp::Ordering;
+
+/// A `Pareto` fitness value holds the values of several objectives. All
+/// objectives are maximized, like any `genetic::Fitness` value.
+///
+/// The total order of `Pareto` values, which is required by the
+/// `genetic::Fitness` trait, is the lexicographic order of the objectives.
+/// It is only used by operators for single-objective optimization, e.g. to
+/// determine the best solution of a generation. Multi-objective operators
+/// compare `Pareto` values by `dominates`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pareto<T>
+where
+    T: Fitness,
+{
+    objectives: Vec<T>,
+}
+
+impl<T> Pareto<T>
+where
+    T: Fitness,
+{
+    /// Constructs a new `Pareto` fitness value of the given objectives.
+    pub fn new(objectives: Vec<T>) -> Self {
+        Pareto { objectives }
+    }
+
+    /// Returns the values of the objectives.
+    pub fn objectives(&self) -> &[T] {
+        &self.objectives
+    }
+
+    /// Returns the number of objectives.
+    pub fn num_objectives(&self) -> usize {
+        self.objectives.len()
+    }
+
+    /// Returns whether this value dominates the other value, i.e. it is at
+    /// least as good in all objectives and better in at least one objective.
+    ///
+    /// Values of a different number of objectives do not dominate each
+    /// other.
+    pub fn dominates(&self, other: &Pareto<T>) -> bool {
+        if self.objectives.len() != other.objectives.len() {
+            return false;
+        }
+        let mut better_in_any = false;
+        for (value, other_value) in self.objectives.iter().zip(&other.objectives) {
+            match value.cmp(other_value) {
+                Ordering::Less => return false,
+                Ordering::Greater => better_in_any = true,
+                Ordering::Equal => {},
+            }
+        }
+        better_in_any
+    }
+}
+
+impl<T> From<Vec<T>> for Pareto<T>
+where
+    T: Fitness,
+{
+    fn from(objectives: Vec<T>) -> Self {
+        Pareto::new(objectives)
+    }
+}
+
+impl<T> Fitness for Pareto<T>
+where
+    T: Fitness,
+{
+    /// Returns a `Pareto` value without any objectives.
+    fn zero() -> Self {
+        Pareto::new(Vec::new())
+    }
+
+    /// Returns the absolute differences of the objectives. Objectives that
+    /// only one of the values has are compared to zero.
+    fn abs_diff(&self, other: &Self) -> Self {
+        let zero = T::zero();
+        let num_objectives = self.objectives.len().max(other.objectives.len());
+        Pareto::new(
+            (0..num_objectives)
+                .map(|index| {
+                    let value = self.objectives.get(index).unwrap_or(&zero);
+                    let other_value = other.objectives.get(index).unwrap_or(&zero);
+                    value.abs_diff(other_value)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The scalar value of a `Pareto` value is the sum of the scalar values of
+/// its objectives, i.e. all objectives are weighted equally.
+impl<T> AsScalar for Pareto<T>
+where
+    T: Fitness + AsScalar,
+{
+    fn as_scalar(&self) -> f64 {
+        self.objectives.iter().map(AsScalar::as_scalar).sum()
+    }
+}
+
+/// Sorts the given fitness values into non-dominated fronts.
+///
+/// The first front holds the indices of the values that are not dominated by
+/// any other value. Each following front holds the indices of the values
+/// that are only dominated by values of the previous fronts. The indices in
+/// a front are in ascending order.
+pub fn non_dominated_sort<T>(fitness_values: &[Pareto<T>]) -> Vec<Vec<usize>>
+where
+    T: Fitness,
+{
+    let size = fitness_values.len();
+    // the indices of the values dominated by each value
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); size];
+    // the number of values that dominate each value
+    let mut domination_count = vec![0usize; size];
+    for index1 in 0..size {
+        for index2 in (index1 + 1)..size {
+            if fitness_values[index1].dominates(&fitness_values[index2]) {
+                dominated[index1].push(index2);
+                domination_count[index2] += 1;
+            } else if fitness_values[index2].dominates(&fitness_values[index1]) {
+                dominated[index2].push(index1);
+                domination_count[index1] += 1;
+            }
+        }
+    }
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..size)
+        .filter(|index| domination_count[*index] == 0)
+        .collect();
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+        for index in &front {
+            for dominated_index in &dominated[*index] {
+                domination_count[*dominated_index] -= 1;
+                if domination_count[*dominated_index] == 0 {
+                    next_front.push(*dominated_index);
+                }
+            }
+        }
+        next_front.sort_unstable();
+        fronts.push(front);
+        front = next_front;
+    }
+    fronts
+}
+
+/// Calculates the crowding distance of the members of the given front.
+///
+/// The crowding distance of a member is the sum over all objectives of the
+/// distance between its two neighbors in that objective, normalized by the
+/// range of the objective in the front. The members with the smallest or
+/// the largest value of an objective get an infinite crowding distance. A
+/// big crowding distance means the member lies in a sparse region of the
+/// front.
+///
+/// The returned distances are in the order of the members of the front.
+pub fn crowding_distance<T>(fitness_values: &[Pareto<T>], front: &[usize]) -> Vec<f64>
+where
+    T: Fitness + AsScalar,
+{
+    let size = front.len();
+    let mut distances = vec![0.; size];
+    if size <= 2 {
+        return vec![f64::INFINITY; size];
+    }
+    let num_objectives = front
+        .iter()
+        .map(|index| fitness_values[*index].num_objectives())
+        .min()
+        .unwrap_or(0);
+    let mut order: Vec<usize> = (0..size).collect();
+    for objective in 0..num_objectives {
+        let value_of = |position: usize| -> f64 {
+            fitness_values[front[position]].objectives[objective].as_scalar()
+        };
+        order.sort_by(|position1, position2| {
+            value_of(*position1)
+                .partial_cmp(&value_of(*position2))
+                .unwrap_or(Ordering::Equal)
+        });
+        distances[order[0]] = f64::INFINITY;
+        distances[order[size - 1]] = f64::INFINITY;
+        let range = value_of(order[size - 1]) - value_of(order[0]);
+        if range <= 0. {
+            continue;
+        }
+        for rank in 1..(size - 1) {
+            distances[order[rank]] +=
+                (value_of(order[rank + 1]) - value_of(order[rank - 1])) / range;
+        }
+    }
+    distances
+}
+
+/// The non-domination rank and the crowding distance of each individual of
+/// a population, as used by the crowded comparison of NSGA-II.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrowdedRanking {
+    ranks: Vec<usize>,
+    distances: Vec<f64>,
+}
+
+impl CrowdedRanking {
+    /// Calculates the ranks and crowding distances of the given fitness
+    /// values.
+    pub fn of<T>(fitness_values: &[Pareto<T>]) -> Self
+    where
+        T: Fitness + AsScalar,
+    {
+        let mut ranks = vec![0; fitness_values.len()];
+        let mut distances = vec![0.; fitness_values.len()];
+        for (rank, front) in non_dominated_sort(fitness_values).iter().enumerate() {
+            let front_distances = crowding_distance(fitness_values, front);
+            for (index, distance) in front.iter().zip(front_distances) {
+                ranks[*index] = rank;
+                distances[*index] = distance;
+            }
+        }
+        CrowdedRanking { ranks, distances }
+    }
+
+    /// Returns the index of the non-dominated front of the individual at the
+    /// given index, starting with 0 for the first front.
+    pub fn rank(&self, index: usize) -> usize {
+        self.ranks[index]
+    }
+
+    /// Returns the crowding distance of the individual at the given index
+    /// within its front.
+    pub fn distance(&self, index: usize) -> f64 {
+        self.distances[index]
+    }
+
+    /// Compares the individuals at the given indices by the crowded
+    /// comparison operator: an individual of a lower rank is better, and of
+    /// two individuals of the same rank the one with the larger crowding
+    /// distance is better. The better individual is ordered as greater.
+    pub fn compare(&self, index1: usize, index2: usize) -> Ordering {
+        self.ranks[index2].cmp(&self.ranks[index1]).then_with(|| {
+            self.distances[index1]
+                .partial_cmp(&self.distances[index2])
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,98 @@
+use super::*;
+use galvanic_assert::matchers::*;
+
+fn pareto(objectives: &[i32]) -> Pareto<i32> {
+    Pareto::new(objectives.to_vec())
+}
+
+#[test]
+fn a_value_dominates_a_value_that_is_worse_in_one_objective() {
+    expect_that!(&pareto(&[3, 2]).dominates(&pareto(&[3, 1])), eq(true));
+    expect_that!(&pareto(&[3, 2]).dominates(&pareto(&[2, 1])), eq(true));
+}
+
+#[test]
+fn values_that_are_better_in_different_objectives_do_not_dominate_each_other() {
+    expect_that!(&pareto(&[3, 1]).dominates(&pareto(&[1, 3])), eq(false));
+    expect_that!(&pareto(&[1, 3]).dominates(&pareto(&[3, 1])), eq(false));
+}
+
+#[test]
+fn a_value_does_not_dominate_an_equal_value_or_a_value_of_other_dimensions() {
+    expect_that!(&pareto(&[2, 2]).dominates(&pareto(&[2, 2])), eq(false));
+    expect_that!(&pareto(&[2, 2, 2]).dominates(&pareto(&[1, 1])), eq(false));
+}
+
+#[test]
+fn abs_diff_of_pareto_values_is_the_difference_of_each_objective() {
+    expect_that!(
+        &pareto(&[3, 1]).abs_diff(&pareto(&[1, 4])),
+        eq(pareto(&[2, 3]))
+    );
+    expect_that!(
+        &pareto(&[3]).abs_diff(&pareto(&[1, -4])),
+        eq(pareto(&[2, 4]))
+    );
+}
+
+#[test]
+fn scalar_value_of_pareto_value_is_the_sum_of_the_objectives() {
+    expect_that!(&pareto(&[3, 1, -2]).as_scalar(), eq(2.));
+}
+
+#[test]
+fn non_dominated_sort_returns_the_fronts_from_best_to_worst() {
+    let fitness_values = vec![
+        pareto(&[1, 1]),
+        pareto(&[4, 1]),
+        pareto(&[2, 2]),
+        pareto(&[1, 4]),
+        pareto(&[3, 3]),
+        pareto(&[0, 0]),
+    ];
+
+    let fronts = non_dominated_sort(&fitness_values);
+
+    expect_that!(&fronts, eq(vec![vec![1, 3, 4], vec![2], vec![0], vec![5]]));
+}
+
+#[test]
+fn non_dominated_sort_of_no_values_returns_no_fronts() {
+    expect_that!(&non_dominated_sort::<i32>(&[]).is_empty(), eq(true));
+}
+
+#[test]
+fn crowding_distance_of_the_boundary_members_is_infinite() {
+    let fitness_values = vec![
+        pareto(&[0, 10]),
+        pareto(&[2, 8]),
+        pareto(&[3, 7]),
+        pareto(&[10, 0]),
+    ];
+
+    let distances = crowding_distance(&fitness_values, &[0, 1, 2, 3]);
+
+    expect_that!(&distances[0], eq(f64::INFINITY));
+    expect_that!(&distances[3], eq(f64::INFINITY));
+    expect_that!(&distances[1], eq(0.6));
+    expect_that!(&distances[2], eq(1.6));
+}
+
+#[test]
+fn crowded_ranking_prefers_lower_fronts_and_then_larger_distances() {
+    let fitness_values = vec![
+        pareto(&[0, 10]),
+        pareto(&[2, 8]),
+        pareto(&[3, 7]),
+        pareto(&[10, 0]),
+        pareto(&[1, 1]),
+    ];
+
+    let ranking = CrowdedRanking::of(&fitness_values);
+
+    expect_that!(&ranking.rank(0), eq(0));
+    expect_that!(&ranking.rank(4), eq(1));
+    expect_that!(&ranking.compare(1, 4), eq(Ordering::Greater));
+    expect_that!(&ranking.compare(2, 1), eq(Ordering::Greater));
+    expect_that!(&ranking.compare(1, 0), eq(Ordering::Less));
+}
@@ -0,0 +1,257 @@
+//! The `allocator` module provides hooks to control where the buffers for
+//! the populations and the offspring come from.
+//!
+//! The offspring and the population are built anew in each generation.
+//! Embedded and real-time users, who need predictable allocation patterns,
+//! can provide a `PopulationAllocator` to the `ga::GeneticAlgorithm`, e.g.
+//! the `RecyclingAllocator`, which reuses the buffers of former generations.
+//!
+//! The individuals of a new population are moved into a buffer of the
+//! allocator, and the buffer the `operator::ReinsertionOp` has built them in
+//! is released to the allocator. The population of a generation is handed
+//! out with the `State` of the algorithm, which may outlive the generation.
+//! Its buffer is released to the allocator as soon as the population has
+//! been replaced and is not shared anymore. The algorithm keeps only a few
+//! replaced populations that are still shared. The buffers of older ones are
+//! dropped by whoever shares them last.
+//!
+//! Note: As custom allocators for `Vec` are not available on stable Rust,
+//! a `PopulationAllocator` hands out and takes back whole buffers instead of
+//! allocating raw memory.
+
+use std::{
+    fmt::{self, Debug},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// A `PopulationAllocator` provides the buffers to store the individuals of
+/// the populations and of the offspring.
+pub trait PopulationAllocator<G>: Debug + Send + Sync {
+    /// Returns an empty buffer with a capacity of at least the given
+    /// `capacity`.
+    fn allocate(&self, capacity: usize) -> Vec<G>;
+
+    /// Takes back a buffer that is no longer used.
+    fn release(&self, buffer: Vec<G>);
+}
+
+/// The `GlobalAllocator` allocates each buffer from the global allocator and
+/// drops released buffers. This is the default `PopulationAllocator`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GlobalAllocator;
+
+impl<G> PopulationAllocator<G> for GlobalAllocator {
+    fn allocate(&self, capacity: usize) -> Vec<G> {
+        Vec::with_capacity(capacity)
+    }
+
+    fn release(&self, _buffer: Vec<G>) {}
+}
+
+/// The `RecyclingAllocator` keeps released buffers and hands them out again
+/// on later allocations. A new buffer is only allocated if no kept buffer has
+/// the requested capacity.
+pub struct RecyclingAllocator<G> {
+    /// The maximum number of buffers that are kept for reuse.
+    max_buffers: usize,
+    buffers: Mutex<Vec<Vec<G>>>,
+    num_allocations: AtomicUsize,
+}
+
+impl<G> RecyclingAllocator<G> {
+    /// Constructs a new `RecyclingAllocator` that keeps up to `max_buffers`
+    /// released buffers for reuse.
+    pub fn new(max_buffers: usize) -> Self {
+        RecyclingAllocator {
+            max_buffers,
+            buffers: Mutex::new(Vec::with_capacity(max_buffers)),
+            num_allocations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the maximum number of buffers that are kept for reuse.
+    pub fn max_buffers(&self) -> usize {
+        self.max_buffers
+    }
+
+    /// Returns the number of buffers that are currently kept for reuse.
+    pub fn num_buffers(&self) -> usize {
+        self.lock_buffers().len()
+    }
+
+    /// Returns the number of buffers that have been newly allocated, as no
+    /// kept buffer could be reused.
+    pub fn num_allocations(&self) -> usize {
+        self.num_allocations.load(Ordering::Relaxed)
+    }
+
+    fn lock_buffers(&self) -> std::sync::MutexGuard<'_, Vec<Vec<G>>> {
+        // the buffers are valid even if another thread panicked
+        self.buffers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<G> Debug for RecyclingAllocator<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecyclingAllocator")
+            .field("max_buffers", &self.max_buffers)
+            .field("num_buffers", &self.num_buffers())
+            .field("num_allocations", &self.num_allocations())
+            .finish()
+    }
+}
+
+impl<G> PopulationAllocator<G> for RecyclingAllocator<G>
+where
+    G: Send,
+{
+    fn allocate(&self, capacity: usize) -> Vec<G> {
+        let mut buffers = self.lock_buffers();
+        match buffers
+            .iter()
+            .position(|buffer| buffer.capacity() >= capacity)
+        {
+            Some(index) => buffers.swap_remove(index),
+            None => {
+                self.num_allocations.fetch_add(1, Ordering::Relaxed);
+                Vec::with_capacity(capacity)
+            },
+        }
+    }
+
+    fn release(&self, mut buffer: Vec<G>) {
+        buffer.clear();
+        let mut buffers = self.lock_buffers();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+}
+
+impl<G, A> PopulationAllocator<G> for Arc<A>
+where
+    A: PopulationAllocator<G> + ?Sized,
+{
+    fn allocate(&self, capacity: usize) -> Vec<G> {
+        (**self).allocate(capacity)
+    }
+
+    fn release(&self, buffer: Vec<G>) {
+        (**self).release(buffer)
+    }
+}
+
+/// The maximum number of replaced populations that are kept until they are
+/// not shared anymore.
+const MAX_RETIRED_POPULATIONS: usize = 4;
+
+/// A `SharedAllocator` holds the `PopulationAllocator` used by a genetic
+/// algorithm. Clones of the algorithm share the same allocator.
+pub(crate) struct SharedAllocator<G> {
+    allocator: Arc<dyn PopulationAllocator<G>>,
+    /// The populations that have been replaced, but are still shared.
+    retired: Arc<Mutex<Vec<Arc<Vec<G>>>>>,
+}
+
+impl<G> SharedAllocator<G> {
+    pub(crate) fn new<A>(allocator: A) -> Self
+    where
+        A: PopulationAllocator<G> + 'static,
+    {
+        SharedAllocator {
+            allocator: Arc::new(allocator),
+            retired: Arc::new(Mutex::new(Vec::with_capacity(MAX_RETIRED_POPULATIONS + 1))),
+        }
+    }
+}
+
+impl<G> Clone for SharedAllocator<G> {
+    fn clone(&self) -> Self {
+        SharedAllocator {
+            allocator: self.allocator.clone(),
+            retired: self.retired.clone(),
+        }
+    }
+}
+
+/// Two `SharedAllocator`s are equal if they share the same allocator.
+impl<G> PartialEq for SharedAllocator<G> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.allocator, &other.allocator)
+    }
+}
+
+impl<G> Debug for SharedAllocator<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SharedAllocator")
+            .field(&self.allocator)
+            .finish()
+    }
+}
+
+/// Returns a buffer from the given allocator or from the global allocator if
+/// no allocator is given.
+pub(crate) fn allocate<G>(allocator: Option<&SharedAllocator<G>>, capacity: usize) -> Vec<G> {
+    match allocator {
+        Some(allocator) => allocator.allocator.allocate(capacity),
+        None => Vec::with_capacity(capacity),
+    }
+}
+
+/// Releases the buffer to the given allocator if there is one.
+pub(crate) fn release<G>(allocator: Option<&SharedAllocator<G>>, buffer: Vec<G>) {
+    if let Some(allocator) = allocator {
+        allocator.allocator.release(buffer);
+    }
+}
+
+/// Moves the given individuals of a new population into a buffer from the
+/// given allocator and releases the buffer they have been built in. Without
+/// an allocator the individuals stay in their buffer.
+pub(crate) fn new_population<G>(
+    allocator: Option<&SharedAllocator<G>>,
+    mut individuals: Vec<G>,
+) -> Arc<Vec<G>> {
+    match allocator {
+        Some(allocator) => {
+            let mut population = allocator.allocator.allocate(individuals.len());
+            population.append(&mut individuals);
+            allocator.allocator.release(individuals);
+            Arc::new(population)
+        },
+        None => Arc::new(individuals),
+    }
+}
+
+/// Releases the buffer of the given population that has been replaced to the
+/// given allocator, as soon as the population is not shared anymore. The
+/// buffers of replaced populations that are no longer shared by now are
+/// released as well.
+pub(crate) fn retire<G>(allocator: Option<&SharedAllocator<G>>, population: Arc<Vec<G>>) {
+    if let Some(allocator) = allocator {
+        let mut retired = allocator
+            .retired
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        retired.push(population);
+        let mut index = 0;
+        while index < retired.len() {
+            if Arc::strong_count(&retired[index]) == 1 {
+                if let Ok(buffer) = Arc::try_unwrap(retired.remove(index)) {
+                    allocator.allocator.release(buffer);
+                }
+            } else {
+                index += 1;
+            }
+        }
+        if retired.len() > MAX_RETIRED_POPULATIONS {
+            // the oldest population is left to whoever still shares it
+            retired.remove(0);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! The `hashed` module provides canonical hashing of `genetic::Genotype`s
+//! and the `HashedPopulation`, an index of genomes by their hash.
+//!
+//! Features like the removal of duplicates, caching of fitness values or
+//! novelty archives need to find out quickly whether an equivalent genome
+//! is already known. What counts as equivalent depends on the encoding:
+//! * `ExactHasher` - genomes are equivalent if they are equal, e.g. for
+//!   value encoded genomes of integers or characters.
+//! * `BinaryHasher` - binary encoded genomes are equivalent if they have the
+//!   same bits, regardless of whether they are stored as `Vec<bool>` or as
+//!   `fixedbitset::FixedBitSet`.
+//! * `BucketHasher` - real valued genomes are equivalent if all their values
+//!   fall into the same buckets of a given tolerance.
+//! * `CyclicPermutationHasher` - permutations are equivalent if they describe
+//!   the same cycle, e.g. the same tour of a traveling salesman, regardless
+//!   of the start and the direction of the tour.
+
+use crate::{cache::genome_hash, genetic::Genotype, population::Population};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
+
+/// A `GenomeHasher` calculates a canonical hash of a `genetic::Genotype`.
+///
+/// Equivalent genomes must have the same hash. Genomes with the same hash
+/// may still not be equivalent, in which case `equivalent` decides.
+pub trait GenomeHasher<G>: Clone + Debug + Send + Sync
+where
+    G: Genotype,
+{
+    /// Calculates the canonical hash of the given genome.
+    fn hash_genome(&self, genome: &G) -> u64;
+
+    /// Returns whether the given genomes are equivalent.
+    fn equivalent(&self, genome1: &G, genome2: &G) -> bool;
+}
+
+/// The `ExactHasher` considers genomes as equivalent if they are equal.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ExactHasher;
+
+impl<G> GenomeHasher<G> for ExactHasher
+where
+    G: Genotype + Hash + PartialEq,
+{
+    fn hash_genome(&self, genome: &G) -> u64 {
+        genome_hash(genome)
+    }
+
+    fn equivalent(&self, genome1: &G, genome2: &G) -> bool {
+        genome1 == genome2
+    }
+}
+
+/// The `BinaryHasher` hashes the bits of binary encoded genomes. The hash of
+/// a genome is the same for all supported representations of the bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BinaryHasher;
+
+impl BinaryHasher {
+    fn hash_bits<I>(bits: I) -> u64
+    where
+        I: Iterator<Item = bool>,
+    {
+        let mut hasher = DefaultHasher::new();
+        let mut block = 0u64;
+        let mut length = 0usize;
+        for bit in bits {
+            if bit {
+                block |= 1 << (length % 64);
+            }
+            length += 1;
+            if length.is_multiple_of(64) {
+                hasher.write_u64(block);
+                block = 0;
+            }
+        }
+        hasher.write_u64(block);
+        hasher.write_usize(length);
+        hasher.finish()
+    }
+}
+
+impl GenomeHasher<Vec<bool>> for BinaryHasher {
+    fn hash_genome(&self, genome: &Vec<bool>) -> u64 {
+        Self::hash_bits(genome.iter().cloned())
+    }
+
+    fn equivalent(&self, genome1: &Vec<bool>, genome2: &Vec<bool>) -> bool {
+        genome1 == genome2
+    }
+}
+
+#[cfg(feature = "fixedbitset")]
+impl GenomeHasher<fixedbitset::FixedBitSet> for BinaryHasher {
+    fn hash_genome(&self, genome: &fixedbitset::FixedBitSet) -> u64 {
+        Self::hash_bits((0..genome.len()).map(|bit| genome.contains(bit)))
+    }
+
+    fn equivalent(
+        &self,
+        genome1: &fixedbitset::FixedBitSet,
+        genome2: &fixedbitset::FixedBitSet,
+    ) -> bool {
+        genome1 == genome2
+    }
+}
+
+/// The `BucketHasher` divides the range of real values into buckets of the
+/// size `tolerance`. Real valued genomes are equivalent if all their values
+/// fall into the same buckets.
+///
+/// Values that differ by less than the tolerance may still fall into
+/// neighboring buckets. Thus the `BucketHasher` finds most, but not all near
+/// duplicates.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketHasher {
+    tolerance: f64,
+}
+
+impl BucketHasher {
+    /// Constructs a new `BucketHasher` with buckets of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is not a positive number.
+    pub fn new(tolerance: f64) -> Self {
+        assert!(
+            tolerance > 0.,
+            "the tolerance must be a positive number, but is {}",
+            tolerance
+        );
+        BucketHasher { tolerance }
+    }
+
+    /// Returns the size of the buckets.
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Returns the bucket of the given value. All NaN values fall into the
+    /// same bucket.
+    pub fn bucket_of(&self, value: f64) -> i64 {
+        if value.is_nan() {
+            i64::MIN
+        } else {
+            // the cast saturates for values out of range of i64
+            (value / self.tolerance).floor() as i64
+        }
+    }
+
+    fn hash_values<I>(&self, values: I) -> u64
+    where
+        I: Iterator<Item = f64>,
+    {
+        let mut hasher = DefaultHasher::new();
+        let mut length = 0usize;
+        for value in values {
+            hasher.write_i64(self.bucket_of(value));
+            length += 1;
+        }
+        hasher.write_usize(length);
+        hasher.finish()
+    }
+
+    fn same_buckets<I1, I2>(&self, values1: I1, values2: I2) -> bool
+    where
+        I1: ExactSizeIterator<Item = f64>,
+        I2: ExactSizeIterator<Item = f64>,
+    {
+        values1.len() == values2.len()
+            && values1
+                .zip(values2)
+                .all(|(value1, value2)| self.bucket_of(value1) == self.bucket_of(value2))
+    }
+}
+
+impl GenomeHasher<Vec<f64>> for BucketHasher {
+    fn hash_genome(&self, genome: &Vec<f64>) -> u64 {
+        self.hash_values(genome.iter().cloned())
+    }
+
+    fn equivalent(&self, genome1: &Vec<f64>, genome2: &Vec<f64>) -> bool {
+        self.same_buckets(genome1.iter().cloned(), genome2.iter().cloned())
+    }
+}
+
+impl GenomeHasher<Vec<f32>> for BucketHasher {
+    fn hash_genome(&self, genome: &Vec<f32>) -> u64 {
+        self.hash_values(genome.iter().map(|value| f64::from(*value)))
+    }
+
+    fn equivalent(&self, genome1: &Vec<f32>, genome2: &Vec<f32>) -> bool {
+        self.same_buckets(
+            genome1.iter().map(|value| f64::from(*value)),
+            genome2.iter().map(|value| f64::from(*value)),
+        )
+    }
+}
+
+/// The `CyclicPermutationHasher` considers permutations as equivalent if
+/// they describe the same cycle, i.e. one permutation can be turned into the
+/// other by rotating and/or reversing it. E.g. `[0, 1, 2, 3]`, `[2, 3, 0, 1]`
+/// and `[1, 0, 3, 2]` are the same tour of a traveling salesman.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CyclicPermutationHasher;
+
+impl CyclicPermutationHasher {
+    /// Returns the canonical form of the given permutation: the rotation
+    /// that starts with the smallest value, in the direction in which the
+    /// second value is the smaller one.
+    pub fn canonical_form<V>(&self, permutation: &[V]) -> Vec<V>
+    where
+        V: Clone + Ord,
+    {
+        let length = permutation.len();
+        let start = match permutation
+            .iter()
+            .enumerate()
+            .min_by(|(_, value1), (_, value2)| value1.cmp(value2))
+        {
+            Some((start, _)) => start,
+            None => return Vec::new(),
+        };
+        let forward = permutation[(start + 1) % length].clone();
+        let backward = permutation[(start + length - 1) % length].clone();
+        if forward <= backward {
+            (0..length)
+                .map(|offset| permutation[(start + offset) % length].clone())
+                .collect()
+        } else {
+            (0..length)
+                .map(|offset| permutation[(start + length - offset) % length].clone())
+                .collect()
+        }
+    }
+}
+
+impl<V> GenomeHasher<Vec<V>> for CyclicPermutationHasher
+where
+    V: Clone + Debug + Ord + Hash + Send + Sync,
+{
+    fn hash_genome(&self, genome: &Vec<V>) -> u64 {
+        genome_hash(&self.canonical_form(genome))
+    }
+
+    fn equivalent(&self, genome1: &Vec<V>, genome2: &Vec<V>) -> bool {
+        genome1.len() == genome2.len()
+            && self.canonical_form(genome1) == self.canonical_form(genome2)
+    }
+}
+
+/// The `HashedPopulation` holds a set of genomes that are pairwise not
+/// equivalent according to a `GenomeHasher`, and finds equivalent genomes
+/// by their hash.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashedPopulation<G, H>
+where
+    G: Genotype,
+    H: GenomeHasher<G>,
+{
+    hasher: H,
+    genomes: Vec<G>,
+    index: HashMap<u64, Vec<usize>>,
+}
+
+impl<G, H> HashedPopulation<G, H>
+where
+    G: Genotype,
+    H: GenomeHasher<G>,
+{
+    /// Constructs a new empty `HashedPopulation` using the given hasher.
+    pub fn new(hasher: H) -> Self {
+        HashedPopulation {
+            hasher,
+            genomes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Constructs a new `HashedPopulation` from the individuals of the given
+    /// population. Individuals that are equivalent to an earlier individual
+    /// are left out.
+    pub fn from_population(population: &Population<G>, hasher: H) -> Self {
+        let mut hashed = HashedPopulation::new(hasher);
+        for genome in population.individuals() {
+            hashed.insert(genome.clone());
+        }
+        hashed
+    }
+
+    /// Returns the hasher used by this `HashedPopulation`.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the genomes in the order they have been inserted.
+    pub fn genomes(&self) -> &[G] {
+        &self.genomes
+    }
+
+    /// Returns the number of genomes.
+    pub fn len(&self) -> usize {
+        self.genomes.len()
+    }
+
+    /// Returns whether there are no genomes.
+    pub fn is_empty(&self) -> bool {
+        self.genomes.is_empty()
+    }
+
+    /// Returns the index of the genome that is equivalent to the given
+    /// genome, if there is one.
+    pub fn index_of(&self, genome: &G) -> Option<usize> {
+        let hash = self.hasher.hash_genome(genome);
+        self.find(hash, genome)
+    }
+
+    /// Returns whether there is a genome that is equivalent to the given
+    /// genome.
+    pub fn contains(&self, genome: &G) -> bool {
+        self.index_of(genome).is_some()
+    }
+
+    /// Inserts the given genome, if there is no equivalent genome yet.
+    /// Returns false if the genome has not been inserted.
+    pub fn insert(&mut self, genome: G) -> bool {
+        let hash = self.hasher.hash_genome(&genome);
+        if self.find(hash, &genome).is_some() {
+            return false;
+        }
+        self.index.entry(hash).or_default().push(self.genomes.len());
+        self.genomes.push(genome);
+        true
+    }
+
+    /// Removes all genomes.
+    pub fn clear(&mut self) {
+        self.genomes.clear();
+        self.index.clear();
+    }
+
+    /// Converts this `HashedPopulation` into a `Population` of its genomes.
+    pub fn into_population(self) -> Population<G> {
+        Population::with_individuals(self.genomes)
+    }
+
+    fn find(&self, hash: u64, genome: &G) -> Option<usize> {
+        self.index.get(&hash).and_then(|indices| {
+            indices
+                .iter()
+                .cloned()
+                .find(|index| self.hasher.equivalent(&self.genomes[*index], genome))
+        })
+    }
+}
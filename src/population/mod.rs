@@ -7,8 +7,11 @@
 //! `genetic::Genotype`.
 //!
 //! Default implementations of `GenomeBuilder` are provided for the binary
-//! encoded types `fixedbitset::FixedBitSet` and `Vec<bool>` and for the
-//! value encoded type `Vec<T>`.
+//! encoded types `fixedbitset::FixedBitSet` and `Vec<bool>`, for the
+//! value encoded type `Vec<T>`, for permutations of type `Vec<usize>`, for
+//! text of type `Vec<char>`, for graphs of type `encoding::graph::Graph` and
+//! for matrices of type `encoding::matrix::Matrix`. The binary, value and
+//! permutation encoded genomes can also be fixed-size arrays `[T; N]`.
 //!
 //! ## Examples
 //!
@@ -97,18 +100,31 @@
 //! }
 //! ```
 
+pub mod allocator;
+pub mod hashed;
+
+#[cfg(feature = "graph")]
+use crate::encoding::graph::Graph;
+#[cfg(feature = "matrix")]
+use crate::encoding::matrix::Matrix;
 use crate::{
     genetic::Genotype,
-    random::{get_rng, random_seed, Prng, Rng, Seed},
+    random::{get_rng, random_index, random_seed, Prng, Rng, Seed, SeedableRng, SliceRandom},
 };
 use rand::distributions::uniform::SampleUniform;
-#[cfg(not(target_arch = "wasm32"))]
-use rayon;
-use std::{fmt::Debug, marker::PhantomData};
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
+};
 
 /// The `Population` defines a set of possible solutions to the optimization
 /// or search problem.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Population<G>
 where
     G: Genotype,
@@ -131,12 +147,192 @@ where
         &self.individuals
     }
 
+    /// Returns a mutable slice of all individuals of this `Population`, e.g.
+    /// to repair or post-process the individuals in place.
+    pub fn individuals_mut(&mut self) -> &mut [G] {
+        &mut self.individuals
+    }
+
+    /// Consumes this `Population` and returns its individuals.
+    pub fn into_individuals(self) -> Vec<G> {
+        self.individuals
+    }
+
     /// Returns the number of individuals in this `Population`.
     pub fn size(&self) -> usize {
         self.individuals.len()
     }
+
+    /// Creates a new `Population` with the given individuals as members, if
+    /// all individuals have the same genome length.
+    pub fn try_with_individuals(individuals: Vec<G>) -> Result<Population<G>, PopulationError>
+    where
+        G: GenomeLength,
+    {
+        let population = Population { individuals };
+        population.validate_genome_lengths()?;
+        Ok(population)
+    }
+
+    /// Checks that all individuals of this `Population` have the same genome
+    /// length as the first individual.
+    ///
+    /// Length preserving operators like the crossover operators in the
+    /// `recombination` module expect all genomes of a population to have the
+    /// same length. A custom `GenomeBuilder` that builds genomes of different
+    /// lengths should be detected here, rather than by an index out of bounds
+    /// error in the middle of a simulation.
+    pub fn validate_genome_lengths(&self) -> Result<(), PopulationError>
+    where
+        G: GenomeLength,
+    {
+        let expected_length = match self.individuals.first() {
+            Some(first) => first.genome_length(),
+            None => return Ok(()),
+        };
+        let offending_indices: Vec<usize> = self
+            .individuals
+            .iter()
+            .enumerate()
+            .filter(|(_, genome)| genome.genome_length() != expected_length)
+            .map(|(index, _)| index)
+            .collect();
+        if offending_indices.is_empty() {
+            Ok(())
+        } else {
+            Err(PopulationError::InconsistentGenomeLengths {
+                expected_length,
+                offending_indices,
+            })
+        }
+    }
+}
+
+impl<G> From<Vec<G>> for Population<G>
+where
+    G: Genotype,
+{
+    fn from(individuals: Vec<G>) -> Self {
+        Population { individuals }
+    }
 }
 
+impl<G> From<Population<G>> for Vec<G>
+where
+    G: Genotype,
+{
+    fn from(population: Population<G>) -> Self {
+        population.individuals
+    }
+}
+
+impl<G> FromIterator<G> for Population<G>
+where
+    G: Genotype,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Population {
+            individuals: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<G> Extend<G> for Population<G>
+where
+    G: Genotype,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = G>,
+    {
+        self.individuals.extend(iter);
+    }
+}
+
+impl<G> IntoIterator for Population<G>
+where
+    G: Genotype,
+{
+    type Item = G;
+    type IntoIter = std::vec::IntoIter<G>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.individuals.into_iter()
+    }
+}
+
+impl<'a, G> IntoIterator for &'a Population<G>
+where
+    G: Genotype,
+{
+    type Item = &'a G;
+    type IntoIter = std::slice::Iter<'a, G>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.individuals.iter()
+    }
+}
+
+/// A `genetic::Genotype` that consists of a sequence of genes, whose number
+/// is the length of the genome.
+pub trait GenomeLength {
+    /// Returns the number of genes of this genome.
+    fn genome_length(&self) -> usize;
+}
+
+impl<V> GenomeLength for Vec<V> {
+    fn genome_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<V, const N: usize> GenomeLength for [V; N] {
+    fn genome_length(&self) -> usize {
+        N
+    }
+}
+
+/// The length of a `String` genome is its number of characters.
+impl GenomeLength for String {
+    fn genome_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+/// An error that can occur when constructing a `Population`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PopulationError {
+    /// Some genomes of the population have a different length than the first
+    /// genome of the population.
+    InconsistentGenomeLengths {
+        /// The length of the first genome of the population.
+        expected_length: usize,
+        /// The indices of the genomes whose length differs.
+        offending_indices: Vec<usize>,
+    },
+}
+
+impl Display for PopulationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PopulationError::InconsistentGenomeLengths {
+                expected_length,
+                offending_indices,
+            } => write!(
+                f,
+                "The genomes at the indices {:?} do not have the length {} of the first genome \
+                 of the population",
+                offending_indices, expected_length
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PopulationError {}
+
 /// The `PopulationBuilder` creates a new `Population` with a number of newly
 /// created individuals or just individual `genetic::Genotype`s.
 ///
@@ -149,51 +345,63 @@ where
 #[derive(Clone, Debug, PartialEq)]
 pub struct PopulationBuilder;
 
-#[cfg(not(target_arch = "wasm32"))]
+/// The default number of genomes up to which a population is built
+/// sequentially. Larger populations are built in parallel in chunks of at
+/// least this number of genomes.
+pub const DEFAULT_SEQUENTIAL_THRESHOLD: usize = 1024;
+
 impl PopulationBuilder {
-    fn build_population<B, G>(genome_builder: &B, size: usize, mut rng: Prng) -> Population<G>
+    /// Builds a population of the given size.
+    ///
+    /// Each genome is built with its own `Prng` which is seeded from the
+    /// index of the genome and a base seed drawn from the given `rng`. Thus
+    /// the built population only depends on the given `rng` and not on the
+    /// sequential threshold or the number of threads.
+    fn build_population<B, G>(
+        genome_builder: &B,
+        size: usize,
+        sequential_threshold: usize,
+        mut rng: Prng,
+    ) -> Population<G>
     where
         B: GenomeBuilder<G>,
         G: Genotype,
     {
-        if size < 50 {
-            Population {
-                individuals: (0..size)
-                    .map(|index| genome_builder.build_genome(index, &mut rng))
-                    .collect(),
-            }
-        } else {
-            rng.jump();
-            let rng1 = rng.clone();
-            rng.jump();
-            let rng2 = rng.clone();
-            let left_size = size / 2;
-            let right_size = size - left_size;
-            let (left_population, right_population) = rayon::join(
-                || Self::build_population(genome_builder, left_size, rng1),
-                || Self::build_population(genome_builder, right_size, rng2),
-            );
-            let mut right_individuals = right_population.individuals;
-            let mut individuals = left_population.individuals;
-            individuals.append(&mut right_individuals);
-            Population { individuals }
+        let base_seed: u64 = rng.gen();
+        let build_genome = |index: usize| {
+            let mut rng = Prng::seed_from_u64(base_seed.wrapping_add(index as u64));
+            genome_builder.build_genome(index, &mut rng)
+        };
+        Population {
+            individuals: Self::build_individuals(size, sequential_threshold, build_genome),
         }
     }
-}
 
-#[cfg(target_arch = "wasm32")]
-impl PopulationBuilder {
-    fn build_population<B, G>(genome_builder: &B, size: usize, mut rng: Prng) -> Population<G>
+    #[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
+    fn build_individuals<G, F>(size: usize, sequential_threshold: usize, build_genome: F) -> Vec<G>
     where
-        B: GenomeBuilder<G>,
         G: Genotype,
+        F: Fn(usize) -> G + Sync,
     {
-        Population {
-            individuals: (0..size)
-                .map(|index| genome_builder.build_genome(index, &mut rng))
-                .collect(),
+        if size <= sequential_threshold {
+            (0..size).map(build_genome).collect()
+        } else {
+            (0..size)
+                .into_par_iter()
+                .with_min_len(sequential_threshold.max(1))
+                .map(&build_genome)
+                .collect()
         }
     }
+
+    #[cfg(all(target_arch = "wasm32", not(feature = "wasm-parallel")))]
+    fn build_individuals<G, F>(size: usize, _: usize, build_genome: F) -> Vec<G>
+    where
+        G: Genotype,
+        F: Fn(usize) -> G + Sync,
+    {
+        (0..size).map(build_genome).collect()
+    }
 }
 
 /// A `GenomeBuilder` defines how to build individuals of a population for
@@ -257,6 +465,7 @@ where
             _g: self._g,
             genome_builder: self.genome_builder,
             population_size,
+            sequential_threshold: DEFAULT_SEQUENTIAL_THRESHOLD,
         }
     }
 }
@@ -270,6 +479,7 @@ where
     _g: PhantomData<G>,
     genome_builder: B,
     population_size: usize,
+    sequential_threshold: usize,
 }
 
 impl<B, G> PopulationWithGenomeBuilderAndSizeBuilder<B, G>
@@ -277,21 +487,61 @@ where
     B: GenomeBuilder<G>,
     G: Genotype,
 {
+    /// Sets the number of genomes up to which the population is built
+    /// sequentially. Larger populations are built in parallel in chunks of
+    /// at least this number of genomes. Defaults to
+    /// `DEFAULT_SEQUENTIAL_THRESHOLD`.
+    ///
+    /// The threshold only affects the build time, the built population is
+    /// the same for any threshold.
+    pub fn with_sequential_threshold(mut self, sequential_threshold: usize) -> Self {
+        self.sequential_threshold = sequential_threshold;
+        self
+    }
+
     pub fn uniform_at_random(self) -> Population<G> {
         PopulationBuilder::build_population(
             &self.genome_builder,
             self.population_size,
+            self.sequential_threshold,
             get_rng(random_seed()),
         )
     }
 
-    pub fn using_seed(self, seed: Seed) -> Population<G> {
+    pub fn using_seed<S>(self, seed: S) -> Population<G>
+    where
+        S: Into<Seed>,
+    {
         PopulationBuilder::build_population(
             &self.genome_builder,
             self.population_size,
+            self.sequential_threshold,
             get_rng(seed),
         )
     }
+
+    /// Builds the population like `uniform_at_random` and checks that all
+    /// genomes have the same length.
+    pub fn try_uniform_at_random(self) -> Result<Population<G>, PopulationError>
+    where
+        G: GenomeLength,
+    {
+        let population = self.uniform_at_random();
+        population.validate_genome_lengths()?;
+        Ok(population)
+    }
+
+    /// Builds the population like `using_seed` and checks that all genomes
+    /// have the same length.
+    pub fn try_using_seed<S>(self, seed: S) -> Result<Population<G>, PopulationError>
+    where
+        G: GenomeLength,
+        S: Into<Seed>,
+    {
+        let population = self.using_seed(seed);
+        population.validate_genome_lengths()?;
+        Ok(population)
+    }
 }
 
 pub fn build_population() -> EmptyPopulationBuilder {
@@ -368,12 +618,137 @@ where
     }
 }
 
+/// A `GenomeBuilder` that builds text encoded `genetic::Genotype`s.
+///
+/// The default implementation builds `Vec<char>` and `String` genomes. The
+/// characters are picked uniformly at random from the given alphabet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharValueEncodedGenomeBuilder {
+    genome_length: usize,
+    alphabet: Vec<char>,
+}
+
+impl CharValueEncodedGenomeBuilder {
+    /// Returns a new instance of the `CharValueEncodedGenomeBuilder` that
+    /// builds text encoded genomes of length specified by the given
+    /// `genome_length`.
+    ///
+    /// The characters of the generated genomes are taken from the given
+    /// `alphabet`, which must not be empty.
+    pub fn new(genome_length: usize, alphabet: &str) -> Self {
+        CharValueEncodedGenomeBuilder {
+            genome_length,
+            alphabet: alphabet.chars().collect(),
+        }
+    }
+
+    /// Returns the characters the genomes are built of.
+    pub fn alphabet(&self) -> &[char] {
+        &self.alphabet
+    }
+}
+
+impl GenomeBuilder<Vec<char>> for CharValueEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Vec<char>
+    where
+        R: Rng + Sized,
+    {
+        (0..self.genome_length)
+            .map(|_| self.alphabet[random_index(rng, self.alphabet.len())])
+            .collect()
+    }
+}
+
+impl GenomeBuilder<String> for CharValueEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> String
+    where
+        R: Rng + Sized,
+    {
+        (0..self.genome_length)
+            .map(|_| self.alphabet[random_index(rng, self.alphabet.len())])
+            .collect()
+    }
+}
+
+/// A `GenomeBuilder` that builds permutation encoded `genetic::Genotype`s.
+///
+/// The default implementation builds `Vec<usize>` genomes, each of which is
+/// a random permutation of the values `0` to `genome_length - 1`.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PermutationEncodedGenomeBuilder {
+    genome_length: usize,
+}
+
+impl PermutationEncodedGenomeBuilder {
+    /// Returns a new instance of the `PermutationEncodedGenomeBuilder` that
+    /// builds permutations of length specified by the given `genome_length`.
+    pub fn new(genome_length: usize) -> Self {
+        PermutationEncodedGenomeBuilder { genome_length }
+    }
+}
+
+impl GenomeBuilder<Vec<usize>> for PermutationEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Vec<usize>
+    where
+        R: Rng + Sized,
+    {
+        let mut genome: Vec<usize> = (0..self.genome_length).collect();
+        genome.shuffle(rng);
+        genome
+    }
+}
+
+/// The genome length of the `BinaryEncodedGenomeBuilder` is ignored for
+/// `[bool; N]` genomes, which always have `N` genes.
+impl<const N: usize> GenomeBuilder<[bool; N]> for BinaryEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _index: usize, rng: &mut R) -> [bool; N]
+    where
+        R: Rng + Sized,
+    {
+        std::array::from_fn(|_| rng.gen())
+    }
+}
+
+/// The genome length of the `ValueEncodedGenomeBuilder` is ignored for
+/// `[V; N]` genomes, which always have `N` genes.
+impl<V, const N: usize> GenomeBuilder<[V; N]> for ValueEncodedGenomeBuilder<V>
+where
+    V: Clone + Debug + PartialEq + PartialOrd + SampleUniform + Send + Sync,
+{
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> [V; N]
+    where
+        R: Rng + Sized,
+    {
+        std::array::from_fn(|_| rng.gen_range(self.min_value.clone()..self.max_value.clone()))
+    }
+}
+
+/// The genome length of the `PermutationEncodedGenomeBuilder` is ignored for
+/// `[usize; N]` genomes, which are permutations of the values `0` to `N - 1`.
+impl<const N: usize> GenomeBuilder<[usize; N]> for PermutationEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> [usize; N]
+    where
+        R: Rng + Sized,
+    {
+        let mut genome: [usize; N] = std::array::from_fn(|index| index);
+        genome.shuffle(rng);
+        genome
+    }
+}
+
 #[cfg(feature = "fixedbitset")]
 mod fixedbitset_genome_builder {
-    use super::{BinaryEncodedGenomeBuilder, GenomeBuilder};
+    use super::{BinaryEncodedGenomeBuilder, GenomeBuilder, GenomeLength};
     use fixedbitset::FixedBitSet;
     use rand::Rng;
 
+    impl GenomeLength for FixedBitSet {
+        fn genome_length(&self) -> usize {
+            self.len()
+        }
+    }
+
     impl GenomeBuilder<FixedBitSet> for BinaryEncodedGenomeBuilder {
         fn build_genome<R>(&self, _index: usize, rng: &mut R) -> FixedBitSet
         where
@@ -390,11 +765,22 @@ mod fixedbitset_genome_builder {
 
 #[cfg(feature = "smallvec")]
 mod smallvec_genome_builder {
-    use super::{BinaryEncodedGenomeBuilder, GenomeBuilder, ValueEncodedGenomeBuilder};
+    use super::{
+        BinaryEncodedGenomeBuilder, GenomeBuilder, GenomeLength, ValueEncodedGenomeBuilder,
+    };
     use rand::{distributions::uniform::SampleUniform, Rng};
     use smallvec::{Array, SmallVec};
     use std::fmt::Debug;
 
+    impl<A> GenomeLength for SmallVec<A>
+    where
+        A: Array,
+    {
+        fn genome_length(&self) -> usize {
+            self.len()
+        }
+    }
+
     impl<A> GenomeBuilder<SmallVec<A>> for BinaryEncodedGenomeBuilder
     where
         A: Array<Item = bool> + Sync,
@@ -425,3 +811,130 @@ mod smallvec_genome_builder {
 
 #[cfg(test)]
 mod tests;
+
+/// A `GenomeBuilder` that builds random graphs of type
+/// `encoding::graph::Graph`.
+///
+/// The graphs have the given number of nodes. The degree of each node is
+/// chosen at random between a minimum and a maximum degree (both inclusive).
+/// If the degree bounds can not be satisfied, e.g. because the minimum degree
+/// is not smaller than the number of nodes, they are met as far as possible
+/// while the maximum degree is never exceeded.
+#[cfg(feature = "graph")]
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphEncodedGenomeBuilder {
+    num_nodes: usize,
+    min_degree: usize,
+    max_degree: usize,
+}
+
+#[cfg(feature = "graph")]
+impl GraphEncodedGenomeBuilder {
+    /// Returns a new instance of the `GraphEncodedGenomeBuilder` that builds
+    /// graphs with the given number of nodes whose degrees are between
+    /// `min_degree` and `max_degree` (both inclusive).
+    pub fn new(num_nodes: usize, min_degree: usize, max_degree: usize) -> Self {
+        GraphEncodedGenomeBuilder {
+            num_nodes,
+            min_degree,
+            max_degree: max_degree.max(min_degree),
+        }
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.num_nodes
+    }
+
+    pub fn min_degree(&self) -> usize {
+        self.min_degree
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+}
+
+#[cfg(feature = "graph")]
+impl GenomeBuilder<Graph> for GraphEncodedGenomeBuilder {
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Graph
+    where
+        R: Rng + Sized,
+    {
+        let mut graph = Graph::new(self.num_nodes);
+        let target_degrees: Vec<usize> = (0..self.num_nodes)
+            .map(|_| rng.gen_range(self.min_degree..=self.max_degree))
+            .collect();
+        let mut pairs: Vec<(usize, usize)> = (0..self.num_nodes)
+            .flat_map(|node1| (node1 + 1..self.num_nodes).map(move |node2| (node1, node2)))
+            .collect();
+        pairs.shuffle(rng);
+        // connect nodes as long as both are below their target degree
+        for &(node1, node2) in &pairs {
+            if graph.degree(node1) < target_degrees[node1]
+                && graph.degree(node2) < target_degrees[node2]
+            {
+                graph.add_edge(node1, node2);
+            }
+        }
+        // connect nodes below the minimum degree to any node below the
+        // maximum degree
+        for &(node1, node2) in &pairs {
+            if (graph.degree(node1) < self.min_degree || graph.degree(node2) < self.min_degree)
+                && graph.degree(node1) < self.max_degree
+                && graph.degree(node2) < self.max_degree
+            {
+                graph.add_edge(node1, node2);
+            }
+        }
+        graph
+    }
+}
+
+/// A `GenomeBuilder` that builds random matrices of type
+/// `encoding::matrix::Matrix`.
+///
+/// Each value of the matrix is picked uniformly at random from the given
+/// categories.
+#[cfg(feature = "matrix")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixEncodedGenomeBuilder<V> {
+    rows: usize,
+    cols: usize,
+    categories: Vec<V>,
+}
+
+#[cfg(feature = "matrix")]
+impl<V> MatrixEncodedGenomeBuilder<V> {
+    /// Returns a new instance of the `MatrixEncodedGenomeBuilder` that builds
+    /// matrices of the given size whose values are taken from the given
+    /// `categories`, which must not be empty.
+    pub fn new(rows: usize, cols: usize, categories: Vec<V>) -> Self {
+        MatrixEncodedGenomeBuilder {
+            rows,
+            cols,
+            categories,
+        }
+    }
+
+    /// Returns the values the matrices are built of.
+    pub fn categories(&self) -> &[V] {
+        &self.categories
+    }
+}
+
+#[cfg(feature = "matrix")]
+impl<V> GenomeBuilder<Matrix<V>> for MatrixEncodedGenomeBuilder<V>
+where
+    V: Clone + Debug + Send + Sync,
+{
+    fn build_genome<R>(&self, _: usize, rng: &mut R) -> Matrix<V>
+    where
+        R: Rng + Sized,
+    {
+        let values = (0..self.rows * self.cols)
+            .map(|_| self.categories[random_index(rng, self.categories.len())].clone())
+            .collect();
+        Matrix::from_values(self.rows, self.cols, values)
+    }
+}
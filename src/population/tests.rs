@@ -21,6 +21,7 @@ mod population_builder {
             let population: Population<Vec<bool>> = PopulationBuilder::build_population(
                 &BinaryEncodedGenomeBuilder::new(42),
                 size,
+                DEFAULT_SEQUENTIAL_THRESHOLD,
                 rng,
             );
 
@@ -37,6 +38,7 @@ mod population_builder {
             let population: Population<FixedBitSet> = PopulationBuilder::build_population(
                 &BinaryEncodedGenomeBuilder::new(42),
                 size,
+                DEFAULT_SEQUENTIAL_THRESHOLD,
                 rng,
             );
 
@@ -55,6 +57,7 @@ mod population_builder {
             let population: Population<SmallVec<[bool; GENOME_LEN]>> = PopulationBuilder::build_population(
                 &BinaryEncodedGenomeBuilder::new(GENOME_LEN),
                 size,
+                DEFAULT_SEQUENTIAL_THRESHOLD,
                 rng,
             );
 
@@ -62,3 +65,504 @@ mod population_builder {
         }
     }
 }
+
+mod sequential_threshold {
+
+    use super::*;
+
+    #[test]
+    fn population_built_with_a_seed_does_not_depend_on_the_threshold() {
+        let build = |sequential_threshold| {
+            build_population()
+                .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+                .of_size(1_000)
+                .with_sequential_threshold(sequential_threshold)
+                .using_seed([5; 32])
+        };
+
+        let sequential: Population<Vec<i32>> = build(1_000);
+        let chunked = build(16);
+        let unchunked = build(0);
+
+        assert_eq!(sequential, chunked);
+        assert_eq!(sequential, unchunked);
+    }
+
+    #[test]
+    fn genomes_are_built_in_the_order_of_their_index() {
+        #[derive(Clone, Debug)]
+        struct IndexGenomeBuilder;
+
+        impl GenomeBuilder<Vec<usize>> for IndexGenomeBuilder {
+            fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<usize>
+            where
+                R: Rng + Sized,
+            {
+                vec![index]
+            }
+        }
+
+        let population = build_population()
+            .with_genome_builder(IndexGenomeBuilder)
+            .of_size(500)
+            .with_sequential_threshold(7)
+            .using_seed([1; 32]);
+
+        let indices: Vec<usize> = population
+            .individuals()
+            .iter()
+            .map(|genome| genome[0])
+            .collect();
+        assert_eq!(indices, (0..500).collect::<Vec<_>>());
+    }
+}
+
+mod genome_length_validation {
+
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct ShrinkingGenomeBuilder;
+
+    impl GenomeBuilder<Vec<u8>> for ShrinkingGenomeBuilder {
+        fn build_genome<R>(&self, index: usize, _: &mut R) -> Vec<u8>
+        where
+            R: Rng + Sized,
+        {
+            vec![0; if index % 3 == 2 { 4 } else { 5 }]
+        }
+    }
+
+    #[test]
+    fn population_of_genomes_with_the_same_length_is_valid() {
+        let population = Population::try_with_individuals(vec![vec![1, 2], vec![3, 4]]);
+
+        assert_eq!(population.map(|population| population.size()), Ok(2));
+    }
+
+    #[test]
+    fn empty_population_is_valid() {
+        let population = Population::<Vec<u8>>::try_with_individuals(vec![]);
+
+        assert_eq!(population.map(|population| population.size()), Ok(0));
+    }
+
+    #[test]
+    fn reports_the_indices_of_genomes_with_a_different_length() {
+        let population = build_population()
+            .with_genome_builder(ShrinkingGenomeBuilder)
+            .of_size(8)
+            .try_using_seed([7; 32]);
+
+        assert_eq!(
+            population,
+            Err(PopulationError::InconsistentGenomeLengths {
+                expected_length: 5,
+                offending_indices: vec![2, 5],
+            })
+        );
+    }
+
+    #[test]
+    fn error_message_names_the_offending_indices() {
+        let error =
+            Population::try_with_individuals(vec![vec![1], vec![1, 2], vec![3]]).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "The genomes at the indices [1] do not have the length 1 of the first genome of \
+             the population"
+        );
+    }
+}
+
+mod char_value_encoded_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn builds_genomes_of_the_given_length_from_the_alphabet() {
+        let rng = get_rng(random_seed());
+
+        let population: Population<Vec<char>> = PopulationBuilder::build_population(
+            &CharValueEncodedGenomeBuilder::new(30, "ACGT"),
+            50,
+            DEFAULT_SEQUENTIAL_THRESHOLD,
+            rng,
+        );
+
+        for genome in population.individuals() {
+            expect_that!(&genome.len(), eq(30));
+            expect_that!(&genome.iter().all(|c| "ACGT".contains(*c)), eq(true));
+        }
+    }
+}
+
+mod recycling_allocator {
+
+    use super::allocator::{PopulationAllocator, RecyclingAllocator};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn released_buffers_are_reused() {
+        let allocator: RecyclingAllocator<Vec<u8>> = RecyclingAllocator::new(2);
+
+        let mut buffer = allocator.allocate(10);
+        buffer.push(vec![1]);
+        allocator.release(buffer);
+        let buffer = allocator.allocate(8);
+
+        expect_that!(&allocator.num_allocations(), eq(1));
+        expect_that!(&buffer.is_empty(), eq(true));
+        expect_that!(&buffer.capacity(), greater_than_or_equal(10));
+    }
+
+    #[test]
+    fn buffers_that_are_too_small_are_not_reused() {
+        let allocator: RecyclingAllocator<Vec<u8>> = RecyclingAllocator::new(2);
+
+        allocator.release(Vec::with_capacity(4));
+        let buffer = allocator.allocate(10);
+
+        expect_that!(&allocator.num_allocations(), eq(1));
+        expect_that!(&allocator.num_buffers(), eq(1));
+        expect_that!(&buffer.capacity(), greater_than_or_equal(10));
+    }
+
+    #[test]
+    fn keeps_at_most_max_buffers() {
+        let allocator: RecyclingAllocator<Vec<u8>> = RecyclingAllocator::new(2);
+
+        for _ in 0..3 {
+            allocator.release(Vec::with_capacity(4));
+        }
+
+        expect_that!(&allocator.num_buffers(), eq(2));
+    }
+}
+
+#[cfg(feature = "graph")]
+mod graph_encoded_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn builds_graphs_that_reach_the_minimum_degree_if_there_is_no_maximum(
+            num_nodes in 2usize..40,
+            min_degree in 0usize..2,
+        ) {
+            let rng = get_rng(random_seed());
+
+            let population: Population<Graph> = PopulationBuilder::build_population(
+                &GraphEncodedGenomeBuilder::new(num_nodes, min_degree, num_nodes - 1),
+                10,
+                DEFAULT_SEQUENTIAL_THRESHOLD,
+                rng,
+            );
+
+            for graph in population.individuals() {
+                for node in 0..num_nodes {
+                    prop_assert!(graph.degree(node) >= min_degree);
+                }
+            }
+        }
+
+        #[test]
+        fn builds_graphs_that_do_not_exceed_the_maximum_degree(
+            num_nodes in 6usize..40,
+            min_degree in 0usize..3,
+            extra_degree in 0usize..3,
+        ) {
+            let max_degree = min_degree + extra_degree;
+            let rng = get_rng(random_seed());
+
+            let population: Population<Graph> = PopulationBuilder::build_population(
+                &GraphEncodedGenomeBuilder::new(num_nodes, min_degree, max_degree),
+                10,
+                DEFAULT_SEQUENTIAL_THRESHOLD,
+                rng,
+            );
+
+            for graph in population.individuals() {
+                prop_assert_eq!(graph.num_nodes(), num_nodes);
+                for node in 0..num_nodes {
+                    prop_assert!(graph.degree(node) <= max_degree);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "matrix")]
+mod matrix_encoded_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn builds_matrices_of_the_given_size_from_the_categories() {
+        let genome: Matrix<u8> = MatrixEncodedGenomeBuilder::new(3, 7, vec![1, 5, 9])
+            .build_genome(0, &mut get_rng(random_seed()));
+
+        expect_that!(&genome.rows(), eq(3));
+        expect_that!(&genome.cols(), eq(7));
+        expect_that!(
+            &genome.values().iter().all(|v| [1, 5, 9].contains(v)),
+            eq(true)
+        );
+    }
+}
+
+mod hashed_population {
+
+    use super::*;
+    use crate::population::hashed::{
+        BinaryHasher, BucketHasher, CyclicPermutationHasher, ExactHasher, GenomeHasher,
+        HashedPopulation,
+    };
+    #[cfg(feature = "fixedbitset")]
+    use fixedbitset::FixedBitSet;
+
+    #[test]
+    fn leaves_out_equal_genomes() {
+        let population =
+            Population::with_individuals(vec![vec![1, 2], vec![2, 1], vec![1, 2], vec![3, 3]]);
+
+        let hashed = HashedPopulation::from_population(&population, ExactHasher);
+
+        assert_eq!(hashed.genomes(), &[vec![1, 2], vec![2, 1], vec![3, 3]]);
+        assert_eq!(hashed.index_of(&vec![3, 3]), Some(2));
+        assert!(!hashed.contains(&vec![3, 2]));
+    }
+
+    #[test]
+    fn insert_returns_whether_the_genome_has_been_inserted() {
+        let mut hashed = HashedPopulation::new(ExactHasher);
+
+        assert!(hashed.insert(vec!['a', 'b']));
+        assert!(!hashed.insert(vec!['a', 'b']));
+        assert_eq!(hashed.len(), 1);
+
+        hashed.clear();
+
+        assert!(hashed.is_empty());
+        assert!(hashed.insert(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn binary_hash_depends_on_the_number_of_bits() {
+        let hash1 = BinaryHasher.hash_genome(&vec![true, false, true]);
+        let hash2 = BinaryHasher.hash_genome(&vec![true, false, true, false]);
+
+        assert_ne!(hash1, hash2);
+    }
+
+    #[cfg(feature = "fixedbitset")]
+    #[test]
+    fn binary_hash_is_the_same_for_vec_of_bool_and_fixedbitset() {
+        let bits: Vec<bool> = (0..100).map(|bit| bit % 3 == 0).collect();
+        let mut bitset = FixedBitSet::with_capacity(100);
+        for (bit, value) in bits.iter().enumerate() {
+            bitset.set(bit, *value);
+        }
+
+        assert_eq!(
+            BinaryHasher.hash_genome(&bits),
+            BinaryHasher.hash_genome(&bitset)
+        );
+    }
+
+    #[test]
+    fn values_in_the_same_buckets_are_equivalent() {
+        let hasher = BucketHasher::new(0.1);
+        let mut hashed = HashedPopulation::new(hasher);
+
+        assert!(hashed.insert(vec![0.51, -1.22]));
+        assert!(!hashed.insert(vec![0.55, -1.29]));
+        assert!(hashed.insert(vec![0.61, -1.22]));
+        assert!(hashed.insert(vec![0.51]));
+    }
+
+    #[test]
+    fn rotated_and_reversed_tours_are_equivalent() {
+        let hasher = CyclicPermutationHasher;
+
+        assert_eq!(hasher.canonical_form(&[2, 3, 0, 1]), vec![0, 1, 2, 3]);
+        assert_eq!(hasher.canonical_form(&[1, 0, 3, 2]), vec![0, 1, 2, 3]);
+        assert_eq!(
+            hasher.hash_genome(&vec![3, 1, 4, 0, 2]),
+            hasher.hash_genome(&vec![2, 0, 4, 1, 3])
+        );
+        assert!(hasher.equivalent(&vec![3, 1, 4, 0, 2], &vec![4, 1, 3, 2, 0]));
+        assert!(!hasher.equivalent(&vec![0, 1, 2, 3], &vec![0, 2, 1, 3]));
+    }
+}
+
+mod population_conversions {
+
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn population_is_collected_from_an_iterator() {
+        let population: Population<Vec<i32>> = (0..3).map(|value| vec![value; 2]).collect();
+
+        expect_that!(
+            &population.into_individuals(),
+            eq(vec![vec![0, 0], vec![1, 1], vec![2, 2]])
+        );
+    }
+
+    #[test]
+    fn population_converts_from_and_into_a_vec() {
+        let individuals = vec![vec![1, 2], vec![3, 4]];
+
+        let population = Population::from(individuals.clone());
+        expect_that!(
+            &population,
+            eq(Population::with_individuals(individuals.clone()))
+        );
+
+        let converted: Vec<Vec<i32>> = population.into();
+        expect_that!(&converted, eq(individuals));
+    }
+
+    #[test]
+    fn population_is_extended_by_an_iterator() {
+        let mut population = Population::with_individuals(vec![vec![1]]);
+
+        population.extend(vec![vec![2], vec![3]]);
+
+        expect_that!(&population.size(), eq(3));
+        expect_that!(&population.individuals()[2], eq(vec![3]));
+    }
+
+    #[test]
+    fn population_is_iterated_by_value_and_by_reference() {
+        let population = Population::with_individuals(vec![vec![1, 2], vec![3, 4]]);
+
+        let sums: Vec<i32> = (&population)
+            .into_iter()
+            .map(|genome| genome.iter().sum())
+            .collect();
+        expect_that!(&sums, eq(vec![3, 7]));
+
+        let doubled: Population<Vec<i32>> = population
+            .into_iter()
+            .map(|genome| genome.iter().map(|value| value * 2).collect())
+            .collect();
+        expect_that!(
+            &doubled.into_individuals(),
+            eq(vec![vec![2, 4], vec![6, 8]])
+        );
+    }
+
+    #[test]
+    fn individuals_are_mutated_in_place() {
+        let mut population = Population::with_individuals(vec![vec![1, 2], vec![3, 4]]);
+
+        for genome in population.individuals_mut() {
+            genome.reverse();
+        }
+
+        expect_that!(
+            &population.into_individuals(),
+            eq(vec![vec![2, 1], vec![4, 3]])
+        );
+    }
+}
+
+mod array_genome_builders {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn value_encoded_genome_builder_builds_arrays_of_values_in_range() {
+        let population: Population<[i32; 5]> = PopulationBuilder::build_population(
+            &ValueEncodedGenomeBuilder::new(5, -3, 4),
+            20,
+            DEFAULT_SEQUENTIAL_THRESHOLD,
+            get_rng(random_seed()),
+        );
+
+        for genome in population.individuals() {
+            expect_that!(&genome.genome_length(), eq(5));
+            expect_that!(
+                &genome.iter().all(|value| (-3..4).contains(value)),
+                eq(true)
+            );
+        }
+    }
+
+    #[test]
+    fn permutation_encoded_genome_builder_builds_permuted_arrays() {
+        let mut genome: [usize; 6] =
+            PermutationEncodedGenomeBuilder::new(6).build_genome(0, &mut get_rng(random_seed()));
+
+        genome.sort_unstable();
+        expect_that!(&genome, eq([0, 1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn binary_encoded_genome_builder_builds_bool_arrays() {
+        let population: Population<[bool; 16]> = PopulationBuilder::build_population(
+            &BinaryEncodedGenomeBuilder::new(16),
+            10,
+            DEFAULT_SEQUENTIAL_THRESHOLD,
+            get_rng(random_seed()),
+        );
+
+        expect_that!(&population.size(), eq(10));
+    }
+}
+
+mod string_genome_builder {
+
+    use super::*;
+    use crate::random::{get_rng, random_seed};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn builds_strings_of_the_given_number_of_characters() {
+        let population: Population<String> = PopulationBuilder::build_population(
+            &CharValueEncodedGenomeBuilder::new(12, "äöü€"),
+            20,
+            DEFAULT_SEQUENTIAL_THRESHOLD,
+            get_rng(random_seed()),
+        );
+
+        for genome in population.individuals() {
+            expect_that!(&genome.genome_length(), eq(12));
+            expect_that!(&genome.chars().all(|c| "äöü€".contains(c)), eq(true));
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod population_serialization {
+
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn population_can_be_serialized_and_deserialized() {
+        let population: Population<Vec<u8>> = build_population()
+            .with_genome_builder(ValueEncodedGenomeBuilder::new(4, 0, 10))
+            .of_size(5)
+            .using_seed([3; 32]);
+
+        let json = serde_json::to_string(&population).unwrap();
+        let loaded: Population<Vec<u8>> = serde_json::from_str(&json).unwrap();
+
+        expect_that!(&loaded, eq(population));
+    }
+}
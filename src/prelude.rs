@@ -1,26 +1,66 @@
+//! The `prelude` re-exports the items that are commonly used to set up and
+//! run a genetic algorithm, so that they can be imported with a single
+//! `use genevo::prelude::*;`.
+//!
+//! This includes all operators of `operator::prelude`. The formatting traits
+//! of `types::fmt` are imported anonymously, so that e.g. durations can be
+//! formatted without shadowing `std::fmt::Display`.
+
 // Genetic
 //
-pub use crate::genetic::{Fitness, FitnessFunction, Genotype, Phenotype};
+pub use crate::genetic::{
+    AsScalar, Children, EvalError, Fitness, FitnessFunction, Genotype, Offspring, Pair, Parents,
+    Phenotype, Segment, TracedChild, TracedChildren, TryFitnessFunction,
+};
 
 // Algorithm
 //
 pub use crate::{
-    algorithm::Algorithm,
-    ga::{genetic_algorithm, GeneticAlgorithm},
+    algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation, HallOfFame, TieBreaking},
+    ga::{
+        builder::GeneticAlgorithmBuilder,
+        genetic_algorithm,
+        presets::{self, evolve, Evolution},
+        rank_candidates, EvaluationErrorPolicy, GeneticAlgorithm,
+    },
     random::{Prng, Rng, Seed},
+    types::{FitnessF32, FitnessF64},
+};
+
+// Operators
+//
+pub use crate::operator::{
+    prelude::*, CrossoverOp, FixerOp, GenerationAware, GenerationFeedback, GeneticOperator,
+    LocalSearchOp, MutationOp, ParentDemand, ReinsertionOp, SelectionOp, TunableOperator,
 };
 
+// Multi-objective optimization
+//
+#[cfg(feature = "multi-objective")]
+pub use crate::pareto::Pareto;
+
 // Population
 //
-pub use crate::population::{build_population, GenomeBuilder, Population};
+pub use crate::population::{
+    build_population, BinaryEncodedGenomeBuilder, GenomeBuilder, GenomeLength,
+    PermutationEncodedGenomeBuilder, Population, PopulationError, ValueEncodedGenomeBuilder,
+};
 
 // Simulation
 //
-pub use crate::simulation::{simulator::simulate, SimResult, Simulation, SimulationBuilder};
+pub use crate::simulation::{
+    simulator::{simulate, SimError, Simulator},
+    SimResult, Simulation, SimulationBuilder, SimulationController,
+};
 
 // Termination
 //
 pub use crate::termination::{
     combinator::{and, or, And, Or},
     limit::*,
+    StopFlag, StopReason, Termination,
 };
+
+// Formatting
+//
+pub use crate::types::fmt::{Display as _, DisplayDuration as _};
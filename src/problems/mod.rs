@@ -0,0 +1,15 @@
+//! The `problems` module provides complete setups for well known optimization
+//! problems. Each problem comes with its `genetic::FitnessFunction`, the
+//! `population::GenomeBuilder` for its encoding and a `ga::presets::Preset`
+//! of operators that are suited for the problem.
+//!
+//! The problems serve as ready to use solvers as well as examples of how to
+//! assemble the building blocks of this crate for a concrete use case.
+//!
+//! The provided problems are:
+//! * `tsp` - the traveling salesman problem
+
+pub mod tsp;
+
+#[cfg(test)]
+mod tests;
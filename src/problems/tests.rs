@@ -0,0 +1,247 @@
+use super::tsp::*;
+use crate::{
+    ga::presets::evolve, genetic::FitnessFunction, population::GenomeBuilder, random::get_rng,
+    termination::limit::GenerationLimit,
+};
+use galvanic_assert::matchers::*;
+
+mod tsp {
+
+    use super::*;
+
+    /// The corners of a rectangle of 4 by 3 and a city in the middle of its
+    /// bottom edge.
+    fn cities() -> Vec<(f64, f64)> {
+        vec![(0., 0.), (0., 3.), (4., 3.), (4., 0.), (2., 0.)]
+    }
+
+    #[test]
+    fn distances_from_coordinates_are_euclidean() {
+        let distances = DistanceMatrix::from_coordinates(&cities());
+
+        expect_that!(&distances.num_cities(), eq(5));
+        expect_that!(&distances.distance(0, 2), eq(5.));
+        expect_that!(&distances.distance(2, 0), eq(5.));
+        expect_that!(&distances.distance(3, 3), eq(0.));
+    }
+
+    #[test]
+    fn tour_length_includes_the_way_back_to_the_first_city() {
+        let distances = DistanceMatrix::from_coordinates(&cities());
+
+        expect_that!(&distances.tour_length(&[0, 1, 2, 3, 4]), eq(14.));
+        expect_that!(&distances.tour_length(&[0, 2, 1, 3, 4]), eq(18.));
+        expect_that!(&distances.tour_length(&[]), eq(0.));
+    }
+
+    #[test]
+    fn distances_from_rows_may_be_asymmetric() {
+        let distances =
+            DistanceMatrix::from_rows(vec![vec![0., 1., 2.], vec![3., 0., 4.], vec![5., 6., 0.]])
+                .unwrap();
+
+        expect_that!(&distances.distance(0, 1), eq(1.));
+        expect_that!(&distances.distance(1, 0), eq(3.));
+        expect_that!(&distances.tour_length(&[0, 1, 2]), eq(10.));
+        expect_that!(&distances.tour_length(&[0, 2, 1]), eq(11.));
+    }
+
+    #[test]
+    fn distances_from_rows_must_form_a_square_matrix() {
+        let result = DistanceMatrix::from_rows(vec![vec![0., 1.], vec![1.]]);
+
+        expect_that!(
+            &result,
+            eq(Err(TspError::InvalidDistanceMatrix(
+                "the distance matrix must be square, but row 1 has 1 instead of 2 columns."
+                    .to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn distances_from_rows_must_not_be_negative() {
+        let result = DistanceMatrix::from_rows(vec![vec![0., -1.], vec![1., 0.]]);
+
+        expect_that!(&result.is_err(), eq(true));
+    }
+
+    #[test]
+    fn shorter_tours_have_a_higher_fitness() {
+        let tour_length = TourLength::new(DistanceMatrix::from_coordinates(&cities()));
+
+        let short = tour_length.fitness_of(&vec![0, 1, 2, 3, 4]);
+        let long = tour_length.fitness_of(&vec![0, 2, 1, 3, 4]);
+
+        expect_that!(&short, eq(-14_000));
+        expect_that!(&short, greater_than(long));
+        expect_that!(&tour_length.tour_length_of_fitness(short), eq(14.));
+        expect_that!(
+            &long,
+            greater_than_or_equal(tour_length.lowest_possible_fitness())
+        );
+    }
+
+    #[test]
+    fn genome_builder_builds_tours_through_all_cities() {
+        let tour_length = TourLength::new(DistanceMatrix::from_coordinates(&cities()));
+
+        let mut tour: Vec<usize> = tour_length
+            .genome_builder()
+            .build_genome(0, &mut get_rng([5; 32]));
+
+        tour.sort_unstable();
+        expect_that!(&tour, eq(vec![0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn preset_finds_the_shortest_tour_of_a_small_instance() {
+        let tour_length = TourLength::new(DistanceMatrix::from_coordinates(&cities()));
+
+        let evolution = evolve(
+            tour_length.clone(),
+            preset(5).with_population_size(30).with_seed([3; 32]),
+            GenerationLimit::new(100),
+        )
+        .unwrap();
+
+        let best_tour = evolution.best_solution.solution.genome;
+        expect_that!(&tour_length.tour_length(&best_tour), eq(14.));
+    }
+}
+
+#[cfg(feature = "tsplib")]
+mod tsplib {
+
+    use super::*;
+
+    #[test]
+    fn parses_node_coordinates_with_euclidean_distances() {
+        let instance = parse_tsplib(
+            "NAME : square\n\
+             COMMENT : four cities\n\
+             TYPE : TSP\n\
+             DIMENSION : 4\n\
+             EDGE_WEIGHT_TYPE : EUC_2D\n\
+             NODE_COORD_SECTION\n\
+             1 0 0\n\
+             2 0 3\n\
+             3 4.2 3\n\
+             4 4 0\n\
+             EOF\n",
+        )
+        .unwrap();
+
+        expect_that!(&instance.name, eq("square".to_string()));
+        expect_that!(&instance.comment, eq("four cities".to_string()));
+        expect_that!(&instance.distances.num_cities(), eq(4));
+        expect_that!(&instance.distances.distance(0, 1), eq(3.));
+        expect_that!(&instance.distances.distance(0, 2), eq(5.));
+        expect_that!(&instance.distances.distance(1, 2), eq(4.));
+    }
+
+    #[test]
+    fn parses_explicit_edge_weights_of_the_upper_triangle() {
+        let instance = parse_tsplib(
+            "NAME: triangle\n\
+             TYPE: TSP\n\
+             DIMENSION: 3\n\
+             EDGE_WEIGHT_TYPE: EXPLICIT\n\
+             EDGE_WEIGHT_FORMAT: UPPER_ROW\n\
+             EDGE_WEIGHT_SECTION\n\
+             7 9\n\
+             11\n",
+        )
+        .unwrap();
+
+        expect_that!(&instance.distances.distance(0, 1), eq(7.));
+        expect_that!(&instance.distances.distance(2, 0), eq(9.));
+        expect_that!(&instance.distances.distance(1, 2), eq(11.));
+        expect_that!(&instance.distances.tour_length(&[0, 1, 2]), eq(27.));
+    }
+
+    #[test]
+    fn parses_explicit_edge_weights_of_the_lower_triangle_with_diagonal() {
+        let instance = parse_tsplib(
+            "TYPE: TSP\n\
+             DIMENSION: 3\n\
+             EDGE_WEIGHT_TYPE: EXPLICIT\n\
+             EDGE_WEIGHT_FORMAT: LOWER_DIAG_ROW\n\
+             EDGE_WEIGHT_SECTION\n\
+             0 7 0 9 11 0\n\
+             EOF\n",
+        )
+        .unwrap();
+
+        expect_that!(&instance.distances.distance(0, 1), eq(7.));
+        expect_that!(&instance.distances.distance(0, 2), eq(9.));
+        expect_that!(&instance.distances.distance(2, 1), eq(11.));
+    }
+
+    #[test]
+    fn pseudo_euclidean_distances_are_rounded_up() {
+        let instance = parse_tsplib(
+            "TYPE: TSP\n\
+             DIMENSION: 2\n\
+             EDGE_WEIGHT_TYPE: ATT\n\
+             NODE_COORD_SECTION\n\
+             1 0 0\n\
+             2 30 40\n",
+        )
+        .unwrap();
+
+        // sqrt((30² + 40²) / 10) = 15.81
+        expect_that!(&instance.distances.distance(0, 1), eq(16.));
+    }
+
+    #[test]
+    fn unsupported_edge_weight_type_is_an_error() {
+        let result = parse_tsplib(
+            "TYPE: TSP\n\
+             DIMENSION: 2\n\
+             EDGE_WEIGHT_TYPE: EUC_3D\n\
+             NODE_COORD_SECTION\n\
+             1 0 0\n\
+             2 1 1\n",
+        );
+
+        expect_that!(
+            &result,
+            eq(Err(TspError::InvalidTsplib(
+                "the edge weight type EUC_3D is not supported.".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn number_of_edge_weights_must_match_the_dimension() {
+        let result = parse_tsplib(
+            "TYPE: TSP\n\
+             DIMENSION: 3\n\
+             EDGE_WEIGHT_TYPE: EXPLICIT\n\
+             EDGE_WEIGHT_FORMAT: FULL_MATRIX\n\
+             EDGE_WEIGHT_SECTION\n\
+             0 1 2\n\
+             1 0 3\n",
+        );
+
+        expect_that!(
+            &result,
+            eq(Err(TspError::InvalidTsplib(
+                "expected 9 edge weights, but found 6.".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        let result = load_tsplib("does/not/exist.tsp");
+
+        match result {
+            Err(TspError::Io(details)) => {
+                expect_that!(&details.contains("does/not/exist.tsp"), eq(true));
+            },
+            other => panic!("expected an I/O error, but got {:?}", other),
+        }
+    }
+}
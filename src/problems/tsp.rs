@@ -0,0 +1,526 @@
+//! The `tsp` module provides the setup for solving the
+//! [traveling salesman problem](https://en.wikipedia.org/wiki/Travelling_salesman_problem)
+//! (TSP): find the shortest round trip that visits each of a number of
+//! cities exactly once.
+//!
+//! A tour is encoded as a permutation of the indices of the cities, i.e. as
+//! a genome of type `Vec<usize>`. The distances between the cities are given
+//! by a `DistanceMatrix`, which may be asymmetric. The `TourLength` fitness
+//! function evaluates a tour by its length, where shorter tours are fitter.
+//!
+//! The `preset` bundles the operators that work well for the TSP: the
+//! `EdgeRecombinationCrossover` preserves the edges of the parent tours and
+//! the `InversionMutator` reverses sections of a tour. The reinsertion is
+//! elitist, so that the best tour found is never lost.
+//!
+//! With the crate feature `tsplib` enabled, instances in the format of the
+//! [TSPLIB](http://comopt.ifi.uni-heidelberg.de/software/TSPLIB95/) can be
+//! loaded by `load_tsplib` or parsed by `parse_tsplib`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{
+//!     ga::presets::evolve,
+//!     prelude::*,
+//!     problems::tsp::{self, DistanceMatrix, TourLength},
+//! };
+//!
+//! let cities = [(0., 0.), (0., 3.), (4., 3.), (4., 0.), (2., -1.)];
+//! let tour_length = TourLength::new(DistanceMatrix::from_coordinates(&cities));
+//!
+//! let evolution = evolve(
+//!     tour_length.clone(),
+//!     tsp::preset(cities.len()).with_population_size(20).with_seed([42; 32]),
+//!     GenerationLimit::new(50),
+//! )
+//! .unwrap();
+//!
+//! let best_tour = evolution.best_solution.solution.genome;
+//! assert_eq!(best_tour.len(), cities.len());
+//! assert!(tour_length.tour_length(&best_tour) < 15.);
+//! ```
+
+use crate::{
+    ga::presets::{one_gene_per_genome, Preset, PresetAlgorithm},
+    genetic::FitnessFunction,
+    mutation::order::InversionMutator,
+    population::PermutationEncodedGenomeBuilder,
+    recombination::order::EdgeRecombinationCrossover,
+    selection::tournament::TournamentSelector,
+};
+use std::{
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+/// The fitness of a tour is its negated length multiplied by this factor and
+/// rounded to an integer.
+pub const FITNESS_PRECISION: f64 = 1_000.;
+
+/// An error that can occur when setting up a traveling salesman problem.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TspError {
+    /// The given distances do not form a valid distance matrix.
+    InvalidDistanceMatrix(String),
+    /// The content of a TSPLIB file is not valid or not supported.
+    InvalidTsplib(String),
+    /// A TSPLIB file can not be read.
+    Io(String),
+}
+
+impl Display for TspError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TspError::InvalidDistanceMatrix(details) => write!(f, "{}", details),
+            TspError::InvalidTsplib(details) => write!(f, "{}", details),
+            TspError::Io(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for TspError {}
+
+/// The `DistanceMatrix` holds the distance from each city to each other city.
+/// The distance from city `a` to city `b` may differ from the distance from
+/// `b` to `a`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceMatrix {
+    num_cities: usize,
+    distances: Vec<f64>,
+}
+
+impl DistanceMatrix {
+    /// Constructs a new `DistanceMatrix` for the given number of cities, that
+    /// calls the given function with the indices of two cities to calculate
+    /// the distance between them.
+    pub fn from_fn<D>(num_cities: usize, distance: D) -> Self
+    where
+        D: Fn(usize, usize) -> f64,
+    {
+        let mut distances = Vec::with_capacity(num_cities * num_cities);
+        for from in 0..num_cities {
+            for to in 0..num_cities {
+                distances.push(if from == to { 0. } else { distance(from, to) });
+            }
+        }
+        DistanceMatrix {
+            num_cities,
+            distances,
+        }
+    }
+
+    /// Constructs a new `DistanceMatrix` of the euclidean distances between
+    /// cities at the given coordinates.
+    pub fn from_coordinates(coordinates: &[(f64, f64)]) -> Self {
+        DistanceMatrix::from_fn(coordinates.len(), |from, to| {
+            let (x1, y1) = coordinates[from];
+            let (x2, y2) = coordinates[to];
+            (x2 - x1).hypot(y2 - y1)
+        })
+    }
+
+    /// Constructs a new `DistanceMatrix` from the given rows, where the
+    /// value at column `b` of row `a` is the distance from city `a` to city
+    /// `b`.
+    ///
+    /// Returns an error if the rows do not form a square matrix or if any
+    /// distance is negative or not finite.
+    pub fn from_rows(rows: Vec<Vec<f64>>) -> Result<Self, TspError> {
+        let num_cities = rows.len();
+        let mut distances = Vec::with_capacity(num_cities * num_cities);
+        for (from, row) in rows.into_iter().enumerate() {
+            if row.len() != num_cities {
+                return Err(TspError::InvalidDistanceMatrix(format!(
+                    "the distance matrix must be square, but row {} has {} instead of {} columns.",
+                    from,
+                    row.len(),
+                    num_cities
+                )));
+            }
+            if let Some(to) = row
+                .iter()
+                .position(|value| !value.is_finite() || *value < 0.)
+            {
+                return Err(TspError::InvalidDistanceMatrix(format!(
+                    "distances must be non-negative finite numbers, but the distance from {} to {} is {}.",
+                    from, to, row[to]
+                )));
+            }
+            distances.extend(row);
+        }
+        Ok(DistanceMatrix {
+            num_cities,
+            distances,
+        })
+    }
+
+    /// Returns the number of cities.
+    pub fn num_cities(&self) -> usize {
+        self.num_cities
+    }
+
+    /// Returns the distance from the city `from` to the city `to`.
+    pub fn distance(&self, from: usize, to: usize) -> f64 {
+        self.distances[from * self.num_cities + to]
+    }
+
+    /// Returns the length of the round trip that visits the cities in the
+    /// order of the given tour and returns from the last city to the first.
+    pub fn tour_length(&self, tour: &[usize]) -> f64 {
+        match (tour.first(), tour.last()) {
+            (Some(first), Some(last)) => {
+                tour.windows(2)
+                    .map(|leg| self.distance(leg[0], leg[1]))
+                    .sum::<f64>()
+                    + self.distance(*last, *first)
+            },
+            _ => 0.,
+        }
+    }
+}
+
+/// The `TourLength` is the `genetic::FitnessFunction` of the traveling
+/// salesman problem. The fitness of a tour is its negated length in units of
+/// 1 / `FITNESS_PRECISION`, so that shorter tours have a higher fitness.
+///
+/// The `DistanceMatrix` is shared between the clones of a `TourLength`, so
+/// cloning it is cheap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TourLength {
+    distances: Arc<DistanceMatrix>,
+    lowest_fitness: i64,
+}
+
+impl TourLength {
+    /// Constructs a new `TourLength` for the cities of the given
+    /// `DistanceMatrix`.
+    pub fn new(distances: DistanceMatrix) -> Self {
+        // no tour is longer than leaving every city by its longest edge
+        let longest_tour: f64 = (0..distances.num_cities())
+            .map(|from| {
+                (0..distances.num_cities())
+                    .map(|to| distances.distance(from, to))
+                    .fold(0., f64::max)
+            })
+            .sum();
+        TourLength {
+            distances: Arc::new(distances),
+            lowest_fitness: to_fitness(longest_tour),
+        }
+    }
+
+    /// Returns the `DistanceMatrix` of the cities.
+    pub fn distances(&self) -> &DistanceMatrix {
+        &self.distances
+    }
+
+    /// Returns the number of cities.
+    pub fn num_cities(&self) -> usize {
+        self.distances.num_cities()
+    }
+
+    /// Returns the length of the given tour.
+    pub fn tour_length(&self, tour: &[usize]) -> f64 {
+        self.distances.tour_length(tour)
+    }
+
+    /// Returns the tour length that corresponds to the given fitness value.
+    pub fn tour_length_of_fitness(&self, fitness: i64) -> f64 {
+        -(fitness as f64) / FITNESS_PRECISION
+    }
+
+    /// Returns the `population::GenomeBuilder` that builds random tours
+    /// through all cities.
+    pub fn genome_builder(&self) -> PermutationEncodedGenomeBuilder {
+        PermutationEncodedGenomeBuilder::new(self.num_cities())
+    }
+}
+
+fn to_fitness(tour_length: f64) -> i64 {
+    -(tour_length * FITNESS_PRECISION).round() as i64
+}
+
+impl FitnessFunction<Vec<usize>, i64> for TourLength {
+    fn fitness_of(&self, tour: &Vec<usize>) -> i64 {
+        to_fitness(self.tour_length(tour))
+    }
+
+    fn average(&self, fitness_values: &[i64]) -> i64 {
+        if fitness_values.is_empty() {
+            return 0;
+        }
+        let sum: i128 = fitness_values.iter().map(|value| *value as i128).sum();
+        (sum / fitness_values.len() as i128) as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        self.lowest_fitness
+    }
+}
+
+/// The `Preset` for the traveling salesman problem returned by `preset`.
+pub type TspPreset = Preset<
+    Vec<usize>,
+    PermutationEncodedGenomeBuilder,
+    TournamentSelector,
+    EdgeRecombinationCrossover,
+    InversionMutator,
+>;
+
+/// The `GeneticAlgorithm` built from a `TspPreset` evaluated by the
+/// `TourLength`.
+pub type TspAlgorithm = PresetAlgorithm<
+    Vec<usize>,
+    i64,
+    TourLength,
+    TournamentSelector,
+    EdgeRecombinationCrossover,
+    InversionMutator,
+>;
+
+/// Returns a `Preset` for the traveling salesman problem with the given
+/// number of cities.
+///
+/// The offspring is bred by the edge recombination crossover and mutated by
+/// one inversion per tour on average.
+pub fn preset(num_cities: usize) -> TspPreset {
+    Preset::new(
+        PermutationEncodedGenomeBuilder::new(num_cities),
+        EdgeRecombinationCrossover::new(),
+        InversionMutator::new(one_gene_per_genome(num_cities)),
+    )
+}
+
+#[cfg(feature = "tsplib")]
+pub use self::tsplib::{load_tsplib, parse_tsplib, TsplibInstance};
+
+#[cfg(feature = "tsplib")]
+mod tsplib {
+    use super::{DistanceMatrix, TspError};
+    use std::{collections::HashMap, fs, path::Path};
+
+    /// A `TsplibInstance` is a traveling salesman problem read from a file in
+    /// the TSPLIB format.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct TsplibInstance {
+        /// The name of the instance.
+        pub name: String,
+        /// The comment of the instance, or an empty string.
+        pub comment: String,
+        /// The distances between the cities of the instance.
+        pub distances: DistanceMatrix,
+    }
+
+    /// Loads a `TsplibInstance` from the file at the given path.
+    ///
+    /// See `parse_tsplib` for the supported content.
+    pub fn load_tsplib<P>(path: P) -> Result<TsplibInstance, TspError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|error| {
+            TspError::Io(format!(
+                "can not read TSPLIB file {}: {}",
+                path.display(),
+                error
+            ))
+        })?;
+        parse_tsplib(&text)
+    }
+
+    /// Parses a `TsplibInstance` from the given content of a TSPLIB file.
+    ///
+    /// Instances of the types `TSP` and `ATSP` are supported with the edge
+    /// weight types `EUC_2D`, `CEIL_2D`, `ATT`, `GEO` and `EXPLICIT`. Explicit
+    /// edge weights can be given in any of the matrix formats `FULL_MATRIX`,
+    /// `UPPER_ROW`, `LOWER_ROW`, `UPPER_DIAG_ROW`, `LOWER_DIAG_ROW` and their
+    /// column-wise counterparts. The distances are calculated as defined by
+    /// TSPLIB, including the rounding to integers.
+    pub fn parse_tsplib(text: &str) -> Result<TsplibInstance, TspError> {
+        let mut specification: HashMap<String, String> = HashMap::new();
+        let mut section: Option<String> = None;
+        let mut coordinates: Vec<(f64, f64)> = Vec::new();
+        let mut edge_weights: Vec<f64> = Vec::new();
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if line == "EOF" {
+                break;
+            }
+            if line.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                match line.split_once(':') {
+                    Some((key, value)) => {
+                        specification.insert(key.trim().to_string(), value.trim().to_string());
+                        section = None;
+                    },
+                    None => section = Some(line.to_string()),
+                }
+                continue;
+            }
+            let values = line
+                .split_whitespace()
+                .map(|value| {
+                    value
+                        .parse::<f64>()
+                        .map_err(|_| invalid(format!("the value {} is not a number.", value)))
+                })
+                .collect::<Result<Vec<f64>, TspError>>()?;
+            match section.as_deref() {
+                Some("NODE_COORD_SECTION") => match values[..] {
+                    [_, x, y] => coordinates.push((x, y)),
+                    _ => {
+                        return Err(invalid(format!(
+                            "a node coordinate must consist of the node number and 2 coordinates, but is {}.",
+                            line
+                        )))
+                    },
+                },
+                Some("EDGE_WEIGHT_SECTION") => edge_weights.extend(values),
+                _ => {},
+            }
+        }
+
+        let value_of = |key: &str| specification.get(key).map(String::as_str);
+        match value_of("TYPE") {
+            Some("TSP") | Some("ATSP") => {},
+            other => {
+                return Err(invalid(format!(
+                    "only instances of the types TSP and ATSP are supported, but the type is {}.",
+                    other.unwrap_or("missing")
+                )))
+            },
+        }
+        let dimension: usize = value_of("DIMENSION")
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| invalid("the DIMENSION must be a positive number.".to_string()))?;
+        let distances = match value_of("EDGE_WEIGHT_TYPE") {
+            Some("EXPLICIT") => explicit_distances(
+                dimension,
+                value_of("EDGE_WEIGHT_FORMAT").unwrap_or("FULL_MATRIX"),
+                &edge_weights,
+            )?,
+            Some(edge_weight_type) => {
+                let distance: fn((f64, f64), (f64, f64)) -> f64 = match edge_weight_type {
+                    "EUC_2D" => |from, to| nint(euclidean(from, to)),
+                    "CEIL_2D" => |from, to| euclidean(from, to).ceil(),
+                    "ATT" => pseudo_euclidean,
+                    "GEO" => geographical,
+                    _ => {
+                        return Err(invalid(format!(
+                            "the edge weight type {} is not supported.",
+                            edge_weight_type
+                        )))
+                    },
+                };
+                if coordinates.len() != dimension {
+                    return Err(invalid(format!(
+                        "expected {} node coordinates, but found {}.",
+                        dimension,
+                        coordinates.len()
+                    )));
+                }
+                DistanceMatrix::from_fn(dimension, |from, to| {
+                    distance(coordinates[from], coordinates[to])
+                })
+            },
+            None => return Err(invalid("the EDGE_WEIGHT_TYPE is missing.".to_string())),
+        };
+        Ok(TsplibInstance {
+            name: value_of("NAME").unwrap_or_default().to_string(),
+            comment: value_of("COMMENT").unwrap_or_default().to_string(),
+            distances,
+        })
+    }
+
+    fn invalid(details: String) -> TspError {
+        TspError::InvalidTsplib(details)
+    }
+
+    fn explicit_distances(
+        dimension: usize,
+        format: &str,
+        weights: &[f64],
+    ) -> Result<DistanceMatrix, TspError> {
+        // the cells of the matrix in the order they are listed, where the
+        // column-wise formats list the transposed cells of the row-wise ones
+        let cells: Vec<(usize, usize)> = match format {
+            "FULL_MATRIX" => (0..dimension)
+                .flat_map(|row| (0..dimension).map(move |column| (row, column)))
+                .collect(),
+            "UPPER_ROW" | "LOWER_COL" => (0..dimension)
+                .flat_map(|row| (row + 1..dimension).map(move |column| (row, column)))
+                .collect(),
+            "LOWER_ROW" | "UPPER_COL" => (0..dimension)
+                .flat_map(|row| (0..row).map(move |column| (row, column)))
+                .collect(),
+            "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => (0..dimension)
+                .flat_map(|row| (row..dimension).map(move |column| (row, column)))
+                .collect(),
+            "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => (0..dimension)
+                .flat_map(|row| (0..=row).map(move |column| (row, column)))
+                .collect(),
+            _ => {
+                return Err(invalid(format!(
+                    "the edge weight format {} is not supported.",
+                    format
+                )))
+            },
+        };
+        if weights.len() != cells.len() {
+            return Err(invalid(format!(
+                "expected {} edge weights, but found {}.",
+                cells.len(),
+                weights.len()
+            )));
+        }
+        let mut rows = vec![vec![0.; dimension]; dimension];
+        for ((row, column), weight) in cells.into_iter().zip(weights) {
+            rows[row][column] = *weight;
+            if format != "FULL_MATRIX" {
+                rows[column][row] = *weight;
+            }
+        }
+        DistanceMatrix::from_rows(rows).map_err(|error| invalid(error.to_string()))
+    }
+
+    /// Rounds to the nearest integer as defined by TSPLIB.
+    fn nint(value: f64) -> f64 {
+        (value + 0.5).floor()
+    }
+
+    fn euclidean(from: (f64, f64), to: (f64, f64)) -> f64 {
+        (to.0 - from.0).hypot(to.1 - from.1)
+    }
+
+    fn pseudo_euclidean(from: (f64, f64), to: (f64, f64)) -> f64 {
+        let distance = (((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)) / 10.).sqrt();
+        let rounded = nint(distance);
+        if rounded < distance {
+            rounded + 1.
+        } else {
+            rounded
+        }
+    }
+
+    fn geographical(from: (f64, f64), to: (f64, f64)) -> f64 {
+        // TSPLIB defines the distance with this approximation of π
+        #[allow(clippy::approx_constant)]
+        const PI_TSPLIB: f64 = 3.141592;
+        const EARTH_RADIUS: f64 = 6378.388;
+        let radians = |value: f64| {
+            let degrees = value.trunc();
+            let minutes = value - degrees;
+            PI_TSPLIB * (degrees + 5. * minutes / 3.) / 180.
+        };
+        let (latitude1, longitude1) = (radians(from.0), radians(from.1));
+        let (latitude2, longitude2) = (radians(to.0), radians(to.1));
+        let q1 = (longitude1 - longitude2).cos();
+        let q2 = (latitude1 - latitude2).cos();
+        let q3 = (latitude1 + latitude2).cos();
+        (EARTH_RADIUS * (0.5 * ((1. + q1) * q2 - (1. - q1) * q3)).acos() + 1.).trunc()
+    }
+}
@@ -8,24 +8,170 @@ pub use rand::{
 };
 
 use crate::genetic::AsScalar;
-use rand_xoshiro::Xoshiro256Plus;
+use rand::RngCore;
+use rand_xoshiro::{SplitMix64, Xoshiro256Plus};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
 
 /// The `Prng` is the pseudo random number generator used through out this
 /// library.
 pub type Prng = Xoshiro256Plus;
 
 /// The `Seed` as used through out this library to seed the `Prng`.
-pub type Seed = <Prng as SeedableRng>::Seed;
+///
+/// A `Seed` consists of 32 bytes. It can be constructed from the raw bytes,
+/// from a single `u64` or from a string of 64 hexadecimal digits. Its
+/// `Display` representation is the hexadecimal form, which can be parsed back
+/// by `FromStr`, so the seed of a simulation run can be logged and used to
+/// repeat the run later on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Seed([u8; 32]);
+
+impl Seed {
+    /// The number of bytes of a `Seed`.
+    pub const LENGTH: usize = 32;
+
+    /// Constructs a new `Seed` from the given raw bytes.
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        Seed(bytes)
+    }
+
+    /// Constructs a new `Seed` from a single `u64`. The value is expanded to
+    /// 32 bytes by a `SplitMix64` generator, so that seeds of similar values
+    /// still lead to very different sequences of random numbers.
+    pub fn from_u64(value: u64) -> Self {
+        let mut bytes = [0; 32];
+        SplitMix64::seed_from_u64(value).fill_bytes(&mut bytes);
+        Seed(bytes)
+    }
+
+    /// Constructs a new `Seed` from a string of exactly 64 hexadecimal
+    /// digits. Upper and lower case digits are accepted.
+    pub fn from_hex(hex: &str) -> Result<Self, SeedError> {
+        if hex.len() != 2 * Seed::LENGTH {
+            return Err(SeedError::InvalidFormat(format!(
+                "expected {} hexadecimal digits, but got {} characters",
+                2 * Seed::LENGTH,
+                hex.len()
+            )));
+        }
+        if !hex.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+            return Err(SeedError::InvalidFormat(format!(
+                "{} is not a hexadecimal number",
+                hex
+            )));
+        }
+        let mut bytes = [0; 32];
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let high = char::from(digits[0])
+                .to_digit(16)
+                .expect("hexadecimal digit");
+            let low = char::from(digits[1])
+                .to_digit(16)
+                .expect("hexadecimal digit");
+            *byte = (high * 16 + low) as u8;
+        }
+        Ok(Seed(bytes))
+    }
+
+    /// Returns the 64 lowercase hexadecimal digits of this `Seed`.
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the raw bytes of this `Seed`.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Converts this `Seed` into its raw bytes.
+    pub fn into_bytes(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl From<[u8; 32]> for Seed {
+    fn from(bytes: [u8; 32]) -> Self {
+        Seed(bytes)
+    }
+}
+
+impl From<Seed> for [u8; 32] {
+    fn from(seed: Seed) -> Self {
+        seed.0
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Seed {
+    type Err = SeedError;
+
+    /// Parses a `Seed` either from 64 hexadecimal digits as written by
+    /// `Display` or from a decimal `u64` as accepted by `Seed::from_u64`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() == 2 * Seed::LENGTH {
+            Seed::from_hex(s)
+        } else {
+            s.parse::<u64>().map(Seed::from_u64).map_err(|_| {
+                SeedError::InvalidFormat(format!(
+                    "{} is neither a 64 digit hexadecimal number nor a u64",
+                    s
+                ))
+            })
+        }
+    }
+}
+
+/// The `SeedError` is returned when a `Seed` can not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SeedError {
+    /// The given string is not a valid representation of a `Seed`.
+    InvalidFormat(String),
+}
+
+impl Display for SeedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeedError::InvalidFormat(details) => write!(f, "invalid seed: {}", details),
+        }
+    }
+}
+
+impl Error for SeedError {}
 
 /// Generates a random seed to initialize the `Prng`.
 pub fn random_seed() -> Seed {
     let mut rng = Prng::from_entropy();
-    rng.gen()
+    Seed(rng.gen())
 }
 
 /// Returns a new `Prng` initialized with the given seed.
-pub fn get_rng(seed: Seed) -> Prng {
-    Prng::from_seed(seed)
+pub fn get_rng<S>(seed: S) -> Prng
+where
+    S: Into<Seed>,
+{
+    Prng::from_seed(seed.into().0)
 }
 
 /// Generates a random index into a slice of given length using the given
@@ -136,6 +282,19 @@ where
     rng.sample(Open01)
 }
 
+/// Generates a random value of the standard normal distribution, i.e. with
+/// mean 0 and standard deviation 1, using the given `Prng`.
+///
+/// The value is sampled by the Box-Muller transform.
+pub fn random_standard_normal<R>(rng: &mut R) -> f64
+where
+    R: Rng + Sized,
+{
+    let u1 = random_probability(rng);
+    let u2 = random_probability(rng);
+    (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+}
+
 /// The `WeightedDistribution` is used to select values proportional to their
 /// weighted values.
 ///
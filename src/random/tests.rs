@@ -132,6 +132,7 @@ mod random_n_cut_points {
 mod weighted_distribution {
 
     use super::*;
+    use crate::random::Rng;
 
     #[test]
     fn weighted_distribution_select() {
@@ -142,7 +143,7 @@ mod weighted_distribution {
 
         let weighted_distribution = WeightedDistribution::from_scalar_values(&weights);
 
-        let mut counter = vec![0, 0, 0, 0];
+        let mut counter = [0, 0, 0, 0];
         for _ in 0..n_sum {
             let random = rng.gen::<f64>() * weighted_distribution.sum();
             let index = weighted_distribution.select(random);
@@ -159,3 +160,95 @@ mod weighted_distribution {
         expect_that!(&counter[3], is(less_than(60)));
     }
 }
+
+mod seed {
+
+    use super::*;
+
+    #[test]
+    fn seed_is_displayed_as_64_lowercase_hexadecimal_digits() {
+        let mut bytes = [0; 32];
+        bytes[0] = 0xab;
+        bytes[31] = 0x0f;
+        let seed = Seed::from_bytes(bytes);
+
+        expect_that!(&seed.to_string(), eq(format!("ab{}0f", "00".repeat(30))));
+        expect_that!(&seed.to_hex(), eq(seed.to_string()));
+    }
+
+    #[test]
+    fn seed_from_hex_accepts_upper_case_digits() {
+        let seed = Seed::from_hex(&"AB".repeat(32)).unwrap();
+
+        expect_that!(&seed.into_bytes(), eq([0xab; 32]));
+    }
+
+    #[test]
+    fn seed_from_hex_rejects_wrong_length() {
+        let result = Seed::from_hex("abcd");
+
+        expect_that!(&result.is_err(), eq(true));
+    }
+
+    #[test]
+    fn seed_from_hex_rejects_non_hexadecimal_digits() {
+        let result = Seed::from_hex(&format!("+f{}", "00".repeat(31)));
+
+        expect_that!(&result.is_err(), eq(true));
+    }
+
+    #[test]
+    fn seed_from_str_parses_a_decimal_u64() {
+        let seed: Seed = "42".parse().unwrap();
+
+        expect_that!(&seed, eq(Seed::from_u64(42)));
+    }
+
+    #[test]
+    fn seed_from_str_rejects_garbage() {
+        let result = "not a seed".parse::<Seed>();
+
+        expect_that!(
+            &result,
+            eq(Err(SeedError::InvalidFormat(
+                "not a seed is neither a 64 digit hexadecimal number nor a u64".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn seeds_from_consecutive_u64_differ_in_all_words() {
+        let seed1 = Seed::from_u64(1).into_bytes();
+        let seed2 = Seed::from_u64(2).into_bytes();
+
+        for (word1, word2) in seed1.chunks(8).zip(seed2.chunks(8)) {
+            expect_that!(&word1, not(eq(word2)));
+        }
+    }
+
+    #[test]
+    fn get_rng_accepts_a_seed_and_its_raw_bytes() {
+        let mut rng1 = get_rng(Seed::from_bytes([7; 32]));
+        let mut rng2 = get_rng([7; 32]);
+
+        expect_that!(
+            &random_index(&mut rng1, 1_000_000),
+            eq(random_index(&mut rng2, 1_000_000))
+        );
+    }
+
+    proptest! {
+
+        #[test]
+        fn seed_display_and_from_str_roundtrip(bytes in any::<[u8; 32]>()) {
+            let seed = Seed::from(bytes);
+
+            prop_assert_eq!(seed.to_string().parse::<Seed>(), Ok(seed));
+        }
+
+        #[test]
+        fn seed_from_u64_is_deterministic(value in any::<u64>()) {
+            prop_assert_eq!(Seed::from_u64(value), Seed::from_u64(value));
+        }
+    }
+}
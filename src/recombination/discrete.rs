@@ -12,8 +12,11 @@
 //!   `smallvec::SmallVec` and `Vec` of any type.
 
 use crate::{
-    genetic::{Children, Genotype, Parents},
-    operator::{CrossoverOp, GeneticOperator},
+    genetic::{Children, Genotype, Pair, Parents, Segment, TracedChild, TracedChildren},
+    operator::{
+        unknown_parameter, usize_parameter, CrossoverOp, GeneticOperator, OperatorParameterError,
+        ParamRange, ParamSpace, TunableOperator,
+    },
     random::{random_n_cut_points, Rng},
 };
 use std::fmt::Debug;
@@ -41,9 +44,23 @@ impl GeneticOperator for UniformCrossBreeder {
     }
 }
 
+impl TunableOperator for UniformCrossBreeder {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
 impl<V> CrossoverOp<Vec<V>> for UniformCrossBreeder
 where
-    V: Clone + Debug + PartialEq + Send + Sync,
+    V: Clone + Debug + Send + Sync,
 {
     fn crossover<R>(&self, parents: Parents<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
     where
@@ -56,6 +73,7 @@ where
         while num_parents > offspring.len() {
             let mut genome = Vec::with_capacity(genome_length);
             // for each value in the genotype
+            #[allow(clippy::needless_range_loop)]
             for locus in 0..genome_length {
                 // pick the value of a randomly chosen parent
                 let random = rng.gen_range(0..num_parents);
@@ -66,6 +84,57 @@ where
         }
         offspring
     }
+
+    fn crossover_pair<R>(&self, parents: Pair<Vec<V>>, rng: &mut R) -> Children<Vec<V>>
+    where
+        R: Rng + Sized,
+    {
+        let Pair(first, second) = parents;
+        let child = |rng: &mut R| {
+            first
+                .iter()
+                .zip(second.iter())
+                .map(|(value1, value2)| {
+                    if rng.gen_bool(0.5) {
+                        value1.clone()
+                    } else {
+                        value2.clone()
+                    }
+                })
+                .collect::<Vec<V>>()
+        };
+        let child1 = child(rng);
+        let child2 = child(rng);
+        vec![child1, child2]
+    }
+}
+
+/// `String` genomes are recombined character by character.
+impl CrossoverOp<String> for UniformCrossBreeder {
+    fn crossover<R>(&self, parents: Parents<String>, rng: &mut R) -> Children<String>
+    where
+        R: Rng + Sized,
+    {
+        let children = self.crossover(chars_of_parents(parents), rng);
+        children.into_iter().map(String::from_iter).collect()
+    }
+
+    fn crossover_pair<R>(&self, parents: Pair<String>, rng: &mut R) -> Children<String>
+    where
+        R: Rng + Sized,
+    {
+        let pair = Pair(parents.0.chars().collect(), parents.1.chars().collect());
+        let children: Children<Vec<char>> = self.crossover_pair(pair, rng);
+        children.into_iter().map(String::from_iter).collect()
+    }
+}
+
+/// Splits `String` parents into their characters.
+fn chars_of_parents(parents: Parents<String>) -> Parents<Vec<char>> {
+    parents
+        .iter()
+        .map(|parent| parent.chars().collect())
+        .collect()
 }
 
 #[cfg(feature = "fixedbitset")]
@@ -90,6 +159,7 @@ mod fixedbitset_uniform_cross_breeder {
             while num_parents > offspring.len() {
                 let mut genome = FixedBitSet::with_capacity(genome_length);
                 // for each value in the genotype
+                #[allow(clippy::needless_range_loop)]
                 for locus in 0..genome_length {
                     // pick the value of a randomly chosen parent
                     let random = rng.gen_range(0..num_parents);
@@ -114,7 +184,7 @@ mod smallvec_uniform_cross_breeder {
     impl<A, V> CrossoverOp<SmallVec<A>> for UniformCrossBreeder
     where
         A: Array<Item = V> + Sync,
-        V: Clone + Debug + PartialEq + Send + Sync,
+        V: Clone + Debug + Send + Sync,
     {
         fn crossover<R>(&self, parents: Vec<SmallVec<A>>, rng: &mut R) -> Vec<SmallVec<A>>
         where
@@ -127,6 +197,7 @@ mod smallvec_uniform_cross_breeder {
             while num_parents > offspring.len() {
                 let mut genome = SmallVec::with_capacity(genome_length);
                 // for each value in the genotype
+                #[allow(clippy::needless_range_loop)]
                 for locus in 0..genome_length {
                     // pick the value of a randomly chosen parent
                     let random = rng.gen_range(0..num_parents);
@@ -163,6 +234,20 @@ impl GeneticOperator for SinglePointCrossBreeder {
     }
 }
 
+impl TunableOperator for SinglePointCrossBreeder {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
 impl<G> CrossoverOp<G> for SinglePointCrossBreeder
 where
     G: Genotype + MultiPointCrossover,
@@ -173,6 +258,20 @@ where
     {
         MultiPointCrossover::crossover(parents, 1, rng)
     }
+
+    fn crossover_pair<R>(&self, parents: Pair<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_pair(parents, 1, rng)
+    }
+
+    fn crossover_traced<R>(&self, parents: Parents<G>, rng: &mut R) -> TracedChildren<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_traced(parents, 1, rng)
+    }
 }
 
 /// The `MultiPointCrossBreeder` operator combines binary or value encoded
@@ -211,6 +310,32 @@ impl GeneticOperator for MultiPointCrossBreeder {
     }
 }
 
+impl TunableOperator for MultiPointCrossBreeder {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["num_cut_points"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "num_cut_points" => Some(self.num_cut_points as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "num_cut_points" => self.num_cut_points = usize_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(ParamRange::integer("num_cut_points", 1., f64::INFINITY))
+    }
+}
+
 impl<G> CrossoverOp<G> for MultiPointCrossBreeder
 where
     G: Genotype + MultiPointCrossover,
@@ -221,6 +346,20 @@ where
     {
         MultiPointCrossover::crossover(parents, self.num_cut_points, rng)
     }
+
+    fn crossover_pair<R>(&self, parents: Pair<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_pair(parents, self.num_cut_points, rng)
+    }
+
+    fn crossover_traced<R>(&self, parents: Parents<G>, rng: &mut R) -> TracedChildren<G>
+    where
+        R: Rng + Sized,
+    {
+        MultiPointCrossover::crossover_traced(parents, self.num_cut_points, rng)
+    }
 }
 
 pub trait MultiPointCrossover: Genotype {
@@ -229,24 +368,65 @@ pub trait MultiPointCrossover: Genotype {
     fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
     where
         R: Rng + Sized;
+
+    /// Performs the crossover of a `genetic::Pair` of parents. The default
+    /// implementation converts the pair into `genetic::Parents`.
+    fn crossover_pair<R>(parents: Pair<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Self as MultiPointCrossover>::crossover(parents.into(), num_cut_points, rng)
+    }
+
+    /// Performs the crossover and records the `genetic::Segment`s the
+    /// children are assembled from. The default implementation returns the
+    /// children without segments.
+    fn crossover_traced<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        rng: &mut R,
+    ) -> TracedChildren<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Self as MultiPointCrossover>::crossover(parents, num_cut_points, rng)
+            .into_iter()
+            .map(TracedChild::untraced)
+            .collect()
+    }
 }
 
 impl<V> MultiPointCrossover for Vec<V>
 where
-    V: Clone + Debug + PartialEq + Send + Sync,
+    V: Clone + Debug + Send + Sync,
 {
     type Dna = V;
 
     fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Self as MultiPointCrossover>::crossover_traced(parents, num_cut_points, rng)
+            .into_iter()
+            .map(|child| child.genome)
+            .collect()
+    }
+
+    fn crossover_traced<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        rng: &mut R,
+    ) -> TracedChildren<Self>
     where
         R: Rng + Sized,
     {
         let genome_length = parents[0].len();
         let num_parents = parents.len();
         // breed one child for each partner in parents
-        let mut offspring: Vec<Vec<V>> = Vec::with_capacity(num_parents);
+        let mut offspring: TracedChildren<Vec<V>> = Vec::with_capacity(num_parents);
         while num_parents > offspring.len() {
             let mut genome = Vec::with_capacity(genome_length);
+            let mut segments = Vec::with_capacity(num_cut_points + 1);
             let mut cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
             cutpoints.push(genome_length);
             let mut start = 0;
@@ -264,16 +444,176 @@ where
                 for partner in partner.iter().take(end).skip(start) {
                     genome.push(partner.clone())
                 }
+                segments.push(Segment {
+                    start,
+                    end,
+                    parent: p_index,
+                });
                 if cutpoints.is_empty() {
                     break;
                 }
                 start = end;
                 end = cutpoints.remove(0);
             }
-            offspring.push(genome);
+            offspring.push(TracedChild { genome, segments });
+        }
+        offspring
+    }
+
+    fn crossover_pair<R>(parents: Pair<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        let genome_length = parents.0.len();
+        let partners = [&parents.0, &parents.1];
+        let child = |rng: &mut R| {
+            let mut genome = Vec::with_capacity(genome_length);
+            // with two parents the partner alternates at each cut point
+            let mut p_index = rng.gen_range(0..2);
+            let mut start = 0;
+            let cutpoints = random_n_cut_points(rng, num_cut_points, genome_length);
+            for end in cutpoints.into_iter().chain(Some(genome_length)) {
+                genome.extend_from_slice(&partners[p_index][start..end]);
+                p_index = 1 - p_index;
+                start = end;
+            }
+            genome
+        };
+        let child1 = child(rng);
+        let child2 = child(rng);
+        vec![child1, child2]
+    }
+}
+
+impl<V, const N: usize> MultiPointCrossover for [V; N]
+where
+    V: Clone + Debug + Send + Sync,
+{
+    type Dna = V;
+
+    fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Self as MultiPointCrossover>::crossover_traced(parents, num_cut_points, rng)
+            .into_iter()
+            .map(|child| child.genome)
+            .collect()
+    }
+
+    fn crossover_traced<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        rng: &mut R,
+    ) -> TracedChildren<Self>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len();
+        // breed one child for each partner in parents
+        let mut offspring: TracedChildren<[V; N]> = Vec::with_capacity(num_parents);
+        while num_parents > offspring.len() {
+            // the genes of the child are overwritten segment by segment
+            let mut genome = parents[0].clone();
+            let mut segments = Vec::with_capacity(num_cut_points + 1);
+            let mut start = 0;
+            let mut p_index = num_parents;
+            let cutpoints = random_n_cut_points(rng, num_cut_points, N);
+            for end in cutpoints.into_iter().chain(Some(N)) {
+                loop {
+                    let index = rng.gen_range(0..num_parents);
+                    if index != p_index {
+                        p_index = index;
+                        break;
+                    }
+                }
+                genome[start..end].clone_from_slice(&parents[p_index][start..end]);
+                segments.push(Segment {
+                    start,
+                    end,
+                    parent: p_index,
+                });
+                start = end;
+            }
+            offspring.push(TracedChild { genome, segments });
         }
         offspring
     }
+
+    fn crossover_pair<R>(parents: Pair<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        let partners = [&parents.0, &parents.1];
+        let child = |rng: &mut R| {
+            // with two parents the partner alternates at each cut point
+            let mut p_index = rng.gen_range(0..2);
+            let mut genome = partners[p_index].clone();
+            let mut start = 0;
+            let cutpoints = random_n_cut_points(rng, num_cut_points, N);
+            for end in cutpoints.into_iter().chain(Some(N)) {
+                genome[start..end].clone_from_slice(&partners[p_index][start..end]);
+                p_index = 1 - p_index;
+                start = end;
+            }
+            genome
+        };
+        let child1 = child(rng);
+        let child2 = child(rng);
+        vec![child1, child2]
+    }
+}
+
+/// The cut points of `String` genomes are placed between characters, so that
+/// multi-byte characters are never split.
+impl MultiPointCrossover for String {
+    type Dna = char;
+
+    fn crossover<R>(parents: Parents<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Vec<char> as MultiPointCrossover>::crossover(
+            chars_of_parents(parents),
+            num_cut_points,
+            rng,
+        )
+        .into_iter()
+        .map(String::from_iter)
+        .collect()
+    }
+
+    fn crossover_traced<R>(
+        parents: Parents<Self>,
+        num_cut_points: usize,
+        rng: &mut R,
+    ) -> TracedChildren<Self>
+    where
+        R: Rng + Sized,
+    {
+        <Vec<char> as MultiPointCrossover>::crossover_traced(
+            chars_of_parents(parents),
+            num_cut_points,
+            rng,
+        )
+        .into_iter()
+        .map(|child| TracedChild {
+            genome: child.genome.into_iter().collect(),
+            segments: child.segments,
+        })
+        .collect()
+    }
+
+    fn crossover_pair<R>(parents: Pair<Self>, num_cut_points: usize, rng: &mut R) -> Children<Self>
+    where
+        R: Rng + Sized,
+    {
+        let pair = Pair(parents.0.chars().collect(), parents.1.chars().collect());
+        <Vec<char> as MultiPointCrossover>::crossover_pair(pair, num_cut_points, rng)
+            .into_iter()
+            .map(String::from_iter)
+            .collect()
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -287,7 +627,7 @@ mod smallvec_multipoint_crossover {
     impl<A, V> MultiPointCrossover for SmallVec<A>
     where
         A: Array<Item = V> + Sync,
-        V: Clone + Debug + PartialEq + Send + Sync,
+        V: Clone + Debug + Send + Sync,
     {
         type Dna = V;
 
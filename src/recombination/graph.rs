@@ -0,0 +1,106 @@
+//! The `graph` module provides `operator::CrossoverOp`s for graph encoded
+//! `genetic::Genotype`s.
+
+use crate::{
+    encoding::graph::Graph,
+    genetic::{Children, Parents},
+    operator::{
+        float_parameter, unknown_parameter, CrossoverOp, GeneticOperator, OperatorParameterError,
+        ParamRange, ParamSpace, TunableOperator,
+    },
+    random::Rng,
+};
+
+/// The `SubgraphCrossBreeder` recombines `Graph`s by exchanging subgraphs.
+///
+/// For each child a random subset of the nodes is chosen. Each node is
+/// part of the subset with the probability given by `exchange_ratio`. The
+/// child takes over all edges between the nodes of the subset from a partner
+/// and all other edges from its own parent.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter. The partner of
+/// the parent at index `i` is the parent at index `i + 1`. All parents must
+/// have the same number of nodes.
+///
+/// Note: the exchange of subgraphs does not keep degree bounds, as the degree
+/// of the nodes within the subset can change.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubgraphCrossBreeder {
+    exchange_ratio: f64,
+}
+
+impl SubgraphCrossBreeder {
+    pub fn new(exchange_ratio: f64) -> Self {
+        SubgraphCrossBreeder { exchange_ratio }
+    }
+
+    pub fn exchange_ratio(&self) -> f64 {
+        self.exchange_ratio
+    }
+
+    pub fn set_exchange_ratio(&mut self, value: f64) {
+        self.exchange_ratio = value;
+    }
+}
+
+impl GeneticOperator for SubgraphCrossBreeder {
+    fn name() -> String {
+        "Subgraph-Cross-Breeder".to_string()
+    }
+}
+
+impl TunableOperator for SubgraphCrossBreeder {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["exchange_ratio"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "exchange_ratio" => Some(self.exchange_ratio),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "exchange_ratio" => self.exchange_ratio = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(ParamRange::float("exchange_ratio", 0., 1.))
+    }
+}
+
+impl CrossoverOp<Graph> for SubgraphCrossBreeder {
+    fn crossover<R>(&self, parents: Parents<Graph>, rng: &mut R) -> Children<Graph>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len();
+        let mut offspring = Vec::with_capacity(num_parents);
+        for (index, parent) in parents.iter().enumerate() {
+            let partner = &parents[(index + 1) % num_parents];
+            let num_nodes = parent.num_nodes();
+            let in_subgraph: Vec<bool> = (0..num_nodes)
+                .map(|_| rng.gen_bool(self.exchange_ratio))
+                .collect();
+            let edges_of_parent = parent
+                .edges()
+                .filter(|&(node1, node2)| !(in_subgraph[node1] && in_subgraph[node2]));
+            let edges_of_partner = partner
+                .edges()
+                .filter(|&(node1, node2)| in_subgraph[node1] && in_subgraph[node2]);
+            offspring.push(Graph::with_edges(
+                num_nodes,
+                edges_of_parent.chain(edges_of_partner),
+            ));
+        }
+        offspring
+    }
+}
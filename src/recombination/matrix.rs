@@ -0,0 +1,93 @@
+//! The `matrix` module provides `operator::CrossoverOp`s for matrix encoded
+//! `genetic::Genotype`s.
+
+use crate::{
+    encoding::matrix::{Axis, Matrix},
+    genetic::{Children, Parents},
+    operator::{
+        unknown_parameter, CrossoverOp, GeneticOperator, OperatorParameterError, TunableOperator,
+    },
+    random::{random_index, Rng},
+};
+use std::fmt::Debug;
+
+/// The `MatrixCrossBreeder` recombines `Matrix` genomes row-wise or
+/// column-wise, depending on the `Axis` the breeder works on. Each row (or
+/// column) of a child is copied as a whole from a randomly chosen parent.
+///
+/// Keeping rows or columns intact preserves the assignments within a row
+/// (or column), e.g. the complete week schedule of a room.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter. All parents
+/// must have the same size.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixCrossBreeder {
+    axis: Axis,
+}
+
+impl MatrixCrossBreeder {
+    pub fn new(axis: Axis) -> Self {
+        MatrixCrossBreeder { axis }
+    }
+
+    /// Returns the `Axis` along which the parents are recombined.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+}
+
+impl GeneticOperator for MatrixCrossBreeder {
+    fn name() -> String {
+        "Matrix-Cross-Breeder".to_string()
+    }
+}
+
+impl TunableOperator for MatrixCrossBreeder {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
+impl<V> CrossoverOp<Matrix<V>> for MatrixCrossBreeder
+where
+    V: Clone + Debug + Send + Sync,
+{
+    fn crossover<R>(&self, parents: Parents<Matrix<V>>, rng: &mut R) -> Children<Matrix<V>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents = parents.len();
+        let (rows, cols) = (parents[0].rows(), parents[0].cols());
+        let mut offspring = Vec::with_capacity(num_parents);
+        while num_parents > offspring.len() {
+            let mut child = parents[0].clone();
+            for line in 0..parents[0].len_of(self.axis) {
+                let partner = &parents[random_index(rng, num_parents)];
+                match self.axis {
+                    Axis::Rows => {
+                        for col in 0..cols {
+                            child.set(line, col, partner.get(line, col).clone());
+                        }
+                    },
+                    Axis::Columns => {
+                        for row in 0..rows {
+                            child.set(row, line, partner.get(row, line).clone());
+                        }
+                    },
+                }
+            }
+            offspring.push(child);
+        }
+        offspring
+    }
+}
@@ -2,9 +2,27 @@
 //! `operator::CrossoverOp`. The provided crossover operators are organized
 //! in the categories:
 //! * `discrete` - crossover schemes working on discrete values of a bitset or
-//!                or list of values.
+//!   or list of values.
 //! * `order` - crossover schemes for permutation encoded values.
+//! * `graph` - crossover schemes for graph encoded values (with crate
+//!   feature `graph`).
+//! * `matrix` - crossover schemes for matrix encoded values (with crate
+//!   feature `matrix`).
+//!
+//! The `probabilistic` module provides a wrapper that applies any of these
+//! crossover operators only with a given crossover probability.
 
 pub mod discrete;
 
 pub mod order;
+
+#[cfg(feature = "graph")]
+pub mod graph;
+
+#[cfg(feature = "matrix")]
+pub mod matrix;
+
+pub mod probabilistic;
+
+#[cfg(test)]
+mod tests;
@@ -6,12 +6,15 @@
 //! The provided `operator::CrossoverOp`s for permutation encoded values are:
 //! * `OrderOneCrossover` (OX1)
 //! * `PartiallyMappedCrossover` (PMX)
+//! * `EdgeRecombinationCrossover` (ERX)
 
 use std::collections::HashMap;
 
 use crate::{
     genetic::{Children, Parents, ParentsSlice},
-    operator::{CrossoverOp, GeneticOperator},
+    operator::{
+        unknown_parameter, CrossoverOp, GeneticOperator, OperatorParameterError, TunableOperator,
+    },
     random::{random_cut_points, Rng},
 };
 
@@ -36,6 +39,20 @@ impl GeneticOperator for OrderOneCrossover {
     }
 }
 
+impl TunableOperator for OrderOneCrossover {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
 impl CrossoverOp<Vec<usize>> for OrderOneCrossover {
     fn crossover<R>(&self, parents: Parents<Vec<usize>>, rng: &mut R) -> Children<Vec<usize>>
     where
@@ -66,6 +83,20 @@ impl GeneticOperator for PartiallyMappedCrossover {
     }
 }
 
+impl TunableOperator for PartiallyMappedCrossover {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
 impl CrossoverOp<Vec<usize>> for PartiallyMappedCrossover {
     fn crossover<R>(&self, parents: Parents<Vec<usize>>, rng: &mut R) -> Children<Vec<usize>>
     where
@@ -75,6 +106,66 @@ impl CrossoverOp<Vec<usize>> for PartiallyMappedCrossover {
     }
 }
 
+/// The `EdgeRecombinationCrossover` operator combines permutation encoded
+/// `genetic::Genotype`s according the edge recombination crossover scheme
+/// (ERX).
+///
+/// The child is built from the edges between neighbouring genes of both
+/// parents, where the first and the last gene are neighbours as well. Starting
+/// with the first gene of the first parent, the next gene is the neighbour of
+/// the current gene with the fewest remaining neighbours. Only if the current
+/// gene has no neighbours left, a random remaining gene is taken. So the
+/// child preserves most of the adjacency of its parents, which makes this
+/// operator well suited for routing problems like the traveling salesman
+/// problem.
+///
+/// This crossover operator always creates as many child individuals as there
+/// are individuals in the given `genetic::Parents` parameter.
+#[allow(missing_copy_implementations)]
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct EdgeRecombinationCrossover {}
+
+impl EdgeRecombinationCrossover {
+    pub fn new() -> Self {
+        EdgeRecombinationCrossover {}
+    }
+}
+
+impl GeneticOperator for EdgeRecombinationCrossover {
+    fn name() -> String {
+        "Edge-Recombination-Crossover".to_string()
+    }
+}
+
+impl TunableOperator for EdgeRecombinationCrossover {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
+impl CrossoverOp<Vec<usize>> for EdgeRecombinationCrossover {
+    fn crossover<R>(&self, parents: Parents<Vec<usize>>, rng: &mut R) -> Children<Vec<usize>>
+    where
+        R: Rng + Sized,
+    {
+        let parents_size = parents.len();
+        (0..parents_size)
+            .map(|p1_index| {
+                let p2_index = (p1_index + 1) % parents_size;
+                edge_recombination_crossover(&parents[p1_index], &parents[p2_index], rng)
+            })
+            .collect()
+    }
+}
+
 fn multi_parents_cyclic_crossover<'a, FN, R>(
     parents: ParentsSlice<'a, Vec<usize>>,
     crossover: FN,
@@ -188,11 +279,92 @@ fn partial_mapped_crossover(
     genome
 }
 
+fn edge_recombination_crossover<R>(parent1: &[usize], parent2: &[usize], rng: &mut R) -> Vec<usize>
+where
+    R: Rng + Sized,
+{
+    let genome_length = parent1.len();
+    if genome_length == 0 {
+        return Vec::new();
+    }
+    // the neighbours of each gene in any of the parents
+    let mut edges: HashMap<usize, Vec<usize>> = HashMap::with_capacity(genome_length);
+    for parent in [parent1, parent2] {
+        for (locus, gene) in parent.iter().enumerate() {
+            let neighbours = edges.entry(*gene).or_default();
+            for neighbour in [
+                parent[(locus + genome_length - 1) % genome_length],
+                parent[(locus + 1) % genome_length],
+            ] {
+                if neighbour != *gene && !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+            }
+        }
+    }
+    let mut genome: Vec<usize> = Vec::with_capacity(genome_length);
+    let mut remaining: Vec<usize> = parent1.to_vec();
+    let mut current = parent1[0];
+    loop {
+        genome.push(current);
+        remaining.retain(|gene| *gene != current);
+        if remaining.is_empty() {
+            break;
+        }
+        for neighbours in edges.values_mut() {
+            neighbours.retain(|gene| *gene != current);
+        }
+        let candidates = edges.remove(&current).unwrap_or_default();
+        current = match candidates.iter().map(|gene| edges[gene].len()).min() {
+            Some(fewest) => {
+                let fewest_neighbours: Vec<usize> = candidates
+                    .into_iter()
+                    .filter(|gene| edges[gene].len() == fewest)
+                    .collect();
+                fewest_neighbours[rng.gen_range(0..fewest_neighbours.len())]
+            },
+            None => remaining[rng.gen_range(0..remaining.len())],
+        };
+    }
+    genome
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::random::{get_rng, random_seed};
     use galvanic_assert::matchers::*;
 
+    #[test]
+    fn edge_recombination_crossover_keeps_the_common_tour() {
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![4, 5, 6, 7, 0, 1, 2, 3];
+        let mut rng = get_rng(random_seed());
+
+        let child = edge_recombination_crossover(&p1, &p2, &mut rng);
+
+        // the tour may be followed in either direction
+        let forward = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let backward = vec![0, 7, 6, 5, 4, 3, 2, 1];
+        expect_that!(&(child == forward || child == backward), eq(true));
+    }
+
+    #[test]
+    fn edge_recombination_crossover_creates_a_permutation_of_the_parent_edges() {
+        let p1 = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let p2 = vec![9, 3, 7, 8, 2, 6, 5, 1, 4];
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..100 {
+            let child = edge_recombination_crossover(&p1, &p2, &mut rng);
+
+            let mut sorted = child.clone();
+            sorted.sort_unstable();
+            expect_that!(&sorted, eq(p1.clone()));
+            expect_that!(&child[0], eq(1));
+        }
+    }
+
     #[test]
     fn order_one_crossover_cutpoints_3_6() {
         let p1 = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
@@ -0,0 +1,190 @@
+//! The `probabilistic` module provides the `ProbabilisticCrossover`, an
+//! `operator::CrossoverOp` that applies a wrapped `operator::CrossoverOp`
+//! only with a given probability.
+//!
+//! Classic genetic algorithms recombine each group of parents with the
+//! crossover probability `pc` and otherwise copy the parents unchanged into
+//! the offspring. The copies are still mutated, so that a part of the
+//! offspring is bred from mutation only.
+
+use crate::{
+    genetic::{Children, Genotype, Pair, Parents, TracedChild, TracedChildren},
+    operator::{
+        float_parameter, CrossoverOp, GenerationAware, GeneticOperator, OperatorError,
+        OperatorParameterError, ParamRange, ParamSpace, TunableOperator,
+    },
+    random::{random_probability, Rng},
+};
+
+/// The name of the parameter of the crossover probability.
+const CROSSOVER_PROBABILITY: &str = "crossover_probability";
+
+/// The `ProbabilisticCrossover` wraps a `operator::CrossoverOp` and applies
+/// it to a group of parents with the crossover probability. Otherwise the
+/// children are clones of the parents.
+///
+/// With a crossover probability of 1 the wrapped operator is applied to all
+/// parents, with a crossover probability of 0 all children are clones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbabilisticCrossover<C> {
+    breeder: C,
+    crossover_probability: f64,
+}
+
+impl<C> ProbabilisticCrossover<C> {
+    /// Constructs a new `ProbabilisticCrossover` that applies the given
+    /// breeder with the given crossover probability.
+    pub fn new(breeder: C, crossover_probability: f64) -> Self {
+        ProbabilisticCrossover {
+            breeder,
+            crossover_probability,
+        }
+    }
+
+    /// Returns the wrapped breeder.
+    pub fn breeder(&self) -> &C {
+        &self.breeder
+    }
+
+    /// Returns the wrapped breeder as mutable reference.
+    pub fn breeder_mut(&mut self) -> &mut C {
+        &mut self.breeder
+    }
+
+    /// Returns the probability that the wrapped breeder is applied to a group
+    /// of parents.
+    pub fn crossover_probability(&self) -> f64 {
+        self.crossover_probability
+    }
+
+    /// Sets the probability that the wrapped breeder is applied to a group of
+    /// parents.
+    pub fn set_crossover_probability(&mut self, value: f64) {
+        self.crossover_probability = value;
+    }
+
+    /// Returns whether the next group of parents shall be recombined.
+    fn recombines<R>(&self, rng: &mut R) -> bool
+    where
+        R: Rng + Sized,
+    {
+        random_probability(rng) < self.crossover_probability
+    }
+}
+
+impl<C> GeneticOperator for ProbabilisticCrossover<C>
+where
+    C: GeneticOperator,
+{
+    fn name() -> String {
+        format!("Probabilistic-{}", C::name())
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        self.breeder.as_generation_aware()
+    }
+}
+
+impl<C> TunableOperator for ProbabilisticCrossover<C>
+where
+    C: TunableOperator,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        let mut names = vec![CROSSOVER_PROBABILITY];
+        names.extend(self.breeder.parameter_names());
+        names
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            CROSSOVER_PROBABILITY => Some(self.crossover_probability),
+            _ => self.breeder.parameter(name),
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        match name {
+            CROSSOVER_PROBABILITY => {
+                let value = self.parameter_space().validate(name, value)?;
+                self.crossover_probability = float_parameter(name, value)?;
+                Ok(())
+            },
+            _ => self.breeder.set_parameter(name, value),
+        }
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(ParamRange::float(CROSSOVER_PROBABILITY, 0., 1.))
+            .merge(self.breeder.parameter_space())
+    }
+}
+
+impl<G, C> CrossoverOp<G> for ProbabilisticCrossover<C>
+where
+    G: Genotype,
+    C: CrossoverOp<G>,
+{
+    fn crossover<R>(&self, parents: Parents<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        if self.recombines(rng) {
+            self.breeder.crossover(parents, rng)
+        } else {
+            parents
+        }
+    }
+
+    fn crossover_pair<R>(&self, parents: Pair<G>, rng: &mut R) -> Children<G>
+    where
+        R: Rng + Sized,
+    {
+        if self.recombines(rng) {
+            self.breeder.crossover_pair(parents, rng)
+        } else {
+            parents.into()
+        }
+    }
+
+    fn crossover_traced<R>(&self, parents: Parents<G>, rng: &mut R) -> TracedChildren<G>
+    where
+        R: Rng + Sized,
+    {
+        if self.recombines(rng) {
+            self.breeder.crossover_traced(parents, rng)
+        } else {
+            parents.into_iter().map(TracedChild::untraced).collect()
+        }
+    }
+
+    fn try_crossover<R>(
+        &self,
+        parents: Parents<G>,
+        rng: &mut R,
+    ) -> Result<Children<G>, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        if self.recombines(rng) {
+            self.breeder.try_crossover(parents, rng)
+        } else {
+            Ok(parents)
+        }
+    }
+
+    fn try_crossover_traced<R>(
+        &self,
+        parents: Parents<G>,
+        rng: &mut R,
+    ) -> Result<TracedChildren<G>, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        if self.recombines(rng) {
+            self.breeder.try_crossover_traced(parents, rng)
+        } else {
+            Ok(parents.into_iter().map(TracedChild::untraced).collect())
+        }
+    }
+}
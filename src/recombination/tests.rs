@@ -0,0 +1,253 @@
+use crate::{
+    operator::CrossoverOp,
+    random::{get_rng, random_seed},
+};
+use galvanic_assert::matchers::*;
+
+#[cfg(feature = "graph")]
+mod subgraph_cross_breeder {
+
+    use super::*;
+    use crate::{encoding::graph::Graph, recombination::graph::*};
+
+    #[test]
+    fn exchange_ratio_of_zero_copies_the_parents() {
+        let parent1 = Graph::with_edges(4, vec![(0, 1), (1, 2)]);
+        let parent2 = Graph::with_edges(4, vec![(2, 3), (0, 3)]);
+
+        let children = SubgraphCrossBreeder::new(0.).crossover(
+            vec![parent1.clone(), parent2.clone()],
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&children, eq(vec![parent1, parent2]));
+    }
+
+    #[test]
+    fn exchange_ratio_of_one_swaps_the_parents() {
+        let parent1 = Graph::with_edges(4, vec![(0, 1), (1, 2)]);
+        let parent2 = Graph::with_edges(4, vec![(2, 3), (0, 3)]);
+
+        let children = SubgraphCrossBreeder::new(1.).crossover(
+            vec![parent1.clone(), parent2.clone()],
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&children, eq(vec![parent2, parent1]));
+    }
+
+    #[test]
+    fn children_consist_of_edges_of_the_parents() {
+        let parent1 = Graph::with_edges(6, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        let parent2 = Graph::with_edges(6, vec![(0, 5), (1, 4), (2, 5), (0, 3)]);
+
+        for _ in 0..20 {
+            let children = SubgraphCrossBreeder::new(0.5).crossover(
+                vec![parent1.clone(), parent2.clone()],
+                &mut get_rng(random_seed()),
+            );
+
+            expect_that!(&children.len(), eq(2));
+            for child in &children {
+                expect_that!(&child.num_nodes(), eq(6));
+                for (node1, node2) in child.edges() {
+                    expect_that!(
+                        &(parent1.has_edge(node1, node2) || parent2.has_edge(node1, node2)),
+                        eq(true)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "matrix")]
+mod matrix_cross_breeder {
+
+    use super::*;
+    use crate::{
+        encoding::matrix::{Axis, Matrix},
+        recombination::matrix::MatrixCrossBreeder,
+    };
+
+    #[test]
+    fn children_take_whole_rows_from_the_parents() {
+        let parent1 = Matrix::new(5, 4, 1);
+        let parent2 = Matrix::new(5, 4, 2);
+
+        let children = MatrixCrossBreeder::new(Axis::Rows)
+            .crossover(vec![parent1, parent2], &mut get_rng(random_seed()));
+
+        expect_that!(&children.len(), eq(2));
+        for child in &children {
+            for row in 0..5 {
+                let first = child.get(row, 0);
+                expect_that!(&child.row(row).iter().all(|value| value == first), eq(true));
+            }
+        }
+    }
+
+    #[test]
+    fn children_take_whole_columns_from_the_parents() {
+        let parent1 = Matrix::new(5, 4, 1);
+        let parent2 = Matrix::new(5, 4, 2);
+
+        let children = MatrixCrossBreeder::new(Axis::Columns)
+            .crossover(vec![parent1, parent2], &mut get_rng(random_seed()));
+
+        for child in &children {
+            for col in 0..4 {
+                let first = child.get(0, col);
+                expect_that!(&child.column(col).all(|value| value == first), eq(true));
+            }
+        }
+    }
+}
+
+mod multi_point_cross_breeder {
+
+    use super::*;
+    use crate::{genetic::Pair, recombination::discrete::MultiPointCrossBreeder};
+    use proptest::prelude::*;
+
+    proptest! {
+
+        #[test]
+        fn traced_children_are_assembled_from_the_recorded_segments(
+            genome_length in 8usize..50,
+            num_cut_points in 1usize..4,
+        ) {
+            let parent1: Vec<usize> = (0..genome_length).collect();
+            let parent2: Vec<usize> = (100..100 + genome_length).collect();
+            let parents = vec![parent1, parent2];
+
+            let children = MultiPointCrossBreeder::new(num_cut_points)
+                .crossover_traced(parents.clone(), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(children.len(), 2);
+            for child in &children {
+                prop_assert_eq!(child.segments.first().map(|s| s.start), Some(0));
+                prop_assert_eq!(child.segments.last().map(|s| s.end), Some(genome_length));
+                for pair in child.segments.windows(2) {
+                    prop_assert_eq!(pair[0].end, pair[1].start);
+                }
+                for segment in &child.segments {
+                    let parent = &parents[segment.parent];
+                    prop_assert_eq!(
+                        &child.genome[segment.start..segment.end],
+                        &parent[segment.start..segment.end]
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn children_of_arrays_take_each_gene_from_a_parent(num_cut_points in 1usize..4) {
+            let parent1: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+            let parent2: [usize; 8] = [10, 11, 12, 13, 14, 15, 16, 17];
+            let breeder = MultiPointCrossBreeder::new(num_cut_points);
+
+            let children = breeder.crossover(vec![parent1, parent2], &mut get_rng(random_seed()));
+            let pair_children =
+                breeder.crossover_pair(Pair(parent1, parent2), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(children.len(), 2);
+            prop_assert_eq!(pair_children.len(), 2);
+            for child in children.iter().chain(&pair_children) {
+                for (locus, gene) in child.iter().enumerate() {
+                    prop_assert!(*gene == parent1[locus] || *gene == parent2[locus]);
+                }
+            }
+        }
+
+        #[test]
+        fn children_of_strings_are_cut_between_characters(num_cut_points in 1usize..4) {
+            let parents = vec!["äöüäöüäöü".to_string(), "€€€€€€€€€".to_string()];
+            let breeder = MultiPointCrossBreeder::new(num_cut_points);
+
+            let children = breeder.crossover_traced(parents.clone(), &mut get_rng(random_seed()));
+
+            prop_assert_eq!(children.len(), 2);
+            for child in &children {
+                let genome: Vec<char> = child.genome.chars().collect();
+                prop_assert_eq!(genome.len(), 9);
+                for segment in &child.segments {
+                    let parent: Vec<char> = parents[segment.parent].chars().collect();
+                    prop_assert_eq!(
+                        &genome[segment.start..segment.end],
+                        &parent[segment.start..segment.end]
+                    );
+                }
+            }
+        }
+    }
+}
+
+mod probabilistic_crossover {
+
+    use super::*;
+    use crate::{
+        operator::TunableOperator,
+        recombination::{discrete::UniformCrossBreeder, probabilistic::ProbabilisticCrossover},
+    };
+
+    fn parents() -> Vec<Vec<u8>> {
+        vec![vec![1; 16], vec![2; 16]]
+    }
+
+    #[test]
+    fn crossover_probability_of_zero_copies_the_parents() {
+        let breeder = ProbabilisticCrossover::new(UniformCrossBreeder::new(), 0.);
+        let mut rng = get_rng(random_seed());
+
+        for _ in 0..20 {
+            expect_that!(&breeder.crossover(parents(), &mut rng), eq(parents()));
+            expect_that!(
+                &breeder.try_crossover(parents(), &mut rng),
+                eq(Ok(parents()))
+            );
+        }
+    }
+
+    #[test]
+    fn crossover_probability_of_one_always_recombines_the_parents() {
+        let breeder = ProbabilisticCrossover::new(UniformCrossBreeder::new(), 1.);
+        let mut rng = get_rng([5; 32]);
+
+        for _ in 0..20 {
+            let children = breeder.crossover(parents(), &mut rng);
+
+            expect_that!(&children, not(eq(parents())));
+        }
+    }
+
+    #[test]
+    fn parents_are_recombined_with_the_crossover_probability() {
+        let breeder = ProbabilisticCrossover::new(UniformCrossBreeder::new(), 0.3);
+        let mut rng = get_rng([5; 32]);
+
+        let num_recombined = (0..1_000)
+            .filter(|_| breeder.crossover(parents(), &mut rng) != parents())
+            .count();
+
+        expect_that!(&num_recombined, greater_than(250));
+        expect_that!(&num_recombined, less_than(350));
+    }
+
+    #[test]
+    fn crossover_probability_is_a_parameter_besides_the_ones_of_the_breeder() {
+        let mut breeder = ProbabilisticCrossover::new(UniformCrossBreeder::new(), 0.3);
+
+        breeder.set_parameter("crossover_probability", 0.8).unwrap();
+
+        expect_that!(&breeder.crossover_probability(), eq(0.8));
+        expect_that!(
+            &breeder.set_parameter("crossover_probability", 1.5).is_err(),
+            eq(true)
+        );
+        expect_that!(
+            &breeder.parameter_names(),
+            eq(vec!["crossover_probability"])
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! The `crowding` module provides an `operator::ReinsertionOp` that
+//! maintains several niches in the population, so that the algorithm can
+//! find several distinct optima instead of converging to one of them.
+//!
+//! Crowding lets each child of the offspring compete only with the most
+//! similar individuals of the population. A good child in one region of the
+//! search space therefore replaces a worse individual of the same region,
+//! but never the individuals that occupy other regions. The similarity of
+//! two individuals is measured by a user supplied distance function of the
+//! genomes.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{Fitness, FitnessFunction, Genotype, Offspring},
+    operator::{
+        unknown_parameter, usize_parameter, GeneticOperator, OperatorParameterError, ParamRange,
+        ParamSpace, ReinsertionOp, SingleObjective, TunableOperator,
+    },
+    random::{Rng, SliceRandom},
+};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// The name of the `window_size` parameter of the `CrowdingReinserter`.
+const WINDOW_SIZE: &str = "window_size";
+
+/// The `CrowdingReinserter` implements restricted tournament replacement.
+/// Each child of the offspring is compared with a window of individuals
+/// chosen at random from the population. The child replaces the individual
+/// of the window that is closest to it, if the child has a better fitness.
+/// Otherwise the child is discarded.
+///
+/// The distance between two genomes is calculated by the given function,
+/// e.g. `<Vec<f64> as GenomeDistance>::distance` for genotypes that
+/// implement `genetic::GenomeDistance`.
+///
+/// The `window_size` defines how many individuals each child competes with.
+/// A window size of 0 or of at least the size of the population compares
+/// each child with the whole population, which is the most accurate but
+/// also the most expensive choice.
+///
+/// Together with the `algorithm::HallOfFame` of the
+/// `GeneticAlgorithmBuilder::with_hall_of_fame` the best individuals of
+/// several niches are returned as result of a simulation.
+pub struct CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    /// The `FitnessFunction` to be used to calculate fitness values of
+    /// individuals of the offspring.
+    fitness_evaluator: Box<E>,
+    /// The `distance` calculates how different two genomes are.
+    distance: fn(&G, &G) -> f64,
+    /// The `window_size` defines the number of individuals of the population
+    /// each child is compared with.
+    window_size: usize,
+    // phantom types
+    _f: PhantomData<F>,
+}
+
+impl<G, F, E> CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    /// Constructs a new instance of the `CrowdingReinserter`, which compares
+    /// each child with the whole population.
+    pub fn new(fitness_evaluator: E, distance: fn(&G, &G) -> f64) -> Self {
+        CrowdingReinserter {
+            fitness_evaluator: Box::new(fitness_evaluator),
+            distance,
+            window_size: 0,
+            _f: PhantomData,
+        }
+    }
+
+    /// Sets the number of individuals of the population each child is
+    /// compared with. Defaults to 0, which means the whole population.
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size;
+        self
+    }
+
+    /// Returns the `window_size` of this `CrowdingReinserter`.
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Sets the `window_size` of this `CrowdingReinserter` to the given
+    /// value.
+    pub fn set_window_size(&mut self, value: usize) {
+        self.window_size = value;
+    }
+
+    /// Returns the distance function of this `CrowdingReinserter`.
+    pub fn distance(&self) -> fn(&G, &G) -> f64 {
+        self.distance
+    }
+}
+
+impl<G, F, E> Clone for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn clone(&self) -> Self {
+        CrowdingReinserter {
+            fitness_evaluator: self.fitness_evaluator.clone(),
+            distance: self.distance,
+            window_size: self.window_size,
+            _f: PhantomData,
+        }
+    }
+}
+
+impl<G, F, E> Debug for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F> + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CrowdingReinserter")
+            .field("fitness_evaluator", &self.fitness_evaluator)
+            .field("window_size", &self.window_size)
+            .finish()
+    }
+}
+
+/// Two `CrowdingReinserter`s are equal if their fitness evaluators and
+/// window sizes are equal. The distance functions are not compared.
+impl<G, F, E> PartialEq for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.fitness_evaluator == other.fitness_evaluator && self.window_size == other.window_size
+    }
+}
+
+impl<G, F, E> GeneticOperator for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn name() -> String {
+        "Crowding-Reinserter".to_string()
+    }
+}
+
+impl<G, F, E> TunableOperator for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec![WINDOW_SIZE]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            WINDOW_SIZE => Some(self.window_size as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            WINDOW_SIZE => self.window_size = usize_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(ParamRange::integer(WINDOW_SIZE, 0., f64::INFINITY))
+    }
+}
+
+/// Can be used for single-objective optimization
+impl<G, F, E> SingleObjective for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+}
+
+impl<G, F, E> ReinsertionOp<G, F> for CrowdingReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn combine<R>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let mut new_population = evaluated.individuals().to_vec();
+        let mut fitness_values = evaluated.fitness_values().to_vec();
+        let population_size = new_population.len();
+        if population_size == 0 {
+            return new_population;
+        }
+        let compare_all = self.window_size == 0 || self.window_size >= population_size;
+        let mut indices: Vec<usize> = (0..population_size).collect();
+        let mut rejected = Vec::new();
+
+        for child in offspring.take_individuals() {
+            let window = if compare_all {
+                &indices[..]
+            } else {
+                indices.partial_shuffle(rng, self.window_size).0
+            };
+            // find the individual of the window that is most similar to the child
+            let mut closest = window[0];
+            let mut closest_distance = (self.distance)(&child, &new_population[closest]);
+            for &index in &window[1..] {
+                let distance = (self.distance)(&child, &new_population[index]);
+                if distance < closest_distance {
+                    closest = index;
+                    closest_distance = distance;
+                }
+            }
+            let fitness = self.fitness_evaluator.fitness_of(&child);
+            if fitness > fitness_values[closest] {
+                new_population[closest] = child;
+                fitness_values[closest] = fitness;
+            } else {
+                rejected.push(child);
+            }
+        }
+        // leave the children that have not made it into the new population
+        for child in rejected {
+            offspring.push(child);
+        }
+        new_population
+    }
+}
@@ -3,9 +3,13 @@
 //! individuals from both.
 
 use crate::{
-    algorithm::EvaluatedPopulation,
+    algorithm::{EvaluatedPopulation, TieBreaking},
     genetic::{Fitness, FitnessFunction, Genotype, Offspring},
-    operator::{GeneticOperator, MultiObjective, ReinsertionOp, SingleObjective},
+    operator::{
+        float_parameter, unknown_parameter, GeneticOperator, MultiObjective,
+        OperatorParameterError, ParamSpace, ParentDemand, ReinsertionOp, SingleObjective,
+        TunableOperator, REPLACE_RATIO,
+    },
     random::Rng,
 };
 use std::marker::PhantomData;
@@ -26,6 +30,9 @@ use std::marker::PhantomData;
 /// individuals then the new population is filled up with individuals from the
 /// old population. If the offspring contains more individuals than the size of
 /// the population then the individuals are chosen uniformly at random.
+///
+/// Among individuals of equal fitness the reinserter prefers the ones chosen
+/// by the configured `algorithm::TieBreaking`.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ElitistReinserter<G, F, E>
 where
@@ -43,6 +50,9 @@ where
     /// The `replace_ratio` defines the fraction of the population size that
     /// is going to be replaced by individuals from the offspring.
     replace_ratio: f64,
+    /// The `tie_breaking` defines which individuals are preferred among
+    /// individuals of equal fitness.
+    tie_breaking: TieBreaking<G>,
     // phantom types
     _g: PhantomData<G>,
     _f: PhantomData<F>,
@@ -60,6 +70,7 @@ where
             fitness_evaluator: Box::new(fitness_evaluator),
             offspring_has_precedence,
             replace_ratio,
+            tie_breaking: TieBreaking::default(),
             _g: PhantomData,
             _f: PhantomData,
         }
@@ -87,6 +98,25 @@ where
     pub fn set_replace_ratio(&mut self, value: f64) {
         self.replace_ratio = value;
     }
+
+    /// Sets which individuals are preferred among individuals of equal
+    /// fitness. Defaults to `TieBreaking::First`.
+    pub fn with_tie_breaking(mut self, tie_breaking: TieBreaking<G>) -> Self {
+        self.tie_breaking = tie_breaking;
+        self
+    }
+
+    /// Returns which individuals are preferred among individuals of equal
+    /// fitness.
+    pub fn tie_breaking(&self) -> &TieBreaking<G> {
+        &self.tie_breaking
+    }
+
+    /// Sets which individuals are preferred among individuals of equal
+    /// fitness.
+    pub fn set_tie_breaking(&mut self, value: TieBreaking<G>) {
+        self.tie_breaking = value;
+    }
 }
 
 impl<G, F, E> GeneticOperator for ElitistReinserter<G, F, E>
@@ -100,6 +130,37 @@ where
     }
 }
 
+impl<G, F, E> TunableOperator for ElitistReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["replace_ratio"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "replace_ratio" => Some(self.replace_ratio),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "replace_ratio" => self.replace_ratio = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(REPLACE_RATIO)
+    }
+}
+
 /// Can be used for single-objective optimization
 impl<G, F, E> SingleObjective for ElitistReinserter<G, F, E>
 where
@@ -127,7 +188,7 @@ where
         &self,
         offspring: &mut Offspring<G>,
         evaluated: &EvaluatedPopulation<G, F>,
-        _: &mut R,
+        rng: &mut R,
     ) -> Vec<G>
     where
         R: Rng + Sized,
@@ -138,12 +199,20 @@ where
         let mut old_population_indices: Vec<usize> = (0..old_fitness_values.len()).collect();
         // sort fitness indices from best performing to worst performing index
         old_population_indices.sort_by(|x, y| old_fitness_values[*y].cmp(&old_fitness_values[*x]));
+        let old_keys: Vec<(&F, &G)> = old_population_indices
+            .iter()
+            .map(|index| (&old_fitness_values[*index], &old_individuals[*index]))
+            .collect();
+        if let Some(order) = self.order_ties(&old_keys, false, rng) {
+            old_population_indices = reorder(old_population_indices, &order);
+        }
 
         let population_size = old_individuals.len();
         let mut new_population: Vec<G> = Vec::with_capacity(population_size);
 
         // How many individuals should we take from the offspring?
-        let num_offspring = (population_size as f64 * self.replace_ratio + 0.5).floor() as usize;
+        let num_offspring =
+            ParentDemand::from_ratio(population_size, self.replace_ratio).num_offspring();
 
         if self.offspring_has_precedence {
             // first pick individuals from offspring
@@ -156,14 +225,18 @@ where
                 }
                 // sort offspring from worst to best performing performing
                 offspring_fitness.sort_by(|x, y| x.1.cmp(&y.1));
+                offspring_fitness = self.order_offspring_ties(offspring_fitness, rng);
                 // pick only the best individuals from the offspring
-                for _ in 0..num_offspring {
-                    new_population.push(offspring_fitness.pop().unwrap().0);
+                while num_offspring > new_population.len() {
+                    match offspring_fitness.pop() {
+                        Some((child, _)) => new_population.push(child),
+                        None => break,
+                    }
                 }
             } else {
                 // insert all individuals from offspring
                 offspring.truncate(num_offspring);
-                new_population.append(offspring);
+                new_population.append(&mut offspring.take_individuals());
             }
             // finally fill up new population with individuals from old population
             let num_old_population = population_size - new_population.len();
@@ -180,13 +253,16 @@ where
             }
             // sort offspring from worst to best performing performing
             offspring_fitness.sort_by(|x, y| x.1.cmp(&y.1));
+            offspring_fitness = self.order_offspring_ties(offspring_fitness, rng);
+            let prefer_offspring = self.tie_breaking == TieBreaking::PreferOffspring;
             for _ in 0..population_size {
                 // compare fitness of best offspring with best fitness of old population
                 let index_old = old_population_indices[0];
-                if !offspring_fitness.is_empty()
-                    && offspring_fitness[offspring_fitness.len() - 1].1
-                        > old_fitness_values[index_old]
-                {
+                let offspring_wins = offspring_fitness.last().is_some_and(|(_, fitness)| {
+                    *fitness > old_fitness_values[index_old]
+                        || (prefer_offspring && *fitness == old_fitness_values[index_old])
+                });
+                if offspring_wins {
                     let (offspring, _) = offspring_fitness.pop().unwrap();
                     // insert best from offspring
                     new_population.push(offspring);
@@ -199,4 +275,88 @@ where
         }
         new_population
     }
+
+    fn parent_demand(&self, population_size: usize) -> Option<ParentDemand> {
+        // without precedence all offspring compete with the old population
+        if self.offspring_has_precedence {
+            Some(ParentDemand::from_ratio(
+                population_size,
+                self.replace_ratio,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<G, F, E> ElitistReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness,
+    E: FitnessFunction<G, F>,
+{
+    /// Orders the offspring, which is sorted from worst to best fitness, so
+    /// that the preferred individual of each run of equal fitness comes last.
+    fn order_offspring_ties<R>(&self, offspring_fitness: Vec<(G, F)>, rng: &mut R) -> Vec<(G, F)>
+    where
+        R: Rng + Sized,
+    {
+        let keys: Vec<(&F, &G)> = offspring_fitness
+            .iter()
+            .map(|(child, fitness)| (fitness, child))
+            .collect();
+        match self.order_ties(&keys, true, rng) {
+            Some(order) => reorder(offspring_fitness, &order),
+            None => offspring_fitness,
+        }
+    }
+
+    /// Returns the new order of the given items, which are sorted by
+    /// fitness, in which each run of items of equal fitness is ordered
+    /// according to the tie breaking. The preferred item of a run comes
+    /// first, or last if `preferred_last` is true. Returns `None` if the
+    /// order of the items does not change.
+    fn order_ties<R>(
+        &self,
+        items: &[(&F, &G)],
+        preferred_last: bool,
+        rng: &mut R,
+    ) -> Option<Vec<usize>>
+    where
+        R: Rng + Sized,
+    {
+        if let TieBreaking::First | TieBreaking::PreferOffspring = self.tie_breaking {
+            return None;
+        }
+        let mut order = Vec::with_capacity(items.len());
+        let mut start = 0;
+        while start < items.len() {
+            let fitness = items[start].0;
+            let end = start
+                + items[start..]
+                    .iter()
+                    .take_while(|(other, _)| *other == fitness)
+                    .count();
+            let genomes: Vec<&G> = items[start..end]
+                .iter()
+                .map(|(_, genome)| *genome)
+                .collect();
+            let mut positions = self.tie_breaking.order(&genomes, rng);
+            if preferred_last {
+                positions.reverse();
+            }
+            order.extend(positions.into_iter().map(|position| start + position));
+            start = end;
+        }
+        Some(order)
+    }
+}
+
+/// Arranges the given items in the given order.
+fn reorder<T>(items: Vec<T>, order: &[usize]) -> Vec<T> {
+    let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+    order
+        .iter()
+        .filter_map(|position| items[*position].take())
+        .collect()
 }
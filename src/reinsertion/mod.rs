@@ -4,7 +4,20 @@
 //! The provided implementations of the `operator:ReinsertionOp` are:
 //! * `random::UniformReinserter`
 //! * `elitist::ElitistReinserter`
+//! * `proportionate::FitnessProportionateReinserter`
+//! * `nsga2::Nsga2Reinserter` (with crate feature `multi-objective`)
+//! * `crowding::CrowdingReinserter`
 
 pub mod random;
 
 pub mod elitist;
+
+pub mod proportionate;
+
+#[cfg(feature = "multi-objective")]
+pub mod nsga2;
+
+pub mod crowding;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,156 @@
+//! The `nsga2` module provides the reinsertion of the NSGA-II algorithm
+//! (Non-dominated Sorting Genetic Algorithm II) for multi-objective
+//! optimization.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{AsScalar, Fitness, FitnessFunction, Genotype, Offspring},
+    operator::{
+        unknown_parameter, GeneticOperator, MultiObjective, OperatorParameterError, ParentDemand,
+        ReinsertionOp, TunableOperator,
+    },
+    pareto::{crowding_distance, non_dominated_sort, Pareto},
+    random::Rng,
+};
+use std::{cmp::Ordering, marker::PhantomData};
+
+/// The `Nsga2Reinserter` combines the offspring with the old population by
+/// the elitist environmental selection of the NSGA-II algorithm.
+///
+/// The individuals of the offspring and the old population are sorted into
+/// non-dominated fronts by their `pareto::Pareto` fitness values. The new
+/// population is filled front by front, starting with the first front. The
+/// individuals of the front that does not fit completely into the new
+/// population are picked by their crowding distance, largest first.
+///
+/// The reinserter demands as many offspring as the population has
+/// individuals.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+    /// The `FitnessFunction` to be used to calculate fitness values of
+    /// individuals of the offspring.
+    fitness_evaluator: Box<E>,
+    // phantom types
+    _g: PhantomData<G>,
+    _t: PhantomData<T>,
+}
+
+impl<G, T, E> Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+    /// Constructs a new instance of the `Nsga2Reinserter`.
+    pub fn new(fitness_evaluator: E) -> Self {
+        Nsga2Reinserter {
+            fitness_evaluator: Box::new(fitness_evaluator),
+            _g: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<G, T, E> GeneticOperator for Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+    fn name() -> String {
+        "NSGA-II-Reinserter".to_string()
+    }
+}
+
+impl<G, T, E> TunableOperator for Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
+/// Can be used for multi-objective optimization
+impl<G, T, E> MultiObjective for Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+}
+
+impl<G, T, E> ReinsertionOp<G, Pareto<T>> for Nsga2Reinserter<G, T, E>
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+    E: FitnessFunction<G, Pareto<T>>,
+{
+    fn combine<R>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, Pareto<T>>,
+        _: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let old_individuals = evaluated.individuals();
+        let population_size = old_individuals.len();
+        // the pool holds the old population followed by the offspring
+        let mut pool: Vec<Option<G>> = old_individuals.iter().cloned().map(Some).collect();
+        let mut fitness_values = evaluated.fitness_values().to_vec();
+        for child in offspring.take_individuals() {
+            fitness_values.push(self.fitness_evaluator.fitness_of(&child));
+            pool.push(Some(child));
+        }
+
+        let mut new_population: Vec<G> = Vec::with_capacity(population_size);
+        for mut front in non_dominated_sort(&fitness_values) {
+            let num_missing = population_size - new_population.len();
+            if num_missing == 0 {
+                break;
+            }
+            if front.len() > num_missing {
+                // pick the members of the least crowded regions of the front
+                let distances = crowding_distance(&fitness_values, &front);
+                let mut positions: Vec<usize> = (0..front.len()).collect();
+                positions.sort_by(|position1, position2| {
+                    distances[*position2]
+                        .partial_cmp(&distances[*position1])
+                        .unwrap_or(Ordering::Equal)
+                });
+                front = positions
+                    .into_iter()
+                    .take(num_missing)
+                    .map(|position| front[position])
+                    .collect();
+            }
+            for index in front {
+                if let Some(individual) = pool[index].take() {
+                    new_population.push(individual);
+                }
+            }
+        }
+        new_population
+    }
+
+    fn parent_demand(&self, population_size: usize) -> Option<ParentDemand> {
+        Some(ParentDemand::new(population_size))
+    }
+}
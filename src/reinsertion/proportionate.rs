@@ -0,0 +1,139 @@
+//! The `proportionate` module provides `operator::ReinsertionOp`s that combine
+//! the individuals from the offspring and the old population by sampling them
+//! proportionally to their fitness.
+//!
+//! Fitness proportionate reinsertion is an option between pure elitism, which
+//! always keeps the best individuals, and uniform replacement, which does not
+//! consider the fitness at all.
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{AsScalar, Fitness, FitnessFunction, Genotype, Offspring},
+    operator::{
+        unknown_parameter, GeneticOperator, OperatorParameterError, ReinsertionOp, SingleObjective,
+        TunableOperator,
+    },
+    random::{random_index, random_probability, Rng, WeightedDistribution},
+};
+use std::marker::PhantomData;
+
+/// The `FitnessProportionateReinserter` fills the new population by sampling
+/// from the combined pool of the offspring and the old population. Each
+/// individual of the pool is picked with a probability that is proportional
+/// to its fitness value, like in `selection::proportionate::RouletteWheelSelector`.
+///
+/// The individuals are sampled with replacement, so the same individual can
+/// be inserted several times into the new population. If the sum of the
+/// fitness values of the pool is not positive, the individuals are picked
+/// uniformly at random.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    /// The `FitnessFunction` to be used to calculate fitness values of
+    /// individuals of the offspring.
+    fitness_evaluator: Box<E>,
+    // phantom types
+    _g: PhantomData<G>,
+    _f: PhantomData<F>,
+}
+
+impl<G, F, E> FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    /// Constructs a new instance of the `FitnessProportionateReinserter`.
+    pub fn new(fitness_evaluator: E) -> Self {
+        FitnessProportionateReinserter {
+            fitness_evaluator: Box::new(fitness_evaluator),
+            _g: PhantomData,
+            _f: PhantomData,
+        }
+    }
+}
+
+impl<G, F, E> GeneticOperator for FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    fn name() -> String {
+        "Fitness-Proportionate-Reinserter".to_string()
+    }
+}
+
+impl<G, F, E> TunableOperator for FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    fn parameter_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    fn parameter(&self, _name: &str) -> Option<f64> {
+        None
+    }
+
+    fn set_parameter(&mut self, name: &str, _value: f64) -> Result<(), OperatorParameterError> {
+        Err(unknown_parameter(name))
+    }
+}
+
+/// Can be used for single-objective optimization
+impl<G, F, E> SingleObjective for FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+}
+
+impl<G, F, E> ReinsertionOp<G, F> for FitnessProportionateReinserter<G, F, E>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    fn combine<R>(
+        &self,
+        offspring: &mut Offspring<G>,
+        evaluated: &EvaluatedPopulation<G, F>,
+        rng: &mut R,
+    ) -> Vec<G>
+    where
+        R: Rng + Sized,
+    {
+        let old_individuals = evaluated.individuals();
+        let population_size = old_individuals.len();
+
+        // combine offspring and old population into one pool
+        let mut pool: Vec<G> = offspring.take_individuals();
+        let mut pool_fitness: Vec<F> = pool
+            .iter()
+            .map(|child| self.fitness_evaluator.fitness_of(child))
+            .collect();
+        pool.extend(old_individuals.iter().cloned());
+        pool_fitness.extend(evaluated.fitness_values().iter().cloned());
+
+        let mut new_population = Vec::with_capacity(population_size);
+        let weighted_distribution = WeightedDistribution::from_scalar_values(&pool_fitness);
+        for _ in 0..population_size {
+            let selected = if weighted_distribution.sum() > 0. {
+                let random = random_probability(rng) * weighted_distribution.sum();
+                weighted_distribution.select(random)
+            } else {
+                random_index(rng, pool.len())
+            };
+            new_population.push(pool[selected].clone());
+        }
+        new_population
+    }
+}
@@ -5,7 +5,11 @@
 use crate::{
     algorithm::EvaluatedPopulation,
     genetic::{Fitness, Genotype, Offspring},
-    operator::{GeneticOperator, MultiObjective, ReinsertionOp, SingleObjective},
+    operator::{
+        float_parameter, unknown_parameter, GeneticOperator, MultiObjective,
+        OperatorParameterError, ParamSpace, ParentDemand, ReinsertionOp, SingleObjective,
+        TunableOperator, REPLACE_RATIO,
+    },
     random::{random_index, Rng},
 };
 
@@ -58,6 +62,32 @@ impl GeneticOperator for UniformReinserter {
     }
 }
 
+impl TunableOperator for UniformReinserter {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["replace_ratio"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "replace_ratio" => Some(self.replace_ratio),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "replace_ratio" => self.replace_ratio = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new().with(REPLACE_RATIO)
+    }
+}
+
 /// Can be used for single-objective optimization
 impl SingleObjective for UniformReinserter {}
 /// Can be used for multi-objective optimization
@@ -82,7 +112,8 @@ where
         let mut new_population = Vec::with_capacity(population_size);
 
         // How many individuals should we take from the offspring?
-        let num_offspring = (population_size as f64 * self.replace_ratio + 0.5).floor() as usize;
+        let num_offspring =
+            ParentDemand::from_ratio(population_size, self.replace_ratio).num_offspring();
 
         // first pick individuals from offspring
         if num_offspring < offspring.len() {
@@ -108,4 +139,11 @@ where
         }
         new_population
     }
+
+    fn parent_demand(&self, population_size: usize) -> Option<ParentDemand> {
+        Some(ParentDemand::from_ratio(
+            population_size,
+            self.replace_ratio,
+        ))
+    }
 }
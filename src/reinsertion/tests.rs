@@ -0,0 +1,313 @@
+use super::proportionate::*;
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{FitnessFunction, Offspring},
+    operator::ReinsertionOp,
+    random::{get_rng, random_seed},
+};
+use galvanic_assert::matchers::*;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+struct FirstGene;
+
+impl FitnessFunction<Vec<u32>, u32> for FirstGene {
+    fn fitness_of(&self, genome: &Vec<u32>) -> u32 {
+        genome[0]
+    }
+
+    fn average(&self, fitness_values: &[u32]) -> u32 {
+        fitness_values.iter().sum::<u32>() / fitness_values.len() as u32
+    }
+
+    fn highest_possible_fitness(&self) -> u32 {
+        100
+    }
+
+    fn lowest_possible_fitness(&self) -> u32 {
+        0
+    }
+}
+
+fn evaluated_population(individuals: Vec<Vec<u32>>) -> EvaluatedPopulation<Vec<u32>, u32> {
+    let fitness_values: Vec<u32> = individuals.iter().map(|genome| genome[0]).collect();
+    let highest = *fitness_values.iter().max().unwrap();
+    let lowest = *fitness_values.iter().min().unwrap();
+    let average = FirstGene.average(&fitness_values);
+    EvaluatedPopulation::new(
+        Arc::new(individuals),
+        fitness_values,
+        highest,
+        lowest,
+        average,
+    )
+}
+
+mod fitness_proportionate_reinserter {
+
+    use super::*;
+
+    #[test]
+    fn new_population_has_the_size_of_the_old_population() {
+        let reinserter = FitnessProportionateReinserter::new(FirstGene);
+        let evaluated = evaluated_population(vec![vec![1], vec![2], vec![3], vec![4]]);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![5], vec![6]].into();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        expect_that!(&new_population.len(), eq(4));
+        expect_that!(&offspring.len(), eq(0));
+    }
+
+    #[test]
+    fn individuals_without_fitness_are_never_picked() {
+        let reinserter = FitnessProportionateReinserter::new(FirstGene);
+        let evaluated = evaluated_population(vec![vec![0], vec![0], vec![7]]);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![0], vec![9]].into();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        for genome in new_population {
+            expect_that!(&(genome == vec![7] || genome == vec![9]), eq(true));
+        }
+    }
+
+    #[test]
+    fn picks_uniformly_if_no_individual_has_fitness() {
+        let reinserter = FitnessProportionateReinserter::new(FirstGene);
+        let evaluated = evaluated_population(vec![vec![0], vec![0]]);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![0]].into();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        expect_that!(&new_population, eq(vec![vec![0], vec![0]]));
+    }
+}
+
+mod empty_offspring {
+
+    use super::*;
+    use crate::reinsertion::{elitist::ElitistReinserter, random::UniformReinserter};
+
+    fn old_population() -> EvaluatedPopulation<Vec<u32>, u32> {
+        evaluated_population(vec![vec![1], vec![2], vec![3]])
+    }
+
+    #[test]
+    fn elitist_reinserter_with_offspring_precedence_keeps_the_old_population() {
+        let reinserter = ElitistReinserter::new(FirstGene, true, 0.7);
+        let mut offspring: Offspring<Vec<u32>> = Offspring::with_capacity(0);
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&new_population, eq(vec![vec![3], vec![2], vec![1]]));
+    }
+
+    #[test]
+    fn elitist_reinserter_without_offspring_precedence_keeps_the_old_population() {
+        let reinserter = ElitistReinserter::new(FirstGene, false, 0.7);
+        let mut offspring: Offspring<Vec<u32>> = Offspring::with_capacity(0);
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&new_population, eq(vec![vec![3], vec![2], vec![1]]));
+    }
+
+    #[test]
+    fn uniform_reinserter_fills_up_with_the_old_population() {
+        let reinserter = UniformReinserter::new(0.7);
+        let mut offspring: Offspring<Vec<u32>> = Offspring::with_capacity(0);
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&new_population.len(), eq(3));
+    }
+
+    #[test]
+    fn fitness_proportionate_reinserter_samples_from_the_old_population() {
+        let reinserter = FitnessProportionateReinserter::new(FirstGene);
+        let mut offspring: Offspring<Vec<u32>> = Offspring::with_capacity(0);
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(&new_population.len(), eq(3));
+    }
+}
+
+mod tie_breaking {
+
+    use super::*;
+    use crate::{algorithm::TieBreaking, reinsertion::elitist::ElitistReinserter};
+
+    #[allow(clippy::ptr_arg)]
+    fn distance(genome1: &Vec<u32>, genome2: &Vec<u32>) -> f64 {
+        (genome1[1] as f64 - genome2[1] as f64).abs()
+    }
+
+    #[test]
+    fn elitist_reinserter_keeps_the_old_individual_of_equal_fitness_by_default() {
+        let reinserter = ElitistReinserter::new(FirstGene, false, 0.5);
+        let evaluated = evaluated_population(vec![vec![5, 0], vec![1, 0]]);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![5, 1]].into();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        expect_that!(&new_population, eq(vec![vec![5, 0], vec![5, 1]]));
+    }
+
+    #[test]
+    fn elitist_reinserter_prefers_offspring_of_equal_fitness() {
+        let reinserter = ElitistReinserter::new(FirstGene, false, 0.5)
+            .with_tie_breaking(TieBreaking::PreferOffspring);
+        let evaluated = evaluated_population(vec![vec![5, 0], vec![1, 0]]);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![5, 1]].into();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        expect_that!(&new_population, eq(vec![vec![5, 1], vec![5, 0]]));
+    }
+
+    #[test]
+    fn elitist_reinserter_prefers_the_most_diverse_of_equal_fitness() {
+        let reinserter = ElitistReinserter::new(FirstGene, true, 0.)
+            .with_tie_breaking(TieBreaking::MostDiverse(distance));
+        let evaluated =
+            evaluated_population(vec![vec![5, 10], vec![5, 11], vec![5, 90], vec![1, 0]]);
+        let mut offspring: Offspring<Vec<u32>> = Offspring::new();
+
+        let new_population =
+            reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+        expect_that!(&new_population[0], eq(vec![5, 90]));
+        expect_that!(&new_population[3], eq(vec![1, 0]));
+    }
+
+    #[test]
+    fn best_solution_is_chosen_among_ties_by_the_tie_breaking() {
+        let evaluated = evaluated_population(vec![vec![5, 10], vec![5, 11], vec![5, 90]]);
+        let mut rng = get_rng(random_seed());
+
+        expect_that!(
+            &evaluated.index_of_best(&TieBreaking::First, &mut rng),
+            eq(Some(0))
+        );
+        expect_that!(
+            &evaluated.index_of_best(&TieBreaking::MostDiverse(distance), &mut rng),
+            eq(Some(2))
+        );
+        let mut chosen = [false; 3];
+        for _ in 0..100 {
+            let index = evaluated
+                .index_of_best(&TieBreaking::Random, &mut rng)
+                .unwrap();
+            chosen[index] = true;
+        }
+        expect_that!(&chosen, eq([true, true, true]));
+    }
+}
+
+mod crowding_reinserter {
+
+    use super::*;
+    use crate::{operator::TunableOperator, reinsertion::crowding::CrowdingReinserter};
+
+    #[allow(clippy::ptr_arg)]
+    fn position_distance(a: &Vec<u32>, b: &Vec<u32>) -> f64 {
+        (a[1] as f64 - b[1] as f64).abs()
+    }
+
+    fn old_population() -> EvaluatedPopulation<Vec<u32>, u32> {
+        evaluated_population(vec![vec![1, 0], vec![5, 50], vec![2, 100]])
+    }
+
+    #[test]
+    fn child_replaces_the_closest_individual_with_lower_fitness() {
+        let reinserter = CrowdingReinserter::new(FirstGene, position_distance);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![3, 98], vec![4, 2]].into();
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(
+            &new_population,
+            eq(vec![vec![4, 2], vec![5, 50], vec![3, 98]])
+        );
+        expect_that!(&offspring.len(), eq(0));
+    }
+
+    #[test]
+    fn child_that_is_not_better_than_the_closest_individual_is_discarded() {
+        let reinserter = CrowdingReinserter::new(FirstGene, position_distance);
+        let mut offspring: Offspring<Vec<u32>> = vec![vec![4, 52], vec![5, 48]].into();
+
+        let new_population = reinserter.combine(
+            &mut offspring,
+            &old_population(),
+            &mut get_rng(random_seed()),
+        );
+
+        expect_that!(
+            &new_population,
+            eq(vec![vec![1, 0], vec![5, 50], vec![2, 100]])
+        );
+        expect_that!(&offspring.len(), eq(2));
+    }
+
+    #[test]
+    fn child_competes_only_with_the_individuals_of_the_window() {
+        let reinserter = CrowdingReinserter::new(FirstGene, position_distance).with_window_size(1);
+        let evaluated = evaluated_population(vec![vec![1, 0], vec![1, 50], vec![1, 100]]);
+
+        for _ in 0..20 {
+            let mut offspring: Offspring<Vec<u32>> = vec![vec![9, 0]].into();
+
+            let new_population =
+                reinserter.combine(&mut offspring, &evaluated, &mut get_rng(random_seed()));
+
+            let replaced: Vec<bool> = new_population
+                .iter()
+                .zip(evaluated.individuals().iter())
+                .map(|(new, old)| new != old)
+                .collect();
+            expect_that!(&replaced.iter().filter(|r| **r).count(), eq(1));
+        }
+    }
+
+    #[test]
+    fn window_size_can_be_tuned() {
+        let mut reinserter = CrowdingReinserter::new(FirstGene, position_distance);
+
+        reinserter.set_parameter("window_size", 4.).unwrap();
+
+        expect_that!(&reinserter.window_size(), eq(4));
+        expect_that!(&reinserter.parameter("window_size"), eq(Some(4.)));
+        expect_that!(
+            &reinserter.set_parameter("window_size", -1.).is_err(),
+            eq(true)
+        );
+    }
+}
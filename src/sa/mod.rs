@@ -0,0 +1,350 @@
+//! The `sa` module provides an implementation of simulated annealing, a
+//! single-solution optimizer that can be run by the same
+//! `simulation::Simulator` and stopped by the same `termination` conditions
+//! as the `ga::GeneticAlgorithm`.
+//!
+//! Simulated annealing reuses the building blocks of the genetic algorithm:
+//! the quality of a solution is calculated by a `genetic::FitnessFunction`
+//! and the neighbors of a solution are generated by a
+//! `operator::MutationOp`.
+//!
+//! The steps of the simulated annealing algorithm are:
+//!
+//! 1. **Initialize**: Evaluate the initial solution. It becomes the current
+//!    and the best solution.
+//! 2. **Neighbor**: Mutate the current solution to get a candidate solution
+//!    and evaluate it.
+//! 3. **Acceptance**: A candidate that is at least as fit as the current
+//!    solution always replaces it. A less fit candidate replaces it with the
+//!    probability `exp(-delta / temperature)`, where `delta` is the
+//!    difference between the fitness of the current solution and the
+//!    fitness of the candidate.
+//! 4. **Cooling**: Lower the temperature according to the
+//!    `CoolingSchedule`, so that worse candidates are accepted less often
+//!    the longer the algorithm runs.
+//! 5. **Loop**: Go to step 2
+//!
+//! Each iteration of the `SimulatedAnnealing` algorithm processes a
+//! configurable number of steps at the same temperature.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    algorithm::{Algorithm, BestSolution, Evaluated, OptimizationResult},
+    genetic::{AsScalar, Fitness, FitnessFunction, Genotype},
+    operator::MutationOp,
+    random::{Prng, Rng},
+    statistic::{timed, ProcessingTime, TrackProcessingTime},
+};
+use chrono::Local;
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+/// The `CoolingSchedule` determines the temperature of a
+/// `SimulatedAnnealing` algorithm in each iteration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoolingSchedule {
+    /// The temperature is multiplied by `factor` in each iteration, i.e. the
+    /// temperature in iteration `k` is `initial_temperature * factor^k`.
+    /// The factor is usually chosen between 0.8 and 0.99.
+    Exponential {
+        /// The factor the temperature is multiplied by.
+        factor: f64,
+    },
+    /// The temperature is lowered by `decrement` in each iteration until it
+    /// reaches 0, from when on only candidates that are at least as fit as
+    /// the current solution are accepted.
+    Linear {
+        /// The amount the temperature is lowered by.
+        decrement: f64,
+    },
+    /// The temperature in iteration `k` is
+    /// `initial_temperature / (1 + ln(1 + k))`. It cools down very slowly.
+    Logarithmic,
+}
+
+impl CoolingSchedule {
+    /// Returns the temperature in the given iteration for the given initial
+    /// temperature. Iterations are counted from 0.
+    pub fn temperature(&self, initial_temperature: f64, iteration: u64) -> f64 {
+        match *self {
+            CoolingSchedule::Exponential { factor } => {
+                initial_temperature * factor.powf(iteration as f64)
+            },
+            CoolingSchedule::Linear { decrement } => {
+                (initial_temperature - decrement * iteration as f64).max(0.)
+            },
+            CoolingSchedule::Logarithmic => {
+                initial_temperature / (1. + (1. + iteration as f64).ln())
+            },
+        }
+    }
+}
+
+impl Default for CoolingSchedule {
+    fn default() -> Self {
+        CoolingSchedule::Exponential { factor: 0.95 }
+    }
+}
+
+/// The `State` struct holds the results of one iteration of the simulated
+/// annealing algorithm.
+#[derive(Clone, Debug, PartialEq)]
+pub struct State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// The current solution after this iteration.
+    pub current_solution: Evaluated<G, F>,
+    /// The best solution found so far.
+    pub best_solution: BestSolution<G, F>,
+    /// The temperature of this iteration.
+    pub temperature: f64,
+    /// The number of candidates that replaced the current solution in this
+    /// iteration.
+    pub num_accepted: usize,
+    /// Processing time for this iteration.
+    pub processing_time: ProcessingTime,
+}
+
+impl<G, F> OptimizationResult<G, F> for State<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn best_solution(&self) -> &BestSolution<G, F> {
+        &self.best_solution
+    }
+}
+
+/// An error that can occur during execution of the `SimulatedAnnealing`
+/// algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SimulatedAnnealingError {
+    /// The initial temperature is negative or not a finite number.
+    InvalidTemperature(String),
+}
+
+impl Display for SimulatedAnnealingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimulatedAnnealingError::InvalidTemperature(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for SimulatedAnnealingError {}
+
+/// The `SimulatedAnnealing` algorithm improves a single solution by moving
+/// to neighbors generated by a `operator::MutationOp`. Worse neighbors are
+/// accepted with a probability that decreases with the temperature, which
+/// lets the search escape local optima early on.
+///
+/// The fitness values are converted to scalars by `genetic::AsScalar` to
+/// calculate the probability of accepting a worse candidate. The initial
+/// temperature should therefore be in the order of magnitude of typical
+/// fitness differences between neighbors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulatedAnnealing<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    _f: PhantomData<F>,
+    evaluator: E,
+    mutator: M,
+    initial_solution: G,
+    initial_temperature: f64,
+    cooling_schedule: CoolingSchedule,
+    steps_per_iteration: usize,
+    current: Option<Evaluated<G, F>>,
+    best: Option<BestSolution<G, F>>,
+    processing_time: ProcessingTime,
+}
+
+impl<G, F, E, M> SimulatedAnnealing<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    /// Constructs a new instance of the `SimulatedAnnealing` algorithm that
+    /// starts from the given initial solution at the given initial
+    /// temperature. By default it cools down exponentially by a factor of
+    /// 0.95 and processes one step per iteration.
+    pub fn new(evaluator: E, mutator: M, initial_solution: G, initial_temperature: f64) -> Self {
+        SimulatedAnnealing {
+            _f: PhantomData,
+            evaluator,
+            mutator,
+            initial_solution,
+            initial_temperature,
+            cooling_schedule: CoolingSchedule::default(),
+            steps_per_iteration: 1,
+            current: None,
+            best: None,
+            processing_time: ProcessingTime::zero(),
+        }
+    }
+
+    /// Sets the `CoolingSchedule` that determines the temperature in each
+    /// iteration.
+    pub fn with_cooling_schedule(mut self, cooling_schedule: CoolingSchedule) -> Self {
+        self.cooling_schedule = cooling_schedule;
+        self
+    }
+
+    /// Sets the number of candidates that are evaluated at the same
+    /// temperature in each iteration.
+    pub fn with_steps_per_iteration(mut self, steps_per_iteration: usize) -> Self {
+        self.steps_per_iteration = steps_per_iteration.max(1);
+        self
+    }
+
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    pub fn mutator(&self) -> &M {
+        &self.mutator
+    }
+
+    pub fn initial_solution(&self) -> &G {
+        &self.initial_solution
+    }
+
+    pub fn initial_temperature(&self) -> f64 {
+        self.initial_temperature
+    }
+
+    pub fn cooling_schedule(&self) -> CoolingSchedule {
+        self.cooling_schedule
+    }
+
+    pub fn steps_per_iteration(&self) -> usize {
+        self.steps_per_iteration
+    }
+
+    /// Returns the current solution, or `None` if the algorithm has not been
+    /// started yet.
+    pub fn current_solution(&self) -> Option<&Evaluated<G, F>> {
+        self.current.as_ref()
+    }
+
+    fn evaluate(&self, genome: G) -> Evaluated<G, F> {
+        let fitness = self.evaluator.fitness_of(&genome);
+        Evaluated { genome, fitness }
+    }
+
+    /// Processes the given number of steps at the given temperature and
+    /// returns the number of accepted candidates.
+    fn anneal(&mut self, iteration: u64, temperature: f64, rng: &mut Prng) -> usize {
+        let mut current = match self.current.take() {
+            Some(current) => current,
+            None => self.evaluate(self.initial_solution.clone()),
+        };
+        let mut best = self.best.take().unwrap_or_else(|| BestSolution {
+            found_at: Local::now(),
+            generation: iteration,
+            solution: current.clone(),
+        });
+        let mut num_accepted = 0;
+        for _ in 0..self.steps_per_iteration {
+            let candidate = self.evaluate(self.mutator.mutate(current.genome.clone(), rng));
+            if accepts(&current.fitness, &candidate.fitness, temperature, rng) {
+                current = candidate;
+                num_accepted += 1;
+                if current.fitness > best.solution.fitness {
+                    best = BestSolution {
+                        found_at: Local::now(),
+                        generation: iteration,
+                        solution: current.clone(),
+                    };
+                }
+            }
+        }
+        self.current = Some(current);
+        self.best = Some(best);
+        num_accepted
+    }
+}
+
+/// Decides whether a candidate with the given fitness replaces the current
+/// solution at the given temperature.
+fn accepts<F, R>(current: &F, candidate: &F, temperature: f64, rng: &mut R) -> bool
+where
+    F: Fitness + AsScalar,
+    R: Rng + Sized,
+{
+    if candidate >= current {
+        return true;
+    }
+    if temperature <= 0. {
+        return false;
+    }
+    let delta = (current.as_scalar() - candidate.as_scalar()).abs();
+    rng.gen::<f64>() < (-delta / temperature).exp()
+}
+
+impl<G, F, E, M> TrackProcessingTime for SimulatedAnnealing<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+}
+
+impl<G, F, E, M> Algorithm for SimulatedAnnealing<G, F, E, M>
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+    M: MutationOp<G>,
+{
+    type Output = State<G, F>;
+    type Error = SimulatedAnnealingError;
+
+    fn is_improvement(output: &Self::Output, best: &Self::Output) -> bool {
+        output.improves_on(best)
+    }
+
+    fn next(&mut self, iteration: u64, rng: &mut Prng) -> Result<Self::Output, Self::Error> {
+        if !self.initial_temperature.is_finite() || self.initial_temperature < 0. {
+            return Err(SimulatedAnnealingError::InvalidTemperature(format!(
+                "The initial temperature must be a non-negative finite number, but is {}.",
+                self.initial_temperature
+            )));
+        }
+        let temperature = self
+            .cooling_schedule
+            .temperature(self.initial_temperature, iteration.saturating_sub(1));
+        let annealing = timed(|| self.anneal(iteration, temperature, rng)).run();
+        self.processing_time = annealing.time;
+
+        Ok(State {
+            current_solution: self.current.clone().expect("current solution is evaluated"),
+            best_solution: self.best.clone().expect("best solution is evaluated"),
+            temperature,
+            num_accepted: annealing.result,
+            processing_time: self.processing_time,
+        })
+    }
+
+    fn reset(&mut self) -> Result<bool, Self::Error> {
+        self.processing_time = ProcessingTime::zero();
+        self.current = None;
+        self.best = None;
+        Ok(true)
+    }
+}
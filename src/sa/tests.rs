@@ -0,0 +1,225 @@
+use super::*;
+use crate::{
+    mutation::value::RandomValueMutator,
+    random::get_rng,
+    simulation::{simulator::simulate, SimResult, Simulation, SimulationBuilder},
+    termination::{
+        limit::{FitnessLimit, FitnessPlateau, GenerationLimit},
+        or,
+    },
+};
+use galvanic_assert::matchers::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        400
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+type SumOfValuesAnnealing =
+    SimulatedAnnealing<Vec<i32>, i32, SumOfValues, RandomValueMutator<Vec<i32>>>;
+
+fn sum_of_values_annealing() -> SumOfValuesAnnealing {
+    SimulatedAnnealing::new(
+        SumOfValues,
+        RandomValueMutator::new(0.25, 0, 100),
+        vec![0; 4],
+        50.,
+    )
+}
+
+mod cooling_schedule {
+
+    use super::*;
+
+    #[test]
+    fn exponential_schedule_multiplies_the_temperature_by_the_factor() {
+        let schedule = CoolingSchedule::Exponential { factor: 0.5 };
+
+        expect_that!(&schedule.temperature(100., 0), eq(100.));
+        expect_that!(&schedule.temperature(100., 1), eq(50.));
+        expect_that!(&schedule.temperature(100., 3), eq(12.5));
+    }
+
+    #[test]
+    fn linear_schedule_lowers_the_temperature_down_to_0() {
+        let schedule = CoolingSchedule::Linear { decrement: 30. };
+
+        expect_that!(&schedule.temperature(100., 0), eq(100.));
+        expect_that!(&schedule.temperature(100., 2), eq(40.));
+        expect_that!(&schedule.temperature(100., 4), eq(0.));
+    }
+
+    #[test]
+    fn logarithmic_schedule_starts_at_the_initial_temperature_and_decreases() {
+        let schedule = CoolingSchedule::Logarithmic;
+
+        expect_that!(&schedule.temperature(100., 0), eq(100.));
+        expect_that!(&schedule.temperature(100., 10), less_than(100.));
+        expect_that!(
+            &schedule.temperature(100., 100),
+            less_than(schedule.temperature(100., 10))
+        );
+    }
+}
+
+mod acceptance {
+
+    use super::*;
+
+    #[test]
+    fn better_and_equal_candidates_are_always_accepted() {
+        let mut rng = get_rng([1; 32]);
+
+        expect_that!(&accepts(&5, &6, 0., &mut rng), eq(true));
+        expect_that!(&accepts(&5, &5, 0., &mut rng), eq(true));
+    }
+
+    #[test]
+    fn worse_candidates_are_never_accepted_at_temperature_0() {
+        let mut rng = get_rng([1; 32]);
+
+        for _ in 0..100 {
+            expect_that!(&accepts(&5, &4, 0., &mut rng), eq(false));
+        }
+    }
+
+    #[test]
+    fn worse_candidates_are_accepted_more_often_at_higher_temperature() {
+        let mut rng = get_rng([1; 32]);
+        let mut count_accepted = |temperature: f64| {
+            (0..1_000)
+                .filter(|_| accepts(&50, &40, temperature, &mut rng))
+                .count()
+        };
+
+        let accepted_cold = count_accepted(1.);
+        let accepted_hot = count_accepted(100.);
+
+        expect_that!(&accepted_cold, less_than(accepted_hot));
+    }
+}
+
+mod simulated_annealing {
+
+    use super::*;
+
+    #[test]
+    fn first_iteration_starts_at_the_initial_temperature() {
+        let mut annealing = sum_of_values_annealing().with_steps_per_iteration(5);
+        let mut rng = get_rng([3; 32]);
+
+        let state = annealing.next(1, &mut rng).unwrap();
+
+        expect_that!(&state.temperature, eq(50.));
+        expect_that!(&state.num_accepted, less_than_or_equal(5));
+        expect_that!(
+            &state.best_solution.solution.fitness,
+            greater_than_or_equal(state.current_solution.fitness)
+        );
+    }
+
+    #[test]
+    fn best_solution_never_gets_worse() {
+        let mut annealing = sum_of_values_annealing();
+        let mut rng = get_rng([3; 32]);
+        let mut best_fitness = 0;
+
+        for iteration in 1..=50 {
+            let state = annealing.next(iteration, &mut rng).unwrap();
+
+            expect_that!(
+                &state.best_solution.solution.fitness,
+                greater_than_or_equal(best_fitness)
+            );
+            best_fitness = state.best_solution.solution.fitness;
+        }
+    }
+
+    #[test]
+    fn reset_starts_again_from_the_initial_solution() {
+        let mut annealing = sum_of_values_annealing();
+        let mut rng = get_rng([3; 32]);
+        for iteration in 1..=10 {
+            annealing.next(iteration, &mut rng).unwrap();
+        }
+
+        annealing.reset().unwrap();
+
+        expect_that!(&annealing.current_solution(), eq(None));
+    }
+
+    #[test]
+    fn negative_initial_temperature_is_an_error() {
+        let mut annealing = SimulatedAnnealing::new(
+            SumOfValues,
+            RandomValueMutator::new(0.25, 0, 100),
+            vec![0; 4],
+            -1.,
+        );
+        let mut rng = get_rng([3; 32]);
+
+        let result = annealing.next(1, &mut rng);
+
+        expect_that!(
+            &result,
+            eq(Err(SimulatedAnnealingError::InvalidTemperature(
+                "The initial temperature must be a non-negative finite number, but is -1."
+                    .to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn simulator_runs_simulated_annealing_until_the_fitness_limit_is_reached() {
+        let annealing = sum_of_values_annealing()
+            .with_cooling_schedule(CoolingSchedule::Exponential { factor: 0.9 })
+            .with_steps_per_iteration(20);
+        let mut simulator = simulate(annealing)
+            .until(or(FitnessLimit::new(360), GenerationLimit::new(10_000)))
+            .build_with_seed([7; 32]);
+
+        let result = simulator.run().unwrap();
+
+        match result {
+            SimResult::Final(state, _, _, stop_reason, _) => {
+                expect_that!(&stop_reason.contains("fitness"), eq(true));
+                expect_that!(
+                    &state.result.best_solution.solution.fitness,
+                    greater_than_or_equal(360)
+                );
+            },
+            SimResult::Intermediate(_) => panic!("expected final result"),
+        }
+    }
+
+    #[test]
+    fn simulator_reports_the_best_solution_of_simulated_annealing() {
+        let mut simulator = simulate(sum_of_values_annealing())
+            .until(or(FitnessPlateau::new(0, 30), GenerationLimit::new(1_000)))
+            .with_best_solution_fitness()
+            .build_with_seed([7; 32]);
+        let handle = simulator.handle();
+
+        let result = simulator.run().unwrap();
+
+        let best_fitness = result.state().best_solution().solution.fitness;
+        expect_that!(&result.stop_reason().is_some(), eq(true));
+        expect_that!(&handle.best_fitness(), eq(Some(best_fitness as f64)));
+    }
+}
@@ -7,6 +7,7 @@
 //! * `ranking`
 //! * `proportionate`
 //! * `tournament`
+//! * `nsga2` (with crate feature `multi-objective`)
 
 pub mod proportionate;
 
@@ -15,3 +16,6 @@ pub mod ranking;
 pub mod tournament;
 
 pub mod truncation;
+
+#[cfg(feature = "multi-objective")]
+pub mod nsga2;
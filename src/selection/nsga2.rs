@@ -0,0 +1,210 @@
+//! The `nsga2` module provides the selection of the NSGA-II algorithm
+//! (Non-dominated Sorting Genetic Algorithm II) for multi-objective
+//! optimization.
+//!
+//! The provided `SelectionOp` implementations are:
+//! * `Nsga2Selector`
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{AsScalar, Fitness, Genotype, Parents},
+    operator::{
+        count_of_ratio, float_parameter, unknown_parameter, usize_parameter, GeneticOperator,
+        MultiObjective, OperatorParameterError, ParamRange, ParamSpace, ParentDemand, SelectionOp,
+        TunableOperator, NUM_INDIVIDUALS_PER_PARENTS, SELECTION_RATIO,
+    },
+    pareto::{CrowdedRanking, Pareto},
+    random::{random_index, Rng},
+};
+use std::cmp::Ordering;
+
+/// The `Nsga2Selector` selects parents by crowded tournaments, as in the
+/// NSGA-II algorithm.
+///
+/// The population is sorted into non-dominated fronts by the
+/// `pareto::Pareto` fitness values of the individuals. Each tournament is won
+/// by the participant of the lowest front. Among participants of the same
+/// front the one with the largest crowding distance wins, which keeps the
+/// population spread along the Pareto front.
+///
+/// This `Nsga2Selector` can only be used for multi-objective fitness values.
+/// It should be combined with the `reinsertion::nsga2::Nsga2Reinserter`.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Nsga2Selector {
+    /// The fraction of number of parents to select in relation to the
+    /// number of individuals in the population.
+    selection_ratio: f64,
+    /// The number of individuals per parents.
+    num_individuals_per_parents: usize,
+    /// The number of participants on each tournament.
+    tournament_size: usize,
+}
+
+impl Nsga2Selector {
+    /// Constructs a new instance of the `Nsga2Selector` that runs binary
+    /// tournaments.
+    pub fn new(selection_ratio: f64, num_individuals_per_parents: usize) -> Self {
+        Nsga2Selector {
+            selection_ratio,
+            num_individuals_per_parents,
+            tournament_size: 2,
+        }
+    }
+
+    /// Sets the number of participants on each tournament.
+    pub fn with_tournament_size(mut self, tournament_size: usize) -> Self {
+        self.tournament_size = tournament_size;
+        self
+    }
+
+    /// Returns the selection ratio.
+    ///
+    /// The selection ratio is the fraction of number of parents that are
+    /// selected on every call of the `select_from` function and the number
+    /// of individuals in the population.
+    pub fn selection_ratio(&self) -> f64 {
+        self.selection_ratio
+    }
+
+    /// Sets the selection ratio to a new value.
+    pub fn set_selection_ratio(&mut self, value: f64) {
+        self.selection_ratio = value;
+    }
+
+    /// Returns the number of individuals per parents use by this selector.
+    pub fn num_individuals_per_parents(&self) -> usize {
+        self.num_individuals_per_parents
+    }
+
+    /// Sets the number of individuals per parents to the given value.
+    pub fn set_num_individuals_per_parents(&mut self, value: usize) {
+        self.num_individuals_per_parents = value;
+    }
+
+    /// Returns the size of one tournament.
+    pub fn tournament_size(&self) -> usize {
+        self.tournament_size
+    }
+
+    /// Sets the size of one tournament to a given value. The value must be
+    /// a positive integer greater 0.
+    pub fn set_tournament_size(&mut self, value: usize) {
+        self.tournament_size = value;
+    }
+
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, T, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, Pareto<T>>,
+        num_parents_to_select: usize,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        G: Genotype,
+        T: Fitness + AsScalar,
+        R: Rng + Sized,
+    {
+        let individuals = evaluated.individuals();
+        if individuals.is_empty() {
+            return Vec::new();
+        }
+        let ranking = CrowdedRanking::of(evaluated.fitness_values());
+        let mut selected: Vec<Parents<G>> = Vec::with_capacity(num_parents_to_select);
+        for _ in 0..num_parents_to_select {
+            let mut tuple = Vec::with_capacity(self.num_individuals_per_parents);
+            for _ in 0..self.num_individuals_per_parents {
+                let mut winner = random_index(rng, individuals.len());
+                for _ in 1..self.tournament_size {
+                    let participant = random_index(rng, individuals.len());
+                    if ranking.compare(participant, winner) == Ordering::Greater {
+                        winner = participant;
+                    }
+                }
+                tuple.push(individuals[winner].clone());
+            }
+            selected.push(tuple);
+        }
+        selected
+    }
+}
+
+/// Can be used for multi-objective optimization
+impl MultiObjective for Nsga2Selector {}
+
+impl GeneticOperator for Nsga2Selector {
+    fn name() -> String {
+        "NSGA-II-Selection".to_string()
+    }
+}
+
+impl TunableOperator for Nsga2Selector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec![
+            "selection_ratio",
+            "num_individuals_per_parents",
+            "tournament_size",
+        ]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            "tournament_size" => Some(self.tournament_size as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            "tournament_size" => self.tournament_size = usize_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+            .with(ParamRange::integer("tournament_size", 1., f64::INFINITY))
+    }
+}
+
+impl<G, T> SelectionOp<G, Pareto<T>> for Nsga2Selector
+where
+    G: Genotype,
+    T: Fitness + AsScalar,
+{
+    fn select_from<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, Pareto<T>>,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, Pareto<T>>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+}
@@ -13,11 +13,18 @@
 //! The provided **fitness proportionate selection** operators are:
 //! * `RouletteWheelSelector` - no bias - does not guarantee minimal spread.
 //! * `UniversalSamplingSelector` - no bias - minimal spread.
+//! * `BoltzmannSelector` - selection pressure increases over the
+//!   generations - does not guarantee minimal spread.
 
 use crate::{
     algorithm::EvaluatedPopulation,
     genetic::{AsScalar, Fitness, Genotype, Parents},
-    operator::{GeneticOperator, SelectionOp, SingleObjective},
+    operator::{
+        count_of_ratio, float_parameter, unknown_parameter, usize_parameter, GenerationAware,
+        GenerationFeedback, GeneticOperator, OperatorParameterError, ParamRange, ParamSpace,
+        ParentDemand, SelectionOp, SingleObjective, TunableOperator, NUM_INDIVIDUALS_PER_PARENTS,
+        SELECTION_RATIO,
+    },
     random::{random_probability, Rng, WeightedDistribution},
 };
 
@@ -82,18 +89,52 @@ impl GeneticOperator for RouletteWheelSelector {
     }
 }
 
-impl<G, F> SelectionOp<G, F> for RouletteWheelSelector
-where
-    G: Genotype,
-    F: Fitness + AsScalar,
-{
-    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+impl TunableOperator for RouletteWheelSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["selection_ratio", "num_individuals_per_parents"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+    }
+}
+
+impl RouletteWheelSelector {
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
     where
+        G: Genotype,
+        F: Fitness + AsScalar,
         R: Rng + Sized,
     {
         let individuals = evaluated.individuals();
-        let num_parents_to_select =
-            (individuals.len() as f64 * self.selection_ratio + 0.5).floor() as usize;
         let mut parents = Vec::with_capacity(num_parents_to_select);
         let weighted_distribution =
             WeightedDistribution::from_scalar_values(evaluated.fitness_values());
@@ -110,6 +151,34 @@ where
     }
 }
 
+impl<G, F> SelectionOp<G, F> for RouletteWheelSelector
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+}
+
 /// The `UniversalSamplingSelector` implements stochastic fitness proportionate
 /// selection. The first candidate is picked randomly. All other candidates are
 /// picked by equidistant jumps.
@@ -171,24 +240,60 @@ impl GeneticOperator for UniversalSamplingSelector {
     }
 }
 
-impl<G, F> SelectionOp<G, F> for UniversalSamplingSelector
-where
-    G: Genotype,
-    F: Fitness + AsScalar,
-{
-    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+impl TunableOperator for UniversalSamplingSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["selection_ratio", "num_individuals_per_parents"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+    }
+}
+
+impl UniversalSamplingSelector {
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
     where
+        G: Genotype,
+        F: Fitness + AsScalar,
         R: Rng + Sized,
     {
         let individuals = evaluated.individuals();
-        let num_parents_to_select =
-            (individuals.len() as f64 * self.selection_ratio + 0.5).floor() as usize;
         let mut parents = Vec::with_capacity(num_parents_to_select);
         let weighted_distribution =
             WeightedDistribution::from_scalar_values(evaluated.fitness_values());
         let distance = weighted_distribution.sum()
             / (num_parents_to_select * self.num_individuals_per_parents) as f64;
-        let mut pointer = random_probability(rng) * weighted_distribution.sum();
+        // the first pointer is placed in the first interval so that the
+        // last pointer does not exceed the sum of the weights
+        let mut pointer = random_probability(rng) * distance;
         for _ in 0..num_parents_to_select {
             let mut tuple = Vec::with_capacity(self.num_individuals_per_parents);
             for _ in 0..self.num_individuals_per_parents {
@@ -201,3 +306,313 @@ where
         parents
     }
 }
+
+impl<G, F> SelectionOp<G, F> for UniversalSamplingSelector
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+}
+
+/// The `BoltzmannSelector` implements stochastic Boltzmann selection. Each
+/// candidate is picked randomly with a probability of being picked that is
+/// proportional to `exp(f / T)`, where `f` is its fitness value and `T` is
+/// the temperature of the current generation.
+///
+/// The temperature decays over the generations like in simulated annealing:
+/// in generation `g` it is `initial_temperature * cooling_rate^(g - 1)`, but
+/// not lower than the minimum temperature. At a high temperature all
+/// candidates are picked with nearly the same probability. The lower the
+/// temperature gets the more the fittest candidates are preferred.
+///
+/// The selector learns the current generation through the
+/// `operator::GenerationAware` hook, which is called by the
+/// `ga::GeneticAlgorithm` before each selection.
+///
+/// Characteristics: selection pressure increases over the generations, does
+/// not guarantee minimal spread.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoltzmannSelector {
+    /// The fraction of number of parents to select in relation to the
+    /// number of individuals in the population.
+    selection_ratio: f64,
+    /// The number of individuals per parents.
+    num_individuals_per_parents: usize,
+    /// The temperature in the first generation.
+    initial_temperature: f64,
+    /// The factor by which the temperature decays from one generation to
+    /// the next.
+    cooling_rate: f64,
+    /// The lower bound of the temperature.
+    min_temperature: f64,
+    /// The temperature of the current generation.
+    temperature: f64,
+}
+
+impl BoltzmannSelector {
+    /// Constructs a new instance of `BoltzmannSelector`. The minimum
+    /// temperature is 0.
+    pub fn new(
+        selection_ratio: f64,
+        num_individuals_per_parents: usize,
+        initial_temperature: f64,
+        cooling_rate: f64,
+    ) -> Self {
+        BoltzmannSelector {
+            selection_ratio,
+            num_individuals_per_parents,
+            initial_temperature,
+            cooling_rate,
+            min_temperature: 0.,
+            temperature: initial_temperature,
+        }
+    }
+
+    /// Sets the lower bound of the temperature.
+    pub fn with_min_temperature(mut self, min_temperature: f64) -> Self {
+        self.min_temperature = min_temperature;
+        self.temperature = self.temperature.max(min_temperature);
+        self
+    }
+
+    /// Returns the selection ratio.
+    ///
+    /// The selection ratio is the fraction of number of parents that are
+    /// selected on every call of the `select_from` function and the number
+    /// of individuals in the population.
+    pub fn selection_ratio(&self) -> f64 {
+        self.selection_ratio
+    }
+
+    /// Sets the selection ratio to a new value.
+    ///
+    /// The selection ratio is the fraction of number of parents that are
+    /// selected on every call of the `select_from` function and the number
+    /// of individuals in the population.
+    pub fn set_selection_ratio(&mut self, value: f64) {
+        self.selection_ratio = value;
+    }
+
+    /// Returns the number of individuals per parents use by this selector.
+    pub fn num_individuals_per_parents(&self) -> usize {
+        self.num_individuals_per_parents
+    }
+
+    /// Sets the number of individuals per parents to the given value.
+    pub fn set_num_individuals_per_parents(&mut self, value: usize) {
+        self.num_individuals_per_parents = value;
+    }
+
+    /// Returns the temperature in the first generation.
+    pub fn initial_temperature(&self) -> f64 {
+        self.initial_temperature
+    }
+
+    /// Sets the temperature in the first generation.
+    pub fn set_initial_temperature(&mut self, value: f64) {
+        self.initial_temperature = value;
+    }
+
+    /// Returns the factor by which the temperature decays from one
+    /// generation to the next.
+    pub fn cooling_rate(&self) -> f64 {
+        self.cooling_rate
+    }
+
+    /// Sets the factor by which the temperature decays from one generation
+    /// to the next.
+    pub fn set_cooling_rate(&mut self, value: f64) {
+        self.cooling_rate = value;
+    }
+
+    /// Returns the lower bound of the temperature.
+    pub fn min_temperature(&self) -> f64 {
+        self.min_temperature
+    }
+
+    /// Sets the lower bound of the temperature.
+    pub fn set_min_temperature(&mut self, value: f64) {
+        self.min_temperature = value;
+    }
+
+    /// Returns the temperature of the current generation.
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Returns the temperature of the given generation. Generations are
+    /// counted from 1.
+    pub fn temperature_of_generation(&self, generation: u64) -> f64 {
+        let exponent = i32::try_from(generation.saturating_sub(1)).unwrap_or(i32::MAX);
+        (self.initial_temperature * self.cooling_rate.powi(exponent)).max(self.min_temperature)
+    }
+}
+
+impl SingleObjective for BoltzmannSelector {}
+
+impl GeneticOperator for BoltzmannSelector {
+    fn name() -> String {
+        "Boltzmann-Selection".to_string()
+    }
+
+    fn as_generation_aware(&mut self) -> Option<&mut dyn GenerationAware> {
+        Some(self)
+    }
+}
+
+impl GenerationAware for BoltzmannSelector {
+    fn generation_evaluated(&mut self, feedback: &GenerationFeedback) {
+        self.temperature = self.temperature_of_generation(feedback.generation);
+    }
+}
+
+impl TunableOperator for BoltzmannSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec![
+            "selection_ratio",
+            "num_individuals_per_parents",
+            "initial_temperature",
+            "cooling_rate",
+            "min_temperature",
+        ]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            "initial_temperature" => Some(self.initial_temperature),
+            "cooling_rate" => Some(self.cooling_rate),
+            "min_temperature" => Some(self.min_temperature),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            "initial_temperature" => self.initial_temperature = float_parameter(name, value)?,
+            "cooling_rate" => self.cooling_rate = float_parameter(name, value)?,
+            "min_temperature" => self.min_temperature = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+            .with(ParamRange::float("initial_temperature", 0., f64::INFINITY))
+            .with(ParamRange::float("cooling_rate", 0., 1.))
+            .with(ParamRange::float("min_temperature", 0., f64::INFINITY))
+    }
+}
+
+impl BoltzmannSelector {
+    /// Returns the cumulative Boltzmann weights of the given fitness values
+    /// at the current temperature.
+    fn cumulative_weights<F>(&self, fitness_values: &[F]) -> Vec<f64>
+    where
+        F: AsScalar,
+    {
+        let temperature = self.temperature.max(f64::MIN_POSITIVE);
+        let highest = fitness_values
+            .iter()
+            .map(AsScalar::as_scalar)
+            .fold(f64::NEG_INFINITY, f64::max);
+        // the weights are scaled by exp(-highest / T) to avoid an overflow
+        let mut sum = 0.;
+        fitness_values
+            .iter()
+            .map(|fitness| {
+                sum += ((fitness.as_scalar() - highest) / temperature).exp();
+                sum
+            })
+            .collect()
+    }
+
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        G: Genotype,
+        F: Fitness + AsScalar,
+        R: Rng + Sized,
+    {
+        let individuals = evaluated.individuals();
+        let mut parents = Vec::with_capacity(num_parents_to_select);
+        let cumulative_weights = self.cumulative_weights(evaluated.fitness_values());
+        let sum = cumulative_weights.last().copied().unwrap_or(0.);
+        for _ in 0..num_parents_to_select {
+            let mut tuple = Vec::with_capacity(self.num_individuals_per_parents);
+            for _ in 0..self.num_individuals_per_parents {
+                let random = random_probability(rng) * sum;
+                let selected = cumulative_weights
+                    .partition_point(|weight| *weight < random)
+                    .min(individuals.len() - 1);
+                tuple.push(individuals[selected].clone());
+            }
+            parents.push(tuple);
+        }
+        parents
+    }
+}
+
+impl<G, F> SelectionOp<G, F> for BoltzmannSelector
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+}
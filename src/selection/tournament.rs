@@ -6,7 +6,11 @@
 use crate::{
     algorithm::EvaluatedPopulation,
     genetic::{Fitness, Genotype, Parents},
-    operator::{GeneticOperator, MultiObjective, SelectionOp, SingleObjective},
+    operator::{
+        count_of_ratio, float_parameter, unknown_parameter, usize_parameter, GeneticOperator,
+        MultiObjective, OperatorParameterError, ParamRange, ParamSpace, ParentDemand, SelectionOp,
+        SingleObjective, TunableOperator, NUM_INDIVIDUALS_PER_PARENTS, SELECTION_RATIO,
+    },
     random::{random_index, random_probability, Rng},
 };
 
@@ -148,13 +152,60 @@ impl GeneticOperator for TournamentSelector {
     }
 }
 
-impl<G, F> SelectionOp<G, F> for TournamentSelector
-where
-    G: Genotype,
-    F: Fitness,
-{
-    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+impl TunableOperator for TournamentSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec![
+            "selection_ratio",
+            "num_individuals_per_parents",
+            "tournament_size",
+            "probability",
+        ]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            "tournament_size" => Some(self.tournament_size as f64),
+            "probability" => Some(self.probability),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            "tournament_size" => self.tournament_size = usize_parameter(name, value)?,
+            "probability" => self.probability = float_parameter(name, value)?,
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+            .with(ParamRange::integer("tournament_size", 1., f64::INFINITY))
+            .with(ParamRange::float("probability", 0., 1.))
+    }
+}
+
+impl TournamentSelector {
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
     where
+        G: Genotype,
+        F: Fitness,
         R: Rng + Sized,
     {
         let individuals = evaluated.individuals();
@@ -163,8 +214,6 @@ where
         // mating pool holds indices to the individuals and fitness_values slices
         let mut mating_pool: Vec<usize> = (0..fitness_values.len()).collect();
 
-        let num_parents_to_select =
-            (individuals.len() as f64 * self.selection_ratio + 0.5).floor() as usize;
         let target_num_candidates = num_parents_to_select * self.num_individuals_per_parents;
 
         // select candidates for parents
@@ -188,7 +237,7 @@ where
             // pick candidates with probability
             let mut prob = self.probability;
             let mut prob_redux = 1.;
-            while prob > 0. {
+            while prob > 0. && !tournament.is_empty() {
                 if random_probability(rng) <= prob {
                     let picked = tournament.remove(0);
                     if self.remove_selected_individuals {
@@ -203,17 +252,46 @@ where
                 prob *= prob_redux;
             }
         }
-        // convert selected candidate indices to parents of individuals
-        let mut selected: Vec<Parents<G>> = Vec::with_capacity(num_parents_to_select);
-        while !picked_candidates.is_empty() {
-            let mut tuple = Vec::with_capacity(self.num_individuals_per_parents);
-            for _ in 0..self.num_individuals_per_parents {
-                // index into individuals slice
-                let index_i = picked_candidates.remove(0);
-                tuple.push(individuals[index_i].clone());
-            }
-            selected.push(tuple);
-        }
-        selected
+        // drop the surplus candidates picked in the last tournament
+        picked_candidates.truncate(target_num_candidates);
+        // convert selected candidate indices to parents of individuals, only
+        // complete groups of parents are returned
+        picked_candidates
+            .chunks_exact(self.num_individuals_per_parents.max(1))
+            .map(|candidates| {
+                candidates
+                    .iter()
+                    .map(|index_i| individuals[*index_i].clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<G, F> SelectionOp<G, F> for TournamentSelector
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
     }
 }
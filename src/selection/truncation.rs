@@ -4,11 +4,16 @@
 //!
 //! The provided `SelectionOp` implementations are:
 //! * `MaximizeSelector`
+//! * `TruncationSelector`
 
 use crate::{
     algorithm::EvaluatedPopulation,
     genetic::{Fitness, Genotype, Parents},
-    operator::{GeneticOperator, MultiObjective, SelectionOp, SingleObjective},
+    operator::{
+        count_of_ratio, float_parameter, unknown_parameter, usize_parameter, GeneticOperator,
+        MultiObjective, OperatorParameterError, ParamRange, ParamSpace, ParentDemand, SelectionOp,
+        SingleObjective, TunableOperator, NUM_INDIVIDUALS_PER_PARENTS, SELECTION_RATIO,
+    },
     random::Rng,
 };
 
@@ -78,26 +83,60 @@ impl GeneticOperator for MaximizeSelector {
     }
 }
 
-impl<G, F> SelectionOp<G, F> for MaximizeSelector
-where
-    G: Genotype,
-    F: Fitness,
-{
-    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, _: &mut R) -> Vec<Parents<G>>
+impl TunableOperator for MaximizeSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec!["selection_ratio", "num_individuals_per_parents"]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+    }
+}
+
+impl MaximizeSelector {
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F, R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+        _rng: &mut R,
+    ) -> Vec<Parents<G>>
     where
+        G: Genotype,
+        F: Fitness,
         R: Rng + Sized,
     {
         let individuals = evaluated.individuals();
         let fitness_values = evaluated.fitness_values();
 
         // mating pool holds indices to the individuals and fitness_values slices
-        let mut mating_pool: Vec<usize> = (0..fitness_values.len()).collect();
-        // sort mating pool from best performing to worst performing index
-        mating_pool.sort_by(|x, y| fitness_values[*y].cmp(&fitness_values[*x]));
-        let mating_pool = mating_pool;
+        // ordered from best performing to worst performing index. Only as
+        // many individuals as are picked need to be ordered.
+        let num_picked = num_parents_to_select * self.num_individuals_per_parents;
+        let mating_pool = evaluated.top_k_indices(num_picked.min(fitness_values.len()));
 
-        let num_parents_to_select =
-            (individuals.len() as f64 * self.selection_ratio + 0.5).floor() as usize;
         let pool_size = mating_pool.len();
         let mut selected: Vec<Parents<G>> = Vec::with_capacity(num_parents_to_select);
 
@@ -117,3 +156,235 @@ where
         selected
     }
 }
+
+impl<G, F> SelectionOp<G, F> for MaximizeSelector
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select, rng)
+    }
+}
+
+/// The `TruncationSelector` selects parents only from the best performing
+/// `genetic::Genotype`s of the population. The truncation threshold is the
+/// fraction of the population that forms the mating pool, e.g. a threshold
+/// of 0.2 allows only the best 20 % of the individuals to become parents.
+/// The lower the threshold the higher the selection pressure.
+///
+/// Unlike the `MaximizeSelector`, whose mating pool grows with the number of
+/// parents to be selected, the size of the mating pool is independent of the
+/// selection ratio. The selection is deterministic: the parents are built by
+/// going through the mating pool in the order of fitness, so every individual
+/// of the mating pool leads the same number of parents. On each pass through
+/// the mating pool the mates of an individual are the individuals that are
+/// one further rank apart, so that the same parents are not combined twice
+/// as long as the mating pool is large enough.
+///
+/// This `TruncationSelector` can be used for single-objective fitness values
+/// as well as multi-objective fitness values.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TruncationSelector {
+    /// The fraction of the population that forms the mating pool.
+    truncation_threshold: f64,
+    /// The ratio between the number of parents to be selected and the size
+    /// of the population.
+    selection_ratio: f64,
+    /// The number of individuals per parents.
+    num_individuals_per_parents: usize,
+}
+
+impl TruncationSelector {
+    /// Constructs a new instance of the `TruncationSelector`.
+    pub fn new(
+        truncation_threshold: f64,
+        selection_ratio: f64,
+        num_individuals_per_parents: usize,
+    ) -> Self {
+        TruncationSelector {
+            truncation_threshold,
+            selection_ratio,
+            num_individuals_per_parents,
+        }
+    }
+
+    /// Returns the truncation threshold.
+    ///
+    /// The truncation threshold is the fraction of the population that forms
+    /// the mating pool.
+    pub fn truncation_threshold(&self) -> f64 {
+        self.truncation_threshold
+    }
+
+    /// Sets the truncation threshold to a new value.
+    ///
+    /// The truncation threshold is the fraction of the population that forms
+    /// the mating pool.
+    pub fn set_truncation_threshold(&mut self, value: f64) {
+        self.truncation_threshold = value;
+    }
+
+    /// Returns the selection ratio.
+    ///
+    /// The selection ratio is the fraction of number of parents that are
+    /// selected on every call of the `selection` function and the number
+    /// of individuals in the population.
+    pub fn selection_ratio(&self) -> f64 {
+        self.selection_ratio
+    }
+
+    /// Sets the selection ratio to a new value.
+    ///
+    /// The selection ratio is the fraction of number of parents that are
+    /// selected on every call of the `selection` function and the number
+    /// of individuals in the population.
+    pub fn set_selection_ratio(&mut self, value: f64) {
+        self.selection_ratio = value;
+    }
+
+    /// Returns the number of individuals per parents use by this selector.
+    pub fn num_individuals_per_parents(&self) -> usize {
+        self.num_individuals_per_parents
+    }
+
+    /// Sets the number of individuals per parents to the given value.
+    pub fn set_num_individuals_per_parents(&mut self, value: usize) {
+        self.num_individuals_per_parents = value;
+    }
+
+    /// Returns the number of individuals in the mating pool for a population
+    /// of the given size, which is at least 1 for a non-empty population.
+    pub fn mating_pool_size(&self, population_size: usize) -> usize {
+        count_of_ratio(population_size, self.truncation_threshold)
+            .clamp(population_size.min(1), population_size)
+    }
+
+    /// Selects the given number of groups of parents.
+    fn select_parents<G, F>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        num_parents_to_select: usize,
+    ) -> Vec<Parents<G>>
+    where
+        G: Genotype,
+        F: Fitness,
+    {
+        let individuals = evaluated.individuals();
+        let mating_pool = evaluated.top_k_indices(self.mating_pool_size(individuals.len()));
+        let pool_size = mating_pool.len();
+        if pool_size == 0 {
+            return Vec::new();
+        }
+
+        (0..num_parents_to_select)
+            .map(|index_p| {
+                let first = index_p % pool_size;
+                let distance = index_p / pool_size + 1;
+                (0..self.num_individuals_per_parents)
+                    .map(|index_t| {
+                        let index_m = (first + index_t * distance) % pool_size;
+                        individuals[mating_pool[index_m]].clone()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Can be used for single-objective optimization
+impl SingleObjective for TruncationSelector {}
+/// Can be used for multi-objective optimization
+impl MultiObjective for TruncationSelector {}
+
+impl GeneticOperator for TruncationSelector {
+    fn name() -> String {
+        "Truncation-Selection".to_string()
+    }
+}
+
+impl TunableOperator for TruncationSelector {
+    fn parameter_names(&self) -> Vec<&'static str> {
+        vec![
+            "truncation_threshold",
+            "selection_ratio",
+            "num_individuals_per_parents",
+        ]
+    }
+
+    fn parameter(&self, name: &str) -> Option<f64> {
+        match name {
+            "truncation_threshold" => Some(self.truncation_threshold),
+            "selection_ratio" => Some(self.selection_ratio),
+            "num_individuals_per_parents" => Some(self.num_individuals_per_parents as f64),
+            _ => None,
+        }
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), OperatorParameterError> {
+        let value = self.parameter_space().validate(name, value)?;
+        match name {
+            "truncation_threshold" => self.truncation_threshold = float_parameter(name, value)?,
+            "selection_ratio" => self.selection_ratio = float_parameter(name, value)?,
+            "num_individuals_per_parents" => {
+                self.num_individuals_per_parents = usize_parameter(name, value)?
+            },
+            _ => return Err(unknown_parameter(name)),
+        }
+        Ok(())
+    }
+
+    fn parameter_space(&self) -> ParamSpace {
+        ParamSpace::new()
+            .with(ParamRange::float("truncation_threshold", 0., 1.))
+            .with(SELECTION_RATIO)
+            .with(NUM_INDIVIDUALS_PER_PARENTS)
+    }
+}
+
+impl<G, F> SelectionOp<G, F> for TruncationSelector
+where
+    G: Genotype,
+    F: Fitness,
+{
+    fn select_from<R>(&self, evaluated: &EvaluatedPopulation<G, F>, _rng: &mut R) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select =
+            count_of_ratio(evaluated.individuals().len(), self.selection_ratio);
+        self.select_parents(evaluated, num_parents_to_select)
+    }
+
+    fn select_for<R>(
+        &self,
+        evaluated: &EvaluatedPopulation<G, F>,
+        demand: ParentDemand,
+        _rng: &mut R,
+    ) -> Vec<Parents<G>>
+    where
+        R: Rng + Sized,
+    {
+        let num_parents_to_select = demand.num_parent_groups(self.num_individuals_per_parents);
+        self.select_parents(evaluated, num_parents_to_select)
+    }
+}
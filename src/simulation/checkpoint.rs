@@ -0,0 +1,134 @@
+//! The `checkpoint` module provides the `Checkpoint` of a
+//! `simulator::Simulator`, which allows to interrupt a long-running
+//! simulation and resume it later.
+//!
+//! A `Checkpoint` holds the progress of the simulation, i.e. the master
+//! seed, the state of the random number generator, the iteration counter,
+//! the accumulated processing time and the best result found so far,
+//! together with a snapshot of the progress of the `algorithm::Algorithm`,
+//! e.g. the current population of a genetic algorithm. It does not hold the
+//! configuration of the algorithm and the termination criteria. Those are
+//! provided again when the simulation is resumed.
+//!
+//! The state of the termination criteria is not saved either. They start
+//! anew when the simulation is resumed, e.g. a `termination::limit::Budget`
+//! counts the fitness evaluations from the checkpoint on, and a
+//! `termination::limit::FitnessPlateau` counts the stagnant generations
+//! from the checkpoint on. Criteria that only look at the `State` of an
+//! iteration, like the `termination::limit::GenerationLimit`, are not
+//! affected.
+//!
+//! With the `serde` feature enabled a `Checkpoint` can be serialized, e.g.
+//! to save it to disk.
+//!
+//! A checkpoint is saved between two iterations. A simulation that is
+//! resumed from a checkpoint produces the same sequence of iterations as the
+//! original simulation would have produced, given that the algorithm is
+//! configured the same way, and reports the same best result unless the
+//! termination criteria stop it at another iteration.
+
+use crate::{
+    algorithm::{Algorithm, Checkpointable},
+    random::{Prng, Seed},
+    statistic::ProcessingTime,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `Checkpoint` holds the progress of a `simulator::Simulator` with the
+/// best result `O` found so far and a snapshot `S` of its
+/// `algorithm::Checkpointable` algorithm.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Checkpoint<S, O> {
+    snapshot: S,
+    seed: Seed,
+    rng: Prng,
+    iteration: u64,
+    processing_time: ProcessingTime,
+    last_improvement: u64,
+    best_output: Option<O>,
+}
+
+impl<S, O> Checkpoint<S, O> {
+    pub(crate) fn new(
+        snapshot: S,
+        seed: Seed,
+        rng: Prng,
+        iteration: u64,
+        processing_time: ProcessingTime,
+        last_improvement: u64,
+        best_output: Option<O>,
+    ) -> Self {
+        Checkpoint {
+            snapshot,
+            seed,
+            rng,
+            iteration,
+            processing_time,
+            last_improvement,
+            best_output,
+        }
+    }
+
+    /// Returns the snapshot of the progress of the algorithm.
+    pub fn snapshot(&self) -> &S {
+        &self.snapshot
+    }
+
+    /// Returns the master seed of the simulation that has been checkpointed.
+    pub fn seed(&self) -> Seed {
+        self.seed
+    }
+
+    /// Returns the number of the last iteration processed before the
+    /// checkpoint has been saved.
+    pub fn iteration(&self) -> u64 {
+        self.iteration
+    }
+
+    /// Returns the processing time accumulated over all iterations before
+    /// the checkpoint has been saved.
+    pub fn processing_time(&self) -> ProcessingTime {
+        self.processing_time
+    }
+
+    /// Returns the number of the iteration in which the best solution was
+    /// last improved.
+    pub fn last_improvement(&self) -> u64 {
+        self.last_improvement
+    }
+
+    /// Returns the best result of all iterations processed before the
+    /// checkpoint has been saved, or `None` if no iteration has been
+    /// processed.
+    pub fn best_output(&self) -> Option<&O> {
+        self.best_output.as_ref()
+    }
+
+    pub(crate) fn into_parts(self) -> CheckpointParts<S, O> {
+        CheckpointParts {
+            snapshot: self.snapshot,
+            seed: self.seed,
+            rng: self.rng,
+            iteration: self.iteration,
+            processing_time: self.processing_time,
+            last_improvement: self.last_improvement,
+            best_output: self.best_output,
+        }
+    }
+}
+
+/// The parts of a `Checkpoint` that are restored by the simulator.
+pub(crate) struct CheckpointParts<S, O> {
+    pub(crate) snapshot: S,
+    pub(crate) seed: Seed,
+    pub(crate) rng: Prng,
+    pub(crate) iteration: u64,
+    pub(crate) processing_time: ProcessingTime,
+    pub(crate) last_improvement: u64,
+    pub(crate) best_output: Option<O>,
+}
+
+/// The type of the `Checkpoint` of a simulation of the algorithm `A`.
+pub type CheckpointOf<A> = Checkpoint<<A as Checkpointable>::Snapshot, <A as Algorithm>::Output>;
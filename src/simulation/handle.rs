@@ -0,0 +1,150 @@
+//! The `handle` module provides the `SimulatorHandle`, a cheap shared handle
+//! to inspect the progress of a running `simulator::Simulator` from other
+//! threads.
+//!
+//! The simulator publishes a summary of each iteration to the handle using
+//! atomic values. Reading the summary never blocks the simulation, which
+//! makes the handle suitable for dashboards or progress bars that poll the
+//! progress periodically.
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+
+/// The bits of the best fitness that mark the best fitness as unknown.
+const UNKNOWN_FITNESS: u64 = u64::MAX;
+
+/// The `GenerationSummary` is a snapshot of the progress of a simulation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GenerationSummary {
+    /// The number of the last processed iteration, 0 if no iteration has
+    /// been processed yet.
+    pub iteration: u64,
+    /// The number of the iteration in which the best solution was last
+    /// improved.
+    pub last_improvement: u64,
+    /// The scalar value of the best fitness found so far, if the simulator
+    /// has been configured to report it.
+    pub best_fitness: Option<f64>,
+    /// Whether the simulation is running.
+    pub running: bool,
+}
+
+/// The `SimulatorHandle` gives read-only access to the progress of a
+/// simulation. It can be cloned cheaply and sent to other threads.
+///
+/// Each value of the summary is updated atomically. As the values are not
+/// updated all at once, a summary may combine values of two consecutive
+/// iterations.
+#[derive(Clone, Debug)]
+pub struct SimulatorHandle {
+    shared: Arc<SharedSummary>,
+}
+
+impl SimulatorHandle {
+    /// Returns the number of the last processed iteration.
+    pub fn iteration(&self) -> u64 {
+        self.shared.iteration.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of the iteration in which the best solution was
+    /// last improved.
+    pub fn last_improvement(&self) -> u64 {
+        self.shared.last_improvement.load(Ordering::Acquire)
+    }
+
+    /// Returns the scalar value of the best fitness found so far, if the
+    /// simulator has been configured to report it.
+    pub fn best_fitness(&self) -> Option<f64> {
+        match self.shared.best_fitness.load(Ordering::Acquire) {
+            UNKNOWN_FITNESS => None,
+            bits => Some(f64::from_bits(bits)),
+        }
+    }
+
+    /// Returns whether the simulation is running.
+    pub fn is_running(&self) -> bool {
+        self.shared.running.load(Ordering::Acquire)
+    }
+
+    /// Returns a snapshot of the progress of the simulation.
+    pub fn summary(&self) -> GenerationSummary {
+        GenerationSummary {
+            iteration: self.iteration(),
+            last_improvement: self.last_improvement(),
+            best_fitness: self.best_fitness(),
+            running: self.is_running(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SharedSummary {
+    iteration: AtomicU64,
+    last_improvement: AtomicU64,
+    best_fitness: AtomicU64,
+    running: AtomicBool,
+}
+
+/// The `SummaryPublisher` is the side of a `SimulatorHandle` that is held by
+/// the simulator. Clones of a simulator publish to their own handles.
+#[derive(Debug)]
+pub(crate) struct SummaryPublisher {
+    shared: Arc<SharedSummary>,
+}
+
+impl SummaryPublisher {
+    pub(crate) fn new() -> Self {
+        SummaryPublisher::of(GenerationSummary {
+            iteration: 0,
+            last_improvement: 0,
+            best_fitness: None,
+            running: false,
+        })
+    }
+
+    fn of(summary: GenerationSummary) -> Self {
+        SummaryPublisher {
+            shared: Arc::new(SharedSummary {
+                iteration: AtomicU64::new(summary.iteration),
+                last_improvement: AtomicU64::new(summary.last_improvement),
+                best_fitness: AtomicU64::new(fitness_bits(summary.best_fitness)),
+                running: AtomicBool::new(summary.running),
+            }),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> SimulatorHandle {
+        SimulatorHandle {
+            shared: self.shared.clone(),
+        }
+    }
+
+    pub(crate) fn publish(&self, iteration: u64, last_improvement: u64, best_fitness: Option<f64>) {
+        self.shared
+            .best_fitness
+            .store(fitness_bits(best_fitness), Ordering::Release);
+        self.shared
+            .last_improvement
+            .store(last_improvement, Ordering::Release);
+        self.shared.iteration.store(iteration, Ordering::Release);
+    }
+
+    pub(crate) fn set_running(&self, running: bool) {
+        self.shared.running.store(running, Ordering::Release);
+    }
+}
+
+impl Clone for SummaryPublisher {
+    fn clone(&self) -> Self {
+        SummaryPublisher::of(self.handle().summary())
+    }
+}
+
+fn fitness_bits(best_fitness: Option<f64>) -> u64 {
+    // NaN values are reported as unknown fitness
+    best_fitness
+        .filter(|fitness| !fitness.is_nan())
+        .map_or(UNKNOWN_FITNESS, f64::to_bits)
+}
@@ -1,9 +1,23 @@
+pub mod checkpoint;
+
+pub mod handle;
+
+pub mod replay;
+
 pub mod simulator;
 
+pub mod stream;
+
 use crate::{
-    algorithm::Algorithm, random::Seed, statistic::ProcessingTime, termination::StopReason,
+    algorithm::{Algorithm, BestSolution, OptimizationResult},
+    genetic::{Fitness, Genotype},
+    random::Seed,
+    statistic::{ProcessingTime, Statistics},
+    termination::StopReason,
 };
 use chrono::{DateTime, Duration, Local};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A `Simulation` is the execution of an algorithm.
 pub trait Simulation<A>
@@ -44,35 +58,199 @@ where
     /// A simulation run can be repeated with the exact same sequence of
     /// iterations/generations, by providing the same seed as for a previous
     /// run.
-    fn build_with_seed(self, seed: Seed) -> S;
+    fn build_with_seed<Z>(self, seed: Z) -> S
+    where
+        Z: Into<Seed>;
+}
+
+/// A `SimulationController` adjusts the `Algorithm` while a `Simulation` is
+/// running. It is called by the simulation after each iteration with the
+/// `State` of the iteration and can change the algorithm based on the
+/// observed state, e.g. the parameters of the operators of a genetic
+/// algorithm through the `operator::TunableOperator` trait.
+///
+/// This is the foundation for self-adaptive control loops that do not need
+/// to fork the algorithm.
+///
+/// Any closure of type `FnMut(&State<A>, &mut A)` can be used as controller.
+pub trait SimulationController<A>
+where
+    A: Algorithm,
+{
+    /// Adjusts the algorithm based on the state of the last iteration.
+    fn control(&mut self, state: &State<A>, algorithm: &mut A);
+}
+
+impl<A, C> SimulationController<A> for C
+where
+    A: Algorithm,
+    C: FnMut(&State<A>, &mut A),
+{
+    fn control(&mut self, state: &State<A>, algorithm: &mut A) {
+        self(state, algorithm)
+    }
+}
+
+/// A `SimulationObserver` is notified about the progress of a `Simulation`,
+/// e.g. to log the progress, to plot it or to record metrics. In contrast to
+/// a `SimulationController` it can not change the algorithm.
+///
+/// The observer is called after each iteration with the `State` of the
+/// iteration, regardless of whether the simulation is processed by `run`,
+/// `step` or `tick`. When the termination criteria are met it is called once
+/// more with the final result.
+///
+/// Any closure of type `FnMut(&State<A>)` can be used as observer of the
+/// iterations.
+pub trait SimulationObserver<A>
+where
+    A: Algorithm,
+{
+    /// Observes the state of the last iteration.
+    fn on_generation(&mut self, state: &State<A>);
+
+    /// Observes the final result after the termination criteria are met.
+    /// The state of the last iteration has already been passed to
+    /// `on_generation`.
+    ///
+    /// The default implementation does nothing.
+    fn on_finish(
+        &mut self,
+        _state: &State<A>,
+        _processing_time: ProcessingTime,
+        _duration: Duration,
+        _stop_reason: &StopReason,
+    ) {
+    }
+}
+
+impl<A, O> SimulationObserver<A> for O
+where
+    A: Algorithm,
+    O: FnMut(&State<A>),
+{
+    fn on_generation(&mut self, state: &State<A>) {
+        self(state)
+    }
 }
 
 /// The `State` struct holds the state of the `Simulation`.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(
+        serialize = "<A as Algorithm>::Output: Serialize",
+        deserialize = "<A as Algorithm>::Output: Deserialize<'de>"
+    ))
+)]
 pub struct State<A>
 where
     A: Algorithm,
 {
     /// The local time when this simulation started.
     pub started_at: DateTime<Local>,
+    /// The master seed from which all random numbers of the simulation are
+    /// derived. A simulation built with the same seed, algorithm and
+    /// termination criteria repeats the exact same iterations.
+    pub seed: Seed,
     /// The number of the iteration that this state represents. Iterations
     /// are counted from 1 and increased by 1 on each iteration of the
     /// simulation loop.
     pub iteration: u64,
     /// Duration of processing the current iteration. This is the time it
     /// took to process one iteration of the algorithm.
+    #[cfg_attr(feature = "serde", serde(with = "crate::statistic::serde_duration"))]
     pub duration: Duration,
     /// Accumulated time spent by each thread in case of parallel processing.
     /// In case of sequential processing this time is nearly the same as the
     /// `duration` value. In case of parallel processing this time is usually
     /// a multitude of the `duration`.
     pub processing_time: ProcessingTime,
+    /// The number of the iteration in which the best solution was last
+    /// improved.
+    pub last_improvement: u64,
+    /// The local time when the best solution was last improved.
+    pub last_improved_at: DateTime<Local>,
+    /// The number of iterations since the best solution was last improved.
+    /// It is 0 if the best solution has been improved in this iteration.
+    pub generations_since_improvement: u64,
+    /// The optional statistics of this iteration as defined by a
+    /// `statistic::StatisticsConfig`. It is `None` in iterations in which
+    /// the statistics are not due.
+    pub statistics: Option<Statistics>,
     /// The result of this iteration.
     pub result: <A as Algorithm>::Output,
 }
 
+impl<A> State<A>
+where
+    A: Algorithm,
+{
+    /// Returns the time from the start of the simulation until the best
+    /// solution was last improved.
+    pub fn time_to_best(&self) -> Duration {
+        self.last_improved_at.signed_duration_since(self.started_at)
+    }
+
+    /// Returns the best solution of the result of this iteration, for every
+    /// algorithm whose output is an `algorithm::OptimizationResult`.
+    ///
+    /// For the genetic algorithm this is the best solution of the generation
+    /// of this iteration, also in the state of the final result. The best
+    /// solution of all iterations is held by the final result only, see
+    /// `SimResult::best_result`.
+    pub fn best_solution<'a, G, F>(&'a self) -> &'a BestSolution<G, F>
+    where
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+        G: Genotype + 'a,
+        F: Fitness,
+    {
+        self.result.best_solution()
+    }
+}
+
+impl<A> Clone for State<A>
+where
+    A: Algorithm,
+{
+    fn clone(&self) -> Self {
+        State {
+            started_at: self.started_at,
+            seed: self.seed,
+            iteration: self.iteration,
+            duration: self.duration,
+            processing_time: self.processing_time,
+            last_improvement: self.last_improvement,
+            last_improved_at: self.last_improved_at,
+            generations_since_improvement: self.generations_since_improvement,
+            statistics: self.statistics.clone(),
+            result: self.result.clone(),
+        }
+    }
+}
+
+impl<A> PartialEq for State<A>
+where
+    A: Algorithm,
+    <A as Algorithm>::Output: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.started_at == other.started_at
+            && self.seed == other.seed
+            && self.iteration == other.iteration
+            && self.duration == other.duration
+            && self.processing_time == other.processing_time
+            && self.last_improvement == other.last_improvement
+            && self.last_improved_at == other.last_improved_at
+            && self.generations_since_improvement == other.generations_since_improvement
+            && self.statistics == other.statistics
+            && self.result == other.result
+    }
+}
+
 /// The result of running a step in the `Simulation`.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum SimResult<A>
 where
     A: Algorithm,
@@ -86,6 +264,100 @@ where
     /// The parameters are:
     /// * The `State` of last processed generation.
     /// * The total processing time of the simulation.
+    /// * The duration of the simulation.
     /// * The `StopReason` is the matching criteria why the simulation stopped.
-    Final(State<A>, ProcessingTime, Duration, StopReason),
+    /// * The best result of all iterations, as determined by
+    ///   `algorithm::Algorithm::is_improvement`. It can be better than the
+    ///   result of the last processed generation, e.g. if the algorithm does
+    ///   not keep its best individuals. A simulation that has been restored
+    ///   from a checkpoint considers the iterations after the checkpoint
+    ///   only.
+    Final(
+        State<A>,
+        ProcessingTime,
+        Duration,
+        StopReason,
+        <A as Algorithm>::Output,
+    ),
+}
+
+impl<A> SimResult<A>
+where
+    A: Algorithm,
+{
+    /// Returns the `State` of the last processed iteration, regardless of
+    /// whether the simulation has finished.
+    pub fn state(&self) -> &State<A> {
+        match self {
+            SimResult::Intermediate(state) | SimResult::Final(state, _, _, _, _) => state,
+        }
+    }
+
+    /// Returns the `StopReason` if the simulation has finished.
+    pub fn stop_reason(&self) -> Option<&StopReason> {
+        match self {
+            SimResult::Intermediate(_) => None,
+            SimResult::Final(_, _, _, stop_reason, _) => Some(stop_reason),
+        }
+    }
+
+    /// Returns the best result of all iterations if the simulation has
+    /// finished.
+    pub fn best_result(&self) -> Option<&<A as Algorithm>::Output> {
+        match self {
+            SimResult::Intermediate(_) => None,
+            SimResult::Final(_, _, _, _, best_result) => Some(best_result),
+        }
+    }
+}
+
+impl<A> Clone for SimResult<A>
+where
+    A: Algorithm,
+{
+    fn clone(&self) -> Self {
+        match self {
+            SimResult::Intermediate(state) => SimResult::Intermediate(state.clone()),
+            SimResult::Final(state, processing_time, duration, stop_reason, best_result) => {
+                SimResult::Final(
+                    state.clone(),
+                    *processing_time,
+                    *duration,
+                    stop_reason.clone(),
+                    best_result.clone(),
+                )
+            },
+        }
+    }
+}
+
+impl<A> PartialEq for SimResult<A>
+where
+    A: Algorithm,
+    <A as Algorithm>::Output: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (SimResult::Intermediate(state), SimResult::Intermediate(other_state)) => {
+                state == other_state
+            },
+            (
+                SimResult::Final(state, processing_time, duration, stop_reason, best_result),
+                SimResult::Final(
+                    other_state,
+                    other_time,
+                    other_duration,
+                    other_reason,
+                    other_best_result,
+                ),
+            ) => {
+                state == other_state
+                    && processing_time == other_time
+                    && duration == other_duration
+                    && stop_reason == other_reason
+                    && best_result == other_best_result
+            },
+            _ => false,
+        }
+    }
 }
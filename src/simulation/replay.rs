@@ -0,0 +1,86 @@
+//! The `replay` module provides the `SeedLog` of a `simulator::Simulator`,
+//! which allows to replay the exact same evolution of a simulation, e.g. to
+//! debug why a particular run produced a particular best solution.
+//!
+//! Each iteration of a simulation uses its own random number generator,
+//! which is seeded with a seed drawn from the master seed of the
+//! simulation. The simulator records these seeds in its `SeedLog`. Replaying
+//! the log with `simulator::Simulator::replay` processes the iterations
+//! again with the same seeds.
+//!
+//! With the `serde` feature enabled a `SeedLog` can be serialized, e.g. to
+//! attach it to a bug report.
+
+use crate::random::Seed;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `SeedLog` holds the seeds of the random number generators of
+/// consecutive iterations of a simulation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeedLog {
+    first_iteration: u64,
+    seeds: Vec<Seed>,
+}
+
+impl SeedLog {
+    /// Constructs an empty `SeedLog` whose first seed is the seed of the
+    /// given iteration.
+    pub fn new(first_iteration: u64) -> Self {
+        SeedLog {
+            first_iteration,
+            seeds: Vec::new(),
+        }
+    }
+
+    /// Constructs a `SeedLog` of the given seeds, where the first seed is
+    /// the seed of the given iteration.
+    pub fn of(first_iteration: u64, seeds: Vec<Seed>) -> Self {
+        SeedLog {
+            first_iteration,
+            seeds,
+        }
+    }
+
+    /// Returns the number of the iteration of the first seed. It is 1 for
+    /// the log of a simulation from its start and greater than 1 for a
+    /// simulation that has been resumed from a checkpoint.
+    pub fn first_iteration(&self) -> u64 {
+        self.first_iteration
+    }
+
+    /// Returns the seeds of the iterations in the order of the iterations.
+    pub fn seeds(&self) -> &[Seed] {
+        &self.seeds
+    }
+
+    /// Returns the seed of the given iteration if it is in this log.
+    pub fn seed_of(&self, iteration: u64) -> Option<Seed> {
+        iteration
+            .checked_sub(self.first_iteration)
+            .and_then(|index| self.seeds.get(index as usize))
+            .copied()
+    }
+
+    /// Returns the number of seeds in this log.
+    pub fn len(&self) -> usize {
+        self.seeds.len()
+    }
+
+    /// Returns whether this log holds no seeds.
+    pub fn is_empty(&self) -> bool {
+        self.seeds.is_empty()
+    }
+
+    /// Appends the seed of the next iteration.
+    pub(crate) fn push(&mut self, seed: Seed) {
+        self.seeds.push(seed);
+    }
+}
+
+impl Default for SeedLog {
+    fn default() -> Self {
+        SeedLog::new(1)
+    }
+}
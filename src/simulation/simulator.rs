@@ -1,15 +1,27 @@
 use crate::{
-    algorithm::Algorithm,
-    random::{get_rng, random_seed, Prng, Seed},
-    simulation::{SimResult, Simulation, SimulationBuilder, State},
-    statistic::{ProcessingTime, TrackProcessingTime},
+    algorithm::{Algorithm, Checkpointable, OptimizationResult, StagedAlgorithm},
+    genetic::{AsScalar, Fitness, Genotype},
+    random::{get_rng, random_seed, Prng, Rng, Seed},
+    simulation::{
+        checkpoint::{Checkpoint, CheckpointOf},
+        handle::{SimulatorHandle, SummaryPublisher},
+        replay::SeedLog,
+        stream::StateSender,
+        SimResult, Simulation, SimulationBuilder, SimulationController, SimulationObserver, State,
+    },
+    statistic::{
+        history::{GenerationRecord, GenerationStatistics, SimulationStatistics},
+        ProcessingTime, StatisticsConfig, TrackProcessingTime,
+    },
     termination::{StopFlag, Termination},
 };
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Duration, Local};
 use std::{
+    collections::VecDeque,
     error::Error,
     fmt::{self, Debug, Display},
     hash::Hash,
+    sync::{Arc, Mutex, MutexGuard},
 };
 
 /// The `simulate` function creates a new `Simulator` for the given
@@ -29,6 +41,143 @@ where
 {
     algorithm: A,
     termination: T,
+    min_iteration_duration: Option<Duration>,
+    controller: Option<SharedHook<dyn SimulationController<A> + Send>>,
+    observers: Vec<SharedHook<dyn SimulationObserver<A> + Send>>,
+    statistics: StatisticsConfig<<A as Algorithm>::Output>,
+    best_fitness: Option<SharedHook<BestFitnessFn<A>>>,
+    record_generation: Option<SharedHook<RecordFn<A>>>,
+    state_sender: Option<StateSender<A>>,
+    seed: Option<Seed>,
+}
+
+impl<A, T> SimulatorBuilder<A, T>
+where
+    A: Algorithm,
+    T: Termination<A>,
+{
+    /// Sets the master seed of the simulation. All random numbers used by
+    /// the simulation are derived deterministically from this seed, so that
+    /// a run can be repeated with the exact same sequence of iterations.
+    ///
+    /// Without a seed the simulation is seeded randomly. Either way the seed
+    /// is recorded in the `State` of each iteration, including the final
+    /// result, to replay the run later.
+    pub fn with_seed<Z>(mut self, seed: Z) -> Self
+    where
+        Z: Into<Seed>,
+    {
+        self.seed = Some(seed.into());
+        self
+    }
+
+    /// Sets the minimum duration of one iteration of the simulation.
+    ///
+    /// If processing an iteration takes less time than the given duration
+    /// the simulator waits for the remaining time before it continues with
+    /// the next iteration, or before `step` returns. The observers are
+    /// notified about the result of the iteration before the simulator
+    /// waits. This is useful to slow down the evolution for
+    /// visualizations, e.g. in GUIs or for teaching purposes, while the
+    /// progress is reported without delay.
+    ///
+    /// On `wasm32` targets the simulator can not wait and therefore this
+    /// setting has no effect.
+    pub fn with_min_iteration_duration(mut self, min_iteration_duration: Duration) -> Self {
+        self.min_iteration_duration = Some(min_iteration_duration);
+        self
+    }
+
+    /// Sets the `SimulationController` that is called after each iteration.
+    /// The controller can adjust the algorithm, e.g. the parameters of its
+    /// operators, based on the state of the last iteration.
+    pub fn with_controller<C>(mut self, controller: C) -> Self
+    where
+        C: SimulationController<A> + Send + 'static,
+    {
+        self.controller = Some(SharedHook(Arc::new(Mutex::new(controller))));
+        self
+    }
+
+    /// Adds a `SimulationObserver` that is notified after each iteration and
+    /// when the simulation finishes. Several observers can be added; they
+    /// are notified in the order in which they have been added.
+    pub fn with_observer<O>(mut self, observer: O) -> Self
+    where
+        O: SimulationObserver<A> + Send + 'static,
+    {
+        self.observers
+            .push(SharedHook(Arc::new(Mutex::new(observer))));
+        self
+    }
+
+    /// Sets the `statistic::StatisticsConfig` that defines the optional
+    /// statistics calculated from the result of the iterations.
+    ///
+    /// The statistics are returned in the `State` of the iterations in which
+    /// they are due according to the stride of the configuration.
+    pub fn with_statistics(
+        mut self,
+        statistics: StatisticsConfig<<A as Algorithm>::Output>,
+    ) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// Sets the function that calculates the scalar value of the best
+    /// fitness from the best result found so far. The value is reported by
+    /// the `SimulatorHandle`s of the simulator.
+    pub fn with_best_fitness<B>(mut self, best_fitness: B) -> Self
+    where
+        B: Fn(&<A as Algorithm>::Output) -> f64 + Send + 'static,
+    {
+        self.best_fitness = Some(SharedHook(Arc::new(Mutex::new(best_fitness))));
+        self
+    }
+
+    /// Reports the fitness of the best solution found so far through the
+    /// `SimulatorHandle`s of the simulator, for every algorithm whose output
+    /// is an `algorithm::OptimizationResult` with a scalar fitness. It is a
+    /// shortcut for `with_best_fitness` with a function that returns the
+    /// scalar value of the best fitness.
+    pub fn with_best_solution_fitness<G, F>(self) -> Self
+    where
+        <A as Algorithm>::Output: OptimizationResult<G, F>,
+        G: Genotype,
+        F: Fitness + AsScalar,
+    {
+        self.with_best_fitness(|output: &<A as Algorithm>::Output| {
+            output.best_fitness().as_scalar()
+        })
+    }
+
+    /// Records the key figures of each generation, such as the best,
+    /// average and worst fitness, in the `statistic::history::SimulationStatistics`
+    /// of the simulator. They are returned by
+    /// `Simulator::simulation_statistics` and can be exported as CSV.
+    pub fn with_statistics_history(mut self) -> Self
+    where
+        <A as Algorithm>::Output: GenerationStatistics,
+    {
+        self.record_generation = Some(SharedHook(Arc::new(Mutex::new(
+            |iteration, output: &<A as Algorithm>::Output, duration, processing_time| {
+                GenerationRecord::of(iteration, output, duration, processing_time)
+            },
+        ))));
+        self
+    }
+
+    /// Sets the `stream::StateSender` to which the result of each iteration
+    /// is sent. The consumer of the results receives them from the
+    /// corresponding `stream::StateReceiver`.
+    ///
+    /// If the channel is full the simulator waits for the consumer or drops
+    /// the result according to the `stream::Backpressure` policy of the
+    /// channel.
+    pub fn with_state_sender(mut self, state_sender: StateSender<A>) -> Self {
+        self.state_sender = Some(state_sender);
+        self
+    }
 }
 
 impl<A, T> SimulationBuilder<Simulator<A, T>, A> for SimulatorBuilder<A, T>
@@ -38,18 +187,40 @@ where
     T: Termination<A>,
 {
     fn build(self) -> Simulator<A, T> {
-        self.build_with_seed(random_seed())
+        let seed = self.seed.unwrap_or_else(random_seed);
+        self.build_with_seed(seed)
     }
 
-    fn build_with_seed(self, seed: Seed) -> Simulator<A, T> {
+    fn build_with_seed<Z>(self, seed: Z) -> Simulator<A, T>
+    where
+        Z: Into<Seed>,
+    {
+        let seed = seed.into();
         Simulator {
             algorithm: self.algorithm,
             termination: self.termination,
             run_mode: RunMode::NotRunning,
+            seed,
             rng: get_rng(seed),
+            iteration_rng: get_rng(seed),
+            seed_log: SeedLog::new(1),
+            replay_seeds: VecDeque::new(),
             started_at: Local::now(),
             iteration: 0,
             processing_time: ProcessingTime::zero(),
+            min_iteration_duration: self.min_iteration_duration,
+            controller: self.controller,
+            observers: self.observers,
+            statistics: self.statistics,
+            best_fitness: self.best_fitness,
+            record_generation: self.record_generation,
+            simulation_statistics: SimulationStatistics::new(),
+            summary: SummaryPublisher::new(),
+            state_sender: self.state_sender,
+            best_output: None,
+            last_improvement: 0,
+            last_improved_at: Local::now(),
+            iteration_progress: None,
         }
     }
 }
@@ -73,10 +244,57 @@ where
         SimulatorBuilder {
             algorithm: self.algorithm,
             termination,
+            min_iteration_duration: None,
+            controller: None,
+            observers: Vec::new(),
+            statistics: StatisticsConfig::new(),
+            best_fitness: None,
+            record_generation: None,
+            state_sender: None,
+            seed: None,
         }
     }
 }
 
+/// The function that calculates the scalar value of the best fitness from
+/// the output of an algorithm.
+type BestFitnessFn<A> = dyn Fn(&<A as Algorithm>::Output) -> f64 + Send;
+
+/// The function that records the key figures of a generation from the
+/// output of an algorithm.
+type RecordFn<A> =
+    dyn Fn(u64, &<A as Algorithm>::Output, Duration, ProcessingTime) -> GenerationRecord + Send;
+
+/// A `SharedHook` holds a hook, such as a `SimulationController`, that is
+/// called by the `Simulator`. Clones of a `Simulator` share the same hooks.
+struct SharedHook<H: ?Sized>(Arc<Mutex<H>>);
+
+impl<H: ?Sized> SharedHook<H> {
+    fn lock(&self) -> MutexGuard<'_, H> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<H: ?Sized> Clone for SharedHook<H> {
+    fn clone(&self) -> Self {
+        SharedHook(self.0.clone())
+    }
+}
+
+impl<H: ?Sized> Debug for SharedHook<H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SharedHook")
+    }
+}
+
+impl<H: ?Sized> PartialEq for SharedHook<H> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// The `RunMode` identifies whether the simulation is running and how it has
 /// been started.
 #[derive(Clone, Debug, PartialEq)]
@@ -99,6 +317,8 @@ where
 {
     AlgorithmError(<A as Algorithm>::Error),
     SimulationAlreadyRunning(String),
+    InvalidSeedLog(String),
+    IterationInProgress(String),
 }
 
 impl<A> Display for SimError<A>
@@ -112,6 +332,10 @@ where
             SimError::SimulationAlreadyRunning(ref message) => {
                 write!(f, "simulation already running {}", message)
             }
+            SimError::InvalidSeedLog(ref message) => write!(f, "invalid seed log: {}", message),
+            SimError::IterationInProgress(ref message) => {
+                write!(f, "iteration in progress {}", message)
+            }
         }
     }
 }
@@ -124,7 +348,9 @@ where
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SimError::AlgorithmError(ref error) => Some(error),
-            SimError::SimulationAlreadyRunning(_) => None,
+            SimError::SimulationAlreadyRunning(_)
+            | SimError::InvalidSeedLog(_)
+            | SimError::IterationInProgress(_) => None,
         }
     }
 }
@@ -138,10 +364,27 @@ where
     algorithm: A,
     termination: T,
     run_mode: RunMode,
+    seed: Seed,
     rng: Prng,
+    iteration_rng: Prng,
+    seed_log: SeedLog,
+    replay_seeds: VecDeque<Seed>,
     started_at: DateTime<Local>,
     iteration: u64,
     processing_time: ProcessingTime,
+    min_iteration_duration: Option<Duration>,
+    controller: Option<SharedHook<dyn SimulationController<A> + Send>>,
+    observers: Vec<SharedHook<dyn SimulationObserver<A> + Send>>,
+    statistics: StatisticsConfig<<A as Algorithm>::Output>,
+    best_fitness: Option<SharedHook<BestFitnessFn<A>>>,
+    record_generation: Option<SharedHook<RecordFn<A>>>,
+    simulation_statistics: SimulationStatistics,
+    summary: SummaryPublisher,
+    state_sender: Option<StateSender<A>>,
+    best_output: Option<<A as Algorithm>::Output>,
+    last_improvement: u64,
+    last_improved_at: DateTime<Local>,
+    iteration_progress: Option<Duration>,
 }
 
 impl<A, T> Simulator<A, T>
@@ -150,30 +393,515 @@ where
     <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
     T: Termination<A>,
 {
+    pub fn algorithm(&self) -> &A {
+        &self.algorithm
+    }
+
     pub fn termination(&self) -> &T {
         &self.termination
     }
 
+    /// Returns the master seed from which all random numbers of this
+    /// simulation are derived.
+    pub fn seed(&self) -> Seed {
+        self.seed
+    }
+
+    /// Returns the `replay::SeedLog` with the seeds of the iterations that
+    /// have been started since the simulation has been built, reset or
+    /// restored from a checkpoint.
+    pub fn seed_log(&self) -> &SeedLog {
+        &self.seed_log
+    }
+
+    /// Replays the iterations of the given `replay::SeedLog`, i.e. processes
+    /// them again with the same seeds, which repeats the exact same
+    /// evolution as long as the algorithm and the termination criteria are
+    /// configured the same way.
+    ///
+    /// A log that starts at iteration 1 is replayed from the start, i.e.
+    /// the simulation is reset first. A log of a simulation that has been
+    /// resumed from a checkpoint is replayed after restoring the same
+    /// checkpoint.
+    ///
+    /// The replay ends when all seeds of the log are used up or the
+    /// termination criteria are met. It returns the result of the last
+    /// replayed iteration. If the simulation is not finished, it stays in
+    /// step mode and can be continued by the `step` function.
+    pub fn replay(
+        &mut self,
+        seed_log: &SeedLog,
+    ) -> Result<SimResult<A>, <Self as Simulation<A>>::Error> {
+        if seed_log.is_empty() {
+            return Err(SimError::InvalidSeedLog(
+                "The seed log holds no seeds to replay.".to_string(),
+            ));
+        }
+        if seed_log.first_iteration() == 1 {
+            self.reset()?;
+        } else if self.run_mode != RunMode::NotRunning {
+            return Err(SimError::SimulationAlreadyRunning(format!(
+                "since {}. Stop the simulation before replaying a seed log.",
+                &self.started_at
+            )));
+        } else if seed_log.first_iteration() != self.iteration + 1 {
+            return Err(SimError::InvalidSeedLog(format!(
+                "The seed log starts at iteration {}, but the simulation continues with \
+                 iteration {}.",
+                seed_log.first_iteration(),
+                self.iteration + 1
+            )));
+        }
+        self.replay_seeds = seed_log.seeds().iter().copied().collect();
+        let mut result = Err(SimError::InvalidSeedLog(
+            "The seed log holds no seeds to replay.".to_string(),
+        ));
+        while !self.replay_seeds.is_empty() {
+            result = self.step();
+            if !matches!(result, Ok(SimResult::Intermediate(_))) {
+                break;
+            }
+        }
+        self.replay_seeds.clear();
+        result
+    }
+
+    /// Returns the minimum duration of one iteration if one is set.
+    pub fn min_iteration_duration(&self) -> Option<Duration> {
+        self.min_iteration_duration
+    }
+
+    /// Returns a new `SimulatorHandle` to inspect the progress of this
+    /// simulator from other threads.
+    pub fn handle(&self) -> SimulatorHandle {
+        self.summary.handle()
+    }
+
+    /// Returns the configuration of the optional statistics.
+    pub fn statistics(&self) -> &StatisticsConfig<<A as Algorithm>::Output> {
+        &self.statistics
+    }
+
+    /// Returns the key figures recorded for each generation of this
+    /// simulation, or `None` if the simulator has not been built with
+    /// `with_statistics_history`.
+    ///
+    /// The history starts anew when the simulation is reset. Restoring a
+    /// checkpoint drops the records of the generations after the
+    /// checkpoint.
+    pub fn simulation_statistics(&self) -> Option<&SimulationStatistics> {
+        self.record_generation
+            .as_ref()
+            .map(|_| &self.simulation_statistics)
+    }
+
+    /// Returns an iterator over the generations of this simulation. Each
+    /// call of `next` processes one iteration in step mode, like the `step`
+    /// function, and returns its `State`.
+    ///
+    /// The iterator ends after the state of the iteration in which the
+    /// termination criteria are met, or after an error. Use the `step`
+    /// function instead if the `StopReason` of the final result is needed.
+    pub fn iter(&mut self) -> Generations<'_, A, T> {
+        Generations {
+            simulator: self,
+            finished: false,
+        }
+    }
+
     /// Processes one iteration of the algorithm used in this simulation.
     fn process_one_iteration(&mut self) -> Result<State<A>, <Self as Simulation<A>>::Error> {
         let loop_started_at = Local::now();
 
-        self.iteration += 1;
-        let result = self.algorithm.next(self.iteration, &mut self.rng);
-        self.processing_time += self.algorithm.processing_time();
+        // an iteration that has been started by `tick` is completed
+        let started_duration = match self.iteration_progress.take() {
+            Some(duration) => duration,
+            None => {
+                self.start_iteration();
+                Duration::zero()
+            },
+        };
+        let result = self.algorithm.next(self.iteration, &mut self.iteration_rng);
 
-        let loop_duration = Local::now().signed_duration_since(loop_started_at);
+        let loop_duration = started_duration + Local::now().signed_duration_since(loop_started_at);
         match result {
-            Ok(result) => Ok(State {
-                started_at: self.started_at,
-                iteration: self.iteration,
-                duration: loop_duration,
-                processing_time: self.algorithm.processing_time(),
-                result,
-            }),
+            Ok(result) => Ok(self.complete_iteration(result, loop_duration)),
             Err(error) => Err(SimError::AlgorithmError(error)),
         }
     }
+
+    /// Starts the next iteration with a random number generator that is
+    /// seeded with the next seed drawn from the master seed, or with the
+    /// next seed of the log that is replayed.
+    fn start_iteration(&mut self) {
+        self.iteration += 1;
+        // the seed is always drawn, so that a simulation can be continued
+        // after a replay of its own seed log
+        let drawn = Seed::from_bytes(self.rng.gen());
+        let seed = self.replay_seeds.pop_front().unwrap_or(drawn);
+        self.seed_log.push(seed);
+        self.iteration_rng = get_rng(seed);
+    }
+
+    /// Builds the `State` of the completed iteration with the given result
+    /// and calls the controller.
+    fn complete_iteration(
+        &mut self,
+        result: <A as Algorithm>::Output,
+        loop_duration: Duration,
+    ) -> State<A> {
+        self.processing_time += self.algorithm.processing_time();
+        self.track_improvement(&result);
+        let statistics = self.statistics.calculate(self.iteration, &result);
+        if let Some(record_generation) = &self.record_generation {
+            let record = record_generation.lock()(
+                self.iteration,
+                &result,
+                loop_duration,
+                self.algorithm.processing_time(),
+            );
+            self.simulation_statistics.record(record);
+        }
+        let state = State {
+            started_at: self.started_at,
+            seed: self.seed,
+            iteration: self.iteration,
+            duration: loop_duration,
+            processing_time: self.algorithm.processing_time(),
+            last_improvement: self.last_improvement,
+            last_improved_at: self.last_improved_at,
+            generations_since_improvement: self.iteration - self.last_improvement,
+            statistics,
+            result,
+        };
+        if let Some(controller) = &self.controller {
+            controller.lock().control(&state, &mut self.algorithm);
+        }
+        state
+    }
+
+    /// Evaluates the termination criteria for the given state of a
+    /// simulation in step mode.
+    fn step_result(&mut self, state: State<A>) -> SimResult<A> {
+        // Stage 5: Be aware of the termination:
+        match self.termination.evaluate(&state) {
+            StopFlag::Continue => SimResult::Intermediate(state),
+            StopFlag::StopNow(reason) => {
+                let processing_time = self.processing_time;
+                let duration = Local::now().signed_duration_since(self.started_at);
+                self.set_run_mode(RunMode::NotRunning);
+                let best_result = self.best_result(&state);
+                SimResult::Final(state, processing_time, duration, reason, best_result)
+            },
+        }
+    }
+
+    /// Returns the best result of all iterations since the simulation has
+    /// been started or reset, including the iterations before a restored
+    /// checkpoint, falling back to the result of the given state.
+    fn best_result(&self, state: &State<A>) -> <A as Algorithm>::Output {
+        self.best_output
+            .clone()
+            .unwrap_or_else(|| state.result.clone())
+    }
+
+    /// Records the current iteration as the last improvement if the result
+    /// of the algorithm is better than the best result so far.
+    fn track_improvement(&mut self, result: &<A as Algorithm>::Output) {
+        let improved = self
+            .best_output
+            .as_ref()
+            .is_none_or(|best| A::is_improvement(result, best));
+        if improved {
+            self.best_output = Some(result.clone());
+            self.last_improvement = self.iteration;
+            self.last_improved_at = Local::now();
+        }
+        self.summary.publish(
+            self.iteration,
+            self.last_improvement,
+            self.best_fitness_value(),
+        );
+    }
+
+    /// Returns the fitness of the best result so far as measured by the
+    /// configured best fitness function, if there is one.
+    fn best_fitness_value(&self) -> Option<f64> {
+        match (&self.best_fitness, &self.best_output) {
+            (Some(best_fitness), Some(best_output)) => Some(best_fitness.lock()(best_output)),
+            _ => None,
+        }
+    }
+
+    /// Notifies the observers about the given result and sends it to the
+    /// consumer of the results, if there is one.
+    fn forward(&self, result: &SimResult<A>) {
+        self.notify(result);
+        if let Some(state_sender) = &self.state_sender {
+            state_sender.send(result.clone());
+        }
+    }
+
+    /// Notifies the observers about the given result.
+    fn notify(&self, result: &SimResult<A>) {
+        for observer in &self.observers {
+            let mut observer = observer.lock();
+            match result {
+                SimResult::Intermediate(state) => observer.on_generation(state),
+                SimResult::Final(state, processing_time, duration, stop_reason, _) => {
+                    observer.on_generation(state);
+                    observer.on_finish(state, *processing_time, *duration, stop_reason);
+                },
+            }
+        }
+    }
+
+    /// Sets the run mode and tells the `SimulatorHandle`s whether the
+    /// simulation is running.
+    fn set_run_mode(&mut self, run_mode: RunMode) {
+        self.summary.set_running(run_mode != RunMode::NotRunning);
+        self.run_mode = run_mode;
+    }
+
+    /// Waits for the remaining time if the iteration, including the
+    /// notification of the observers since `notified_at`, took less time
+    /// than the configured minimum iteration duration.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn throttle(&self, loop_duration: Duration, notified_at: DateTime<Local>) {
+        if let Some(min_duration) = self.min_iteration_duration {
+            let duration = loop_duration + Local::now().signed_duration_since(notified_at);
+            if let Ok(remaining) = (min_duration - duration).to_std() {
+                std::thread::sleep(remaining);
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn throttle(&self, _loop_duration: Duration, _notified_at: DateTime<Local>) {}
+}
+
+impl<A, T> Simulator<A, T>
+where
+    A: Algorithm + Checkpointable + TrackProcessingTime + Debug,
+    <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+    T: Termination<A>,
+{
+    /// Creates a new `Simulator` that resumes the simulation saved in the
+    /// given `Checkpoint`. The simulator is seeded with the master seed of
+    /// the checkpointed simulation.
+    ///
+    /// The algorithm and the termination criteria must be configured the
+    /// same way as for the simulation that has been checkpointed. The
+    /// termination criteria start anew from the checkpoint on, as their
+    /// state is not saved in the checkpoint. To configure the other options
+    /// of the simulator, build the simulator with the `simulate` function and
+    /// call `restore_checkpoint` instead.
+    pub fn from_checkpoint(checkpoint: CheckpointOf<A>, algorithm: A, termination: T) -> Self {
+        let mut simulator = simulate(algorithm)
+            .until(termination)
+            .build_with_seed(checkpoint.seed());
+        simulator.restore(checkpoint);
+        simulator
+    }
+
+    /// Saves the progress of this simulation in a `Checkpoint`.
+    ///
+    /// Returns an error if an iteration has been started by `tick` but is
+    /// not completed yet. The checkpoint can be saved after the tick that
+    /// completes the iteration.
+    pub fn save_checkpoint(&self) -> Result<CheckpointOf<A>, <Self as Simulation<A>>::Error> {
+        if self.iteration_progress.is_some() {
+            return Err(SimError::IterationInProgress(format!(
+                "in iteration {}. Complete the iteration before saving a checkpoint.",
+                self.iteration
+            )));
+        }
+        Ok(Checkpoint::new(
+            self.algorithm.snapshot(),
+            self.seed,
+            self.rng.clone(),
+            self.iteration,
+            self.processing_time,
+            self.last_improvement,
+            self.best_output.clone(),
+        ))
+    }
+
+    /// Restores the progress of this simulation from the given
+    /// `Checkpoint`. The next iteration continues the simulation where the
+    /// checkpoint has been saved. The master seed of this simulation is
+    /// replaced by the master seed of the checkpoint. The best result saved
+    /// in the checkpoint remains the best result until a better one is
+    /// found. The termination criteria are reset, as their state is not
+    /// saved in the checkpoint.
+    ///
+    /// Returns an error if the simulation is running.
+    pub fn restore_checkpoint(
+        &mut self,
+        checkpoint: CheckpointOf<A>,
+    ) -> Result<(), <Self as Simulation<A>>::Error> {
+        if self.run_mode != RunMode::NotRunning {
+            return Err(SimError::SimulationAlreadyRunning(format!(
+                "since {}. Stop the simulation before restoring a checkpoint.",
+                &self.started_at
+            )));
+        }
+        self.restore(checkpoint);
+        Ok(())
+    }
+
+    fn restore(&mut self, checkpoint: CheckpointOf<A>) {
+        let parts = checkpoint.into_parts();
+        self.algorithm.restore(parts.snapshot);
+        self.seed = parts.seed;
+        self.rng = parts.rng;
+        self.seed_log = SeedLog::new(parts.iteration + 1);
+        self.iteration = parts.iteration;
+        self.processing_time = parts.processing_time;
+        self.best_output = parts.best_output;
+        self.iteration_progress = None;
+        self.last_improvement = parts.last_improvement;
+        self.simulation_statistics.truncate_after(parts.iteration);
+        self.termination.reset();
+        self.summary.publish(
+            parts.iteration,
+            parts.last_improvement,
+            self.best_fitness_value(),
+        );
+    }
+}
+
+impl<A, T> Simulator<A, T>
+where
+    A: StagedAlgorithm + TrackProcessingTime + Debug,
+    <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+    T: Termination<A>,
+{
+    /// Advances the simulation by as many stages of the algorithm as fit
+    /// into the given time budget. The stages are processed in the calling
+    /// thread, so that a simulation can be integrated into the frame loop of
+    /// a game or a GUI without spawning threads. This way a simulation in a
+    /// browser does not block its event loop, e.g. by processing one tick per
+    /// animation frame.
+    ///
+    /// At least one stage is processed per tick. Further stages are
+    /// processed as long as the time used so far plus the duration of the
+    /// longest stage of this tick is within the budget. An iteration that is
+    /// not completed within a tick is continued by the next tick. Like the
+    /// `step` function, a tick puts the simulation in step mode.
+    ///
+    /// Returns the result of the last iteration completed in this tick, or
+    /// `None` if no iteration has been completed. The results of all
+    /// completed iterations are sent to the `StateSender` if there is one.
+    /// The tick ends with the final result as soon as the termination
+    /// criteria are met.
+    pub fn tick(
+        &mut self,
+        budget: Duration,
+    ) -> Result<Option<SimResult<A>>, <Self as Simulation<A>>::Error> {
+        match self.run_mode {
+            RunMode::Loop => {
+                return Err(SimError::SimulationAlreadyRunning(format!(
+                    "in loop mode since {}",
+                    &self.started_at
+                )))
+            },
+            RunMode::Step => (),
+            RunMode::NotRunning => {
+                self.set_run_mode(RunMode::Step);
+                self.started_at = Local::now();
+            },
+        }
+        let tick_started_at = Local::now();
+        let mut longest_stage = Duration::zero();
+        let mut last_result = None;
+        loop {
+            let stage_started_at = Local::now();
+            let started_duration = match self.iteration_progress.take() {
+                Some(duration) => duration,
+                None => {
+                    self.start_iteration();
+                    Duration::zero()
+                },
+            };
+            let output = self
+                .algorithm
+                .next_stage(self.iteration, &mut self.iteration_rng)
+                .map_err(SimError::AlgorithmError)?;
+            let stage_duration = Local::now().signed_duration_since(stage_started_at);
+            longest_stage = longest_stage.max(stage_duration);
+            match output {
+                Some(output) => {
+                    let state = self.complete_iteration(output, started_duration + stage_duration);
+                    let result = self.step_result(state);
+                    self.forward(&result);
+                    let is_final = matches!(result, SimResult::Final(..));
+                    last_result = Some(result);
+                    if is_final {
+                        break;
+                    }
+                },
+                None => self.iteration_progress = Some(started_duration + stage_duration),
+            }
+            let used = Local::now().signed_duration_since(tick_started_at);
+            if used + longest_stage > budget {
+                break;
+            }
+        }
+        Ok(last_result)
+    }
+}
+
+impl<'a, A, T> IntoIterator for &'a mut Simulator<A, T>
+where
+    A: Algorithm + TrackProcessingTime + Debug,
+    <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+    T: Termination<A>,
+{
+    type Item = Result<State<A>, SimError<A>>;
+    type IntoIter = Generations<'a, A, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The iterator over the generations of a `Simulator`, which is returned by
+/// `Simulator::iter`.
+#[derive(Debug)]
+pub struct Generations<'a, A, T>
+where
+    A: Algorithm,
+    T: Termination<A>,
+{
+    simulator: &'a mut Simulator<A, T>,
+    finished: bool,
+}
+
+impl<A, T> Iterator for Generations<'_, A, T>
+where
+    A: Algorithm + TrackProcessingTime + Debug,
+    <A as Algorithm>::Error: Eq + Hash + Display + Send + Sync,
+    T: Termination<A>,
+{
+    type Item = Result<State<A>, SimError<A>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.simulator.step() {
+            Ok(SimResult::Intermediate(state)) => Some(Ok(state)),
+            Ok(SimResult::Final(state, _, _, _, _)) => {
+                self.finished = true;
+                Some(Ok(state))
+            },
+            Err(error) => {
+                self.finished = true;
+                Some(Err(error))
+            },
+        }
+    }
 }
 
 impl<A, T> Simulation<A> for Simulator<A, T>
@@ -199,7 +927,7 @@ where
                 )))
             }
             RunMode::NotRunning => {
-                self.run_mode = RunMode::Loop;
+                self.set_run_mode(RunMode::Loop);
                 self.started_at = Local::now();
             }
         }
@@ -208,11 +936,24 @@ where
                 Ok(state) => {
                     // Stage 5: Be aware of the termination:
                     match self.termination.evaluate(&state) {
-                        StopFlag::Continue => {}
+                        StopFlag::Continue => {
+                            let loop_duration = state.duration;
+                            let notified_at = Local::now();
+                            let result = SimResult::Intermediate(state);
+                            self.notify(&result);
+                            if let Some(state_sender) = &self.state_sender {
+                                state_sender.send(result);
+                            }
+                            self.throttle(loop_duration, notified_at);
+                        }
                         StopFlag::StopNow(reason) => {
                             let processing_time = self.processing_time;
                             let duration = Local::now().signed_duration_since(self.started_at);
-                            break Ok(SimResult::Final(state, processing_time, duration, reason));
+                            let best_result = self.best_result(&state);
+                            let result =
+                                SimResult::Final(state, processing_time, duration, reason, best_result);
+                            self.forward(&result);
+                            break Ok(result);
                         }
                     }
                 }
@@ -221,7 +962,7 @@ where
                 }
             }
         };
-        self.run_mode = RunMode::NotRunning;
+        self.set_run_mode(RunMode::NotRunning);
         result
     }
 
@@ -235,29 +976,27 @@ where
             }
             RunMode::Step => (),
             RunMode::NotRunning => {
-                self.run_mode = RunMode::Step;
+                self.set_run_mode(RunMode::Step);
                 self.started_at = Local::now();
             }
         }
-        self.process_one_iteration().and_then(|state|
-            // Stage 5: Be aware of the termination:
-            Ok(match self.termination.evaluate(&state) {
-                StopFlag::Continue => {
-                    SimResult::Intermediate(state)
-                },
-                StopFlag::StopNow(reason) => {
-                    let processing_time = self.processing_time;
-                    let duration = Local::now().signed_duration_since(self.started_at);
-                    self.run_mode = RunMode::NotRunning;
-                    SimResult::Final(state, processing_time, duration, reason)
-                },
-            }))
+        let result = self
+            .process_one_iteration()
+            .map(|state| self.step_result(state));
+        if let Ok(result) = &result {
+            let notified_at = Local::now();
+            self.forward(result);
+            if let SimResult::Intermediate(state) = result {
+                self.throttle(state.duration, notified_at);
+            }
+        }
+        result
     }
 
     fn stop(&mut self) -> Result<bool, Self::Error> {
         match self.run_mode {
             RunMode::Loop | RunMode::Step => {
-                self.run_mode = RunMode::NotRunning;
+                self.set_run_mode(RunMode::NotRunning);
                 Ok(true)
             }
             RunMode::NotRunning => Ok(false),
@@ -282,9 +1021,17 @@ where
             }
             RunMode::NotRunning => (),
         }
-        self.run_mode = RunMode::NotRunning;
+        self.set_run_mode(RunMode::NotRunning);
+        self.rng = get_rng(self.seed);
+        self.seed_log = SeedLog::new(1);
         self.processing_time = ProcessingTime::zero();
         self.iteration = 0;
+        self.best_output = None;
+        self.iteration_progress = None;
+        self.last_improvement = 0;
+        self.simulation_statistics.clear();
+        self.summary.publish(0, 0, None);
+        self.termination.reset();
         self.algorithm.reset().map_err(SimError::AlgorithmError)
     }
 }
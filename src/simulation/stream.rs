@@ -0,0 +1,222 @@
+//! The `stream` module provides a bounded channel to stream the results of
+//! the iterations of a `simulator::Simulator` to a consumer, e.g. a UI that
+//! runs in another thread.
+//!
+//! The channel holds at most a configured number of results. If the consumer
+//! is slower than the simulation, the `Backpressure` policy decides whether
+//! the simulation waits for the consumer or drops intermediate results. The
+//! final result of a simulation is never dropped.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{
+//!     ga::presets,
+//!     prelude::*,
+//!     simulation::stream::{state_channel, Backpressure},
+//! };
+//!
+//! #[derive(Clone, Debug)]
+//! struct CountOnes;
+//!
+//! impl FitnessFunction<Vec<bool>, usize> for CountOnes {
+//!     fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+//!         genome.iter().filter(|gene| **gene).count()
+//!     }
+//!
+//!     fn average(&self, values: &[usize]) -> usize {
+//!         values.iter().sum::<usize>() / values.len()
+//!     }
+//!
+//!     fn highest_possible_fitness(&self) -> usize {
+//!         32
+//!     }
+//!
+//!     fn lowest_possible_fitness(&self) -> usize {
+//!         0
+//!     }
+//! }
+//!
+//! let algorithm = presets::binary(32).with_evaluation(CountOnes).build();
+//! let (sender, receiver) = state_channel(16, Backpressure::DropNewest);
+//! let mut simulator = simulate(algorithm)
+//!     .until(GenerationLimit::new(100))
+//!     .with_state_sender(sender)
+//!     .build();
+//!
+//! std::thread::spawn(move || simulator.run());
+//!
+//! for result in receiver {
+//!     if let SimResult::Final(state, _, _, _, _) = result {
+//!         println!("finished after {} generations", state.iteration);
+//!     }
+//! }
+//! ```
+
+use crate::{algorithm::Algorithm, simulation::SimResult};
+use std::{
+    fmt::{self, Debug},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+        Arc,
+    },
+};
+
+/// The `Backpressure` policy defines what the simulation does with the
+/// result of an iteration if the channel is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Backpressure {
+    /// The simulation waits until the consumer has received a result.
+    #[default]
+    Block,
+    /// The simulation drops the result of the iteration and continues.
+    /// Final results are never dropped.
+    DropNewest,
+}
+
+/// Creates a new bounded channel that holds at most `capacity` results of
+/// iterations.
+pub fn state_channel<A>(
+    capacity: usize,
+    backpressure: Backpressure,
+) -> (StateSender<A>, StateReceiver<A>)
+where
+    A: Algorithm,
+{
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+    let dropped = Arc::new(AtomicU64::new(0));
+    (
+        StateSender {
+            sender,
+            backpressure,
+            dropped: dropped.clone(),
+        },
+        StateReceiver { receiver, dropped },
+    )
+}
+
+/// The `StateSender` is the side of a state channel that is held by the
+/// simulator.
+pub struct StateSender<A>
+where
+    A: Algorithm,
+{
+    sender: SyncSender<SimResult<A>>,
+    backpressure: Backpressure,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<A> StateSender<A>
+where
+    A: Algorithm,
+{
+    /// Returns the `Backpressure` policy of this channel.
+    pub fn backpressure(&self) -> Backpressure {
+        self.backpressure
+    }
+
+    /// Sends the given result according to the `Backpressure` policy.
+    ///
+    /// Results are discarded silently once the receiver has been dropped, as
+    /// the simulation does not depend on its consumers.
+    pub(crate) fn send(&self, result: SimResult<A>) {
+        let is_final = matches!(result, SimResult::Final(..));
+        if is_final || self.backpressure == Backpressure::Block {
+            let _ = self.sender.send(result);
+        } else if let Err(TrySendError::Full(_)) = self.sender.try_send(result) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl<A> Clone for StateSender<A>
+where
+    A: Algorithm,
+{
+    fn clone(&self) -> Self {
+        StateSender {
+            sender: self.sender.clone(),
+            backpressure: self.backpressure,
+            dropped: self.dropped.clone(),
+        }
+    }
+}
+
+impl<A> Debug for StateSender<A>
+where
+    A: Algorithm,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateSender")
+            .field("backpressure", &self.backpressure)
+            .finish()
+    }
+}
+
+impl<A> PartialEq for StateSender<A>
+where
+    A: Algorithm,
+{
+    fn eq(&self, other: &Self) -> bool {
+        // senders are equal if they send into the same channel
+        Arc::ptr_eq(&self.dropped, &other.dropped)
+    }
+}
+
+/// The `StateReceiver` is the side of a state channel that is held by the
+/// consumer of the results.
+///
+/// Iterating over a `StateReceiver` blocks until the next result is
+/// available and ends when the simulator has been dropped.
+pub struct StateReceiver<A>
+where
+    A: Algorithm,
+{
+    receiver: Receiver<SimResult<A>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<A> StateReceiver<A>
+where
+    A: Algorithm,
+{
+    /// Waits for the next result. Returns `None` if the simulator has been
+    /// dropped.
+    pub fn recv(&self) -> Option<SimResult<A>> {
+        self.receiver.recv().ok()
+    }
+
+    /// Returns the next result if one is available without waiting.
+    pub fn try_recv(&self) -> Option<SimResult<A>> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Returns the number of intermediate results that have been dropped
+    /// because the channel was full.
+    pub fn dropped_states(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl<A> Iterator for StateReceiver<A>
+where
+    A: Algorithm,
+{
+    type Item = SimResult<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+impl<A> Debug for StateReceiver<A>
+where
+    A: Algorithm,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateReceiver")
+            .field("dropped_states", &self.dropped_states())
+            .finish()
+    }
+}
@@ -0,0 +1,52 @@
+//! The `stable` module is the stable public API of this crate for crates
+//! that extend it with new operators or algorithms. It is available with the
+//! crate feature `stable-api`.
+//!
+//! The items of this module are re-exports of the traits and data structures
+//! that such crates need to implement and to exchange with this crate. They
+//! follow semantic versioning strictly: an item is neither removed nor
+//! changed incompatibly before the `API_VERSION` is increased, which happens
+//! only with a new major version of this crate. The modules the items are
+//! defined in may be reorganized at any time, so extension crates should
+//! import them from here instead of from their defining modules.
+//!
+//! Besides the re-exports this module provides the
+//! `registry::AlgorithmRegistry`, which finds algorithms by name, so that
+//! the algorithm to run can be chosen by a configuration.
+
+pub mod registry;
+
+/// The version of the stable API. It is increased with each incompatible
+/// change of the items of this module.
+pub const API_VERSION: u32 = 1;
+
+// Algorithm
+//
+pub use crate::algorithm::{Algorithm, BestSolution, Evaluated, EvaluatedPopulation};
+
+// Genetic
+//
+pub use crate::genetic::{
+    AsScalar, Children, Fitness, FitnessFunction, Genotype, Locus, Offspring, Parents, Phenotype,
+};
+
+// Operators
+//
+pub use crate::operator::{
+    CrossoverOp, FixerOp, GeneticOperator, MutationOp, ParentDemand, ReinsertionOp, SelectionOp,
+};
+
+// Random
+//
+pub use crate::random::{Prng, Rng, Seed};
+
+// Simulation
+//
+pub use crate::simulation::{SimResult, Simulation, SimulationBuilder, State};
+
+// Termination
+//
+pub use crate::termination::{StopFlag, StopReason, Termination};
+
+#[cfg(test)]
+mod tests;
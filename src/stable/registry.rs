@@ -0,0 +1,162 @@
+//! The `registry` module provides the `AlgorithmRegistry`, which finds
+//! algorithms by name.
+//!
+//! Extension crates register their algorithms under a unique name together
+//! with a factory that constructs the algorithm from named parameters. An
+//! application that assembles its algorithm from a configuration looks up
+//! the configured name and passes the configured parameters to the factory.
+//!
+//! ```
+//! use genevo::stable::registry::{AlgorithmRegistry, Parameters, RegistryError};
+//!
+//! let mut registry: AlgorithmRegistry<String> = AlgorithmRegistry::new();
+//! registry
+//!     .register("greeting", |parameters: &Parameters| {
+//!         let times = parameters.get("times").copied().unwrap_or(1.) as usize;
+//!         Ok("hello ".repeat(times))
+//!     })
+//!     .unwrap();
+//!
+//! let parameters = Parameters::from([("times".to_string(), 2.)]);
+//! assert_eq!(
+//!     registry.create("greeting", &parameters),
+//!     Ok("hello hello ".to_string())
+//! );
+//! assert_eq!(
+//!     registry.create("farewell", &parameters),
+//!     Err(RegistryError::UnknownAlgorithm(
+//!         "no algorithm is registered under the name farewell.".to_string()
+//!     ))
+//! );
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+    sync::Arc,
+};
+
+/// The named parameters an algorithm is constructed from.
+pub type Parameters = BTreeMap<String, f64>;
+
+/// The function that constructs an algorithm from its parameters.
+type Factory<A> = dyn Fn(&Parameters) -> Result<A, RegistryError> + Send + Sync;
+
+/// An error that can occur when registering or creating an algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegistryError {
+    /// An algorithm is already registered under the name.
+    DuplicateName(String),
+    /// No algorithm is registered under the name.
+    UnknownAlgorithm(String),
+    /// The parameters are not valid for the algorithm.
+    InvalidParameters(String),
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::DuplicateName(details) => write!(f, "{}", details),
+            RegistryError::UnknownAlgorithm(details) => write!(f, "{}", details),
+            RegistryError::InvalidParameters(details) => write!(f, "{}", details),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// The `AlgorithmRegistry` maps names to factories of algorithms of type
+/// `A`.
+///
+/// As the registered algorithms usually are of different types, `A` is a
+/// type that can hold any of them, e.g. an enum of the supported algorithms.
+/// Clones of a registry share the factories.
+pub struct AlgorithmRegistry<A> {
+    factories: BTreeMap<String, Arc<Factory<A>>>,
+}
+
+impl<A> AlgorithmRegistry<A> {
+    /// Constructs a new empty `AlgorithmRegistry`.
+    pub fn new() -> Self {
+        AlgorithmRegistry {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the given factory under the given name.
+    ///
+    /// Returns a `RegistryError::DuplicateName` if an algorithm is already
+    /// registered under the name.
+    pub fn register<N, C>(&mut self, name: N, factory: C) -> Result<(), RegistryError>
+    where
+        N: Into<String>,
+        C: Fn(&Parameters) -> Result<A, RegistryError> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        if self.factories.contains_key(&name) {
+            return Err(RegistryError::DuplicateName(format!(
+                "an algorithm is already registered under the name {}.",
+                name
+            )));
+        }
+        self.factories.insert(name, Arc::new(factory));
+        Ok(())
+    }
+
+    /// Returns whether an algorithm is registered under the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Returns the names of the registered algorithms in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(String::as_str)
+    }
+
+    /// Returns the number of registered algorithms.
+    pub fn len(&self) -> usize {
+        self.factories.len()
+    }
+
+    /// Returns whether no algorithm is registered.
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+
+    /// Constructs the algorithm registered under the given name from the
+    /// given parameters.
+    ///
+    /// Returns a `RegistryError::UnknownAlgorithm` if no algorithm is
+    /// registered under the name, or the error of the factory.
+    pub fn create(&self, name: &str, parameters: &Parameters) -> Result<A, RegistryError> {
+        match self.factories.get(name) {
+            Some(factory) => factory(parameters),
+            None => Err(RegistryError::UnknownAlgorithm(format!(
+                "no algorithm is registered under the name {}.",
+                name
+            ))),
+        }
+    }
+}
+
+impl<A> Default for AlgorithmRegistry<A> {
+    fn default() -> Self {
+        AlgorithmRegistry::new()
+    }
+}
+
+impl<A> Clone for AlgorithmRegistry<A> {
+    fn clone(&self) -> Self {
+        AlgorithmRegistry {
+            factories: self.factories.clone(),
+        }
+    }
+}
+
+impl<A> Debug for AlgorithmRegistry<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AlgorithmRegistry")
+            .field("names", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+use super::{registry::*, *};
+use crate::{
+    ga::{genetic_algorithm, GeneticAlgorithm},
+    operator::prelude::*,
+    population::{build_population, ValueEncodedGenomeBuilder},
+    random::get_rng,
+};
+use galvanic_assert::matchers::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        4 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+type SumOfValuesAlgorithm = GeneticAlgorithm<
+    Vec<i32>,
+    i32,
+    SumOfValues,
+    MaximizeSelector,
+    UniformCrossBreeder,
+    RandomValueMutator<Vec<i32>>,
+    ElitistReinserter<Vec<i32>, i32, SumOfValues>,
+>;
+
+fn sum_of_values(parameters: &Parameters) -> Result<SumOfValuesAlgorithm, RegistryError> {
+    let population_size = parameters.get("population_size").copied().unwrap_or(20.);
+    if population_size < 6. {
+        return Err(RegistryError::InvalidParameters(format!(
+            "population_size must be at least 6, but is {}.",
+            population_size
+        )));
+    }
+    let mutation_rate = parameters.get("mutation_rate").copied().unwrap_or(0.1);
+    let initial_population = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(4, 0, 100))
+        .of_size(population_size as usize)
+        .using_seed([1; 32]);
+    Ok(genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(mutation_rate, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.8))
+        .with_initial_population(initial_population)
+        .build())
+}
+
+#[test]
+fn registered_algorithm_is_created_from_its_parameters() {
+    let mut registry = AlgorithmRegistry::new();
+    registry.register("sum-of-values", sum_of_values).unwrap();
+
+    let parameters = Parameters::from([("population_size".to_string(), 10.)]);
+    let mut algorithm = registry.create("sum-of-values", &parameters).unwrap();
+    let state = algorithm.next(1, &mut get_rng([2; 32])).unwrap();
+
+    expect_that!(&state.evaluated_population.individuals().len(), eq(10));
+}
+
+#[test]
+fn invalid_parameters_are_reported_by_the_factory() {
+    let mut registry = AlgorithmRegistry::new();
+    registry.register("sum-of-values", sum_of_values).unwrap();
+
+    let parameters = Parameters::from([("population_size".to_string(), 2.)]);
+
+    expect_that!(
+        &registry.create("sum-of-values", &parameters).err(),
+        eq(Some(RegistryError::InvalidParameters(
+            "population_size must be at least 6, but is 2.".to_string()
+        )))
+    );
+}
+
+#[test]
+fn names_must_be_unique() {
+    let mut registry = AlgorithmRegistry::new();
+    registry.register("sum-of-values", sum_of_values).unwrap();
+
+    let result = registry.register("sum-of-values", sum_of_values);
+
+    expect_that!(
+        &result,
+        eq(Err(RegistryError::DuplicateName(
+            "an algorithm is already registered under the name sum-of-values.".to_string()
+        )))
+    );
+    expect_that!(&registry.len(), eq(1));
+}
+
+#[test]
+fn registered_names_are_listed_in_alphabetical_order() {
+    let mut registry: AlgorithmRegistry<u32> = AlgorithmRegistry::new();
+    registry.register("zeta", |_: &Parameters| Ok(1)).unwrap();
+    registry.register("alpha", |_: &Parameters| Ok(2)).unwrap();
+
+    expect_that!(
+        &registry.names().collect::<Vec<_>>(),
+        eq(vec!["alpha", "zeta"])
+    );
+    expect_that!(&registry.contains("alpha"), eq(true));
+    expect_that!(&registry.contains("beta"), eq(false));
+    expect_that!(&registry.create("alpha", &Parameters::new()), eq(Ok(2)));
+}
@@ -0,0 +1,228 @@
+//! The `drift` module provides the `PopulationDrift`, which describes how a
+//! population changed between two generations, e.g. between the snapshots of
+//! two checkpoints of a simulation.
+//!
+//! The drift is measured by:
+//! * the turnover, i.e. the fraction of individuals of the later population
+//!   that are not present in the earlier population,
+//! * the allele drift, i.e. how much the frequencies of the alleles at each
+//!   locus changed,
+//! * the movement of the best and of the average fitness.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use genevo::{prelude::*, statistic::drift::PopulationDrift};
+//!
+//! #[derive(Clone, Debug)]
+//! struct SumOfValues;
+//!
+//! impl FitnessFunction<Vec<i32>, i32> for SumOfValues {
+//!     fn fitness_of(&self, genome: &Vec<i32>) -> i32 {
+//!         genome.iter().sum()
+//!     }
+//!
+//!     fn average(&self, values: &[i32]) -> i32 {
+//!         values.iter().sum::<i32>() / values.len() as i32
+//!     }
+//!
+//!     fn highest_possible_fitness(&self) -> i32 {
+//!         100
+//!     }
+//!
+//!     fn lowest_possible_fitness(&self) -> i32 {
+//!         0
+//!     }
+//! }
+//!
+//! let earlier = vec![vec![1, 2], vec![3, 4]];
+//! let later = vec![vec![3, 4], vec![3, 6]];
+//!
+//! let drift = PopulationDrift::between(&earlier, &later, &SumOfValues);
+//!
+//! assert_eq!(drift.turnover, 0.5);
+//! assert_eq!(drift.allele_drift, 0.5);
+//! assert_eq!(drift.best_fitness_change, 2.);
+//! assert_eq!(drift.average_fitness_change, 3.);
+//! ```
+//!
+//! The populations of two checkpoints of a simulation are compared by
+//! `PopulationDrift::between_snapshots`.
+
+use crate::{
+    ga::Snapshot,
+    genetic::{AsScalar, Fitness, FitnessFunction, Genotype},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// The `PopulationDrift` describes how a population changed from an earlier
+/// to a later generation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PopulationDrift {
+    /// The fraction of individuals of the later population that are not
+    /// present in the earlier population. It ranges from 0, when every
+    /// individual survived, to 1, when the population has been replaced
+    /// completely.
+    pub turnover: f64,
+    /// The average over all loci of the total variation distance between the
+    /// allele frequencies of the earlier and the later population. It ranges
+    /// from 0, when the frequencies did not change, to 1, when no allele at
+    /// any locus is shared between the populations.
+    pub allele_drift: f64,
+    /// The highest fitness of the later population minus the highest fitness
+    /// of the earlier population.
+    pub best_fitness_change: f64,
+    /// The average fitness of the later population minus the average fitness
+    /// of the earlier population.
+    pub average_fitness_change: f64,
+}
+
+impl PopulationDrift {
+    /// Calculates the `PopulationDrift` from the earlier to the later
+    /// population. The fitness of the individuals is calculated by the given
+    /// fitness function.
+    pub fn between<G, V, F, E>(earlier: &[G], later: &[G], evaluator: &E) -> Self
+    where
+        G: Genotype + AsRef<[V]> + Hash + Eq,
+        V: Hash + Eq,
+        F: Fitness + AsScalar,
+        E: FitnessFunction<G, F>,
+    {
+        let (earlier_best, earlier_average) = best_and_average(earlier, evaluator);
+        let (later_best, later_average) = best_and_average(later, evaluator);
+        PopulationDrift {
+            turnover: turnover(earlier, later),
+            allele_drift: allele_drift(earlier, later),
+            best_fitness_change: later_best - earlier_best,
+            average_fitness_change: later_average - earlier_average,
+        }
+    }
+
+    /// Calculates the `PopulationDrift` from the population of the earlier
+    /// to the population of the later `ga::Snapshot`.
+    pub fn between_snapshots<G, V, F, E>(
+        earlier: &Snapshot<G, F>,
+        later: &Snapshot<G, F>,
+        evaluator: &E,
+    ) -> Self
+    where
+        G: Genotype + AsRef<[V]> + Hash + Eq,
+        V: Hash + Eq,
+        F: Fitness + AsScalar,
+        E: FitnessFunction<G, F>,
+    {
+        PopulationDrift::between(&earlier.population, &later.population, evaluator)
+    }
+}
+
+/// Returns the fraction of individuals of the later population that are not
+/// present in the earlier population, or 0 if the later population is
+/// empty.
+pub fn turnover<G>(earlier: &[G], later: &[G]) -> f64
+where
+    G: Hash + Eq,
+{
+    if later.is_empty() {
+        return 0.;
+    }
+    let survivors: HashSet<&G> = earlier.iter().collect();
+    let newcomers = later
+        .iter()
+        .filter(|genome| !survivors.contains(genome))
+        .count();
+    newcomers as f64 / later.len() as f64
+}
+
+/// Returns the frequency of each allele at each locus of the given
+/// population. The frequencies at locus `i` are found at index `i` of the
+/// result. Genomes that are shorter than the longest genome do not count at
+/// the loci they do not have.
+pub fn allele_frequencies<G, V>(population: &[G]) -> Vec<HashMap<&V, f64>>
+where
+    G: AsRef<[V]>,
+    V: Hash + Eq,
+{
+    let mut counts: Vec<HashMap<&V, usize>> = Vec::new();
+    for genome in population {
+        let genes = genome.as_ref();
+        if counts.len() < genes.len() {
+            counts.resize_with(genes.len(), HashMap::new);
+        }
+        for (locus, allele) in genes.iter().enumerate() {
+            *counts[locus].entry(allele).or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|alleles| {
+            let total: usize = alleles.values().sum();
+            alleles
+                .into_iter()
+                .map(|(allele, count)| (allele, count as f64 / total as f64))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the average over all loci of the total variation distance between
+/// the allele frequencies of the earlier and the later population. A locus
+/// that is present in only one of the populations has a distance of 1.
+pub fn allele_drift<G, V>(earlier: &[G], later: &[G]) -> f64
+where
+    G: AsRef<[V]>,
+    V: Hash + Eq,
+{
+    let earlier = allele_frequencies(earlier);
+    let later = allele_frequencies(later);
+    let num_loci = earlier.len().max(later.len());
+    if num_loci == 0 {
+        return 0.;
+    }
+    let no_alleles = HashMap::new();
+    let sum: f64 = (0..num_loci)
+        .map(|locus| {
+            let before = earlier.get(locus).unwrap_or(&no_alleles);
+            let after = later.get(locus).unwrap_or(&no_alleles);
+            if before.is_empty() || after.is_empty() {
+                return 1.;
+            }
+            let alleles: HashSet<&&V> = before.keys().chain(after.keys()).collect();
+            let difference: f64 = alleles
+                .into_iter()
+                .map(|allele| {
+                    let a = before.get(allele).copied().unwrap_or(0.);
+                    let b = after.get(allele).copied().unwrap_or(0.);
+                    (a - b).abs()
+                })
+                .sum();
+            difference / 2.
+        })
+        .sum();
+    sum / num_loci as f64
+}
+
+/// Returns the highest and the average fitness of the given population as
+/// scalar values, or 0 for both if the population is empty.
+fn best_and_average<G, F, E>(population: &[G], evaluator: &E) -> (f64, f64)
+where
+    G: Genotype,
+    F: Fitness + AsScalar,
+    E: FitnessFunction<G, F>,
+{
+    if population.is_empty() {
+        return (0., 0.);
+    }
+    let fitness_values: Vec<F> = population
+        .iter()
+        .map(|genome| evaluator.fitness_of(genome))
+        .collect();
+    let best = fitness_values
+        .iter()
+        .max()
+        .map_or(0., |fitness| fitness.as_scalar());
+    let average = evaluator.average(&fitness_values).as_scalar();
+    (best, average)
+}
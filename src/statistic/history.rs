@@ -0,0 +1,170 @@
+//! The `history` module provides the `SimulationStatistics`, which record
+//! the key figures of each generation of a simulation, so that the course
+//! of the evolution can be inspected or exported after the run.
+//!
+//! The `simulation::simulator::Simulator` records the statistics if it has
+//! been built with `with_statistics_history`. They are available from
+//! `Simulator::simulation_statistics` after or during the run.
+
+use crate::statistic::{PopulationDiversity, ProcessingTime};
+use chrono::Duration;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+/// The output of an algorithm that provides the key figures of one
+/// generation for the `SimulationStatistics`.
+pub trait GenerationStatistics {
+    /// Returns the highest fitness of the generation as scalar value.
+    fn highest_fitness_value(&self) -> f64;
+
+    /// Returns the average fitness of the generation as scalar value.
+    fn average_fitness_value(&self) -> f64;
+
+    /// Returns the lowest fitness of the generation as scalar value.
+    fn lowest_fitness_value(&self) -> f64;
+
+    /// Returns the diversity of the population of the generation if it has
+    /// been measured.
+    fn population_diversity(&self) -> Option<PopulationDiversity> {
+        None
+    }
+}
+
+/// The `GenerationRecord` holds the key figures of one generation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GenerationRecord {
+    /// The iteration of the simulation that processed the generation.
+    pub iteration: u64,
+    /// The fitness of the best individual of the generation.
+    pub best_fitness: f64,
+    /// The average fitness of the generation.
+    pub average_fitness: f64,
+    /// The fitness of the worst individual of the generation.
+    pub worst_fitness: f64,
+    /// The diversity of the population, if it has been measured.
+    pub diversity: Option<PopulationDiversity>,
+    /// The wall clock time the iteration took.
+    #[cfg_attr(feature = "serde", serde(with = "crate::statistic::serde_duration"))]
+    pub duration: Duration,
+    /// The processing time of the iteration. In case of parallel processing
+    /// it is the accumulated time spent by each thread.
+    pub processing_time: ProcessingTime,
+}
+
+impl GenerationRecord {
+    /// Constructs a new `GenerationRecord` with the key figures of the given
+    /// output of an algorithm.
+    pub fn of<O>(
+        iteration: u64,
+        output: &O,
+        duration: Duration,
+        processing_time: ProcessingTime,
+    ) -> Self
+    where
+        O: GenerationStatistics,
+    {
+        GenerationRecord {
+            iteration,
+            best_fitness: output.highest_fitness_value(),
+            average_fitness: output.average_fitness_value(),
+            worst_fitness: output.lowest_fitness_value(),
+            diversity: output.population_diversity(),
+            duration,
+            processing_time,
+        }
+    }
+}
+
+/// The `SimulationStatistics` collect a `GenerationRecord` for each
+/// generation of a simulation in the order of the iterations.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SimulationStatistics {
+    history: Vec<GenerationRecord>,
+}
+
+impl SimulationStatistics {
+    /// Constructs new `SimulationStatistics` without any records.
+    pub fn new() -> Self {
+        SimulationStatistics {
+            history: Vec::new(),
+        }
+    }
+
+    /// Adds the given record of the next generation.
+    pub fn record(&mut self, record: GenerationRecord) {
+        self.history.push(record);
+    }
+
+    /// Returns the records of all generations in the order of the
+    /// iterations.
+    pub fn history(&self) -> &[GenerationRecord] {
+        &self.history
+    }
+
+    /// Returns the record of the most recent generation.
+    pub fn last(&self) -> Option<&GenerationRecord> {
+        self.history.last()
+    }
+
+    /// Returns the number of recorded generations.
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns whether no generation has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Removes the records of all generations after the given iteration,
+    /// e.g. when a simulation is continued from an earlier checkpoint.
+    pub fn truncate_after(&mut self, iteration: u64) {
+        self.history.retain(|record| record.iteration <= iteration);
+    }
+
+    /// Removes all records.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Writes the records as comma separated values to the given writer.
+    ///
+    /// The first line holds the names of the columns. The durations are
+    /// written in milliseconds. The diversity columns are empty for
+    /// generations whose diversity has not been measured.
+    pub fn to_csv<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        writeln!(
+            writer,
+            "iteration,best_fitness,average_fitness,worst_fitness,genotypic_diversity,\
+             phenotypic_diversity,duration_ms,processing_time_ms"
+        )?;
+        for record in &self.history {
+            let (genotypic, phenotypic) = match record.diversity {
+                Some(diversity) => (
+                    diversity.genotypic.to_string(),
+                    diversity.phenotypic.to_string(),
+                ),
+                None => (String::new(), String::new()),
+            };
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{}",
+                record.iteration,
+                record.best_fitness,
+                record.average_fitness,
+                record.worst_fitness,
+                genotypic,
+                phenotypic,
+                record.duration.num_milliseconds(),
+                record.processing_time.duration().num_milliseconds()
+            )?;
+        }
+        writer.flush()
+    }
+}
@@ -1,16 +1,29 @@
 //! The `statistic` module provides functionality to collect and display
 //! statistic about a genetic algorithm application and its execution.
 
-use crate::types::fmt::Display;
+pub mod drift;
+
+pub mod history;
+
+use crate::{
+    algorithm::EvaluatedPopulation,
+    genetic::{AsScalar, Fitness, GenomeDistance, Genotype},
+    types::fmt::Display,
+};
 use chrono::{Duration, Local};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     convert::From,
     fmt,
     ops::{Add, AddAssign},
+    sync::Arc,
 };
 
 #[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
 pub struct ProcessingTime {
+    #[cfg_attr(feature = "serde", serde(with = "serde_duration"))]
     duration: Duration,
 }
 
@@ -63,6 +76,30 @@ impl AddAssign for ProcessingTime {
     }
 }
 
+/// Serializes a `chrono::Duration` as number of nanoseconds.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_duration {
+    use chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        duration
+            .num_nanoseconds()
+            .unwrap_or(i64::MAX)
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Duration::nanoseconds)
+    }
+}
+
 pub trait TrackProcessingTime {
     fn processing_time(&self) -> ProcessingTime;
 }
@@ -103,5 +140,354 @@ where
     }
 }
 
+/// The `FitnessStats` hold the minimum, maximum, mean and standard deviation
+/// of the scalar values of a list of `genetic::Fitness` values.
+///
+/// The statistics are calculated in a single pass over the values. Partial
+/// statistics of slices of the values can be merged, so that they are
+/// calculated in a single parallel reduction for large populations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitnessStats {
+    count: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+    /// The sum of squared differences from the mean.
+    m2: f64,
+}
+
+impl FitnessStats {
+    /// Constructs `FitnessStats` of no values.
+    pub fn empty() -> Self {
+        FitnessStats {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            mean: 0.,
+            m2: 0.,
+        }
+    }
+
+    /// Calculates the `FitnessStats` of the given fitness values.
+    #[cfg(any(not(target_arch = "wasm32"), feature = "wasm-parallel"))]
+    pub fn of<F>(fitness_values: &[F]) -> Self
+    where
+        F: AsScalar + Sync,
+    {
+        if fitness_values.len() < 1000 {
+            fitness_values
+                .iter()
+                .fold(FitnessStats::empty(), |stats, value| {
+                    stats.with_value(value.as_scalar())
+                })
+        } else {
+            let (l_slice, r_slice) = fitness_values.split_at(fitness_values.len() / 2);
+            let (left, right) =
+                rayon::join(|| FitnessStats::of(l_slice), || FitnessStats::of(r_slice));
+            left.merge(right)
+        }
+    }
+
+    /// Calculates the `FitnessStats` of the given fitness values.
+    #[cfg(all(target_arch = "wasm32", not(feature = "wasm-parallel")))]
+    pub fn of<F>(fitness_values: &[F]) -> Self
+    where
+        F: AsScalar + Sync,
+    {
+        fitness_values
+            .iter()
+            .fold(FitnessStats::empty(), |stats, value| {
+                stats.with_value(value.as_scalar())
+            })
+    }
+
+    /// Returns these `FitnessStats` with the given value added.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self
+    }
+
+    /// Merges these `FitnessStats` with the `FitnessStats` of other values.
+    pub fn merge(self, other: FitnessStats) -> Self {
+        if self.count == 0 {
+            return other;
+        }
+        if other.count == 0 {
+            return self;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        FitnessStats {
+            count,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+            mean: self.mean + delta * other.count as f64 / count as f64,
+            m2: self.m2
+                + other.m2
+                + delta * delta * self.count as f64 * other.count as f64 / count as f64,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the smallest value or `None` if there are no values.
+    pub fn min(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+
+    /// Returns the largest value or `None` if there are no values.
+    pub fn max(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+
+    /// Returns the arithmetic mean of the values, which is 0 if there are no
+    /// values.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the population variance of the values, which is 0 if there
+    /// are no values.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Returns the population standard deviation of the values.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+impl Default for FitnessStats {
+    fn default() -> Self {
+        FitnessStats::empty()
+    }
+}
+
+/// The `PopulationDiversity` measures how different the individuals of a
+/// population are.
+///
+/// A diversity that drops fast is a sign of premature convergence: the
+/// population has lost the variation needed to escape a local optimum.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PopulationDiversity {
+    /// The average distance between all pairs of individuals as measured by
+    /// the `genetic::GenomeDistance` of the genotype.
+    pub genotypic: f64,
+    /// The standard deviation of the fitness values.
+    pub phenotypic: f64,
+}
+
+impl PopulationDiversity {
+    /// Calculates the `PopulationDiversity` of the given evaluated
+    /// population.
+    pub fn of<G, F>(evaluated: &EvaluatedPopulation<G, F>) -> Self
+    where
+        G: Genotype + GenomeDistance,
+        F: Fitness + AsScalar + Sync,
+    {
+        PopulationDiversity {
+            genotypic: evaluated.genotypic_diversity(),
+            phenotypic: evaluated.phenotypic_diversity(),
+        }
+    }
+}
+
+/// Returns the average distance between all pairs of the given genomes as
+/// calculated by the given function, or 0 for less than two genomes.
+///
+/// The distance of each pair is calculated once, so it takes
+/// `n * (n - 1) / 2` calculations for `n` genomes.
+pub fn average_pairwise_distance<G, D>(genomes: &[G], distance: D) -> f64
+where
+    D: Fn(&G, &G) -> f64,
+{
+    let num_genomes = genomes.len();
+    if num_genomes < 2 {
+        return 0.;
+    }
+    let sum: f64 = genomes
+        .iter()
+        .enumerate()
+        .map(|(index, genome)| {
+            genomes[index + 1..]
+                .iter()
+                .map(|other| distance(genome, other))
+                .sum::<f64>()
+        })
+        .sum();
+    sum / (num_genomes * (num_genomes - 1) / 2) as f64
+}
+
+/// The `StatisticsConfig` defines optional statistics that are calculated
+/// from the output of an algorithm by the `simulation::simulator::Simulator`,
+/// e.g. the diversity of the population or the frequencies of alleles.
+///
+/// Such statistics can be expensive to calculate for large populations.
+/// Therefore they are calculated only every `stride` iterations, i.e. in the
+/// iterations `stride`, `2 * stride`, `3 * stride` and so on. By default the
+/// stride is 1 and the statistics are calculated in every iteration.
+pub struct StatisticsConfig<O> {
+    stride: u64,
+    statistics: Vec<(String, Arc<StatisticFn<O>>)>,
+}
+
+/// The function that calculates an optional statistic from the output of
+/// an algorithm.
+type StatisticFn<O> = dyn Fn(&O) -> f64 + Send + Sync;
+
+impl<O> StatisticsConfig<O> {
+    /// Constructs a new `StatisticsConfig` without any statistics that would
+    /// be calculated in every iteration.
+    pub fn new() -> Self {
+        StatisticsConfig {
+            stride: 1,
+            statistics: Vec::new(),
+        }
+    }
+
+    /// Sets the number of iterations between two calculations of the
+    /// statistics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stride is 0.
+    pub fn with_stride(mut self, stride: u64) -> Self {
+        assert!(stride > 0, "the stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
+    /// Adds a statistic with the given name that is calculated by the given
+    /// function from the output of an algorithm.
+    pub fn with_statistic<S>(mut self, name: &str, statistic: S) -> Self
+    where
+        S: Fn(&O) -> f64 + Send + Sync + 'static,
+    {
+        self.statistics
+            .push((name.to_string(), Arc::new(statistic)));
+        self
+    }
+
+    /// Returns the number of iterations between two calculations of the
+    /// statistics.
+    pub fn stride(&self) -> u64 {
+        self.stride
+    }
+
+    /// Returns the names of the statistics in the order they have been
+    /// added.
+    pub fn names(&self) -> Vec<&str> {
+        self.statistics
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Returns whether the statistics are due to be calculated in the given
+    /// iteration.
+    pub fn is_due(&self, iteration: u64) -> bool {
+        !self.statistics.is_empty() && iteration.is_multiple_of(self.stride)
+    }
+
+    /// Calculates the statistics from the given output if they are due in
+    /// the given iteration.
+    pub fn calculate(&self, iteration: u64, output: &O) -> Option<Statistics> {
+        if self.is_due(iteration) {
+            Some(Statistics {
+                iteration,
+                values: self
+                    .statistics
+                    .iter()
+                    .map(|(name, statistic)| (name.clone(), statistic(output)))
+                    .collect(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<O> Default for StatisticsConfig<O> {
+    fn default() -> Self {
+        StatisticsConfig::new()
+    }
+}
+
+impl<O> Clone for StatisticsConfig<O> {
+    fn clone(&self) -> Self {
+        StatisticsConfig {
+            stride: self.stride,
+            statistics: self.statistics.clone(),
+        }
+    }
+}
+
+impl<O> fmt::Debug for StatisticsConfig<O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StatisticsConfig")
+            .field("stride", &self.stride)
+            .field("statistics", &self.names())
+            .finish()
+    }
+}
+
+impl<O> PartialEq for StatisticsConfig<O> {
+    fn eq(&self, other: &Self) -> bool {
+        // functions are not comparable, so only the names are compared
+        self.stride == other.stride && self.names() == other.names()
+    }
+}
+
+/// The values of the optional statistics defined by a `StatisticsConfig`
+/// as calculated in one iteration.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Statistics {
+    iteration: u64,
+    values: Vec<(String, f64)>,
+}
+
+impl Statistics {
+    /// Returns the iteration in which these statistics have been calculated.
+    pub fn iteration(&self) -> u64 {
+        self.iteration
+    }
+
+    /// Returns the value of the statistic with the given name.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(value_name, _)| value_name == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the names and values of all statistics.
+    pub fn values(&self) -> &[(String, f64)] {
+        &self.values
+    }
+}
+
 #[cfg(test)]
 mod tests;
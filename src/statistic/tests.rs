@@ -33,3 +33,340 @@ mod timed_fn {
         );
     }
 }
+
+mod fitness_stats {
+
+    use super::*;
+    use crate::statistic::FitnessStats;
+
+    #[test]
+    fn fitness_stats_of_no_values() {
+        let stats = FitnessStats::of::<u32>(&[]);
+
+        expect_that!(&stats.count(), eq(0));
+        expect_that!(&stats.min(), eq(None));
+        expect_that!(&stats.max(), eq(None));
+        expect_that!(&stats.mean(), eq(0.));
+        expect_that!(&stats.std_dev(), eq(0.));
+    }
+
+    #[test]
+    fn fitness_stats_of_some_values() {
+        let stats = FitnessStats::of(&[2u32, 4, 4, 4, 5, 5, 7, 9]);
+
+        expect_that!(&stats.count(), eq(8));
+        expect_that!(&stats.min(), eq(Some(2.)));
+        expect_that!(&stats.max(), eq(Some(9.)));
+        expect_that!(&stats.mean(), eq(5.));
+        expect_that!(&stats.variance(), eq(4.));
+        expect_that!(&stats.std_dev(), eq(2.));
+    }
+
+    #[test]
+    fn fitness_stats_of_many_values_are_reduced_in_parallel() {
+        let values: Vec<i64> = (0..10_001).collect();
+
+        let stats = FitnessStats::of(&values);
+
+        expect_that!(&stats.count(), eq(10_001));
+        expect_that!(&stats.min(), eq(Some(0.)));
+        expect_that!(&stats.max(), eq(Some(10_000.)));
+        expect_that!(&stats.mean(), eq(5_000.));
+        let expected_variance = (10_001. * 10_001. - 1.) / 12.;
+        expect_that!(
+            &((stats.variance() - expected_variance).abs() < 1e-6 * expected_variance),
+            eq(true)
+        );
+    }
+
+    #[test]
+    fn merged_fitness_stats_equal_the_fitness_stats_of_all_values() {
+        let left = FitnessStats::of(&[1u32, 2, 3]);
+        let right = FitnessStats::of(&[10u32, 20]);
+
+        let merged = left.merge(right);
+        let all = FitnessStats::of(&[1u32, 2, 3, 10, 20]);
+
+        expect_that!(&merged.count(), eq(all.count()));
+        expect_that!(&merged.min(), eq(all.min()));
+        expect_that!(&merged.max(), eq(all.max()));
+        expect_that!(&((merged.mean() - all.mean()).abs() < 1e-9), eq(true));
+        expect_that!(
+            &((merged.variance() - all.variance()).abs() < 1e-9),
+            eq(true)
+        );
+    }
+}
+
+mod statistics_config {
+
+    use super::*;
+    use crate::statistic::StatisticsConfig;
+
+    fn config() -> StatisticsConfig<Vec<f64>> {
+        StatisticsConfig::new()
+            .with_stride(3)
+            .with_statistic("sum", |values: &Vec<f64>| values.iter().sum())
+            .with_statistic("length", |values: &Vec<f64>| values.len() as f64)
+    }
+
+    #[test]
+    fn statistics_are_due_every_stride_iterations() {
+        let config = config();
+
+        let due: Vec<u64> = (1..=10)
+            .filter(|iteration| config.is_due(*iteration))
+            .collect();
+
+        expect_that!(&due, eq(vec![3, 6, 9]));
+    }
+
+    #[test]
+    fn statistics_are_never_due_if_there_are_no_statistics() {
+        let config = StatisticsConfig::<Vec<f64>>::new();
+
+        expect_that!(
+            &(1..=10).any(|iteration| config.is_due(iteration)),
+            eq(false)
+        );
+    }
+
+    #[test]
+    fn calculate_returns_the_values_of_all_statistics_if_they_are_due() {
+        let config = config();
+        let output = vec![1., 2., 4.];
+
+        let statistics = config.calculate(6, &output).unwrap();
+
+        expect_that!(&statistics.iteration(), eq(6));
+        expect_that!(&statistics.get("sum"), eq(Some(7.)));
+        expect_that!(&statistics.get("length"), eq(Some(3.)));
+        expect_that!(&statistics.get("diversity"), eq(None));
+        expect_that!(&config.calculate(7, &output), eq(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "the stride must be at least 1")]
+    fn a_stride_of_0_is_rejected() {
+        let _ = StatisticsConfig::<Vec<f64>>::new().with_stride(0);
+    }
+}
+
+mod population_diversity {
+
+    use crate::{
+        algorithm::EvaluatedPopulation,
+        genetic::GenomeDistance,
+        statistic::{average_pairwise_distance, PopulationDiversity},
+    };
+    use galvanic_assert::matchers::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn average_pairwise_distance_of_less_than_two_genomes_is_zero() {
+        expect_that!(
+            &average_pairwise_distance(&Vec::<Vec<i32>>::new(), Vec::distance),
+            eq(0.)
+        );
+        expect_that!(
+            &average_pairwise_distance(&[vec![1, 2]], Vec::distance),
+            eq(0.)
+        );
+    }
+
+    #[test]
+    fn average_pairwise_distance_averages_the_distance_of_each_pair() {
+        let genomes = vec![vec![0, 0], vec![3, 4], vec![0, 8]];
+
+        // distances of the pairs are 5, 8 and 5
+        expect_that!(&average_pairwise_distance(&genomes, Vec::distance), eq(6.));
+    }
+
+    #[test]
+    fn hamming_distance_counts_different_bits() {
+        expect_that!(
+            &vec![true, false, true].distance(&vec![false, false, true, true]),
+            eq(2.)
+        );
+    }
+
+    #[test]
+    fn diversity_of_a_converged_population_is_zero() {
+        let evaluated =
+            EvaluatedPopulation::new(Arc::new(vec![vec![1, 2]; 4]), vec![3; 4], 3, 3, 3);
+
+        expect_that!(
+            &PopulationDiversity::of(&evaluated),
+            eq(PopulationDiversity {
+                genotypic: 0.,
+                phenotypic: 0.,
+            })
+        );
+    }
+
+    #[test]
+    fn diversity_of_an_evaluated_population() {
+        let evaluated = EvaluatedPopulation::new(
+            Arc::new(vec![vec![0, 0], vec![6, 8]]),
+            vec![0, 14],
+            14,
+            0,
+            7,
+        );
+
+        expect_that!(&evaluated.genotypic_diversity(), eq(10.));
+        expect_that!(&evaluated.phenotypic_diversity(), eq(7.));
+    }
+}
+
+mod population_drift {
+
+    use crate::{
+        ga::Snapshot,
+        genetic::FitnessFunction,
+        statistic::drift::{allele_drift, allele_frequencies, turnover, PopulationDrift},
+    };
+    use galvanic_assert::matchers::*;
+
+    #[derive(Clone, Debug)]
+    struct SumOfValues;
+
+    impl FitnessFunction<Vec<u8>, u32> for SumOfValues {
+        fn fitness_of(&self, genome: &Vec<u8>) -> u32 {
+            genome.iter().map(|value| u32::from(*value)).sum()
+        }
+
+        fn average(&self, fitness_values: &[u32]) -> u32 {
+            fitness_values.iter().sum::<u32>() / fitness_values.len() as u32
+        }
+
+        fn highest_possible_fitness(&self) -> u32 {
+            u32::MAX
+        }
+
+        fn lowest_possible_fitness(&self) -> u32 {
+            0
+        }
+    }
+
+    #[test]
+    fn turnover_is_the_fraction_of_new_individuals() {
+        let earlier = vec![vec![1u8], vec![2], vec![3], vec![4]];
+        let later = vec![vec![1u8], vec![2], vec![5], vec![6]];
+
+        expect_that!(&turnover(&earlier, &later), eq(0.5));
+        expect_that!(&turnover(&earlier, &earlier), eq(0.));
+        expect_that!(&turnover(&earlier, &[]), eq(0.));
+    }
+
+    #[test]
+    fn allele_frequencies_are_counted_per_locus() {
+        let population = vec![vec![1u8, 0], vec![1, 1], vec![2, 1], vec![1, 1]];
+
+        let frequencies = allele_frequencies(&population);
+
+        expect_that!(&frequencies.len(), eq(2));
+        expect_that!(&frequencies[0][&1], eq(0.75));
+        expect_that!(&frequencies[0][&2], eq(0.25));
+        expect_that!(&frequencies[1][&0], eq(0.25));
+        expect_that!(&frequencies[1][&1], eq(0.75));
+    }
+
+    #[test]
+    fn allele_drift_ranges_from_unchanged_to_disjoint_alleles() {
+        let earlier = vec![vec![0u8, 0], vec![1, 1]];
+        let shuffled = vec![vec![1u8, 0], vec![0, 1]];
+        let disjoint = vec![vec![2u8, 2], vec![3, 3]];
+
+        expect_that!(&allele_drift(&earlier, &shuffled), eq(0.));
+        expect_that!(&allele_drift(&earlier, &disjoint), eq(1.));
+    }
+
+    #[test]
+    fn drift_between_snapshots_reports_the_movement_of_the_fitness() {
+        let earlier = Snapshot {
+            population: vec![vec![1u8, 1], vec![2, 2]],
+            best_fitness: Some(4),
+            stagnant_generations: 0,
+            hall_of_fame: None,
+        };
+        let later = Snapshot {
+            population: vec![vec![2u8, 2], vec![5, 5]],
+            best_fitness: Some(10),
+            stagnant_generations: 0,
+            hall_of_fame: None,
+        };
+
+        let drift = PopulationDrift::between_snapshots(&earlier, &later, &SumOfValues);
+
+        expect_that!(&drift.turnover, eq(0.5));
+        expect_that!(&drift.allele_drift, eq(0.5));
+        expect_that!(&drift.best_fitness_change, eq(6.));
+        expect_that!(&drift.average_fitness_change, eq(4.));
+    }
+}
+
+mod simulation_statistics {
+
+    use super::*;
+    use crate::statistic::{
+        history::{GenerationRecord, SimulationStatistics},
+        PopulationDiversity, ProcessingTime,
+    };
+    use chrono::Duration;
+
+    fn record(iteration: u64, diversity: Option<PopulationDiversity>) -> GenerationRecord {
+        GenerationRecord {
+            iteration,
+            best_fitness: 9.,
+            average_fitness: 5.5,
+            worst_fitness: 1.,
+            diversity,
+            duration: Duration::milliseconds(12),
+            processing_time: ProcessingTime::from(Duration::milliseconds(30)),
+        }
+    }
+
+    #[test]
+    fn to_csv_writes_a_header_and_one_line_per_generation() {
+        let mut statistics = SimulationStatistics::new();
+        statistics.record(record(1, None));
+        statistics.record(record(
+            2,
+            Some(PopulationDiversity {
+                genotypic: 2.5,
+                phenotypic: 0.5,
+            }),
+        ));
+        let mut csv = Vec::new();
+
+        statistics.to_csv(&mut csv).unwrap();
+
+        expect_that!(
+            &String::from_utf8(csv).unwrap(),
+            eq(
+                "iteration,best_fitness,average_fitness,worst_fitness,genotypic_diversity,\
+                phenotypic_diversity,duration_ms,processing_time_ms\n\
+                1,9,5.5,1,,,12,30\n\
+                2,9,5.5,1,2.5,0.5,12,30\n"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn truncate_after_drops_the_records_of_later_generations() {
+        let mut statistics = SimulationStatistics::new();
+        for iteration in 1..=5 {
+            statistics.record(record(iteration, None));
+        }
+
+        statistics.truncate_after(3);
+
+        expect_that!(&statistics.len(), eq(3));
+        expect_that!(
+            &statistics.last().map(|record| record.iteration),
+            eq(Some(3))
+        );
+    }
+}
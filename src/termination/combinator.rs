@@ -72,6 +72,11 @@ where
             _ => StopFlag::StopNow(reasons.join(" and ")), /* TODO how combine the two `StopReason`s preserving combinator semantics? */
         }
     }
+
+    fn reset(&mut self) {
+        self.condition1.reset();
+        self.condition2.reset();
+    }
 }
 
 // TODO add doc comments
@@ -142,4 +147,9 @@ where
             _ => StopFlag::StopNow(reasons.join(" and ")), /* TODO how combine the two `StopReason`s preserving combinator semantics? */
         }
     }
+
+    fn reset(&mut self) {
+        self.condition1.reset();
+        self.condition2.reset();
+    }
 }
@@ -4,21 +4,31 @@
 //! Provided limiters are:
 //! * `FitnessLimit` - stops the simulation after a solution with a certain
 //!   fitness has been found.
+//! * `FitnessPlateau` - stops the simulation when the best fitness has not
+//!   improved by more than an epsilon for a number of generations.
+//! * `DiversityLimit` - stops the simulation when the diversity of the
+//!   population has fallen below a minimum for a number of generations.
 //! * `IterationLimit` - stops the simulation after a maximum number of
 //!   iterations has been processed.
 //! * `TimeLimit` - stops the simulation after a the specified time limit
 //!   has been reached.
+//! * `Budget` - stops the simulation as soon as one of a maximum number of
+//!   fitness evaluations, a maximum time or a maximum number of generations
+//!   is reached.
 
 use crate::{
-    algorithm::Algorithm,
+    algorithm::{Algorithm, OptimizationResult},
     ga::GeneticAlgorithm,
-    genetic::{Fitness, FitnessFunction, Genotype},
+    genetic::{AsScalar, Fitness, FitnessFunction, GenomeDistance, Genotype},
     operator::{CrossoverOp, MutationOp, ReinsertionOp, SelectionOp},
     simulation::State,
     termination::{StopFlag, Termination},
 };
 use chrono::{Duration, Local};
-use std::marker::PhantomData;
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
 
 /// The `FitnessLimit` condition stops the simulation after a solution with
 /// a certain fitness has been found.
@@ -53,11 +63,241 @@ where
     }
 }
 
-impl<G, F, E, S, C, M, R> Termination<GeneticAlgorithm<G, F, E, S, C, M, R>> for FitnessLimit<G, F>
+impl<A, G, F> Termination<A> for FitnessLimit<G, F>
 where
+    A: Algorithm,
+    <A as Algorithm>::Output: OptimizationResult<G, F>,
     G: Genotype,
-    F: Fitness + Send + Sync,
-    E: FitnessFunction<G, F>,
+    F: Fitness,
+{
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
+        let highest_fitness = state.result.best_fitness();
+        if *highest_fitness >= self.fitness_target {
+            StopFlag::StopNow(format!(
+                "Simulation stopped after a solution with a fitness of {:?} \
+                 has been found.",
+                highest_fitness
+            ))
+        } else {
+            StopFlag::Continue
+        }
+    }
+}
+
+/// The `FitnessPlateau` condition stops the simulation when the fitness of
+/// the best solution has reached a plateau, i.e. it has not improved by more
+/// than `epsilon` for `max_stagnant_generations` consecutive generations.
+///
+/// Improvements that are not greater than `epsilon` do not reset the count
+/// of stagnant generations, but they add up: once the best fitness exceeds
+/// the fitness of the last counted improvement by more than `epsilon`, the
+/// count starts again.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FitnessPlateau<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    _g: PhantomData<G>,
+    /// The minimum improvement of the fitness that resets the count.
+    epsilon: F,
+    /// The number of generations without improvement after which the
+    /// simulation is stopped.
+    max_stagnant_generations: u64,
+    /// The fitness of the last counted improvement.
+    reference_fitness: Option<F>,
+    /// The number of generations since the last counted improvement.
+    stagnant_generations: u64,
+}
+
+impl<G, F> FitnessPlateau<G, F>
+where
+    G: Genotype,
+    F: Fitness,
+{
+    /// Create a new instance of `FitnessPlateau` that stops the simulation
+    /// when the best fitness has not improved by more than `epsilon` for
+    /// `max_stagnant_generations` generations.
+    pub fn new(epsilon: F, max_stagnant_generations: u64) -> Self {
+        FitnessPlateau {
+            _g: PhantomData,
+            epsilon,
+            max_stagnant_generations,
+            reference_fitness: None,
+            stagnant_generations: 0,
+        }
+    }
+
+    /// Returns the minimum improvement of the fitness that counts as
+    /// improvement.
+    pub fn epsilon(&self) -> &F {
+        &self.epsilon
+    }
+
+    /// Returns the number of generations without improvement after which
+    /// the simulation is stopped.
+    pub fn max_stagnant_generations(&self) -> u64 {
+        self.max_stagnant_generations
+    }
+
+    /// Returns the number of generations since the best fitness has last
+    /// improved by more than `epsilon`.
+    pub fn stagnant_generations(&self) -> u64 {
+        self.stagnant_generations
+    }
+}
+
+impl<A, G, F> Termination<A> for FitnessPlateau<G, F>
+where
+    A: Algorithm,
+    <A as Algorithm>::Output: OptimizationResult<G, F>,
+    G: Genotype,
+    F: Fitness,
+{
+    fn evaluate(&mut self, state: &State<A>) -> StopFlag {
+        let best_fitness = state.result.best_fitness();
+        let improved = self.reference_fitness.as_ref().is_none_or(|reference| {
+            best_fitness > reference && best_fitness.abs_diff(reference) > self.epsilon
+        });
+        if improved {
+            self.reference_fitness = Some(best_fitness.clone());
+            self.stagnant_generations = 0;
+        } else {
+            self.stagnant_generations += 1;
+        }
+        if self.stagnant_generations >= self.max_stagnant_generations {
+            StopFlag::StopNow(format!(
+                "Simulation stopped after the best fitness of {:?} has not improved \
+                 by more than {:?} for {} generations.",
+                best_fitness, self.epsilon, self.stagnant_generations
+            ))
+        } else {
+            StopFlag::Continue
+        }
+    }
+
+    fn reset(&mut self) {
+        self.reference_fitness = None;
+        self.stagnant_generations = 0;
+    }
+}
+
+/// The measure of population diversity that is watched by a
+/// `DiversityLimit`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DiversityMeasure {
+    /// The average distance between all pairs of individuals as measured by
+    /// the `genetic::GenomeDistance` of the genotype.
+    Genotypic,
+    /// The standard deviation of the fitness values.
+    Phenotypic,
+}
+
+impl Display for DiversityMeasure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiversityMeasure::Genotypic => write!(f, "genotypic"),
+            DiversityMeasure::Phenotypic => write!(f, "phenotypic"),
+        }
+    }
+}
+
+/// The `DiversityLimit` condition stops the simulation when the diversity of
+/// the population has collapsed, i.e. it has been below a minimum diversity
+/// for a number of consecutive generations. Continuing the evolution of a
+/// converged population wastes time, as crossover of almost identical
+/// individuals hardly produces anything new.
+///
+/// The diversity reported in the `ga::State` is used if the algorithm tracks
+/// the diversity (see `GeneticAlgorithmBuilder::with_diversity_tracking`).
+/// Otherwise the watched measure is calculated from the evaluated
+/// population, which for the genotypic diversity takes time quadratic in the
+/// size of the population.
+///
+/// ```
+/// use genevo::termination::limit::{DiversityLimit, DiversityMeasure};
+///
+/// let limit = DiversityLimit::genotypic(0.5).for_generations(3);
+///
+/// assert_eq!(limit.measure(), DiversityMeasure::Genotypic);
+/// assert_eq!(limit.min_diversity(), 0.5);
+/// assert_eq!(limit.max_collapsed_generations(), 3);
+/// ```
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversityLimit {
+    /// The measure of diversity that is watched.
+    measure: DiversityMeasure,
+    /// The diversity below which the population counts as collapsed.
+    min_diversity: f64,
+    /// The number of consecutive generations the population must be
+    /// collapsed to stop the simulation.
+    max_collapsed_generations: u64,
+    /// The number of consecutive generations the population has been
+    /// collapsed so far.
+    collapsed_generations: u64,
+}
+
+impl DiversityLimit {
+    /// Create a new instance of `DiversityLimit` that stops the simulation
+    /// as soon as the given measure of diversity falls below
+    /// `min_diversity`.
+    pub fn new(measure: DiversityMeasure, min_diversity: f64) -> Self {
+        DiversityLimit {
+            measure,
+            min_diversity,
+            max_collapsed_generations: 1,
+            collapsed_generations: 0,
+        }
+    }
+
+    /// Create a new instance of `DiversityLimit` that watches the genotypic
+    /// diversity.
+    pub fn genotypic(min_diversity: f64) -> Self {
+        DiversityLimit::new(DiversityMeasure::Genotypic, min_diversity)
+    }
+
+    /// Create a new instance of `DiversityLimit` that watches the phenotypic
+    /// diversity.
+    pub fn phenotypic(min_diversity: f64) -> Self {
+        DiversityLimit::new(DiversityMeasure::Phenotypic, min_diversity)
+    }
+
+    /// Stops the simulation only after the diversity has been below the
+    /// minimum for the given number of consecutive generations, so that a
+    /// single generation with low diversity does not end the evolution.
+    pub fn for_generations(mut self, max_collapsed_generations: u64) -> Self {
+        self.max_collapsed_generations = max_collapsed_generations.max(1);
+        self
+    }
+
+    /// Returns the measure of diversity that is watched.
+    pub fn measure(&self) -> DiversityMeasure {
+        self.measure
+    }
+
+    /// Returns the diversity below which the population counts as collapsed.
+    pub fn min_diversity(&self) -> f64 {
+        self.min_diversity
+    }
+
+    /// Returns the number of consecutive generations the population must be
+    /// collapsed to stop the simulation.
+    pub fn max_collapsed_generations(&self) -> u64 {
+        self.max_collapsed_generations
+    }
+
+    /// Returns the number of consecutive generations the population has
+    /// been collapsed so far.
+    pub fn collapsed_generations(&self) -> u64 {
+        self.collapsed_generations
+    }
+}
+
+impl<G, F, E, S, C, M, R> Termination<GeneticAlgorithm<G, F, E, S, C, M, R>> for DiversityLimit
+where
+    G: Genotype + GenomeDistance,
+    F: Fitness + AsScalar + Send + Sync,
     E: FitnessFunction<G, F> + Sync,
     S: SelectionOp<G, F>,
     C: CrossoverOp<G> + Sync,
@@ -65,17 +305,35 @@ where
     R: ReinsertionOp<G, F>,
 {
     fn evaluate(&mut self, state: &State<GeneticAlgorithm<G, F, E, S, C, M, R>>) -> StopFlag {
-        let highest_fitness = &state.result.best_solution.solution.fitness;
-        if *highest_fitness >= self.fitness_target {
+        let diversity = match (self.measure, state.result.diversity) {
+            (DiversityMeasure::Genotypic, Some(tracked)) => tracked.genotypic,
+            (DiversityMeasure::Phenotypic, Some(tracked)) => tracked.phenotypic,
+            (DiversityMeasure::Genotypic, None) => {
+                state.result.evaluated_population.genotypic_diversity()
+            },
+            (DiversityMeasure::Phenotypic, None) => {
+                state.result.evaluated_population.phenotypic_diversity()
+            },
+        };
+        if diversity < self.min_diversity {
+            self.collapsed_generations += 1;
+        } else {
+            self.collapsed_generations = 0;
+        }
+        if self.collapsed_generations >= self.max_collapsed_generations {
             StopFlag::StopNow(format!(
-                "Simulation stopped after a solution with a fitness of {:?} \
-                 has been found.",
-                highest_fitness
+                "Simulation stopped after the {} diversity of {} has been below \
+                 the minimum of {} for {} generations.",
+                self.measure, diversity, self.min_diversity, self.collapsed_generations
             ))
         } else {
             StopFlag::Continue
         }
     }
+
+    fn reset(&mut self) {
+        self.collapsed_generations = 0;
+    }
 }
 
 /// The `GenerationLimit` condition stops the simulation after a maximum
@@ -119,6 +377,21 @@ where
 /// The `TimeLimit` condition stops the simulation after the specified time
 /// limit has been reached, i.e. the simulation is already running for the
 /// specified amount of time.
+///
+/// The time is measured as wall-clock time since `State::started_at`. As the
+/// condition is evaluated after each iteration, the simulation may exceed
+/// the time limit by the duration of one iteration. To limit both the time
+/// and the number of generations, combine it with a `GenerationLimit` by
+/// the `or` combinator or use a `Budget`.
+///
+/// ```
+/// use chrono::Duration;
+/// use genevo::termination::limit::TimeLimit;
+///
+/// let time_limit = TimeLimit::new(Duration::milliseconds(200));
+///
+/// assert_eq!(time_limit.max_time(), &Duration::milliseconds(200));
+/// ```
 #[allow(missing_copy_implementations)]
 #[derive(Clone, Debug, PartialEq)]
 pub struct TimeLimit {
@@ -156,3 +429,172 @@ where
         }
     }
 }
+
+/// The component of a `Budget` that has been used up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BudgetComponent {
+    /// The maximum number of fitness evaluations has been reached.
+    Evaluations,
+    /// The maximum time has been reached.
+    Time,
+    /// The maximum number of generations has been reached.
+    Generations,
+}
+
+impl Display for BudgetComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BudgetComponent::Evaluations => write!(f, "evaluations"),
+            BudgetComponent::Time => write!(f, "time"),
+            BudgetComponent::Generations => write!(f, "generations"),
+        }
+    }
+}
+
+/// The `Budget` condition stops the simulation as soon as one of its
+/// components is used up. A `Budget` can limit the number of fitness
+/// evaluations, the time the simulation is running and the number of
+/// generations. Components that are not specified are unlimited.
+///
+/// The number of fitness evaluations is the accumulated number of fitness
+/// values that the `ga::GeneticAlgorithm` reports to have calculated in all
+/// generations processed so far, see `ga::State::fitness_evaluations`.
+///
+/// ```
+/// use chrono::Duration;
+/// use genevo::termination::limit::Budget;
+///
+/// let budget = Budget::new()
+///     .evaluations(1_000_000)
+///     .time(Duration::minutes(10));
+///
+/// assert_eq!(budget.max_evaluations(), Some(1_000_000));
+/// assert_eq!(budget.max_generations(), None);
+/// ```
+///
+/// The component that stopped the simulation is named in the `StopReason`
+/// and can be queried by the `tripped` function.
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Budget {
+    /// Maximum number of fitness evaluations.
+    max_evaluations: Option<u64>,
+    /// Maximum time the simulation should run.
+    max_time: Option<Duration>,
+    /// Maximum number of generations to process.
+    max_generations: Option<u64>,
+    /// Number of fitness evaluations counted so far.
+    evaluations: u64,
+    /// The component that has been used up.
+    tripped: Option<BudgetComponent>,
+}
+
+impl Budget {
+    /// Create a new instance of `Budget` that is not limited in any way.
+    pub fn new() -> Self {
+        Budget::default()
+    }
+
+    /// Limits the number of fitness evaluations to the given number.
+    pub fn evaluations(mut self, max_evaluations: u64) -> Self {
+        self.max_evaluations = Some(max_evaluations);
+        self
+    }
+
+    /// Limits the time the simulation is running to the given duration.
+    pub fn time(mut self, max_time: Duration) -> Self {
+        self.max_time = Some(max_time);
+        self
+    }
+
+    /// Limits the number of generations to the given number.
+    pub fn generations(mut self, max_generations: u64) -> Self {
+        self.max_generations = Some(max_generations);
+        self
+    }
+
+    /// Returns the maximum number of fitness evaluations if limited.
+    pub fn max_evaluations(&self) -> Option<u64> {
+        self.max_evaluations
+    }
+
+    /// Returns the maximum time the simulation should run if limited.
+    pub fn max_time(&self) -> Option<&Duration> {
+        self.max_time.as_ref()
+    }
+
+    /// Returns the maximum number of generations if limited.
+    pub fn max_generations(&self) -> Option<u64> {
+        self.max_generations
+    }
+
+    /// Returns the number of fitness evaluations counted so far.
+    pub fn used_evaluations(&self) -> u64 {
+        self.evaluations
+    }
+
+    /// Returns the component that has been used up, or `None` if the budget
+    /// has not been used up yet.
+    pub fn tripped(&self) -> Option<BudgetComponent> {
+        self.tripped
+    }
+
+    fn stop(&mut self, component: BudgetComponent, details: String) -> StopFlag {
+        self.tripped = Some(component);
+        StopFlag::StopNow(format!(
+            "Simulation stopped as the {} budget is used up: {}",
+            component, details
+        ))
+    }
+}
+
+impl<G, F, E, S, C, M, R> Termination<GeneticAlgorithm<G, F, E, S, C, M, R>> for Budget
+where
+    G: Genotype,
+    F: Fitness + Send + Sync,
+    E: FitnessFunction<G, F> + Sync,
+    S: SelectionOp<G, F>,
+    C: CrossoverOp<G> + Sync,
+    M: MutationOp<G> + Sync,
+    R: ReinsertionOp<G, F>,
+{
+    fn evaluate(&mut self, state: &State<GeneticAlgorithm<G, F, E, S, C, M, R>>) -> StopFlag {
+        self.evaluations += state.result.fitness_evaluations as u64;
+        if let Some(max_evaluations) = self.max_evaluations {
+            if self.evaluations >= max_evaluations {
+                let details = format!(
+                    "{} fitness evaluations have been processed which reaches the \
+                     limit of {}.",
+                    self.evaluations, max_evaluations
+                );
+                return self.stop(BudgetComponent::Evaluations, details);
+            }
+        }
+        if let Some(max_time) = self.max_time {
+            let duration = Local::now().signed_duration_since(state.started_at);
+            if duration >= max_time {
+                let details = format!(
+                    "running for {} which exceeds the maximal runtime of {}.",
+                    duration, max_time
+                );
+                return self.stop(BudgetComponent::Time, details);
+            }
+        }
+        if let Some(max_generations) = self.max_generations {
+            if state.iteration >= max_generations {
+                let details = format!(
+                    "{} generations have been processed which reaches the limit \
+                     of {}.",
+                    state.iteration, max_generations
+                );
+                return self.stop(BudgetComponent::Generations, details);
+            }
+        }
+        StopFlag::Continue
+    }
+
+    fn reset(&mut self) {
+        self.evaluations = 0;
+        self.tripped = None;
+    }
+}
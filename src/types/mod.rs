@@ -16,10 +16,19 @@
 //! * `u32`
 //! * `u64`
 //! * `usize`
+//!
+//! Floats do not implement `Eq` and `Ord` because of NaN, so they can not
+//! be used as fitness values directly. Instead this module provides the
+//! wrapper types `FitnessF32` and `FitnessF64`, which order floats totally.
 
 pub mod fmt;
 
 use crate::genetic::{AsScalar, Fitness};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+};
 
 macro_rules! implement_fitness_for_signed_integer {
     ( $($t:ty),* ) => {
@@ -76,5 +85,114 @@ macro_rules! implement_fitness_for_unsigned_integer {
 
 implement_fitness_for_unsigned_integer!(u8, u16, u32, u64, usize);
 
+macro_rules! implement_fitness_for_float {
+    ( $($name:ident: $t:ty),* ) => {
+        $(
+            #[doc = concat!("A `genetic::Fitness` value of type `", stringify!($t), "`.")]
+            ///
+            /// The values are ordered like floats, except that NaN is equal to
+            /// itself and less than any other value, even negative infinity.
+            /// This way a fitness function that fails to calculate a value
+            /// ranks the genome last. Negative and positive zero are equal.
+            #[derive(Clone, Copy, Debug, Default)]
+            pub struct $name($t);
+
+            impl $name {
+                #[doc = concat!("Constructs a new `", stringify!($name), "` of the given value.")]
+                pub fn new(value: $t) -> Self {
+                    $name(value)
+                }
+
+                /// Returns the wrapped value.
+                pub fn value(&self) -> $t {
+                    self.0
+                }
+
+                /// Returns whether the wrapped value is NaN.
+                pub fn is_nan(&self) -> bool {
+                    self.0.is_nan()
+                }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for $name {}
+
+            impl PartialOrd for $name {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for $name {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    match (self.0.is_nan(), other.0.is_nan()) {
+                        (true, true) => Ordering::Equal,
+                        (true, false) => Ordering::Less,
+                        (false, true) => Ordering::Greater,
+                        (false, false) => self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal),
+                    }
+                }
+            }
+
+            /// Hashes all NaNs alike and both zeros alike, consistent with
+            /// the equality.
+            impl Hash for $name {
+                fn hash<H: Hasher>(&self, state: &mut H) {
+                    let value = if self.0.is_nan() {
+                        <$t>::NAN
+                    } else if self.0 == 0. {
+                        0.
+                    } else {
+                        self.0
+                    };
+                    value.to_bits().hash(state);
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut Formatter) -> FmtResult {
+                    Display::fmt(&self.0, f)
+                }
+            }
+
+            impl From<$t> for $name {
+                fn from(value: $t) -> Self {
+                    $name(value)
+                }
+            }
+
+            impl From<$name> for $t {
+                fn from(fitness: $name) -> Self {
+                    fitness.0
+                }
+            }
+
+            impl Fitness for $name {
+                fn zero() -> $name {
+                    $name(0.)
+                }
+
+                fn abs_diff(&self, other: &$name) -> $name {
+                    $name((self.0 - other.0).abs())
+                }
+            }
+
+            impl AsScalar for $name {
+                #[inline]
+                fn as_scalar(&self) -> f64 {
+                    f64::from(self.0)
+                }
+            }
+        )*
+    }
+}
+
+implement_fitness_for_float!(FitnessF32: f32, FitnessF64: f64);
+
 #[cfg(test)]
 mod tests;
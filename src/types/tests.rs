@@ -1,6 +1,5 @@
 use super::*;
 use galvanic_assert::matchers::*;
-use std::{i16, i32, i64, i8, isize, u16, u32, u64, u8, usize};
 
 #[test]
 fn abs_diff_of_signed_1_and_0() {
@@ -326,3 +325,76 @@ fn abs_diff_of_unsigned_1_and_max() {
         is(equal_to(usize::MAX - 1))
     );
 }
+
+mod float_fitness {
+
+    use super::*;
+
+    #[test]
+    fn float_fitness_values_are_ordered_like_floats() {
+        expect_that!(&FitnessF64::new(1.5), greater_than(FitnessF64::new(-2.)));
+        expect_that!(
+            &FitnessF64::new(f64::INFINITY),
+            greater_than(FitnessF64::new(f64::MAX))
+        );
+        expect_that!(&FitnessF32::new(0.25), less_than(FitnessF32::new(0.5)));
+        expect_that!(&FitnessF64::new(-0.), eq(FitnessF64::new(0.)));
+    }
+
+    #[test]
+    fn nan_is_equal_to_itself_and_less_than_any_other_value() {
+        let nan = FitnessF64::new(f64::NAN);
+
+        expect_that!(&nan, eq(FitnessF64::new(-f64::NAN)));
+        expect_that!(&nan, less_than(FitnessF64::new(f64::NEG_INFINITY)));
+        expect_that!(&FitnessF32::new(f32::NAN).is_nan(), eq(true));
+        expect_that!(
+            &[FitnessF64::new(1.), nan, FitnessF64::new(-1.)]
+                .iter()
+                .max()
+                .copied(),
+            eq(Some(FitnessF64::new(1.)))
+        );
+    }
+
+    #[test]
+    fn equal_float_fitness_values_have_equal_hashes() {
+        use std::collections::HashSet;
+
+        let values: HashSet<FitnessF64> = [
+            FitnessF64::new(0.),
+            FitnessF64::new(-0.),
+            FitnessF64::new(f64::NAN),
+            FitnessF64::new(-f64::NAN),
+        ]
+        .into_iter()
+        .collect();
+
+        expect_that!(&values.len(), eq(2));
+    }
+
+    #[test]
+    fn abs_diff_of_float_fitness_values() {
+        expect_that!(
+            &Fitness::abs_diff(&FitnessF64::new(-1.5), &FitnessF64::new(2.)),
+            eq(FitnessF64::new(3.5))
+        );
+        expect_that!(
+            &Fitness::abs_diff(&FitnessF32::new(2.), &FitnessF32::new(0.5)),
+            eq(FitnessF32::new(1.5))
+        );
+        expect_that!(&FitnessF64::zero(), eq(FitnessF64::new(0.)));
+    }
+
+    #[test]
+    fn float_fitness_values_convert_from_and_into_floats() {
+        let fitness: FitnessF64 = 2.5.into();
+        let value: f32 = FitnessF32::from(0.75).into();
+
+        expect_that!(&fitness.value(), eq(2.5));
+        expect_that!(&fitness.as_scalar(), eq(2.5));
+        expect_that!(&value, eq(0.75));
+        expect_that!(&FitnessF32::new(0.75).as_scalar(), eq(0.75));
+        expect_that!(&FitnessF64::new(-3.25).to_string(), eq("-3.25".to_string()));
+    }
+}
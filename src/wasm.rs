@@ -0,0 +1,22 @@
+//! The `wasm` module integrates the thread pool of `rayon` on wasm32 targets
+//! with the crate feature `wasm-parallel`.
+//!
+//! On wasm32 targets the threads of `rayon` are Web Workers, which are
+//! provided by [wasm-bindgen-rayon]. The `init_thread_pool` function is
+//! re-exported by this module. An application exports it to JavaScript by
+//!
+//! ```rust,ignore
+//! pub use genevo::wasm::init_thread_pool;
+//! ```
+//!
+//! and awaits it from JavaScript before the first simulation is started,
+//! e.g. by `await initThreadPool(navigator.hardwareConcurrency);`. Until the
+//! thread pool is initialized, the parallel stages of the algorithms can
+//! not run.
+//!
+//! The application must be built with the target features `atomics` and
+//! `bulk-memory` as described by wasm-bindgen-rayon.
+//!
+//! [wasm-bindgen-rayon]: https://github.com/RReverser/wasm-bindgen-rayon
+
+pub use wasm_bindgen_rayon::init_thread_pool;
@@ -4,34 +4,44 @@ extern crate galvanic_assert;
 
 use galvanic_assert::matchers::*;
 
-use genevo::{operator::prelude::*, population::ValueEncodedGenomeBuilder, prelude::*};
+use genevo::{
+    fitness::{
+        callback::CallbackFitnessFunction,
+        constraint::{Constrained, ConstraintFunction, FeasibilityFirst},
+        function::FnFitnessFunction,
+    },
+    ga::presets,
+    operator::prelude::*,
+    population::{CharValueEncodedGenomeBuilder, ValueEncodedGenomeBuilder},
+    prelude::*,
+};
 
-#[test]
-fn create_new_genetic_algorithm_application() {
-    type MyGenome = Vec<f64>;
+type MyGenome = Vec<f64>;
 
-    #[derive(Clone, Debug, PartialEq)]
-    struct MyFitnessEvaluator;
+#[derive(Clone, Debug, PartialEq)]
+struct MyFitnessEvaluator;
 
-    impl FitnessFunction<MyGenome, u32> for MyFitnessEvaluator {
-        fn fitness_of(&self, individual: &MyGenome) -> u32 {
-            (individual.iter().sum::<f64>() * 10000. + 0.5).floor() as u32
-        }
+impl FitnessFunction<MyGenome, u32> for MyFitnessEvaluator {
+    fn fitness_of(&self, individual: &MyGenome) -> u32 {
+        (individual.iter().sum::<f64>() * 10000. + 0.5).floor() as u32
+    }
 
-        fn average(&self, fitness_values: &[u32]) -> u32 {
-            (fitness_values.iter().sum::<u32>() as f64 / fitness_values.len() as f64 + 0.5).floor()
-                as u32
-        }
+    fn average(&self, fitness_values: &[u32]) -> u32 {
+        (fitness_values.iter().sum::<u32>() as f64 / fitness_values.len() as f64 + 0.5).floor()
+            as u32
+    }
 
-        fn highest_possible_fitness(&self) -> u32 {
-            10000
-        }
+    fn highest_possible_fitness(&self) -> u32 {
+        10000
+    }
 
-        fn lowest_possible_fitness(&self) -> u32 {
-            0
-        }
+    fn lowest_possible_fitness(&self) -> u32 {
+        0
     }
+}
 
+#[test]
+fn create_new_genetic_algorithm_application() {
     let initial_population: Population<Vec<f64>> = build_population()
         .with_genome_builder(ValueEncodedGenomeBuilder::new(4, -2., 2.))
         .of_size(200)
@@ -50,3 +60,301 @@ fn create_new_genetic_algorithm_application() {
     assert_that!(&algorithm.selector().num_individuals_per_parents(), eq(2));
     assert_that!(&algorithm.breeder().num_cut_points(), eq(3));
 }
+
+#[test]
+fn create_genetic_algorithm_with_a_crossover_probability() {
+    let initial_population: Population<Vec<f64>> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, -1., 1.))
+        .of_size(20)
+        .uniform_at_random();
+
+    let algorithm = genetic_algorithm()
+        .with_evaluation(MyFitnessEvaluator)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(MultiPointCrossBreeder::new(3))
+        .with_crossover_probability(0.6)
+        .with_mutation(RandomValueMutator::new(0.015, -2.0, 2.0))
+        .with_reinsertion(ElitistReinserter::new(MyFitnessEvaluator, false, 0.7))
+        .with_initial_population(initial_population)
+        .build();
+
+    assert_that!(&algorithm.breeder().crossover_probability(), eq(0.6));
+    assert_that!(&algorithm.breeder().breeder().num_cut_points(), eq(3));
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(3)).build();
+    assert_that!(&sim.run().is_ok(), eq(true));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SortedPrefix;
+
+impl FitnessFunction<Vec<usize>, usize> for SortedPrefix {
+    fn fitness_of(&self, genome: &Vec<usize>) -> usize {
+        genome
+            .iter()
+            .enumerate()
+            .take_while(|(locus, value)| locus == *value)
+            .count()
+    }
+
+    fn average(&self, fitness_values: &[usize]) -> usize {
+        fitness_values.iter().sum::<usize>() / fitness_values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        12
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn create_genetic_algorithm_from_a_preset_with_a_swapped_component() {
+    let algorithm = presets::real_valued(4, -2.0..2.0)
+        .with_population_size(20)
+        .with_selection(RouletteWheelSelector::new(0.8, 2))
+        .with_evaluation(MyFitnessEvaluator)
+        .build();
+
+    assert_that!(&algorithm.selector().selection_ratio(), eq(0.8));
+    assert_that!(&algorithm.mutator().mutation_rate(), eq(0.25));
+}
+
+#[test]
+fn permutation_preset_evolves_valid_permutations() {
+    let mut sim = simulate(
+        presets::permutation(12)
+            .with_population_size(30)
+            .with_evaluation(SortedPrefix)
+            .build(),
+    )
+    .until(GenerationLimit::new(20))
+    .build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            for genome in state.result.evaluated_population.individuals().iter() {
+                let mut values = genome.clone();
+                values.sort_unstable();
+                assert_that!(&values, eq((0..12).collect::<Vec<usize>>()));
+            }
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn preset_algorithm_with_callback_evaluator_has_a_concrete_type() {
+    let count_ones = CallbackFitnessFunction::new(
+        |genome: &Vec<bool>| genome.iter().filter(|gene| **gene).count() as i64,
+        0,
+        16,
+    );
+    let algorithm: presets::BinaryAlgorithm<i64, CallbackFitnessFunction<Vec<bool>>> =
+        presets::binary(16)
+            .with_population_size(10)
+            .with_evaluation(count_ones)
+            .build();
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(1)).build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let summary = state.result.summary();
+            assert_that!(&summary.generation, eq(1));
+            assert_that!(&summary.population_size, eq(10));
+            assert_that!(
+                &summary.best_fitness,
+                eq(*state.result.evaluated_population.highest_fitness() as f64)
+            );
+            assert_that!(
+                &(summary.lowest_fitness <= summary.average_fitness
+                    && summary.average_fitness <= summary.highest_fitness),
+                eq(true)
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn evolve_runs_a_preset_until_the_termination_and_reports_the_best_solution() {
+    let evolution = evolve(
+        SortedPrefix,
+        presets::permutation(12)
+            .with_population_size(30)
+            .with_seed([5; 32]),
+        GenerationLimit::new(15),
+    )
+    .unwrap();
+
+    assert_that!(&evolution.generations, eq(15));
+    assert_that!(&evolution.best_solution.generation, less_than_or_equal(15));
+    assert_that!(&(evolution.time_to_best <= evolution.duration), eq(true));
+    let mut genome = evolution.best_solution.solution.genome.clone();
+    genome.sort_unstable();
+    assert_that!(&genome, eq((0..12).collect::<Vec<usize>>()));
+}
+
+/// A knapsack of 6 items whose total weight must not exceed 10.
+#[derive(Clone, Debug, PartialEq)]
+struct KnapsackWeightLimit;
+
+const WEIGHTS: [i32; 6] = [5, 4, 6, 3, 2, 7];
+const VALUES: [i32; 6] = [10, 40, 30, 50, 15, 35];
+
+impl ConstraintFunction<Vec<bool>> for KnapsackWeightLimit {
+    fn violations_of(&self, genome: &Vec<bool>) -> Vec<f64> {
+        let weight: i32 = WEIGHTS
+            .iter()
+            .zip(genome)
+            .filter(|(_, packed)| **packed)
+            .map(|(weight, _)| weight)
+            .sum();
+        vec![f64::from(weight - 10)]
+    }
+}
+
+#[test]
+fn genetic_algorithm_with_feasibility_first_finds_the_best_feasible_solution() {
+    let value = FnFitnessFunction::new(|genome: &Vec<bool>| {
+        VALUES
+            .iter()
+            .zip(genome)
+            .filter(|(_, packed)| **packed)
+            .map(|(value, _)| value)
+            .sum::<i32>()
+    });
+    let evaluator = FeasibilityFirst::new(value, KnapsackWeightLimit);
+    let initial_population = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(6))
+        .of_size(30)
+        .using_seed([3; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(evaluator.clone())
+        .with_selection(TournamentSelector::new(0.8, 2, 3, 1.0, false))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.1, false, true))
+        .with_reinsertion(ElitistReinserter::new(evaluator, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(50))
+        .build_with_seed([5; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let best = state.result.best_solution.solution;
+            // items 1 and 3 weigh 7 and are worth 90, item 4 adds 2 and 15
+            expect_that!(&best.fitness, eq(Constrained::Feasible(105)));
+            expect_that!(
+                &best.genome,
+                eq(vec![false, true, false, true, true, false])
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn genetic_algorithm_minimizes_a_continuous_objective_with_float_fitness() {
+    let sphere = FnFitnessFunction::new(|genome: &Vec<f64>| {
+        FitnessF64::new(-genome.iter().map(|x| x * x).sum::<f64>())
+    })
+    .with_bounds(FitnessF64::new(-300.), FitnessF64::new(0.));
+    let initial_population = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(3, -10., 10.))
+        .of_size(40)
+        .using_seed([3; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(sphere.clone())
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.2, -10., 10.))
+        .with_reinsertion(ElitistReinserter::new(sphere, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(100))
+        .build_with_seed([5; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let best = state.result.best_solution.solution.fitness;
+            expect_that!(&best.value(), greater_than(-1.));
+            expect_that!(&best, less_than_or_equal(FitnessF64::zero()));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn genetic_algorithm_evolves_fixed_size_array_genomes() {
+    let ones = FnFitnessFunction::new(|genome: &[i32; 8]| genome.iter().sum::<i32>() as u32)
+        .with_bounds(0, 72);
+    let initial_population = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 10))
+        .of_size(40)
+        .using_seed([3; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(ones.clone())
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.1, 0, 9))
+        .with_reinsertion(ElitistReinserter::new(ones, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(100))
+        .build_with_seed([5; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let best = state.result.best_solution.solution;
+            expect_that!(&best.fitness, greater_than_or_equal(64));
+            expect_that!(
+                &best.genome.iter().all(|gene| (0..10).contains(gene)),
+                eq(true)
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn genetic_algorithm_evolves_string_genomes() {
+    let target = "héllo wörld";
+    let matches = FnFitnessFunction::new(move |genome: &String| {
+        genome
+            .chars()
+            .zip(target.chars())
+            .filter(|(c, t)| c == t)
+            .count()
+    })
+    .with_bounds(0, target.chars().count());
+    let alphabet = "dehlorwäéö ";
+    let initial_population = build_population()
+        .with_genome_builder(CharValueEncodedGenomeBuilder::new(11, alphabet))
+        .of_size(60)
+        .using_seed([3; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(matches.clone())
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomCharMutator::new(0.1, alphabet))
+        .with_reinsertion(ElitistReinserter::new(matches, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(200))
+        .build_with_seed([5; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let best = state.result.best_solution.solution;
+            expect_that!(&best.genome.chars().count(), eq(11));
+            expect_that!(&best.fitness, greater_than_or_equal(9));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "bench")]
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{bench::measure_scaling, ga::presets, prelude::*};
+
+#[derive(Clone, Debug)]
+struct CountOnes;
+
+impl FitnessFunction<Vec<bool>, usize> for CountOnes {
+    fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+        genome.iter().filter(|gene| **gene).count()
+    }
+
+    fn average(&self, values: &[usize]) -> usize {
+        values.iter().sum::<usize>() / values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        32
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn measure_scaling_reports_the_throughput_for_each_thread_count() {
+    let build_algorithm = || {
+        presets::binary(32)
+            .with_population_size(120)
+            .with_seed([3; 32])
+            .with_evaluation(CountOnes)
+            .build()
+    };
+
+    let report = measure_scaling(build_algorithm, 5, &[1, 2, 4]).unwrap();
+
+    let thread_counts: Vec<usize> = report
+        .measurements()
+        .iter()
+        .map(|throughput| throughput.num_threads)
+        .collect();
+    assert_that!(&thread_counts, eq(vec![1, 2, 4]));
+    for throughput in report.measurements() {
+        expect_that!(&throughput.generations, eq(5));
+        expect_that!(&throughput.evaluations, eq(5 * 120));
+        expect_that!(&throughput.generations_per_second, greater_than(0.));
+        expect_that!(
+            &throughput.evaluations_per_second,
+            greater_than(throughput.generations_per_second)
+        );
+    }
+    expect_that!(&report.speedup(1), eq(Some(1.)));
+    expect_that!(&report.speedup(8), eq(None));
+}
+
+#[test]
+fn scaling_report_is_displayed_as_table() {
+    let build_algorithm = || {
+        presets::binary(32)
+            .with_population_size(20)
+            .with_evaluation(CountOnes)
+            .build()
+    };
+
+    let report = measure_scaling(build_algorithm, 2, &[1, 2]).unwrap();
+
+    let table = report.to_string();
+    expect_that!(&table.lines().count(), eq(3));
+    expect_that!(&table.starts_with(" threads"), eq(true));
+}
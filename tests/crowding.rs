@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    genetic::GenomeDistance, operator::prelude::*, population::ValueEncodedGenomeBuilder,
+    prelude::*,
+};
+
+type Point = Vec<i32>;
+
+const PEAKS: [[i32; 2]; 2] = [[20, 20], [80, 80]];
+
+fn distance_to_peak(genome: &Point, peak: &[i32; 2]) -> i32 {
+    (genome[0] - peak[0]).abs() + (genome[1] - peak[1]).abs()
+}
+
+/// Two peaks of equal height, so that the population has to be spread
+/// over both of them to find all optima.
+#[derive(Clone, Debug, PartialEq)]
+struct TwoPeaks;
+
+impl FitnessFunction<Point, i32> for TwoPeaks {
+    fn fitness_of(&self, genome: &Point) -> i32 {
+        let nearest = PEAKS
+            .iter()
+            .map(|peak| distance_to_peak(genome, peak))
+            .min()
+            .unwrap();
+        200 - nearest
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        200
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn crowding_keeps_individuals_on_each_peak() {
+    let initial_population: Population<Point> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(2, 0, 101))
+        .of_size(40)
+        .using_seed([7; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(TwoPeaks)
+        .with_selection(MaximizeSelector::new(0.5, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.2, 0, 101))
+        .with_reinsertion(CrowdingReinserter::new(
+            TwoPeaks,
+            <Point as GenomeDistance>::distance,
+        ))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(200))
+        .build_with_seed([11; 32]);
+
+    let population = match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => state.result.evaluated_population,
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    };
+
+    for peak in &PEAKS {
+        let on_peak = population
+            .individuals()
+            .iter()
+            .filter(|genome| distance_to_peak(genome, peak) <= 2)
+            .count();
+        expect_that!(&on_peak, greater_than(0));
+    }
+}
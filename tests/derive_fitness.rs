@@ -0,0 +1,53 @@
+#![cfg(feature = "derive")]
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{prelude::*, statistic::FitnessStats};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Fitness)]
+struct Score(i32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Fitness)]
+struct Profit {
+    cents: u64,
+}
+
+#[test]
+fn derived_fitness_of_tuple_struct_delegates_to_the_wrapped_value() {
+    expect_that!(&Score::zero(), eq(Score(0)));
+    expect_that!(&Score(3).abs_diff(&Score(-4)), eq(Score(7)));
+    expect_that!(&Score(-4).as_scalar(), eq(-4.));
+}
+
+#[test]
+fn derived_fitness_of_named_struct_delegates_to_the_wrapped_value() {
+    expect_that!(&Profit::zero(), eq(Profit { cents: 0 }));
+    expect_that!(
+        &Profit { cents: 3 }.abs_diff(&Profit { cents: 10 }),
+        eq(Profit { cents: 7 })
+    );
+    expect_that!(&Profit { cents: 250 }.as_scalar(), eq(250.));
+}
+
+#[test]
+fn derived_arithmetic_operates_on_the_wrapped_value() {
+    expect_that!(&(Score(3) + Score(4)), eq(Score(7)));
+    expect_that!(&(Score(3) - Score(4)), eq(Score(-1)));
+    expect_that!(
+        &[Score(1), Score(2), Score(3)]
+            .iter()
+            .copied()
+            .sum::<Score>(),
+        eq(Score(6))
+    );
+}
+
+#[test]
+fn derived_fitness_can_be_used_for_statistics() {
+    let stats = FitnessStats::of(&[Score(1), Score(3)]);
+
+    expect_that!(&stats.mean(), eq(2.));
+}
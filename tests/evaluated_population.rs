@@ -0,0 +1,82 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{algorithm::EvaluatedPopulation, random::get_rng};
+use proptest::prelude::*;
+use std::{collections::HashSet, sync::Arc};
+
+fn evaluated_population(fitness_values: Vec<u32>) -> EvaluatedPopulation<Vec<u8>, u32> {
+    let individuals = (0..fitness_values.len()).map(|i| vec![i as u8]).collect();
+    let highest = fitness_values.iter().copied().max().unwrap_or(0);
+    let lowest = fitness_values.iter().copied().min().unwrap_or(0);
+    EvaluatedPopulation::new(Arc::new(individuals), fitness_values, highest, lowest, 0)
+}
+
+#[test]
+fn top_k_indices_are_ordered_from_best_to_worst() {
+    let evaluated = evaluated_population(vec![3, 9, 1, 7, 5]);
+
+    expect_that!(&evaluated.top_k_indices(3), eq(vec![1, 3, 4]));
+}
+
+#[test]
+fn top_k_indices_keep_the_order_of_individuals_with_equal_fitness() {
+    let evaluated = evaluated_population(vec![5, 8, 5, 8, 5]);
+
+    expect_that!(&evaluated.top_k_indices(4), eq(vec![1, 3, 0, 2]));
+}
+
+#[test]
+fn top_k_indices_of_more_than_the_population_returns_all_indices() {
+    let evaluated = evaluated_population(vec![2, 4]);
+
+    expect_that!(&evaluated.top_k_indices(5), eq(vec![1, 0]));
+    expect_that!(&evaluated.top_k_indices(0), eq(vec![]));
+}
+
+#[test]
+fn sample_indices_of_more_than_the_population_returns_all_indices() {
+    let evaluated = evaluated_population(vec![2, 4, 6]);
+    let mut rng = get_rng([3; 32]);
+
+    let mut sample = evaluated.sample_indices(&mut rng, 10);
+    sample.sort_unstable();
+
+    expect_that!(&sample, eq(vec![0, 1, 2]));
+}
+
+proptest! {
+
+    #[test]
+    fn top_k_indices_are_the_prefix_of_a_stable_sort(
+        fitness_values in prop::collection::vec(0u32..20, 0..200),
+        k in 0usize..220,
+    ) {
+        let evaluated = evaluated_population(fitness_values.clone());
+        let mut sorted: Vec<usize> = (0..fitness_values.len()).collect();
+        sorted.sort_by(|x, y| fitness_values[*y].cmp(&fitness_values[*x]));
+        sorted.truncate(k);
+
+        prop_assert_eq!(evaluated.top_k_indices(k), sorted);
+    }
+
+    #[test]
+    fn sample_indices_are_distinct_and_in_range(
+        size in 0usize..300,
+        k in 0usize..50,
+        seed in any::<[u8; 32]>(),
+    ) {
+        let evaluated = evaluated_population(vec![1; size]);
+        let mut rng = get_rng(seed);
+
+        let sample = evaluated.sample_indices(&mut rng, k);
+        let distinct: HashSet<usize> = sample.iter().copied().collect();
+
+        prop_assert_eq!(sample.len(), k.min(size));
+        prop_assert_eq!(distinct.len(), sample.len());
+        prop_assert!(sample.iter().all(|index| *index < size));
+    }
+}
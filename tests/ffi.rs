@@ -0,0 +1,115 @@
+#![cfg(feature = "ffi")]
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{ffi::*, ga::GenerationSummary};
+use std::{
+    ffi::c_void,
+    ptr, slice,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+extern "C" fn sum_of_genes(genes: *const f64, length: usize, user_data: *mut c_void) -> i64 {
+    let calls = unsafe { &*(user_data as *const AtomicUsize) };
+    calls.fetch_add(1, Ordering::Relaxed);
+    let genes = unsafe { slice::from_raw_parts(genes, length) };
+    genes.iter().sum::<f64>() as i64
+}
+
+fn config(encoding: u32) -> GenevoConfig {
+    GenevoConfig {
+        encoding,
+        genome_length: 10,
+        population_size: 20,
+        min_value: 0.,
+        max_value: 10.,
+        lowest_fitness: 0,
+        highest_fitness: 100,
+        seed: 42,
+    }
+}
+
+#[test]
+fn host_drives_a_binary_encoded_genetic_algorithm() {
+    let calls = AtomicUsize::new(0);
+    let user_data = &calls as *const AtomicUsize as *mut c_void;
+
+    unsafe {
+        let ga = genevo_ga_create(&config(GENEVO_ENCODING_BINARY), sum_of_genes, user_data);
+        assert_that!(&ga.is_null(), eq(false));
+
+        let mut fitness = 0;
+        assert_that!(&genevo_ga_best_fitness(ga, &mut fitness), eq(-1));
+        for _ in 0..5 {
+            assert_that!(&genevo_ga_step(ga), eq(0));
+        }
+
+        let mut summary = GenerationSummary::default();
+        assert_that!(&genevo_ga_summary(ga, &mut summary), eq(0));
+        expect_that!(&summary.generation, eq(5));
+        expect_that!(&summary.population_size, eq(20));
+
+        let mut genome = [0.; 10];
+        assert_that!(&genevo_ga_best_genome(ga, genome.as_mut_ptr(), 10), eq(10));
+        assert_that!(&genevo_ga_best_fitness(ga, &mut fitness), eq(0));
+        expect_that!(&(genome.iter().sum::<f64>() as i64), eq(fitness));
+        expect_that!(
+            &genome.iter().all(|gene| *gene == 0. || *gene == 1.),
+            eq(true)
+        );
+
+        genevo_ga_destroy(ga);
+    }
+    expect_that!(&(calls.load(Ordering::Relaxed) >= 5 * 20), eq(true));
+}
+
+#[test]
+fn best_genome_of_a_permutation_is_a_permutation() {
+    let calls = AtomicUsize::new(0);
+    let user_data = &calls as *const AtomicUsize as *mut c_void;
+
+    unsafe {
+        let ga = genevo_ga_create(
+            &config(GENEVO_ENCODING_PERMUTATION),
+            sum_of_genes,
+            user_data,
+        );
+        assert_that!(&genevo_ga_step(ga), eq(0));
+
+        let mut genome = [0.; 10];
+        genevo_ga_best_genome(ga, genome.as_mut_ptr(), genome.len());
+        genome.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expect_that!(&genome, eq([0., 1., 2., 3., 4., 5., 6., 7., 8., 9.]));
+
+        genevo_ga_destroy(ga);
+    }
+}
+
+#[test]
+fn invalid_configurations_are_rejected() {
+    let calls = AtomicUsize::new(0);
+    let user_data = &calls as *const AtomicUsize as *mut c_void;
+    let mut unknown_encoding = config(GENEVO_ENCODING_REAL_VALUED);
+    unknown_encoding.encoding = 7;
+    let mut empty_bounds = config(GENEVO_ENCODING_REAL_VALUED);
+    empty_bounds.max_value = empty_bounds.min_value;
+
+    unsafe {
+        expect_that!(
+            &genevo_ga_create(&unknown_encoding, sum_of_genes, user_data).is_null(),
+            eq(true)
+        );
+        expect_that!(
+            &genevo_ga_create(&empty_bounds, sum_of_genes, user_data).is_null(),
+            eq(true)
+        );
+        expect_that!(
+            &genevo_ga_create(ptr::null(), sum_of_genes, user_data).is_null(),
+            eq(true)
+        );
+        expect_that!(&genevo_ga_step(ptr::null_mut()), eq(-1));
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "fuzz")]
+
+use genevo::{
+    fuzz::{fuzz_crossover, fuzz_cut_points, fuzz_mutation, FuzzInput, FuzzLimits},
+    mutation::{
+        order::{InsertOrderMutator, SwapOrderMutator},
+        value::{GaussianMutator, RandomValueMutator},
+    },
+    recombination::{
+        discrete::{MultiPointCrossBreeder, SinglePointCrossBreeder, UniformCrossBreeder},
+        order::{OrderOneCrossover, PartiallyMappedCrossover},
+    },
+};
+use proptest::prelude::*;
+
+#[test]
+fn fuzz_input_decodes_missing_bytes_as_zeros() {
+    let mut input = FuzzInput::new(&[1, 2]);
+
+    assert_eq!(input.byte(), 1);
+    assert!(!input.is_exhausted());
+    assert_eq!(input.byte(), 2);
+    assert!(input.is_exhausted());
+    assert_eq!(input.u64(), 0);
+    assert_eq!(input.usize_in(3..=7), 3);
+}
+
+#[test]
+fn fuzz_input_decodes_permutations() {
+    let mut input = FuzzInput::new(&[7, 3, 9, 200, 41, 5, 17, 8, 99]);
+
+    let mut permutation = input.permutation(10);
+    permutation.sort_unstable();
+
+    assert_eq!(permutation, (0..10).collect::<Vec<_>>());
+}
+
+proptest! {
+
+    #[test]
+    fn discrete_crossover_operators_do_not_panic(data in prop::collection::vec(any::<u8>(), 0..600)) {
+        let limits = FuzzLimits::default();
+        // the multi point crossover requires at least 2 genes per cut point
+        let multi_point_limits = FuzzLimits {
+            genome_length: 6..=64,
+            ..FuzzLimits::default()
+        };
+        fuzz_crossover::<Vec<i32>, _>(&MultiPointCrossBreeder::new(3), &multi_point_limits, &data);
+        fuzz_crossover::<Vec<bool>, _>(&SinglePointCrossBreeder::new(), &limits, &data);
+        fuzz_crossover::<Vec<f64>, _>(&UniformCrossBreeder::new(), &limits, &data);
+    }
+
+    #[test]
+    fn order_crossover_operators_do_not_panic(data in prop::collection::vec(any::<u8>(), 0..600)) {
+        let limits = FuzzLimits {
+            num_parents: 2..=2,
+            ..FuzzLimits::default()
+        };
+        fuzz_crossover::<Vec<usize>, _>(&OrderOneCrossover::new(), &limits, &data);
+        fuzz_crossover::<Vec<usize>, _>(&PartiallyMappedCrossover::new(), &limits, &data);
+    }
+
+    #[test]
+    fn mutation_operators_do_not_panic(data in prop::collection::vec(any::<u8>(), 0..600)) {
+        let limits = FuzzLimits::default();
+        fuzz_mutation::<Vec<i32>, _>(&RandomValueMutator::new(0.3, -100, 100), &limits, &data);
+        fuzz_mutation::<Vec<f64>, _>(&GaussianMutator::new(0.3, 1., -1e6, 1e6), &limits, &data);
+        fuzz_mutation::<Vec<usize>, _>(&InsertOrderMutator::new(0.3), &limits, &data);
+        fuzz_mutation::<Vec<usize>, _>(&SwapOrderMutator::new(0.3), &limits, &data);
+    }
+
+    #[test]
+    fn cut_points_are_valid(data in prop::collection::vec(any::<u8>(), 0..100)) {
+        fuzz_cut_points(&data);
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::algorithm::{Evaluated, EvaluatedPopulation, HallOfFame};
+use std::sync::Arc;
+
+fn evaluated(genome: u8, fitness: u32) -> Evaluated<Vec<u8>, u32> {
+    Evaluated {
+        genome: vec![genome],
+        fitness,
+    }
+}
+
+fn fitness_of_members(hall_of_fame: &HallOfFame<Vec<u8>, u32>) -> Vec<u32> {
+    hall_of_fame
+        .members()
+        .iter()
+        .map(|member| member.fitness)
+        .collect()
+}
+
+#[test]
+fn members_are_ordered_from_the_highest_to_the_lowest_fitness() {
+    let mut hall_of_fame = HallOfFame::new(5);
+
+    for (genome, fitness) in [(1, 3), (2, 9), (3, 5), (4, 7)] {
+        hall_of_fame.offer(evaluated(genome, fitness));
+    }
+
+    expect_that!(&fitness_of_members(&hall_of_fame), eq(vec![9, 7, 5, 3]));
+    expect_that!(&hall_of_fame.best().cloned(), eq(Some(evaluated(2, 9))));
+}
+
+#[test]
+fn better_solution_pushes_out_the_worst_member_of_a_full_hall_of_fame() {
+    let mut hall_of_fame = HallOfFame::new(3);
+    for (genome, fitness) in [(1, 3), (2, 9), (3, 5)] {
+        hall_of_fame.offer(evaluated(genome, fitness));
+    }
+
+    expect_that!(&hall_of_fame.offer(evaluated(4, 4)), eq(true));
+    expect_that!(&hall_of_fame.offer(evaluated(5, 4)), eq(false));
+
+    expect_that!(&fitness_of_members(&hall_of_fame), eq(vec![9, 5, 4]));
+}
+
+#[test]
+fn genome_enters_the_hall_of_fame_only_once() {
+    let mut hall_of_fame = HallOfFame::new(3);
+
+    expect_that!(&hall_of_fame.offer(evaluated(1, 8)), eq(true));
+    expect_that!(&hall_of_fame.offer(evaluated(1, 8)), eq(false));
+
+    expect_that!(&hall_of_fame.len(), eq(1));
+}
+
+#[test]
+fn update_offers_all_individuals_of_an_evaluated_population() {
+    let mut hall_of_fame = HallOfFame::new(2);
+    let individuals = vec![vec![1], vec![2], vec![3], vec![2]];
+    let evaluated_population =
+        EvaluatedPopulation::new(Arc::new(individuals), vec![4, 6, 5, 6], 6, 4, 5);
+
+    hall_of_fame.update(&evaluated_population);
+
+    expect_that!(
+        &hall_of_fame.into_members(),
+        eq(vec![evaluated(2, 6), evaluated(3, 5)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "the capacity must be at least 1")]
+fn hall_of_fame_without_capacity_can_not_be_created() {
+    let _: HallOfFame<Vec<u8>, u32> = HallOfFame::new(0);
+}
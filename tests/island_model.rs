@@ -0,0 +1,357 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    algorithm::Checkpointable,
+    ga::{
+        island::{
+            island_model, EmigrantSelector, ImmigrantReplacer, Island, IslandModel, MigrationStats,
+            RandomEmigrants, ReplaceMostSimilar, ReplaceRandom, TournamentEmigrants,
+        },
+        GeneticAlgorithmError,
+    },
+    population::ValueEncodedGenomeBuilder,
+    prelude::*,
+    random::{get_rng, Prng},
+};
+use std::{collections::HashSet, ops::RangeInclusive};
+
+type MyGenome = Vec<i32>;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<MyGenome, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 100
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+fn population(min_value: i32, max_value: i32) -> Population<MyGenome> {
+    build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, min_value, max_value))
+        .of_size(12)
+        .using_seed([42; 32])
+}
+
+fn explorative_island(
+    min_value: i32,
+    max_value: i32,
+) -> impl Island<MyGenome, i32> + Send + 'static {
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(RouletteWheelSelector::new(0.8, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.5, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.8))
+        .with_initial_population(population(min_value, max_value))
+        .build()
+}
+
+fn exploitative_island(
+    min_value: i32,
+    max_value: i32,
+) -> impl Island<MyGenome, i32> + Send + 'static {
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(BreederValueMutator::new(0.1, 5, 2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.8))
+        .with_initial_population(population(min_value, max_value))
+        .build()
+}
+
+#[test]
+fn island_model_with_heterogeneous_islands_runs_until_the_fitness_limit_is_reached() {
+    let model = island_model()
+        .with_island(explorative_island(0, 100))
+        .with_island(exploitative_island(0, 100))
+        .with_migration_interval(3)
+        .with_num_migrants(2)
+        .build();
+    let mut simulator = simulate(model)
+        .until(or(FitnessLimit::new(650), GenerationLimit::new(500)))
+        .build_with_seed([3; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            expect_that!(&state.result.island_states.len(), eq(2));
+            expect_that!(
+                &state.result.best_solution.solution.fitness,
+                greater_than_or_equal(650)
+            );
+            let best_island = &state.result.island_states[state.result.best_island];
+            expect_that!(
+                &best_island.best_solution.solution.fitness,
+                eq(state.result.best_solution.solution.fitness)
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn best_individuals_migrate_to_the_next_island_in_the_ring() {
+    let mut model = island_model()
+        .with_island(exploitative_island(90, 100))
+        .with_island(exploitative_island(0, 5))
+        .with_migration_interval(1)
+        .with_num_migrants(2)
+        .build();
+
+    let state = model.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&state.migrated, eq(true));
+    let best_of_first_island = &state.island_states[0].best_solution.solution.genome;
+    let second_island = model.island(1).unwrap();
+    expect_that!(
+        &second_island.population().contains(best_of_first_island),
+        eq(true)
+    );
+    expect_that!(
+        &second_island.population().len(),
+        eq(state.island_states[1]
+            .evaluated_population
+            .individuals()
+            .len())
+    );
+}
+
+#[test]
+fn individuals_migrate_only_every_migration_interval() {
+    let mut model = island_model()
+        .with_island(exploitative_island(0, 100))
+        .with_island(explorative_island(0, 100))
+        .with_migration_interval(2)
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    let migrated: Vec<bool> = (1..=4)
+        .map(|generation| model.next(generation, &mut rng).unwrap().migrated)
+        .collect();
+
+    expect_that!(&migrated, eq(vec![false, true, false, true]));
+}
+
+#[test]
+fn island_model_without_islands_returns_an_error() {
+    let mut model: IslandModel<MyGenome, i32> = island_model().build();
+
+    let result = model.next(1, &mut get_rng([7; 32]));
+
+    expect_that!(
+        &result,
+        eq(Err(GeneticAlgorithmError::EmptyPopulation(
+            "Island model in generation 1 has no islands.".to_string()
+        )))
+    );
+}
+
+fn manhattan_distance(a: &MyGenome, b: &MyGenome) -> f64 {
+    a.iter().zip(b).map(|(x, y)| f64::from((x - y).abs())).sum()
+}
+
+#[test]
+fn random_emigrants_are_distinct_members_of_the_population() {
+    let mut model = island_model()
+        .with_island(exploitative_island(0, 100))
+        .with_island(exploitative_island(0, 100))
+        .with_migration_interval(1)
+        .build();
+    let state = model.next(1, &mut get_rng([7; 32])).unwrap();
+    let evaluated = &state.island_states[0].evaluated_population;
+
+    let emigrants = RandomEmigrants.select_emigrants(evaluated, 5, &mut get_rng([9; 32]));
+
+    expect_that!(&emigrants.len(), eq(5));
+    let distinct: HashSet<&MyGenome> = emigrants.iter().collect();
+    expect_that!(&distinct.len(), eq(5));
+    for emigrant in &emigrants {
+        expect_that!(&evaluated.individuals().contains(emigrant), eq(true));
+    }
+}
+
+#[test]
+fn tournament_emigrants_are_fitter_than_the_average() {
+    let mut model = island_model()
+        .with_island(exploitative_island(0, 100))
+        .with_island(exploitative_island(0, 100))
+        .with_migration_interval(1)
+        .build();
+    let state = model.next(1, &mut get_rng([7; 32])).unwrap();
+    let evaluated = &state.island_states[0].evaluated_population;
+
+    let emigrants =
+        TournamentEmigrants::new(4).select_emigrants(evaluated, 20, &mut get_rng([9; 32]));
+
+    expect_that!(&emigrants.len(), eq(20));
+    let average: i32 = emigrants
+        .iter()
+        .map(|genome| SumOfValues.fitness_of(genome))
+        .sum::<i32>()
+        / 20;
+    expect_that!(&average, greater_than(*evaluated.average_fitness()));
+}
+
+#[test]
+fn replace_random_chooses_distinct_positions() {
+    let population: Vec<MyGenome> = (0..10).map(|value| vec![value; 8]).collect();
+    let immigrants = vec![vec![100; 8]; 4];
+
+    let positions = ImmigrantReplacer::<MyGenome, i32>::select_replaced(
+        &ReplaceRandom,
+        &population,
+        &immigrants,
+        &|genome: &MyGenome| SumOfValues.fitness_of(genome),
+        &mut get_rng([9; 32]),
+    );
+
+    expect_that!(&positions.len(), eq(4));
+    let distinct: HashSet<usize> = positions.iter().cloned().collect();
+    expect_that!(&distinct.len(), eq(4));
+    expect_that!(&positions.iter().all(|&position| position < 10), eq(true));
+}
+
+#[test]
+fn replace_most_similar_chooses_the_nearest_individual_for_each_immigrant() {
+    let population: Vec<MyGenome> = vec![vec![0; 8], vec![50; 8], vec![100; 8]];
+    let immigrants = vec![vec![95; 8], vec![96; 8], vec![5; 8]];
+
+    let positions = ImmigrantReplacer::<MyGenome, i32>::select_replaced(
+        &ReplaceMostSimilar::new(manhattan_distance),
+        &population,
+        &immigrants,
+        &|genome: &MyGenome| SumOfValues.fitness_of(genome),
+        &mut get_rng([9; 32]),
+    );
+
+    expect_that!(&positions, eq(vec![2, 1, 0]));
+}
+
+#[test]
+fn migration_statistics_are_reported_per_island() {
+    let mut model = island_model()
+        .with_island(exploitative_island(90, 100))
+        .with_island(exploitative_island(0, 5))
+        .with_migration_interval(1)
+        .with_num_migrants(2)
+        .with_migration_history_capacity(3)
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    let state = model.next(1, &mut rng).unwrap();
+
+    expect_that!(
+        &state.migration_stats[1],
+        eq(MigrationStats {
+            num_emigrants: 2,
+            num_immigrants: 2,
+            num_improving_immigrants: 2,
+        })
+    );
+    expect_that!(&state.migration_stats[1].improvement_ratio(), eq(1.));
+    expect_that!(&state.migration_stats[0].num_immigrants, eq(2));
+    expect_that!(&model.migration_history().len(), eq(2));
+    let event = &model.migration_history()[0];
+    expect_that!(&event.generation, eq(1));
+    expect_that!(&event.from_island, eq(0));
+    expect_that!(&event.to_island, eq(1));
+    expect_that!(&event.num_improving, eq(2));
+
+    model.next(2, &mut rng).unwrap();
+
+    expect_that!(&model.migration_history().len(), eq(3));
+    expect_that!(&model.migration_history()[0].from_island, eq(1));
+    expect_that!(&model.migration_stats()[1].num_immigrants, eq(4));
+
+    model.reset().unwrap();
+
+    expect_that!(&model.migration_history().is_empty(), eq(true));
+    expect_that!(&model.migration_stats()[1], eq(MigrationStats::default()));
+}
+
+#[test]
+fn island_model_with_custom_migration_policies_runs_until_the_fitness_limit_is_reached() {
+    let model = island_model()
+        .with_island(explorative_island(0, 100))
+        .with_island(exploitative_island(0, 100))
+        .with_migration_interval(3)
+        .with_num_migrants(2)
+        .with_emigrant_selector(TournamentEmigrants::new(3))
+        .with_immigrant_replacer(ReplaceMostSimilar::new(manhattan_distance))
+        .build();
+    let mut simulator = simulate(model)
+        .until(or(FitnessLimit::new(650), GenerationLimit::new(500)))
+        .build_with_seed([3; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            expect_that!(
+                &state.result.best_solution.solution.fitness,
+                greater_than_or_equal(650)
+            );
+            expect_that!(&state.result.migration_stats.len(), eq(2));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+fn two_island_model() -> IslandModel<MyGenome, i32> {
+    island_model()
+        .with_island(explorative_island(0, 100))
+        .with_island(exploitative_island(0, 100))
+        .with_migration_interval(2)
+        .with_num_migrants(2)
+        .build()
+}
+
+fn step_island_populations(
+    model: &mut IslandModel<MyGenome, i32>,
+    generations: RangeInclusive<u64>,
+    rng: &mut Prng,
+) -> Vec<Vec<MyGenome>> {
+    generations
+        .flat_map(|generation| {
+            let state = model.next(generation, rng).unwrap();
+            state
+                .island_states
+                .into_iter()
+                .map(|island| island.evaluated_population.individuals().to_vec())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn island_model_restored_from_a_snapshot_continues_the_evolution() {
+    let mut model = two_island_model();
+    let mut rng = get_rng([7; 32]);
+    step_island_populations(&mut model, 1..=3, &mut rng);
+    let snapshot = model.snapshot();
+    let mut resumed_rng = rng.clone();
+    let expected = step_island_populations(&mut model, 4..=6, &mut rng);
+
+    let mut resumed = two_island_model();
+    resumed.restore(snapshot);
+    let populations = step_island_populations(&mut resumed, 4..=6, &mut resumed_rng);
+
+    expect_that!(&populations, eq(expected));
+    expect_that!(&resumed.migration_stats(), eq(model.migration_stats()));
+    expect_that!(&resumed.migration_history(), eq(model.migration_history()));
+}
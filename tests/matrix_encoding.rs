@@ -0,0 +1,43 @@
+#![cfg(feature = "matrix")]
+
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+use genevo::encoding::matrix::Matrix;
+
+/// A timetable of 2 rooms (rows) and 3 time slots (columns) with the
+/// assigned courses as values.
+fn timetable() -> Matrix<char> {
+    Matrix::from_values(2, 3, vec!['a', 'b', 'a', 'c', 'b', 'd'])
+}
+
+#[test]
+fn duplicates_in_rows_counts_repeated_values_within_each_row() {
+    assert_that!(&timetable().duplicates_in_rows(), eq(1));
+}
+
+#[test]
+fn duplicates_in_columns_counts_repeated_values_within_each_column() {
+    let mut timetable = timetable();
+    expect_that!(&timetable.duplicates_in_columns(), eq(1));
+
+    timetable.set(1, 0, 'a');
+    expect_that!(&timetable.duplicates_in_columns(), eq(2));
+}
+
+#[test]
+fn count_violations_counts_the_values_that_violate_a_constraint() {
+    // course 'd' must not be held in the last time slot
+    let violations = timetable().count_violations(|_, col, course| *course == 'd' && col == 2);
+
+    assert_that!(&violations, eq(1));
+}
+
+#[test]
+fn column_iterates_the_values_of_one_column() {
+    let column: Vec<char> = timetable().column(1).cloned().collect();
+
+    assert_that!(&column, eq(vec!['b', 'b']));
+}
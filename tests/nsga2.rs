@@ -0,0 +1,94 @@
+//! End-to-end test of the NSGA-II operators on Schaffer's two-objective
+//! problem.
+
+#![cfg(feature = "multi-objective")]
+
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+use genevo::{pareto::non_dominated_sort, prelude::*};
+
+/// Schaffer's problem minimizes `x^2` and `(x - 2)^2`. The Pareto optimal
+/// solutions are all `x` between 0 and 2.
+#[derive(Clone, Debug)]
+struct Schaffer;
+
+impl Schaffer {
+    fn objective(value: f64) -> i64 {
+        -(value * 1000.).round() as i64
+    }
+}
+
+impl FitnessFunction<Vec<f64>, Pareto<i64>> for Schaffer {
+    fn fitness_of(&self, genome: &Vec<f64>) -> Pareto<i64> {
+        let x = genome[0];
+        Pareto::new(vec![
+            Schaffer::objective(x * x),
+            Schaffer::objective((x - 2.) * (x - 2.)),
+        ])
+    }
+
+    fn average(&self, values: &[Pareto<i64>]) -> Pareto<i64> {
+        let num_values = values.len() as i64;
+        Pareto::new(
+            (0..2)
+                .map(|objective| {
+                    values
+                        .iter()
+                        .map(|value| value.objectives()[objective])
+                        .sum::<i64>()
+                        / num_values
+                })
+                .collect(),
+        )
+    }
+
+    fn highest_possible_fitness(&self) -> Pareto<i64> {
+        Pareto::new(vec![0, 0])
+    }
+
+    fn lowest_possible_fitness(&self) -> Pareto<i64> {
+        Pareto::new(vec![Schaffer::objective(144.), Schaffer::objective(144.)])
+    }
+}
+
+#[test]
+fn nsga2_evolves_the_population_towards_the_pareto_front() {
+    let initial_population: Population<Vec<f64>> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(1, -10., 10.))
+        .of_size(40)
+        .using_seed([3; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(Schaffer)
+        .with_selection(Nsga2Selector::new(0.5, 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.3, -10., 10.))
+        .with_reinsertion(Nsga2Reinserter::new(Schaffer))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(40))
+        .build_with_seed([5; 32]);
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let population = state.result.evaluated_population;
+            // all individuals are close to the optimal solutions, only the
+            // boundaries of the population's front may lie slightly outside
+            let num_optimal = population
+                .individuals()
+                .iter()
+                .filter(|genome| (0. ..=2.).contains(&genome[0]))
+                .count();
+            expect_that!(&num_optimal, greater_than_or_equal(36));
+            for genome in population.individuals().iter() {
+                expect_that!(&(-0.5..=2.5).contains(&genome[0]), eq(true));
+            }
+            let fronts = non_dominated_sort(population.fitness_values());
+            expect_that!(&fronts.len(), eq(1));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::genetic::{Offspring, Provenance};
+
+#[test]
+fn offspring_dereferences_to_the_vector_of_children() {
+    let offspring: Offspring<Vec<u8>> = vec![vec![1], vec![2], vec![3]].into();
+
+    expect_that!(&offspring.len(), eq(3));
+    expect_that!(&offspring[1], eq(vec![2]));
+    expect_that!(&offspring.parent_indices(1), eq(None));
+}
+
+#[test]
+fn parent_indices_stay_in_sync_with_the_children() {
+    let mut offspring = Offspring::with_capacity(4);
+    offspring.push(vec![0]);
+    offspring.push_with_parents(vec![1], vec![3, 4]);
+    offspring.push_with_parents(vec![2], vec![5, 6]);
+    offspring.push_with_parents(vec![3], vec![7, 8]);
+
+    expect_that!(&offspring.capacity(), greater_than_or_equal(4));
+    expect_that!(&offspring.parent_indices(0), eq(None));
+
+    let removed = offspring.remove(1);
+    expect_that!(&removed, eq(vec![1]));
+    expect_that!(&offspring.parent_indices(1), eq(Some(&[5, 6][..])));
+
+    let popped = offspring.pop();
+    expect_that!(&popped, eq(Some(vec![3])));
+    expect_that!(&offspring.len(), eq(2));
+    expect_that!(&offspring.parent_indices(1), eq(Some(&[5, 6][..])));
+}
+
+#[test]
+fn append_keeps_the_parent_indices_of_both_offsprings() {
+    let mut left: Offspring<Vec<u8>> = vec![vec![0]].into();
+    let mut right = Offspring::new();
+    right.push_with_parents(vec![1], vec![2, 3]);
+
+    left.append(&mut right);
+
+    expect_that!(&right.is_empty(), eq(true));
+    expect_that!(&left.len(), eq(2));
+    expect_that!(&left.parent_indices(0), eq(None));
+    expect_that!(&left.parent_indices(1), eq(Some(&[2, 3][..])));
+}
+
+#[test]
+fn offspring_carries_its_provenance() {
+    let provenance = Provenance {
+        crossover: "Uniform-Cross-Breeder".to_string(),
+        mutation: "Random-Value-Mutation".to_string(),
+    };
+    let offspring: Offspring<Vec<u8>> = Offspring::new().with_provenance(provenance.clone());
+
+    expect_that!(&offspring.provenance(), eq(Some(&provenance)));
+}
@@ -0,0 +1,306 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    algorithm::EvaluatedPopulation,
+    genetic::{FitnessFunction, Pair, Parents},
+    operator::{
+        prelude::*, CrossoverOp, GenerationAware, GenerationFeedback, GeneticOperator,
+        ParentDemand, ReinsertionOp, SelectionOp,
+    },
+    random::get_rng,
+};
+use std::sync::Arc;
+
+#[test]
+fn pair_converts_into_parents_and_back() {
+    let parents: Parents<Vec<u8>> = Pair(vec![1, 2], vec![3, 4]).into();
+    expect_that!(&parents, eq(vec![vec![1, 2], vec![3, 4]]));
+
+    let pair = Pair::try_from(parents);
+    expect_that!(&pair, eq(Ok(Pair(vec![1, 2], vec![3, 4]))));
+}
+
+#[test]
+fn parents_of_more_than_two_are_no_pair() {
+    let parents: Parents<Vec<u8>> = vec![vec![1], vec![2], vec![3]];
+
+    let pair = Pair::try_from(parents);
+    expect_that!(&pair, eq(Err(vec![vec![1], vec![2], vec![3]])));
+}
+
+#[test]
+fn multi_point_crossover_of_a_pair_alternates_the_parents_at_the_cut_points() {
+    let breeder = MultiPointCrossBreeder::new(2);
+    let mut rng = get_rng([7; 32]);
+
+    let children = breeder.crossover_pair(Pair(vec![1; 12], vec![2; 12]), &mut rng);
+
+    expect_that!(&children.len(), eq(2));
+    for child in children {
+        expect_that!(&child.len(), eq(12));
+        let num_changes = child
+            .windows(2)
+            .filter(|genes| genes[0] != genes[1])
+            .count();
+        expect_that!(&num_changes, less_than_or_equal(2));
+    }
+}
+
+#[test]
+fn uniform_crossover_of_a_pair_takes_each_gene_from_one_of_the_parents() {
+    let breeder = UniformCrossBreeder::new();
+    let mut rng = get_rng([7; 32]);
+
+    let children = breeder.crossover_pair(Pair(vec![1; 12], vec![2; 12]), &mut rng);
+
+    expect_that!(&children.len(), eq(2));
+    for child in children {
+        expect_that!(&child.len(), eq(12));
+        expect_that!(&child.iter().all(|gene| *gene == 1 || *gene == 2), eq(true));
+    }
+}
+
+fn evaluated_population(size: usize) -> EvaluatedPopulation<Vec<u8>, u32> {
+    let individuals: Vec<Vec<u8>> = (0..size).map(|value| vec![value as u8]).collect();
+    let fitness_values: Vec<u32> = (1..=size as u32).collect();
+    EvaluatedPopulation::new(Arc::new(individuals), fitness_values, size as u32, 1, 5)
+}
+
+fn assert_selects_parents_for_the_demand<S>(selector: S)
+where
+    S: SelectionOp<Vec<u8>, u32>,
+{
+    let population = evaluated_population(10);
+    let mut rng = get_rng([7; 32]);
+
+    for num_offspring in [1, 2, 7, 10] {
+        let demand = ParentDemand::new(num_offspring);
+
+        let parents = selector.select_for(&population, demand, &mut rng);
+
+        expect_that!(&parents.len(), eq(demand.num_parent_groups(2)));
+        expect_that!(&parents.iter().all(|parents| parents.len() == 2), eq(true));
+    }
+}
+
+#[test]
+fn maximize_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(MaximizeSelector::new(0.05, 2));
+}
+
+#[test]
+fn truncation_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(TruncationSelector::new(0.3, 0.05, 2));
+}
+
+#[test]
+fn truncation_selector_selects_parents_from_the_best_individuals_only() {
+    let population = evaluated_population(10);
+    let selector = TruncationSelector::new(0.3, 0.6, 2);
+
+    let parents = selector.select_from(&population, &mut get_rng([7; 32]));
+
+    expect_that!(&selector.mating_pool_size(10), eq(3));
+    expect_that!(
+        &parents,
+        eq(vec![
+            vec![vec![9], vec![8]],
+            vec![vec![8], vec![7]],
+            vec![vec![7], vec![9]],
+            vec![vec![9], vec![7]],
+            vec![vec![8], vec![9]],
+            vec![vec![7], vec![8]],
+        ])
+    );
+}
+
+#[test]
+fn truncation_selector_selects_the_same_parents_for_any_seed() {
+    let population = evaluated_population(10);
+    let selector = TruncationSelector::new(0.5, 1., 3);
+
+    let parents1 = selector.select_from(&population, &mut get_rng([1; 32]));
+    let parents2 = selector.select_from(&population, &mut get_rng([2; 32]));
+
+    expect_that!(&parents1.len(), eq(10));
+    expect_that!(&parents1, eq(parents2));
+}
+
+#[test]
+fn truncation_selector_keeps_at_least_one_individual_in_the_mating_pool() {
+    let population = evaluated_population(10);
+    let selector = TruncationSelector::new(0., 0.2, 2);
+
+    let parents = selector.select_from(&population, &mut get_rng([7; 32]));
+
+    expect_that!(
+        &parents,
+        eq(vec![vec![vec![9], vec![9]], vec![vec![9], vec![9]]])
+    );
+}
+
+#[test]
+fn tournament_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(TournamentSelector::new(0.05, 2, 3, 0.7, false));
+}
+
+#[test]
+fn roulette_wheel_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(RouletteWheelSelector::new(0.05, 2));
+}
+
+#[test]
+fn universal_sampling_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(UniversalSamplingSelector::new(0.05, 2));
+}
+
+#[test]
+fn boltzmann_selector_selects_parents_for_the_demand() {
+    assert_selects_parents_for_the_demand(BoltzmannSelector::new(0.05, 2, 10., 0.9));
+}
+
+fn generation_feedback(generation: u64) -> GenerationFeedback {
+    GenerationFeedback {
+        generation,
+        population_size: 10,
+        improved: false,
+        stagnant_generations: 0,
+        fitness_diversity: 1.,
+        offspring_success: None,
+        diversity: None,
+    }
+}
+
+#[test]
+fn boltzmann_selector_cools_down_over_the_generations() {
+    let mut selector = BoltzmannSelector::new(1., 2, 8., 0.5).with_min_temperature(1.5);
+
+    let temperatures: Vec<f64> = (1..=4)
+        .map(|generation| {
+            selector
+                .as_generation_aware()
+                .unwrap()
+                .generation_evaluated(&generation_feedback(generation));
+            selector.temperature()
+        })
+        .collect();
+
+    expect_that!(&temperatures, eq(vec![8., 4., 2., 1.5]));
+}
+
+#[test]
+fn boltzmann_selector_prefers_the_fittest_individuals_at_a_low_temperature() {
+    let population = evaluated_population(10);
+    let mut selector = BoltzmannSelector::new(1., 2, 100., 0.01);
+    let mut rng = get_rng([7; 32]);
+    let count_fittest = |parents: Vec<Vec<Vec<u8>>>| {
+        parents
+            .iter()
+            .flatten()
+            .filter(|individual| individual[0] == 9)
+            .count()
+    };
+
+    let hot = count_fittest(selector.select_from(&population, &mut rng));
+    selector.generation_evaluated(&generation_feedback(3));
+    let cold = count_fittest(selector.select_from(&population, &mut rng));
+
+    expect_that!(&hot, less_than(10));
+    expect_that!(&cold, eq(20));
+}
+
+#[test]
+fn tournament_selector_returns_only_complete_groups_of_parents() {
+    let population = evaluated_population(5);
+    let selector = TournamentSelector::new(1., 3, 2, 0.5, true);
+
+    for seed in 0..20 {
+        let parents = selector.select_from(&population, &mut get_rng([seed; 32]));
+
+        expect_that!(&parents.len(), less_than_or_equal(5));
+        expect_that!(&parents.iter().all(|parents| parents.len() == 3), eq(true));
+    }
+}
+
+#[test]
+fn parent_demand_of_a_ratio_is_rounded_and_bounded_by_the_population_size() {
+    expect_that!(&ParentDemand::from_ratio(20, 0.85).num_offspring(), eq(17));
+    expect_that!(&ParentDemand::from_ratio(20, 0.01).num_offspring(), eq(1));
+    expect_that!(&ParentDemand::from_ratio(20, 1.5).num_offspring(), eq(20));
+    expect_that!(&ParentDemand::from_ratio(20, 0.).num_offspring(), eq(0));
+    expect_that!(&ParentDemand::from_ratio(0, 0.85).num_offspring(), eq(0));
+}
+
+#[test]
+fn parent_demand_needs_enough_groups_of_parents_for_all_offspring() {
+    let demand = ParentDemand::new(17);
+
+    expect_that!(&demand.num_parent_groups(2), eq(9));
+    expect_that!(&demand.num_parent_groups(1), eq(17));
+    expect_that!(&demand.num_parent_groups(0), eq(0));
+}
+
+#[test]
+fn reinserters_with_a_fixed_number_of_offspring_demand_them() {
+    let elitist = ElitistReinserter::new(CountOnes, true, 0.85);
+    let competing = ElitistReinserter::new(CountOnes, false, 0.85);
+    let uniform = UniformReinserter::new(0.5);
+
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::parent_demand(&elitist, 20),
+        eq(Some(ParentDemand::new(17)))
+    );
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::parent_demand(&competing, 20),
+        eq(None)
+    );
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::parent_demand(&uniform, 20),
+        eq(Some(ParentDemand::new(10)))
+    );
+}
+
+#[test]
+fn reinserters_require_a_full_population_of_offspring_without_a_demand() {
+    let elitist = ElitistReinserter::new(CountOnes, true, 0.85);
+    let competing = ElitistReinserter::new(CountOnes, false, 0.85);
+    let uniform = UniformReinserter::new(0.5);
+
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::required_offspring(&elitist, 20),
+        eq(17)
+    );
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::required_offspring(&competing, 20),
+        eq(20)
+    );
+    expect_that!(
+        &ReinsertionOp::<Vec<u8>, u32>::required_offspring(&uniform, 20),
+        eq(10)
+    );
+}
+
+#[derive(Clone, Debug)]
+struct CountOnes;
+
+impl FitnessFunction<Vec<u8>, u32> for CountOnes {
+    fn fitness_of(&self, genome: &Vec<u8>) -> u32 {
+        genome.iter().filter(|gene| **gene == 1).count() as u32
+    }
+
+    fn average(&self, values: &[u32]) -> u32 {
+        values.iter().sum::<u32>() / values.len() as u32
+    }
+
+    fn highest_possible_fitness(&self) -> u32 {
+        u32::MAX
+    }
+
+    fn lowest_possible_fitness(&self) -> u32 {
+        0
+    }
+}
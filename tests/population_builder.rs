@@ -5,7 +5,9 @@ extern crate galvanic_assert;
 use galvanic_assert::matchers::*;
 
 use genevo::{
-    population::{BinaryEncodedGenomeBuilder, ValueEncodedGenomeBuilder},
+    population::{
+        BinaryEncodedGenomeBuilder, PermutationEncodedGenomeBuilder, ValueEncodedGenomeBuilder,
+    },
     prelude::*,
     random::Rng,
 };
@@ -32,6 +34,21 @@ fn create_population_of_vec_of_f64_uniform_at_random() {
     assert_that!(&population.size(), eq(200));
 }
 
+#[test]
+fn create_population_of_permutations_uniform_at_random() {
+    let population: Population<Vec<usize>> = build_population()
+        .with_genome_builder(PermutationEncodedGenomeBuilder::new(8))
+        .of_size(200)
+        .uniform_at_random();
+
+    assert_that!(&population.size(), eq(200));
+    for genome in population.individuals() {
+        let mut sorted = genome.clone();
+        sorted.sort_unstable();
+        assert_that!(&sorted, eq((0..8).collect::<Vec<usize>>()));
+    }
+}
+
 #[test]
 fn create_population_of_custom_genotype_uniform_at_random() {
     #[derive(Clone, Debug, PartialEq)]
@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct CountTrue;
+
+impl FitnessFunction<Vec<bool>, usize> for CountTrue {
+    fn fitness_of(&self, genome: &Vec<bool>) -> usize {
+        genome.iter().filter(|gene| **gene).count()
+    }
+
+    fn average(&self, fitness_values: &[usize]) -> usize {
+        fitness_values.iter().sum::<usize>() / fitness_values.len()
+    }
+
+    fn highest_possible_fitness(&self) -> usize {
+        8
+    }
+
+    fn lowest_possible_fitness(&self) -> usize {
+        0
+    }
+}
+
+#[test]
+fn genetic_algorithm_can_be_set_up_and_run_with_the_prelude_only() {
+    let seed = Seed::from_bytes([3; 32]);
+    let initial_population = build_population()
+        .with_genome_builder(BinaryEncodedGenomeBuilder::new(8))
+        .of_size(10)
+        .using_seed(seed);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(CountTrue)
+        .with_selection(TournamentSelector::new(0.8, 2, 3, 1.0, false))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.1, false, true))
+        .with_reinsertion(ElitistReinserter::new(CountTrue, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut sim = simulate(algorithm)
+        .until(or(
+            GenerationLimit::new(3),
+            FitnessLimit::new(CountTrue.highest_possible_fitness()),
+        ))
+        .build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(_, processing_time, duration, _, _) => {
+            expect_that!(&duration.fmt().is_empty(), eq(false));
+            expect_that!(&processing_time.duration().fmt().is_empty(), eq(false));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
@@ -0,0 +1,78 @@
+//! End-to-end test of the real-coded pipeline on the 10-dimensional
+//! Rastrigin function.
+
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+use genevo::{prelude::*, random::Seed};
+use std::f64::consts::PI;
+
+const DIMENSIONS: usize = 10;
+const BOUND: f64 = 5.12;
+
+/// The Rastrigin function has its global minimum of 0 at the origin and a
+/// large number of local minima.
+fn rastrigin(x: &[f64]) -> f64 {
+    10. * x.len() as f64
+        + x.iter()
+            .map(|xi| xi * xi - 10. * (2. * PI * xi).cos())
+            .sum::<f64>()
+}
+
+/// Maximizes the negative Rastrigin value scaled to an integer with a
+/// precision of 1/1000.
+#[derive(Clone, Debug)]
+struct Rastrigin;
+
+impl FitnessFunction<Vec<f64>, i64> for Rastrigin {
+    fn fitness_of(&self, genome: &Vec<f64>) -> i64 {
+        -(rastrigin(genome) * 1000.).round() as i64
+    }
+
+    fn average(&self, values: &[i64]) -> i64 {
+        values.iter().sum::<i64>() / values.len() as i64
+    }
+
+    fn highest_possible_fitness(&self) -> i64 {
+        0
+    }
+
+    fn lowest_possible_fitness(&self) -> i64 {
+        -(rastrigin(&[BOUND; DIMENSIONS]) * 1000.).round() as i64
+    }
+}
+
+fn evolve_rastrigin(seed: Seed) -> Evolution<Vec<f64>, i64> {
+    evolve(
+        Rastrigin,
+        presets::real_valued(DIMENSIONS, -BOUND..BOUND)
+            .with_population_size(200)
+            .with_seed(seed),
+        GenerationLimit::new(150),
+    )
+    .unwrap()
+}
+
+#[test]
+fn real_valued_preset_finds_the_global_minimum_of_the_rastrigin_function() {
+    let evolution = evolve_rastrigin(Seed::from_bytes([7; 32]));
+
+    // a value below 1 is only reached in the basin of the global minimum
+    assert_that!(
+        &rastrigin(&evolution.best_solution.solution.genome),
+        less_than(0.01)
+    );
+}
+
+#[test]
+fn real_valued_evolution_is_reproducible_with_a_fixed_seed() {
+    let evolution1 = evolve_rastrigin(Seed::from_bytes([11; 32]));
+    let evolution2 = evolve_rastrigin(Seed::from_bytes([11; 32]));
+
+    assert_that!(
+        &evolution1.best_solution.solution,
+        eq(evolution2.best_solution.solution)
+    );
+}
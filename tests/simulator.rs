@@ -0,0 +1,3126 @@
+#[cfg(test)]
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+use genevo::{
+    algorithm::StagedAlgorithm,
+    fitness::{
+        cached::CachedFitnessFunction,
+        fallible::FallibleFitness,
+        novelty::{BehaviorDescriptor, NoveltyFitness},
+    },
+    ga::{
+        island::Island,
+        trace::{pretty_print, TraceError, TraceReader, TraceRecord},
+        GeneticAlgorithmError, Stage,
+    },
+    operator::{prelude::*, OperatorError, OperatorParameterError, TunableOperator},
+    population::{
+        allocator::{PopulationAllocator, RecyclingAllocator},
+        ValueEncodedGenomeBuilder,
+    },
+    prelude::*,
+    random::{get_rng, Seed},
+    simulation::{
+        handle::GenerationSummary,
+        replay::SeedLog,
+        simulator::{SimError, Simulator},
+        stream::{state_channel, Backpressure},
+        SimulationObserver, State,
+    },
+    statistic::{PopulationDiversity, ProcessingTime, StatisticsConfig},
+};
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+type MyGenome = Vec<i32>;
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValues;
+
+impl FitnessFunction<MyGenome, i32> for SumOfValues {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+type SumOfValuesAlgorithm = GeneticAlgorithm<
+    MyGenome,
+    i32,
+    SumOfValues,
+    MaximizeSelector,
+    MultiPointCrossBreeder,
+    RandomValueMutator<MyGenome>,
+    ElitistReinserter<MyGenome, i32, SumOfValues>,
+>;
+
+fn sum_of_values_algorithm() -> SumOfValuesAlgorithm {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .build()
+}
+
+#[test]
+fn simulator_with_min_iteration_duration_slows_down_the_simulation() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .with_min_iteration_duration(chrono::Duration::milliseconds(30))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, duration, _, _) => {
+            assert_that!(&state.iteration, eq(3));
+            // the simulator waits between the iterations
+            expect_that!(
+                &duration,
+                greater_than_or_equal(chrono::Duration::milliseconds(60))
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn simulator_with_min_iteration_duration_notifies_the_observers_without_delay() {
+    let notified_after = Arc::new(Mutex::new(Vec::new()));
+    let recorded = notified_after.clone();
+    let started_at = std::time::Instant::now();
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(2))
+        .with_min_iteration_duration(chrono::Duration::milliseconds(500))
+        .with_observer(move |_: &State<SumOfValuesAlgorithm>| {
+            recorded.lock().unwrap().push(started_at.elapsed());
+        })
+        .build();
+
+    simulator.run().unwrap();
+
+    let notified_after = notified_after.lock().unwrap();
+    expect_that!(&notified_after.len(), eq(2));
+    expect_that!(
+        &notified_after[0],
+        less_than(std::time::Duration::from_millis(500))
+    );
+    expect_that!(
+        &notified_after[1],
+        greater_than_or_equal(std::time::Duration::from_millis(500))
+    );
+}
+
+#[test]
+fn simulator_without_min_iteration_duration_has_no_throttle() {
+    let simulator: Simulator<_, _> = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .build();
+
+    assert_that!(&simulator.min_iteration_duration(), eq(None));
+}
+
+#[test]
+fn simulation_can_be_moved_to_another_thread() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .build();
+
+    let iteration = std::thread::spawn(move || match simulator.run() {
+        Ok(SimResult::Final(state, _, _, _, _)) => state.iteration,
+        _ => 0,
+    })
+    .join()
+    .unwrap();
+
+    expect_that!(&iteration, eq(5));
+}
+
+#[test]
+fn simulator_handle_reports_the_progress_of_the_simulation() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .with_best_fitness(|result: &<SumOfValuesAlgorithm as Algorithm>::Output| {
+            f64::from(result.best_solution.solution.fitness)
+        })
+        .build();
+    let handle = simulator.handle();
+    expect_that!(
+        &handle.summary(),
+        eq(GenerationSummary {
+            iteration: 0,
+            last_improvement: 0,
+            best_fitness: None,
+            running: false,
+        })
+    );
+
+    let mut best_fitness = 0;
+    for iteration in 1..=2 {
+        match simulator.step().unwrap() {
+            SimResult::Intermediate(state) => {
+                best_fitness = best_fitness.max(state.result.best_solution.solution.fitness);
+            },
+            SimResult::Final(_, _, _, _, _) => panic!("expected intermediate result"),
+        }
+        expect_that!(&handle.iteration(), eq(iteration));
+        expect_that!(&handle.best_fitness(), eq(Some(f64::from(best_fitness))));
+        expect_that!(&handle.is_running(), eq(true));
+    }
+    simulator.step().unwrap();
+
+    let polled = std::thread::spawn(move || handle.summary()).join().unwrap();
+    expect_that!(&polled.iteration, eq(3));
+    expect_that!(&polled.running, eq(false));
+}
+
+#[test]
+fn state_channel_with_block_policy_receives_all_results() {
+    let (sender, receiver) = state_channel(1, Backpressure::Block);
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_state_sender(sender)
+        .build();
+
+    let consumer = std::thread::spawn(move || receiver.collect::<Vec<_>>());
+    let result = simulator.run().unwrap();
+    drop(simulator);
+    let received = consumer.join().unwrap();
+
+    expect_that!(&received.len(), eq(5));
+    expect_that!(
+        &received
+            .iter()
+            .filter(|result| matches!(result, SimResult::Intermediate(_)))
+            .count(),
+        eq(4)
+    );
+    expect_that!(&received[4], eq(result));
+}
+
+#[test]
+fn state_channel_with_drop_newest_policy_drops_intermediate_results_only() {
+    let (sender, receiver) = state_channel(1, Backpressure::DropNewest);
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_state_sender(sender)
+        .build();
+
+    for _ in 0..4 {
+        simulator.step().unwrap();
+    }
+    expect_that!(&receiver.dropped_states(), eq(3));
+    match receiver.try_recv() {
+        Some(SimResult::Intermediate(state)) => {
+            expect_that!(&state.iteration, eq(1));
+        },
+        _ => panic!("expected the result of the first iteration"),
+    }
+
+    let result = simulator.step().unwrap();
+    drop(simulator);
+    let received: Vec<_> = receiver.collect();
+    expect_that!(&received, eq(vec![result]));
+}
+
+#[test]
+fn ticks_with_a_zero_budget_advance_the_simulation_stage_by_stage() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .build();
+
+    let mut ticks = 0;
+    let result = loop {
+        ticks += 1;
+        if let Some(result) = simulator.tick(chrono::Duration::zero()).unwrap() {
+            break result;
+        }
+    };
+
+    expect_that!(&ticks, eq(4));
+    match result {
+        SimResult::Intermediate(state) => {
+            expect_that!(&state.iteration, eq(1));
+        },
+        SimResult::Final(..) => panic!("expected intermediate result"),
+    }
+    expect_that!(&simulator.handle().is_running(), eq(true));
+}
+
+#[test]
+fn tick_with_a_large_budget_runs_until_the_termination_criteria_are_met() {
+    let (sender, receiver) = state_channel(10, Backpressure::Block);
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_state_sender(sender)
+        .build();
+
+    let result = simulator.tick(chrono::Duration::minutes(1)).unwrap();
+    drop(simulator);
+
+    match &result {
+        Some(SimResult::Final(state, _, _, _, _)) => {
+            expect_that!(&state.iteration, eq(5));
+        },
+        _ => panic!("expected final result"),
+    }
+    expect_that!(&receiver.count(), eq(5));
+}
+
+#[test]
+fn ticks_produce_the_same_generations_as_steps() {
+    let mut stepped = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(4))
+        .build_with_seed([3; 32]);
+    let mut ticked = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(4))
+        .build_with_seed([3; 32]);
+    let expected = step_populations(&mut stepped, 4);
+
+    let mut populations = Vec::new();
+    while populations.len() < 4 {
+        if let Some(SimResult::Intermediate(state) | SimResult::Final(state, _, _, _, _)) =
+            ticked.tick(chrono::Duration::zero()).unwrap()
+        {
+            populations.push(state.result.evaluated_population.individuals().to_vec());
+        }
+    }
+
+    expect_that!(&populations, eq(expected));
+}
+
+#[test]
+fn checkpoint_can_not_be_saved_in_the_middle_of_a_generation() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .build();
+    simulator.step().unwrap();
+    simulator.tick(chrono::Duration::zero()).unwrap();
+
+    let result = simulator.save_checkpoint();
+
+    expect_that!(&simulator.algorithm().stage(), eq(Stage::Select));
+    expect_that!(
+        &result,
+        eq(Err(SimError::IterationInProgress(
+            "in iteration 2. Complete the iteration before saving a checkpoint.".to_string()
+        )))
+    );
+
+    simulator.step().unwrap();
+    let checkpoint = simulator.save_checkpoint().unwrap();
+
+    expect_that!(&checkpoint.iteration(), eq(2));
+}
+
+#[test]
+fn genetic_algorithm_processes_the_stages_of_a_generation_in_order() {
+    let mut algorithm = sum_of_values_algorithm();
+    let mut rng = get_rng([5; 32]);
+
+    let mut stages = Vec::new();
+    loop {
+        stages.push(algorithm.stage());
+        if algorithm.next_stage(1, &mut rng).unwrap().is_some() {
+            break;
+        }
+    }
+
+    expect_that!(
+        &stages,
+        eq(vec![
+            Stage::Evaluate,
+            Stage::Select,
+            Stage::Breed,
+            Stage::Reinsert
+        ])
+    );
+    expect_that!(&algorithm.stage(), eq(Stage::Evaluate));
+}
+
+#[test]
+fn next_completes_a_generation_that_has_been_started_stage_by_stage() {
+    let mut staged = sum_of_values_algorithm();
+    let mut one_shot = sum_of_values_algorithm();
+    let mut staged_rng = get_rng([5; 32]);
+    let mut one_shot_rng = get_rng([5; 32]);
+
+    staged.next_stage(1, &mut staged_rng).unwrap();
+    staged.next_stage(1, &mut staged_rng).unwrap();
+    let state = staged.next(1, &mut staged_rng).unwrap();
+    let expected = one_shot.next(1, &mut one_shot_rng).unwrap();
+
+    expect_that!(
+        &state.evaluated_population,
+        eq(expected.evaluated_population)
+    );
+    let next_state = staged.next(2, &mut staged_rng).unwrap();
+    let next_expected = one_shot.next(2, &mut one_shot_rng).unwrap();
+    expect_that!(
+        &next_state.evaluated_population,
+        eq(next_expected.evaluated_population)
+    );
+}
+
+type ElitismAlgorithm = GeneticAlgorithm<
+    MyGenome,
+    i32,
+    SumOfValues,
+    RouletteWheelSelector,
+    UniformCrossBreeder,
+    RandomValueMutator<MyGenome>,
+    UniformReinserter,
+>;
+
+fn algorithm_with_elitism(elitism: usize) -> ElitismAlgorithm {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(RouletteWheelSelector::new(1., 2))
+        .with_crossover(UniformCrossBreeder::new())
+        .with_mutation(RandomValueMutator::new(0.9, 0, 100))
+        .with_reinsertion(UniformReinserter::new(1.))
+        .with_initial_population(initial_population)
+        .with_elitism(elitism)
+        .build()
+}
+
+#[test]
+fn genetic_algorithm_with_elitism_copies_the_fittest_individuals_into_the_next_generation() {
+    let mut algorithm = algorithm_with_elitism(2);
+    let mut rng = get_rng([11; 32]);
+
+    let state = algorithm.next(1, &mut rng).unwrap();
+
+    let evaluated = &state.evaluated_population;
+    let mut ranking: Vec<(i32, MyGenome)> = evaluated
+        .fitness_values()
+        .iter()
+        .cloned()
+        .zip(evaluated.individuals().iter().cloned())
+        .collect();
+    ranking.sort_by_key(|(fitness, _)| std::cmp::Reverse(*fitness));
+    for (_, elite) in ranking.iter().take(2) {
+        expect_that!(&Island::population(&algorithm).contains(elite), eq(true));
+    }
+    expect_that!(&Island::population(&algorithm).len(), eq(20));
+}
+
+#[test]
+fn genetic_algorithm_with_elitism_never_loses_the_best_solution() {
+    let mut algorithm = algorithm_with_elitism(1);
+    let mut rng = get_rng([11; 32]);
+
+    let best_fitness: Vec<i32> = (1..=15)
+        .map(|generation| {
+            algorithm
+                .next(generation, &mut rng)
+                .unwrap()
+                .best_solution
+                .solution
+                .fitness
+        })
+        .collect();
+
+    expect_that!(
+        &best_fitness.windows(2).all(|pair| pair[0] <= pair[1]),
+        eq(true)
+    );
+}
+
+fn tied_elites() -> Vec<MyGenome> {
+    vec![
+        vec![25; 8],
+        vec![26, 24, 25, 25, 25, 25, 25, 25],
+        vec![100, 100, 0, 0, 0, 0, 0, 0],
+    ]
+}
+
+fn manhattan_distance(genome1: &MyGenome, genome2: &MyGenome) -> f64 {
+    genome1
+        .iter()
+        .zip(genome2)
+        .map(|(value1, value2)| f64::from((value1 - value2).abs()))
+        .sum()
+}
+
+fn algorithm_with_tied_elites(
+    elitism: usize,
+    tie_breaking: TieBreaking<MyGenome>,
+) -> ElitismAlgorithm {
+    let mut individuals = tied_elites();
+    individuals.extend((0..7).map(|value| vec![value; 8]));
+
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(RouletteWheelSelector::new(1., 2))
+        .with_crossover(UniformCrossBreeder::new())
+        // the offspring never equal one of the elites
+        .with_mutation(RandomValueMutator::new(1., 200, 300))
+        .with_reinsertion(UniformReinserter::new(1.))
+        .with_initial_population(Population::with_individuals(individuals))
+        .with_elitism(elitism)
+        .with_tie_breaking(tie_breaking)
+        .build()
+}
+
+#[test]
+fn genetic_algorithm_with_elitism_chooses_among_tied_elites_by_the_tie_breaking() {
+    let elites = tied_elites();
+    let mut first = algorithm_with_tied_elites(1, TieBreaking::First);
+    let mut most_diverse =
+        algorithm_with_tied_elites(1, TieBreaking::MostDiverse(manhattan_distance));
+
+    first.next(1, &mut get_rng([11; 32])).unwrap();
+    most_diverse.next(1, &mut get_rng([11; 32])).unwrap();
+
+    expect_that!(&Island::population(&first).contains(&elites[0]), eq(true));
+    expect_that!(
+        &Island::population(&most_diverse).contains(&elites[2]),
+        eq(true)
+    );
+}
+
+#[test]
+fn genetic_algorithm_with_elitism_copies_each_of_the_tied_elites_once() {
+    let mut algorithm = algorithm_with_tied_elites(3, TieBreaking::Random);
+    let mut rng = get_rng([11; 32]);
+
+    algorithm.next(1, &mut rng).unwrap();
+
+    let population = Island::population(&algorithm);
+    for elite in &tied_elites() {
+        let copies = population.iter().filter(|genome| *genome == elite).count();
+        expect_that!(&copies, eq(1));
+    }
+    expect_that!(&population.len(), eq(10));
+}
+
+fn step_populations(
+    simulator: &mut Simulator<SumOfValuesAlgorithm, GenerationLimit>,
+    steps: usize,
+) -> Vec<Vec<MyGenome>> {
+    (0..steps)
+        .map(|_| match simulator.step().unwrap() {
+            SimResult::Intermediate(state) | SimResult::Final(state, _, _, _, _) => {
+                state.result.evaluated_population.individuals().to_vec()
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn simulator_resumed_from_checkpoint_continues_the_simulation() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([7; 32]);
+    step_populations(&mut simulator, 3);
+    let checkpoint = simulator.save_checkpoint().unwrap();
+    let expected = step_populations(&mut simulator, 3);
+
+    expect_that!(&checkpoint.iteration(), eq(3));
+    let mut resumed = Simulator::from_checkpoint(
+        checkpoint,
+        sum_of_values_algorithm(),
+        GenerationLimit::new(6),
+    );
+    let populations = step_populations(&mut resumed, 3);
+
+    expect_that!(&populations, eq(expected));
+    expect_that!(&resumed.handle().iteration(), eq(6));
+}
+
+#[test]
+fn simulator_resumed_from_checkpoint_repeats_an_uninterrupted_simulation() {
+    let mut uninterrupted = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([9; 32]);
+    let expected = step_populations(&mut uninterrupted, 6);
+    let mut interrupted = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([9; 32]);
+    step_populations(&mut interrupted, 2);
+    interrupted.tick(chrono::Duration::zero()).unwrap();
+    step_populations(&mut interrupted, 1);
+    let checkpoint = interrupted.save_checkpoint().unwrap();
+    drop(interrupted);
+
+    let mut resumed = Simulator::from_checkpoint(
+        checkpoint,
+        sum_of_values_algorithm(),
+        GenerationLimit::new(6),
+    );
+    let populations = step_populations(&mut resumed, 3);
+
+    expect_that!(&resumed.seed(), eq(uninterrupted.seed()));
+    expect_that!(&populations, eq(expected[3..].to_vec()));
+    expect_that!(
+        &resumed.seed_log().seeds().to_vec(),
+        eq(uninterrupted.seed_log().seeds()[3..].to_vec())
+    );
+}
+
+fn final_best_solution(
+    simulator: &mut Simulator<SumOfValuesAlgorithm, GenerationLimit>,
+) -> (u64, i32) {
+    match simulator.run().unwrap() {
+        SimResult::Final(_, _, _, _, best) => (
+            best.best_solution.generation,
+            best.best_solution.solution.fitness,
+        ),
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn simulator_resumed_from_checkpoint_reports_the_best_solution_found_before() {
+    let mut uninterrupted = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(40))
+        .build_with_seed([3; 32]);
+    let (found_at, best_fitness) = final_best_solution(&mut uninterrupted);
+    assert_that!(&found_at, less_than(40));
+    let mut interrupted = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(40))
+        .build_with_seed([3; 32]);
+    step_populations(&mut interrupted, found_at as usize);
+    let checkpoint = interrupted.save_checkpoint().unwrap();
+
+    expect_that!(
+        &checkpoint
+            .best_output()
+            .map(|best| best.best_solution.solution.fitness),
+        eq(Some(best_fitness))
+    );
+    let mut resumed = Simulator::from_checkpoint(
+        checkpoint,
+        sum_of_values_algorithm(),
+        GenerationLimit::new(40),
+    );
+    expect_that!(
+        &final_best_solution(&mut resumed),
+        eq((found_at, best_fitness))
+    );
+    expect_that!(&resumed.handle().last_improvement(), eq(found_at));
+}
+
+#[test]
+fn simulator_can_not_restore_a_checkpoint_while_running() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build();
+    simulator.step().unwrap();
+    let checkpoint = simulator.save_checkpoint().unwrap();
+
+    expect_that!(&simulator.restore_checkpoint(checkpoint).is_err(), eq(true));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn checkpoint_can_be_serialized_and_deserialized() {
+    use genevo::simulation::checkpoint::CheckpointOf;
+
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([7; 32]);
+    step_populations(&mut simulator, 2);
+    let checkpoint = simulator.save_checkpoint().unwrap();
+
+    let json = serde_json::to_string(&checkpoint).unwrap();
+    let loaded: CheckpointOf<SumOfValuesAlgorithm> = serde_json::from_str(&json).unwrap();
+
+    expect_that!(&loaded, eq(checkpoint));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn state_of_the_simulation_can_be_serialized_and_deserialized() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([7; 32]);
+    let state = match simulator.step().unwrap() {
+        SimResult::Intermediate(state) => state,
+        SimResult::Final(state, _, _, _, _) => state,
+    };
+
+    let json = serde_json::to_string(&state).unwrap();
+    let loaded: State<SumOfValuesAlgorithm> = serde_json::from_str(&json).unwrap();
+
+    expect_that!(&loaded, eq(state));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn best_solution_can_be_exported_as_json() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .build_with_seed([7; 32]);
+    let best_solution = match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => state.result.best_solution,
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    };
+
+    let json = serde_json::to_value(&best_solution).unwrap();
+
+    expect_that!(
+        &json["solution"]["fitness"],
+        eq(serde_json::json!(best_solution.solution.fitness))
+    );
+    expect_that!(
+        &json["generation"],
+        eq(serde_json::json!(best_solution.generation))
+    );
+}
+
+#[test]
+fn simulator_calls_controller_after_each_iteration() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_controller(
+            |state: &State<SumOfValuesAlgorithm>, algorithm: &mut SumOfValuesAlgorithm| {
+                let rate = 0.1 * state.iteration as f64;
+                algorithm
+                    .mutator_mut()
+                    .set_parameter("mutation_rate", rate)
+                    .unwrap();
+            },
+        )
+        .build();
+
+    simulator.run().unwrap();
+
+    let mutation_rate = simulator.algorithm().mutator().parameter("mutation_rate");
+    expect_that!(&mutation_rate, eq(Some(0.5)));
+}
+
+fn final_populations(
+    simulator: &mut Simulator<SumOfValuesAlgorithm, GenerationLimit>,
+) -> (Seed, Vec<MyGenome>) {
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => (
+            state.seed,
+            state.result.evaluated_population.individuals().to_vec(),
+        ),
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn simulator_with_seed_records_the_seed_in_the_state() {
+    let seed = Seed::from_u64(42);
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .with_seed(seed)
+        .build();
+
+    let (recorded_seed, _) = final_populations(&mut simulator);
+
+    expect_that!(&simulator.seed(), eq(seed));
+    expect_that!(&recorded_seed, eq(seed));
+}
+
+#[test]
+fn simulation_can_be_replayed_with_the_seed_of_the_final_result() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .build();
+    let (seed, population) = final_populations(&mut simulator);
+
+    let mut replay = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_seed(seed)
+        .build();
+
+    expect_that!(&final_populations(&mut replay), eq((seed, population)));
+}
+
+#[test]
+fn reset_simulation_repeats_the_run_from_the_seed() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_seed([9; 32])
+        .build();
+    let first_run = final_populations(&mut simulator);
+
+    simulator.reset().unwrap();
+
+    expect_that!(&final_populations(&mut simulator), eq(first_run));
+}
+
+#[test]
+fn simulator_records_the_seed_of_each_iteration_in_the_seed_log() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(4))
+        .with_seed([9; 32])
+        .build();
+
+    simulator.run().unwrap();
+
+    let seed_log = simulator.seed_log();
+    expect_that!(&seed_log.first_iteration(), eq(1));
+    expect_that!(&seed_log.len(), eq(4));
+    expect_that!(&seed_log.seed_of(4).is_some(), eq(true));
+    expect_that!(&seed_log.seed_of(5), eq(None));
+}
+
+#[test]
+fn replaying_the_seed_log_repeats_the_evolution() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build();
+    let (_, population) = final_populations(&mut simulator);
+    let seed_log = simulator.seed_log().clone();
+
+    let replayed = simulator.replay(&seed_log).unwrap();
+
+    match replayed {
+        SimResult::Final(state, _, _, _, _) => {
+            expect_that!(&state.iteration, eq(6));
+            expect_that!(
+                &state.result.evaluated_population.individuals().to_vec(),
+                eq(population)
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+    expect_that!(simulator.seed_log(), eq(seed_log));
+}
+
+#[test]
+fn replaying_the_seed_log_resets_the_termination_criteria() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(Budget::new().evaluations(120))
+        .with_seed([6; 32])
+        .build();
+    let generations = match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => state.iteration,
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    };
+    let seed_log = simulator.seed_log().clone();
+
+    let replayed = simulator.replay(&seed_log).unwrap();
+
+    match replayed {
+        SimResult::Final(state, _, _, _, _) => {
+            expect_that!(&state.iteration, eq(generations));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+    expect_that!(&(generations > 1), eq(true));
+    expect_that!(
+        &(simulator.termination().used_evaluations() >= 120),
+        eq(true)
+    );
+}
+
+#[test]
+fn simulation_continues_after_replaying_a_part_of_its_seed_log() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .with_seed([4; 32])
+        .build();
+    let (_, population) = final_populations(&mut simulator);
+    let partial_log = SeedLog::of(1, simulator.seed_log().seeds()[..3].to_vec());
+
+    let replayed = simulator.replay(&partial_log).unwrap();
+    let continued = step_populations(&mut simulator, 3);
+
+    expect_that!(&matches!(replayed, SimResult::Intermediate(_)), eq(true));
+    expect_that!(&continued[2], eq(population));
+}
+
+#[test]
+fn seed_log_must_continue_the_simulation() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build();
+
+    let empty = simulator.replay(&SeedLog::new(1));
+    let gap = simulator.replay(&SeedLog::of(3, vec![Seed::from_u64(1)]));
+
+    expect_that!(
+        &empty,
+        eq(Err(SimError::InvalidSeedLog(
+            "The seed log holds no seeds to replay.".to_string()
+        )))
+    );
+    expect_that!(
+        &gap,
+        eq(Err(SimError::InvalidSeedLog(
+            "The seed log starts at iteration 3, but the simulation continues with iteration 1."
+                .to_string()
+        )))
+    );
+}
+
+#[test]
+fn simulator_iterates_over_the_generations_until_the_termination_criteria_are_met() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(4))
+        .build_with_seed([7; 32]);
+
+    let iterations: Vec<u64> = simulator
+        .iter()
+        .map(|state| state.unwrap().iteration)
+        .collect();
+
+    expect_that!(&iterations, eq(vec![1, 2, 3, 4]));
+}
+
+#[test]
+fn generations_can_be_taken_from_the_simulator_in_a_for_loop() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(100))
+        .build_with_seed([7; 32]);
+    let mut last_iteration = 0;
+
+    for state in simulator.iter().take(5) {
+        last_iteration = state.unwrap().iteration;
+    }
+
+    expect_that!(&last_iteration, eq(5));
+    match simulator.step().unwrap() {
+        SimResult::Intermediate(state) => {
+            expect_that!(&state.iteration, eq(6));
+        },
+        SimResult::Final(..) => panic!("expected intermediate result"),
+    }
+}
+
+#[test]
+fn iterating_over_generations_produces_the_same_generations_as_steps() {
+    let mut stepped = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([11; 32]);
+    let mut iterated = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(6))
+        .build_with_seed([11; 32]);
+    let expected = step_populations(&mut stepped, 6);
+
+    let mut populations = Vec::new();
+    for state in &mut iterated {
+        let state = state.unwrap();
+        populations.push(state.result.evaluated_population.individuals().to_vec());
+    }
+
+    expect_that!(&populations, eq(expected));
+}
+
+#[derive(Clone, Debug, Default)]
+struct RecordingObserver {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl SimulationObserver<SumOfValuesAlgorithm> for RecordingObserver {
+    fn on_generation(&mut self, state: &State<SumOfValuesAlgorithm>) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("generation {}", state.iteration));
+    }
+
+    fn on_finish(
+        &mut self,
+        state: &State<SumOfValuesAlgorithm>,
+        _processing_time: ProcessingTime,
+        _duration: chrono::Duration,
+        _stop_reason: &StopReason,
+    ) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("finish {}", state.iteration));
+    }
+}
+
+#[test]
+fn simulator_notifies_observers_after_each_iteration_of_a_run() {
+    let observer = RecordingObserver::default();
+    let events = observer.events.clone();
+    let iterations = Arc::new(AtomicUsize::new(0));
+    let counter = iterations.clone();
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .with_observer(observer)
+        .with_observer(move |_: &State<SumOfValuesAlgorithm>| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })
+        .build();
+
+    simulator.run().unwrap();
+
+    expect_that!(
+        &*events.lock().unwrap(),
+        eq(vec![
+            "generation 1".to_string(),
+            "generation 2".to_string(),
+            "generation 3".to_string(),
+            "finish 3".to_string(),
+        ])
+    );
+    expect_that!(&iterations.load(Ordering::SeqCst), eq(3));
+}
+
+#[test]
+fn simulator_notifies_observers_in_step_mode() {
+    let observer = RecordingObserver::default();
+    let events = observer.events.clone();
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(2))
+        .with_observer(observer)
+        .build();
+
+    simulator.step().unwrap();
+    simulator.step().unwrap();
+
+    expect_that!(
+        &*events.lock().unwrap(),
+        eq(vec![
+            "generation 1".to_string(),
+            "generation 2".to_string(),
+            "finish 2".to_string(),
+        ])
+    );
+}
+
+#[test]
+fn simulator_calculates_the_optional_statistics_every_stride_iterations() {
+    let calculations = Arc::new(AtomicUsize::new(0));
+    let counter = calculations.clone();
+    let statistics = StatisticsConfig::new().with_stride(3).with_statistic(
+        "distinct_genomes",
+        move |result: &<SumOfValuesAlgorithm as Algorithm>::Output| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut genomes = result.evaluated_population.individuals().to_vec();
+            genomes.sort();
+            genomes.dedup();
+            genomes.len() as f64
+        },
+    );
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(10))
+        .with_statistics(statistics)
+        .build();
+
+    let mut iterations_with_statistics = Vec::new();
+    loop {
+        let state = match simulator.step().unwrap() {
+            SimResult::Intermediate(state) => state,
+            SimResult::Final(state, _, _, _, _) => state,
+        };
+        if let Some(statistics) = &state.statistics {
+            expect_that!(&statistics.iteration(), eq(state.iteration));
+            let distinct_genomes = statistics.get("distinct_genomes").unwrap();
+            expect_that!(&(1. ..=20.).contains(&distinct_genomes), eq(true));
+            iterations_with_statistics.push(state.iteration);
+        }
+        if state.iteration == 10 {
+            break;
+        }
+    }
+
+    expect_that!(&iterations_with_statistics, eq(vec![3, 6, 9]));
+    expect_that!(&calculations.load(Ordering::SeqCst), eq(3));
+}
+
+#[test]
+fn final_result_reports_the_best_result_of_all_iterations() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(8))
+        .build_with_seed([9; 32]);
+
+    let mut highest_fitness = Vec::new();
+    let best_result = loop {
+        let result = simulator.step().unwrap();
+        match &result {
+            SimResult::Intermediate(state) => {
+                expect_that!(&result.best_result(), eq(None));
+                highest_fitness.push(state.result.best_solution.solution.fitness);
+            },
+            SimResult::Final(state, _, _, _, best_result) => {
+                highest_fitness.push(state.result.best_solution.solution.fitness);
+                expect_that!(&result.best_result(), eq(Some(best_result)));
+                break best_result.clone();
+            },
+        }
+    };
+
+    expect_that!(&highest_fitness.len(), eq(8));
+    expect_that!(
+        &best_result.best_solution.solution.fitness,
+        eq(highest_fitness.iter().copied().max().unwrap())
+    );
+}
+
+#[test]
+fn simulator_records_the_history_of_the_generations() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(5))
+        .with_statistics_history()
+        .build_with_seed([3; 32]);
+
+    let mut states = Vec::new();
+    for state in simulator.iter() {
+        states.push(state.unwrap());
+    }
+
+    let statistics = simulator.simulation_statistics().unwrap();
+    expect_that!(&statistics.len(), eq(5));
+    for (record, state) in statistics.history().iter().zip(&states) {
+        let evaluated = &state.result.evaluated_population;
+        expect_that!(&record.iteration, eq(state.iteration));
+        expect_that!(
+            &record.best_fitness,
+            eq(*evaluated.highest_fitness() as f64)
+        );
+        expect_that!(
+            &record.average_fitness,
+            eq(*evaluated.average_fitness() as f64)
+        );
+        expect_that!(
+            &record.worst_fitness,
+            eq(*evaluated.lowest_fitness() as f64)
+        );
+        expect_that!(&record.duration, eq(state.duration));
+    }
+    let mut csv = Vec::new();
+    statistics.to_csv(&mut csv).unwrap();
+    expect_that!(&String::from_utf8(csv).unwrap().lines().count(), eq(6));
+}
+
+#[test]
+fn simulator_without_statistics_history_records_no_generations() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(2))
+        .build();
+
+    simulator.run().unwrap();
+
+    expect_that!(&simulator.simulation_statistics(), eq(None));
+}
+
+#[test]
+fn reset_simulation_clears_the_history_of_the_generations() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(3))
+        .with_statistics_history()
+        .build();
+    simulator.run().unwrap();
+
+    simulator.reset().unwrap();
+
+    expect_that!(
+        &simulator
+            .simulation_statistics()
+            .map(|statistics| statistics.len()),
+        eq(Some(0))
+    );
+}
+
+#[test]
+fn tunable_operator_rejects_unknown_parameter() {
+    let mut selector = TournamentSelector::new(0.7, 2, 3, 0.9, true);
+
+    expect_that!(&selector.set_parameter("tournament_size", 5.), eq(Ok(())));
+    expect_that!(&selector.tournament_size(), eq(5));
+    expect_that!(
+        &selector.set_parameter("unknown", 1.),
+        eq(Err(OperatorParameterError::UnknownParameter(
+            "unknown".to_string()
+        )))
+    );
+}
+
+#[derive(Clone, Debug)]
+struct FirstTwoValues;
+
+impl BehaviorDescriptor<MyGenome> for FirstTwoValues {
+    fn behavior_of(&self, genome: &MyGenome) -> Vec<f64> {
+        vec![genome[0] as f64, genome[1] as f64]
+    }
+}
+
+#[test]
+fn genetic_algorithm_maintains_the_novelty_archive() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let novelty = NoveltyFitness::new(FirstTwoValues, 3, 5., 100);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(novelty.clone())
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(novelty.clone(), false, 0.85))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(5))
+        .build_with_seed([42; 32]);
+
+    simulator.run().unwrap();
+
+    // the whole initial population is novel compared to the empty archive
+    expect_that!(&novelty.archive_len(), greater_than(20));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SlowOnEvenFirstValue;
+
+impl FitnessFunction<MyGenome, i32> for SlowOnEvenFirstValue {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        if genome[0] % 2 == 0 {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+        }
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        -1
+    }
+}
+
+#[test]
+fn genetic_algorithm_assigns_the_fallback_fitness_to_evaluations_that_time_out() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let num_slow = initial_population
+        .individuals()
+        .iter()
+        .filter(|genome| genome[0] % 2 == 0)
+        .count();
+
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SlowOnEvenFirstValue)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SlowOnEvenFirstValue, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_evaluation_timeout(chrono::Duration::milliseconds(20), -1)
+        .build();
+    let mut simulator = simulate(algorithm).until(GenerationLimit::new(1)).build();
+
+    match simulator.step().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let evaluated = &state.result.evaluated_population;
+            assert_that!(&state.result.evaluation_timeouts, eq(num_slow));
+            for (genome, fitness) in evaluated
+                .individuals()
+                .iter()
+                .zip(evaluated.fitness_values())
+            {
+                let expected = if genome[0] % 2 == 0 {
+                    -1
+                } else {
+                    genome.iter().sum()
+                };
+                expect_that!(fitness, eq(expected));
+            }
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+/// A gene without `PartialEq`, like floats wrapped by some numeric library.
+#[derive(Clone, Copy, Debug)]
+struct Weight(f64);
+
+#[derive(Clone, Debug, PartialEq)]
+struct WeightedPrefix;
+
+impl FitnessFunction<Vec<Weight>, i32> for WeightedPrefix {
+    fn fitness_of(&self, genome: &Vec<Weight>) -> i32 {
+        genome
+            .iter()
+            .enumerate()
+            .map(|(locus, weight)| (weight.0 * (genome.len() - locus) as f64) as i32)
+            .sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        36 * 10
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn genetic_algorithm_evolves_genomes_that_can_not_be_compared() {
+    let initial_population = Population::with_individuals(
+        (0..10)
+            .map(|offset| {
+                (0..8)
+                    .map(|value| Weight(((value + offset) % 10) as f64))
+                    .collect()
+            })
+            .collect(),
+    );
+    let algorithm = genetic_algorithm()
+        .with_evaluation(WeightedPrefix)
+        .with_selection(MaximizeSelector::new(0.8, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(SwapOrderMutator::new(0.2))
+        .with_reinsertion(ElitistReinserter::new(WeightedPrefix, false, 0.8))
+        .with_initial_population(initial_population)
+        .build();
+    let mut sim = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    match sim.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            assert_that!(&state.iteration, eq(5));
+            assert_that!(
+                &state.result.evaluated_population.individuals().len(),
+                eq(10)
+            );
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn simulator_tracks_the_generations_since_the_best_solution_was_improved() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(GenerationLimit::new(30))
+        .build();
+    let mut best_fitness = 0;
+    let mut last_improvement = 0;
+
+    loop {
+        let state = match simulator.step().unwrap() {
+            SimResult::Intermediate(state) => state,
+            SimResult::Final(state, _, _, _, _) => {
+                expect_that!(
+                    &state.time_to_best(),
+                    greater_than_or_equal(chrono::Duration::zero())
+                );
+                break;
+            },
+        };
+        let fitness = state.result.best_solution.solution.fitness;
+        if state.iteration == 1 || fitness > best_fitness {
+            best_fitness = fitness;
+            last_improvement = state.iteration;
+        }
+        expect_that!(&state.last_improvement, eq(last_improvement));
+        expect_that!(
+            &state.generations_since_improvement,
+            eq(state.iteration - last_improvement)
+        );
+    }
+}
+
+#[test]
+fn genetic_algorithm_keeps_the_population_if_no_parents_are_selected() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(8)
+        .using_seed([42; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.05, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population.clone())
+        .with_pinned_offspring(0)
+        .build();
+    let mut simulator = simulate(algorithm).until(GenerationLimit::new(3)).build();
+
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, _, _) => {
+            let mut individuals = state.result.evaluated_population.individuals().to_vec();
+            let mut expected = initial_population.individuals().to_vec();
+            individuals.sort();
+            expected.sort();
+            assert_that!(&individuals, eq(expected));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SumOfValuesWithJitter;
+
+impl FitnessFunction<MyGenome, i32> for SumOfValuesWithJitter {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        // let the threads finish their work in a different order
+        std::thread::sleep(std::time::Duration::from_micros(genome[0] as u64 * 20));
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+fn assert_fitness_values_are_ordered_like_the_individuals(
+    evaluated_population: &EvaluatedPopulation<MyGenome, i32>,
+) {
+    let individuals = evaluated_population.individuals();
+    let fitness_values = evaluated_population.fitness_values();
+    assert_that!(&fitness_values.len(), eq(individuals.len()));
+    for (genome, fitness) in individuals.iter().zip(fitness_values) {
+        assert_that!(fitness, eq(genome.iter().sum::<i32>()));
+    }
+}
+
+fn evaluate_jittered_population(timeout: Option<chrono::Duration>, population_size: usize) {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(population_size)
+        .using_seed([7; 32]);
+    let builder = genetic_algorithm()
+        .with_evaluation(SumOfValuesWithJitter)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValuesWithJitter, false, 0.85))
+        .with_initial_population(initial_population);
+    let builder = match timeout {
+        Some(timeout) => builder.with_evaluation_timeout(timeout, -1),
+        None => builder,
+    };
+    let mut simulator = simulate(builder.build())
+        .until(GenerationLimit::new(2))
+        .build();
+
+    loop {
+        match simulator.step().unwrap() {
+            SimResult::Intermediate(state) => {
+                assert_fitness_values_are_ordered_like_the_individuals(
+                    &state.result.evaluated_population,
+                );
+            },
+            SimResult::Final(state, _, _, _, _) => {
+                assert_fitness_values_are_ordered_like_the_individuals(
+                    &state.result.evaluated_population,
+                );
+                break;
+            },
+        }
+    }
+}
+
+#[test]
+fn sequentially_evaluated_fitness_values_are_ordered_like_the_individuals() {
+    evaluate_jittered_population(None, 20);
+}
+
+#[test]
+fn parallel_evaluated_fitness_values_are_ordered_like_the_individuals() {
+    evaluate_jittered_population(None, 300);
+}
+
+#[test]
+fn fitness_values_evaluated_with_timeout_are_ordered_like_the_individuals() {
+    evaluate_jittered_population(Some(chrono::Duration::seconds(5)), 120);
+}
+
+#[test]
+fn genetic_algorithm_reuses_the_buffers_of_a_recycling_allocator() {
+    let allocator = Arc::new(RecyclingAllocator::new(4));
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_allocator(allocator.clone())
+        .build();
+    let mut simulator = simulate(algorithm).until(GenerationLimit::new(100)).build();
+
+    let allocations: Vec<usize> = (0..100)
+        .map(|_| {
+            simulator.step().unwrap();
+            allocator.num_allocations()
+        })
+        .collect();
+
+    expect_that!(&allocations, eq(vec![1; 100]));
+    expect_that!(&allocator.num_buffers(), eq(4));
+}
+
+/// Records the capacities of the buffers it allocates and the lengths of the
+/// buffers that are released.
+#[derive(Debug, Default)]
+struct RecordingAllocator {
+    capacities: Mutex<Vec<usize>>,
+    released: Mutex<Vec<usize>>,
+}
+
+impl PopulationAllocator<MyGenome> for RecordingAllocator {
+    fn allocate(&self, capacity: usize) -> Vec<MyGenome> {
+        self.capacities.lock().unwrap().push(capacity);
+        Vec::with_capacity(capacity)
+    }
+
+    fn release(&self, buffer: Vec<MyGenome>) {
+        self.released.lock().unwrap().push(buffer.len());
+    }
+}
+
+#[test]
+fn genetic_algorithm_allocates_the_populations_from_the_allocator() {
+    let allocator = Arc::new(RecordingAllocator::default());
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(Population::with_individuals(vec![vec![50; 8]; 20]))
+        .with_allocator(allocator.clone())
+        .build();
+    let mut simulator = simulate(algorithm).until(GenerationLimit::new(5)).build();
+
+    simulator.run().unwrap();
+
+    // one buffer for the offspring and one for the population per generation
+    expect_that!(&*allocator.capacities.lock().unwrap(), eq(vec![20; 10]));
+    // the populations of the last two generations are still shared by the
+    // results of the simulation
+    let released_populations = allocator
+        .released
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|len| **len == 20)
+        .count();
+    expect_that!(&released_populations, eq(3));
+}
+
+#[test]
+fn fitness_plateau_stops_the_simulation_when_the_best_fitness_stagnates() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(or(FitnessPlateau::new(0, 3), GenerationLimit::new(1_000)))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, _, stop_reason, _) => {
+            expect_that!(&stop_reason.contains("has not improved"), eq(true));
+            expect_that!(&state.generations_since_improvement, eq(3));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn fitness_plateau_ignores_improvements_not_greater_than_epsilon() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(FitnessPlateau::new(8 * 99, 4))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, _, _, _) => {
+            expect_that!(&state.iteration, eq(5));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn time_limit_stops_the_simulation_after_the_wall_clock_time_is_used_up() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(or(
+            TimeLimit::new(chrono::Duration::milliseconds(60)),
+            GenerationLimit::new(1_000),
+        ))
+        .with_min_iteration_duration(chrono::Duration::milliseconds(20))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, duration, stop_reason, _) => {
+            expect_that!(&stop_reason.contains("maximal runtime"), eq(true));
+            expect_that!(
+                &duration,
+                greater_than_or_equal(chrono::Duration::milliseconds(60))
+            );
+            expect_that!(&state.iteration, less_than(1_000));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn budget_stops_the_simulation_when_the_evaluations_are_used_up() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(Budget::new().evaluations(90).generations(10))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, _, stop_reason, _) => {
+            // 20 individuals are evaluated per generation
+            assert_that!(&state.iteration, eq(5));
+            expect_that!(&stop_reason.contains("evaluations budget"), eq(true));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn budget_names_the_component_that_has_been_used_up() {
+    let mut budget = Budget::new()
+        .evaluations(1_000)
+        .time(chrono::Duration::minutes(10))
+        .generations(3);
+    let mut algorithm = sum_of_values_algorithm();
+    let mut rng = genevo::random::get_rng(genevo::random::random_seed());
+    let started_at = chrono::Local::now();
+
+    let mut stop_flag = StopFlag::Continue;
+    for iteration in 1..=3 {
+        let result = algorithm.next(iteration, &mut rng).unwrap();
+        let state: State<SumOfValuesAlgorithm> = State {
+            started_at,
+            seed: Seed::default(),
+            iteration,
+            duration: chrono::Duration::zero(),
+            processing_time: result.processing_time,
+            last_improvement: iteration,
+            last_improved_at: started_at,
+            generations_since_improvement: 0,
+            statistics: None,
+            result,
+        };
+        stop_flag = budget.evaluate(&state);
+    }
+
+    assert_that!(&budget.tripped(), eq(Some(BudgetComponent::Generations)));
+    assert_that!(&budget.used_evaluations(), eq(60));
+    expect_that!(&matches!(stop_flag, StopFlag::StopNow(_)), eq(true));
+
+    Termination::<SumOfValuesAlgorithm>::reset(&mut budget);
+    expect_that!(&budget.tripped(), eq(None));
+    expect_that!(&budget.used_evaluations(), eq(0));
+}
+
+type CachedSumOfValuesAlgorithm = GeneticAlgorithm<
+    MyGenome,
+    i32,
+    CachedFitnessFunction<MyGenome, i32, CountingSumOfValues>,
+    MaximizeSelector,
+    MultiPointCrossBreeder,
+    RandomValueMutator<MyGenome>,
+    ElitistReinserter<MyGenome, i32, SumOfValues>,
+>;
+
+#[test]
+fn combined_budget_starts_again_when_the_simulation_is_reset() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(or(Budget::new().evaluations(90), GenerationLimit::new(10)))
+        .build();
+    simulator.run().unwrap();
+    expect_that!(
+        &simulator.termination().condition1().tripped(),
+        eq(Some(BudgetComponent::Evaluations))
+    );
+
+    simulator.reset().unwrap();
+
+    expect_that!(&simulator.termination().condition1().tripped(), eq(None));
+    expect_that!(
+        &simulator.termination().condition1().used_evaluations(),
+        eq(0)
+    );
+    match simulator.run().unwrap() {
+        SimResult::Final(state, _, _, stop_reason, _) => {
+            expect_that!(&state.iteration, eq(5));
+            expect_that!(&stop_reason.contains("evaluations budget"), eq(true));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn budget_in_an_and_combinator_is_reset() {
+    let mut termination = and(Budget::new().evaluations(1), GenerationLimit::new(1));
+    let mut algorithm = sum_of_values_algorithm();
+    let mut rng = get_rng([3; 32]);
+    let started_at = chrono::Local::now();
+    let result = algorithm.next(1, &mut rng).unwrap();
+    let state: State<SumOfValuesAlgorithm> = State {
+        started_at,
+        seed: Seed::default(),
+        iteration: 1,
+        duration: chrono::Duration::zero(),
+        processing_time: result.processing_time,
+        last_improvement: 1,
+        last_improved_at: started_at,
+        generations_since_improvement: 0,
+        statistics: None,
+        result,
+    };
+    expect_that!(
+        &matches!(termination.evaluate(&state), StopFlag::StopNow(_)),
+        eq(true)
+    );
+    expect_that!(&termination.condition1().used_evaluations(), eq(20));
+
+    Termination::<SumOfValuesAlgorithm>::reset(&mut termination);
+
+    expect_that!(&termination.condition1().used_evaluations(), eq(0));
+    expect_that!(&termination.condition1().tripped(), eq(None));
+}
+
+#[test]
+fn budget_counts_the_fitness_evaluations_reported_by_the_algorithm() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let evaluator = CachedFitnessFunction::new(CountingSumOfValues(calls.clone()), 100);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(evaluator)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(Population::with_individuals(vec![vec![50; 8]; 20]))
+        .build();
+    let mut budget = Budget::new().evaluations(1_000);
+    let mut rng = get_rng([5; 32]);
+    let started_at = chrono::Local::now();
+
+    for iteration in 1..=3 {
+        let result = algorithm.next(iteration, &mut rng).unwrap();
+        if iteration == 1 {
+            // the identical genomes of the initial population are evaluated once
+            expect_that!(&result.fitness_evaluations, eq(1));
+        }
+        let state: State<CachedSumOfValuesAlgorithm> = State {
+            started_at,
+            seed: Seed::default(),
+            iteration,
+            duration: chrono::Duration::zero(),
+            processing_time: result.processing_time,
+            last_improvement: iteration,
+            last_improved_at: started_at,
+            generations_since_improvement: 0,
+            statistics: None,
+            result,
+        };
+        budget.evaluate(&state);
+    }
+
+    expect_that!(
+        &budget.used_evaluations(),
+        eq(calls.load(Ordering::SeqCst) as u64)
+    );
+}
+
+#[test]
+fn rank_candidates_evaluates_and_sorts_candidates_from_best_to_worst() {
+    // more than 50 candidates are evaluated in parallel
+    let candidates: Vec<MyGenome> = (0..120).map(|i| vec![(i * 37) % 101, 1, 2]).collect();
+
+    let ranked = rank_candidates(&SumOfValues, &candidates);
+
+    assert_that!(&ranked.len(), eq(120));
+    for evaluated in &ranked {
+        expect_that!(
+            &evaluated.fitness,
+            eq(SumOfValues.fitness_of(&evaluated.genome))
+        );
+    }
+    for pair in ranked.windows(2) {
+        expect_that!(&pair[0].fitness, greater_than_or_equal(pair[1].fitness));
+    }
+}
+
+#[test]
+fn rank_candidates_keeps_the_order_of_candidates_with_equal_fitness() {
+    let candidates: Vec<MyGenome> = vec![vec![1, 2], vec![5], vec![2, 1], vec![3, 0]];
+
+    let ranked = rank_candidates(&SumOfValues, &candidates);
+
+    let genomes: Vec<MyGenome> = ranked.into_iter().map(|e| e.genome).collect();
+    assert_that!(
+        &genomes,
+        eq(vec![vec![5], vec![1, 2], vec![2, 1], vec![3, 0]])
+    );
+}
+
+/// A mutator that checks that the children are traced by the breeder.
+#[derive(Clone, Debug)]
+struct ExpectSegmentsMutator;
+
+impl GeneticOperator for ExpectSegmentsMutator {
+    fn name() -> String {
+        "Expect-Segments-Mutator".to_string()
+    }
+}
+
+impl MutationOp<MyGenome> for ExpectSegmentsMutator {
+    fn mutate<R>(&self, _genome: MyGenome, _rng: &mut R) -> MyGenome
+    where
+        R: Rng + Sized,
+    {
+        panic!("children must be mutated by mutate_traced")
+    }
+
+    fn uses_segments(&self) -> bool {
+        true
+    }
+
+    fn mutate_traced<R>(&self, child: TracedChild<MyGenome>, _rng: &mut R) -> MyGenome
+    where
+        R: Rng + Sized,
+    {
+        assert_eq!(
+            child.segments.last().map(|s| s.end),
+            Some(child.genome.len())
+        );
+        child.genome
+    }
+}
+
+#[test]
+fn genetic_algorithm_passes_the_crossover_segments_to_the_mutation() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(ExpectSegmentsMutator)
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .build();
+    let mut simulator = simulate(algorithm).until(GenerationLimit::new(3)).build();
+
+    let result = simulator.run();
+
+    assert_that!(&result.is_ok(), eq(true));
+}
+
+/// A mutator that rejects children containing a gene above 50.
+#[derive(Clone, Debug)]
+struct ConstrainedMutator;
+
+impl GeneticOperator for ConstrainedMutator {
+    fn name() -> String {
+        "Constrained-Mutator".to_string()
+    }
+}
+
+impl MutationOp<MyGenome> for ConstrainedMutator {
+    fn mutate<R>(&self, genome: MyGenome, rng: &mut R) -> MyGenome
+    where
+        R: Rng + Sized,
+    {
+        self.try_mutate(genome, rng).expect("valid genome")
+    }
+
+    fn try_mutate<R>(&self, genome: MyGenome, _rng: &mut R) -> Result<MyGenome, OperatorError>
+    where
+        R: Rng + Sized,
+    {
+        match genome.iter().find(|gene| **gene > 50) {
+            Some(gene) => Err(OperatorError::ConstraintViolated(format!(
+                "gene {} is above 50",
+                gene
+            ))),
+            None => Ok(genome),
+        }
+    }
+}
+
+#[test]
+fn genetic_algorithm_surfaces_operator_failures_as_errors() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(ConstrainedMutator)
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    let result = algorithm.next(1, &mut rng);
+
+    match result {
+        Err(GeneticAlgorithmError::OperatorFailed { stage, details }) => {
+            expect_that!(&stage, eq(Stage::Breed));
+            expect_that!(
+                &details.starts_with(
+                    "The mutation operator Constrained-Mutator failed: constraint violated: gene"
+                ),
+                eq(true)
+            );
+        },
+        other => panic!("expected an operator failure, but got {:?}", other),
+    }
+}
+
+fn uniform_population_algorithm(track_offspring_success: bool) -> SumOfValuesAlgorithm {
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        // every mutation raises a value from 50 to 60
+        .with_mutation(RandomValueMutator::new(1., 60, 61))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(Population::with_individuals(vec![vec![50; 8]; 20]))
+        .with_offspring_success_tracking(track_offspring_success)
+        .build()
+}
+
+#[test]
+fn genetic_algorithm_tracks_the_children_that_are_fitter_than_their_parents() {
+    let mut algorithm = uniform_population_algorithm(true);
+    let mut rng = genevo::random::get_rng([5; 32]);
+
+    let first_state = algorithm.next(1, &mut rng).unwrap();
+    // the children are compared with their parents when they are evaluated
+    // with the next generation
+    let state = algorithm.next(2, &mut rng).unwrap();
+
+    expect_that!(&first_state.offspring_success, eq(None));
+    let success = state.offspring_success.unwrap();
+    expect_that!(&success.num_children, eq(20));
+    expect_that!(&success.num_successful, eq(20));
+    expect_that!(&success.ratio(), eq(1.));
+}
+
+#[test]
+fn genetic_algorithm_does_not_track_the_offspring_success_by_default() {
+    let mut algorithm = uniform_population_algorithm(false);
+    let mut rng = genevo::random::get_rng([5; 32]);
+
+    let state = algorithm.next(1, &mut rng).unwrap();
+
+    expect_that!(&state.offspring_success, eq(None));
+}
+
+/// Sums up the values of a genome and counts its calls.
+#[derive(Clone, Debug)]
+struct CountingSumOfValues(Arc<AtomicUsize>);
+
+impl PartialEq for CountingSumOfValues {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl FitnessFunction<MyGenome, i32> for CountingSumOfValues {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 100
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+fn count_evaluations(track_offspring_success: bool) -> usize {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(CountingSumOfValues(calls.clone()))
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(Population::with_individuals(vec![vec![50; 8]; 20]))
+        .with_offspring_success_tracking(track_offspring_success)
+        .build();
+    let mut rng = get_rng([5; 32]);
+    for generation in 1..=3 {
+        algorithm.next(generation, &mut rng).unwrap();
+    }
+    calls.load(Ordering::SeqCst)
+}
+
+#[test]
+fn tracking_the_offspring_success_does_not_evaluate_genomes_again() {
+    expect_that!(&count_evaluations(true), eq(count_evaluations(false)));
+    expect_that!(&count_evaluations(true), eq(3 * 20));
+}
+
+#[test]
+fn genetic_algorithm_feeds_back_generations_to_an_adaptive_mutator() {
+    // all individuals are equal, so the population is converged from the start
+    let mutator = AdaptiveMutationOp::new(RandomValueMutator::new(0.01, 50, 51), 0.04)
+        .with_adaptation_factor(2.);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(mutator)
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(Population::with_individuals(vec![vec![50; 8]; 20]))
+        .build();
+    let mut rng = genevo::random::get_rng([5; 32]);
+
+    algorithm.next(1, &mut rng).unwrap();
+    expect_that!(&algorithm.mutator().mutation_rate(), close_to(0.02, 1e-9));
+
+    algorithm.next(2, &mut rng).unwrap();
+    algorithm.next(3, &mut rng).unwrap();
+    expect_that!(&algorithm.mutator().mutation_rate(), close_to(0.04, 1e-9));
+}
+
+#[derive(Clone, Debug, Default)]
+struct BatchSumOfValues {
+    population_calls: Arc<AtomicUsize>,
+    genome_calls: Arc<AtomicUsize>,
+}
+
+impl FitnessFunction<MyGenome, i32> for BatchSumOfValues {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        self.genome_calls.fetch_add(1, Ordering::SeqCst);
+        genome.iter().sum()
+    }
+
+    fn fitness_of_population(&self, population: &[MyGenome]) -> Vec<i32> {
+        self.population_calls.fetch_add(1, Ordering::SeqCst);
+        population
+            .iter()
+            .map(|genome| genome.iter().sum())
+            .collect()
+    }
+
+    fn evaluates_population_at_once(&self) -> bool {
+        true
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        SumOfValues.average(fitness_values)
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        SumOfValues.highest_possible_fitness()
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        SumOfValues.lowest_possible_fitness()
+    }
+}
+
+#[test]
+fn genetic_algorithm_evaluates_the_whole_population_at_once() {
+    let evaluator = BatchSumOfValues::default();
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(60)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(evaluator.clone())
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    for generation in 1..=3 {
+        let state = algorithm.next(generation, &mut rng).unwrap();
+        let evaluated = &state.evaluated_population;
+        for (genome, fitness) in evaluated
+            .individuals()
+            .iter()
+            .zip(evaluated.fitness_values())
+        {
+            expect_that!(fitness, eq(SumOfValues.fitness_of(genome)));
+        }
+        expect_that!(
+            &state.best_solution.solution.fitness,
+            eq(*evaluated.highest_fitness())
+        );
+    }
+
+    expect_that!(&evaluator.population_calls.load(Ordering::SeqCst), eq(3));
+    expect_that!(&evaluator.genome_calls.load(Ordering::SeqCst), eq(0));
+}
+
+fn subsample_algorithm(fraction: Option<f64>) -> SumOfValuesAlgorithm {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(200)
+        .using_seed([42; 32]);
+    let builder = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population);
+    match fraction {
+        Some(fraction) => builder.with_selection_subsample(fraction).build(),
+        None => builder.build(),
+    }
+}
+
+#[test]
+fn genetic_algorithm_selects_from_the_whole_population_by_default() {
+    let mut algorithm = subsample_algorithm(None);
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&algorithm.selection_subsample(), eq(None));
+    expect_that!(&state.selection_sample_size, eq(200));
+    expect_that!(&state.summary().selection_sample_size, eq(200));
+}
+
+#[test]
+fn genetic_algorithm_selects_from_a_subsample_of_the_population() {
+    let mut algorithm = subsample_algorithm(Some(0.1));
+    let mut rng = get_rng([7; 32]);
+
+    for generation in 1..=5 {
+        let state = algorithm.next(generation, &mut rng).unwrap();
+
+        expect_that!(&state.selection_sample_size, eq(20));
+        expect_that!(&state.summary().selection_sample_size, eq(20));
+        expect_that!(&state.summary().population_size, eq(200));
+        expect_that!(&Island::population(&algorithm).len(), eq(200));
+    }
+}
+
+#[test]
+fn subsample_of_a_tiny_fraction_holds_at_least_one_individual() {
+    let mut algorithm = subsample_algorithm(Some(0.0001));
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&state.selection_sample_size, eq(1));
+}
+
+#[test]
+fn genetic_algorithm_does_not_track_the_diversity_by_default() {
+    let mut algorithm = sum_of_values_algorithm();
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&algorithm.tracks_diversity(), eq(false));
+    expect_that!(&state.diversity, eq(None));
+}
+
+#[test]
+fn genetic_algorithm_tracks_the_diversity_of_each_generation() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_diversity_tracking()
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    for generation in 1..=3 {
+        let state = algorithm.next(generation, &mut rng).unwrap();
+
+        let diversity = state.diversity.expect("diversity is tracked");
+        expect_that!(
+            &diversity,
+            eq(PopulationDiversity::of(&state.evaluated_population))
+        );
+        expect_that!(&diversity.genotypic, greater_than(0.));
+        expect_that!(&diversity.phenotypic, greater_than(0.));
+    }
+}
+
+#[test]
+fn diversity_limit_stops_the_simulation_when_the_population_has_converged() {
+    let mut simulator = simulate(sum_of_values_algorithm())
+        .until(or(
+            DiversityLimit::genotypic(1.).for_generations(2),
+            GenerationLimit::new(1_000),
+        ))
+        .build_with_seed([7; 32]);
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, _, stop_reason, _) => {
+            expect_that!(&stop_reason.contains("genotypic diversity"), eq(true));
+            expect_that!(
+                &state.result.evaluated_population.genotypic_diversity(),
+                less_than(1.)
+            );
+            expect_that!(&state.iteration, less_than(1_000));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+#[test]
+fn diversity_limit_uses_the_tracked_diversity() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_diversity_tracking()
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(DiversityLimit::phenotypic(f64::INFINITY))
+        .build();
+
+    let result = simulator.run().unwrap();
+
+    match result {
+        SimResult::Final(state, _, _, stop_reason, _) => {
+            expect_that!(&stop_reason.contains("phenotypic diversity"), eq(true));
+            expect_that!(&state.iteration, eq(1));
+            expect_that!(&state.result.diversity.is_some(), eq(true));
+        },
+        SimResult::Intermediate(_) => panic!("expected final result"),
+    }
+}
+
+/// A writer that keeps the written trace for inspection by the test.
+#[derive(Clone, Debug, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedBuffer {
+    fn records(&self) -> Vec<TraceRecord> {
+        let trace = self.0.lock().unwrap().clone();
+        TraceReader::new(&trace[..])
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+}
+
+fn traced_algorithm<W>(writer: W, mutation_rate: f64) -> SumOfValuesAlgorithm
+where
+    W: Write + Send + 'static,
+{
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(1))
+        .with_mutation(RandomValueMutator::new(mutation_rate, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_trace(writer, 2..=3)
+        .build()
+}
+
+#[test]
+fn trace_mode_writes_the_stages_of_the_traced_generations_only() {
+    let buffer = SharedBuffer::default();
+    let mut algorithm = traced_algorithm(buffer.clone(), 0.2);
+    let mut rng = get_rng([7; 32]);
+
+    for generation in 1..=4 {
+        algorithm.next(generation, &mut rng).unwrap();
+    }
+
+    let records = buffer.records();
+    let generations: Vec<u64> = records.iter().map(TraceRecord::generation).collect();
+    expect_that!(&generations.first(), eq(Some(&2)));
+    expect_that!(&generations.last(), eq(Some(&3)));
+    for record in records {
+        match record {
+            TraceRecord::Selection { parents, .. } => {
+                expect_that!(&parents.len(), eq(2));
+                expect_that!(&parents.iter().all(|parent| parent.is_some()), eq(true));
+            },
+            TraceRecord::Breeding {
+                cut_points,
+                mutated_loci,
+                ..
+            } => {
+                expect_that!(&cut_points.len(), less_than_or_equal(1));
+                expect_that!(&mutated_loci.iter().all(|locus| *locus < 8), eq(true));
+            },
+            TraceRecord::Reinsertion {
+                offspring,
+                survivors,
+                ..
+            } => {
+                expect_that!(&(offspring.len() + survivors.len()), eq(20));
+            },
+        }
+    }
+}
+
+#[test]
+fn trace_mode_reports_no_mutated_loci_without_mutation() {
+    let buffer = SharedBuffer::default();
+    let mut algorithm = traced_algorithm(buffer.clone(), 0.);
+    let mut rng = get_rng([7; 32]);
+
+    for generation in 1..=2 {
+        algorithm.next(generation, &mut rng).unwrap();
+    }
+
+    let breedings: Vec<TraceRecord> = buffer
+        .records()
+        .into_iter()
+        .filter(|record| matches!(record, TraceRecord::Breeding { .. }))
+        .collect();
+    expect_that!(&breedings.is_empty(), eq(false));
+    for record in breedings {
+        if let TraceRecord::Breeding { mutated_loci, .. } = record {
+            expect_that!(&mutated_loci, eq(Vec::<usize>::new()));
+        }
+    }
+}
+
+#[test]
+fn trace_records_can_be_read_back_and_pretty_printed() {
+    let trace = "2 select 0 parents=12,?\n\
+                 2 breed 0.1 cut_points=3 mutated_loci=-\n\
+                 \n\
+                 2 reinsert offspring=0,1 survivors=-\n";
+
+    let records: Vec<TraceRecord> = TraceReader::new(trace.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    expect_that!(
+        &records,
+        eq(vec![
+            TraceRecord::Selection {
+                generation: 2,
+                group: 0,
+                parents: vec![Some(12), None],
+            },
+            TraceRecord::Breeding {
+                generation: 2,
+                group: 0,
+                child: 1,
+                cut_points: vec![3],
+                mutated_loci: vec![],
+            },
+            TraceRecord::Reinsertion {
+                generation: 2,
+                offspring: vec![0, 1],
+                survivors: vec![],
+            },
+        ])
+    );
+    let written: Vec<String> = records.iter().map(ToString::to_string).collect();
+    expect_that!(
+        &written.join("\n"),
+        eq(trace.replace("\n\n", "\n").trim_end().to_string())
+    );
+
+    let mut pretty = Vec::new();
+    pretty_print(trace.as_bytes(), &mut pretty).unwrap();
+    expect_that!(
+        &String::from_utf8(pretty).unwrap(),
+        eq("Generation 2\n  \
+            Selection\n    \
+            group 0: parents 12, not in population\n  \
+            Breeding\n    \
+            group 0 child 1: cut points 3; mutated loci none\n  \
+            Reinsertion\n    \
+            offspring 0, 1\n    \
+            survivors none\n"
+            .to_string())
+    );
+}
+
+#[test]
+fn invalid_trace_record_is_an_error() {
+    let result: Result<Vec<TraceRecord>, TraceError> =
+        TraceReader::new("2 breed 0 cut_points=3".as_bytes()).collect();
+
+    expect_that!(
+        &result,
+        eq(Err(TraceError::InvalidRecord(
+            "invalid trace record: 2 breed 0 cut_points=3".to_string()
+        )))
+    );
+}
+
+/// A writer that always fails.
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn failure_to_write_the_trace_stops_the_algorithm() {
+    let mut algorithm = traced_algorithm(FailingWriter, 0.2);
+    let mut rng = get_rng([7; 32]);
+
+    algorithm.next(1, &mut rng).unwrap();
+    let result = algorithm.next(2, &mut rng);
+
+    expect_that!(
+        &result.err(),
+        eq(Some(GeneticAlgorithmError::TraceFailed(
+            "Can not write the trace of generation 2: disk full".to_string()
+        )))
+    );
+}
+
+#[test]
+fn genetic_algorithm_breeds_the_offspring_required_by_the_reinserter() {
+    let mut algorithm = uniform_population_algorithm(true);
+    let mut rng = get_rng([5; 32]);
+
+    expect_that!(&algorithm.required_offspring(), eq(20));
+    algorithm.set_pinned_offspring(Some(7));
+    expect_that!(&algorithm.pinned_offspring(), eq(Some(7)));
+    expect_that!(&algorithm.required_offspring(), eq(7));
+
+    algorithm.next(1, &mut rng).unwrap();
+    let state = algorithm.next(2, &mut rng).unwrap();
+
+    // 4 pairs of parents breed 8 children
+    expect_that!(&state.offspring_success.unwrap().num_children, eq(8));
+}
+
+/// Repairs genomes whose values exceed a limit of 50.
+#[derive(Clone, Debug, PartialEq)]
+struct ValueLimitFixer;
+
+impl GeneticOperator for ValueLimitFixer {
+    fn name() -> String {
+        "Value-Limit-Fixer".to_string()
+    }
+}
+
+impl FixerOp<MyGenome> for ValueLimitFixer {
+    fn fix<R>(&self, genome: &mut MyGenome, _rng: &mut R)
+    where
+        R: Rng + Sized,
+    {
+        for value in genome.iter_mut() {
+            *value = (*value).min(50);
+        }
+    }
+}
+
+#[test]
+fn genetic_algorithm_repairs_the_offspring_with_the_fixer() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 51))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.5, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_fixer(ValueLimitFixer)
+        .build();
+    let mut rng = get_rng([7; 32]);
+
+    expect_that!(&algorithm.fixer_name(), eq(Some("Value-Limit-Fixer")));
+    for generation in 1..=5 {
+        let state = algorithm.next(generation, &mut rng).unwrap();
+
+        let individuals = state.evaluated_population.individuals();
+        expect_that!(
+            &individuals.iter().flatten().all(|value| *value <= 50),
+            eq(true)
+        );
+    }
+}
+
+#[test]
+fn genetic_algorithm_does_not_repair_the_offspring_by_default() {
+    let algorithm = sum_of_values_algorithm();
+
+    expect_that!(&algorithm.fixer_name(), eq(None));
+}
+
+fn best_fitness_after_generations(mut algorithm: SumOfValuesAlgorithm, generations: u64) -> i32 {
+    let mut rng = get_rng([9; 32]);
+    (1..=generations)
+        .map(|generation| {
+            algorithm
+                .next(generation, &mut rng)
+                .unwrap()
+                .best_solution
+                .solution
+                .fitness
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn memetic_algorithm_refines_the_offspring_with_the_local_search() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 20))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let memetic = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 99))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population.clone())
+        .with_local_search(
+            HillClimber::new(RandomValueMutator::new(0.25, 0, 99), 30),
+            0.5,
+        )
+        .build();
+    let plain = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 99))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .build();
+
+    expect_that!(
+        &memetic.local_search_name(),
+        eq(Some("Hill-Climber-Random-Value-Mutator"))
+    );
+    expect_that!(&memetic.local_search_ratio(), eq(Some(0.5)));
+    expect_that!(&plain.local_search_name(), eq(None));
+    let memetic_best = best_fitness_after_generations(memetic, 4);
+    let plain_best = best_fitness_after_generations(plain, 4);
+    expect_that!(&memetic_best, greater_than(plain_best));
+}
+
+/// Records the names of the threads the fitness is evaluated in.
+#[derive(Clone, Debug, Default)]
+struct ThreadRecordingSum {
+    thread_names: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+impl FitnessFunction<MyGenome, i32> for ThreadRecordingSum {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        let name = std::thread::current().name().map(str::to_string);
+        self.thread_names.lock().unwrap().push(name);
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn genetic_algorithm_evaluates_the_fitness_in_the_given_thread_pool() {
+    let evaluator = ThreadRecordingSum::default();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .thread_name(|index| format!("ga-worker-{}", index))
+        .build()
+        .unwrap();
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(200)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(evaluator.clone())
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_thread_pool(pool)
+        .build();
+
+    algorithm.next(1, &mut get_rng([5; 32])).unwrap();
+
+    let thread_names = evaluator.thread_names.lock().unwrap();
+    expect_that!(&thread_names.len(), eq(200));
+    let in_pool = |name: &Option<String>| {
+        name.as_deref()
+            .is_some_and(|name| name.starts_with("ga-worker-"))
+    };
+    expect_that!(&thread_names.iter().all(in_pool), eq(true));
+}
+
+#[test]
+fn genetic_algorithm_with_a_thread_cap_reports_its_number_of_threads() {
+    let algorithm = sum_of_values_algorithm();
+    expect_that!(&algorithm.num_threads(), eq(None));
+
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let capped = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_num_threads(3)
+        .unwrap()
+        .build();
+
+    expect_that!(&capped.num_threads(), eq(Some(3)));
+}
+
+#[test]
+fn genetic_algorithm_in_a_thread_pool_evolves_like_in_the_global_pool() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let pooled = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_num_threads(1)
+        .unwrap()
+        .build();
+    let mut global = sum_of_values_algorithm();
+    let mut pooled = pooled;
+    let mut global_rng = get_rng([5; 32]);
+    let mut pooled_rng = get_rng([5; 32]);
+
+    for generation in 1..=3 {
+        let global_state = global.next(generation, &mut global_rng).unwrap();
+        let pooled_state = pooled.next(generation, &mut pooled_rng).unwrap();
+
+        expect_that!(
+            &pooled_state.evaluated_population.individuals(),
+            eq(global_state.evaluated_population.individuals())
+        );
+    }
+}
+
+/// Sums up the values of genomes whose values do not exceed a limit of 50.
+#[derive(Clone, Debug, PartialEq)]
+struct SumWithinLimit;
+
+impl TryFitnessFunction<MyGenome, i32> for SumWithinLimit {
+    fn try_fitness_of(&self, genome: &MyGenome) -> Result<i32, EvalError> {
+        if genome.iter().any(|value| *value > 50) {
+            Err(EvalError::Invalid("value exceeds 50".to_string()))
+        } else {
+            Ok(genome.iter().sum())
+        }
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 50
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        -1
+    }
+}
+
+type SumWithinLimitAlgorithm = GeneticAlgorithm<
+    MyGenome,
+    i32,
+    FallibleFitness<SumWithinLimit>,
+    MaximizeSelector,
+    MultiPointCrossBreeder,
+    RandomValueMutator<MyGenome>,
+    ElitistReinserter<MyGenome, i32, FallibleFitness<SumWithinLimit>>,
+>;
+
+fn limited_population() -> Population<MyGenome> {
+    build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 60))
+        .of_size(20)
+        .using_seed([42; 32])
+}
+
+fn num_exceeding_limit(population: &Population<MyGenome>) -> usize {
+    population
+        .individuals()
+        .iter()
+        .filter(|genome| genome.iter().any(|value| *value > 50))
+        .count()
+}
+
+fn sum_within_limit_algorithm(policy: EvaluationErrorPolicy) -> SumWithinLimitAlgorithm {
+    genetic_algorithm()
+        .with_evaluation(FallibleFitness::new(SumWithinLimit))
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 60))
+        .with_reinsertion(ElitistReinserter::new(
+            FallibleFitness::new(SumWithinLimit),
+            false,
+            0.85,
+        ))
+        .with_initial_population(limited_population())
+        .with_evaluation_error_policy(policy)
+        .with_fixer(ValueLimitFixer)
+        .build()
+}
+
+#[test]
+fn genetic_algorithm_aborts_on_a_failing_evaluation_by_default() {
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(FallibleFitness::new(SumWithinLimit))
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 60))
+        .with_reinsertion(ElitistReinserter::new(
+            FallibleFitness::new(SumWithinLimit),
+            false,
+            0.85,
+        ))
+        .with_initial_population(limited_population())
+        .build();
+
+    expect_that!(
+        &algorithm.evaluation_error_policy(),
+        eq(EvaluationErrorPolicy::Abort)
+    );
+    match algorithm.next(1, &mut get_rng([7; 32])) {
+        Err(GeneticAlgorithmError::EvaluationFailed(details)) => {
+            expect_that!(&details.contains("value exceeds 50"), eq(true));
+        },
+        result => panic!("expected a failed evaluation, got {:?}", result),
+    }
+}
+
+#[test]
+fn genetic_algorithm_assigns_the_lowest_fitness_to_failing_evaluations() {
+    let mut algorithm = sum_within_limit_algorithm(EvaluationErrorPolicy::LowestFitness);
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    let evaluated = &state.evaluated_population;
+    expect_that!(
+        &state.evaluation_failures,
+        eq(num_exceeding_limit(&limited_population()))
+    );
+    expect_that!(&evaluated.individuals().len(), eq(20));
+    for (genome, fitness) in evaluated
+        .individuals()
+        .iter()
+        .zip(evaluated.fitness_values())
+    {
+        let expected = if genome.iter().any(|value| *value > 50) {
+            -1
+        } else {
+            genome.iter().sum()
+        };
+        expect_that!(fitness, eq(expected));
+    }
+}
+
+#[test]
+fn genetic_algorithm_discards_genomes_whose_evaluation_fails() {
+    let mut algorithm = sum_within_limit_algorithm(EvaluationErrorPolicy::Discard);
+    let num_failing = num_exceeding_limit(&limited_population());
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    let individuals = state.evaluated_population.individuals();
+    expect_that!(&state.evaluation_failures, eq(num_failing));
+    expect_that!(&individuals.len(), eq(20 - num_failing));
+    expect_that!(
+        &individuals.iter().flatten().all(|value| *value <= 50),
+        eq(true)
+    );
+}
+
+#[test]
+fn genetic_algorithm_repairs_and_evaluates_genomes_whose_evaluation_fails_again() {
+    let mut algorithm = sum_within_limit_algorithm(EvaluationErrorPolicy::RepairAndRetry);
+    let num_failing = num_exceeding_limit(&limited_population());
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    let evaluated = &state.evaluated_population;
+    expect_that!(&state.evaluation_failures, eq(num_failing));
+    expect_that!(&evaluated.individuals().len(), eq(20));
+    for (genome, fitness) in evaluated
+        .individuals()
+        .iter()
+        .zip(evaluated.fitness_values())
+    {
+        expect_that!(&genome.iter().all(|value| *value <= 50), eq(true));
+        expect_that!(fitness, eq(genome.iter().sum::<i32>()));
+    }
+}
+
+#[test]
+fn genetic_algorithm_handles_failing_evaluations_with_timeout_by_the_error_policy() {
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(FallibleFitness::new(SumWithinLimit))
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 60))
+        .with_reinsertion(ElitistReinserter::new(
+            FallibleFitness::new(SumWithinLimit),
+            false,
+            0.85,
+        ))
+        .with_initial_population(limited_population())
+        .with_evaluation_timeout(chrono::Duration::seconds(5), -2)
+        .with_evaluation_error_policy(EvaluationErrorPolicy::Discard)
+        .build();
+    let num_failing = num_exceeding_limit(&limited_population());
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&state.evaluation_timeouts, eq(0));
+    expect_that!(&state.evaluation_failures, eq(num_failing));
+    expect_that!(
+        &state.evaluated_population.individuals().len(),
+        eq(20 - num_failing)
+    );
+}
+
+static RUNNING_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+static MAX_RUNNING_EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Takes longer than the timeout and records how many evaluations run at the
+/// same time.
+#[derive(Clone, Debug, PartialEq)]
+struct AlwaysSlow;
+
+impl FitnessFunction<MyGenome, i32> for AlwaysSlow {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        let running = RUNNING_EVALUATIONS.fetch_add(1, Ordering::SeqCst) + 1;
+        MAX_RUNNING_EVALUATIONS.fetch_max(running, Ordering::SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        RUNNING_EVALUATIONS.fetch_sub(1, Ordering::SeqCst);
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 100
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        -1
+    }
+}
+
+#[test]
+fn evaluations_with_timeout_run_on_no_more_threads_than_the_thread_pool_has() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(AlwaysSlow)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(AlwaysSlow, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_evaluation_timeout(chrono::Duration::seconds(5), -1)
+        .with_num_threads(2)
+        .unwrap()
+        .build();
+
+    // only the evaluation stage evaluates with a timeout
+    let output = algorithm.next_stage(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&output.is_none(), eq(true));
+    expect_that!(&algorithm.stage(), eq(Stage::Select));
+    expect_that!(
+        &MAX_RUNNING_EVALUATIONS.load(Ordering::SeqCst),
+        less_than_or_equal(2)
+    );
+}
+
+/// Never finishes the evaluation of a genome with a value greater than 50.
+#[derive(Clone, Debug, PartialEq)]
+struct HangsAboveLimit;
+
+impl FitnessFunction<MyGenome, i32> for HangsAboveLimit {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        if genome.iter().any(|value| *value > 50) {
+            std::thread::sleep(std::time::Duration::from_secs(3600));
+        }
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 100
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        -1
+    }
+}
+
+#[test]
+fn evaluations_that_never_finish_do_not_block_the_other_evaluations() {
+    let num_hanging = num_exceeding_limit(&limited_population());
+    assert_that!(&num_hanging, greater_than(2));
+    let mut algorithm = genetic_algorithm()
+        .with_evaluation(HangsAboveLimit)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 50))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(limited_population())
+        .with_evaluation_timeout(chrono::Duration::milliseconds(20), -1)
+        .with_num_threads(2)
+        .unwrap()
+        .build();
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+        sender.send(state).unwrap();
+    });
+    let state = receiver
+        .recv_timeout(std::time::Duration::from_secs(30))
+        .expect("the generation has not been completed");
+
+    expect_that!(&state.evaluation_timeouts, eq(num_hanging));
+    expect_that!(&state.evaluated_population.individuals().len(), eq(20));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct PanicsOnZero;
+
+impl FitnessFunction<MyGenome, i32> for PanicsOnZero {
+    fn fitness_of(&self, genome: &MyGenome) -> i32 {
+        if genome.contains(&0) {
+            panic!("genome contains a zero");
+        }
+        genome.iter().sum()
+    }
+
+    fn average(&self, fitness_values: &[i32]) -> i32 {
+        fitness_values.iter().sum::<i32>() / fitness_values.len() as i32
+    }
+
+    fn highest_possible_fitness(&self) -> i32 {
+        8 * 99
+    }
+
+    fn lowest_possible_fitness(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn panicking_fitness_function_stops_the_simulation_with_an_error() {
+    let mut initial_population: Vec<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 1, 60))
+        .of_size(20)
+        .using_seed([42; 32])
+        .individuals()
+        .to_vec();
+    initial_population[3][2] = 0;
+    let algorithm = genetic_algorithm()
+        .with_evaluation(PanicsOnZero)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 1, 60))
+        .with_reinsertion(ElitistReinserter::new(PanicsOnZero, false, 0.85))
+        .with_initial_population(Population::with_individuals(initial_population))
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(3))
+        .build_with_seed([1; 32]);
+
+    match simulator.step() {
+        Err(SimError::AlgorithmError(GeneticAlgorithmError::EvaluationFailed(details))) => {
+            expect_that!(&details.contains("genome 3"), eq(true));
+            expect_that!(&details.contains("genome contains a zero"), eq(true));
+        },
+        result => panic!("expected a failed evaluation, got {:?}", result),
+    }
+}
+
+#[test]
+fn genetic_algorithm_keeps_the_best_distinct_solutions_of_the_run_in_the_hall_of_fame() {
+    let initial_population: Population<MyGenome> = build_population()
+        .with_genome_builder(ValueEncodedGenomeBuilder::new(8, 0, 100))
+        .of_size(20)
+        .using_seed([42; 32]);
+    let algorithm = genetic_algorithm()
+        .with_evaluation(SumOfValues)
+        .with_selection(MaximizeSelector::new(0.85, 2))
+        .with_crossover(MultiPointCrossBreeder::new(2))
+        .with_mutation(RandomValueMutator::new(0.2, 0, 100))
+        .with_reinsertion(ElitistReinserter::new(SumOfValues, false, 0.85))
+        .with_initial_population(initial_population)
+        .with_hall_of_fame(10)
+        .build();
+    let mut simulator = simulate(algorithm)
+        .until(GenerationLimit::new(10))
+        .build_with_seed([9; 32]);
+
+    let mut best_of_generations = Vec::new();
+    let hall_of_fame = loop {
+        match simulator.step().unwrap() {
+            SimResult::Intermediate(state) => {
+                best_of_generations.push(state.result.best_solution.solution.fitness);
+            },
+            SimResult::Final(state, _, _, _, _) => {
+                best_of_generations.push(state.result.best_solution.solution.fitness);
+                break state.result.hall_of_fame.unwrap();
+            },
+        }
+    };
+
+    let members = hall_of_fame.members();
+    expect_that!(&members.len(), eq(10));
+    expect_that!(
+        &members
+            .windows(2)
+            .all(|pair| pair[0].fitness >= pair[1].fitness),
+        eq(true)
+    );
+    for (index, member) in members.iter().enumerate() {
+        expect_that!(
+            &members[index + 1..]
+                .iter()
+                .all(|other| other.genome != member.genome),
+            eq(true)
+        );
+    }
+    expect_that!(
+        &hall_of_fame.best().map(|best| best.fitness),
+        eq(best_of_generations.iter().max().copied())
+    );
+    expect_that!(
+        &simulator.algorithm().hall_of_fame(),
+        eq(Some(&hall_of_fame))
+    );
+}
+
+#[test]
+fn genetic_algorithm_keeps_no_hall_of_fame_by_default() {
+    let mut algorithm = sum_of_values_algorithm();
+
+    let state = algorithm.next(1, &mut get_rng([7; 32])).unwrap();
+
+    expect_that!(&state.hall_of_fame, eq(None));
+    expect_that!(&algorithm.hall_of_fame(), eq(None));
+}